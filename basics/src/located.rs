@@ -1,4 +1,5 @@
 #[derive(Debug, Clone, PartialEq, Eq, From, Into)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[from((T, Location))]
 #[into((T, Location))]
 pub struct Located<T>(pub T, pub Location);
@@ -1,4 +1,5 @@
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Location {
     range: Range<u32>, // in bytes
     line: u32,
@@ -28,4 +28,23 @@ impl From<NonNegative> for u32 {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for NonNegative {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for NonNegative {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = i32::deserialize(deserializer)?;
+        if value < 0 {
+            let msg = format!("value must be a non-negative integer: {value}");
+            return Err(serde::de::Error::custom(msg))
+        }
+        Ok(Self(value))
+    }
+}
+
 use derive_more::Into;
@@ -1,4 +1,5 @@
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NumberRadix {
     Binary = 2,
     Octal = 8,
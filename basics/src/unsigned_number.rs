@@ -1,4 +1,5 @@
 #[derive(Debug, Clone, Eq, PartialEq, From)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UnsignedNumber {
     #[from] IntegerConst(NonNegative),
     NumericConst { value: Box<str>, radix: NumberRadix },
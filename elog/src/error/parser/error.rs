@@ -63,6 +63,21 @@ pub enum Error {
     #[display("frame starting from following row cannot have preceding rows")]
     InvalidStartFollowingEndPrecedingFrame,
 
+    #[display(r#"window "{_0}" is already defined"#)]
+    DuplicateWindow(Str),
+
+    #[display(r#"window "{_0}" does not exist"#)]
+    UndefinedWindow(Str),
+
+    #[display(r#"cannot override PARTITION BY clause of window "{_0}""#)]
+    WindowPartitionOverride(Str),
+
+    #[display(r#"cannot override ORDER BY clause of window "{_0}""#)]
+    WindowOrderOverride(Str),
+
+    #[display(r#"cannot copy window "{_0}" because it has a frame clause"#)]
+    WindowFrameCopy(Str),
+
     #[display("type modifier cannot have parameter name")]
     InvalidNamedTypeModifier,
 
@@ -98,6 +113,43 @@ pub enum Error {
 
     #[display("only string constants are supported in JSON_TABLE path specification")]
     NonStringJsonTablePathSpec,
+
+    #[display("multiple COLLATE clauses not allowed")]
+    MultipleCollateClauses,
+
+    #[display("dropping an enum value is not implemented")]
+    DropEnumValue,
+
+    #[display("CREATE EXTENSION ... FROM is no longer supported")]
+    CreateExtensionFrom,
+
+    #[display("CREATE SCHEMA IF NOT EXISTS cannot include schema elements")]
+    SchemaElementsWithIfNotExists,
+
+    /// When a schema element is valid, but its statement isn't implemented yet.
+    #[display("{_0} is not supported as a schema element")]
+    UnsupportedSchemaElement(&'static str),
+
+    #[display("property graphs cannot be unlogged because they do not have storage")]
+    UnloggedPropertyGraph,
+
+    #[display("multiple ORDER BY clauses not allowed")]
+    MultipleSortClauses,
+
+    #[display("multiple OFFSET clauses not allowed")]
+    MultipleOffsetClauses,
+
+    #[display("multiple LIMIT clauses not allowed")]
+    MultipleLimitClauses,
+
+    #[display("LIMIT #,# syntax is not supported")]
+    LimitOffsetSyntax,
+
+    #[display("VALUES in FROM must have an alias")]
+    ValuesWithoutAlias,
+
+    #[display("stack depth limit exceeded")]
+    StackDepthLimitExceeded,
 }
 
 impl core::error::Error for Error {}
@@ -171,6 +223,11 @@ impl_log_message! {
     InvalidUnboundedPrecedingFrame => [WindowingError, None],
     InvalidCurrentRowFrame => [WindowingError, None],
     InvalidStartFollowingEndPrecedingFrame => [WindowingError, None],
+    DuplicateWindow => [WindowingError, None],
+    UndefinedWindow => [UndefinedObject, None],
+    WindowPartitionOverride => [WindowingError, None],
+    WindowOrderOverride => [WindowingError, None],
+    WindowFrameCopy => [WindowingError, None],
     InvalidNamedTypeModifier => [SyntaxError, None],
     InvalidOrderedTypeModifiers => [SyntaxError, None],
     MultipleOrderBy => [SyntaxError, None],
@@ -183,12 +240,29 @@ impl_log_message! {
     PathValueAlreadyDeclared => [SyntaxError, None],
     ConflictingNullability => [SyntaxError, None],
     NonStringJsonTablePathSpec => [FeatureNotSupported, None],
+    MultipleCollateClauses => [SyntaxError, None],
+    DropEnumValue => [FeatureNotSupported, None],
+    CreateExtensionFrom => [FeatureNotSupported, None],
+    SchemaElementsWithIfNotExists => [FeatureNotSupported, None],
+    UnsupportedSchemaElement => [FeatureNotSupported, None],
+    UnloggedPropertyGraph => [FeatureNotSupported, None],
+    MultipleSortClauses => [SyntaxError, None],
+    MultipleOffsetClauses => [SyntaxError, None],
+    MultipleLimitClauses => [SyntaxError, None],
+    LimitOffsetSyntax => [SyntaxError, Some("Use separate LIMIT and OFFSET clauses.")],
+    ValuesWithoutAlias => [SyntaxError, Some("For example, FROM (VALUES ...) [AS] foo.")],
+    StackDepthLimitExceeded => [
+        StatementTooComplex,
+        Some("Increase the parser configuration parameter \"max_stack_depth\", after ensuring the platform's stack depth limit is adequate.")
+    ],
 }
 
 use crate::sql_state::SqlState;
 use crate::sql_state::SqlState::FeatureNotSupported;
 use crate::sql_state::SqlState::InvalidParameterValue;
+use crate::sql_state::SqlState::StatementTooComplex;
 use crate::sql_state::SqlState::SyntaxError;
+use crate::sql_state::SqlState::UndefinedObject;
 use crate::sql_state::SqlState::WindowingError;
 use crate::LogMessage;
 use core::fmt;
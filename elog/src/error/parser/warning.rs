@@ -1,7 +1,23 @@
-#[derive(Debug, Copy, Clone, Eq, PartialEq, From, Display)]
+#[derive(Debug, Clone, Eq, PartialEq, Display)]
 pub enum Warning {
     #[display("GLOBAL is deprecated in temporary table creation")]
     DeprecatedGlobalTemporaryTable,
+
+    #[display(r"nonstandard use of \' in a string literal")]
+    NonstandardQuoteEscape,
+
+    #[display(r"nonstandard use of \\ in a string literal")]
+    NonstandardBackslashEscape,
+
+    #[display("nonstandard use of escape in a string literal")]
+    NonstandardEscape,
+
+    /// A `NOTICE` upstream.
+    #[display(r#"identifier "{identifier}" will be truncated to "{truncated}""#)]
+    IdentifierTruncated {
+        identifier: Str,
+        truncated: Str,
+    },
 }
 
 impl core::error::Error for Warning {}
@@ -11,12 +27,20 @@ impl LogMessage for Warning {
     fn sql_state(&self) -> SqlState {
         match self {
             Self::DeprecatedGlobalTemporaryTable => SqlState::Warning,
+            Self::NonstandardQuoteEscape
+            | Self::NonstandardBackslashEscape
+            | Self::NonstandardEscape => SqlState::NonstandardUseOfEscapeCharacter,
+            Self::IdentifierTruncated { .. } => SqlState::NameTooLong,
         }
     }
 
     fn hint(&self) -> Option<&str> {
         match self {
             Self::DeprecatedGlobalTemporaryTable => None,
+            Self::NonstandardQuoteEscape => Some("Use '' to write quotes in strings, or use the escape string syntax (E'...')."),
+            Self::NonstandardBackslashEscape => Some(r"Use the escape string syntax for backslashes, e.g., E'\\'."),
+            Self::NonstandardEscape => Some(r"Use the escape string syntax for escapes, e.g., E'\r\n'."),
+            Self::IdentifierTruncated { .. } => None,
         }
     }
 }
@@ -24,4 +48,4 @@ impl LogMessage for Warning {
 use crate::sql_state::SqlState;
 use crate::LogMessage;
 use derive_more::Display;
-use derive_more::From;
+use pg_basics::Str;
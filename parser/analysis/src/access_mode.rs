@@ -0,0 +1,11 @@
+/// How a statement uses an object it references.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum AccessMode {
+    /// Looked up, or read, e.g., a table in a `FROM` clause, or a type in a cast.
+    Read,
+    /// Data is written to it, e.g., the sequence of `nextval()`.
+    Write,
+    /// Created or altered.
+    Ddl,
+    Drop,
+}
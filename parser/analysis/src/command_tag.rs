@@ -0,0 +1,268 @@
+/// The tag that the server reports when the statement completes, like `CreateCommandTag()`.
+pub fn command_tag(stmt: &RawStmt) -> &'static str {
+    match stmt {
+        RawStmt::AlterCompositeTypeStmt(_) => "ALTER TYPE",
+        RawStmt::AlterDatabaseRefreshCollStmt(_) => "ALTER DATABASE",
+        RawStmt::AlterDatabaseSetStmt(_) => "ALTER DATABASE",
+        RawStmt::AlterDatabaseStmt(_) => "ALTER DATABASE",
+        RawStmt::AlterDefaultPrivilegesStmt(_) => "ALTER DEFAULT PRIVILEGES",
+        RawStmt::AlterDomainStmt(_) => "ALTER DOMAIN",
+        RawStmt::AlterEnumStmt(_) => "ALTER TYPE",
+        RawStmt::AlterEventTrigStmt(_) => "ALTER EVENT TRIGGER",
+        RawStmt::AlterExtensionContentsStmt(_) => "ALTER EXTENSION",
+        RawStmt::AlterExtensionStmt(_) => "ALTER EXTENSION",
+        RawStmt::AlterFunctionStmt(stmt) => match stmt.kind() {
+            AlterFunctionKind::Function => "ALTER FUNCTION",
+            AlterFunctionKind::Procedure => "ALTER PROCEDURE",
+            AlterFunctionKind::Routine => "ALTER ROUTINE",
+        },
+        RawStmt::AlterObjectDependsStmt(stmt) => match stmt.target() {
+            AlterObjectDependsTarget::Function(_) => "ALTER FUNCTION",
+            AlterObjectDependsTarget::Index(_) => "ALTER INDEX",
+            AlterObjectDependsTarget::MaterializedView(_) => "ALTER MATERIALIZED VIEW",
+            AlterObjectDependsTarget::Procedure(_) => "ALTER PROCEDURE",
+            AlterObjectDependsTarget::Routine(_) => "ALTER ROUTINE",
+            AlterObjectDependsTarget::Trigger { .. } => "ALTER TRIGGER",
+        },
+        RawStmt::AlterObjectSchemaStmt(stmt) => alter_object_schema(stmt.target()),
+        RawStmt::AlterOpFamilyStmt(_) => "ALTER OPERATOR FAMILY",
+        RawStmt::AlterOperatorStmt(_) => "ALTER OPERATOR",
+        RawStmt::AlterOwnerStmt(stmt) => alter_owner(stmt.target()),
+        RawStmt::AlterPropGraphStmt(_) => "ALTER PROPERTY GRAPH",
+        RawStmt::AlterRoleSetStmt(_) => "ALTER ROLE",
+        RawStmt::AlterRoleStmt(_) => "ALTER ROLE",
+        RawStmt::AlterSeqStmt(_) => "ALTER SEQUENCE",
+        RawStmt::AlterSystemStmt(_) => "ALTER SYSTEM",
+        RawStmt::AlterTextSearchConfigurationStmt(_) => "ALTER TEXT SEARCH CONFIGURATION",
+        RawStmt::AlterTextSearchDictionaryStmt(_) => "ALTER TEXT SEARCH DICTIONARY",
+        RawStmt::AlterUserMappingStmt(_) => "ALTER USER MAPPING",
+        RawStmt::CheckPointStmt(_) => "CHECKPOINT",
+        RawStmt::ClosePortalStmt(OneOrAll::All) => "CLOSE CURSOR ALL",
+        RawStmt::ClosePortalStmt(OneOrAll::One(_)) => "CLOSE CURSOR",
+        RawStmt::CommentStmt(_) => "COMMENT",
+        RawStmt::CompositeTypeStmt(_) => "CREATE TYPE",
+        RawStmt::ConstraintsSetStmt(_) => "SET CONSTRAINTS",
+        RawStmt::CreateAccessMethodStmt(_) => "CREATE ACCESS METHOD",
+        RawStmt::CreateCastStmt(_) => "CREATE CAST",
+        RawStmt::CreateConversionStmt(_) => "CREATE CONVERSION",
+        RawStmt::CreateDatabaseStmt(_) => "CREATE DATABASE",
+        RawStmt::CreateDomainStmt(_) => "CREATE DOMAIN",
+        RawStmt::CreateEnumStmt(_) => "CREATE TYPE",
+        RawStmt::CreateExtensionStmt(_) => "CREATE EXTENSION",
+        RawStmt::CreateOpClassStmt(_) => "CREATE OPERATOR CLASS",
+        RawStmt::CreateOpFamilyStmt(_) => "CREATE OPERATOR FAMILY",
+        RawStmt::CreatePLangStmt(_) => "CREATE LANGUAGE",
+        RawStmt::CreatePropGraphStmt(_) => "CREATE PROPERTY GRAPH",
+        RawStmt::CreateRangeStmt(_) => "CREATE TYPE",
+        // Also for `CREATE USER` and `CREATE GROUP`.
+        RawStmt::CreateRoleStmt(_) => "CREATE ROLE",
+        RawStmt::CreateSchemaStmt(_) => "CREATE SCHEMA",
+        RawStmt::CreateSeqStmt(_) => "CREATE SEQUENCE",
+        RawStmt::CreateStatsStmt(_) => "CREATE STATISTICS",
+        RawStmt::CreateTableSpaceStmt(_) => "CREATE TABLESPACE",
+        RawStmt::CreateTransformStmt(_) => "CREATE TRANSFORM",
+        RawStmt::CreateUserMappingStmt(_) => "CREATE USER MAPPING",
+        RawStmt::DeallocateStmt(OneOrAll::All) => "DEALLOCATE ALL",
+        RawStmt::DeallocateStmt(OneOrAll::One(_)) => "DEALLOCATE",
+        RawStmt::DefineStmt(stmt) => match stmt {
+            DefineStmt::Aggregate { .. } | DefineStmt::OldStyleAggregate { .. } => "CREATE AGGREGATE",
+            DefineStmt::Collation { .. } => "CREATE COLLATION",
+            DefineStmt::Operator { .. } => "CREATE OPERATOR",
+            DefineStmt::Type { .. } => "CREATE TYPE",
+            DefineStmt::TextSearchConfiguration { .. } => "CREATE TEXT SEARCH CONFIGURATION",
+            DefineStmt::TextSearchDictionary { .. } => "CREATE TEXT SEARCH DICTIONARY",
+            DefineStmt::TextSearchParser { .. } => "CREATE TEXT SEARCH PARSER",
+            DefineStmt::TextSearchTemplate { .. } => "CREATE TEXT SEARCH TEMPLATE",
+        },
+        RawStmt::DiscardStmt(stmt) => match stmt {
+            DiscardStmt::All => "DISCARD ALL",
+            DiscardStmt::Plans => "DISCARD PLANS",
+            DiscardStmt::Sequences => "DISCARD SEQUENCES",
+            DiscardStmt::Temporary => "DISCARD TEMP",
+        },
+        RawStmt::DropStmt(stmt) => match stmt.target() {
+            DropTarget::PropertyGraph(_) => "DROP PROPERTY GRAPH",
+        },
+        RawStmt::ListenStmt(_) => "LISTEN",
+        RawStmt::LoadStmt(_) => "LOAD",
+        RawStmt::NotifyStmt(_) => "NOTIFY",
+        RawStmt::PrepareStmt(_) => "PREPARE",
+        RawStmt::PrepareTransactionStmt(_) => "PREPARE TRANSACTION",
+        RawStmt::ReassignOwnedStmt(_) => "REASSIGN OWNED",
+        RawStmt::RefreshCollationVersionStmt(_) => "ALTER COLLATION",
+        RawStmt::RenameStmt(stmt) => rename(stmt.target()),
+        RawStmt::RuleStmt(_) => "CREATE RULE",
+        RawStmt::SecurityLabelStmt(_) => "SECURITY LABEL",
+        RawStmt::SelectStmt(_) => "SELECT",
+        RawStmt::TransactionStmt(stmt) => match stmt {
+            TransactionStmt::Begin(_) => "BEGIN",
+            TransactionStmt::Start(_) => "START TRANSACTION",
+            TransactionStmt::Commit { .. } => "COMMIT",
+            TransactionStmt::CommitPrepared(_) => "COMMIT PREPARED",
+            TransactionStmt::Savepoint(_) => "SAVEPOINT",
+            TransactionStmt::Release(_) => "RELEASE",
+            TransactionStmt::Prepare(_) => "PREPARE TRANSACTION",
+            TransactionStmt::Rollback { .. } | TransactionStmt::RollbackTo(_) => "ROLLBACK",
+            TransactionStmt::RollbackPrepared(_) => "ROLLBACK PREPARED",
+        },
+        RawStmt::UnlistenStmt(_) => "UNLISTEN",
+        RawStmt::VariableResetStmt(_) => "RESET",
+        RawStmt::VariableSetStmt(_) => "SET",
+        RawStmt::VariableShowStmt(_) => "SHOW",
+    }
+}
+
+fn alter_object_schema(target: &AlterObjectSchemaTarget) -> &'static str {
+    use AlterObjectSchemaTarget as T;
+    match target {
+        T::Aggregate(_) => "ALTER AGGREGATE",
+        T::Collation(_) => "ALTER COLLATION",
+        T::Conversion(_) => "ALTER CONVERSION",
+        T::Domain(_) => "ALTER DOMAIN",
+        T::Extension(_) => "ALTER EXTENSION",
+        T::ForeignTable { .. } => "ALTER FOREIGN TABLE",
+        T::Function(_) => "ALTER FUNCTION",
+        T::MaterializedView { .. } => "ALTER MATERIALIZED VIEW",
+        T::Operator(_) => "ALTER OPERATOR",
+        T::OperatorClass(_) => "ALTER OPERATOR CLASS",
+        T::OperatorFamily(_) => "ALTER OPERATOR FAMILY",
+        T::Procedure(_) => "ALTER PROCEDURE",
+        T::PropertyGraph { .. } => "ALTER PROPERTY GRAPH",
+        T::Routine(_) => "ALTER ROUTINE",
+        T::Sequence { .. } => "ALTER SEQUENCE",
+        T::Statistic(_) => "ALTER STATISTICS",
+        T::Table { .. } => "ALTER TABLE",
+        T::TextSearchConfiguration(_) => "ALTER TEXT SEARCH CONFIGURATION",
+        T::TextSearchDictionary(_) => "ALTER TEXT SEARCH DICTIONARY",
+        T::TextSearchParser(_) => "ALTER TEXT SEARCH PARSER",
+        T::TextSearchTemplate(_) => "ALTER TEXT SEARCH TEMPLATE",
+        T::Type(_) => "ALTER TYPE",
+        T::View { .. } => "ALTER VIEW",
+    }
+}
+
+fn alter_owner(target: &AlterOwnerTarget) -> &'static str {
+    use AlterOwnerTarget as T;
+    match target {
+        T::Aggregate(_) => "ALTER AGGREGATE",
+        T::Collation(_) => "ALTER COLLATION",
+        T::Conversion(_) => "ALTER CONVERSION",
+        T::Database(_) => "ALTER DATABASE",
+        T::Domain(_) => "ALTER DOMAIN",
+        T::EventTrigger(_) => "ALTER EVENT TRIGGER",
+        T::ForeignDataWrapper(_) => "ALTER FOREIGN DATA WRAPPER",
+        T::ForeignServer(_) => "ALTER SERVER",
+        T::Function(_) => "ALTER FUNCTION",
+        T::Language(_) => "ALTER LANGUAGE",
+        T::LargeObject(_) => "ALTER LARGE OBJECT",
+        T::Operator(_) => "ALTER OPERATOR",
+        T::OperatorClass(_) => "ALTER OPERATOR CLASS",
+        T::OperatorFamily(_) => "ALTER OPERATOR FAMILY",
+        T::Procedure(_) => "ALTER PROCEDURE",
+        T::PropertyGraph(_) => "ALTER PROPERTY GRAPH",
+        T::Publication(_) => "ALTER PUBLICATION",
+        T::Routine(_) => "ALTER ROUTINE",
+        T::Schema(_) => "ALTER SCHEMA",
+        T::Statistic(_) => "ALTER STATISTICS",
+        T::Subscription(_) => "ALTER SUBSCRIPTION",
+        T::Tablespace(_) => "ALTER TABLESPACE",
+        T::TextSearchConfiguration(_) => "ALTER TEXT SEARCH CONFIGURATION",
+        T::TextSearchDictionary(_) => "ALTER TEXT SEARCH DICTIONARY",
+        T::Type(_) => "ALTER TYPE",
+    }
+}
+
+/// Like upstream, renaming a column has the tag of its relation's type.
+fn rename(target: &RenameTarget) -> &'static str {
+    use RenameTarget as T;
+    match target {
+        T::Aggregate(_) => "ALTER AGGREGATE",
+        T::Collation(_) => "ALTER COLLATION",
+        T::Conversion(_) => "ALTER CONVERSION",
+        T::Database(_) => "ALTER DATABASE",
+        T::Domain(_) | T::DomainConstraint { .. } => "ALTER DOMAIN",
+        T::EventTrigger(_) => "ALTER EVENT TRIGGER",
+        T::ForeignDataWrapper(_) => "ALTER FOREIGN DATA WRAPPER",
+        T::ForeignServer(_) => "ALTER SERVER",
+        T::ForeignTable { .. } | T::ForeignTableColumn { .. } => "ALTER FOREIGN TABLE",
+        T::Function(_) => "ALTER FUNCTION",
+        T::Index { .. } => "ALTER INDEX",
+        T::Language(_) => "ALTER LANGUAGE",
+        T::MaterializedView { .. } | T::MaterializedViewColumn { .. } => "ALTER MATERIALIZED VIEW",
+        T::OperatorClass(_) => "ALTER OPERATOR CLASS",
+        T::OperatorFamily(_) => "ALTER OPERATOR FAMILY",
+        T::Policy { .. } => "ALTER POLICY",
+        T::Procedure(_) => "ALTER PROCEDURE",
+        T::PropertyGraph(_) => "ALTER PROPERTY GRAPH",
+        T::Publication(_) => "ALTER PUBLICATION",
+        T::Role(_) => "ALTER ROLE",
+        T::Routine(_) => "ALTER ROUTINE",
+        T::Rule { .. } => "ALTER RULE",
+        T::Schema(_) => "ALTER SCHEMA",
+        T::Sequence { .. } => "ALTER SEQUENCE",
+        T::Statistic(_) => "ALTER STATISTICS",
+        T::Subscription(_) => "ALTER SUBSCRIPTION",
+        T::Table { .. } | T::TableColumn { .. } | T::TableConstraint { .. } => "ALTER TABLE",
+        T::Tablespace(_) => "ALTER TABLESPACE",
+        T::TextSearchConfiguration(_) => "ALTER TEXT SEARCH CONFIGURATION",
+        T::TextSearchDictionary(_) => "ALTER TEXT SEARCH DICTIONARY",
+        T::TextSearchParser(_) => "ALTER TEXT SEARCH PARSER",
+        T::TextSearchTemplate(_) => "ALTER TEXT SEARCH TEMPLATE",
+        T::Trigger { .. } => "ALTER TRIGGER",
+        T::Type(_) | T::TypeAttribute { .. } => "ALTER TYPE",
+        T::View { .. } | T::ViewColumn { .. } => "ALTER VIEW",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pg_parser::Parser;
+    use pg_parser_core::ParserConfig;
+    use test_case::test_case;
+
+    #[test_case("select 1", "SELECT")]
+    #[test_case("values (1)", "SELECT")]
+    #[test_case("begin", "BEGIN")]
+    #[test_case("start transaction read only", "START TRANSACTION")]
+    #[test_case("end", "COMMIT")]
+    #[test_case("rollback to savepoint s", "ROLLBACK")]
+    #[test_case("commit prepared 'x'", "COMMIT PREPARED")]
+    #[test_case("prepare transaction 'x'", "PREPARE TRANSACTION")]
+    #[test_case("set a = 1", "SET")]
+    #[test_case("reset all", "RESET")]
+    #[test_case("show a", "SHOW")]
+    #[test_case("close all", "CLOSE CURSOR ALL")]
+    #[test_case("deallocate p", "DEALLOCATE")]
+    #[test_case("discard temp", "DISCARD TEMP")]
+    #[test_case("create user u", "CREATE ROLE")]
+    #[test_case("create type t as enum ('a')", "CREATE TYPE")]
+    #[test_case("create text search parser p (start = f)", "CREATE TEXT SEARCH PARSER")]
+    #[test_case("alter procedure p() owner to r", "ALTER PROCEDURE")]
+    #[test_case("alter type t rename to u", "ALTER TYPE")]
+    #[test_case("alter function f() set schema x", "ALTER FUNCTION")]
+    #[test_case("alter text search dictionary d set schema x", "ALTER TEXT SEARCH DICTIONARY")]
+    #[test_case("alter collation c refresh version", "ALTER COLLATION")]
+    fn test_command_tag(source: &str, expected: &str) {
+        let stmt = Parser::new(source, ParserConfig::default())
+            .parse()
+            .result
+            .remove(0)
+            .unwrap()
+            .into_source();
+
+        assert_eq!(expected, command_tag(&stmt));
+    }
+}
+
+use pg_ast::AlterFunctionKind;
+use pg_ast::AlterObjectDependsTarget;
+use pg_ast::AlterObjectSchemaTarget;
+use pg_ast::AlterOwnerTarget;
+use pg_ast::DefineStmt;
+use pg_ast::DiscardStmt;
+use pg_ast::DropTarget;
+use pg_ast::OneOrAll;
+use pg_ast::RawStmt;
+use pg_ast::RenameTarget;
+use pg_ast::TransactionStmt;
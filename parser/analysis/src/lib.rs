@@ -0,0 +1,13 @@
+//! Analysis of parsed statements, like the classification that `tcop/utility.c` does upstream,
+//! e.g., to route read-only statements to replicas, or to check which objects a statement uses.
+
+pg_basics::reexport! { pub
+    access_mode,
+    command_tag,
+    log_stmt_level,
+    object_kind,
+    object_reference,
+    read_only_flags,
+    references,
+    stmt_class,
+}
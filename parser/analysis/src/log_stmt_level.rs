@@ -0,0 +1,10 @@
+/// The least `log_statement` setting that logs a statement, like `GetCommandLogLevel()`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum LogStmtLevel {
+    /// Data definition, e.g., `CREATE`, `ALTER` and `DROP`.
+    Ddl,
+    /// Data modification, e.g., `INSERT`, `UPDATE` and `DELETE`.
+    Mod,
+    /// Everything else, e.g., `SELECT`, `SET` and transaction control.
+    All,
+}
@@ -0,0 +1,34 @@
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ObjectKind {
+    Collation,
+    Database,
+    Extension,
+    ForeignDataWrapper,
+    ForeignServer,
+    /// Also aggregates, procedures and routines.
+    Function,
+    Language,
+    OperatorClass,
+    OperatorFamily,
+    /// A configuration parameter, e.g., in `GRANT SET ON PARAMETER`.
+    Parameter,
+    PropertyGraph,
+    /// A table, view, materialized view or foreign table.
+    Relation,
+    Role,
+    /// Named by its table, and then its own name.
+    Rule,
+    Schema,
+    Sequence,
+    /// Extended statistics, from `CREATE STATISTICS`.
+    Statistics,
+    Tablespace,
+    TextSearchConfiguration,
+    TextSearchDictionary,
+    TextSearchParser,
+    TextSearchTemplate,
+    /// Named by the type that it transforms.
+    Transform,
+    /// Also domains.
+    Type,
+}
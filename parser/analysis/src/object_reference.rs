@@ -0,0 +1,30 @@
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ObjectReference {
+    kind: ObjectKind,
+    name: QualifiedName,
+    access: AccessMode,
+}
+
+impl ObjectReference {
+    pub fn new(kind: ObjectKind, name: QualifiedName, access: AccessMode) -> Self {
+        Self { kind, name, access }
+    }
+
+    pub fn kind(&self) -> ObjectKind {
+        self.kind
+    }
+
+    /// As written in the statement, so it's only qualified when the statement qualifies it.
+    pub fn name(&self) -> &[Str] {
+        &self.name
+    }
+
+    pub fn access(&self) -> AccessMode {
+        self.access
+    }
+}
+
+use crate::AccessMode;
+use crate::ObjectKind;
+use pg_basics::QualifiedName;
+use pg_basics::Str;
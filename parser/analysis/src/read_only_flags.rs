@@ -0,0 +1,20 @@
+bitflags! {
+    /// Where a statement is allowed to run, like the `COMMAND_OK_IN_*` flags
+    /// of `ClassifyUtilityCommandAsReadOnly()`.
+    #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+    pub struct ReadOnlyFlags: u8 {
+        const None             = 0;
+        /// In a `READ ONLY` transaction.
+        const OkInReadOnlyTxn  = 0x01;
+        /// In a parallel worker.
+        const OkInParallelMode = 0x02;
+        /// On a standby, during recovery.
+        const OkInRecovery     = 0x04;
+        /// Doesn't change anything, anywhere.
+        const StrictlyReadOnly = Self::OkInReadOnlyTxn.bits()
+            | Self::OkInParallelMode.bits()
+            | Self::OkInRecovery.bits();
+    }
+}
+
+use bitflags::bitflags;
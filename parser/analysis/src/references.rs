@@ -0,0 +1,1117 @@
+/// The objects that a statement references, in the order they're found, without duplicates.
+///
+/// The statement decides the access mode (e.g., `DROP` drops its targets), except that
+/// expressions, types, and queries inside a statement only read what they reference,
+/// and that `nextval()` and `setval()` write their sequence.
+/// The objects that DDL only uses are also only read, e.g., the support functions of an operator class,
+/// or the functions and types that the arguments of a definition name (like `sfunc = f`).
+///
+/// Schema-qualified names also reference their schema, which is only looked up.
+///
+/// Builtin types that have their own syntax (e.g., `integer` or `varchar(10)`) are named like PG names them
+/// (e.g., `pg_catalog.int4`), so they're reported like any other type.
+/// Roles like `PUBLIC` or `CURRENT_USER` aren't included.
+///
+/// `WITH` isn't parsed yet, so there aren't any CTE names to tell apart from relations.
+pub fn references(stmt: &RawStmt) -> Vec<ObjectReference> {
+    let mut visitor = References::new();
+    let _ = stmt.accept(&mut visitor);
+    visitor.references
+}
+
+#[derive(Debug)]
+struct References {
+    references: Vec<ObjectReference>,
+    access: AccessMode,
+    /// The kind of the next relation name, when it's the name of e.g., a sequence.
+    next_relation: Option<ObjectKind>,
+}
+
+impl References {
+
+    fn new() -> Self {
+        Self {
+            references: Vec::new(),
+            access: AccessMode::Read,
+            next_relation: None,
+        }
+    }
+
+    fn push(&mut self, kind: ObjectKind, name: QualifiedName) {
+        self.push_reference(ObjectReference::new(kind, name, self.access));
+    }
+
+    /// Also pushes the schema, when the name has one.
+    fn push_qualified(&mut self, kind: ObjectKind, name: QualifiedName) {
+        if let [.., schema, _] = &name[..] {
+            let schema = ObjectReference::new(ObjectKind::Schema, vec![schema.clone()], AccessMode::Read);
+            self.push_reference(schema);
+        }
+        self.push(kind, name);
+    }
+
+    /// Pushes an object that the statement only looks up.
+    fn push_read(&mut self, kind: ObjectKind, name: QualifiedName) {
+        let outer = mem::replace(&mut self.access, AccessMode::Read);
+        self.push_qualified(kind, name);
+        self.access = outer;
+    }
+
+    fn push_reference(&mut self, reference: ObjectReference) {
+        if !self.references.contains(&reference) {
+            self.references.push(reference);
+        }
+    }
+
+    fn push_names<'a, T>(&mut self, kind: ObjectKind, names: T)
+    where
+        T: IntoIterator<Item = &'a Str>
+    {
+        for name in names {
+            self.push(kind, vec![name.clone()]);
+        }
+    }
+
+    fn with_access<F>(&mut self, access: AccessMode, f: F) -> ControlFlow<()>
+    where
+        F: FnOnce(&mut Self) -> ControlFlow<()>
+    {
+        let outer = mem::replace(&mut self.access, access);
+        // The relation names inside e.g., an expression aren't the target of the outer node.
+        let next_relation = self.next_relation.take();
+        let result = f(self);
+        self.access = outer;
+        self.next_relation = next_relation;
+        result
+    }
+
+    /// The first relation name in the node is its target, because nodes are walked in declaration order.
+    fn walk_with_relation<T: Walk>(&mut self, kind: ObjectKind, node: &T) -> ControlFlow<()> {
+        let outer = self.next_relation.replace(kind);
+        let result = node.walk(self);
+        self.next_relation = outer;
+        result
+    }
+}
+
+impl Visit for References {
+    type Break = ();
+
+    fn visit_raw_stmt(&mut self, node: &RawStmt) -> ControlFlow<()> {
+        let access = match node {
+            RawStmt::DropStmt(_) => AccessMode::Drop,
+            _ if classify(node).log_level() == LogStmtLevel::Ddl => AccessMode::Ddl,
+            _ => AccessMode::Read,
+        };
+        self.with_access(access, |visitor| node.walk(visitor))
+    }
+
+    fn visit_select_stmt(&mut self, node: &SelectStmt) -> ControlFlow<()> {
+        self.with_access(AccessMode::Read, |visitor| node.walk(visitor))
+    }
+
+    fn visit_expr_node(&mut self, node: &ExprNode) -> ControlFlow<()> {
+        self.with_access(AccessMode::Read, |visitor| node.walk(visitor))
+    }
+
+    fn visit_type(&mut self, node: &Type) -> ControlFlow<()> {
+        self.with_access(AccessMode::Read, |visitor| {
+            if let Some(name) = type_name(node.name()) {
+                visitor.push_qualified(ObjectKind::Type, name);
+            }
+            node.walk(visitor)
+        })
+    }
+
+    fn visit_relation_name(&mut self, node: &RelationName) -> ControlFlow<()> {
+        let kind = self.next_relation.take().unwrap_or(ObjectKind::Relation);
+        self.push_qualified(kind, relation_name(node));
+        node.walk(self)
+    }
+
+    fn visit_collate_expr(&mut self, node: &CollateExpr) -> ControlFlow<()> {
+        node.walk(self)?;
+        self.push_read(ObjectKind::Collation, node.collation().clone());
+        Continue(())
+    }
+
+    fn visit_simple_column_definition(&mut self, node: &SimpleColumnDefinition) -> ControlFlow<()> {
+        node.walk(self)?;
+        if let Some(collation) = node.collation() {
+            self.push_read(ObjectKind::Collation, collation.to_vec());
+        }
+        Continue(())
+    }
+
+    fn visit_create_domain_stmt(&mut self, node: &CreateDomainStmt) -> ControlFlow<()> {
+        self.push_qualified(ObjectKind::Type, node.domain().clone());
+        node.walk(self)?;
+        if let Some(collation) = node.collation() {
+            self.push_read(ObjectKind::Collation, collation.clone());
+        }
+        Continue(())
+    }
+
+    fn visit_alter_domain_stmt(&mut self, node: &AlterDomainStmt) -> ControlFlow<()> {
+        self.push_qualified(ObjectKind::Type, node.domain().clone());
+        node.walk(self)
+    }
+
+    fn visit_create_enum_stmt(&mut self, node: &CreateEnumStmt) -> ControlFlow<()> {
+        self.push_qualified(ObjectKind::Type, node.type_name().clone());
+        node.walk(self)
+    }
+
+    fn visit_alter_enum_stmt(&mut self, node: &AlterEnumStmt) -> ControlFlow<()> {
+        self.push_qualified(ObjectKind::Type, node.type_name().clone());
+        node.walk(self)
+    }
+
+    fn visit_create_range_stmt(&mut self, node: &CreateRangeStmt) -> ControlFlow<()> {
+        self.push_qualified(ObjectKind::Type, node.type_name().clone());
+        node.walk(self)
+    }
+
+    fn visit_composite_type_stmt(&mut self, node: &CompositeTypeStmt) -> ControlFlow<()> {
+        self.push_qualified(ObjectKind::Type, node.type_name().clone());
+        node.walk(self)
+    }
+
+    fn visit_alter_composite_type_stmt(&mut self, node: &AlterCompositeTypeStmt) -> ControlFlow<()> {
+        self.push_qualified(ObjectKind::Type, node.type_name().clone());
+        node.walk(self)
+    }
+
+    fn visit_alter_type_cmd(&mut self, node: &AlterTypeCmd) -> ControlFlow<()> {
+        node.walk(self)?;
+        if let AlterTypeCmd::AlterAttributeType { collation: Some(collation), .. } = node {
+            self.push_read(ObjectKind::Collation, collation.clone());
+        }
+        Continue(())
+    }
+
+    fn visit_define_stmt(&mut self, node: &DefineStmt) -> ControlFlow<()> {
+
+        let target = match node {
+            // The aggregate is pushed by its `AggregateWithArgs`.
+            DefineStmt::Aggregate { .. } => None,
+            DefineStmt::OldStyleAggregate { name, .. } => Some((ObjectKind::Function, name)),
+            DefineStmt::Collation { name, .. } => Some((ObjectKind::Collation, name)),
+            // Operators aren't reported.
+            DefineStmt::Operator { .. } => None,
+            DefineStmt::Type { name, .. } => Some((ObjectKind::Type, name)),
+            DefineStmt::TextSearchConfiguration { name, .. } => Some((ObjectKind::TextSearchConfiguration, name)),
+            DefineStmt::TextSearchDictionary { name, .. } => Some((ObjectKind::TextSearchDictionary, name)),
+            DefineStmt::TextSearchParser { name, .. } => Some((ObjectKind::TextSearchParser, name)),
+            DefineStmt::TextSearchTemplate { name, .. } => Some((ObjectKind::TextSearchTemplate, name)),
+        };
+
+        if let Some((kind, name)) = target {
+            self.push_qualified(kind, name.clone());
+        }
+        node.walk(self)
+    }
+
+    /// `CREATE COLLATION ... FROM` copies a collation, which is only looked up.
+    fn visit_collation_definition(&mut self, node: &CollationDefinition) -> ControlFlow<()> {
+        if let CollationDefinition::From(name) = node {
+            self.push_read(ObjectKind::Collation, name.clone());
+        }
+        node.walk(self)
+    }
+
+    /// The argument of a definition is only a reference when its key names an object,
+    /// e.g., `sfunc = f` is a function, and `stype = t` is a type, which are only looked up.
+    /// The other arguments (e.g., `category = 'x'`, or operators) aren't reported.
+    fn visit_definition_elem(&mut self, node: &DefinitionElem) -> ControlFlow<()> {
+
+        let Some(kind) = definition_kind(node.name()) else { return Continue(()) };
+
+        match node.arg() {
+            // The multirange type is created along with its range.
+            Some(DefinitionArg::Type(FuncType::Type(arg))) if node.name() == "multirange_type_name" => {
+                if let Some(name) = type_name(arg.name()) {
+                    self.push_qualified(ObjectKind::Type, name);
+                }
+            },
+            Some(DefinitionArg::Type(FuncType::Type(arg))) if kind == ObjectKind::Type => return arg.accept(self),
+            Some(DefinitionArg::Type(FuncType::Type(arg))) => {
+                if let TypeName::Generic { name, .. } = arg.name() {
+                    self.push_read(kind, name.clone());
+                }
+            },
+            // Like `defGetQualifiedName()`, a string is a single name.
+            Some(DefinitionArg::String(name)) => self.push_read(kind, vec![Str::from_cloning(name)]),
+            _ => {},
+        }
+
+        Continue(())
+    }
+
+    fn visit_create_plang_stmt(&mut self, node: &CreatePLangStmt) -> ControlFlow<()> {
+        self.push(ObjectKind::Language, vec![Str::from_cloning(node.name())]);
+        node.walk(self)
+    }
+
+    /// The handler functions of a language are only looked up.
+    fn visit_plang_handler(&mut self, node: &PLangHandler) -> ControlFlow<()> {
+        let functions = iter::once(node.handler())
+            .chain(node.inline_handler())
+            .chain(node.validator());
+        for function in functions {
+            self.push_read(ObjectKind::Function, function.clone());
+        }
+        Continue(())
+    }
+
+    fn visit_create_table_space_stmt(&mut self, node: &CreateTableSpaceStmt) -> ControlFlow<()> {
+        self.push(ObjectKind::Tablespace, vec![Str::from_cloning(node.name())]);
+        node.walk(self)
+    }
+
+    fn visit_create_op_class_stmt(&mut self, node: &CreateOpClassStmt) -> ControlFlow<()> {
+        self.push_qualified(ObjectKind::OperatorClass, node.name().clone());
+        if let Some(family) = node.family() {
+            self.push_read(ObjectKind::OperatorFamily, family.clone());
+        }
+        node.walk(self)
+    }
+
+    fn visit_create_op_family_stmt(&mut self, node: &CreateOpFamilyStmt) -> ControlFlow<()> {
+        self.push_qualified(ObjectKind::OperatorFamily, node.name().clone());
+        node.walk(self)
+    }
+
+    fn visit_alter_op_family_stmt(&mut self, node: &AlterOpFamilyStmt) -> ControlFlow<()> {
+        self.push_qualified(ObjectKind::OperatorFamily, node.name().clone());
+        node.walk(self)
+    }
+
+    /// The operators, support functions and types of an operator class are only looked up.
+    fn visit_op_class_item(&mut self, node: &OpClassItem) -> ControlFlow<()> {
+        self.with_access(AccessMode::Read, |visitor| node.walk(visitor))
+    }
+
+    fn visit_op_class_purpose(&mut self, node: &OpClassPurpose) -> ControlFlow<()> {
+        if let OpClassPurpose::OrderBy(family) = node {
+            self.push_read(ObjectKind::OperatorFamily, family.clone());
+        }
+        node.walk(self)
+    }
+
+    fn visit_create_stats_stmt(&mut self, node: &CreateStatsStmt) -> ControlFlow<()> {
+        if let Some(name) = node.name() {
+            self.push_qualified(ObjectKind::Statistics, name.clone());
+        }
+        node.walk(self)
+    }
+
+    fn visit_rule_stmt(&mut self, node: &RuleStmt) -> ControlFlow<()> {
+        let mut name = relation_name(node.relation());
+        name.push(Str::from_cloning(node.name()));
+        self.push(ObjectKind::Rule, name);
+        node.walk(self)
+    }
+
+    /// The functions of a transform are only looked up.
+    fn visit_create_transform_stmt(&mut self, node: &CreateTransformStmt) -> ControlFlow<()> {
+        if let Some(name) = type_name(node.transform().for_type().name()) {
+            self.push(ObjectKind::Transform, name);
+        }
+        node.transform().accept(self)?;
+        self.with_access(AccessMode::Read, |visitor| {
+            node.from_sql()
+                .into_iter()
+                .chain(node.to_sql())
+                .try_for_each(|function| function.accept(visitor))
+        })
+    }
+
+    fn visit_transform(&mut self, node: &Transform) -> ControlFlow<()> {
+        node.walk(self)?;
+        self.push_read(ObjectKind::Language, vec![Str::from_cloning(node.language())]);
+        Continue(())
+    }
+
+    fn visit_create_seq_stmt(&mut self, node: &CreateSeqStmt) -> ControlFlow<()> {
+        self.walk_with_relation(ObjectKind::Sequence, node)
+    }
+
+    fn visit_alter_seq_stmt(&mut self, node: &AlterSeqStmt) -> ControlFlow<()> {
+        self.walk_with_relation(ObjectKind::Sequence, node)
+    }
+
+    fn visit_create_prop_graph_stmt(&mut self, node: &CreatePropGraphStmt) -> ControlFlow<()> {
+        self.walk_with_relation(ObjectKind::PropertyGraph, node)
+    }
+
+    fn visit_graph_table(&mut self, node: &GraphTable) -> ControlFlow<()> {
+        self.walk_with_relation(ObjectKind::PropertyGraph, node)
+    }
+
+    /// `OWNED BY t.a` references the table of the column, which is only looked up, like the type of `AS`.
+    fn visit_seq_opt_elem(&mut self, node: &SeqOptElem) -> ControlFlow<()> {
+        if let SeqOptElem::As(type_name) = node
+            && let Some(name) = self::type_name(type_name)
+        {
+            self.push_read(ObjectKind::Type, name);
+        }
+        // `OWNED BY NONE` doesn't have a table.
+        if let SeqOptElem::OwnedBy(name) = node
+            && let [table @ .., _] = &name[..]
+            && !table.is_empty()
+        {
+            self.with_access(AccessMode::Read, |visitor| {
+                visitor.push_qualified(ObjectKind::Relation, table.to_vec());
+                Continue(())
+            })?;
+        }
+        node.walk(self)
+    }
+
+    fn visit_func_call(&mut self, node: &FuncCall) -> ControlFlow<()> {
+        self.push_qualified(ObjectKind::Function, node.name().to_vec());
+        if let Some((access, name)) = sequence_argument(node) {
+            self.with_access(access, |visitor| {
+                visitor.push_qualified(ObjectKind::Sequence, name);
+                Continue(())
+            })?;
+        }
+        node.walk(self)
+    }
+
+    fn visit_function_with_args(&mut self, node: &FunctionWithArgs) -> ControlFlow<()> {
+        self.push_qualified(ObjectKind::Function, node.name().to_vec());
+        node.walk(self)
+    }
+
+    fn visit_aggregate_with_args(&mut self, node: &AggregateWithArgs) -> ControlFlow<()> {
+        self.push_qualified(ObjectKind::Function, node.name().clone());
+        node.walk(self)
+    }
+
+    fn visit_create_schema_stmt(&mut self, node: &CreateSchemaStmt) -> ControlFlow<()> {
+        if let Some(name) = node.name() {
+            self.push(ObjectKind::Schema, vec![Str::from_cloning(name)]);
+        }
+        node.walk(self)
+    }
+
+    /// The new schema is only looked up.
+    fn visit_alter_object_schema_stmt(&mut self, node: &AlterObjectSchemaStmt) -> ControlFlow<()> {
+        node.target().accept(self)?;
+        self.with_access(AccessMode::Read, |visitor| {
+            visitor.push(ObjectKind::Schema, vec![Str::from_cloning(node.new_schema())]);
+            Continue(())
+        })
+    }
+
+    fn visit_create_extension_stmt(&mut self, node: &CreateExtensionStmt) -> ControlFlow<()> {
+        self.push(ObjectKind::Extension, vec![Str::from_cloning(node.name())]);
+        node.walk(self)
+    }
+
+    fn visit_alter_extension_stmt(&mut self, node: &AlterExtensionStmt) -> ControlFlow<()> {
+        self.push(ObjectKind::Extension, vec![Str::from_cloning(node.name())]);
+        node.walk(self)
+    }
+
+    fn visit_alter_extension_contents_stmt(&mut self, node: &AlterExtensionContentsStmt) -> ControlFlow<()> {
+        self.push(ObjectKind::Extension, vec![Str::from_cloning(node.name())]);
+        node.walk(self)
+    }
+
+    /// The schema that the extension is created in is only looked up.
+    fn visit_create_extension_option(&mut self, node: &CreateExtensionOption) -> ControlFlow<()> {
+        if let CreateExtensionOption::Schema(name) = node {
+            self.with_access(AccessMode::Read, |visitor| {
+                visitor.push(ObjectKind::Schema, vec![name.clone()]);
+                Continue(())
+            })?;
+        }
+        node.walk(self)
+    }
+
+    fn visit_alter_extension_contents_target(&mut self, node: &AlterExtensionContentsTarget) -> ControlFlow<()> {
+        if let AlterExtensionContentsTarget::Schema(name) = node {
+            self.push(ObjectKind::Schema, vec![name.clone()]);
+        }
+        node.walk(self)
+    }
+
+    fn visit_alter_owner_target(&mut self, node: &AlterOwnerTarget) -> ControlFlow<()> {
+        if let AlterOwnerTarget::Schema(name) = node {
+            self.push(ObjectKind::Schema, vec![name.clone()]);
+        }
+        node.walk(self)
+    }
+
+    fn visit_comment_target(&mut self, node: &CommentTarget) -> ControlFlow<()> {
+        if let CommentTarget::Schema(name) = node {
+            self.push(ObjectKind::Schema, vec![name.clone()]);
+        }
+        node.walk(self)
+    }
+
+    fn visit_rename_target(&mut self, node: &RenameTarget) -> ControlFlow<()> {
+        if let RenameTarget::Schema(name) = node {
+            self.push(ObjectKind::Schema, vec![name.clone()]);
+        }
+        node.walk(self)
+    }
+
+    fn visit_security_label_target(&mut self, node: &SecurityLabelTarget) -> ControlFlow<()> {
+        if let SecurityLabelTarget::Schema(name) = node {
+            self.push(ObjectKind::Schema, vec![name.clone()]);
+        }
+        node.walk(self)
+    }
+
+    fn visit_role_spec(&mut self, node: &RoleSpec) -> ControlFlow<()> {
+        if let RoleSpec::Name(name) = node {
+            self.push(ObjectKind::Role, vec![name.clone()]);
+        }
+        Continue(())
+    }
+
+    /// The new owner is only looked up.
+    fn visit_alter_owner_stmt(&mut self, node: &AlterOwnerStmt) -> ControlFlow<()> {
+        node.target().accept(self)?;
+        self.with_access(AccessMode::Read, |visitor| node.new_owner().accept(visitor))
+    }
+
+    fn visit_drop_target(&mut self, node: &DropTarget) -> ControlFlow<()> {
+        match node {
+            DropTarget::PropertyGraph(names) => {
+                for name in names {
+                    self.push_qualified(ObjectKind::PropertyGraph, name.clone());
+                }
+            },
+        }
+        Continue(())
+    }
+
+    fn visit_privilege_target(&mut self, node: &PrivilegeTarget) -> ControlFlow<()> {
+        use PrivilegeTarget as T;
+
+        let relations = |kind, names: &[RelationName], visitor: &mut Self| {
+            names.iter().try_for_each(|name| {
+                visitor.next_relation = Some(kind);
+                name.accept(visitor)
+            })
+        };
+
+        match node {
+            T::AllFunctionsInSchema(names)
+            | T::AllProceduresInSchema(names)
+            | T::AllRoutinesInSchema(names)
+            | T::AllSequencesInSchema(names)
+            | T::AllTablesInSchema(names)
+            | T::Schema(names) => self.push_names(ObjectKind::Schema, names),
+            T::Database(names) => self.push_names(ObjectKind::Database, names),
+            T::ForeignDataWrapper(names) => self.push_names(ObjectKind::ForeignDataWrapper, names),
+            T::ForeignServer(names) => self.push_names(ObjectKind::ForeignServer, names),
+            T::Language(names) => self.push_names(ObjectKind::Language, names),
+            T::Tablespace(names) => self.push_names(ObjectKind::Tablespace, names),
+            T::Domain(names) | T::Type(names) => {
+                for name in names {
+                    self.push_qualified(ObjectKind::Type, name.clone());
+                }
+            },
+            T::ParameterAcl(names) => {
+                for name in names {
+                    self.push(ObjectKind::Parameter, name.clone());
+                }
+            },
+            T::Function(functions) | T::Procedure(functions) | T::Routine(functions) => {
+                return functions.accept(self)
+            },
+            T::PropertyGraph(names) => return relations(ObjectKind::PropertyGraph, names, self),
+            T::Sequence(names) => return relations(ObjectKind::Sequence, names, self),
+            T::Table(names) => return relations(ObjectKind::Relation, names, self),
+            // Large objects don't have names.
+            T::LargeObject(_) => {},
+        }
+
+        Continue(())
+    }
+}
+
+fn relation_name(node: &RelationName) -> QualifiedName {
+    node.catalog()
+        .into_iter()
+        .chain(node.schema().map(SchemaName::name))
+        .chain(iter::once(node.name()))
+        .map(Str::from_cloning)
+        .collect()
+}
+
+/// The name of a type, where the builtin types that have their own syntax are named like `SystemTypeName()` names them.
+///
+/// Types that are only known by their OID don't have a name.
+fn type_name(node: &TypeName) -> Option<QualifiedName> {
+
+    let name = match node {
+        TypeName::Generic { name, .. } => return Some(name.clone()),
+        TypeName::Oid(_) => return None,
+        TypeName::Json => "json",
+        TypeName::Bool => "bool",
+        TypeName::Int2 => "int2",
+        TypeName::Int4 => "int4",
+        TypeName::Int8 => "int8",
+        TypeName::Float4 => "float4",
+        TypeName::Float8 => "float8",
+        TypeName::Numeric(_) => "numeric",
+        TypeName::Bpchar { .. } => "bpchar",
+        TypeName::Varchar { .. } => "varchar",
+        TypeName::Bit(_) => "bit",
+        TypeName::Varbit(_) => "varbit",
+        TypeName::Time { .. } => "time",
+        TypeName::TimeTz { .. } => "timetz",
+        TypeName::Timestamp { .. } => "timestamp",
+        TypeName::TimestampTz { .. } => "timestamptz",
+        TypeName::Interval(_) => "interval",
+    };
+
+    Some(vec!["pg_catalog".into(), name.into()])
+}
+
+/// The kind of the object that a definition's argument names, by the definition's key.
+fn definition_kind(key: &str) -> Option<ObjectKind> {
+
+    let kind = match key {
+        // Aggregates
+        "sfunc" | "sfunc1" | "finalfunc" | "combinefunc" | "serialfunc" | "deserialfunc"
+        | "msfunc" | "minvfunc" | "mfinalfunc"
+        // Operators
+        | "function" | "procedure" | "restrict" | "join"
+        // Base types
+        | "input" | "output" | "receive" | "send" | "typmod_in" | "typmod_out" | "analyze" | "subscript"
+        // Ranges
+        | "canonical" | "subtype_diff"
+        // Text search parsers and templates
+        | "start" | "gettoken" | "end" | "headline" | "lextypes" | "init" | "lexize" => ObjectKind::Function,
+        "basetype" | "stype" | "stype1" | "mstype" | "leftarg" | "rightarg"
+        | "element" | "like" | "subtype" | "multirange_type_name" => ObjectKind::Type,
+        "collation" => ObjectKind::Collation,
+        "subtype_opclass" => ObjectKind::OperatorClass,
+        "copy" => ObjectKind::TextSearchConfiguration,
+        "parser" => ObjectKind::TextSearchParser,
+        "template" => ObjectKind::TextSearchTemplate,
+        _ => return None,
+    };
+
+    Some(kind)
+}
+
+/// The sequence of `nextval('s')` and `setval('s', 1)`, which write it, or of `currval('s')`.
+///
+/// Only string constants are known, e.g., not `nextval($1)`.
+fn sequence_argument(func: &FuncCall) -> Option<(AccessMode, QualifiedName)> {
+
+    let name = match func.name() {
+        [name] => name,
+        [schema, name] if schema.as_ref() == "pg_catalog" => name,
+        _ => return None,
+    };
+
+    let access = match name.as_ref() {
+        "nextval" | "setval" => AccessMode::Write,
+        "currval" => AccessMode::Read,
+        _ => return None,
+    };
+
+    let FuncArgsKind::All { args, .. } = func.args() else { return None };
+    let arg = args.first()?.source();
+    if arg.name().is_some() {
+        return None
+    }
+
+    // E.g.: `nextval('s'::regclass)`
+    let arg = match arg.value().kind() {
+        ExprKind::Typecast(typecast) => typecast.arg(),
+        _ => arg.value(),
+    };
+
+    match arg.kind() {
+        ExprKind::StringConst(name) => regclass_name(name).map(|name| (access, name)),
+        _ => None,
+    }
+}
+
+/// Splits the text of a `regclass` like `stringToQualifiedNameList()`:
+/// names are separated by `.`, and are lowercased unless they're quoted.
+fn regclass_name(text: &str) -> Option<QualifiedName> {
+
+    let mut names = Vec::new();
+    let mut chars = text.chars().peekable();
+
+    loop {
+        let mut name = String::new();
+
+        if chars.next_if_eq(&'"').is_some() {
+            loop {
+                match chars.next()? {
+                    '"' if chars.next_if_eq(&'"').is_some() => name.push('"'),
+                    '"' => break,
+                    c => name.push(c),
+                }
+            }
+        }
+        else {
+            while let Some(c) = chars.next_if(|c| *c != '.') {
+                name.push(c.to_ascii_lowercase());
+            }
+            if name.is_empty() {
+                return None
+            }
+        }
+
+        names.push(name.into());
+
+        match chars.next() {
+            None => return Some(names),
+            Some('.') => {},
+            Some(_) => return None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pg_ast::DropBehavior;
+    use pg_ast::DropStmt;
+    use pg_ast::Presence;
+    use pg_parser::Parser;
+    use pg_parser_core::ParserConfig;
+    use test_case::test_case;
+
+    fn parse(source: &str) -> RawStmt {
+        Parser::new(source, ParserConfig::default())
+            .parse()
+            .result
+            .remove(0)
+            .unwrap()
+            .into_source()
+    }
+
+    fn reference(kind: ObjectKind, name: &[&'static str], access: AccessMode) -> ObjectReference {
+        ObjectReference::new(kind, name.iter().copied().map(Str::from).collect(), access)
+    }
+
+    #[test_case("select 1", vec![])]
+    #[test_case(
+        "select a from s.t, u where f(u.b) > 0 and a in (select c from t)",
+        vec![
+            reference(ObjectKind::Schema, &["s"], AccessMode::Read),
+            reference(ObjectKind::Relation, &["s", "t"], AccessMode::Read),
+            reference(ObjectKind::Relation, &["u"], AccessMode::Read),
+            reference(ObjectKind::Function, &["f"], AccessMode::Read),
+            reference(ObjectKind::Relation, &["t"], AccessMode::Read),
+        ]
+    )]
+    #[test_case(
+        "select '1'::integer, a::my.typ, count(*) from t",
+        vec![
+            reference(ObjectKind::Schema, &["pg_catalog"], AccessMode::Read),
+            reference(ObjectKind::Type, &["pg_catalog", "int4"], AccessMode::Read),
+            reference(ObjectKind::Schema, &["my"], AccessMode::Read),
+            reference(ObjectKind::Type, &["my", "typ"], AccessMode::Read),
+            reference(ObjectKind::Function, &["count"], AccessMode::Read),
+            reference(ObjectKind::Relation, &["t"], AccessMode::Read),
+        ]
+    )]
+    #[test_case(
+        "alter function s.f(typ) owner to r",
+        vec![
+            reference(ObjectKind::Schema, &["s"], AccessMode::Read),
+            reference(ObjectKind::Function, &["s", "f"], AccessMode::Ddl),
+            reference(ObjectKind::Type, &["typ"], AccessMode::Read),
+            reference(ObjectKind::Role, &["r"], AccessMode::Read),
+        ]
+    )]
+    #[test_case(
+        "create sequence s.q as bigint owned by t.a",
+        vec![
+            reference(ObjectKind::Schema, &["s"], AccessMode::Read),
+            reference(ObjectKind::Sequence, &["s", "q"], AccessMode::Ddl),
+            reference(ObjectKind::Schema, &["pg_catalog"], AccessMode::Read),
+            reference(ObjectKind::Type, &["pg_catalog", "int8"], AccessMode::Read),
+            reference(ObjectKind::Relation, &["t"], AccessMode::Read),
+        ]
+    )]
+    #[test_case(
+        "alter sequence q owned by s.t.a",
+        vec![
+            reference(ObjectKind::Sequence, &["q"], AccessMode::Ddl),
+            reference(ObjectKind::Schema, &["s"], AccessMode::Read),
+            reference(ObjectKind::Relation, &["s", "t"], AccessMode::Read),
+        ]
+    )]
+    #[test_case("alter sequence q owned by none", vec![reference(ObjectKind::Sequence, &["q"], AccessMode::Ddl)])]
+    #[test_case(
+        "select nextval('s.q'), setval('\"Q\"', 1), currval('Q')",
+        vec![
+            reference(ObjectKind::Function, &["nextval"], AccessMode::Read),
+            reference(ObjectKind::Schema, &["s"], AccessMode::Read),
+            reference(ObjectKind::Sequence, &["s", "q"], AccessMode::Write),
+            reference(ObjectKind::Function, &["setval"], AccessMode::Read),
+            reference(ObjectKind::Sequence, &["Q"], AccessMode::Write),
+            reference(ObjectKind::Function, &["currval"], AccessMode::Read),
+            reference(ObjectKind::Sequence, &["q"], AccessMode::Read),
+        ]
+    )]
+    #[test_case(
+        "select pg_catalog.nextval('q'::regclass), nextval($1)",
+        vec![
+            reference(ObjectKind::Schema, &["pg_catalog"], AccessMode::Read),
+            reference(ObjectKind::Function, &["pg_catalog", "nextval"], AccessMode::Read),
+            reference(ObjectKind::Sequence, &["q"], AccessMode::Write),
+            reference(ObjectKind::Type, &["regclass"], AccessMode::Read),
+            reference(ObjectKind::Function, &["nextval"], AccessMode::Read),
+        ]
+    )]
+    #[test_case("create schema s authorization r", vec![
+        reference(ObjectKind::Schema, &["s"], AccessMode::Ddl),
+        reference(ObjectKind::Role, &["r"], AccessMode::Ddl),
+    ])]
+    #[test_case("security label on schema s is 'x'", vec![reference(ObjectKind::Schema, &["s"], AccessMode::Ddl)])]
+    #[test_case("comment on schema s is 'x'", vec![reference(ObjectKind::Schema, &["s"], AccessMode::Ddl)])]
+    #[test_case(
+        "alter function f() set schema s",
+        vec![
+            reference(ObjectKind::Function, &["f"], AccessMode::Ddl),
+            reference(ObjectKind::Schema, &["s"], AccessMode::Read),
+        ]
+    )]
+    #[test_case("alter role r with login", vec![reference(ObjectKind::Role, &["r"], AccessMode::Ddl)])]
+    #[test_case("alter role current_user with login", vec![])]
+    #[test_case(
+        "create type s.t (input = s.t_in, output = t_out, element = float4, category = 'x', like = s.u)",
+        vec![
+            reference(ObjectKind::Schema, &["s"], AccessMode::Read),
+            reference(ObjectKind::Type, &["s", "t"], AccessMode::Ddl),
+            reference(ObjectKind::Function, &["s", "t_in"], AccessMode::Read),
+            reference(ObjectKind::Function, &["t_out"], AccessMode::Read),
+            reference(ObjectKind::Type, &["float4"], AccessMode::Read),
+            reference(ObjectKind::Type, &["s", "u"], AccessMode::Read),
+        ]
+    )]
+    #[test_case("create type t as enum ('a', 'b')", vec![reference(ObjectKind::Type, &["t"], AccessMode::Ddl)])]
+    #[test_case(
+        "create domain s.d as int collate \"C\" check (value > 0)",
+        vec![
+            reference(ObjectKind::Schema, &["s"], AccessMode::Read),
+            reference(ObjectKind::Type, &["s", "d"], AccessMode::Ddl),
+            reference(ObjectKind::Schema, &["pg_catalog"], AccessMode::Read),
+            reference(ObjectKind::Type, &["pg_catalog", "int4"], AccessMode::Read),
+            reference(ObjectKind::Collation, &["C"], AccessMode::Read),
+        ]
+    )]
+    #[test_case(
+        "create type r as range (subtype = float8, subtype_opclass = s.o, collation = c, canonical = f, subtype_diff = g, multirange_type_name = m)",
+        vec![
+            reference(ObjectKind::Type, &["r"], AccessMode::Ddl),
+            reference(ObjectKind::Type, &["float8"], AccessMode::Read),
+            reference(ObjectKind::Schema, &["s"], AccessMode::Read),
+            reference(ObjectKind::OperatorClass, &["s", "o"], AccessMode::Read),
+            reference(ObjectKind::Collation, &["c"], AccessMode::Read),
+            reference(ObjectKind::Function, &["f"], AccessMode::Read),
+            reference(ObjectKind::Function, &["g"], AccessMode::Read),
+            reference(ObjectKind::Type, &["m"], AccessMode::Ddl),
+        ]
+    )]
+    #[test_case(
+        "alter type t add attribute a text collate c, alter attribute b type int",
+        vec![
+            reference(ObjectKind::Type, &["t"], AccessMode::Ddl),
+            reference(ObjectKind::Type, &["text"], AccessMode::Read),
+            reference(ObjectKind::Collation, &["c"], AccessMode::Read),
+            reference(ObjectKind::Schema, &["pg_catalog"], AccessMode::Read),
+            reference(ObjectKind::Type, &["pg_catalog", "int4"], AccessMode::Read),
+        ]
+    )]
+    #[test_case("alter type t add value 'c'", vec![reference(ObjectKind::Type, &["t"], AccessMode::Ddl)])]
+    #[test_case(
+        "create extension e schema s",
+        vec![
+            reference(ObjectKind::Extension, &["e"], AccessMode::Ddl),
+            reference(ObjectKind::Schema, &["s"], AccessMode::Read),
+        ]
+    )]
+    #[test_case(
+        "create collation s.c (locale = 'en_US')",
+        vec![
+            reference(ObjectKind::Schema, &["s"], AccessMode::Read),
+            reference(ObjectKind::Collation, &["s", "c"], AccessMode::Ddl),
+        ]
+    )]
+    #[test_case(
+        "create collation c from s.d",
+        vec![
+            reference(ObjectKind::Collation, &["c"], AccessMode::Ddl),
+            reference(ObjectKind::Schema, &["s"], AccessMode::Read),
+            reference(ObjectKind::Collation, &["s", "d"], AccessMode::Read),
+        ]
+    )]
+    #[test_case(
+        "create language l handler h inline i validator v",
+        vec![
+            reference(ObjectKind::Language, &["l"], AccessMode::Ddl),
+            reference(ObjectKind::Function, &["h"], AccessMode::Read),
+            reference(ObjectKind::Function, &["i"], AccessMode::Read),
+            reference(ObjectKind::Function, &["v"], AccessMode::Read),
+        ]
+    )]
+    #[test_case("create tablespace ts location '/x'", vec![reference(ObjectKind::Tablespace, &["ts"], AccessMode::Ddl)])]
+    #[test_case(
+        "create operator class s.c default for type int using btree family f as operator 1 <, function 1 s.f(int, int), storage text",
+        vec![
+            reference(ObjectKind::Schema, &["s"], AccessMode::Read),
+            reference(ObjectKind::OperatorClass, &["s", "c"], AccessMode::Ddl),
+            reference(ObjectKind::OperatorFamily, &["f"], AccessMode::Read),
+            reference(ObjectKind::Schema, &["pg_catalog"], AccessMode::Read),
+            reference(ObjectKind::Type, &["pg_catalog", "int4"], AccessMode::Read),
+            reference(ObjectKind::Function, &["s", "f"], AccessMode::Read),
+            reference(ObjectKind::Type, &["text"], AccessMode::Read),
+        ]
+    )]
+    #[test_case(
+        "create operator family s.f using btree",
+        vec![
+            reference(ObjectKind::Schema, &["s"], AccessMode::Read),
+            reference(ObjectKind::OperatorFamily, &["s", "f"], AccessMode::Ddl),
+        ]
+    )]
+    #[test_case(
+        "alter operator family f using btree add function 1 g(int, int)",
+        vec![
+            reference(ObjectKind::OperatorFamily, &["f"], AccessMode::Ddl),
+            reference(ObjectKind::Function, &["g"], AccessMode::Read),
+            reference(ObjectKind::Schema, &["pg_catalog"], AccessMode::Read),
+            reference(ObjectKind::Type, &["pg_catalog", "int4"], AccessMode::Read),
+        ]
+    )]
+    #[test_case(
+        "create text search configuration s.c (parser = p)",
+        vec![
+            reference(ObjectKind::Schema, &["s"], AccessMode::Read),
+            reference(ObjectKind::TextSearchConfiguration, &["s", "c"], AccessMode::Ddl),
+            reference(ObjectKind::TextSearchParser, &["p"], AccessMode::Read),
+        ]
+    )]
+    #[test_case(
+        "create text search configuration c (copy = s.d)",
+        vec![
+            reference(ObjectKind::TextSearchConfiguration, &["c"], AccessMode::Ddl),
+            reference(ObjectKind::Schema, &["s"], AccessMode::Read),
+            reference(ObjectKind::TextSearchConfiguration, &["s", "d"], AccessMode::Read),
+        ]
+    )]
+    #[test_case(
+        "create text search dictionary s.d (template = t, stopwords = 'english')",
+        vec![
+            reference(ObjectKind::Schema, &["s"], AccessMode::Read),
+            reference(ObjectKind::TextSearchDictionary, &["s", "d"], AccessMode::Ddl),
+            reference(ObjectKind::TextSearchTemplate, &["t"], AccessMode::Read),
+        ]
+    )]
+    #[test_case(
+        "create text search parser p (start = f1, gettoken = f2, end = f3, lextypes = f4)",
+        vec![
+            reference(ObjectKind::TextSearchParser, &["p"], AccessMode::Ddl),
+            reference(ObjectKind::Function, &["f1"], AccessMode::Read),
+            reference(ObjectKind::Function, &["f2"], AccessMode::Read),
+            reference(ObjectKind::Function, &["f3"], AccessMode::Read),
+            reference(ObjectKind::Function, &["f4"], AccessMode::Read),
+        ]
+    )]
+    #[test_case(
+        "create text search template t (init = f1, lexize = f2)",
+        vec![
+            reference(ObjectKind::TextSearchTemplate, &["t"], AccessMode::Ddl),
+            reference(ObjectKind::Function, &["f1"], AccessMode::Read),
+            reference(ObjectKind::Function, &["f2"], AccessMode::Read),
+        ]
+    )]
+    #[test_case(
+        "create statistics s.st on a, b from t",
+        vec![
+            reference(ObjectKind::Schema, &["s"], AccessMode::Read),
+            reference(ObjectKind::Statistics, &["s", "st"], AccessMode::Ddl),
+            reference(ObjectKind::Relation, &["t"], AccessMode::Ddl),
+        ]
+    )]
+    #[test_case(
+        "create rule r as on insert to s.t do instead nothing",
+        vec![
+            reference(ObjectKind::Rule, &["s", "t", "r"], AccessMode::Ddl),
+            reference(ObjectKind::Schema, &["s"], AccessMode::Read),
+            reference(ObjectKind::Relation, &["s", "t"], AccessMode::Ddl),
+        ]
+    )]
+    #[test_case(
+        "create transform for int language l (from sql with function f(internal), to sql with function g(internal))",
+        vec![
+            reference(ObjectKind::Transform, &["pg_catalog", "int4"], AccessMode::Ddl),
+            reference(ObjectKind::Schema, &["pg_catalog"], AccessMode::Read),
+            reference(ObjectKind::Type, &["pg_catalog", "int4"], AccessMode::Read),
+            reference(ObjectKind::Language, &["l"], AccessMode::Read),
+            reference(ObjectKind::Function, &["f"], AccessMode::Read),
+            reference(ObjectKind::Type, &["internal"], AccessMode::Read),
+            reference(ObjectKind::Function, &["g"], AccessMode::Read),
+        ]
+    )]
+    #[test_case(
+        "create aggregate s.a(int) (sfunc = f, stype = int8, finalfunc = g, initcond = '0')",
+        vec![
+            reference(ObjectKind::Schema, &["s"], AccessMode::Read),
+            reference(ObjectKind::Function, &["s", "a"], AccessMode::Ddl),
+            reference(ObjectKind::Schema, &["pg_catalog"], AccessMode::Read),
+            reference(ObjectKind::Type, &["pg_catalog", "int4"], AccessMode::Read),
+            reference(ObjectKind::Function, &["f"], AccessMode::Read),
+            reference(ObjectKind::Type, &["int8"], AccessMode::Read),
+            reference(ObjectKind::Function, &["g"], AccessMode::Read),
+        ]
+    )]
+    #[test_case(
+        "create operator === (leftarg = int, rightarg = s.t, function = f, commutator = ===)",
+        vec![
+            reference(ObjectKind::Schema, &["pg_catalog"], AccessMode::Read),
+            reference(ObjectKind::Type, &["pg_catalog", "int4"], AccessMode::Read),
+            reference(ObjectKind::Schema, &["s"], AccessMode::Read),
+            reference(ObjectKind::Type, &["s", "t"], AccessMode::Read),
+            reference(ObjectKind::Function, &["f"], AccessMode::Read),
+        ]
+    )]
+    fn test_references(source: &str, expected: Vec<ObjectReference>) {
+        assert_eq!(expected, references(&parse(source)));
+    }
+
+    #[test]
+    fn test_drop() {
+        let stmt = DropStmt::new(
+            DropTarget::PropertyGraph(vec![vec!["g".into()]]),
+            Presence::Ignore,
+            DropBehavior::Restrict
+        );
+
+        assert_eq!(
+            vec![reference(ObjectKind::PropertyGraph, &["g"], AccessMode::Drop)],
+            references(&RawStmt::DropStmt(stmt))
+        );
+    }
+
+    #[test_case(Some("s.q"), Some(vec!["s", "q"]))]
+    #[test_case(Some("S.\"Q.\"\"x\""), Some(vec!["s", "Q.\"x"]))]
+    #[test_case(Some("s..q"), None)]
+    #[test_case(Some("\"q"), None)]
+    #[test_case(Some("\"q\"x"), None)]
+    fn test_regclass_name(text: Option<&'static str>, expected: Option<Vec<&'static str>>) {
+        let expected = expected.map(|names| names.into_iter().map(Str::from).collect::<QualifiedName>());
+        assert_eq!(expected, text.and_then(regclass_name));
+    }
+
+    #[test]
+    fn test_relation_kind_scope() {
+        let mut visitor = References::new();
+        let _ = visitor.walk_with_relation(ObjectKind::Sequence, &DropTarget::PropertyGraph(vec![]));
+        let _ = RelationName::new("t").accept(&mut visitor);
+
+        assert_eq!(
+            vec![reference(ObjectKind::Relation, &["t"], AccessMode::Read)],
+            visitor.references
+        );
+    }
+
+    #[test]
+    fn test_privilege_target() {
+        let target = PrivilegeTarget::Table(vec![RelationName::new("t").with_schema("s")]);
+
+        let mut visitor = References::new();
+        let _ = target.accept(&mut visitor);
+
+        assert_eq!(
+            vec![
+                reference(ObjectKind::Schema, &["s"], AccessMode::Read),
+                reference(ObjectKind::Relation, &["s", "t"], AccessMode::Read),
+            ],
+            visitor.references
+        );
+    }
+}
+
+use crate::classify;
+use crate::AccessMode;
+use crate::LogStmtLevel;
+use crate::ObjectKind;
+use crate::ObjectReference;
+use core::iter;
+use core::mem;
+use core::ops::ControlFlow;
+use core::ops::ControlFlow::Continue;
+use pg_ast::Accept;
+use pg_ast::AggregateWithArgs;
+use pg_ast::AlterCompositeTypeStmt;
+use pg_ast::AlterDomainStmt;
+use pg_ast::AlterEnumStmt;
+use pg_ast::AlterExtensionContentsStmt;
+use pg_ast::AlterExtensionContentsTarget;
+use pg_ast::AlterExtensionStmt;
+use pg_ast::AlterObjectSchemaStmt;
+use pg_ast::AlterOpFamilyStmt;
+use pg_ast::AlterOwnerStmt;
+use pg_ast::AlterOwnerTarget;
+use pg_ast::AlterSeqStmt;
+use pg_ast::AlterTypeCmd;
+use pg_ast::CollateExpr;
+use pg_ast::CollationDefinition;
+use pg_ast::CommentTarget;
+use pg_ast::CompositeTypeStmt;
+use pg_ast::CreateDomainStmt;
+use pg_ast::CreateEnumStmt;
+use pg_ast::CreateExtensionOption;
+use pg_ast::CreateExtensionStmt;
+use pg_ast::CreateOpClassStmt;
+use pg_ast::CreateOpFamilyStmt;
+use pg_ast::CreatePLangStmt;
+use pg_ast::CreatePropGraphStmt;
+use pg_ast::CreateRangeStmt;
+use pg_ast::CreateSchemaStmt;
+use pg_ast::CreateSeqStmt;
+use pg_ast::CreateStatsStmt;
+use pg_ast::CreateTableSpaceStmt;
+use pg_ast::CreateTransformStmt;
+use pg_ast::DefineStmt;
+use pg_ast::DefinitionArg;
+use pg_ast::DefinitionElem;
+use pg_ast::DropTarget;
+use pg_ast::ExprKind;
+use pg_ast::ExprNode;
+use pg_ast::FuncArgsKind;
+use pg_ast::FuncCall;
+use pg_ast::FuncType;
+use pg_ast::FunctionWithArgs;
+use pg_ast::GraphTable;
+use pg_ast::OpClassItem;
+use pg_ast::OpClassPurpose;
+use pg_ast::PLangHandler;
+use pg_ast::PrivilegeTarget;
+use pg_ast::RawStmt;
+use pg_ast::RelationName;
+use pg_ast::RenameTarget;
+use pg_ast::RoleSpec;
+use pg_ast::RuleStmt;
+use pg_ast::SchemaName;
+use pg_ast::SecurityLabelTarget;
+use pg_ast::SelectStmt;
+use pg_ast::SeqOptElem;
+use pg_ast::SimpleColumnDefinition;
+use pg_ast::Transform;
+use pg_ast::Type;
+use pg_ast::TypeName;
+use pg_ast::Visit;
+use pg_ast::Walk;
+use pg_basics::QualifiedName;
+use pg_basics::Str;
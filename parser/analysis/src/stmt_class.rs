@@ -0,0 +1,255 @@
+/// What kind of statement a [`RawStmt`] is, e.g., to route read-only statements to replicas,
+/// or to reject the ones that can't run in a transaction-pooled session.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct StmtClass {
+    command_tag: &'static str,
+    read_only_flags: ReadOnlyFlags,
+    log_level: LogStmtLevel,
+    ok_in_transaction_block: bool,
+    privileged: bool,
+}
+
+impl StmtClass {
+    pub fn command_tag(&self) -> &'static str {
+        self.command_tag
+    }
+
+    pub fn read_only_flags(&self) -> ReadOnlyFlags {
+        self.read_only_flags
+    }
+
+    /// Whether it's allowed in a `READ ONLY` transaction.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only_flags.contains(ReadOnlyFlags::OkInReadOnlyTxn)
+    }
+
+    /// Whether it's allowed on a standby, e.g., a replica.
+    pub fn is_ok_in_recovery(&self) -> bool {
+        self.read_only_flags.contains(ReadOnlyFlags::OkInRecovery)
+    }
+
+    pub fn log_level(&self) -> LogStmtLevel {
+        self.log_level
+    }
+
+    /// Whether it can run inside a transaction block,
+    /// i.e., it isn't rejected by `PreventInTransactionBlock()`.
+    pub fn is_ok_in_transaction_block(&self) -> bool {
+        self.ok_in_transaction_block
+    }
+
+    /// Whether it needs superuser, or a role attribute or predefined role that's usually only given to admins,
+    /// e.g., `CREATEDB`, `CREATEROLE` or `pg_checkpoint`.
+    pub fn is_privileged(&self) -> bool {
+        self.privileged
+    }
+}
+
+pub fn classify(stmt: &RawStmt) -> StmtClass {
+    StmtClass {
+        command_tag: command_tag(stmt),
+        read_only_flags: read_only_flags(stmt),
+        log_level: log_level(stmt),
+        ok_in_transaction_block: is_ok_in_transaction_block(stmt),
+        privileged: is_privileged(stmt),
+    }
+}
+
+/// See `ClassifyUtilityCommandAsReadOnly()`.
+fn read_only_flags(stmt: &RawStmt) -> ReadOnlyFlags {
+    match stmt {
+        // Queries are checked by the executor, and a `SELECT` doesn't write anything.
+        RawStmt::SelectStmt(_)
+        | RawStmt::VariableShowStmt(_)
+        // It only writes the config file, so it doesn't change anything that's replicated.
+        | RawStmt::AlterSystemStmt(_)
+        // During recovery, it's a restartpoint.
+        | RawStmt::CheckPointStmt(_) => ReadOnlyFlags::StrictlyReadOnly,
+
+        RawStmt::TransactionStmt(
+            TransactionStmt::Prepare(_)
+            | TransactionStmt::CommitPrepared(_)
+            | TransactionStmt::RollbackPrepared(_)
+        )
+        | RawStmt::PrepareTransactionStmt(_) => ReadOnlyFlags::OkInReadOnlyTxn,
+        RawStmt::TransactionStmt(_) => ReadOnlyFlags::StrictlyReadOnly,
+
+        // These only change the state of the session.
+        RawStmt::ClosePortalStmt(_)
+        | RawStmt::ConstraintsSetStmt(_)
+        | RawStmt::DeallocateStmt(_)
+        | RawStmt::DiscardStmt(_)
+        | RawStmt::LoadStmt(_)
+        | RawStmt::PrepareStmt(_)
+        | RawStmt::UnlistenStmt(_)
+        | RawStmt::VariableResetStmt(_)
+        | RawStmt::VariableSetStmt(_) => ReadOnlyFlags::OkInReadOnlyTxn | ReadOnlyFlags::OkInRecovery,
+
+        // Notifications aren't sent during recovery.
+        RawStmt::ListenStmt(_)
+        | RawStmt::NotifyStmt(_) => ReadOnlyFlags::OkInReadOnlyTxn,
+
+        _ => ReadOnlyFlags::None,
+    }
+}
+
+/// See `GetCommandLogLevel()`.
+fn log_level(stmt: &RawStmt) -> LogStmtLevel {
+    match stmt {
+        RawStmt::PrepareStmt(stmt) => log_level(stmt.query()),
+
+        RawStmt::SelectStmt(_)
+        | RawStmt::CheckPointStmt(_)
+        | RawStmt::ClosePortalStmt(_)
+        | RawStmt::ConstraintsSetStmt(_)
+        | RawStmt::DeallocateStmt(_)
+        | RawStmt::DiscardStmt(_)
+        | RawStmt::ListenStmt(_)
+        | RawStmt::LoadStmt(_)
+        | RawStmt::NotifyStmt(_)
+        | RawStmt::PrepareTransactionStmt(_)
+        | RawStmt::TransactionStmt(_)
+        | RawStmt::UnlistenStmt(_)
+        | RawStmt::VariableResetStmt(_)
+        | RawStmt::VariableSetStmt(_)
+        | RawStmt::VariableShowStmt(_) => LogStmtLevel::All,
+
+        _ => LogStmtLevel::Ddl,
+    }
+}
+
+/// The statements that call `PreventInTransactionBlock()`.
+fn is_ok_in_transaction_block(stmt: &RawStmt) -> bool {
+    match stmt {
+        RawStmt::AlterDatabaseStmt(stmt) => {
+            !stmt.options()
+                .iter()
+                .any(|option| matches!(option.kind(), AlterdbOptionKind::Tablespace))
+        },
+        RawStmt::AlterSystemStmt(_)
+        | RawStmt::CreateDatabaseStmt(_)
+        | RawStmt::CreateTableSpaceStmt(_)
+        | RawStmt::DiscardStmt(DiscardStmt::All)
+        | RawStmt::TransactionStmt(TransactionStmt::CommitPrepared(_) | TransactionStmt::RollbackPrepared(_)) => false,
+        _ => true,
+    }
+}
+
+fn is_privileged(stmt: &RawStmt) -> bool {
+    match stmt {
+        RawStmt::RenameStmt(stmt) => matches!(stmt.target(), RenameTarget::Role(_)),
+
+        RawStmt::DefineStmt(stmt) => matches!(
+            stmt,
+            DefineStmt::TextSearchParser { .. } | DefineStmt::TextSearchTemplate { .. }
+        ),
+
+        RawStmt::AlterEventTrigStmt(_)
+        | RawStmt::AlterOpFamilyStmt(_)
+        | RawStmt::AlterRoleSetStmt(_)
+        | RawStmt::AlterRoleStmt(_)
+        | RawStmt::AlterSystemStmt(_)
+        | RawStmt::CheckPointStmt(_)
+        | RawStmt::CreateAccessMethodStmt(_)
+        | RawStmt::CreateDatabaseStmt(_)
+        | RawStmt::CreateOpClassStmt(_)
+        | RawStmt::CreateOpFamilyStmt(_)
+        // Even trusted languages need the database owner, since PG 13.
+        | RawStmt::CreatePLangStmt(_)
+        | RawStmt::CreateRoleStmt(_)
+        | RawStmt::CreateTableSpaceStmt(_)
+        // Unless the library is in `$libdir/plugins`.
+        | RawStmt::LoadStmt(_) => true,
+
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pg_ast::PrepareStmt;
+    use pg_parser::Parser;
+    use pg_parser_core::ParserConfig;
+    use test_case::test_case;
+
+    fn parse(source: &str) -> RawStmt {
+        Parser::new(source, ParserConfig::default())
+            .parse()
+            .result
+            .remove(0)
+            .unwrap()
+            .into_source()
+    }
+
+    #[test_case("select 1", ReadOnlyFlags::StrictlyReadOnly)]
+    #[test_case("show a", ReadOnlyFlags::StrictlyReadOnly)]
+    #[test_case("begin", ReadOnlyFlags::StrictlyReadOnly)]
+    #[test_case("commit prepared 'x'", ReadOnlyFlags::OkInReadOnlyTxn)]
+    #[test_case("set a = 1", ReadOnlyFlags::OkInReadOnlyTxn | ReadOnlyFlags::OkInRecovery)]
+    #[test_case("notify c", ReadOnlyFlags::OkInReadOnlyTxn)]
+    #[test_case("create role r", ReadOnlyFlags::None)]
+    #[test_case("comment on schema s is 'x'", ReadOnlyFlags::None)]
+    fn test_read_only_flags(source: &str, expected: ReadOnlyFlags) {
+        assert_eq!(expected, classify(&parse(source)).read_only_flags());
+    }
+
+    #[test_case("select 1", LogStmtLevel::All)]
+    #[test_case("create schema s", LogStmtLevel::Ddl)]
+    #[test_case("alter system set a = 1", LogStmtLevel::Ddl)]
+    fn test_log_level(source: &str, expected: LogStmtLevel) {
+        assert_eq!(expected, classify(&parse(source)).log_level());
+    }
+
+    #[test_case("select 1", true)]
+    #[test_case("create database d owner r", false)]
+    #[test_case("create tablespace t location '/x'", false)]
+    #[test_case("alter database d set tablespace t", false)]
+    #[test_case("alter database d connection limit 1", true)]
+    #[test_case("alter system reset all", false)]
+    #[test_case("discard all", false)]
+    #[test_case("discard plans", true)]
+    #[test_case("rollback prepared 'x'", false)]
+    fn test_is_ok_in_transaction_block(source: &str, expected: bool) {
+        assert_eq!(expected, classify(&parse(source)).is_ok_in_transaction_block());
+    }
+
+    #[test_case("select 1", false)]
+    #[test_case("create role r", true)]
+    #[test_case("alter role r rename to s", true)]
+    #[test_case("alter collation c rename to d", false)]
+    #[test_case("checkpoint", true)]
+    #[test_case("create language l", true)]
+    #[test_case("create trusted language l", true)]
+    fn test_is_privileged(source: &str, expected: bool) {
+        assert_eq!(expected, classify(&parse(source)).is_privileged());
+    }
+
+    #[test]
+    fn test_prepare_log_level() {
+        let stmt = PrepareStmt::new("p".into(), Vec::new(), parse("create schema s"));
+        let actual = classify(&RawStmt::PrepareStmt(Box::new(stmt)));
+
+        assert_eq!("PREPARE", actual.command_tag());
+        assert_eq!(LogStmtLevel::Ddl, actual.log_level());
+    }
+
+    #[test]
+    fn test_classify() {
+        let actual = classify(&parse("start transaction"));
+
+        assert_eq!("START TRANSACTION", actual.command_tag());
+        assert!(actual.is_read_only());
+        assert!(actual.is_ok_in_recovery());
+    }
+}
+
+use crate::command_tag;
+use crate::LogStmtLevel;
+use crate::ReadOnlyFlags;
+use pg_ast::AlterdbOptionKind;
+use pg_ast::DefineStmt;
+use pg_ast::DiscardStmt;
+use pg_ast::RawStmt;
+use pg_ast::RenameTarget;
+use pg_ast::TransactionStmt;
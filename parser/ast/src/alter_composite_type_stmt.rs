@@ -0,0 +1,48 @@
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlterCompositeTypeStmt {
+    type_name: QualifiedName,
+    actions: Vec<AlterTypeCmd>,
+}
+
+impl AlterCompositeTypeStmt {
+    pub fn new(type_name: QualifiedName, actions: Vec<AlterTypeCmd>) -> Self {
+        Self { type_name, actions }
+    }
+
+    pub fn type_name(&self) -> &QualifiedName {
+        &self.type_name
+    }
+
+    pub fn actions(&self) -> &[AlterTypeCmd] {
+        &self.actions
+    }
+}
+
+/// Alias: `alter_type_cmd`
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlterTypeCmd {
+    AddAttribute {
+        attribute: SimpleColumnDefinition,
+        behavior: DropBehavior,
+    },
+    DropAttribute {
+        name: Str,
+        existence: Presence,
+        behavior: DropBehavior,
+    },
+    AlterAttributeType {
+        name: Str,
+        type_name: Type,
+        collation: Option<QualifiedName>,
+        behavior: DropBehavior,
+    },
+}
+
+use crate::DropBehavior;
+use crate::Presence;
+use crate::SimpleColumnDefinition;
+use crate::Type;
+use pg_basics::QualifiedName;
+use pg_basics::Str;
@@ -1,4 +1,5 @@
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AlterDefaultPrivilegesStmt {
     options: Vec<AclOption>,
     action: GrantStmt,
@@ -0,0 +1,46 @@
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlterDomainStmt {
+    domain: QualifiedName,
+    action: AlterDomainAction,
+}
+
+impl AlterDomainStmt {
+    pub fn new(domain: QualifiedName, action: AlterDomainAction) -> Self {
+        Self { domain, action }
+    }
+
+    pub fn domain(&self) -> &QualifiedName {
+        &self.domain
+    }
+
+    pub fn action(&self) -> &AlterDomainAction {
+        &self.action
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlterDomainAction {
+    SetDefault(ExprNode),
+    DropDefault,
+    SetNotNull,
+    DropNotNull,
+    AddConstraint {
+        constraint: DomainConstraint,
+        not_valid: bool,
+    },
+    DropConstraint {
+        name: Str,
+        existence: Presence,
+        behavior: DropBehavior,
+    },
+    ValidateConstraint(Str),
+}
+
+use crate::DomainConstraint;
+use crate::DropBehavior;
+use crate::ExprNode;
+use crate::Presence;
+use pg_basics::QualifiedName;
+use pg_basics::Str;
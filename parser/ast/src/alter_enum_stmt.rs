@@ -0,0 +1,44 @@
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlterEnumStmt {
+    type_name: QualifiedName,
+    action: AlterEnumAction,
+}
+
+impl AlterEnumStmt {
+    pub fn new(type_name: QualifiedName, action: AlterEnumAction) -> Self {
+        Self { type_name, action }
+    }
+
+    pub fn type_name(&self) -> &QualifiedName {
+        &self.type_name
+    }
+
+    pub fn action(&self) -> &AlterEnumAction {
+        &self.action
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlterEnumAction {
+    AddValue {
+        value: Box<str>,
+        position: Option<EnumValuePosition>,
+        existence: Presence,
+    },
+    RenameValue {
+        old_value: Box<str>,
+        new_value: Box<str>,
+    },
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EnumValuePosition {
+    Before(Box<str>),
+    After(Box<str>),
+}
+
+use crate::Presence;
+use pg_basics::QualifiedName;
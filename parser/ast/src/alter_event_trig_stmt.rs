@@ -1,4 +1,5 @@
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EventTriggerState {
     FiresOnReplica,
     FiresOnOrigin,
@@ -6,7 +7,8 @@ pub enum EventTriggerState {
     Disabled,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AlterEventTrigStmt {
     trigger: Str,
     state: EventTriggerState,
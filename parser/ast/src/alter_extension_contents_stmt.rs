@@ -1,4 +1,5 @@
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AlterExtensionContentsStmt {
     name: Str,
     action: AddDrop,
@@ -27,7 +28,8 @@ impl AlterExtensionContentsStmt {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AlterExtensionContentsTarget {
     AccessMethod(Str),
     Aggregate(AggregateWithArgs),
@@ -1,4 +1,5 @@
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AlterObjectDependsStmt {
     target: AlterObjectDependsTarget,
     extension: Str,
@@ -28,7 +29,8 @@ impl AlterObjectDependsStmt {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AlterObjectDependsTarget {
     Function(FunctionWithArgs),
     Index(QualifiedName),
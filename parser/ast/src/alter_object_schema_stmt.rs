@@ -1,4 +1,5 @@
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AlterObjectSchemaStmt {
     target: AlterObjectSchemaTarget,
     new_schema: Str,
@@ -22,7 +23,8 @@ impl AlterObjectSchemaStmt {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AlterObjectSchemaTarget {
     Aggregate(AggregateWithArgs),
     Collation(QualifiedName),
@@ -36,6 +38,7 @@ pub enum AlterObjectSchemaTarget {
     OperatorClass(QualifiedName),
     OperatorFamily(QualifiedName),
     Procedure(FunctionWithArgs),
+    PropertyGraph { target: QualifiedName, missing_ok: bool },
     Routine(FunctionWithArgs),
     Sequence { target: QualifiedName, missing_ok: bool },
     Statistic(QualifiedName),
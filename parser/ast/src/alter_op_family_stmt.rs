@@ -0,0 +1,41 @@
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlterOpFamilyStmt {
+    name: QualifiedName,
+    index_method: Str,
+    action: AlterOpFamilyAction,
+}
+
+impl AlterOpFamilyStmt {
+    pub fn new<T: Into<Str>>(name: QualifiedName, index_method: T, action: AlterOpFamilyAction) -> Self {
+        Self {
+            name,
+            index_method: index_method.into(),
+            action,
+        }
+    }
+
+    pub fn name(&self) -> &QualifiedName {
+        &self.name
+    }
+
+    pub fn index_method(&self) -> &str {
+        &self.index_method
+    }
+
+    pub fn action(&self) -> &AlterOpFamilyAction {
+        &self.action
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlterOpFamilyAction {
+    Add(Vec<OpClassItem>),
+    Drop(Vec<OpClassDrop>),
+}
+
+use crate::OpClassDrop;
+use crate::OpClassItem;
+use pg_basics::QualifiedName;
+use pg_basics::Str;
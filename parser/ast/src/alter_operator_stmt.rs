@@ -0,0 +1,24 @@
+/// `ALTER OPERATOR name ( args ) SET ( options )`
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlterOperatorStmt {
+    operator: OperatorWithArgs,
+    options: Vec<DefinitionElem>,
+}
+
+impl AlterOperatorStmt {
+    pub fn new(operator: OperatorWithArgs, options: Vec<DefinitionElem>) -> Self {
+        Self { operator, options }
+    }
+
+    pub fn operator(&self) -> &OperatorWithArgs {
+        &self.operator
+    }
+
+    pub fn options(&self) -> &[DefinitionElem] {
+        &self.options
+    }
+}
+
+use crate::DefinitionElem;
+use crate::OperatorWithArgs;
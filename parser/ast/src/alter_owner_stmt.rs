@@ -1,4 +1,5 @@
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AlterOwnerStmt {
     target: AlterOwnerTarget,
     new_owner: RoleSpec,
@@ -19,7 +20,8 @@ impl AlterOwnerStmt {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AlterOwnerTarget {
     Aggregate(AggregateWithArgs),
     Collation(QualifiedName),
@@ -36,6 +38,7 @@ pub enum AlterOwnerTarget {
     OperatorClass(QualifiedName),
     OperatorFamily(QualifiedName),
     Procedure(FunctionWithArgs),
+    PropertyGraph(QualifiedName),
     Publication(Str),
     Routine(FunctionWithArgs),
     Schema(Str),
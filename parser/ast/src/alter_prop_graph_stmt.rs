@@ -0,0 +1,67 @@
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlterPropGraphStmt {
+    name: QualifiedName,
+    action: AlterPropGraphAction,
+}
+
+impl AlterPropGraphStmt {
+    pub fn new(name: QualifiedName, action: AlterPropGraphAction) -> Self {
+        Self { name, action }
+    }
+
+    pub fn name(&self) -> &QualifiedName {
+        &self.name
+    }
+
+    pub fn action(&self) -> &AlterPropGraphAction {
+        &self.action
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlterPropGraphAction {
+    AddTables {
+        vertex_tables: Option<Vec<PropGraphVertexTable>>,
+        edge_tables: Option<Vec<PropGraphEdgeTable>>,
+    },
+    DropTables {
+        kind: PropGraphElementKind,
+        tables: Vec<Str>,
+        behavior: DropBehavior,
+    },
+    AlterTable {
+        kind: PropGraphElementKind,
+        table: Str,
+        action: AlterPropGraphTableAction,
+    },
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlterPropGraphTableAction {
+    AddLabels(Vec<PropGraphLabel>),
+    DropLabel {
+        label: Str,
+        behavior: DropBehavior,
+    },
+    AddProperties {
+        label: Str,
+        properties: Vec<NamedValue>,
+    },
+    DropProperties {
+        label: Str,
+        properties: Vec<Str>,
+        behavior: DropBehavior,
+    },
+}
+
+use crate::DropBehavior;
+use crate::NamedValue;
+use crate::PropGraphEdgeTable;
+use crate::PropGraphElementKind;
+use crate::PropGraphLabel;
+use crate::PropGraphVertexTable;
+use pg_basics::QualifiedName;
+use pg_basics::Str;
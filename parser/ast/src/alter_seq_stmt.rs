@@ -0,0 +1,33 @@
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlterSeqStmt {
+    sequence: RelationName,
+    options: Vec<SeqOptElem>,
+    existence: Presence,
+}
+
+impl AlterSeqStmt {
+    pub fn new(sequence: RelationName, options: Vec<SeqOptElem>, existence: Presence) -> Self {
+        Self {
+            sequence,
+            options,
+            existence,
+        }
+    }
+
+    pub fn sequence(&self) -> &RelationName {
+        &self.sequence
+    }
+
+    pub fn options(&self) -> &[SeqOptElem] {
+        &self.options
+    }
+
+    pub fn existence(&self) -> Presence {
+        self.existence
+    }
+}
+
+use crate::Presence;
+use crate::RelationName;
+use crate::SeqOptElem;
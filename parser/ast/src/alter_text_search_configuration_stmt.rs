@@ -0,0 +1,53 @@
+/// Alias: `AlterTSConfigurationStmt`
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlterTextSearchConfigurationStmt {
+    configuration: QualifiedName,
+    action: TextSearchMappingAction,
+}
+
+impl AlterTextSearchConfigurationStmt {
+    pub fn new(configuration: QualifiedName, action: TextSearchMappingAction) -> Self {
+        Self { configuration, action }
+    }
+
+    pub fn configuration(&self) -> &QualifiedName {
+        &self.configuration
+    }
+
+    pub fn action(&self) -> &TextSearchMappingAction {
+        &self.action
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextSearchMappingAction {
+    /// `ADD MAPPING FOR token_types WITH dictionaries`
+    Add {
+        token_types: Vec<Str>,
+        dictionaries: Vec<QualifiedName>,
+    },
+    /// `ALTER MAPPING FOR token_types WITH dictionaries`
+    Alter {
+        token_types: Vec<Str>,
+        dictionaries: Vec<QualifiedName>,
+    },
+    /// `ALTER MAPPING [ FOR token_types ] REPLACE old_dictionary WITH new_dictionary`
+    ///
+    /// When `token_types` is `None`, the dictionary is replaced for all token types.
+    Replace {
+        token_types: Option<Vec<Str>>,
+        old_dictionary: QualifiedName,
+        new_dictionary: QualifiedName,
+    },
+    /// `DROP MAPPING [ IF EXISTS ] FOR token_types`
+    Drop {
+        token_types: Vec<Str>,
+        existence: Presence,
+    },
+}
+
+use crate::Presence;
+use pg_basics::QualifiedName;
+use pg_basics::Str;
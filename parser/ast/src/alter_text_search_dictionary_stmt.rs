@@ -0,0 +1,24 @@
+/// Alias: `AlterTSDictionaryStmt`
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlterTextSearchDictionaryStmt {
+    dictionary: QualifiedName,
+    options: Vec<DefinitionElem>,
+}
+
+impl AlterTextSearchDictionaryStmt {
+    pub fn new(dictionary: QualifiedName, options: Vec<DefinitionElem>) -> Self {
+        Self { dictionary, options }
+    }
+
+    pub fn dictionary(&self) -> &QualifiedName {
+        &self.dictionary
+    }
+
+    pub fn options(&self) -> &[DefinitionElem] {
+        &self.options
+    }
+}
+
+use crate::DefinitionElem;
+use pg_basics::QualifiedName;
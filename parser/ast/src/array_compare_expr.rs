@@ -0,0 +1,64 @@
+/// Comparison against the elements of an array, e.g.: `a = ANY (ARRAY[1, 2])`.
+///
+/// Subqueries are [`SubLink`](crate::SubLink)s instead.
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArrayCompareExpr {
+    kind: ArrayCompareKind,
+    operator: QualifiedOperator,
+    left: ExprNode,
+    right: ExprNode,
+}
+
+impl ArrayCompareExpr {
+    /// `left operator { ANY | SOME } ( right )`
+    pub fn any<T: Into<QualifiedOperator>>(operator: T, left: ExprNode, right: ExprNode) -> Self {
+        Self::new(ArrayCompareKind::Any, operator, left, right)
+    }
+
+    /// `left operator ALL ( right )`
+    pub fn all<T: Into<QualifiedOperator>>(operator: T, left: ExprNode, right: ExprNode) -> Self {
+        Self::new(ArrayCompareKind::All, operator, left, right)
+    }
+
+    pub fn new<T: Into<QualifiedOperator>>(
+        kind: ArrayCompareKind,
+        operator: T,
+        left: ExprNode,
+        right: ExprNode
+    ) -> Self {
+        Self {
+            kind,
+            operator: operator.into(),
+            left,
+            right,
+        }
+    }
+
+    pub fn kind(&self) -> ArrayCompareKind {
+        self.kind
+    }
+
+    pub fn operator(&self) -> &QualifiedOperator {
+        &self.operator
+    }
+
+    pub fn left(&self) -> &ExprNode {
+        &self.left
+    }
+
+    pub fn right(&self) -> &ExprNode {
+        &self.right
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ArrayCompareKind {
+    /// `ANY` or `SOME`
+    Any,
+    All,
+}
+
+use crate::ExprNode;
+use crate::QualifiedOperator;
@@ -0,0 +1,64 @@
+/// `expr [ NOT ] BETWEEN [ SYMMETRIC | ASYMMETRIC ] low AND high`
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BetweenExpr {
+    expr: ExprNode,
+    low: ExprNode,
+    high: ExprNode,
+    not: bool,
+    symmetric: bool,
+}
+
+impl BetweenExpr {
+    pub fn new(expr: ExprNode, low: ExprNode, high: ExprNode) -> Self {
+        Self {
+            expr,
+            low,
+            high,
+            not: false,
+            symmetric: false,
+        }
+    }
+
+    pub fn expr(&self) -> &ExprNode {
+        &self.expr
+    }
+
+    pub fn low(&self) -> &ExprNode {
+        &self.low
+    }
+
+    pub fn high(&self) -> &ExprNode {
+        &self.high
+    }
+
+    pub fn not(&self) -> bool {
+        self.not
+    }
+
+    pub fn set_not(&mut self, not: bool) -> &mut Self {
+        self.not = not;
+        self
+    }
+
+    pub fn with_not(mut self, not: bool) -> Self {
+        self.not = not;
+        self
+    }
+
+    pub fn symmetric(&self) -> bool {
+        self.symmetric
+    }
+
+    pub fn set_symmetric(&mut self, symmetric: bool) -> &mut Self {
+        self.symmetric = symmetric;
+        self
+    }
+
+    pub fn with_symmetric(mut self, symmetric: bool) -> Self {
+        self.symmetric = symmetric;
+        self
+    }
+}
+
+use crate::ExprNode;
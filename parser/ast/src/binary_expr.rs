@@ -1,4 +1,5 @@
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BinaryExpr {
     operator: QualifiedOperator,
     left_operand: ExprNode,
@@ -16,6 +17,18 @@ impl BinaryExpr {
             right_operand,
         }
     }
+
+    pub fn operator(&self) -> &QualifiedOperator {
+        &self.operator
+    }
+
+    pub fn left_operand(&self) -> &ExprNode {
+        &self.left_operand
+    }
+
+    pub fn right_operand(&self) -> &ExprNode {
+        &self.right_operand
+    }
 }
 
 use crate::ExprNode;
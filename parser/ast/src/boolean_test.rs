@@ -0,0 +1,35 @@
+/// `expr IS [ NOT ] { TRUE | FALSE | UNKNOWN }`
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BooleanTest {
+    kind: BoolTestKind,
+    expr: ExprNode,
+}
+
+impl BooleanTest {
+    pub fn new(kind: BoolTestKind, expr: ExprNode) -> Self {
+        Self { kind, expr }
+    }
+
+    pub fn kind(&self) -> BoolTestKind {
+        self.kind
+    }
+
+    pub fn expr(&self) -> &ExprNode {
+        &self.expr
+    }
+}
+
+/// Alias: `BoolTestType`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BoolTestKind {
+    IsTrue,
+    IsNotTrue,
+    IsFalse,
+    IsNotFalse,
+    IsUnknown,
+    IsNotUnknown,
+}
+
+use crate::ExprNode;
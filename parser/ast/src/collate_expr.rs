@@ -0,0 +1,26 @@
+/// Alias: `CollateClause`
+///
+/// `expr COLLATE collation`
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CollateExpr {
+    expr: ExprNode,
+    collation: QualifiedName,
+}
+
+impl CollateExpr {
+    pub fn new(expr: ExprNode, collation: QualifiedName) -> Self {
+        Self { expr, collation }
+    }
+
+    pub fn expr(&self) -> &ExprNode {
+        &self.expr
+    }
+
+    pub fn collation(&self) -> &QualifiedName {
+        &self.collation
+    }
+}
+
+use crate::ExprNode;
+use pg_basics::QualifiedName;
@@ -0,0 +1,23 @@
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompositeTypeStmt {
+    type_name: QualifiedName,
+    attributes: Option<Vec<SimpleColumnDefinition>>,
+}
+
+impl CompositeTypeStmt {
+    pub fn new(type_name: QualifiedName, attributes: Option<Vec<SimpleColumnDefinition>>) -> Self {
+        Self { type_name, attributes }
+    }
+
+    pub fn type_name(&self) -> &QualifiedName {
+        &self.type_name
+    }
+
+    pub fn attributes(&self) -> Option<&[SimpleColumnDefinition]> {
+        self.attributes.as_deref()
+    }
+}
+
+use crate::SimpleColumnDefinition;
+use pg_basics::QualifiedName;
@@ -1,5 +1,6 @@
 /// Alias: `CreateAmStmt`
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CreateAccessMethodStmt {
     name: Str,
     kind: AccessMethodKind,
@@ -28,7 +29,8 @@ impl CreateAccessMethodStmt {
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AccessMethodKind {
     Index,
     Table
@@ -0,0 +1,98 @@
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateDomainStmt {
+    domain: QualifiedName,
+    type_name: Type,
+    collation: Option<QualifiedName>,
+    constraints: Option<Vec<DomainConstraint>>,
+}
+
+impl CreateDomainStmt {
+    pub fn new<T: Into<Type>>(domain: QualifiedName, type_name: T) -> Self {
+        Self {
+            domain,
+            type_name: type_name.into(),
+            collation: None,
+            constraints: None,
+        }
+    }
+
+    pub fn domain(&self) -> &QualifiedName {
+        &self.domain
+    }
+
+    pub fn type_name(&self) -> &Type {
+        &self.type_name
+    }
+
+    pub fn set_collation(&mut self, collation: Option<QualifiedName>) -> &mut Self {
+        self.collation = collation;
+        self
+    }
+
+    pub fn with_collation(mut self, collation: QualifiedName) -> Self {
+        self.collation = Some(collation);
+        self
+    }
+
+    pub fn collation(&self) -> Option<&QualifiedName> {
+        self.collation.as_ref()
+    }
+
+    pub fn set_constraints(&mut self, constraints: Option<Vec<DomainConstraint>>) -> &mut Self {
+
+        self.constraints = constraints.filter(|c| !c.is_empty());
+
+        self
+    }
+
+    pub fn with_constraints(mut self, constraints: Vec<DomainConstraint>) -> Self {
+        self.constraints = if constraints.is_empty() { None } else { Some(constraints) };
+        self
+    }
+
+    pub fn constraints(&self) -> Option<&[DomainConstraint]> {
+        self.constraints.as_deref()
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DomainConstraint {
+    name: Option<Str>,
+    kind: DomainConstraintKind,
+}
+
+impl DomainConstraint {
+    pub fn new(name: Option<Str>, kind: DomainConstraintKind) -> Self {
+        Self { name, kind }
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn kind(&self) -> &DomainConstraintKind {
+        &self.kind
+    }
+}
+
+impl From<DomainConstraintKind> for DomainConstraint {
+    fn from(kind: DomainConstraintKind) -> Self {
+        Self::new(None, kind)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DomainConstraintKind {
+    Check(ExprNode),
+    Default(ExprNode),
+    NotNull,
+    Null,
+}
+
+use crate::ExprNode;
+use crate::Type;
+use pg_basics::QualifiedName;
+use pg_basics::Str;
@@ -0,0 +1,22 @@
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateEnumStmt {
+    type_name: QualifiedName,
+    values: Option<Vec<Box<str>>>,
+}
+
+impl CreateEnumStmt {
+    pub fn new(type_name: QualifiedName, values: Option<Vec<Box<str>>>) -> Self {
+        Self { type_name, values }
+    }
+
+    pub fn type_name(&self) -> &QualifiedName {
+        &self.type_name
+    }
+
+    pub fn values(&self) -> Option<&[Box<str>]> {
+        self.values.as_deref()
+    }
+}
+
+use pg_basics::QualifiedName;
@@ -0,0 +1,41 @@
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateExtensionStmt {
+    name: Str,
+    options: Option<Vec<CreateExtensionOption>>,
+    existence: Presence,
+}
+
+impl CreateExtensionStmt {
+    pub fn new<T: Into<Str>>(name: T, options: Option<Vec<CreateExtensionOption>>, existence: Presence) -> Self {
+        Self {
+            name: name.into(),
+            options,
+            existence,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn options(&self) -> Option<&[CreateExtensionOption]> {
+        self.options.as_deref()
+    }
+
+    pub fn existence(&self) -> Presence {
+        self.existence
+    }
+}
+
+/// Alias: `create_extension_opt_item`
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CreateExtensionOption {
+    Schema(Str),
+    Version(Str),
+    Cascade,
+}
+
+use crate::Presence;
+use pg_basics::Str;
@@ -0,0 +1,72 @@
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateOpClassStmt {
+    name: QualifiedName,
+    data_type: Type,
+    index_method: Str,
+    family: Option<QualifiedName>,
+    items: Vec<OpClassItem>,
+    is_default: bool,
+}
+
+impl CreateOpClassStmt {
+    pub fn new<T, I>(
+        name: QualifiedName,
+        data_type: T,
+        index_method: I,
+        items: Vec<OpClassItem>,
+        is_default: bool
+    ) -> Self
+    where
+        T: Into<Type>,
+        I: Into<Str>,
+    {
+        Self {
+            name,
+            data_type: data_type.into(),
+            index_method: index_method.into(),
+            family: None,
+            items,
+            is_default,
+        }
+    }
+
+    pub fn name(&self) -> &QualifiedName {
+        &self.name
+    }
+
+    pub fn data_type(&self) -> &Type {
+        &self.data_type
+    }
+
+    pub fn index_method(&self) -> &str {
+        &self.index_method
+    }
+
+    pub fn set_family(&mut self, family: Option<QualifiedName>) -> &mut Self {
+        self.family = family;
+        self
+    }
+
+    pub fn with_family(mut self, family: QualifiedName) -> Self {
+        self.family = Some(family);
+        self
+    }
+
+    pub fn family(&self) -> Option<&QualifiedName> {
+        self.family.as_ref()
+    }
+
+    pub fn items(&self) -> &[OpClassItem] {
+        &self.items
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.is_default
+    }
+}
+
+use crate::OpClassItem;
+use crate::Type;
+use pg_basics::QualifiedName;
+use pg_basics::Str;
@@ -0,0 +1,26 @@
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateOpFamilyStmt {
+    name: QualifiedName,
+    index_method: Str,
+}
+
+impl CreateOpFamilyStmt {
+    pub fn new<T: Into<Str>>(name: QualifiedName, index_method: T) -> Self {
+        Self {
+            name,
+            index_method: index_method.into(),
+        }
+    }
+
+    pub fn name(&self) -> &QualifiedName {
+        &self.name
+    }
+
+    pub fn index_method(&self) -> &str {
+        &self.index_method
+    }
+}
+
+use pg_basics::QualifiedName;
+use pg_basics::Str;
@@ -0,0 +1,121 @@
+/// `CREATE [ OR REPLACE ] [ TRUSTED ] [ PROCEDURAL ] LANGUAGE name`.
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreatePLangStmt {
+    name: Str,
+    handler: Option<PLangHandler>,
+    trusted: bool,
+    replace: bool,
+}
+
+impl CreatePLangStmt {
+    pub fn new<T: Into<Str>>(name: T) -> Self {
+        Self {
+            name: name.into(),
+            handler: None,
+            trusted: false,
+            replace: false,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn handler(&self) -> Option<&PLangHandler> {
+        self.handler.as_ref()
+    }
+
+    pub fn set_handler(&mut self, handler: Option<PLangHandler>) -> &mut Self {
+        self.handler = handler;
+        self
+    }
+
+    pub fn with_handler(mut self, handler: PLangHandler) -> Self {
+        self.handler = Some(handler);
+        self
+    }
+
+    pub fn trusted(&self) -> bool {
+        self.trusted
+    }
+
+    pub fn set_trusted(&mut self, trusted: bool) -> &mut Self {
+        self.trusted = trusted;
+        self
+    }
+
+    pub fn with_trusted(mut self, trusted: bool) -> Self {
+        self.trusted = trusted;
+        self
+    }
+
+    pub fn replace(&self) -> bool {
+        self.replace
+    }
+
+    pub fn set_replace(&mut self, replace: bool) -> &mut Self {
+        self.replace = replace;
+        self
+    }
+
+    pub fn with_replace(mut self, replace: bool) -> Self {
+        self.replace = replace;
+        self
+    }
+}
+
+/// `HANDLER handler [ INLINE inline_handler ] [ VALIDATOR validator | NO VALIDATOR ]`
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PLangHandler {
+    handler: QualifiedName,
+    inline_handler: Option<QualifiedName>,
+    validator: Option<QualifiedName>,
+}
+
+impl PLangHandler {
+    pub fn new(handler: QualifiedName) -> Self {
+        Self {
+            handler,
+            inline_handler: None,
+            validator: None,
+        }
+    }
+
+    pub fn handler(&self) -> &QualifiedName {
+        &self.handler
+    }
+
+    pub fn inline_handler(&self) -> Option<&QualifiedName> {
+        self.inline_handler.as_ref()
+    }
+
+    pub fn set_inline_handler(&mut self, inline_handler: Option<QualifiedName>) -> &mut Self {
+        self.inline_handler = inline_handler;
+        self
+    }
+
+    pub fn with_inline_handler(mut self, inline_handler: QualifiedName) -> Self {
+        self.inline_handler = Some(inline_handler);
+        self
+    }
+
+    /// `None` when either `NO VALIDATOR` was used, or the validator was omitted.
+    pub fn validator(&self) -> Option<&QualifiedName> {
+        self.validator.as_ref()
+    }
+
+    pub fn set_validator(&mut self, validator: Option<QualifiedName>) -> &mut Self {
+        self.validator = validator;
+        self
+    }
+
+    pub fn with_validator(mut self, validator: QualifiedName) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+}
+
+use pg_basics::QualifiedName;
+use pg_basics::Str;
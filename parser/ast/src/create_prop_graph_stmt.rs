@@ -0,0 +1,53 @@
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreatePropGraphStmt {
+    name: RangeVar,
+    vertex_tables: Option<Vec<PropGraphVertexTable>>,
+    edge_tables: Option<Vec<PropGraphEdgeTable>>,
+}
+
+impl CreatePropGraphStmt {
+    pub fn new<T: Into<RangeVar>>(name: T) -> Self {
+        Self {
+            name: name.into(),
+            vertex_tables: None,
+            edge_tables: None,
+        }
+    }
+
+    pub fn name(&self) -> &RangeVar {
+        &self.name
+    }
+
+    pub fn vertex_tables(&self) -> Option<&[PropGraphVertexTable]> {
+        self.vertex_tables.as_deref()
+    }
+
+    pub fn set_vertex_tables(&mut self, vertex_tables: Option<Vec<PropGraphVertexTable>>) -> &mut Self {
+        self.vertex_tables = vertex_tables;
+        self
+    }
+
+    pub fn with_vertex_tables(mut self, vertex_tables: Vec<PropGraphVertexTable>) -> Self {
+        self.vertex_tables = Some(vertex_tables);
+        self
+    }
+
+    pub fn edge_tables(&self) -> Option<&[PropGraphEdgeTable]> {
+        self.edge_tables.as_deref()
+    }
+
+    pub fn set_edge_tables(&mut self, edge_tables: Option<Vec<PropGraphEdgeTable>>) -> &mut Self {
+        self.edge_tables = edge_tables;
+        self
+    }
+
+    pub fn with_edge_tables(mut self, edge_tables: Vec<PropGraphEdgeTable>) -> Self {
+        self.edge_tables = Some(edge_tables);
+        self
+    }
+}
+
+use crate::PropGraphEdgeTable;
+use crate::PropGraphVertexTable;
+use crate::RangeVar;
@@ -0,0 +1,23 @@
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateRangeStmt {
+    type_name: QualifiedName,
+    params: Vec<DefinitionElem>,
+}
+
+impl CreateRangeStmt {
+    pub fn new(type_name: QualifiedName, params: Vec<DefinitionElem>) -> Self {
+        Self { type_name, params }
+    }
+
+    pub fn type_name(&self) -> &QualifiedName {
+        &self.type_name
+    }
+
+    pub fn params(&self) -> &[DefinitionElem] {
+        &self.params
+    }
+}
+
+use crate::DefinitionElem;
+use pg_basics::QualifiedName;
@@ -1,5 +1,6 @@
 /// Alias: `CreateOptRoleElem`
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CreateRoleOption {
     RoleMembers(Vec<RoleSpec>),
     Password(Option<Box<str>>),
@@ -0,0 +1,54 @@
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateSchemaStmt {
+    name: Option<Str>,
+    authorization: Option<RoleSpec>,
+    elements: Option<Vec<RawStmt>>,
+    existence: Presence,
+}
+
+impl CreateSchemaStmt {
+    /// At least one of `name` or `authorization` is expected to be present.
+    pub fn new(name: Option<Str>, authorization: Option<RoleSpec>, existence: Presence) -> Self {
+        Self {
+            name,
+            authorization,
+            elements: None,
+            existence,
+        }
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn authorization(&self) -> Option<&RoleSpec> {
+        self.authorization.as_ref()
+    }
+
+    pub fn set_elements(&mut self, elements: Option<Vec<RawStmt>>) -> &mut Self {
+        self.elements = elements;
+        self
+    }
+
+    pub fn with_elements(mut self, elements: Vec<RawStmt>) -> Self {
+        self.elements = Some(elements);
+        self
+    }
+
+    /// The statements embedded in the schema definition (`schema_stmt`).
+    ///
+    /// Only `CREATE SEQUENCE` elements are parsed, for now.
+    pub fn elements(&self) -> Option<&[RawStmt]> {
+        self.elements.as_deref()
+    }
+
+    pub fn existence(&self) -> Presence {
+        self.existence
+    }
+}
+
+use crate::Presence;
+use crate::RawStmt;
+use crate::RoleSpec;
+use pg_basics::Str;
@@ -0,0 +1,33 @@
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateSeqStmt {
+    sequence: RangeVar,
+    options: Option<Vec<SeqOptElem>>,
+    existence: Presence,
+}
+
+impl CreateSeqStmt {
+    pub fn new(sequence: RangeVar, options: Option<Vec<SeqOptElem>>, existence: Presence) -> Self {
+        Self {
+            sequence,
+            options,
+            existence,
+        }
+    }
+
+    pub fn sequence(&self) -> &RangeVar {
+        &self.sequence
+    }
+
+    pub fn options(&self) -> Option<&[SeqOptElem]> {
+        self.options.as_deref()
+    }
+
+    pub fn existence(&self) -> Presence {
+        self.existence
+    }
+}
+
+use crate::Presence;
+use crate::RangeVar;
+use crate::SeqOptElem;
@@ -0,0 +1,86 @@
+/// `CREATE STATISTICS [ [ IF NOT EXISTS ] name ] [ ( kinds ) ] ON exprs FROM relations`.
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateStatsStmt {
+    name: Option<QualifiedName>,
+    kinds: Option<Vec<Str>>,
+    exprs: Vec<StatsElem>,
+    relations: Vec<TableRef>,
+    existence: Presence,
+}
+
+impl CreateStatsStmt {
+    pub fn new(exprs: Vec<StatsElem>, relations: Vec<TableRef>) -> Self {
+        Self {
+            name: None,
+            kinds: None,
+            exprs,
+            relations,
+            existence: Presence::Fail,
+        }
+    }
+
+    pub fn name(&self) -> Option<&QualifiedName> {
+        self.name.as_ref()
+    }
+
+    pub fn set_name(&mut self, name: Option<QualifiedName>) -> &mut Self {
+        self.name = name;
+        self
+    }
+
+    pub fn with_name(mut self, name: QualifiedName) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn kinds(&self) -> Option<&[Str]> {
+        self.kinds.as_deref()
+    }
+
+    pub fn set_kinds(&mut self, kinds: Option<Vec<Str>>) -> &mut Self {
+        self.kinds = kinds;
+        self
+    }
+
+    pub fn with_kinds(mut self, kinds: Vec<Str>) -> Self {
+        self.kinds = Some(kinds);
+        self
+    }
+
+    pub fn exprs(&self) -> &[StatsElem] {
+        &self.exprs
+    }
+
+    pub fn relations(&self) -> &[TableRef] {
+        &self.relations
+    }
+
+    pub fn existence(&self) -> Presence {
+        self.existence
+    }
+
+    pub fn set_existence(&mut self, existence: Presence) -> &mut Self {
+        self.existence = existence;
+        self
+    }
+
+    pub fn with_existence(mut self, existence: Presence) -> Self {
+        self.existence = existence;
+        self
+    }
+}
+
+/// Alias: `stats_param`
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StatsElem {
+    Column(Str),
+    Expr(ExprNode),
+}
+
+use crate::ExprNode;
+use crate::Presence;
+use crate::TableRef;
+use pg_basics::QualifiedName;
+use pg_basics::Str;
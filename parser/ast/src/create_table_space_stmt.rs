@@ -0,0 +1,64 @@
+/// `CREATE TABLESPACE name [ OWNER role ] LOCATION 'directory' [ WITH ( options ) ]`.
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateTableSpaceStmt {
+    name: Str,
+    owner: Option<RoleSpec>,
+    location: Box<str>,
+    options: Option<Vec<RelOption>>,
+}
+
+impl CreateTableSpaceStmt {
+    pub fn new<N, L>(name: N, location: L) -> Self
+    where
+        N: Into<Str>,
+        L: Into<Box<str>>
+    {
+        Self {
+            name: name.into(),
+            owner: None,
+            location: location.into(),
+            options: None,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn owner(&self) -> Option<&RoleSpec> {
+        self.owner.as_ref()
+    }
+
+    pub fn set_owner(&mut self, owner: Option<RoleSpec>) -> &mut Self {
+        self.owner = owner;
+        self
+    }
+
+    pub fn with_owner(mut self, owner: RoleSpec) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    pub fn options(&self) -> Option<&[RelOption]> {
+        self.options.as_deref()
+    }
+
+    pub fn set_options(&mut self, options: Option<Vec<RelOption>>) -> &mut Self {
+        self.options = options;
+        self
+    }
+
+    pub fn with_options(mut self, options: Vec<RelOption>) -> Self {
+        self.options = Some(options);
+        self
+    }
+}
+
+use crate::RelOption;
+use crate::RoleSpec;
+use pg_basics::Str;
@@ -0,0 +1,71 @@
+/// `CREATE [ OR REPLACE ] TRANSFORM FOR type LANGUAGE lang ( transform_element_list )`.
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateTransformStmt {
+    transform: Transform,
+    from_sql: Option<FunctionWithArgs>,
+    to_sql: Option<FunctionWithArgs>,
+    replace: bool,
+}
+
+impl CreateTransformStmt {
+    pub fn new(transform: Transform) -> Self {
+        Self {
+            transform,
+            from_sql: None,
+            to_sql: None,
+            replace: false,
+        }
+    }
+
+    pub fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    /// `FROM SQL WITH FUNCTION function`
+    pub fn from_sql(&self) -> Option<&FunctionWithArgs> {
+        self.from_sql.as_ref()
+    }
+
+    pub fn set_from_sql(&mut self, from_sql: Option<FunctionWithArgs>) -> &mut Self {
+        self.from_sql = from_sql;
+        self
+    }
+
+    pub fn with_from_sql(mut self, from_sql: FunctionWithArgs) -> Self {
+        self.from_sql = Some(from_sql);
+        self
+    }
+
+    /// `TO SQL WITH FUNCTION function`
+    pub fn to_sql(&self) -> Option<&FunctionWithArgs> {
+        self.to_sql.as_ref()
+    }
+
+    pub fn set_to_sql(&mut self, to_sql: Option<FunctionWithArgs>) -> &mut Self {
+        self.to_sql = to_sql;
+        self
+    }
+
+    pub fn with_to_sql(mut self, to_sql: FunctionWithArgs) -> Self {
+        self.to_sql = Some(to_sql);
+        self
+    }
+
+    pub fn replace(&self) -> bool {
+        self.replace
+    }
+
+    pub fn set_replace(&mut self, replace: bool) -> &mut Self {
+        self.replace = replace;
+        self
+    }
+
+    pub fn with_replace(mut self, replace: bool) -> Self {
+        self.replace = replace;
+        self
+    }
+}
+
+use crate::FunctionWithArgs;
+use crate::Transform;
@@ -0,0 +1,71 @@
+/// Generic object definitions, which take a `definition` list.
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DefineStmt {
+    /// `CREATE [ OR REPLACE ] AGGREGATE name ( args ) ( definition )`.
+    Aggregate {
+        aggregate: AggregateWithArgs,
+        definition: Vec<DefinitionElem>,
+        replace: bool,
+    },
+    /// `CREATE [ OR REPLACE ] AGGREGATE name ( definition )`.
+    ///
+    /// The old-style syntax, where the input type is part of the definition (`BASETYPE`).
+    OldStyleAggregate {
+        name: QualifiedName,
+        definition: Vec<DefinitionElem>,
+        replace: bool,
+    },
+    /// `CREATE COLLATION [ IF NOT EXISTS ] name { ( definition ) | FROM existing_collation }`.
+    Collation {
+        name: QualifiedName,
+        definition: CollationDefinition,
+        existence: Presence,
+    },
+    /// `CREATE OPERATOR name ( definition )`.
+    Operator {
+        name: QualifiedOperator,
+        definition: Vec<DefinitionElem>,
+    },
+    /// `CREATE TYPE name ( definition )`.
+    ///
+    /// When `definition` is `None`, then it's a shell type.
+    Type {
+        name: QualifiedName,
+        definition: Option<Vec<DefinitionElem>>,
+    },
+    /// `CREATE TEXT SEARCH CONFIGURATION name ( definition )`.
+    TextSearchConfiguration {
+        name: QualifiedName,
+        definition: Vec<DefinitionElem>,
+    },
+    /// `CREATE TEXT SEARCH DICTIONARY name ( definition )`.
+    TextSearchDictionary {
+        name: QualifiedName,
+        definition: Vec<DefinitionElem>,
+    },
+    /// `CREATE TEXT SEARCH PARSER name ( definition )`.
+    TextSearchParser {
+        name: QualifiedName,
+        definition: Vec<DefinitionElem>,
+    },
+    /// `CREATE TEXT SEARCH TEMPLATE name ( definition )`.
+    TextSearchTemplate {
+        name: QualifiedName,
+        definition: Vec<DefinitionElem>,
+    },
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CollationDefinition {
+    Definition(Vec<DefinitionElem>),
+    /// Copies an existing collation.
+    From(QualifiedName),
+}
+
+use crate::AggregateWithArgs;
+use crate::DefinitionElem;
+use crate::Presence;
+use crate::QualifiedOperator;
+use pg_basics::QualifiedName;
@@ -0,0 +1,58 @@
+/// Alias: `def_elem`
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DefinitionElem {
+    name: Str,
+    arg: Option<DefinitionArg>,
+}
+
+impl DefinitionElem {
+    pub fn new<T: Into<Str>>(name: T, arg: Option<DefinitionArg>) -> Self {
+        Self {
+            name: name.into(),
+            arg,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn arg(&self) -> Option<&DefinitionArg> {
+        self.arg.as_ref()
+    }
+}
+
+impl From<Str> for DefinitionElem {
+    fn from(name: Str) -> Self {
+        Self::new(name, None)
+    }
+}
+
+impl From<&'static str> for DefinitionElem {
+    fn from(name: &'static str) -> Self {
+        Self::new(name, None)
+    }
+}
+
+/// Alias: `def_arg`
+#[derive(Debug, Clone, Eq, PartialEq, From, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DefinitionArg {
+    #[from]
+    Type(FuncType),
+    /// A reserved keyword, which is kept as its text.
+    ReservedWord(Str),
+    #[from]
+    Operator(QualifiedOperator),
+    #[from]
+    Number(SignedNumber),
+    String(Box<str>),
+    None,
+}
+
+use crate::FuncType;
+use crate::QualifiedOperator;
+use crate::SignedNumber;
+use derive_more::From;
+use pg_basics::Str;
@@ -0,0 +1,35 @@
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DropStmt {
+    target: DropTarget,
+    existence: Presence,
+    behavior: DropBehavior,
+}
+
+impl DropStmt {
+    pub fn new(target: DropTarget, existence: Presence, behavior: DropBehavior) -> Self {
+        Self { target, existence, behavior }
+    }
+
+    pub fn target(&self) -> &DropTarget {
+        &self.target
+    }
+
+    pub fn existence(&self) -> Presence {
+        self.existence
+    }
+
+    pub fn behavior(&self) -> DropBehavior {
+        self.behavior
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DropTarget {
+    PropertyGraph(Vec<QualifiedName>),
+}
+
+use crate::DropBehavior;
+use crate::Presence;
+use pg_basics::QualifiedName;
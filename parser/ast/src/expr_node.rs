@@ -0,0 +1,126 @@
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExprNode {
+    kind: ExprKind,
+    span: Span,
+}
+
+impl ExprNode {
+    pub fn new<T: Into<ExprKind>>(kind: T, span: Span) -> Self {
+        Self {
+            kind: kind.into(),
+            span,
+        }
+    }
+
+    pub fn kind(&self) -> &ExprKind {
+        &self.kind
+    }
+
+    pub fn into_kind(self) -> ExprKind {
+        self.kind
+    }
+
+    pub fn span(&self) -> &Span {
+        &self.span
+    }
+
+    pub fn set_span(&mut self, span: Span) -> &mut Self {
+        self.span = span;
+        self
+    }
+
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = span;
+        self
+    }
+}
+
+impl From<ExprKind> for ExprNode {
+    fn from(kind: ExprKind) -> Self {
+        Self::new(kind, Span::default())
+    }
+}
+
+macro_rules! from_kind {
+    ( $( $source:ty ),+ $(,)? ) => {
+        $(
+            impl From<$source> for ExprNode {
+                fn from(value: $source) -> Self {
+                    ExprKind::from(value).into()
+                }
+            }
+        )+
+    };
+}
+
+from_kind! {
+    ArrayCompareExpr,
+    BetweenExpr,
+    BinaryExpr,
+    BoolExpr,
+    BooleanTest,
+    CaseExpr,
+    CollateExpr,
+    ColumnRef,
+    FuncCall,
+    FuncCallExpr,
+    InExpr,
+    IndirectionExpr,
+    JsonArrayAggExpr,
+    JsonObjectAggExpr,
+    RowCompareExpr,
+    SimilarToExpr,
+    SqlFunction,
+    StringTypecastExpr,
+    SubLink,
+    TypecastExpr,
+    UnaryExpr,
+    UnsignedNumber,
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::Operator;
+    use pg_basics::Location;
+
+    #[test]
+    fn test_serde() {
+        let left = ExprNode::new(ExprKind::IntegerConst(1), Span::new(Location::new(7..8, 1, 8)));
+        let right = ExprNode::new(ExprKind::StringConst("x".into()), Span::new(Location::new(11..14, 1, 12)));
+        let expr = ExprNode::new(BinaryExpr::new(Operator::Addition, left, right), Span::new(Location::new(7..14, 1, 8)));
+
+        let json = serde_json::to_string(&expr).unwrap();
+        let actual: ExprNode = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(expr, actual);
+        // Spans always compare equal, so they're checked separately.
+        assert_eq!(expr.span().location(), actual.span().location());
+    }
+}
+
+use crate::ArrayCompareExpr;
+use crate::BetweenExpr;
+use crate::BinaryExpr;
+use crate::BoolExpr;
+use crate::BooleanTest;
+use crate::CaseExpr;
+use crate::CollateExpr;
+use crate::ColumnRef;
+use crate::ExprKind;
+use crate::FuncCall;
+use crate::FuncCallExpr;
+use crate::InExpr;
+use crate::IndirectionExpr;
+use crate::JsonArrayAggExpr;
+use crate::JsonObjectAggExpr;
+use crate::RowCompareExpr;
+use crate::SimilarToExpr;
+use crate::Span;
+use crate::SqlFunction;
+use crate::StringTypecastExpr;
+use crate::SubLink;
+use crate::TypecastExpr;
+use crate::UnaryExpr;
+use pg_basics::UnsignedNumber;
@@ -1,4 +1,5 @@
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FrameExtent {
     Unbounded { end: Option<PrecedingEnd> },
     CurrentRow { end: Option<CurrentRowEnd> },
@@ -12,7 +13,8 @@ pub enum FrameExtent {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PrecedingEnd {
     Unbounded,
     CurrentRow,
@@ -20,14 +22,16 @@ pub enum PrecedingEnd {
     Following(ExprNode),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CurrentRowEnd {
     Unbounded,
     CurrentRow,
     Following(ExprNode),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FollowingEnd {
     Unbounded,
     Following(ExprNode),
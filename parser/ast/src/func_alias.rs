@@ -1,6 +1,7 @@
 pub type FuncAlias = OneOrBoth<Str, Vec<FuncAliasColumn>>;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FuncAliasColumn {
     name: Str,
     type_name: Option<Type>,
@@ -1,4 +1,5 @@
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FuncArgsKind {
     Empty {
         order_within_group: Option<Vec<SortBy>>,
@@ -21,7 +22,8 @@ pub enum FuncArgsKind {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FuncArgsOrder {
     OrderBy(Vec<SortBy>),
     WithinGroup(Vec<SortBy>),
@@ -1,12 +1,18 @@
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FuncCall {
     name: QualifiedName,
     args: FuncArgsKind,
+    span: Span,
 }
 
 impl FuncCall {
     pub fn new(name: QualifiedName, args: FuncArgsKind) -> Self {
-        Self { name, args }
+        Self {
+            name,
+            args,
+            span: Span::default(),
+        }
     }
 
     pub fn name(&self) -> &[Str] {
@@ -16,8 +22,23 @@ impl FuncCall {
     pub fn args(&self) -> &FuncArgsKind {
         &self.args
     }
+
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = span;
+        self
+    }
+
+    pub fn set_span(&mut self, span: Span) -> &mut Self {
+        self.span = span;
+        self
+    }
+
+    pub fn span(&self) -> &Span {
+        &self.span
+    }
 }
 
 use crate::FuncArgsKind;
+use crate::Span;
 use pg_basics::QualifiedName;
 use pg_basics::Str;
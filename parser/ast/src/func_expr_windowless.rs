@@ -1,9 +1,20 @@
-#[derive(Debug, Clone, Eq, PartialEq, From)]
+#[derive(Debug, Clone, Eq, PartialEq, From, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FuncExprWindowless {
     SqlFunction(SqlFunction),
     FuncCall(FuncCall),
 }
 
+impl From<FuncExprWindowless> for ExprNode {
+    fn from(value: FuncExprWindowless) -> Self {
+        match value {
+            FuncExprWindowless::SqlFunction(func) => func.into(),
+            FuncExprWindowless::FuncCall(func) => func.into(),
+        }
+    }
+}
+
+use crate::ExprNode;
 use crate::FuncCall;
 use crate::SqlFunction;
 use derive_more::From;
@@ -1,5 +1,6 @@
 /// Alias: `RangeFunction`
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FunctionTableRef {
     function: FuncExprWindowless,
     alias: Option<FuncAlias>,
@@ -1,4 +1,5 @@
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GrantStmt {
     is_grant: bool,
     privileges: AccessPrivilege,
@@ -65,6 +66,10 @@ impl GrantStmt {
     pub fn grant_option(&self) -> GrantOption {
         self.grant_option
     }
+
+    pub fn drop_behavior(&self) -> DropBehavior {
+        self.drop_behavior
+    }
 }
 
 use crate::AccessPrivilege;
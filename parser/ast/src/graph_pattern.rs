@@ -0,0 +1,146 @@
+/// Alias: `graph_pattern`
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GraphPattern {
+    paths: Vec<PathPattern>,
+    where_clause: Option<ExprNode>,
+}
+
+impl GraphPattern {
+    pub fn new(paths: Vec<PathPattern>) -> Self {
+        Self {
+            paths,
+            where_clause: None,
+        }
+    }
+
+    pub fn paths(&self) -> &[PathPattern] {
+        &self.paths
+    }
+
+    pub fn set_where_clause(&mut self, where_clause: Option<ExprNode>) -> &mut Self {
+        self.where_clause = where_clause;
+        self
+    }
+
+    pub fn with_where_clause(mut self, where_clause: ExprNode) -> Self {
+        self.where_clause = Some(where_clause);
+        self
+    }
+
+    pub fn where_clause(&self) -> Option<&ExprNode> {
+        self.where_clause.as_ref()
+    }
+}
+
+/// Alias: `path_term`
+pub type PathPattern = Vec<PathElement>;
+
+/// Alias: `path_primary`
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PathElement {
+    Vertex(ElementPattern),
+    Edge {
+        direction: EdgeDirection,
+        /// Abbreviated edges (e.g.: `->`) have an empty pattern.
+        pattern: ElementPattern,
+    },
+    /// A parenthesized path, e.g.: `((a)-[e]->(b) WHERE a.x > b.x)`.
+    Subpath {
+        path: PathPattern,
+        where_clause: Option<ExprNode>,
+    },
+}
+
+/// The content of a vertex or edge pattern, e.g.: `a IS person WHERE a.age > 18`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementPattern {
+    variable: Option<Str>,
+    label: Option<LabelExpression>,
+    where_clause: Option<ExprNode>,
+}
+
+impl ElementPattern {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn variable(&self) -> Option<&str> {
+        self.variable.as_deref()
+    }
+
+    pub fn set_variable(&mut self, variable: Option<Str>) -> &mut Self {
+        self.variable = variable;
+        self
+    }
+
+    pub fn with_variable<T: Into<Str>>(mut self, variable: T) -> Self {
+        self.variable = Some(variable.into());
+        self
+    }
+
+    pub fn label(&self) -> Option<&LabelExpression> {
+        self.label.as_ref()
+    }
+
+    pub fn set_label(&mut self, label: Option<LabelExpression>) -> &mut Self {
+        self.label = label;
+        self
+    }
+
+    pub fn with_label<T: Into<LabelExpression>>(mut self, label: T) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn where_clause(&self) -> Option<&ExprNode> {
+        self.where_clause.as_ref()
+    }
+
+    pub fn set_where_clause(&mut self, where_clause: Option<ExprNode>) -> &mut Self {
+        self.where_clause = where_clause;
+        self
+    }
+
+    pub fn with_where_clause(mut self, where_clause: ExprNode) -> Self {
+        self.where_clause = Some(where_clause);
+        self
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EdgeDirection {
+    /// `-[ ]->` or `->`
+    Right,
+    /// `<-[ ]-` or `<-`
+    Left,
+    /// `-[ ]-` or `-`
+    Any,
+}
+
+/// Alias: `label_expression`
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LabelExpression {
+    Label(Str),
+    /// `a | b`
+    Disjunction(Vec<LabelExpression>),
+}
+
+impl From<Str> for LabelExpression {
+    fn from(label: Str) -> Self {
+        Self::Label(label)
+    }
+}
+
+impl From<&'static str> for LabelExpression {
+    fn from(label: &'static str) -> Self {
+        Self::Label(label.into())
+    }
+}
+
+use crate::ExprNode;
+use pg_basics::Str;
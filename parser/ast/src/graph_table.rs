@@ -0,0 +1,51 @@
+/// Alias: `RangeGraphTable`
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GraphTable {
+    graph: RelationName,
+    pattern: GraphPattern,
+    columns: Vec<NamedValue>,
+    alias: Option<Alias>,
+}
+
+impl GraphTable {
+    pub fn new<T: Into<RelationName>>(graph: T, pattern: GraphPattern, columns: Vec<NamedValue>) -> Self {
+        Self {
+            graph: graph.into(),
+            pattern,
+            columns,
+            alias: None,
+        }
+    }
+
+    pub fn graph(&self) -> &RelationName {
+        &self.graph
+    }
+
+    pub fn pattern(&self) -> &GraphPattern {
+        &self.pattern
+    }
+
+    pub fn columns(&self) -> &[NamedValue] {
+        &self.columns
+    }
+
+    pub fn set_alias(&mut self, alias: Option<Alias>) -> &mut Self {
+        self.alias = alias;
+        self
+    }
+
+    pub fn with_alias<T: Into<Alias>>(mut self, alias: T) -> Self {
+        self.alias = Some(alias.into());
+        self
+    }
+
+    pub fn alias(&self) -> Option<&Alias> {
+        self.alias.as_ref()
+    }
+}
+
+use crate::Alias;
+use crate::GraphPattern;
+use crate::NamedValue;
+use crate::RelationName;
@@ -0,0 +1,30 @@
+/// Column constraint: `GENERATED ( ALWAYS | BY DEFAULT ) AS IDENTITY`.
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IdentityConstraint {
+    generated: GeneratedWhen,
+    options: Option<Vec<SeqOptElem>>,
+}
+
+impl IdentityConstraint {
+    pub fn new(generated: GeneratedWhen, options: Option<Vec<SeqOptElem>>) -> Self {
+        Self { generated, options }
+    }
+
+    pub fn generated(&self) -> GeneratedWhen {
+        self.generated
+    }
+
+    pub fn options(&self) -> Option<&[SeqOptElem]> {
+        self.options.as_deref()
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GeneratedWhen {
+    Always,
+    ByDefault,
+}
+
+use crate::SeqOptElem;
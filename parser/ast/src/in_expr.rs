@@ -0,0 +1,38 @@
+/// `expr [ NOT ] IN ( expr_list )`
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InExpr {
+    expr: ExprNode,
+    list: Vec<ExprNode>,
+    not: bool,
+}
+
+impl InExpr {
+    pub fn new(expr: ExprNode, list: Vec<ExprNode>) -> Self {
+        Self { expr, list, not: false }
+    }
+
+    pub fn expr(&self) -> &ExprNode {
+        &self.expr
+    }
+
+    pub fn list(&self) -> &[ExprNode] {
+        &self.list
+    }
+
+    pub fn not(&self) -> bool {
+        self.not
+    }
+
+    pub fn set_not(&mut self, not: bool) -> &mut Self {
+        self.not = not;
+        self
+    }
+
+    pub fn with_not(mut self, not: bool) -> Self {
+        self.not = not;
+        self
+    }
+}
+
+use crate::ExprNode;
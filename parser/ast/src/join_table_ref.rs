@@ -0,0 +1,89 @@
+/// Alias: `JoinExpr`
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JoinTableRef {
+    join_type: JoinType,
+    left: Box<TableRef>,
+    right: Box<TableRef>,
+    condition: JoinCondition,
+    alias: Option<Alias>,
+}
+
+impl JoinTableRef {
+    pub fn new<L, R>(join_type: JoinType, left: L, right: R, condition: JoinCondition) -> Self
+    where
+        L: Into<TableRef>,
+        R: Into<TableRef>,
+    {
+        Self {
+            join_type,
+            left: Box::new(left.into()),
+            right: Box::new(right.into()),
+            condition,
+            alias: None,
+        }
+    }
+
+    pub fn join_type(&self) -> JoinType {
+        self.join_type
+    }
+
+    pub fn left(&self) -> &TableRef {
+        &self.left
+    }
+
+    pub fn right(&self) -> &TableRef {
+        &self.right
+    }
+
+    pub fn condition(&self) -> &JoinCondition {
+        &self.condition
+    }
+
+    /// Only parenthesized joins can have an alias, e.g.: `(a JOIN b USING (id)) AS c`.
+    pub fn alias(&self) -> Option<&Alias> {
+        self.alias.as_ref()
+    }
+
+    pub fn set_alias(&mut self, alias: Option<Alias>) -> &mut Self {
+        self.alias = alias;
+        self
+    }
+
+    pub fn with_alias<T: Into<Alias>>(mut self, alias: T) -> Self {
+        self.alias = Some(alias.into());
+        self
+    }
+}
+
+/// Alias: `join_type`
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JoinType {
+    #[default]
+    Inner,
+    Left,
+    Right,
+    Full,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JoinCondition {
+    /// `CROSS JOIN`, which is an `INNER JOIN` without any condition.
+    Cross,
+    /// `NATURAL JOIN`
+    Natural,
+    /// `USING '(' name_list ')' ( AS ColId )?`
+    Using {
+        columns: Vec<Str>,
+        alias: Option<Str>,
+    },
+    /// `ON a_expr`
+    On(ExprNode),
+}
+
+use crate::Alias;
+use crate::ExprNode;
+use crate::TableRef;
+use pg_basics::Str;
@@ -1,4 +1,5 @@
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct JsonBehaviorClause {
     on_error: Option<JsonBehavior>,
     on_empty: Option<JsonBehavior>,
@@ -29,7 +30,8 @@ impl JsonBehaviorClause {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum JsonBehavior {
     Null,
     Error,
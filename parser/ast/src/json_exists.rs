@@ -1,6 +1,7 @@
 pub type JsonArgument = (Str, JsonValueExpr);
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct JsonExistsExpr {
     context_item: JsonValueExpr,
     path_spec: ExprNode,
@@ -1,4 +1,5 @@
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct JsonFormat {
     kind: Option<JsonFormatKind>,
     encoding: Option<JsonEncoding>,
@@ -28,7 +29,8 @@ impl JsonFormat {
 }
 
 /// Alias: `JsonFormatType`
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum JsonFormatKind {
     /// `JSON`
     Text,
@@ -36,7 +38,8 @@ pub enum JsonFormatKind {
     Binary
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum JsonEncoding {
     UTF8,
     UTF16,
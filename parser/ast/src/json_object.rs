@@ -1,4 +1,5 @@
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum JsonObjectExpr {
     ExplicitCall(Option<Vec<NamedValue>>),
     SqlSyntax(JsonObjectArgs),
@@ -10,7 +11,8 @@ impl Default for JsonObjectExpr {
     }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct JsonObjectArgs {
     exprs: Option<Vec<JsonKeyValue>>,
     output: Option<JsonOutput>,
@@ -1,4 +1,5 @@
-#[derive(Debug, Clone, PartialEq, Eq, From)]
+#[derive(Debug, Clone, PartialEq, Eq, From, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum JsonTableColumnDefinition {
     #[from(ignore)]
     ForOrdinality { column_name: Str },
@@ -7,7 +8,8 @@ pub enum JsonTableColumnDefinition {
     Nested(JsonTableNestedColumn),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct JsonTableRegularColumn {
     column_name: Str,
     type_name: Type,
@@ -104,7 +106,8 @@ impl JsonTableRegularColumn {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct JsonTableExistsColumn {
     column_name: Str,
     type_name: Type,
@@ -163,7 +166,8 @@ impl JsonTableExistsColumn {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct JsonTableNestedColumn {
     path_spec: JsonTablePathSpec,
     columns: Vec<JsonTableColumnDefinition>,
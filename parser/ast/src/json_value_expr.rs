@@ -1,4 +1,5 @@
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct JsonValueExpr {
     expr: ExprNode,
     format: JsonFormat,
@@ -24,5 +25,12 @@ impl From<ExprNode> for JsonValueExpr {
     }
 }
 
+impl From<ExprKind> for JsonValueExpr {
+    fn from(expr: ExprKind) -> Self {
+        ExprNode::from(expr).into()
+    }
+}
+
+use crate::ExprKind;
 use crate::ExprNode;
 use crate::JsonFormat;
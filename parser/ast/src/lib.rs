@@ -1,46 +1,80 @@
+#[macro_use]
+extern crate pg_ast_derive;
+
 pg_basics::reexport! { pub
     add_drop,
     aggregate_with_args,
     alias,
+    alter_composite_type_stmt,
     alter_database_set_stmt,
     alter_database_stmt,
     alter_default_privileges_stmt,
+    alter_domain_stmt,
+    alter_enum_stmt,
     alter_event_trig_stmt,
     alter_extension_contents_stmt,
     alter_extension_stmt,
     alter_function_stmt,
     alter_object_depends_stmt,
     alter_object_schema_stmt,
+    alter_op_family_stmt,
+    alter_operator_stmt,
     alter_owner_stmt,
+    alter_prop_graph_stmt,
     alter_role_option,
     alter_role_set_stmt,
     alter_role_stmt,
+    alter_seq_stmt,
     alter_system_stmt,
+    alter_text_search_configuration_stmt,
+    alter_text_search_dictionary_stmt,
     alter_user_mapping_stmt,
     alterdb_option,
     alterdb_option_kind,
+    array_compare_expr,
+    between_expr,
     binary_expr,
     bool_expr,
     boolean_or_string,
+    boolean_test,
     case_expr,
+    collate_expr,
     column_ref,
     comment_stmt,
+    composite_type_stmt,
     constraints_set_mode,
     constraints_set_stmt,
     create_access_method_stmt,
     create_cast_stmt,
     create_conversion_stmt,
     create_database_stmt,
+    create_domain_stmt,
+    create_enum_stmt,
+    create_extension_stmt,
+    create_op_class_stmt,
+    create_op_family_stmt,
+    create_plang_stmt,
+    create_prop_graph_stmt,
+    create_range_stmt,
     create_role_option,
     create_role_stmt,
+    create_schema_stmt,
+    create_seq_stmt,
+    create_stats_stmt,
+    create_table_space_stmt,
+    create_transform_stmt,
     create_user_mapping_stmt,
     createdb_option,
     createdb_option_kind,
     createdb_option_value,
     database_stmt,
     defaultable_value,
+    define_stmt,
+    definition_elem,
     discard_stmt,
     drop_behavior,
+    drop_stmt,
+    expr_node,
     extract_expr,
     frame_extent,
     func_alias,
@@ -56,9 +90,14 @@ pg_basics::reexport! { pub
     generic_option_kind,
     grant_option,
     grant_stmt,
+    graph_pattern,
+    graph_table,
+    identity_constraint,
+    in_expr,
     indirection,
     indirection_expr,
     interval_range,
+    join_table_ref,
     json,
     json_array_agg,
     json_array_agg_expr,
@@ -86,6 +125,7 @@ pg_basics::reexport! { pub
     numeric_spec,
     one_or_all,
     one_or_both,
+    op_class_item,
     operator,
     operator_with_args,
     over_clause,
@@ -94,28 +134,40 @@ pg_basics::reexport! { pub
     prepare_stmt,
     presence,
     privilege_target,
+    prop_graph_table,
     qualified_operator,
     range_function,
     range_var,
     raw_stmt,
     reassign_owned_stmt,
+    rel_option,
     relation_expr,
     relation_name,
+    relation_table_ref,
     rename_stmt,
+    res_target,
     role_kind,
     role_spec,
     role_stmt,
+    row_compare_expr,
     rows_table_ref,
+    rule_stmt,
     sample_table_ref,
     security_label_stmt,
     select_stmt,
+    seq_opt_elem,
+    set_operation,
     set_reset_clause,
     set_rest,
     set_rest_more,
     signed_number,
+    similar_to_expr,
     simple_column_definition,
+    simple_select,
     sort_by,
+    span,
     sql_function,
+    sub_link,
     subselect_table_ref,
     substring_func,
     system_type,
@@ -134,6 +186,7 @@ pg_basics::reexport! { pub
     var_value,
     variable_set_stmt,
     variable_target,
+    visit,
     window_definition,
     window_frame,
     xml_element,
@@ -149,13 +202,15 @@ pg_basics::reexport! { pub
 
 pub type BinaryOperands = Box<(ExprNode, ExprNode)>;
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AclOption {
     Schemas(Vec<Str>),
     Roles(Vec<RoleSpec>),
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SpecificAccessPrivilege {
     AlterSystem,
     Create { columns: Option<Vec<Str>> },
@@ -167,13 +222,15 @@ pub enum SpecificAccessPrivilege {
     },
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AccessPrivilege {
     All { columns: Option<Vec<Str>> },
     Specific(Vec<SpecificAccessPrivilege>),
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PrivilegeDefaultsTarget {
     Functions,
     LargeObjects,
@@ -183,8 +240,9 @@ pub enum PrivilegeDefaultsTarget {
     Types,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, From)]
-pub enum ExprNode {
+#[derive(Debug, Clone, Eq, PartialEq, From, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExprKind {
     /* Constants */
     NullConst,
     StringConst(Box<str>),
@@ -199,6 +257,10 @@ pub enum ExprNode {
     CaseExpr(Box<CaseExpr>),
     ParamRef { index: i32 },
     Row(Option<Vec<ExprNode>>),
+    /// `ARRAY[...]`, where nested arrays (e.g.: `ARRAY[[1, 2], [3, 4]]`) are also `Array`.
+    Array(Vec<ExprNode>),
+    #[from(RowCompareExpr)]
+    RowCompare(Box<RowCompareExpr>),
 
     /// String constant type cast.
     ///
@@ -223,11 +285,35 @@ pub enum ExprNode {
     Distinct(BinaryOperands),
     /// `IS NOT DISTINCT FROM`
     NotDistinct(BinaryOperands),
+    /// `expr IS NULL`, or `expr ISNULL`
+    IsNull(Box<ExprNode>),
+    /// `expr IS NOT NULL`, or `expr NOTNULL`
+    IsNotNull(Box<ExprNode>),
+    #[from(BooleanTest)]
+    BooleanTest(Box<BooleanTest>),
+    #[from(BetweenExpr)]
+    Between(Box<BetweenExpr>),
+    #[from(SimilarToExpr)]
+    SimilarTo(Box<SimilarToExpr>),
+    #[from(ArrayCompareExpr)]
+    ArrayCompare(Box<ArrayCompareExpr>),
+    /// `expr AT TIME ZONE zone`
+    AtTimeZone(BinaryOperands),
+    /// `expr AT LOCAL`
+    AtLocal(Box<ExprNode>),
+    #[from(CollateExpr)]
+    Collate(Box<CollateExpr>),
 
     #[from(IndirectionExpr)]
     Indirection(Box<IndirectionExpr>),
-    #[from]
-    ColumnRef(ColumnRef),
+    #[from(TypecastExpr)]
+    Typecast(Box<TypecastExpr>),
+    #[from(InExpr)]
+    InExpr(Box<InExpr>),
+    #[from(SubLink)]
+    SubLink(Box<SubLink>),
+    #[from(ColumnRef)]
+    ColumnRef(Box<ColumnRef>),
 
     /* Function calls */
     GroupingFunc(Vec<ExprNode>),
@@ -237,7 +323,7 @@ pub enum ExprNode {
     SqlFunction(Box<SqlFunction>),
 }
 
-impl From<UnsignedNumber> for ExprNode {
+impl From<UnsignedNumber> for ExprKind {
     fn from(value: UnsignedNumber) -> Self {
         match value {
             // SAFETY: `int` is originally parsed by `i32::from_str_radix()`, so `0 <= int <= i32::MAX`
@@ -1,4 +1,5 @@
-#[derive(Debug, Clone, Eq, PartialEq, Into)]
+#[derive(Debug, Clone, Eq, PartialEq, Into, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[into((Option<Str>, ExprNode))]
 pub struct NamedValue {
     name: Option<Str>,
@@ -13,7 +13,8 @@ impl NewNumericSpecError {
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NumericSpec {
     precision: NonZero<u16>,
     scale: i16,
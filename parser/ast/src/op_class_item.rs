@@ -0,0 +1,49 @@
+/// Alias: `opclass_item`
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OpClassItem {
+    Operator {
+        strategy: i32,
+        operator: QualifiedOperator,
+        args: Option<OneOrBoth<Type>>,
+        purpose: OpClassPurpose,
+    },
+    Function {
+        support: i32,
+        /// The operand types the function is registered for,
+        /// when they differ from the function's own argument types.
+        class_args: Option<Vec<Type>>,
+        function: FunctionWithArgs,
+    },
+    Storage(Type),
+}
+
+/// Alias: `opclass_purpose`
+#[derive(Debug, Default, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OpClassPurpose {
+    #[default]
+    Search,
+    /// The operator family of the sort ordering.
+    OrderBy(QualifiedName),
+}
+
+/// Alias: `opclass_drop`
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OpClassDrop {
+    Operator {
+        strategy: i32,
+        args: Vec<Type>,
+    },
+    Function {
+        support: i32,
+        args: Vec<Type>,
+    },
+}
+
+use crate::FunctionWithArgs;
+use crate::OneOrBoth;
+use crate::QualifiedOperator;
+use crate::Type;
+use pg_basics::QualifiedName;
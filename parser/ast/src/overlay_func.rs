@@ -1,4 +1,5 @@
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OverlayFunc {
     SqlSyntax(OverlaySqlArgs),
     ExplicitCall(Option<Vec<NamedValue>>),
@@ -10,7 +11,8 @@ impl Default for OverlayFunc {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OverlaySqlArgs {
     arg: ExprNode,
     placing: ExprNode,
@@ -22,6 +24,22 @@ impl OverlaySqlArgs {
     pub fn new(arg: ExprNode, placing: ExprNode, from: ExprNode, for_expr: Option<ExprNode>) -> Self {
         Self { arg, placing, from, for_expr }
     }
+
+    pub fn arg(&self) -> &ExprNode {
+        &self.arg
+    }
+
+    pub fn placing(&self) -> &ExprNode {
+        &self.placing
+    }
+
+    pub fn from(&self) -> &ExprNode {
+        &self.from
+    }
+
+    pub fn for_expr(&self) -> Option<&ExprNode> {
+        self.for_expr.as_ref()
+    }
 }
 
 use crate::ExprNode;
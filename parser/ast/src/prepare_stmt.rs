@@ -1,10 +1,29 @@
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PrepareStmt {
     name: Str,
     arg_types: Vec<Type>,
     query: RawStmt,
 }
 
+impl PrepareStmt {
+    pub fn new(name: Str, arg_types: Vec<Type>, query: RawStmt) -> Self {
+        Self { name, arg_types, query }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn arg_types(&self) -> &[Type] {
+        &self.arg_types
+    }
+
+    pub fn query(&self) -> &RawStmt {
+        &self.query
+    }
+}
+
 use crate::RawStmt;
 use crate::Type;
 use pg_basics::Str;
@@ -1,5 +1,6 @@
 // Alias: `PrivTarget`
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PrivilegeTarget {
     AllFunctionsInSchema(Vec<Str>),
     AllProceduresInSchema(Vec<Str>),
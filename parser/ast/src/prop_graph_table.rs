@@ -0,0 +1,242 @@
+/// Alias: `vertex_table_definition`
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PropGraphVertexTable {
+    table: RelationName,
+    alias: Option<Str>,
+    key: Option<Vec<Str>>,
+    labels: Option<Vec<PropGraphLabel>>,
+}
+
+impl PropGraphVertexTable {
+    pub fn new<T: Into<RelationName>>(table: T) -> Self {
+        Self {
+            table: table.into(),
+            alias: None,
+            key: None,
+            labels: None,
+        }
+    }
+
+    pub fn table(&self) -> &RelationName {
+        &self.table
+    }
+
+    pub fn alias(&self) -> Option<&str> {
+        self.alias.as_deref()
+    }
+
+    pub fn set_alias(&mut self, alias: Option<Str>) -> &mut Self {
+        self.alias = alias;
+        self
+    }
+
+    pub fn with_alias<T: Into<Str>>(mut self, alias: T) -> Self {
+        self.alias = Some(alias.into());
+        self
+    }
+
+    pub fn key(&self) -> Option<&[Str]> {
+        self.key.as_deref()
+    }
+
+    pub fn set_key(&mut self, key: Option<Vec<Str>>) -> &mut Self {
+        self.key = key;
+        self
+    }
+
+    pub fn with_key(mut self, key: Vec<Str>) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    /// `None` means the table gets a default label, that exposes all its columns.
+    pub fn labels(&self) -> Option<&[PropGraphLabel]> {
+        self.labels.as_deref()
+    }
+
+    pub fn set_labels(&mut self, labels: Option<Vec<PropGraphLabel>>) -> &mut Self {
+        self.labels = labels;
+        self
+    }
+
+    pub fn with_labels(mut self, labels: Vec<PropGraphLabel>) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+}
+
+/// Alias: `edge_table_definition`
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PropGraphEdgeTable {
+    table: RelationName,
+    alias: Option<Str>,
+    key: Option<Vec<Str>>,
+    source: PropGraphVertexReference,
+    destination: PropGraphVertexReference,
+    labels: Option<Vec<PropGraphLabel>>,
+}
+
+impl PropGraphEdgeTable {
+    pub fn new<T: Into<RelationName>>(
+        table: T,
+        source: PropGraphVertexReference,
+        destination: PropGraphVertexReference
+    ) -> Self {
+        Self {
+            table: table.into(),
+            alias: None,
+            key: None,
+            source,
+            destination,
+            labels: None,
+        }
+    }
+
+    pub fn table(&self) -> &RelationName {
+        &self.table
+    }
+
+    pub fn alias(&self) -> Option<&str> {
+        self.alias.as_deref()
+    }
+
+    pub fn set_alias(&mut self, alias: Option<Str>) -> &mut Self {
+        self.alias = alias;
+        self
+    }
+
+    pub fn with_alias<T: Into<Str>>(mut self, alias: T) -> Self {
+        self.alias = Some(alias.into());
+        self
+    }
+
+    pub fn key(&self) -> Option<&[Str]> {
+        self.key.as_deref()
+    }
+
+    pub fn set_key(&mut self, key: Option<Vec<Str>>) -> &mut Self {
+        self.key = key;
+        self
+    }
+
+    pub fn with_key(mut self, key: Vec<Str>) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    pub fn source(&self) -> &PropGraphVertexReference {
+        &self.source
+    }
+
+    pub fn destination(&self) -> &PropGraphVertexReference {
+        &self.destination
+    }
+
+    /// `None` means the table gets a default label, that exposes all its columns.
+    pub fn labels(&self) -> Option<&[PropGraphLabel]> {
+        self.labels.as_deref()
+    }
+
+    pub fn set_labels(&mut self, labels: Option<Vec<PropGraphLabel>>) -> &mut Self {
+        self.labels = labels;
+        self
+    }
+
+    pub fn with_labels(mut self, labels: Vec<PropGraphLabel>) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+}
+
+/// Aliases:
+/// * `source_vertex_table`
+/// * `destination_vertex_table`
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PropGraphVertexReference {
+    vertex: Str,
+    key: Option<(Vec<Str>, Vec<Str>)>,
+}
+
+impl PropGraphVertexReference {
+    pub fn new<T: Into<Str>>(vertex: T) -> Self {
+        Self {
+            vertex: vertex.into(),
+            key: None,
+        }
+    }
+
+    pub fn vertex(&self) -> &str {
+        &self.vertex
+    }
+
+    /// The edge's columns, and the vertex's columns they reference.
+    pub fn key(&self) -> Option<(&[Str], &[Str])> {
+        self.key.as_ref()
+            .map(|(columns, referenced)| (columns.as_slice(), referenced.as_slice()))
+    }
+
+    pub fn set_key(&mut self, key: Option<(Vec<Str>, Vec<Str>)>) -> &mut Self {
+        self.key = key;
+        self
+    }
+
+    pub fn with_key(mut self, columns: Vec<Str>, referenced: Vec<Str>) -> Self {
+        self.key = Some((columns, referenced));
+        self
+    }
+}
+
+/// Alias: `element_table_label_clause`
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PropGraphLabel {
+    /// `None` is the `DEFAULT LABEL`.
+    name: Option<Str>,
+    properties: PropGraphProperties,
+}
+
+impl PropGraphLabel {
+    pub fn new(name: Option<Str>, properties: PropGraphProperties) -> Self {
+        Self { name, properties }
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn properties(&self) -> &PropGraphProperties {
+        &self.properties
+    }
+}
+
+/// Alias: `element_table_properties`
+#[derive(Debug, Default, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PropGraphProperties {
+    /// `PROPERTIES ALL COLUMNS`
+    #[default]
+    AllColumns,
+    /// `NO PROPERTIES`
+    None,
+    List(Vec<NamedValue>),
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PropGraphElementKind {
+    /// Aliases:
+    /// * `VERTEX`
+    /// * `NODE`
+    Vertex,
+    /// Aliases:
+    /// * `EDGE`
+    /// * `RELATIONSHIP`
+    Edge,
+}
+
+use crate::NamedValue;
+use crate::RelationName;
+use pg_basics::Str;
@@ -1,4 +1,5 @@
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RangeVar {
     relation: RelationName,
     persistence: RelationPersistence,
@@ -23,6 +24,11 @@ impl RangeVar {
     pub fn persistence(&self) -> RelationPersistence {
         self.persistence
     }
+
+    /// Same as the relation's span.
+    pub fn span(&self) -> &Span {
+        self.relation.span()
+    }
 }
 
 impl From<RelationName> for RangeVar {
@@ -32,9 +38,11 @@ impl From<RelationName> for RangeVar {
 }
 
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RelationPersistence {
     /// regular table
+    #[default]
     Permanent = b'p',
     /// unlogged permanent table
     Unlogged  = b'u',
@@ -43,3 +51,4 @@ pub enum RelationPersistence {
 }
 
 use crate::RelationName;
+use crate::Span;
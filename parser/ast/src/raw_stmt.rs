@@ -1,5 +1,8 @@
-#[derive(Debug, Clone, Eq, PartialEq, From)]
+#[derive(Debug, Clone, Eq, PartialEq, From, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RawStmt {
+    #[from]
+    AlterCompositeTypeStmt(AlterCompositeTypeStmt),
     AlterDatabaseRefreshCollStmt(Str),
     #[from]
     AlterDatabaseSetStmt(AlterDatabaseSetStmt),
@@ -8,6 +11,10 @@ pub enum RawStmt {
     #[from(AlterDefaultPrivilegesStmt)]
     AlterDefaultPrivilegesStmt(Box<AlterDefaultPrivilegesStmt>),
     #[from]
+    AlterDomainStmt(AlterDomainStmt),
+    #[from]
+    AlterEnumStmt(AlterEnumStmt),
+    #[from]
     AlterEventTrigStmt(AlterEventTrigStmt),
     #[from]
     AlterExtensionContentsStmt(AlterExtensionContentsStmt),
@@ -20,20 +27,34 @@ pub enum RawStmt {
     #[from]
     AlterObjectSchemaStmt(AlterObjectSchemaStmt),
     #[from]
+    AlterOpFamilyStmt(AlterOpFamilyStmt),
+    #[from]
+    AlterOperatorStmt(AlterOperatorStmt),
+    #[from]
     AlterOwnerStmt(AlterOwnerStmt),
     #[from]
+    AlterPropGraphStmt(AlterPropGraphStmt),
+    #[from]
     AlterRoleSetStmt(AlterRoleSetStmt),
     #[from]
     AlterRoleStmt(AlterRoleStmt),
     #[from]
+    AlterSeqStmt(AlterSeqStmt),
+    #[from]
     AlterSystemStmt(AlterSystemStmt),
     #[from]
+    AlterTextSearchConfigurationStmt(AlterTextSearchConfigurationStmt),
+    #[from]
+    AlterTextSearchDictionaryStmt(AlterTextSearchDictionaryStmt),
+    #[from]
     AlterUserMappingStmt(AlterUserMappingStmt),
     CheckPointStmt(Option<Vec<UtilityOption>>),
     ClosePortalStmt(OneOrAll<Str>),
     #[from]
     CommentStmt(CommentStmt),
     #[from]
+    CompositeTypeStmt(CompositeTypeStmt),
+    #[from]
     ConstraintsSetStmt(ConstraintsSetStmt),
     #[from]
     CreateAccessMethodStmt(CreateAccessMethodStmt),
@@ -44,12 +65,42 @@ pub enum RawStmt {
     #[from]
     CreateDatabaseStmt(CreateDatabaseStmt),
     #[from]
+    CreateDomainStmt(CreateDomainStmt),
+    #[from]
+    CreateEnumStmt(CreateEnumStmt),
+    #[from]
+    CreateExtensionStmt(CreateExtensionStmt),
+    #[from]
+    CreateOpClassStmt(CreateOpClassStmt),
+    #[from]
+    CreateOpFamilyStmt(CreateOpFamilyStmt),
+    #[from]
+    CreatePLangStmt(CreatePLangStmt),
+    #[from]
+    CreatePropGraphStmt(CreatePropGraphStmt),
+    #[from]
+    CreateRangeStmt(CreateRangeStmt),
+    #[from]
     CreateRoleStmt(CreateRoleStmt),
     #[from]
+    CreateSchemaStmt(CreateSchemaStmt),
+    #[from]
+    CreateSeqStmt(CreateSeqStmt),
+    #[from]
+    CreateStatsStmt(CreateStatsStmt),
+    #[from]
+    CreateTableSpaceStmt(CreateTableSpaceStmt),
+    #[from]
+    CreateTransformStmt(CreateTransformStmt),
+    #[from]
     CreateUserMappingStmt(CreateUserMappingStmt),
     DeallocateStmt(OneOrAll<Str>),
     #[from]
+    DefineStmt(DefineStmt),
+    #[from]
     DiscardStmt(DiscardStmt),
+    #[from]
+    DropStmt(DropStmt),
     ListenStmt(Str),
     LoadStmt(Box<str>),
     #[from]
@@ -63,7 +114,11 @@ pub enum RawStmt {
     #[from]
     RenameStmt(RenameStmt),
     #[from]
+    RuleStmt(RuleStmt),
+    #[from]
     SecurityLabelStmt(SecurityLabelStmt),
+    #[from(SelectStmt)]
+    SelectStmt(Box<SelectStmt>),
     #[from]
     TransactionStmt(TransactionStmt),
     UnlistenStmt(OneOrAll<Str>),
@@ -113,31 +168,56 @@ impl From<DatabaseStmt> for RawStmt {
     }
 }
 
+use crate::AlterCompositeTypeStmt;
 use crate::AlterDatabaseSetStmt;
 use crate::AlterDatabaseStmt;
 use crate::AlterDefaultPrivilegesStmt;
+use crate::AlterDomainStmt;
+use crate::AlterEnumStmt;
 use crate::AlterEventTrigStmt;
 use crate::AlterExtensionContentsStmt;
 use crate::AlterExtensionStmt;
 use crate::AlterFunctionStmt;
 use crate::AlterObjectDependsStmt;
 use crate::AlterObjectSchemaStmt;
+use crate::AlterOpFamilyStmt;
+use crate::AlterOperatorStmt;
 use crate::AlterOwnerStmt;
 use crate::AlterOwnerTarget;
+use crate::AlterPropGraphStmt;
 use crate::AlterRoleSetStmt;
 use crate::AlterRoleStmt;
+use crate::AlterSeqStmt;
 use crate::AlterSystemStmt;
+use crate::AlterTextSearchConfigurationStmt;
+use crate::AlterTextSearchDictionaryStmt;
 use crate::AlterUserMappingStmt;
 use crate::CommentStmt;
+use crate::CompositeTypeStmt;
 use crate::ConstraintsSetStmt;
 use crate::CreateAccessMethodStmt;
 use crate::CreateCastStmt;
 use crate::CreateConversionStmt;
 use crate::CreateDatabaseStmt;
+use crate::CreateDomainStmt;
+use crate::CreateEnumStmt;
+use crate::CreateExtensionStmt;
+use crate::CreateOpClassStmt;
+use crate::CreateOpFamilyStmt;
+use crate::CreatePLangStmt;
+use crate::CreatePropGraphStmt;
+use crate::CreateRangeStmt;
 use crate::CreateRoleStmt;
+use crate::CreateSchemaStmt;
+use crate::CreateSeqStmt;
+use crate::CreateStatsStmt;
+use crate::CreateTableSpaceStmt;
+use crate::CreateTransformStmt;
 use crate::CreateUserMappingStmt;
 use crate::DatabaseStmt;
+use crate::DefineStmt;
 use crate::DiscardStmt;
+use crate::DropStmt;
 use crate::NotifyStmt;
 use crate::OneOrAll;
 use crate::PrepareStmt;
@@ -145,7 +225,9 @@ use crate::ReassignOwnedStmt;
 use crate::RenameStmt;
 use crate::RenameTarget;
 use crate::RoleStmt;
+use crate::RuleStmt;
 use crate::SecurityLabelStmt;
+use crate::SelectStmt;
 use crate::TransactionStmt;
 use crate::UtilityOption;
 use crate::VariableSetStmt;
@@ -0,0 +1,44 @@
+/// Alias: `reloption_elem`
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RelOption {
+    namespace: Option<Str>,
+    name: Str,
+    value: Option<DefinitionArg>,
+}
+
+impl RelOption {
+    pub fn new<T: Into<Str>>(name: T, value: Option<DefinitionArg>) -> Self {
+        Self {
+            namespace: None,
+            name: name.into(),
+            value,
+        }
+    }
+
+    /// E.g.: `toast` in `toast.autovacuum_enabled`.
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    pub fn set_namespace(&mut self, namespace: Option<Str>) -> &mut Self {
+        self.namespace = namespace;
+        self
+    }
+
+    pub fn with_namespace<T: Into<Str>>(mut self, namespace: T) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn value(&self) -> Option<&DefinitionArg> {
+        self.value.as_ref()
+    }
+}
+
+use crate::DefinitionArg;
+use pg_basics::Str;
@@ -1,7 +1,9 @@
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RelationName {
     name: Str,
     schema: Option<SchemaName>,
+    span: Span,
 }
 
 impl RelationName {
@@ -9,7 +11,8 @@ impl RelationName {
     pub fn new<T: Into<Str>>(name: T) -> Self {
         Self {
             name: name.into(),
-            schema: None
+            schema: None,
+            span: Span::default(),
         }
     }
 
@@ -34,6 +37,20 @@ impl RelationName {
     pub fn catalog(&self) -> Option<&str> {
         self.schema().and_then(SchemaName::catalog)
     }
+
+    pub fn set_span(&mut self, span: Span) -> &mut Self {
+        self.span = span;
+        self
+    }
+
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = span;
+        self
+    }
+
+    pub fn span(&self) -> &Span {
+        &self.span
+    }
 }
 
 impl From<Str> for RelationName {
@@ -48,7 +65,8 @@ impl From<&'static str> for RelationName {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SchemaName {
     name: Str,
     catalog: Option<Str>
@@ -94,4 +112,5 @@ impl From<&'static str> for SchemaName {
     }
 }
 
+use crate::Span;
 use pg_basics::Str;
@@ -0,0 +1,37 @@
+/// Alias: `RangeVar` (when used in a `FROM` clause)
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RelationTableRef {
+    relation: RelationExpr,
+    alias: Option<Alias>,
+}
+
+impl RelationTableRef {
+    pub fn new<T: Into<RelationExpr>>(relation: T) -> Self {
+        Self {
+            relation: relation.into(),
+            alias: None,
+        }
+    }
+
+    pub fn relation(&self) -> &RelationExpr {
+        &self.relation
+    }
+
+    pub fn alias(&self) -> Option<&Alias> {
+        self.alias.as_ref()
+    }
+
+    pub fn set_alias(&mut self, alias: Option<Alias>) -> &mut Self {
+        self.alias = alias;
+        self
+    }
+
+    pub fn with_alias<T: Into<Alias>>(mut self, alias: T) -> Self {
+        self.alias = Some(alias.into());
+        self
+    }
+}
+
+use crate::Alias;
+use crate::RelationExpr;
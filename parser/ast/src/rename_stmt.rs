@@ -1,4 +1,5 @@
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RenameStmt {
     target: RenameTarget,
     new_name: Str,
@@ -22,7 +23,8 @@ impl RenameStmt {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RenameTarget {
     Aggregate(AggregateWithArgs),
     Collation(QualifiedName),
@@ -44,6 +46,7 @@ pub enum RenameTarget {
     OperatorFamily(QualifiedName),
     Policy { table: QualifiedName, policy: Str, missing_ok: bool },
     Procedure(FunctionWithArgs),
+    PropertyGraph(QualifiedName),
     Publication(Str),
     /// Aliases:
     /// * `Group`
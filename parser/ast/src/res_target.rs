@@ -0,0 +1,28 @@
+/// Alias: `target_el`
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ResTarget {
+    /// `*`
+    Star,
+    /// Example: `a + 1 AS b`
+    Expr {
+        value: ExprNode,
+        name: Option<Str>,
+    },
+}
+
+impl From<ExprNode> for ResTarget {
+    fn from(value: ExprNode) -> Self {
+        Self::Expr { value, name: None }
+    }
+}
+
+impl From<ExprKind> for ResTarget {
+    fn from(value: ExprKind) -> Self {
+        ExprNode::from(value).into()
+    }
+}
+
+use crate::ExprKind;
+use crate::ExprNode;
+use pg_basics::Str;
@@ -0,0 +1,58 @@
+/// Comparison between two rows, e.g.: `ROW(a, b) < ROW(c, d)`.
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RowCompareExpr {
+    kind: RowCompareKind,
+    left: Vec<ExprNode>,
+    right: Vec<ExprNode>,
+}
+
+impl RowCompareExpr {
+    pub fn new(kind: RowCompareKind, left: Vec<ExprNode>, right: Vec<ExprNode>) -> Self {
+        Self { kind, left, right }
+    }
+
+    pub fn kind(&self) -> RowCompareKind {
+        self.kind
+    }
+
+    pub fn left(&self) -> &[ExprNode] {
+        &self.left
+    }
+
+    pub fn right(&self) -> &[ExprNode] {
+        &self.right
+    }
+}
+
+/// Alias: `RowCompareType`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RowCompareKind {
+    Less,
+    LessEquals,
+    Equals,
+    GreaterEquals,
+    Greater,
+    NotEquals,
+}
+
+impl TryFrom<&Operator> for RowCompareKind {
+    type Error = ();
+
+    /// Only the comparison operators have row-wise semantics.
+    fn try_from(value: &Operator) -> Result<Self, Self::Error> {
+        match value {
+            Operator::Less => Ok(Self::Less),
+            Operator::LessEquals => Ok(Self::LessEquals),
+            Operator::Equals => Ok(Self::Equals),
+            Operator::GreaterEquals => Ok(Self::GreaterEquals),
+            Operator::Greater => Ok(Self::Greater),
+            Operator::NotEquals => Ok(Self::NotEquals),
+            _ => Err(()),
+        }
+    }
+}
+
+use crate::ExprNode;
+use crate::Operator;
@@ -0,0 +1,104 @@
+/// `CREATE [ OR REPLACE ] RULE name AS ON event TO relation [ WHERE condition ] DO [ ALSO | INSTEAD ] actions`.
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RuleStmt {
+    name: Str,
+    relation: RelationName,
+    event: RuleEvent,
+    where_clause: Option<ExprNode>,
+    instead: bool,
+    /// Empty when `NOTHING` was used.
+    actions: Vec<RawStmt>,
+    replace: bool,
+}
+
+impl RuleStmt {
+    pub fn new<N, R>(name: N, relation: R, event: RuleEvent, actions: Vec<RawStmt>) -> Self
+    where
+        N: Into<Str>,
+        R: Into<RelationName>
+    {
+        Self {
+            name: name.into(),
+            relation: relation.into(),
+            event,
+            where_clause: None,
+            instead: false,
+            actions,
+            replace: false,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn relation(&self) -> &RelationName {
+        &self.relation
+    }
+
+    pub fn event(&self) -> RuleEvent {
+        self.event
+    }
+
+    pub fn where_clause(&self) -> Option<&ExprNode> {
+        self.where_clause.as_ref()
+    }
+
+    pub fn set_where_clause(&mut self, where_clause: Option<ExprNode>) -> &mut Self {
+        self.where_clause = where_clause;
+        self
+    }
+
+    pub fn with_where_clause(mut self, where_clause: ExprNode) -> Self {
+        self.where_clause = Some(where_clause);
+        self
+    }
+
+    pub fn instead(&self) -> bool {
+        self.instead
+    }
+
+    pub fn set_instead(&mut self, instead: bool) -> &mut Self {
+        self.instead = instead;
+        self
+    }
+
+    pub fn with_instead(mut self, instead: bool) -> Self {
+        self.instead = instead;
+        self
+    }
+
+    pub fn actions(&self) -> &[RawStmt] {
+        &self.actions
+    }
+
+    pub fn replace(&self) -> bool {
+        self.replace
+    }
+
+    pub fn set_replace(&mut self, replace: bool) -> &mut Self {
+        self.replace = replace;
+        self
+    }
+
+    pub fn with_replace(mut self, replace: bool) -> Self {
+        self.replace = replace;
+        self
+    }
+}
+
+/// Alias: `event`
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RuleEvent {
+    Select,
+    Update,
+    Delete,
+    Insert,
+}
+
+use crate::ExprNode;
+use crate::RawStmt;
+use crate::RelationName;
+use pg_basics::Str;
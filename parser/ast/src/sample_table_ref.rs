@@ -1,5 +1,6 @@
 /// Alias: `RangeTableSample`
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SampleTableRef {
     relation: RelationExpr,
     alias: Option<Alias>,
@@ -48,7 +49,8 @@ impl SampleTableRef {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableSample {
     function_name: QualifiedName,
     args: Vec<ExprNode>,
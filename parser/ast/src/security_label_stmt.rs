@@ -1,5 +1,6 @@
 /// Alias: `SecLabelStmt`
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SecurityLabelStmt {
     target: SecurityLabelTarget,
     provider: Option<Str>,
@@ -24,7 +25,8 @@ impl SecurityLabelStmt {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SecurityLabelTarget {
     AccessMethod(Str),
     Aggregate(AggregateWithArgs),
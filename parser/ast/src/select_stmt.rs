@@ -1,8 +1,95 @@
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct SelectStmt {}
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelectStmt {
+    body: SelectBody,
+    sort_clause: Option<Vec<SortBy>>,
+    limit_offset: Option<ExprNode>,
+    /// `LIMIT ALL` is represented by `NullConst`.
+    limit_count: Option<ExprNode>,
+}
 
 impl SelectStmt {
-    pub fn new() -> Self {
-        todo!()
+    pub fn new<T: Into<SelectBody>>(body: T) -> Self {
+        Self {
+            body: body.into(),
+            sort_clause: None,
+            limit_offset: None,
+            limit_count: None,
+        }
+    }
+
+    pub fn body(&self) -> &SelectBody {
+        &self.body
+    }
+
+    pub fn sort_clause(&self) -> Option<&[SortBy]> {
+        self.sort_clause.as_deref()
+    }
+
+    pub fn set_sort_clause(&mut self, sort_clause: Option<Vec<SortBy>>) -> &mut Self {
+        self.sort_clause = sort_clause;
+        self
+    }
+
+    pub fn with_sort_clause(mut self, sort_clause: Vec<SortBy>) -> Self {
+        self.sort_clause = Some(sort_clause);
+        self
+    }
+
+    pub fn limit_offset(&self) -> Option<&ExprNode> {
+        self.limit_offset.as_ref()
+    }
+
+    pub fn set_limit_offset(&mut self, limit_offset: Option<ExprNode>) -> &mut Self {
+        self.limit_offset = limit_offset;
+        self
+    }
+
+    pub fn with_limit_offset(mut self, limit_offset: ExprNode) -> Self {
+        self.limit_offset = Some(limit_offset);
+        self
+    }
+
+    pub fn limit_count(&self) -> Option<&ExprNode> {
+        self.limit_count.as_ref()
+    }
+
+    pub fn set_limit_count(&mut self, limit_count: Option<ExprNode>) -> &mut Self {
+        self.limit_count = limit_count;
+        self
+    }
+
+    pub fn with_limit_count(mut self, limit_count: ExprNode) -> Self {
+        self.limit_count = Some(limit_count);
+        self
     }
 }
+
+impl From<SimpleSelect> for SelectStmt {
+    fn from(value: SimpleSelect) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Alias: `select_clause`
+#[derive(Debug, Clone, PartialEq, Eq, From, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SelectBody {
+    #[from]
+    Simple(SimpleSelect),
+    /// Example: `VALUES (1, 'one'), (2, 'two')`
+    Values(Vec<Vec<ExprNode>>),
+    /// `UNION`, `INTERSECT` and `EXCEPT`.
+    SetOperation {
+        op: SetOperation,
+        all: bool,
+        left: Box<SelectStmt>,
+        right: Box<SelectStmt>,
+    },
+}
+
+use crate::ExprNode;
+use crate::SetOperation;
+use crate::SimpleSelect;
+use crate::SortBy;
+use derive_more::From;
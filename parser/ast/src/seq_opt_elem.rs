@@ -0,0 +1,24 @@
+/// Shared by `CREATE SEQUENCE`, `ALTER SEQUENCE`, and identity columns.
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SeqOptElem {
+    As(TypeName),
+    Cache(SignedNumber),
+    Cycle(bool),
+    Increment(SignedNumber),
+    /// `None` when `NO MAXVALUE`.
+    MaxValue(Option<SignedNumber>),
+    /// `None` when `NO MINVALUE`.
+    MinValue(Option<SignedNumber>),
+    OwnedBy(QualifiedName),
+    SequenceName(QualifiedName),
+    Start(SignedNumber),
+    /// `None` when restarting from the `START` value.
+    Restart(Option<SignedNumber>),
+    /// `true` when `LOGGED`, and `false` when `UNLOGGED`.
+    Logged(bool),
+}
+
+use crate::SignedNumber;
+use crate::TypeName;
+use pg_basics::QualifiedName;
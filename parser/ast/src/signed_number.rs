@@ -1,4 +1,5 @@
-#[derive(Debug, Clone, Eq, PartialEq, From)]
+#[derive(Debug, Clone, Eq, PartialEq, From, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SignedNumber {
     #[from] IntegerConst(i32),
     NumericConst { value: Box<str>, radix: NumberRadix, negative: bool },
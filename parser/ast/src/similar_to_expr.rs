@@ -0,0 +1,60 @@
+/// `expr [ NOT ] SIMILAR TO pattern [ ESCAPE escape ]`
+///
+/// PG rewrites the pattern into a `similar_to_escape()` call, which is left to the consumers of the AST.
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimilarToExpr {
+    expr: ExprNode,
+    pattern: ExprNode,
+    escape: Option<ExprNode>,
+    not: bool,
+}
+
+impl SimilarToExpr {
+    pub fn new(expr: ExprNode, pattern: ExprNode) -> Self {
+        Self {
+            expr,
+            pattern,
+            escape: None,
+            not: false,
+        }
+    }
+
+    pub fn expr(&self) -> &ExprNode {
+        &self.expr
+    }
+
+    pub fn pattern(&self) -> &ExprNode {
+        &self.pattern
+    }
+
+    pub fn escape(&self) -> Option<&ExprNode> {
+        self.escape.as_ref()
+    }
+
+    pub fn set_escape(&mut self, escape: Option<ExprNode>) -> &mut Self {
+        self.escape = escape;
+        self
+    }
+
+    pub fn with_escape(mut self, escape: ExprNode) -> Self {
+        self.escape = Some(escape);
+        self
+    }
+
+    pub fn not(&self) -> bool {
+        self.not
+    }
+
+    pub fn set_not(&mut self, not: bool) -> &mut Self {
+        self.not = not;
+        self
+    }
+
+    pub fn with_not(mut self, not: bool) -> Self {
+        self.not = not;
+        self
+    }
+}
+
+use crate::ExprNode;
@@ -1,4 +1,5 @@
-#[derive(Debug, Clone, PartialEq, Eq, Into)]
+#[derive(Debug, Clone, PartialEq, Eq, Into, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[into((Str, Type, Option<QualifiedName>))]
 pub struct SimpleColumnDefinition {
     name: Str,
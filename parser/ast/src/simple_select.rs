@@ -0,0 +1,139 @@
+/// `SELECT ... FROM ... WHERE ... GROUP BY ... HAVING ... WINDOW ...`
+#[derive(Debug, Default, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimpleSelect {
+    distinct_clause: Option<DistinctClause>,
+    target_list: Vec<ResTarget>,
+    from_clause: Option<Vec<TableRef>>,
+    where_clause: Option<ExprNode>,
+    group_clause: Option<Vec<ExprNode>>,
+    group_distinct: bool,
+    having_clause: Option<ExprNode>,
+    window_clause: Option<Vec<NamedWindow>>,
+}
+
+impl SimpleSelect {
+    pub fn new(target_list: Vec<ResTarget>) -> Self {
+        Self {
+            target_list,
+            ..Self::default()
+        }
+    }
+
+    pub fn distinct_clause(&self) -> Option<&DistinctClause> {
+        self.distinct_clause.as_ref()
+    }
+
+    pub fn set_distinct_clause(&mut self, distinct_clause: Option<DistinctClause>) -> &mut Self {
+        self.distinct_clause = distinct_clause;
+        self
+    }
+
+    pub fn with_distinct_clause(mut self, distinct_clause: DistinctClause) -> Self {
+        self.distinct_clause = Some(distinct_clause);
+        self
+    }
+
+    pub fn target_list(&self) -> &[ResTarget] {
+        &self.target_list
+    }
+
+    pub fn from_clause(&self) -> Option<&[TableRef]> {
+        self.from_clause.as_deref()
+    }
+
+    pub fn set_from_clause(&mut self, from_clause: Option<Vec<TableRef>>) -> &mut Self {
+        self.from_clause = from_clause;
+        self
+    }
+
+    pub fn with_from_clause(mut self, from_clause: Vec<TableRef>) -> Self {
+        self.from_clause = Some(from_clause);
+        self
+    }
+
+    pub fn where_clause(&self) -> Option<&ExprNode> {
+        self.where_clause.as_ref()
+    }
+
+    pub fn set_where_clause(&mut self, where_clause: Option<ExprNode>) -> &mut Self {
+        self.where_clause = where_clause;
+        self
+    }
+
+    pub fn with_where_clause(mut self, where_clause: ExprNode) -> Self {
+        self.where_clause = Some(where_clause);
+        self
+    }
+
+    pub fn group_clause(&self) -> Option<&[ExprNode]> {
+        self.group_clause.as_deref()
+    }
+
+    pub fn set_group_clause(&mut self, group_clause: Option<Vec<ExprNode>>) -> &mut Self {
+        self.group_clause = group_clause;
+        self
+    }
+
+    pub fn with_group_clause(mut self, group_clause: Vec<ExprNode>) -> Self {
+        self.group_clause = Some(group_clause);
+        self
+    }
+
+    /// `GROUP BY DISTINCT ...`
+    pub fn group_distinct(&self) -> bool {
+        self.group_distinct
+    }
+
+    pub fn set_group_distinct(&mut self, group_distinct: bool) -> &mut Self {
+        self.group_distinct = group_distinct;
+        self
+    }
+
+    pub fn with_group_distinct(mut self, group_distinct: bool) -> Self {
+        self.group_distinct = group_distinct;
+        self
+    }
+
+    pub fn having_clause(&self) -> Option<&ExprNode> {
+        self.having_clause.as_ref()
+    }
+
+    pub fn set_having_clause(&mut self, having_clause: Option<ExprNode>) -> &mut Self {
+        self.having_clause = having_clause;
+        self
+    }
+
+    pub fn with_having_clause(mut self, having_clause: ExprNode) -> Self {
+        self.having_clause = Some(having_clause);
+        self
+    }
+
+    pub fn window_clause(&self) -> Option<&[NamedWindow]> {
+        self.window_clause.as_deref()
+    }
+
+    pub fn set_window_clause(&mut self, window_clause: Option<Vec<NamedWindow>>) -> &mut Self {
+        self.window_clause = window_clause;
+        self
+    }
+
+    pub fn with_window_clause(mut self, window_clause: Vec<NamedWindow>) -> Self {
+        self.window_clause = Some(window_clause);
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DistinctClause {
+    /// `DISTINCT`
+    Distinct,
+    /// `DISTINCT ON ( expr_list )`
+    DistinctOn(Vec<ExprNode>),
+}
+
+use crate::ExprNode;
+use crate::NamedWindow;
+use crate::ResTarget;
+use crate::TableRef;
@@ -0,0 +1,55 @@
+/// Where a node is in the source, like the `location` fields of PG's nodes.
+///
+/// A span isn't part of the node's value:
+/// all spans are equal, so that nodes compare equal regardless of where they were parsed,
+/// or if they were built by hand, in which case there's no location.
+///
+/// The location is boxed, to keep nodes (especially `ExprNode`) small.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span(Option<Box<Location>>);
+
+impl Span {
+    pub fn new(location: Location) -> Self {
+        Self(Some(Box::new(location)))
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        self.0.as_deref()
+    }
+
+    /// Slices the source, if the location is known.
+    pub fn slice<'src>(&self, source: &'src str) -> Option<&'src str> {
+        self.location().map(|loc| loc.slice(source))
+    }
+}
+
+impl From<Location> for Span {
+    fn from(location: Location) -> Self {
+        Self::new(location)
+    }
+}
+
+impl PartialEq for Span {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for Span {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eq() {
+        let span = Span::new(Location::new(3..5, 1, 4));
+
+        assert_eq!(Span::default(), span);
+        assert_eq!(Some("lo"), span.slice("hello"));
+        assert_eq!(None, Span::default().slice("hello"));
+    }
+}
+
+use pg_basics::Location;
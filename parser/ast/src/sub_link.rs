@@ -0,0 +1,102 @@
+/// Subquery within an expression.
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SubLink {
+    kind: SubLinkKind,
+    test_expr: Option<ExprNode>,
+    /// `None` when `kind` is `Any`, and it's an `IN` subquery.
+    operator: Option<QualifiedOperator>,
+    subselect: SelectStmt,
+}
+
+impl SubLink {
+    /// `EXISTS ( SelectStmt )`
+    pub fn exists(subselect: SelectStmt) -> Self {
+        Self::new(SubLinkKind::Exists, None, None, subselect)
+    }
+
+    /// `( SelectStmt )`
+    pub fn expr(subselect: SelectStmt) -> Self {
+        Self::new(SubLinkKind::Expr, None, None, subselect)
+    }
+
+    /// `ARRAY ( SelectStmt )`
+    pub fn array(subselect: SelectStmt) -> Self {
+        Self::new(SubLinkKind::Array, None, None, subselect)
+    }
+
+    /// `test_expr IN ( SelectStmt )`
+    pub fn in_subquery(test_expr: ExprNode, subselect: SelectStmt) -> Self {
+        Self::new(SubLinkKind::Any, Some(test_expr), None, subselect)
+    }
+
+    /// `test_expr operator { ANY | SOME } ( SelectStmt )`
+    pub fn any<T: Into<QualifiedOperator>>(test_expr: ExprNode, operator: T, subselect: SelectStmt) -> Self {
+        Self::new(SubLinkKind::Any, Some(test_expr), Some(operator.into()), subselect)
+    }
+
+    /// `test_expr operator ALL ( SelectStmt )`
+    pub fn all<T: Into<QualifiedOperator>>(test_expr: ExprNode, operator: T, subselect: SelectStmt) -> Self {
+        Self::new(SubLinkKind::All, Some(test_expr), Some(operator.into()), subselect)
+    }
+
+    /// `ROW ( ... ) operator ( SelectStmt )`
+    pub fn row_compare<T: Into<QualifiedOperator>>(test_expr: ExprNode, operator: T, subselect: SelectStmt) -> Self {
+        Self::new(SubLinkKind::RowCompare, Some(test_expr), Some(operator.into()), subselect)
+    }
+
+    pub fn new(
+        kind: SubLinkKind,
+        test_expr: Option<ExprNode>,
+        operator: Option<QualifiedOperator>,
+        subselect: SelectStmt
+    ) -> Self {
+        Self { kind, test_expr, operator, subselect }
+    }
+
+    pub fn kind(&self) -> SubLinkKind {
+        self.kind
+    }
+
+    pub fn test_expr(&self) -> Option<&ExprNode> {
+        self.test_expr.as_ref()
+    }
+
+    pub fn operator(&self) -> Option<&QualifiedOperator> {
+        self.operator.as_ref()
+    }
+
+    pub fn subselect(&self) -> &SelectStmt {
+        &self.subselect
+    }
+
+    pub fn into_subselect(self) -> SelectStmt {
+        self.subselect
+    }
+}
+
+/// Alias: `SubLinkType`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SubLinkKind {
+    /// `EXISTS ( SelectStmt )`
+    Exists,
+    /// `expr operator ALL ( SelectStmt )`
+    All,
+    /// `expr operator { ANY | SOME } ( SelectStmt )`, or `expr IN ( SelectStmt )`
+    Any,
+    /// `( expr, ... ) operator ( SelectStmt )`
+    RowCompare,
+    /// `( SelectStmt )`
+    Expr,
+    /// Multiple column assignment, e.g.: `UPDATE ... SET ( a, b ) = ( SelectStmt )`.
+    MultiExpr,
+    /// `ARRAY ( SelectStmt )`
+    Array,
+    /// Only used by the planner for recursive CTEs, so the parser never produces it.
+    Cte,
+}
+
+use crate::ExprNode;
+use crate::QualifiedOperator;
+use crate::SelectStmt;
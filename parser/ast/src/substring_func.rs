@@ -1,4 +1,5 @@
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SubstringFunc {
     ExplicitCall(Option<Vec<NamedValue>>),
     SqlSyntax(ExprNode, ExprNode, Option<ExprNode>),
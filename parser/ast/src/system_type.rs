@@ -1,12 +1,15 @@
 pub type TypeModifiers = Vec<ExprNode>;
 
-#[derive(Debug, Clone, Eq, PartialEq, Into)]
+#[derive(Debug, Clone, Eq, PartialEq, Into, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[into((TypeName, SetOf, Option<Vec<Option<i32>>>))]
 pub struct Type {
     name: TypeName,
     /// If the type is a table (i.e., set) of records, or just a single record.
     mult: SetOf,
     array_bounds: Option<Vec<Option<i32>>>,
+    #[into(skip)]
+    span: Span,
 }
 
 impl Type {
@@ -15,6 +18,7 @@ impl Type {
             name,
             mult: Default::default(),
             array_bounds: Default::default(),
+            span: Default::default(),
         }
     }
 
@@ -49,9 +53,24 @@ impl Type {
     pub fn mult(&self) -> SetOf {
         self.mult
     }
+
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = span;
+        self
+    }
+
+    pub fn set_span(&mut self, span: Span) -> &mut Self {
+        self.span = span;
+        self
+    }
+
+    pub fn span(&self) -> &Span {
+        &self.span
+    }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, From)]
+#[derive(Debug, Clone, Eq, PartialEq, From, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TypeName {
     Json,
     Bool,
@@ -99,7 +118,8 @@ impl From<TypeName> for Type {
     }
 }
 
-#[derive(Default, Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Default, Debug, Copy, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SetOf {
     /// When the type represents a single record, or scalar
     #[default]
@@ -124,7 +144,8 @@ impl From<SetOf> for bool {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TypeReference {
     field: QualifiedName,
     mult: SetOf
@@ -144,7 +165,8 @@ impl TypeReference {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, From)]
+#[derive(Debug, Clone, Eq, PartialEq, From, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FuncType {
     Type(Type),
     /// When the type is specified with `%TYPE`.
@@ -153,6 +175,7 @@ pub enum FuncType {
 
 use crate::ExprNode;
 use crate::IntervalRange;
+use crate::Span;
 use derive_more::From;
 use derive_more::Into;
 use pg_basics::Oid;
@@ -1,15 +1,22 @@
-#[derive(Debug, Clone, PartialEq, Eq, From)]
+#[derive(Debug, Clone, PartialEq, Eq, From, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TableRef {
+    Relation(RelationTableRef),
+    Join(JoinTableRef),
     XmlTable(XmlTable),
     JsonTable(JsonTable),
     Rows(RowsTableRef),
     Function(FunctionTableRef),
     TableSample(SampleTableRef),
     Subselect(SubselectTableRef),
+    GraphTable(GraphTable),
 }
 
 use crate::FunctionTableRef;
+use crate::GraphTable;
+use crate::JoinTableRef;
 use crate::JsonTable;
+use crate::RelationTableRef;
 use crate::RowsTableRef;
 use crate::SampleTableRef;
 use crate::SubselectTableRef;
@@ -1,4 +1,5 @@
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum IsolationLevel {
     ReadUncommitted,
     ReadCommitted,
@@ -6,7 +7,8 @@ pub enum IsolationLevel {
     Serializable,
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TransactionMode {
     IsolationLevel(IsolationLevel),
     ReadOnly,
@@ -15,7 +17,8 @@ pub enum TransactionMode {
     NotDeferrable,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TransactionStmt {
     Begin(Vec<TransactionMode>),
     /// Semantically identical to `BEGIN`.
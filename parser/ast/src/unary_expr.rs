@@ -1,4 +1,5 @@
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnaryExpr {
     operator: QualifiedOperator,
     operand: ExprNode,
@@ -14,6 +15,14 @@ impl UnaryExpr {
             operand
         }
     }
+
+    pub fn operator(&self) -> &QualifiedOperator {
+        &self.operator
+    }
+
+    pub fn operand(&self) -> &ExprNode {
+        &self.operand
+    }
 }
 
 use crate::ExprNode;
@@ -0,0 +1,710 @@
+/// Visits the children of a node, in declaration order.
+///
+/// Implemented with `#[derive(Walk)]`.
+pub trait Walk {
+
+    fn walk<V>(&self, visitor: &mut V) -> ControlFlow<V::Break>
+    where
+        V: Visit + ?Sized;
+
+    fn walk_mut<V>(&mut self, visitor: &mut V) -> ControlFlow<V::Break>
+    where
+        V: VisitMut + ?Sized;
+}
+
+/// Dispatches a node to its method in `Visit` or `VisitMut`.
+///
+/// Containers (e.g., `Option`, `Vec`, `Box`) dispatch each of their elements,
+/// and leaves (e.g., `Str`, `bool`) have nothing to visit.
+pub trait Accept {
+
+    fn accept<V>(&self, visitor: &mut V) -> ControlFlow<V::Break>
+    where
+        V: Visit + ?Sized;
+
+    fn accept_mut<V>(&mut self, visitor: &mut V) -> ControlFlow<V::Break>
+    where
+        V: VisitMut + ?Sized;
+}
+
+macro_rules! visitor {
+    (
+        $(
+            $node:ty => $method:ident
+        ),+
+        $(,)?
+    ) => {
+        /// Read-only traversal of the AST.
+        ///
+        /// Every method defaults to walking the node's children,
+        /// so an override has to call [`Walk::walk()`] to keep descending.
+        /// Returning `ControlFlow::Break` stops the whole traversal.
+        ///
+        /// E.g., finding the first function call:
+        /// ```
+        /// # use core::ops::ControlFlow;
+        /// # use pg_ast::{Accept, FuncCall, RawStmt, Visit};
+        /// struct FirstFuncCall;
+        ///
+        /// impl Visit for FirstFuncCall {
+        ///     type Break = FuncCall;
+        ///
+        ///     fn visit_func_call(&mut self, node: &FuncCall) -> ControlFlow<FuncCall> {
+        ///         ControlFlow::Break(node.clone())
+        ///     }
+        /// }
+        ///
+        /// fn first_func_call(stmt: &RawStmt) -> Option<FuncCall> {
+        ///     stmt.accept(&mut FirstFuncCall).break_value()
+        /// }
+        /// ```
+        pub trait Visit {
+            type Break;
+
+            $(
+                fn $method(&mut self, node: &$node) -> ControlFlow<Self::Break> {
+                    node.walk(self)
+                }
+            )+
+        }
+
+        /// Mutable traversal of the AST.
+        ///
+        /// Same as [`Visit`], but nodes can be rewritten in place.
+        pub trait VisitMut {
+            type Break;
+
+            $(
+                fn $method(&mut self, node: &mut $node) -> ControlFlow<Self::Break> {
+                    node.walk_mut(self)
+                }
+            )+
+        }
+
+        $(
+            impl Accept for $node {
+
+                fn accept<V>(&self, visitor: &mut V) -> ControlFlow<V::Break>
+                where
+                    V: Visit + ?Sized
+                {
+                    visitor.$method(self)
+                }
+
+                fn accept_mut<V>(&mut self, visitor: &mut V) -> ControlFlow<V::Break>
+                where
+                    V: VisitMut + ?Sized
+                {
+                    visitor.$method(self)
+                }
+            }
+        )+
+
+        /// Node names, and the methods that visit them.
+        #[cfg(test)]
+        const NODE_METHODS: &[(&str, &str)] = &[
+            $( (stringify!($node), stringify!($method)) ),+
+        ];
+
+        /// Records the name of every visited node, with both `Visit` and `VisitMut`.
+        #[cfg(test)]
+        #[derive(Default)]
+        struct NodeNames(Vec<&'static str>);
+
+        #[cfg(test)]
+        impl Visit for NodeNames {
+            type Break = ();
+
+            $(
+                fn $method(&mut self, node: &$node) -> ControlFlow<()> {
+                    self.0.push(stringify!($node));
+                    node.walk(self)
+                }
+            )+
+        }
+
+        #[cfg(test)]
+        impl VisitMut for NodeNames {
+            type Break = ();
+
+            $(
+                fn $method(&mut self, node: &mut $node) -> ControlFlow<()> {
+                    self.0.push(stringify!($node));
+                    node.walk_mut(self)
+                }
+            )+
+        }
+    };
+}
+
+visitor! {
+    AccessMethodKind => visit_access_method_kind,
+    AccessPrivilege => visit_access_privilege,
+    AclOption => visit_acl_option,
+    AddDrop => visit_add_drop,
+    AggregateWithArgs => visit_aggregate_with_args,
+    Alias => visit_alias,
+    AlterCompositeTypeStmt => visit_alter_composite_type_stmt,
+    AlterDatabaseSetStmt => visit_alter_database_set_stmt,
+    AlterDatabaseStmt => visit_alter_database_stmt,
+    AlterDefaultPrivilegesStmt => visit_alter_default_privileges_stmt,
+    AlterDomainAction => visit_alter_domain_action,
+    AlterDomainStmt => visit_alter_domain_stmt,
+    AlterEnumAction => visit_alter_enum_action,
+    AlterEnumStmt => visit_alter_enum_stmt,
+    AlterEventTrigStmt => visit_alter_event_trig_stmt,
+    AlterExtensionContentsStmt => visit_alter_extension_contents_stmt,
+    AlterExtensionContentsTarget => visit_alter_extension_contents_target,
+    AlterExtensionStmt => visit_alter_extension_stmt,
+    AlterFunctionKind => visit_alter_function_kind,
+    AlterFunctionOption => visit_alter_function_option,
+    AlterFunctionStmt => visit_alter_function_stmt,
+    AlterObjectDependsStmt => visit_alter_object_depends_stmt,
+    AlterObjectDependsTarget => visit_alter_object_depends_target,
+    AlterObjectSchemaStmt => visit_alter_object_schema_stmt,
+    AlterObjectSchemaTarget => visit_alter_object_schema_target,
+    AlterOpFamilyAction => visit_alter_op_family_action,
+    AlterOpFamilyStmt => visit_alter_op_family_stmt,
+    AlterOperatorStmt => visit_alter_operator_stmt,
+    AlterOwnerStmt => visit_alter_owner_stmt,
+    AlterOwnerTarget => visit_alter_owner_target,
+    AlterPropGraphAction => visit_alter_prop_graph_action,
+    AlterPropGraphStmt => visit_alter_prop_graph_stmt,
+    AlterPropGraphTableAction => visit_alter_prop_graph_table_action,
+    AlterRoleOption => visit_alter_role_option,
+    AlterRoleSetStmt => visit_alter_role_set_stmt,
+    AlterRoleStmt => visit_alter_role_stmt,
+    AlterSeqStmt => visit_alter_seq_stmt,
+    AlterSystemStmt => visit_alter_system_stmt,
+    AlterTextSearchConfigurationStmt => visit_alter_text_search_configuration_stmt,
+    AlterTextSearchDictionaryStmt => visit_alter_text_search_dictionary_stmt,
+    AlterTypeCmd => visit_alter_type_cmd,
+    AlterUserMappingStmt => visit_alter_user_mapping_stmt,
+    AlterdbOption => visit_alterdb_option,
+    AlterdbOptionKind => visit_alterdb_option_kind,
+    ArrayCompareExpr => visit_array_compare_expr,
+    ArrayCompareKind => visit_array_compare_kind,
+    BetweenExpr => visit_between_expr,
+    BinaryExpr => visit_binary_expr,
+    BoolExpr => visit_bool_expr,
+    BoolTestKind => visit_bool_test_kind,
+    BooleanTest => visit_boolean_test,
+    BooleanOrString => visit_boolean_or_string,
+    CaseExpr => visit_case_expr,
+    CaseWhen => visit_case_when,
+    CastConversion => visit_cast_conversion,
+    CoercionContext => visit_coercion_context,
+    CollateExpr => visit_collate_expr,
+    CollationDefinition => visit_collation_definition,
+    ColumnRef => visit_column_ref,
+    CommentStmt => visit_comment_stmt,
+    CommentTarget => visit_comment_target,
+    CompositeTypeStmt => visit_composite_type_stmt,
+    ConstraintsSetMode => visit_constraints_set_mode,
+    ConstraintsSetStmt => visit_constraints_set_stmt,
+    CreateAccessMethodStmt => visit_create_access_method_stmt,
+    CreateCastStmt => visit_create_cast_stmt,
+    CreateConversionStmt => visit_create_conversion_stmt,
+    CreateDatabaseStmt => visit_create_database_stmt,
+    CreateDomainStmt => visit_create_domain_stmt,
+    CreateEnumStmt => visit_create_enum_stmt,
+    CreateExtensionOption => visit_create_extension_option,
+    CreateExtensionStmt => visit_create_extension_stmt,
+    CreateOpClassStmt => visit_create_op_class_stmt,
+    CreateOpFamilyStmt => visit_create_op_family_stmt,
+    CreatePLangStmt => visit_create_plang_stmt,
+    CreatePropGraphStmt => visit_create_prop_graph_stmt,
+    CreateRangeStmt => visit_create_range_stmt,
+    CreateRoleOption => visit_create_role_option,
+    CreateRoleStmt => visit_create_role_stmt,
+    CreateSchemaStmt => visit_create_schema_stmt,
+    CreateSeqStmt => visit_create_seq_stmt,
+    CreateStatsStmt => visit_create_stats_stmt,
+    CreateTableSpaceStmt => visit_create_table_space_stmt,
+    CreateTransformStmt => visit_create_transform_stmt,
+    CreateUserMappingStmt => visit_create_user_mapping_stmt,
+    CreatedbOption => visit_createdb_option,
+    CreatedbOptionKind => visit_createdb_option_kind,
+    CreatedbOptionValue => visit_createdb_option_value,
+    CurrentRowEnd => visit_current_row_end,
+    DatabaseStmt => visit_database_stmt,
+    DefineStmt => visit_define_stmt,
+    DefinitionArg => visit_definition_arg,
+    DefinitionElem => visit_definition_elem,
+    DiscardStmt => visit_discard_stmt,
+    DistinctClause => visit_distinct_clause,
+    DomainConstraint => visit_domain_constraint,
+    DomainConstraintKind => visit_domain_constraint_kind,
+    DropBehavior => visit_drop_behavior,
+    DropStmt => visit_drop_stmt,
+    DropTarget => visit_drop_target,
+    EdgeDirection => visit_edge_direction,
+    ElementPattern => visit_element_pattern,
+    EnumValuePosition => visit_enum_value_position,
+    EventTriggerState => visit_event_trigger_state,
+    ExprKind => visit_expr_kind,
+    ExprNode => visit_expr_node,
+    ExtractArg => visit_extract_arg,
+    ExtractFunc => visit_extract_func,
+    FollowingEnd => visit_following_end,
+    FrameExtent => visit_frame_extent,
+    FuncAliasColumn => visit_func_alias_column,
+    FuncArgsKind => visit_func_args_kind,
+    FuncArgsOrder => visit_func_args_order,
+    FuncCall => visit_func_call,
+    FuncCallExpr => visit_func_call_expr,
+    FuncExprWindowless => visit_func_expr_windowless,
+    FuncType => visit_func_type,
+    FunctionParameter => visit_function_parameter,
+    FunctionParameterMode => visit_function_parameter_mode,
+    FunctionTableRef => visit_function_table_ref,
+    FunctionWithArgs => visit_function_with_args,
+    GeneratedWhen => visit_generated_when,
+    GenericOption => visit_generic_option,
+    GenericOptionKind => visit_generic_option_kind,
+    GrantOption => visit_grant_option,
+    GrantStmt => visit_grant_stmt,
+    GraphPattern => visit_graph_pattern,
+    GraphTable => visit_graph_table,
+    IdentityConstraint => visit_identity_constraint,
+    InExpr => visit_in_expr,
+    Indirection => visit_indirection,
+    IndirectionExpr => visit_indirection_expr,
+    IntervalRange => visit_interval_range,
+    IsolationLevel => visit_isolation_level,
+    JoinCondition => visit_join_condition,
+    JoinTableRef => visit_join_table_ref,
+    JoinType => visit_join_type,
+    JsonArrayAgg => visit_json_array_agg,
+    JsonArrayAggExpr => visit_json_array_agg_expr,
+    JsonBehavior => visit_json_behavior,
+    JsonBehaviorClause => visit_json_behavior_clause,
+    JsonEncoding => visit_json_encoding,
+    JsonExistsExpr => visit_json_exists_expr,
+    JsonFormat => visit_json_format,
+    JsonFormatKind => visit_json_format_kind,
+    JsonFunc => visit_json_func,
+    JsonKeyValue => visit_json_key_value,
+    JsonObjectAgg => visit_json_object_agg,
+    JsonObjectAggExpr => visit_json_object_agg_expr,
+    JsonObjectArgs => visit_json_object_args,
+    JsonObjectExpr => visit_json_object_expr,
+    JsonOutput => visit_json_output,
+    JsonQueryExpr => visit_json_query_expr,
+    JsonQuotes => visit_json_quotes,
+    JsonSerializeExpr => visit_json_serialize_expr,
+    JsonTable => visit_json_table,
+    JsonTableColumnDefinition => visit_json_table_column_definition,
+    JsonTableExistsColumn => visit_json_table_exists_column,
+    JsonTableNestedColumn => visit_json_table_nested_column,
+    JsonTablePathSpec => visit_json_table_path_spec,
+    JsonTableRegularColumn => visit_json_table_regular_column,
+    JsonValueExpr => visit_json_value_expr,
+    JsonValueFunc => visit_json_value_func,
+    JsonWrapperBehavior => visit_json_wrapper_behavior,
+    LabelExpression => visit_label_expression,
+    NamedValue => visit_named_value,
+    NamedWindow => visit_named_window,
+    NormalizeFunc => visit_normalize_func,
+    NotifyStmt => visit_notify_stmt,
+    NullTreatment => visit_null_treatment,
+    NumericSpec => visit_numeric_spec,
+    OpClassDrop => visit_op_class_drop,
+    OpClassItem => visit_op_class_item,
+    OpClassPurpose => visit_op_class_purpose,
+    Operator => visit_operator,
+    OperatorWithArgs => visit_operator_with_args,
+    OverClause => visit_over_clause,
+    OverlayFunc => visit_overlay_func,
+    OverlaySqlArgs => visit_overlay_sql_args,
+    PLangHandler => visit_plang_handler,
+    PathElement => visit_path_element,
+    PositionFunc => visit_position_func,
+    PrecedingEnd => visit_preceding_end,
+    PrepareStmt => visit_prepare_stmt,
+    Presence => visit_presence,
+    PrivilegeDefaultsTarget => visit_privilege_defaults_target,
+    PrivilegeTarget => visit_privilege_target,
+    PropGraphEdgeTable => visit_prop_graph_edge_table,
+    PropGraphElementKind => visit_prop_graph_element_kind,
+    PropGraphLabel => visit_prop_graph_label,
+    PropGraphProperties => visit_prop_graph_properties,
+    PropGraphVertexReference => visit_prop_graph_vertex_reference,
+    PropGraphVertexTable => visit_prop_graph_vertex_table,
+    QualifiedOperator => visit_qualified_operator,
+    RangeFunction => visit_range_function,
+    RangeVar => visit_range_var,
+    RawStmt => visit_raw_stmt,
+    ReassignOwnedStmt => visit_reassign_owned_stmt,
+    RelOption => visit_rel_option,
+    RelationExpr => visit_relation_expr,
+    RelationName => visit_relation_name,
+    RelationPersistence => visit_relation_persistence,
+    RelationTableRef => visit_relation_table_ref,
+    RenameStmt => visit_rename_stmt,
+    RenameTarget => visit_rename_target,
+    ResTarget => visit_res_target,
+    RoleKind => visit_role_kind,
+    RoleSpec => visit_role_spec,
+    RoleStmt => visit_role_stmt,
+    RowCompareExpr => visit_row_compare_expr,
+    RowCompareKind => visit_row_compare_kind,
+    RowsTableRef => visit_rows_table_ref,
+    RuleEvent => visit_rule_event,
+    RuleStmt => visit_rule_stmt,
+    SampleTableRef => visit_sample_table_ref,
+    SchemaName => visit_schema_name,
+    SecurityLabelStmt => visit_security_label_stmt,
+    SecurityLabelTarget => visit_security_label_target,
+    SelectBody => visit_select_body,
+    SelectStmt => visit_select_stmt,
+    SeqOptElem => visit_seq_opt_elem,
+    SetOf => visit_set_of,
+    SetOperation => visit_set_operation,
+    SetResetClause => visit_set_reset_clause,
+    SetRest => visit_set_rest,
+    SetRestMore => visit_set_rest_more,
+    SignedNumber => visit_signed_number,
+    SimilarToExpr => visit_similar_to_expr,
+    SimpleColumnDefinition => visit_simple_column_definition,
+    SimpleSelect => visit_simple_select,
+    SortBy => visit_sort_by,
+    SortDirection => visit_sort_direction,
+    SortNulls => visit_sort_nulls,
+    SpecificAccessPrivilege => visit_specific_access_privilege,
+    SqlFunction => visit_sql_function,
+    StatsElem => visit_stats_elem,
+    StringTypecastExpr => visit_string_typecast_expr,
+    SubLink => visit_sub_link,
+    SubLinkKind => visit_sub_link_kind,
+    SubselectTableRef => visit_subselect_table_ref,
+    SubstringFunc => visit_substring_func,
+    TableRef => visit_table_ref,
+    TableSample => visit_table_sample,
+    TextSearchMappingAction => visit_text_search_mapping_action,
+    TransactionMode => visit_transaction_mode,
+    TransactionStmt => visit_transaction_stmt,
+    Transform => visit_transform,
+    TrimFunc => visit_trim_func,
+    TrimSide => visit_trim_side,
+    Type => visit_type,
+    TypeName => visit_type_name,
+    TypeReference => visit_type_reference,
+    Typecast => visit_typecast,
+    TypecastExpr => visit_typecast_expr,
+    UnaryExpr => visit_unary_expr,
+    UnicodeNormalForm => visit_unicode_normal_form,
+    UniqueNullTreatment => visit_unique_null_treatment,
+    UtilityOption => visit_utility_option,
+    UtilityOptionName => visit_utility_option_name,
+    VarValue => visit_var_value,
+    VariableSetStmt => visit_variable_set_stmt,
+    VariableTarget => visit_variable_target,
+    Volatility => visit_volatility,
+    WindowDefinition => visit_window_definition,
+    WindowExclusion => visit_window_exclusion,
+    WindowFrame => visit_window_frame,
+    WindowFrameKind => visit_window_frame_kind,
+    XmlElement => visit_xml_element,
+    XmlExists => visit_xml_exists,
+    XmlNodeKind => visit_xml_node_kind,
+    XmlParse => visit_xml_parse,
+    XmlProcessingInstruction => visit_xml_processing_instruction,
+    XmlRoot => visit_xml_root,
+    XmlSerialize => visit_xml_serialize,
+    XmlStandalone => visit_xml_standalone,
+    XmlTable => visit_xml_table,
+    XmlTableColumn => visit_xml_table_column,
+    XmlTableColumnDefinition => visit_xml_table_column_definition,
+    XmlTableColumnKind => visit_xml_table_column_kind,
+    XmlWhitespaceOption => visit_xml_whitespace_option,
+    ZoneValue => visit_zone_value,
+}
+
+/// Generic wrappers are transparent: only their contents are visited.
+macro_rules! transparent {
+    ( $( $wrapper:ident < $($param:ident),+ > ),+ $(,)? ) => {
+        $(
+            impl<$($param: Accept),+> Accept for $wrapper<$($param),+> {
+
+                fn accept<V>(&self, visitor: &mut V) -> ControlFlow<V::Break>
+                where
+                    V: Visit + ?Sized
+                {
+                    self.walk(visitor)
+                }
+
+                fn accept_mut<V>(&mut self, visitor: &mut V) -> ControlFlow<V::Break>
+                where
+                    V: VisitMut + ?Sized
+                {
+                    self.walk_mut(visitor)
+                }
+            }
+        )+
+    };
+}
+
+transparent! {
+    DefaultableValue<T>,
+    OneOrAll<T>,
+    OneOrBoth<L, R>,
+    ValueOrDefault<T>,
+}
+
+macro_rules! leaf {
+    ( $( $leaf:ty ),+ $(,)? ) => {
+        $(
+            impl Accept for $leaf {
+
+                fn accept<V>(&self, _: &mut V) -> ControlFlow<V::Break>
+                where
+                    V: Visit + ?Sized
+                {
+                    Continue(())
+                }
+
+                fn accept_mut<V>(&mut self, _: &mut V) -> ControlFlow<V::Break>
+                where
+                    V: VisitMut + ?Sized
+                {
+                    Continue(())
+                }
+            }
+        )+
+    };
+}
+
+leaf! {
+    bool,
+    i16,
+    i32,
+    u32,
+    NonZero<u16>,
+    str,
+    NonNegative,
+    NumberRadix,
+    Span,
+    Str,
+}
+
+impl<T: Accept + ?Sized> Accept for Box<T> {
+
+    fn accept<V>(&self, visitor: &mut V) -> ControlFlow<V::Break>
+    where
+        V: Visit + ?Sized
+    {
+        (**self).accept(visitor)
+    }
+
+    fn accept_mut<V>(&mut self, visitor: &mut V) -> ControlFlow<V::Break>
+    where
+        V: VisitMut + ?Sized
+    {
+        (**self).accept_mut(visitor)
+    }
+}
+
+impl<T: Accept> Accept for Option<T> {
+
+    fn accept<V>(&self, visitor: &mut V) -> ControlFlow<V::Break>
+    where
+        V: Visit + ?Sized
+    {
+        match self {
+            Some(value) => value.accept(visitor),
+            None => Continue(()),
+        }
+    }
+
+    fn accept_mut<V>(&mut self, visitor: &mut V) -> ControlFlow<V::Break>
+    where
+        V: VisitMut + ?Sized
+    {
+        match self {
+            Some(value) => value.accept_mut(visitor),
+            None => Continue(()),
+        }
+    }
+}
+
+impl<T: Accept> Accept for Vec<T> {
+
+    fn accept<V>(&self, visitor: &mut V) -> ControlFlow<V::Break>
+    where
+        V: Visit + ?Sized
+    {
+        self.iter().try_for_each(|value| value.accept(visitor))
+    }
+
+    fn accept_mut<V>(&mut self, visitor: &mut V) -> ControlFlow<V::Break>
+    where
+        V: VisitMut + ?Sized
+    {
+        self.iter_mut().try_for_each(|value| value.accept_mut(visitor))
+    }
+}
+
+impl<A: Accept, B: Accept> Accept for (A, B) {
+
+    fn accept<V>(&self, visitor: &mut V) -> ControlFlow<V::Break>
+    where
+        V: Visit + ?Sized
+    {
+        self.0.accept(visitor)?;
+        self.1.accept(visitor)
+    }
+
+    fn accept_mut<V>(&mut self, visitor: &mut V) -> ControlFlow<V::Break>
+    where
+        V: VisitMut + ?Sized
+    {
+        self.0.accept_mut(visitor)?;
+        self.1.accept_mut(visitor)
+    }
+}
+
+impl<T: Accept> Accept for Located<T> {
+
+    fn accept<V>(&self, visitor: &mut V) -> ControlFlow<V::Break>
+    where
+        V: Visit + ?Sized
+    {
+        self.0.accept(visitor)
+    }
+
+    fn accept_mut<V>(&mut self, visitor: &mut V) -> ControlFlow<V::Break>
+    where
+        V: VisitMut + ?Sized
+    {
+        self.0.accept_mut(visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ops::ControlFlow::Break;
+
+    fn func_call(name: &'static str) -> ExprNode {
+        let args = FuncArgsKind::Empty { order_within_group: None };
+        FuncCall::new(vec![name.into()], args).into()
+    }
+
+    fn select_from(relation: &'static str) -> SelectStmt {
+        SimpleSelect::new(vec![ResTarget::Star])
+            .with_from_clause(vec![RelationTableRef::new(relation).into()])
+            .into()
+    }
+
+    struct FuncNames(Vec<Str>);
+
+    impl Visit for FuncNames {
+        type Break = ();
+
+        fn visit_func_call(&mut self, node: &FuncCall) -> ControlFlow<()> {
+            self.0.extend_from_slice(node.name());
+            node.walk(self)
+        }
+    }
+
+    struct FirstFuncName;
+
+    impl Visit for FirstFuncName {
+        type Break = Str;
+
+        fn visit_func_call(&mut self, node: &FuncCall) -> ControlFlow<Str> {
+            Break(node.name()[0].clone())
+        }
+    }
+
+    struct RenameRelation;
+
+    impl VisitMut for RenameRelation {
+        type Break = ();
+
+        fn visit_relation_name(&mut self, node: &mut RelationName) -> ControlFlow<()> {
+            *node = RelationName::new("bar");
+            Continue(())
+        }
+    }
+
+    #[test]
+    fn test_visit() {
+        let expr: ExprNode = BinaryExpr::new(Operator::Addition, func_call("f"), func_call("g")).into();
+
+        let mut visitor = FuncNames(Vec::new());
+        assert_eq!(Continue(()), expr.accept(&mut visitor));
+        assert_eq!(vec![Str::from("f"), Str::from("g")], visitor.0);
+    }
+
+    #[test]
+    fn test_visit_break() {
+        let expr: ExprNode = BinaryExpr::new(Operator::Addition, func_call("f"), func_call("g")).into();
+
+        assert_eq!(Break("f".into()), expr.accept(&mut FirstFuncName));
+    }
+
+    #[test]
+    fn test_visit_method_names() {
+        for (node, method) in NODE_METHODS {
+
+            // Consecutive capitals are one word, e.g.: `CreatePLangStmt` => `create_plang_stmt`.
+            let mut expected = String::from("visit");
+            let mut prev_upper = false;
+            for c in node.chars() {
+                if c.is_ascii_uppercase() && !prev_upper {
+                    expected.push('_');
+                }
+                prev_upper = c.is_ascii_uppercase();
+                expected.push(c.to_ascii_lowercase());
+            }
+
+            assert_eq!(expected, *method, "{node} is visited by the wrong method");
+        }
+    }
+
+    #[test]
+    fn test_visit_and_visit_mut_node_names() {
+        let where_clause = BinaryExpr::new(Operator::Addition, func_call("f"), ExprKind::IntegerConst(1).into());
+        let mut stmt: RawStmt = SelectStmt::from(
+            SimpleSelect::new(vec![ResTarget::Star])
+                .with_from_clause(vec![RelationTableRef::new("foo").into()])
+                .with_where_clause(where_clause.into())
+        ).into();
+
+        let mut visitor = NodeNames::default();
+        assert_eq!(Continue(()), stmt.accept(&mut visitor));
+        let actual = visitor.0;
+
+        let mut visitor = NodeNames::default();
+        assert_eq!(Continue(()), stmt.accept_mut(&mut visitor));
+        assert_eq!(actual, visitor.0);
+        let expected = vec![
+            "RawStmt", "SelectStmt", "SelectBody", "SimpleSelect",
+            "ResTarget",
+            "TableRef", "RelationTableRef", "RelationExpr", "RelationName",
+            "ExprNode", "ExprKind", "BinaryExpr", "QualifiedOperator", "Operator",
+            "ExprNode", "ExprKind", "FuncCall", "FuncArgsKind",
+            "ExprNode", "ExprKind",
+        ];
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_visit_mut() {
+        let mut stmt = select_from("foo");
+
+        assert_eq!(Continue(()), stmt.accept_mut(&mut RenameRelation));
+        assert_eq!(select_from("bar"), stmt);
+    }
+}
+
+use crate::*;
+use core::num::NonZero;
+use core::ops::ControlFlow;
+use core::ops::ControlFlow::Continue;
+use pg_basics::Located;
+use pg_basics::NonNegative;
+use pg_basics::NumberRadix;
+use pg_basics::Str;
@@ -1,4 +1,5 @@
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WindowDefinition {
     name: Option<Str>,
     partition_clause: Option<Vec<ExprNode>>,
@@ -33,6 +34,31 @@ impl WindowDefinition {
     }
 }
 
+/// A window declared in the `WINDOW` clause of a `SELECT`.
+#[derive(Debug, Clone, PartialEq, Eq, Walk)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NamedWindow {
+    name: Str,
+    definition: WindowDefinition,
+}
+
+impl NamedWindow {
+    pub fn new<T: Into<Str>>(name: T, definition: WindowDefinition) -> Self {
+        Self {
+            name: name.into(),
+            definition,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn definition(&self) -> &WindowDefinition {
+        &self.definition
+    }
+}
+
 use crate::ExprNode;
 use crate::SortBy;
 use crate::WindowFrame;
@@ -0,0 +1,277 @@
+/// An expression under construction, that converts into an [`ExprNode`].
+///
+/// Arithmetic uses the operators, e.g., `col("a") + 1`, and `!expr` is `NOT expr`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Expr(ExprNode);
+
+/// E.g.: `a`
+pub fn col<T: Into<Str>>(name: T) -> Expr {
+    ColumnRef::SingleName(name.into()).into()
+}
+
+/// E.g.: `t.a`
+pub fn qualified_col<T, C>(table: T, name: C) -> Expr
+where
+    T: Into<Str>,
+    C: Into<Str>
+{
+    ColumnRef::Name(vec![table.into(), name.into()]).into()
+}
+
+/// E.g.: `$1`
+pub fn param(index: i32) -> Expr {
+    ExprKind::ParamRef { index }.into()
+}
+
+pub fn int(value: i32) -> Expr {
+    ExprKind::IntegerConst(value).into()
+}
+
+pub fn string<T: Into<Box<str>>>(value: T) -> Expr {
+    ExprKind::StringConst(value.into()).into()
+}
+
+pub fn boolean(value: bool) -> Expr {
+    ExprKind::BooleanConst(value).into()
+}
+
+pub fn null() -> Expr {
+    ExprKind::NullConst.into()
+}
+
+/// E.g.: `lower(a)`
+pub fn func<T, A>(name: T, args: A) -> Expr
+where
+    T: Into<Str>,
+    A: IntoIterator,
+    A::Item: Into<Expr>
+{
+    let args: Vec<_> = args.into_iter()
+        .map(|arg| {
+            // Built nodes don't have a source, like the ones in the deparser's round trip.
+            let location = Location::new(0..0, 0, 0);
+            Located(NamedValue::unnamed(arg.into().0), location)
+        })
+        .collect();
+
+    let args = if args.is_empty() {
+        FuncArgsKind::Empty { order_within_group: None }
+    } else {
+        FuncArgsKind::All { args, order: None }
+    };
+
+    FuncCallExpr::new(FuncCall::new(vec![name.into()], args)).into()
+}
+
+/// `count(*)`
+pub fn count_star() -> Expr {
+    let func = FuncCall::new(vec!["count".into()], FuncArgsKind::Wildcard { order_within_group: None });
+    FuncCallExpr::new(func).into()
+}
+
+impl Expr {
+
+    pub fn eq<T: Into<Expr>>(self, other: T) -> Expr {
+        self.binary(Operator::Equals, other)
+    }
+
+    pub fn ne<T: Into<Expr>>(self, other: T) -> Expr {
+        self.binary(Operator::NotEquals, other)
+    }
+
+    pub fn lt<T: Into<Expr>>(self, other: T) -> Expr {
+        self.binary(Operator::Less, other)
+    }
+
+    pub fn le<T: Into<Expr>>(self, other: T) -> Expr {
+        self.binary(Operator::LessEquals, other)
+    }
+
+    pub fn gt<T: Into<Expr>>(self, other: T) -> Expr {
+        self.binary(Operator::Greater, other)
+    }
+
+    pub fn ge<T: Into<Expr>>(self, other: T) -> Expr {
+        self.binary(Operator::GreaterEquals, other)
+    }
+
+    pub fn like<T: Into<Expr>>(self, pattern: T) -> Expr {
+        self.binary(Operator::Like, pattern)
+    }
+
+    pub fn and<T: Into<Expr>>(self, other: T) -> Expr {
+        BoolExpr::and(self.0, other.into().0).into()
+    }
+
+    pub fn or<T: Into<Expr>>(self, other: T) -> Expr {
+        BoolExpr::or(self.0, other.into().0).into()
+    }
+
+    /// E.g.: `a IN (1, 2)`
+    pub fn is_in<T>(self, list: T) -> Expr
+    where
+        T: IntoIterator,
+        T::Item: Into<Expr>
+    {
+        let list = list.into_iter()
+            .map(|item| item.into().0)
+            .collect();
+
+        InExpr::new(self.0, list).into()
+    }
+
+    /// E.g.: `a::bigint`
+    pub fn cast<T: Into<Type>>(self, type_name: T) -> Expr {
+        TypecastExpr::new(self.0, type_name).into()
+    }
+
+    /// A target with a name, e.g.: `a + 1 AS b`
+    pub fn alias<T: Into<Str>>(self, name: T) -> ResTarget {
+        ResTarget::Expr { value: self.0, name: Some(name.into()) }
+    }
+
+    pub fn asc(self) -> SortBy {
+        SortBy::new(self.0, Some(SortDirection::Ascending), None)
+    }
+
+    pub fn desc(self) -> SortBy {
+        SortBy::new(self.0, Some(SortDirection::Descending), None)
+    }
+
+    fn binary<T: Into<Expr>>(self, operator: Operator, right: T) -> Expr {
+        BinaryExpr::new(operator, self.0, right.into().0).into()
+    }
+}
+
+macro_rules! binary_ops {
+    ( $( $trait:ident :: $method:ident => $operator:ident ),+ $(,)? ) => {
+        $(
+            impl<T: Into<Expr>> $trait<T> for Expr {
+                type Output = Expr;
+
+                fn $method(self, right: T) -> Expr {
+                    self.binary(Operator::$operator, right)
+                }
+            }
+        )+
+    };
+}
+
+binary_ops! {
+    Add::add => Addition,
+    Sub::sub => Subtraction,
+    Mul::mul => Multiplication,
+    Div::div => Division,
+    Rem::rem => Modulo,
+}
+
+impl Not for Expr {
+    type Output = Expr;
+
+    fn not(self) -> Expr {
+        BoolExpr::not(self.0).into()
+    }
+}
+
+macro_rules! from_node {
+    ( $( $node:ty ),+ $(,)? ) => {
+        $(
+            impl From<$node> for Expr {
+                fn from(value: $node) -> Self {
+                    Self(value.into())
+                }
+            }
+        )+
+    };
+}
+
+from_node! {
+    BinaryExpr,
+    BoolExpr,
+    ColumnRef,
+    ExprKind,
+    ExprNode,
+    FuncCall,
+    FuncCallExpr,
+    InExpr,
+    TypecastExpr,
+}
+
+impl From<i32> for Expr {
+    fn from(value: i32) -> Self {
+        int(value)
+    }
+}
+
+impl From<bool> for Expr {
+    fn from(value: bool) -> Self {
+        boolean(value)
+    }
+}
+
+impl From<Expr> for ExprNode {
+    fn from(value: Expr) -> Self {
+        value.0
+    }
+}
+
+impl From<Expr> for ResTarget {
+    fn from(value: Expr) -> Self {
+        value.0.into()
+    }
+}
+
+impl From<Expr> for SortBy {
+    fn from(value: Expr) -> Self {
+        SortBy::new(value.0, None, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pg_ast::TypeName;
+    use pg_deparser::Deparse;
+    use test_case::test_case;
+
+    #[test_case(col("a").eq(param(1)), "a = $1")]
+    #[test_case(qualified_col("t", "a").ne(string("x")), "t.a <> 'x'")]
+    #[test_case(col("a").lt(1).or(col("a").ge(10)), "a < 1 OR a >= 10")]
+    #[test_case((col("a") + 1) * 2, "(a + 1) * 2")]
+    #[test_case(!col("a").le(col("b")).and(col("c")), "NOT (a <= b AND c)")]
+    #[test_case(col("a").like(string("x%")), "a LIKE 'x%'")]
+    #[test_case(col("a").is_in([1, 2]), "a IN (1, 2)")]
+    #[test_case(col("a").cast(TypeName::Int8), "a::BIGINT")]
+    #[test_case(func("lower", [col("a")]).gt(null()), "lower(a) > NULL")]
+    #[test_case(func("now", Vec::<Expr>::new()), "now()")]
+    #[test_case(count_star(), "count(*)")]
+    fn test_expr(expr: Expr, expected: &str) {
+        assert_eq!(expected, ExprNode::from(expr).sql().to_string());
+    }
+}
+
+use core::ops::Add;
+use core::ops::Div;
+use core::ops::Mul;
+use core::ops::Not;
+use core::ops::Rem;
+use core::ops::Sub;
+use pg_ast::BinaryExpr;
+use pg_ast::BoolExpr;
+use pg_ast::ColumnRef;
+use pg_ast::ExprKind;
+use pg_ast::ExprNode;
+use pg_ast::FuncArgsKind;
+use pg_ast::FuncCall;
+use pg_ast::FuncCallExpr;
+use pg_ast::InExpr;
+use pg_ast::NamedValue;
+use pg_ast::Operator;
+use pg_ast::ResTarget;
+use pg_ast::SortBy;
+use pg_ast::SortDirection;
+use pg_ast::Type;
+use pg_ast::TypecastExpr;
+use pg_basics::Located;
+use pg_basics::Location;
+use pg_basics::Str;
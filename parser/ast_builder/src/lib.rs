@@ -0,0 +1,21 @@
+//! Fluent construction of `pg-ast` statements, to generate SQL with the deparser, instead of concatenating strings.
+//!
+//! E.g.:
+//! ```
+//! # use pg_ast::SelectStmt;
+//! # use pg_ast_builder::{col, param, select, table};
+//! # use pg_deparser::Deparse;
+//! let stmt: SelectStmt = select()
+//!     .column(col("a"))
+//!     .from(table("t"))
+//!     .filter(col("b").eq(param(1)))
+//!     .into();
+//!
+//! assert_eq!("SELECT a FROM t WHERE b = $1", stmt.sql().to_string());
+//! ```
+
+pg_basics::reexport! { pub
+    expr,
+    select,
+    table,
+}
@@ -0,0 +1,218 @@
+/// A `SELECT` under construction, that converts into a [`SelectStmt`] or [`RawStmt`].
+///
+/// Without any columns, it selects `*`.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Select {
+    distinct: bool,
+    target_list: Vec<ResTarget>,
+    from_clause: Vec<TableRef>,
+    where_clause: Option<ExprNode>,
+    group_clause: Vec<ExprNode>,
+    having_clause: Option<ExprNode>,
+    sort_clause: Vec<SortBy>,
+    limit_count: Option<ExprNode>,
+    limit_offset: Option<ExprNode>,
+}
+
+pub fn select() -> Select {
+    Select::default()
+}
+
+impl Select {
+
+    pub fn distinct(mut self) -> Self {
+        self.distinct = true;
+        self
+    }
+
+    /// E.g.: `col("a")`, or `col("a").alias("b")`
+    pub fn column<T: Into<ResTarget>>(mut self, target: T) -> Self {
+        self.target_list.push(target.into());
+        self
+    }
+
+    pub fn columns<T>(mut self, targets: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<ResTarget>
+    {
+        self.target_list.extend(targets.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn from<T: Into<TableRef>>(mut self, table: T) -> Self {
+        self.from_clause.push(table.into());
+        self
+    }
+
+    /// Adds a condition to the `WHERE` clause, with `AND` when there's one already.
+    pub fn filter<T: Into<Expr>>(mut self, condition: T) -> Self {
+        self.where_clause = Some(and(self.where_clause, condition));
+        self
+    }
+
+    pub fn group_by<T: Into<Expr>>(mut self, expr: T) -> Self {
+        self.group_clause.push(expr.into().into());
+        self
+    }
+
+    /// Adds a condition to the `HAVING` clause, with `AND` when there's one already.
+    pub fn having<T: Into<Expr>>(mut self, condition: T) -> Self {
+        self.having_clause = Some(and(self.having_clause, condition));
+        self
+    }
+
+    /// E.g.: `col("a")`, or `col("a").desc()`
+    pub fn order_by<T: Into<SortBy>>(mut self, sort_by: T) -> Self {
+        self.sort_clause.push(sort_by.into());
+        self
+    }
+
+    pub fn limit<T: Into<Expr>>(mut self, count: T) -> Self {
+        self.limit_count = Some(count.into().into());
+        self
+    }
+
+    pub fn offset<T: Into<Expr>>(mut self, offset: T) -> Self {
+        self.limit_offset = Some(offset.into().into());
+        self
+    }
+}
+
+fn and<T: Into<Expr>>(left: Option<ExprNode>, right: T) -> ExprNode {
+    let right = right.into();
+    match left {
+        Some(left) => Expr::from(left).and(right).into(),
+        None => right.into(),
+    }
+}
+
+impl From<Select> for SelectStmt {
+    fn from(value: Select) -> Self {
+
+        let target_list = if value.target_list.is_empty() {
+            vec![ResTarget::Star]
+        } else {
+            value.target_list
+        };
+
+        let mut select = SimpleSelect::new(target_list);
+        if value.distinct {
+            select.set_distinct_clause(Some(DistinctClause::Distinct));
+        }
+        if !value.from_clause.is_empty() {
+            select.set_from_clause(Some(value.from_clause));
+        }
+        select.set_where_clause(value.where_clause);
+        if !value.group_clause.is_empty() {
+            select.set_group_clause(Some(value.group_clause));
+        }
+        select.set_having_clause(value.having_clause);
+
+        let mut stmt = SelectStmt::from(select);
+        if !value.sort_clause.is_empty() {
+            stmt.set_sort_clause(Some(value.sort_clause));
+        }
+        stmt.set_limit_count(value.limit_count);
+        stmt.set_limit_offset(value.limit_offset);
+
+        stmt
+    }
+}
+
+impl From<Select> for RawStmt {
+    fn from(value: Select) -> Self {
+        RawStmt::SelectStmt(Box::new(value.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::col;
+    use crate::count_star;
+    use crate::func;
+    use crate::param;
+    use crate::qualified_col;
+    use crate::string;
+    use crate::table;
+    use core::ops::ControlFlow;
+    use pg_ast::Accept;
+    use pg_ast::FuncArgsKind;
+    use pg_ast::VisitMut;
+    use pg_ast::Walk;
+    use pg_basics::Location;
+    use pg_deparser::Deparse;
+    use pg_parser::Parser;
+    use pg_parser_core::ParserConfig;
+    use test_case::test_case;
+
+    /// Parsed function arguments have locations, and built ones don't.
+    struct ClearLocations;
+
+    impl VisitMut for ClearLocations {
+        type Break = ();
+
+        fn visit_func_args_kind(&mut self, node: &mut FuncArgsKind) -> ControlFlow<()> {
+
+            if let FuncArgsKind::All { args, .. } = node {
+                for arg in args.iter_mut() {
+                    arg.1 = Location::new(0..0, 0, 0);
+                }
+            }
+
+            node.walk_mut(self)
+        }
+    }
+
+    #[test_case(select().from(table("t")).filter(col("a").eq(param(1))), "SELECT * FROM t WHERE a = $1")]
+    #[test_case(
+        select()
+            .distinct()
+            .columns([col("a"), func("lower", [col("b")])])
+            .column(count_star().alias("n"))
+            .from(table("t").alias("x"))
+            .filter(col("a").gt(1))
+            .filter(col("b").like(string("x%")))
+            .group_by(col("a"))
+            .group_by(func("lower", [col("b")]))
+            .having(count_star().gt(1))
+            .order_by(col("a").desc())
+            .order_by(col("n"))
+            .limit(param(1))
+            .offset(10),
+        "SELECT DISTINCT a, lower(b), count(*) AS n FROM t AS x WHERE a > 1 AND b LIKE 'x%' \
+            GROUP BY a, lower(b) HAVING count(*) > 1 ORDER BY a DESC, n LIMIT $1 OFFSET 10"
+    )]
+    #[test_case(
+        select()
+            .column(qualified_col("u", "b"))
+            .from(table("t").left_join(table("u"), qualified_col("t", "a").eq(qualified_col("u", "a"))))
+            .from(table("v")),
+        "SELECT u.b FROM t LEFT JOIN u ON t.a = u.a, v"
+    )]
+    fn test_select(select: Select, expected: &str) {
+        let actual = RawStmt::from(select);
+        assert_eq!(expected, actual.sql().to_string());
+
+        // It's the same tree that the parser makes.
+        let mut parsed = Parser::new(expected, ParserConfig::default())
+            .parse()
+            .result
+            .remove(0)
+            .unwrap()
+            .into_source();
+        let _ = parsed.accept_mut(&mut ClearLocations);
+        assert_eq!(parsed, actual);
+    }
+}
+
+use crate::Expr;
+use pg_ast::DistinctClause;
+use pg_ast::ExprNode;
+use pg_ast::RawStmt;
+use pg_ast::ResTarget;
+use pg_ast::SelectStmt;
+use pg_ast::SimpleSelect;
+use pg_ast::SortBy;
+use pg_ast::TableRef;
@@ -0,0 +1,80 @@
+/// A table in a `FROM` clause, or a join of tables, that converts into a [`TableRef`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Table(TableRef);
+
+/// E.g.: `table("t")`, or `table(RelationName::new("t").with_schema("s"))`
+pub fn table<T: Into<RelationName>>(name: T) -> Table {
+    Table(RelationTableRef::new(name.into()).into())
+}
+
+impl Table {
+
+    pub fn alias<T: Into<Alias>>(self, alias: T) -> Table {
+        let table = match self.0 {
+            TableRef::Relation(table) => table.with_alias(alias).into(),
+            TableRef::Join(join) => join.with_alias(alias).into(),
+            // Tables can only be built from relations and joins.
+            table => table,
+        };
+        Table(table)
+    }
+
+    pub fn join<T, E>(self, right: T, on: E) -> Table
+    where
+        T: Into<TableRef>,
+        E: Into<Expr>
+    {
+        self.with(JoinType::Inner, right, JoinCondition::On(on.into().into()))
+    }
+
+    pub fn left_join<T, E>(self, right: T, on: E) -> Table
+    where
+        T: Into<TableRef>,
+        E: Into<Expr>
+    {
+        self.with(JoinType::Left, right, JoinCondition::On(on.into().into()))
+    }
+
+    pub fn cross_join<T: Into<TableRef>>(self, right: T) -> Table {
+        self.with(JoinType::Inner, right, JoinCondition::Cross)
+    }
+
+    fn with<T: Into<TableRef>>(self, join_type: JoinType, right: T, condition: JoinCondition) -> Table {
+        Table(JoinTableRef::new(join_type, self.0, right.into(), condition).into())
+    }
+}
+
+impl From<Table> for TableRef {
+    fn from(value: Table) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::qualified_col;
+    use pg_deparser::Deparse;
+    use test_case::test_case;
+
+    #[test_case(table("t"), "t")]
+    #[test_case(table(RelationName::new("t").with_schema("s")).alias("x"), "s.t AS x")]
+    #[test_case(
+        table("t").join(table("u"), qualified_col("t", "a").eq(qualified_col("u", "a"))),
+        "t JOIN u ON t.a = u.a"
+    )]
+    #[test_case(table("t").left_join(table("u"), true), "t LEFT JOIN u ON TRUE")]
+    #[test_case(table("t").cross_join(table("u")), "t CROSS JOIN u")]
+    fn test_table(table: Table, expected: &str) {
+        assert_eq!(expected, TableRef::from(table).sql().to_string());
+    }
+}
+
+use crate::Expr;
+use pg_ast::Alias;
+use pg_ast::JoinCondition;
+use pg_ast::JoinTableRef;
+use pg_ast::JoinType;
+use pg_ast::RelationName;
+use pg_ast::RelationTableRef;
+use pg_ast::TableRef;
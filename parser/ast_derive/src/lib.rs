@@ -0,0 +1,22 @@
+//! Derives the traversal of `pg-ast` nodes.
+
+/// Implements `Walk`, which visits every field of a node, in declaration order.
+///
+/// Fields are visited through `Accept`, so each field's type must either be a node,
+/// a container of nodes (e.g., `Option`, `Vec`, `Box`), or a leaf (e.g., `Str`, `bool`).
+/// This is what prevents new nodes from being forgotten by the visitors.
+///
+/// The node itself must implement `Accept` too, i.e., be listed in `visitor!`, or `transparent!` if generic.
+#[proc_macro_derive(Walk)]
+pub fn derive_walk(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    walk::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+mod walk;
+
+use proc_macro::TokenStream;
+use syn::parse_macro_input;
+use syn::DeriveInput;
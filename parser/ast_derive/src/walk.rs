@@ -0,0 +1,125 @@
+pub(super) fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+
+    let name = &input.ident;
+
+    let mut generics = input.generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(crate::Accept));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let (walk, walk_mut) = match &input.data {
+        Data::Struct(data) => {
+            let walk = walk_struct(&data.fields, quote!(accept), quote!(&));
+            let walk_mut = walk_struct(&data.fields, quote!(accept_mut), quote!(&mut));
+            (walk, walk_mut)
+        },
+        Data::Enum(data) => {
+            let walk = walk_enum(data, quote!(accept));
+            let walk_mut = walk_enum(data, quote!(accept_mut));
+            (walk, walk_mut)
+        },
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(&input, "unions are not supported"))
+        },
+    };
+
+    // Generic nodes with defaults (e.g., `TypecastExpr<E = ExprNode>`) are listed with their default arguments.
+    let assert_node = if input.generics.type_params().all(|param| param.default.is_some()) {
+        quote! {
+            fn assert_node() {
+                assert_accept::<#name>();
+            }
+        }
+    } else {
+        quote! {
+            fn assert_node #impl_generics () #where_clause {
+                assert_accept::<#name #ty_generics>();
+            }
+        }
+    };
+
+    let output = quote! {
+        impl #impl_generics crate::Walk for #name #ty_generics #where_clause {
+
+            #[allow(unused_variables)]
+            fn walk<V>(&self, visitor: &mut V) -> ::core::ops::ControlFlow<V::Break>
+            where
+                V: crate::Visit + ?Sized
+            {
+                #walk
+                ::core::ops::ControlFlow::Continue(())
+            }
+
+            #[allow(unused_variables)]
+            fn walk_mut<V>(&mut self, visitor: &mut V) -> ::core::ops::ControlFlow<V::Break>
+            where
+                V: crate::VisitMut + ?Sized
+            {
+                #walk_mut
+                ::core::ops::ControlFlow::Continue(())
+            }
+        }
+
+        // Nodes are dispatched through `Accept`, so they must be listed in `visitor!`, or `transparent!` if generic.
+        const _: () = {
+            fn assert_accept<T: crate::Accept + ?Sized>() {}
+
+            #[allow(dead_code)]
+            #assert_node
+        };
+    };
+
+    Ok(output)
+}
+
+fn walk_struct(fields: &Fields, method: TokenStream, reference: TokenStream) -> TokenStream {
+
+    let members = fields.members();
+
+    quote! {
+        #( crate::Accept::#method(#reference self.#members, visitor)?; )*
+    }
+}
+
+fn walk_enum(data: &DataEnum, method: TokenStream) -> TokenStream {
+
+    let arms = data.variants.iter().map(|variant| {
+
+        let name = &variant.ident;
+        let bindings: Vec<_> = variant.fields.iter()
+            .enumerate()
+            .map(|(i, field)| match &field.ident {
+                Some(ident) => ident.clone(),
+                None => format_ident!("field_{i}"),
+            })
+            .collect();
+
+        let pattern = match &variant.fields {
+            Fields::Named(_) => quote!( Self::#name { #(#bindings),* } ),
+            Fields::Unnamed(_) => quote!( Self::#name( #(#bindings),* ) ),
+            Fields::Unit => quote!( Self::#name ),
+        };
+
+        quote! {
+            #pattern => {
+                #( crate::Accept::#method(#bindings, visitor)?; )*
+            }
+        }
+    });
+
+    quote! {
+        match self {
+            #(#arms)*
+        }
+    }
+}
+
+use proc_macro2::TokenStream;
+use quote::format_ident;
+use quote::quote;
+use syn::parse_quote;
+use syn::Data;
+use syn::DataEnum;
+use syn::DeriveInput;
+use syn::Fields;
@@ -1,17 +1,92 @@
-#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub struct ParserConfig {
     backslash_quote: BackslashQuote,
+    standard_conforming_strings: bool,
+    escape_string_warning: bool,
+    max_stack_depth: usize,
+    fail_fast: bool,
 }
 
 impl ParserConfig {
 
+    /// Default stack depth limit, in kilobytes.
+    ///
+    /// Upstream defaults to 2MB, assuming an 8MB process stack.
+    /// Half of that is used instead, so that deeply nested input fails with an error,
+    /// instead of overflowing the 2MB stack of spawned threads (e.g., test threads).
+    pub const DEFAULT_MAX_STACK_DEPTH: usize = 1024;
+
+    /// Debug builds report the errors of all statements,
+    /// while release builds stop at the first error.
+    pub const DEFAULT_FAIL_FAST: bool = !cfg!(debug_assertions);
+
     pub const fn new(backslash_quote: BackslashQuote) -> Self {
-        Self { backslash_quote }
+        Self {
+            backslash_quote,
+            standard_conforming_strings: true,
+            escape_string_warning: true,
+            max_stack_depth: Self::DEFAULT_MAX_STACK_DEPTH,
+            fail_fast: Self::DEFAULT_FAIL_FAST,
+        }
+    }
+
+    /// Alias: `standard_conforming_strings`
+    ///
+    /// When `false`, backslashes are escapes in `'...'` strings too.
+    pub const fn with_standard_conforming_strings(mut self, standard_conforming_strings: bool) -> Self {
+        self.standard_conforming_strings = standard_conforming_strings;
+        self
+    }
+
+    /// Alias: `escape_string_warning`
+    ///
+    /// Warns about backslashes in `'...'` strings, when `standard_conforming_strings` is `false`.
+    pub const fn with_escape_string_warning(mut self, escape_string_warning: bool) -> Self {
+        self.escape_string_warning = escape_string_warning;
+        self
+    }
+
+    /// Alias: `max_stack_depth`
+    ///
+    /// The maximum stack size used by the parser's recursion, in kilobytes.
+    /// It should be safely below the stack size of the thread that runs the parser.
+    pub const fn with_max_stack_depth(mut self, max_stack_depth: usize) -> Self {
+        self.max_stack_depth = max_stack_depth;
+        self
+    }
+
+    /// When enabled, parsing stops at the first statement with an error.
+    /// Otherwise, parsing resumes after the statement's terminating `;`.
+    pub const fn with_fail_fast(mut self, fail_fast: bool) -> Self {
+        self.fail_fast = fail_fast;
+        self
     }
 
     pub fn backslash_quote(&self) -> BackslashQuote {
         self.backslash_quote
     }
+
+    pub fn standard_conforming_strings(&self) -> bool {
+        self.standard_conforming_strings
+    }
+
+    pub fn escape_string_warning(&self) -> bool {
+        self.escape_string_warning
+    }
+
+    pub fn max_stack_depth(&self) -> usize {
+        self.max_stack_depth
+    }
+
+    pub fn fail_fast(&self) -> bool {
+        self.fail_fast
+    }
+}
+
+impl Default for ParserConfig {
+    fn default() -> Self {
+        Self::new(BackslashQuote::default())
+    }
 }
 
 use pg_basics::guc::BackslashQuote;
@@ -2,7 +2,9 @@
 pub(super) struct BufferedLexer<'src> {
     pub lexer: Lexer<'src>,
     pub peek: Option<eof::Result<Located<RawTokenKind>>>,
-    pub backslash_quote: BackslashQuote
+    pub backslash_quote: BackslashQuote,
+    pub escape_string_warning: bool,
+    pub warnings: Vec<Located<Warning>>,
 }
 
 impl BufferedLexer<'_> {
@@ -17,6 +19,25 @@ impl BufferedLexer<'_> {
         }
     }
 
+    /// Discards tokens and lexer errors that start before `index`.
+    ///
+    /// Unlike `next()`, errors are consumed, so the lexer can move forward.
+    pub fn skip_to(&mut self, index: u32) {
+        loop {
+            let start = match self.peek() {
+                Err(Eof(_)) => return,
+                Ok(Located(_, loc)) => loc.range().start,
+                Err(NotEof(err)) => err.location().range().start,
+            };
+
+            if start >= index {
+                return
+            }
+
+            self.peek = None;
+        }
+    }
+
     fn peek(&mut self) -> &eof::Result<Located<RawTokenKind>> {
 
         self.peek.get_or_insert_with(|| {
@@ -72,7 +93,14 @@ impl BufferedLexer<'_> {
                 .map(char::len_utf8)
                 .sum();
             if len < ident.len() {
+                let identifier = Str::from(ident.clone());
                 ident.truncate(len);
+
+                let warning = IdentifierTruncated {
+                    identifier,
+                    truncated: ident.clone().into(),
+                };
+                self.warnings.push(warning.at_location(loc.clone()));
             }
         }
 
@@ -118,6 +146,9 @@ impl BufferedLexer<'_> {
             | u&'String' ( SCONST )* ( UESCAPE ( SCONST )+ )?
         */
 
+        // `'...'` strings are only lexed as `Extended` when `standard_conforming_strings` is off.
+        let nonstandard = matches!(kind, Extended { .. }) && slice.starts_with('\'');
+
         let slice = strip_delimiters(kind, slice);
         let mut buffer = slice.to_owned();
 
@@ -143,6 +174,10 @@ impl BufferedLexer<'_> {
             }
             Extended { .. } => {
 
+                if nonstandard && self.escape_string_warning {
+                    self.check_escape_warning(&buffer, &loc);
+                }
+
                 let mut decoder = ExtendedStringDecoder::new(&buffer, self.backslash_quote);
                 let result = decoder.decode();
 
@@ -167,6 +202,25 @@ impl BufferedLexer<'_> {
         Ok(Located(value, loc))
     }
 
+    /// Alias: `check_string_escape_warning()`
+    ///
+    /// Like upstream, only the first escape of the string is reported.
+    fn check_escape_warning(&mut self, string: &str, loc: &Location) {
+
+        let mut chars = string.chars();
+        if !chars.any(|c| c == '\\') {
+            return
+        }
+
+        let warning = match chars.next() {
+            Some('\'') => NonstandardQuoteEscape,
+            Some('\\') => NonstandardBackslashEscape,
+            _ => NonstandardEscape,
+        };
+
+        self.warnings.push(warning.at_location(loc.clone()));
+    }
+
     fn uescape(&mut self) -> pg_elog::LocatedResult<char> {
         use StringKind::Basic;
 
@@ -240,9 +294,15 @@ use pg_basics::guc::BackslashQuote;
 use pg_basics::IntoLocated;
 use pg_basics::Located;
 use pg_basics::Location;
+use pg_basics::Str;
 use pg_basics::NAMEDATALEN;
 use pg_elog::parser::Error::InvalidUescapeDelimiter;
 use pg_elog::parser::Error::UescapeDelimiterMissing;
+use pg_elog::parser::Warning;
+use pg_elog::parser::Warning::IdentifierTruncated;
+use pg_elog::parser::Warning::NonstandardBackslashEscape;
+use pg_elog::parser::Warning::NonstandardEscape;
+use pg_elog::parser::Warning::NonstandardQuoteEscape;
 use pg_lexer::BitStringKind;
 use pg_lexer::IdentifierKind;
 use pg_lexer::IdentifierKind::Quoted;
@@ -1,24 +1,30 @@
 #[derive(Debug)]
 pub struct TokenStream<'src> {
     lexer: BufferedLexer<'src>,
-    buf: VecDeque<eof::Result<Located<TokenValue>>>
+    buf: VecDeque<eof::Result<Located<TokenValue>>>,
+    /// End of the last consumed token.
+    consumed_end: u32,
 }
 
 impl<'src> TokenStream<'src> {
 
     pub fn new(source: &'src str, config: ParserConfig) -> Self {
-        let lexer = Lexer::new(source);
-        Self::with_lexer(lexer, config.backslash_quote())
+        let lexer = Lexer::new(source)
+            .with_standard_conforming_strings(config.standard_conforming_strings());
+        Self::with_lexer(lexer, config)
     }
 
-    pub fn with_lexer(lexer: Lexer<'src>, backslash_quote: BackslashQuote) -> Self {
+    pub fn with_lexer(lexer: Lexer<'src>, config: ParserConfig) -> Self {
         Self {
             lexer: BufferedLexer {
                 lexer,
                 peek: None,
-                backslash_quote
+                backslash_quote: config.backslash_quote(),
+                escape_string_warning: config.escape_string_warning(),
+                warnings: Vec::new(),
             },
             buf: VecDeque::with_capacity(2),
+            consumed_end: 0,
         }
     }
 
@@ -26,6 +32,22 @@ impl<'src> TokenStream<'src> {
         self.lexer.lexer.source()
     }
 
+    pub fn standard_conforming_strings(&self) -> bool {
+        self.lexer.lexer.standard_conforming_strings()
+    }
+
+    pub fn add_warning(&mut self, warning: Located<Warning>) {
+        self.lexer.warnings.push(warning);
+    }
+
+    /// Returns the warnings collected so far, and clears them.
+    ///
+    /// Tokens are lexed ahead of the parser,
+    /// so this might include warnings from tokens that weren't consumed yet.
+    pub fn take_warnings(&mut self) -> Vec<Located<Warning>> {
+        mem::take(&mut self.lexer.warnings)
+    }
+
     pub fn eof(&mut self) -> bool {
         matches!(self.peek_mut(), Err(Eof(_)))
     }
@@ -39,8 +61,17 @@ impl<'src> TokenStream<'src> {
         }
     }
 
+    /// Returns the location that starts at `start`, and ends with the last consumed token,
+    /// e.g., to get the span of a node that started at `start`.
+    pub fn location_from(&self, start: Location) -> Location {
+        let range = start.range().start..self.consumed_end.max(start.range().start);
+        Location::new(range, start.line(), start.col())
+    }
+
     pub fn next(&mut self) {
-        self.buf.pop_front();
+        if let Some(Ok(Located(_, loc))) = self.buf.pop_front() {
+            self.consumed_end = loc.range().end;
+        }
     }
 
     pub fn skip(&mut self, n: usize) {
@@ -55,6 +86,28 @@ impl<'src> TokenStream<'src> {
         }
     }
 
+    /// Discards tokens and lexer errors that start before `index`,
+    /// e.g., to resume parsing at the next statement after an error.
+    pub fn skip_to(&mut self, index: u32) {
+
+        // Buffered elements are in source order, so only the leading ones can start before `index`.
+        while let Some(result) = self.buf.front() {
+            let start = match result {
+                Err(Eof(_)) => return,
+                Ok(Located(_, loc)) => loc.range().start,
+                Err(NotEof(err)) => err.location().range().start,
+            };
+
+            if start >= index {
+                return
+            }
+
+            self.buf.pop_front();
+        }
+
+        self.lexer.skip_to(index);
+    }
+
     pub fn peek(&mut self) -> eof::Result<&TokenValue> {
         match self.peek_mut() {
             Ok(Located(tok, _)) => Ok(tok),
@@ -212,7 +265,8 @@ impl TokenConsumer<TokenValue, bool> for TokenStream<'_> {
         }
 
         // SAFETY: `tok` already matched
-        let Located(tok, _) = self.buf.pop_front().unwrap()?;
+        let Located(tok, loc) = self.buf.pop_front().unwrap()?;
+        self.consumed_end = loc.range().end;
         Ok(tok)
     }
 }
@@ -222,8 +276,16 @@ mod tests {
     use super::*;
     use crate::scan::Error::ScanErr;
     use crate::syntax;
+    use pg_basics::guc::BackslashQuote;
+    use pg_basics::IntoLocated;
+    use pg_basics::NAMEDATALEN;
     use pg_elog::parser::Error::Syntax;
+    use pg_elog::parser::Warning::IdentifierTruncated;
+    use pg_elog::parser::Warning::NonstandardBackslashEscape;
+    use pg_elog::parser::Warning::NonstandardEscape;
+    use pg_elog::parser::Warning::NonstandardQuoteEscape;
     use pg_elog::Error::Parser;
+    use test_case::test_case;
     use TokenValue::Identifier;
 
     #[test]
@@ -251,6 +313,17 @@ mod tests {
         assert_eq!(Location::new(15..15, 1, 16), buffer.current_location());
     }
 
+    #[test]
+    fn test_location_from() {
+        let mut buffer =  TokenStream::from("select  two identifiers");
+
+        buffer.skip(1);
+        let start = buffer.current_location();
+        buffer.skip(2);
+
+        assert_eq!(Location::new(8..23, 1, 9), buffer.location_from(start));
+    }
+
     #[test]
     fn test_consume_returning_err() {
         let mut buffer =  TokenStream::from("two identifiers");
@@ -337,6 +410,63 @@ mod tests {
         assert_matches!(result, Err(Eof(_)));
         assert_eq!(Location::new(23..23, 1, 24), buffer.current_location());
     }
+
+    #[test_case(r"'\'' 'a'", Some(NonstandardQuoteEscape))]
+    #[test_case(r"'a\\b'", Some(NonstandardBackslashEscape))]
+    #[test_case(r"'a\nb'", Some(NonstandardEscape))]
+    #[test_case(r"'abc'", None)]
+    #[test_case(r"e'\n'", None)]
+    fn test_escape_string_warning(source: &str, expected: Option<Warning>) {
+        let config = ParserConfig::new(BackslashQuote::On).with_standard_conforming_strings(false);
+        let mut buffer = TokenStream::new(source, config);
+
+        assert_matches!(buffer.peek(), Ok(TokenValue::String(_)));
+
+        let actual = buffer.take_warnings()
+            .into_iter()
+            .map(|Located(warning, _)| warning)
+            .collect::<Vec<_>>();
+
+        assert_eq!(Vec::from_iter(expected), actual);
+    }
+
+    #[test]
+    fn test_escape_string_warning_disabled() {
+        let config = ParserConfig::default()
+            .with_standard_conforming_strings(false)
+            .with_escape_string_warning(false);
+        let mut buffer = TokenStream::new(r"'\n'", config);
+
+        assert_matches!(buffer.peek(), Ok(TokenValue::String(_)));
+        assert_eq!(Vec::<Located<Warning>>::new(), buffer.take_warnings());
+    }
+
+    #[test]
+    fn test_identifier_truncated() {
+        let source = "a".repeat(NAMEDATALEN + 1);
+        let mut buffer = TokenStream::from(source.as_str());
+
+        assert_matches!(buffer.peek(), Ok(Identifier(_)));
+
+        let expected = IdentifierTruncated {
+            identifier: source.clone().into(),
+            truncated: "a".repeat(NAMEDATALEN).into(),
+        };
+        let expected = vec![expected.at_location(Location::new(0..65, 1, 1))];
+        assert_eq!(expected, buffer.take_warnings());
+    }
+
+    #[test]
+    fn test_skip_to() {
+        let mut buffer = TokenStream::from("a 'unterminated; b");
+
+        assert_matches!(buffer.peek(), Ok(Identifier(_)));
+        buffer.next();
+        assert_matches!(buffer.peek(), Err(NotEof(_)));
+
+        buffer.skip_to(17);
+        assert_matches!(buffer.peek(), Err(Eof(_)));
+    }
 }
 
 use crate::eof;
@@ -349,12 +479,13 @@ use crate::stream::buffered_lexer::BufferedLexer;
 use crate::stream::TokenValue;
 use crate::ParserConfig;
 use alloc::collections::VecDeque;
-use pg_basics::guc::BackslashQuote;
+use core::mem;
 use pg_basics::Located;
 use pg_basics::Location;
 use pg_basics::NumberRadix;
 use pg_basics::UnsignedNumber;
 use pg_basics::UnsignedNumber::IntegerConst;
 use pg_basics::UnsignedNumber::NumericConst;
+use pg_elog::parser::Warning;
 use pg_lexer::Lexer;
 use pg_lexer::RawTokenKind;
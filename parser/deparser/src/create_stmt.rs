@@ -0,0 +1,223 @@
+impl Deparse for CreateAccessMethodStmt {
+    /// Alias: `CreateAmStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        let kind = match self.kind() {
+            AccessMethodKind::Index => "INDEX",
+            AccessMethodKind::Table => "TABLE",
+        };
+
+        write!(f,
+            "CREATE ACCESS METHOD {} TYPE {kind} HANDLER {}",
+            Ident(self.name()),
+            QualName(self.handler())
+        )
+    }
+}
+
+impl Deparse for CreateCastStmt {
+    /// Alias: `CreateCastStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        write!(f, "CREATE {} ", self.typecast().sql())?;
+
+        match self.conversion() {
+            CastConversion::WithInout => f.write_str("WITH INOUT")?,
+            CastConversion::WithoutFunction => f.write_str("WITHOUT FUNCTION")?,
+            CastConversion::WithFunction(function) => write!(f, "WITH FUNCTION {}", function.sql())?,
+        }
+
+        match self.coercion() {
+            CoercionContext::Explicit => Ok(()),
+            CoercionContext::Implicit => f.write_str(" AS IMPLICIT"),
+            CoercionContext::Assignment => f.write_str(" AS ASSIGNMENT"),
+        }
+    }
+}
+
+impl Deparse for CreateConversionStmt {
+    /// Alias: `CreateConversionStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        f.write_str("CREATE ")?;
+        if self.is_default() {
+            f.write_str("DEFAULT ")?;
+        }
+
+        write!(f,
+            "CONVERSION {} FOR {} TO {} FROM {}",
+            QualName(self.name()),
+            StrLit(self.for_encoding()),
+            StrLit(self.to_encoding()),
+            QualName(self.function())
+        )
+    }
+}
+
+impl Deparse for CreatePLangStmt {
+    /// Alias: `CreatePLangStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        write_create_or_replace(f, self.replace())?;
+        if self.trusted() {
+            f.write_str("TRUSTED ")?;
+        }
+        write!(f, "LANGUAGE {}", Ident(self.name()))?;
+
+        let Some(handler) = self.handler() else { return Ok(()) };
+
+        write!(f, " HANDLER {}", QualName(handler.handler()))?;
+        if let Some(inline_handler) = handler.inline_handler() {
+            write!(f, " INLINE {}", QualName(inline_handler))?;
+        }
+        if let Some(validator) = handler.validator() {
+            write!(f, " VALIDATOR {}", QualName(validator))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deparse for CreateSchemaStmt {
+    /// Alias: `CreateSchemaStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        f.write_str("CREATE SCHEMA")?;
+        if self.existence() == Presence::Ignore {
+            f.write_str(" IF NOT EXISTS")?;
+        }
+        if let Some(name) = self.name() {
+            write!(f, " {}", Ident(name))?;
+        }
+        if let Some(role) = self.authorization() {
+            write!(f, " AUTHORIZATION {}", role.sql())?;
+        }
+
+        for element in self.elements().unwrap_or_default() {
+            write!(f, " {}", element.sql())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deparse for CreateStatsStmt {
+    /// Alias: `CreateStatsStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        f.write_str("CREATE STATISTICS ")?;
+
+        if let Some(name) = self.name() {
+            if self.existence() == Presence::Ignore {
+                f.write_str("IF NOT EXISTS ")?;
+            }
+            write!(f, "{} ", QualName(name))?;
+        }
+
+        if let Some(kinds) = self.kinds() {
+            write!(f, "({}) ", Idents(kinds))?;
+        }
+
+        write!(f, "ON {} FROM {}", List(self.exprs()), List(self.relations()))
+    }
+}
+
+impl Deparse for StatsElem {
+    /// Alias: `stats_param`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Column(column) => Ident(column).fmt(f),
+            // `func_application` is the only form that parses into a different node when parenthesized.
+            Self::Expr(expr) if matches!(expr.kind(), ExprKind::FuncCall(_)) => expr.deparse(f),
+            Self::Expr(expr) => write!(f, "({})", expr.sql()),
+        }
+    }
+}
+
+impl Deparse for CreateTransformStmt {
+    /// Alias: `CreateTransformStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        write_create_or_replace(f, self.replace())?;
+        write!(f, "{} (", self.transform().sql())?;
+
+        if let Some(from_sql) = self.from_sql() {
+            write!(f, "FROM SQL WITH FUNCTION {}", from_sql.sql())?;
+            if self.to_sql().is_some() {
+                f.write_str(", ")?;
+            }
+        }
+        if let Some(to_sql) = self.to_sql() {
+            write!(f, "TO SQL WITH FUNCTION {}", to_sql.sql())?;
+        }
+
+        f.write_char(')')
+    }
+}
+
+impl Deparse for RuleStmt {
+    /// Alias: `RuleStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        let event = match self.event() {
+            RuleEvent::Select => "SELECT",
+            RuleEvent::Update => "UPDATE",
+            RuleEvent::Delete => "DELETE",
+            RuleEvent::Insert => "INSERT",
+        };
+
+        write_create_or_replace(f, self.replace())?;
+        write!(f, "RULE {} AS ON {event} TO {}", Ident(self.name()), self.relation().sql())?;
+
+        if let Some(where_clause) = self.where_clause() {
+            write!(f, " WHERE {}", where_clause.sql())?;
+        }
+
+        f.write_str(" DO ")?;
+        if self.instead() {
+            f.write_str("INSTEAD ")?;
+        }
+
+        match self.actions() {
+            [] => f.write_str("NOTHING"),
+            [action] => action.deparse(f),
+            actions => {
+                f.write_char('(')?;
+                for (i, action) in actions.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str("; ")?;
+                    }
+                    action.deparse(f)?;
+                }
+                f.write_char(')')
+            },
+        }
+    }
+}
+
+use crate::write_create_or_replace;
+use crate::Deparse;
+use crate::Ident;
+use crate::Idents;
+use crate::List;
+use crate::QualName;
+use crate::StrLit;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Write;
+use pg_ast::AccessMethodKind;
+use pg_ast::CastConversion;
+use pg_ast::CoercionContext;
+use pg_ast::CreateAccessMethodStmt;
+use pg_ast::CreateCastStmt;
+use pg_ast::CreateConversionStmt;
+use pg_ast::CreatePLangStmt;
+use pg_ast::CreateSchemaStmt;
+use pg_ast::CreateStatsStmt;
+use pg_ast::CreateTransformStmt;
+use pg_ast::ExprKind;
+use pg_ast::Presence;
+use pg_ast::RuleEvent;
+use pg_ast::RuleStmt;
+use pg_ast::StatsElem;
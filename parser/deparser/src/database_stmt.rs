@@ -0,0 +1,165 @@
+impl Deparse for CreateDatabaseStmt {
+    /// Alias: `CreatedbStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        write!(f, "CREATE DATABASE {}", Ident(self.name()))?;
+
+        for option in self.options() {
+            let name = match option.kind() {
+                CreatedbOptionKind::AllowConnections => "allow_connections",
+                CreatedbOptionKind::BuiltinLocale => "builtin_locale",
+                CreatedbOptionKind::CollationVersion => "collation_version",
+                CreatedbOptionKind::ConnectionLimit => "CONNECTION LIMIT",
+                CreatedbOptionKind::Encoding => "ENCODING",
+                CreatedbOptionKind::IcuLocale => "icu_locale",
+                CreatedbOptionKind::IcuRules => "icu_rules",
+                CreatedbOptionKind::IsTemplate => "is_template",
+                CreatedbOptionKind::LcCollate => "lc_collate",
+                CreatedbOptionKind::LcCtype => "lc_ctype",
+                CreatedbOptionKind::Locale => "locale",
+                CreatedbOptionKind::LocaleProvider => "locale_provider",
+                CreatedbOptionKind::Location => "LOCATION",
+                CreatedbOptionKind::Owner => "OWNER",
+                CreatedbOptionKind::Tablespace => "TABLESPACE",
+                CreatedbOptionKind::Template => "TEMPLATE",
+                CreatedbOptionKind::Oid => "oid",
+                CreatedbOptionKind::Strategy => "strategy",
+                CreatedbOptionKind::Unknown(name) => {
+                    write!(f, " {} = {}", Identifier(name), option.value().sql())?;
+                    continue
+                },
+            };
+            write!(f, " {name} = {}", option.value().sql())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deparse for AlterDatabaseStmt {
+    /// Alias: `AlterDatabaseStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        write!(f, "ALTER DATABASE {} WITH", Ident(self.name()))?;
+
+        for option in self.options() {
+            let name = match option.kind() {
+                AlterdbOptionKind::AllowConnections => "allow_connections",
+                AlterdbOptionKind::ConnectionLimit => "CONNECTION LIMIT",
+                AlterdbOptionKind::IsTemplate => "is_template",
+                AlterdbOptionKind::Tablespace => "TABLESPACE",
+                AlterdbOptionKind::Unknown(name) => {
+                    write!(f, " {} = {}", Identifier(name), option.value().sql())?;
+                    continue
+                },
+            };
+            write!(f, " {name} = {}", option.value().sql())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deparse for CreatedbOptionValue {
+    /// Alias: `createdb_opt_value`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Default => f.write_str("DEFAULT"),
+            Self::Boolean(true) => f.write_str("TRUE"),
+            Self::Boolean(false) => f.write_str("FALSE"),
+            Self::Number(number) => number.deparse(f),
+            Self::String(value) => StrLit(value).fmt(f),
+        }
+    }
+}
+
+impl Deparse for AlterDatabaseSetStmt {
+    /// Alias: `AlterDatabaseSetStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "ALTER DATABASE {} {}", Ident(self.name()), self.option().sql())
+    }
+}
+
+impl Deparse for CreateTableSpaceStmt {
+    /// Alias: `CreateTableSpaceStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        write!(f, "CREATE TABLESPACE {}", Ident(self.name()))?;
+
+        if let Some(owner) = self.owner() {
+            write!(f, " OWNER {}", owner.sql())?;
+        }
+
+        write!(f, " LOCATION {}", StrLit(self.location()))?;
+
+        if let Some(options) = self.options() {
+            write!(f, " WITH ({})", List(options))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deparse for RelOption {
+    /// Alias: `reloption_elem`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        if let Some(namespace) = self.namespace() {
+            write!(f, "{}.", Label(namespace))?;
+        }
+
+        Label(self.name()).fmt(f)?;
+
+        if let Some(value) = self.value() {
+            write!(f, " = {}", value.sql())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deparse for DefinitionElem {
+    /// Alias: `def_elem`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Label(self.name()).fmt(f)?;
+        if let Some(arg) = self.arg() {
+            write!(f, " = {}", arg.sql())?;
+        }
+        Ok(())
+    }
+}
+
+impl Deparse for DefinitionArg {
+    /// Alias: `def_arg`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Type(typ) => typ.deparse(f),
+            Self::ReservedWord(word) => f.write_str(word),
+            Self::Operator(op) => AllOp(op).fmt(f),
+            Self::Number(number) => number.deparse(f),
+            Self::String(value) => StrLit(value).fmt(f),
+            Self::None => f.write_str("NONE"),
+        }
+    }
+}
+
+use crate::AllOp;
+use crate::Deparse;
+use crate::Ident;
+use crate::Identifier;
+use crate::Label;
+use crate::List;
+use crate::StrLit;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use pg_ast::AlterDatabaseSetStmt;
+use pg_ast::AlterDatabaseStmt;
+use pg_ast::AlterdbOptionKind;
+use pg_ast::CreateDatabaseStmt;
+use pg_ast::CreateTableSpaceStmt;
+use pg_ast::CreatedbOptionKind;
+use pg_ast::CreatedbOptionValue;
+use pg_ast::DefinitionArg;
+use pg_ast::DefinitionElem;
+use pg_ast::RelOption;
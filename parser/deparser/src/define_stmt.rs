@@ -0,0 +1,80 @@
+impl Deparse for DefineStmt {
+    /// Alias: `DefineStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Aggregate { aggregate, definition, replace } => {
+                write_create_or_replace(f, *replace)?;
+                write!(f, "AGGREGATE {} ({})", aggregate.sql(), List(definition))
+            },
+            Self::OldStyleAggregate { name, definition, replace } => {
+                write_create_or_replace(f, *replace)?;
+                write!(f, "AGGREGATE {} (", QualName(name))?;
+                // Only identifiers are accepted as names in the old-style syntax.
+                write_list(f, definition, |f, elem| {
+                    Identifier(elem.name()).fmt(f)?;
+                    match elem.arg() {
+                        Some(arg) => write!(f, " = {}", arg.sql()),
+                        None => Ok(()),
+                    }
+                })?;
+                f.write_char(')')
+            },
+            Self::Collation { name, definition, existence } => {
+                f.write_str("CREATE COLLATION ")?;
+                if *existence == Presence::Ignore {
+                    f.write_str("IF NOT EXISTS ")?;
+                }
+                QualName(name).fmt(f)?;
+                match definition {
+                    CollationDefinition::Definition(definition) => write!(f, " ({})", List(definition)),
+                    CollationDefinition::From(collation) => write!(f, " FROM {}", QualName(collation)),
+                }
+            },
+            Self::Operator { name, definition } => {
+                write!(f, "CREATE OPERATOR {} ({})", AnyOp(name), List(definition))
+            },
+            Self::Type { name, definition } => {
+                write!(f, "CREATE TYPE {}", QualName(name))?;
+                if let Some(definition) = definition {
+                    write!(f, " ({})", List(definition))?;
+                }
+                Ok(())
+            },
+            Self::TextSearchConfiguration { name, definition } => {
+                write!(f, "CREATE TEXT SEARCH CONFIGURATION {} ({})", QualName(name), List(definition))
+            },
+            Self::TextSearchDictionary { name, definition } => {
+                write!(f, "CREATE TEXT SEARCH DICTIONARY {} ({})", QualName(name), List(definition))
+            },
+            Self::TextSearchParser { name, definition } => {
+                write!(f, "CREATE TEXT SEARCH PARSER {} ({})", QualName(name), List(definition))
+            },
+            Self::TextSearchTemplate { name, definition } => {
+                write!(f, "CREATE TEXT SEARCH TEMPLATE {} ({})", QualName(name), List(definition))
+            },
+        }
+    }
+}
+
+pub(crate) fn write_create_or_replace(f: &mut Formatter<'_>, replace: bool) -> fmt::Result {
+    if replace {
+        f.write_str("CREATE OR REPLACE ")
+    }
+    else {
+        f.write_str("CREATE ")
+    }
+}
+
+use crate::write_list;
+use crate::AnyOp;
+use crate::Deparse;
+use crate::Identifier;
+use crate::List;
+use crate::QualName;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Write;
+use pg_ast::CollationDefinition;
+use pg_ast::DefineStmt;
+use pg_ast::Presence;
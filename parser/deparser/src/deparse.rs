@@ -0,0 +1,45 @@
+/// Writes a node as SQL text.
+///
+/// E.g.:
+/// ```
+/// # use pg_ast::ExprKind;
+/// # use pg_ast::ExprNode;
+/// # use pg_deparser::Deparse;
+/// let expr = ExprNode::from(ExprKind::StringConst("it's".into()));
+/// assert_eq!("'it''s'", expr.sql().to_string());
+/// ```
+pub trait Deparse {
+
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result;
+
+    /// Wraps the node, so it can be used with `format!()`, `to_string()`, etc.
+    fn sql(&self) -> Sql<'_, Self> {
+        Sql(self)
+    }
+}
+
+impl<T: Deparse + ?Sized> Deparse for &T {
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        (**self).deparse(f)
+    }
+}
+
+impl<T: Deparse + ?Sized> Deparse for Box<T> {
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        (**self).deparse(f)
+    }
+}
+
+/// `Display` adapter for [`Deparse`].
+#[derive(Debug, Copy, Clone)]
+pub struct Sql<'a, T: ?Sized>(pub &'a T);
+
+impl<T: Deparse + ?Sized> Display for Sql<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.deparse(f)
+    }
+}
+
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
@@ -0,0 +1,665 @@
+impl Deparse for ExprNode {
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_expr(f, self, ExprRule::A, 0)
+    }
+}
+
+/// Alias: `b_expr`
+///
+/// Same as `a_expr`, but boolean operators, `IN`, `LIKE` and subquery comparisons
+/// (e.g.: `a = ANY (SELECT ...)`) have to be parenthesized.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct BExpr<'a>(pub &'a ExprNode);
+
+impl Display for BExpr<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_expr(f, self.0, ExprRule::B, 0)
+    }
+}
+
+/// Alias: `c_expr`
+///
+/// Anything that isn't a primary expression is parenthesized.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct CExpr<'a>(pub &'a ExprNode);
+
+impl Display for CExpr<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_expr(f, self.0, ExprRule::C, 0)
+    }
+}
+
+/// Operator as it's written between, or before, its operands.
+///
+/// Schema qualified operators use the `OPERATOR(schema.op)` syntax.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Op<'a>(pub &'a QualifiedOperator);
+
+impl Display for Op<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        let QualifiedOperator(schema, op) = self.0;
+
+        if schema.is_empty() {
+            let keyword = match op {
+                Operator::Like => "LIKE",
+                Operator::NotLike => "NOT LIKE",
+                Operator::ILike => "ILIKE",
+                Operator::NotILike => "NOT ILIKE",
+                op => return f.write_str(symbol(op)),
+            };
+            return f.write_str(keyword)
+        }
+
+        ExplicitOp(self.0).fmt(f)
+    }
+}
+
+/// Alias: `qual_all_Op`
+///
+/// Same as [`Op`], but `LIKE` and `ILIKE` don't have a keyword form in this context.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct AllOp<'a>(pub &'a QualifiedOperator);
+
+impl Display for AllOp<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        let QualifiedOperator(schema, op) = self.0;
+
+        if schema.is_empty() && !is_like(op) {
+            return f.write_str(symbol(op))
+        }
+
+        ExplicitOp(self.0).fmt(f)
+    }
+}
+
+/// Alias: `any_operator`
+///
+/// E.g.: `pg_catalog.+`
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct AnyOp<'a>(pub &'a QualifiedOperator);
+
+impl Display for AnyOp<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        let QualifiedOperator(schema, op) = self.0;
+
+        for name in schema {
+            write!(f, "{}.", Ident(name))?;
+        }
+        f.write_str(symbol(op))
+    }
+}
+
+/// `OPERATOR '(' any_operator ')'`
+#[derive(Debug, Copy, Clone)]
+struct ExplicitOp<'a>(&'a QualifiedOperator);
+
+impl Display for ExplicitOp<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "OPERATOR({})", AnyOp(self.0))
+    }
+}
+
+/// Alias: `indirection`
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Indirections<'a>(pub &'a [Indirection]);
+
+impl Display for Indirections<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for el in self.0 {
+            match el {
+                Indirection::Wildcard => f.write_str(".*")?,
+                Indirection::Property(name) => write!(f, ".{}", Label(name))?,
+                Indirection::Index(index) => write!(f, "[{}]", index.sql())?,
+                Indirection::Slice(lower, upper) => {
+                    f.write_char('[')?;
+                    if let Some(lower) = lower {
+                        lower.deparse(f)?;
+                    }
+                    f.write_char(':')?;
+                    if let Some(upper) = upper {
+                        upper.deparse(f)?;
+                    }
+                    f.write_char(']')?;
+                },
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Deparse for ColumnRef {
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SingleName(name) => Ident(name).fmt(f),
+            Self::Name(name) => QualName(name).fmt(f),
+            Self::WildcardName(name) => write!(f, "{}.*", QualName(name)),
+            Self::Indirection { name, indirection } => {
+                write!(f, "{}{}", QualName(name), Indirections(indirection))
+            },
+        }
+    }
+}
+
+impl Deparse for CaseExpr {
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        f.write_str("CASE")?;
+        if let Some(target) = self.target() {
+            write!(f, " {}", target.sql())?;
+        }
+
+        for when in self.when_clauses() {
+            write!(f, " WHEN {} THEN {}", when.condition().sql(), when.body().sql())?;
+        }
+
+        if let Some(default) = self.default() {
+            write!(f, " ELSE {}", default.sql())?;
+        }
+
+        f.write_str(" END")
+    }
+}
+
+impl Deparse for SubLink {
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_sub_link(f, self, ExprRule::A)
+    }
+}
+
+impl Deparse for NamedValue {
+    /// Function argument, e.g.: `name => value`.
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(name) = self.name() {
+            write!(f, "{} => ", Ident(name))?;
+        }
+        self.value().deparse(f)
+    }
+}
+
+/// `a_expr ( AS ColLabel )?`
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct LabeledExpr<'a>(pub &'a NamedValue);
+
+impl Display for LabeledExpr<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.value().deparse(f)?;
+        if let Some(name) = self.0.name() {
+            write!(f, " AS {}", Label(name))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum ExprRule {
+    A,
+    B,
+    C,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Associativity {
+    Left(u8),
+    Right(u8),
+    Non(u8),
+}
+
+impl Associativity {
+    fn precedence(self) -> u8 {
+        match self {
+            Left(prec) | Right(prec) | Non(prec) => prec,
+        }
+    }
+
+    fn left_precedence(self) -> u8 {
+        match self {
+            Left(prec) | Right(prec) => prec,
+            Non(prec) => prec + 1,
+        }
+    }
+
+    fn right_precedence(self) -> u8 {
+        match self {
+            Right(prec) => prec,
+            Left(prec) | Non(prec) => prec + 1,
+        }
+    }
+}
+
+// Same precedences as the parser's, from lowest to highest.
+const OR: Associativity = Left(1);
+const AND: Associativity = Left(2);
+const NOT: Associativity = Right(3);
+const IS: Associativity = Non(4);
+const COMPARISON: Associativity = Non(5);
+const IN_LIKE: Associativity = Non(6);
+const QUAL_OP: Associativity = Left(7);
+const ADDITIVE: Associativity = Left(8);
+const MULTIPLICATIVE: Associativity = Left(9);
+const EXPONENTIATION: Associativity = Left(10);
+const AT: Associativity = Left(11);
+const COLLATE: Associativity = Left(12);
+const UNARY_MINUS: Associativity = Right(13);
+const TYPECAST: Associativity = Left(14);
+const PRIMARY: u8 = 15;
+
+fn write_expr(f: &mut Formatter<'_>, expr: &ExprNode, kind: ExprRule, min_precedence: u8) -> fmt::Result {
+
+    let precedence = precedence(expr);
+
+    let allowed = match kind {
+        ExprRule::A => true,
+        ExprRule::B => is_b_expr(expr),
+        ExprRule::C => precedence == PRIMARY,
+    };
+
+    if allowed && precedence >= min_precedence {
+        write_unparenthesized(f, expr, kind)
+    }
+    else {
+        write!(f, "({})", expr.sql())
+    }
+}
+
+fn write_unparenthesized(f: &mut Formatter<'_>, expr: &ExprNode, kind: ExprRule) -> fmt::Result {
+
+    match expr.kind() {
+        NullConst => f.write_str("NULL"),
+        StringConst(value) => StrLit(value).fmt(f),
+        BinaryStringConst(value) => write!(f, "b'{value}'"),
+        HexStringConst(value) => write!(f, "x'{value}'"),
+        IntegerConst(value) => write!(f, "{value}"),
+        NumericConst { value, radix } => NumLit { value, radix: *radix }.fmt(f),
+        BooleanConst(true) => f.write_str("TRUE"),
+        BooleanConst(false) => f.write_str("FALSE"),
+        DefaultExpr => f.write_str("DEFAULT"),
+        ExprKind::CaseExpr(case) => case.deparse(f),
+        ParamRef { index } => write!(f, "${index}"),
+        Row(None) => f.write_str("ROW()"),
+        Row(Some(values)) => write!(f, "ROW({})", List(values)),
+        Array(elements) => {
+            f.write_str("ARRAY")?;
+            write_array_elements(f, elements)
+        },
+        RowCompare(row_compare) => {
+            let op = match row_compare.kind() {
+                RowCompareKind::Less => "<",
+                RowCompareKind::LessEquals => "<=",
+                RowCompareKind::Equals => "=",
+                RowCompareKind::GreaterEquals => ">=",
+                RowCompareKind::Greater => ">",
+                RowCompareKind::NotEquals => "<>",
+            };
+            write!(f,
+                "ROW({}) {op} ROW({})",
+                List(row_compare.left()),
+                List(row_compare.right())
+            )
+        },
+        StringTypecast(typecast) => typecast.deparse(f),
+        BinaryExpr(expr) => {
+            let assoc = infix_associativity(expr.operator());
+            write_binary(f, kind, assoc, expr.left_operand(), Op(expr.operator()), expr.right_operand())
+        },
+        UnaryExpr(expr) => {
+            let QualifiedOperator(schema, op) = expr.operator();
+            match op {
+                Operator::Addition | Operator::Subtraction if schema.is_empty() => {
+                    write!(f, "{} ", symbol(op))?;
+                    write_expr(f, expr.operand(), kind, UNARY_MINUS.right_precedence())
+                },
+                Operator::UserDefined(op) if schema.is_empty() => {
+                    write!(f, "{op} ")?;
+                    write_expr(f, expr.operand(), kind, QUAL_OP.right_precedence())
+                },
+                _ => {
+                    write!(f, "{} ", ExplicitOp(expr.operator()))?;
+                    write_expr(f, expr.operand(), kind, QUAL_OP.right_precedence())
+                }
+            }
+        },
+        BoolExpr(BoolExpr::Not(operand)) => {
+            f.write_str("NOT ")?;
+            write_expr(f, operand, kind, NOT.right_precedence())
+        },
+        BoolExpr(BoolExpr::Or(operands)) => {
+            let (left, right) = &**operands;
+            write_binary(f, kind, OR, left, "OR", right)
+        },
+        BoolExpr(BoolExpr::And(operands)) => {
+            let (left, right) = &**operands;
+            write_binary(f, kind, AND, left, "AND", right)
+        },
+        FuncCallExpr(func) => func.deparse(f),
+        JsonArrayAggExpr(agg) => agg.deparse(f),
+        JsonObjectAggExpr(agg) => agg.deparse(f),
+        Distinct(operands) => {
+            let (left, right) = &**operands;
+            write_binary(f, kind, IS, left, "IS DISTINCT FROM", right)
+        },
+        NotDistinct(operands) => {
+            let (left, right) = &**operands;
+            write_binary(f, kind, IS, left, "IS NOT DISTINCT FROM", right)
+        },
+        IsNull(operand) => {
+            write_expr(f, operand, kind, IS.left_precedence())?;
+            f.write_str(" IS NULL")
+        },
+        IsNotNull(operand) => {
+            write_expr(f, operand, kind, IS.left_precedence())?;
+            f.write_str(" IS NOT NULL")
+        },
+        BooleanTest(test) => {
+            write_expr(f, test.expr(), kind, IS.left_precedence())?;
+            let test = match test.kind() {
+                BoolTestKind::IsTrue => "IS TRUE",
+                BoolTestKind::IsNotTrue => "IS NOT TRUE",
+                BoolTestKind::IsFalse => "IS FALSE",
+                BoolTestKind::IsNotFalse => "IS NOT FALSE",
+                BoolTestKind::IsUnknown => "IS UNKNOWN",
+                BoolTestKind::IsNotUnknown => "IS NOT UNKNOWN",
+            };
+            write!(f, " {test}")
+        },
+        Between(expr) => {
+            write_expr(f, expr.expr(), kind, IN_LIKE.left_precedence())?;
+            let not = if expr.not() { " NOT" } else { "" };
+            let symmetric = if expr.symmetric() { " SYMMETRIC" } else { "" };
+            write!(f, "{not} BETWEEN{symmetric} {} AND ", BExpr(expr.low()))?;
+            write_expr(f, expr.high(), kind, IN_LIKE.right_precedence())
+        },
+        SimilarTo(expr) => {
+            write_expr(f, expr.expr(), kind, IN_LIKE.left_precedence())?;
+            let not = if expr.not() { " NOT" } else { "" };
+            write!(f, "{not} SIMILAR TO ")?;
+            write_expr(f, expr.pattern(), kind, IN_LIKE.right_precedence())?;
+            if let Some(escape) = expr.escape() {
+                f.write_str(" ESCAPE ")?;
+                write_expr(f, escape, kind, IN_LIKE.right_precedence())?;
+            }
+            Ok(())
+        },
+        ArrayCompare(expr) => {
+            let operator = expr.operator();
+            write_expr(f, expr.left(), kind, infix_associativity(operator).left_precedence())?;
+            let sub_type = match expr.kind() {
+                ArrayCompareKind::Any => "ANY",
+                ArrayCompareKind::All => "ALL",
+            };
+            write!(f, " {} {sub_type} ({})", Op(operator), expr.right().sql())
+        },
+        AtTimeZone(operands) => {
+            let (left, right) = &**operands;
+            write_binary(f, kind, AT, left, "AT TIME ZONE", right)
+        },
+        AtLocal(operand) => {
+            write_expr(f, operand, kind, AT.left_precedence())?;
+            f.write_str(" AT LOCAL")
+        },
+        Collate(expr) => {
+            write_expr(f, expr.expr(), kind, COLLATE.left_precedence())?;
+            write!(f, " COLLATE {}", QualName(expr.collation()))
+        },
+        ExprKind::Indirection(expr) => {
+            let base = expr.expr();
+            match base.kind() {
+                ParamRef { .. } => base.deparse(f)?,
+                SubLink(sub_link) if sub_link.kind() == SubLinkKind::Expr => base.deparse(f)?,
+                _ => write!(f, "({})", base.sql())?,
+            }
+            Indirections(expr.indirection()).fmt(f)
+        },
+        Typecast(typecast) => {
+            write_expr(f, typecast.arg(), kind, TYPECAST.left_precedence())?;
+            write!(f, "::{}", typecast.type_name().sql())
+        },
+        InExpr(expr) => {
+            write_expr(f, expr.expr(), kind, IN_LIKE.left_precedence())?;
+            let not = if expr.not() { " NOT" } else { "" };
+            write!(f, "{not} IN ({})", List(expr.list()))
+        },
+        SubLink(sub_link) => write_sub_link(f, sub_link, kind),
+        ExprKind::ColumnRef(column_ref) => column_ref.deparse(f),
+        GroupingFunc(args) => write!(f, "GROUPING({})", List(args)),
+        FuncCall(func) => func.deparse(f),
+        SqlFunction(func) => func.deparse(f),
+    }
+}
+
+fn write_binary(
+    f: &mut Formatter<'_>,
+    kind: ExprRule,
+    assoc: Associativity,
+    left: &ExprNode,
+    op: impl Display,
+    right: &ExprNode
+)
+    -> fmt::Result
+{
+    write_expr(f, left, kind, assoc.left_precedence())?;
+    write!(f, " {op} ")?;
+    write_expr(f, right, kind, assoc.right_precedence())
+}
+
+fn write_sub_link(f: &mut Formatter<'_>, sub_link: &SubLink, kind: ExprRule) -> fmt::Result {
+
+    let subselect = sub_link.subselect().sql();
+
+    let (test_expr, sub_type) = match (sub_link.kind(), sub_link.test_expr()) {
+        (SubLinkKind::Exists, _) => return write!(f, "EXISTS ({subselect})"),
+        (SubLinkKind::Array, _) => return write!(f, "ARRAY({subselect})"),
+        (SubLinkKind::Any, Some(test_expr)) => (test_expr, "ANY "),
+        (SubLinkKind::All, Some(test_expr)) => (test_expr, "ALL "),
+        (SubLinkKind::RowCompare, Some(test_expr)) => (test_expr, ""),
+        _ => return write!(f, "({subselect})"),
+    };
+
+    let Some(operator) = sub_link.operator() else {
+        // `IN` subquery
+        write_expr(f, test_expr, kind, IN_LIKE.left_precedence())?;
+        return write!(f, " IN ({subselect})")
+    };
+
+    write_expr(f, test_expr, kind, infix_associativity(operator).left_precedence())?;
+    write!(f, " {} {sub_type}({subselect})", Op(operator))
+}
+
+/// Alias: `array_expr`
+///
+/// Nested arrays drop the `ARRAY` keyword, unless they're mixed with other expressions.
+fn write_array_elements(f: &mut Formatter<'_>, elements: &[ExprNode]) -> fmt::Result {
+
+    let nested = !elements.is_empty()
+        && elements.iter().all(|el| matches!(el.kind(), Array(_)));
+
+    f.write_char('[')?;
+    if nested {
+        write_list(f, elements, |f, el| {
+            let Array(elements) = el.kind() else { unreachable!() };
+            write_array_elements(f, elements)
+        })?;
+    }
+    else {
+        List(elements).fmt(f)?;
+    }
+    f.write_char(']')
+}
+
+fn precedence(expr: &ExprNode) -> u8 {
+
+    let assoc = match expr.kind() {
+        BoolExpr(BoolExpr::Or(_)) => OR,
+        BoolExpr(BoolExpr::And(_)) => AND,
+        BoolExpr(BoolExpr::Not(_)) => NOT,
+        Distinct(_) | NotDistinct(_) | IsNull(_) | IsNotNull(_) | BooleanTest(_) => IS,
+        RowCompare(_) => COMPARISON,
+        InExpr(_) | Between(_) | SimilarTo(_) => IN_LIKE,
+        ArrayCompare(expr) => infix_associativity(expr.operator()),
+        AtTimeZone(_) | AtLocal(_) => AT,
+        Collate(_) => COLLATE,
+        BinaryExpr(expr) => infix_associativity(expr.operator()),
+        UnaryExpr(expr) => {
+            let QualifiedOperator(schema, op) = expr.operator();
+            if schema.is_empty() && matches!(op, Operator::Addition | Operator::Subtraction) {
+                UNARY_MINUS
+            }
+            else {
+                QUAL_OP
+            }
+        },
+        SubLink(sub_link) => match (sub_link.kind(), sub_link.operator()) {
+            (SubLinkKind::Any, None) => IN_LIKE,
+            (SubLinkKind::Any | SubLinkKind::All | SubLinkKind::RowCompare, Some(op)) => infix_associativity(op),
+            _ => return PRIMARY,
+        },
+        Typecast(_) => TYPECAST,
+        // Negative constants are folded by the parser, so they bind like unary minus.
+        IntegerConst(value) if *value < 0 => UNARY_MINUS,
+        NumericConst { value, .. } if value.starts_with('-') => UNARY_MINUS,
+        _ => return PRIMARY,
+    };
+
+    assoc.precedence()
+}
+
+fn infix_associativity(op: &QualifiedOperator) -> Associativity {
+
+    let QualifiedOperator(schema, op) = op;
+
+    if !schema.is_empty() {
+        return QUAL_OP
+    }
+
+    match op {
+        Operator::Addition | Operator::Subtraction => ADDITIVE,
+        Operator::Multiplication | Operator::Division | Operator::Modulo => MULTIPLICATIVE,
+        Operator::Exponentiation => EXPONENTIATION,
+        Operator::Less
+        | Operator::Greater
+        | Operator::Equals
+        | Operator::LessEquals
+        | Operator::GreaterEquals
+        | Operator::NotEquals => COMPARISON,
+        Operator::Like | Operator::NotLike | Operator::ILike | Operator::NotILike => IN_LIKE,
+        Operator::UserDefined(_) => QUAL_OP,
+    }
+}
+
+fn is_b_expr(expr: &ExprNode) -> bool {
+    match expr.kind() {
+        BoolExpr(_)
+        | InExpr(_)
+        | IsNull(_)
+        | IsNotNull(_)
+        | BooleanTest(_)
+        | Between(_)
+        | SimilarTo(_)
+        | ArrayCompare(_)
+        | AtTimeZone(_)
+        | AtLocal(_)
+        | Collate(_) => false,
+        BinaryExpr(expr) => {
+            let QualifiedOperator(schema, op) = expr.operator();
+            !schema.is_empty() || !is_like(op)
+        },
+        SubLink(sub_link) => !matches!(sub_link.kind(), SubLinkKind::Any | SubLinkKind::All),
+        _ => true,
+    }
+}
+
+fn is_like(op: &Operator) -> bool {
+    matches!(op, Operator::Like | Operator::NotLike | Operator::ILike | Operator::NotILike)
+}
+
+fn symbol(op: &Operator) -> &str {
+    match op {
+        Operator::Addition => "+",
+        Operator::Subtraction => "-",
+        Operator::Multiplication => "*",
+        Operator::Division => "/",
+        Operator::Modulo => "%",
+        Operator::Exponentiation => "^",
+        Operator::Less => "<",
+        Operator::Greater => ">",
+        Operator::Equals => "=",
+        Operator::LessEquals => "<=",
+        Operator::GreaterEquals => ">=",
+        Operator::NotEquals => "<>",
+        Operator::Like => "~~",
+        Operator::NotLike => "!~~",
+        Operator::ILike => "~~*",
+        Operator::NotILike => "!~~*",
+        Operator::UserDefined(op) => op,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pg_parser::Parser;
+    use pg_parser_core::ParserConfig;
+    use test_case::test_case;
+
+    #[test_case("(1 + 2) * 3", "(1 + 2) * 3")]
+    #[test_case("1 - (2 - 3)", "1 - (2 - 3)")]
+    #[test_case("(1 - 2) - 3", "1 - 2 - 3")]
+    #[test_case("2 ^ (3 ^ 4)", "2 ^ (3 ^ 4)")]
+    #[test_case("(a = b) = c", "(a = b) = c")]
+    #[test_case("not (a and b) or c", "NOT (a AND b) OR c")]
+    #[test_case("1 operator(pg_catalog.+) 2", "1 OPERATOR(pg_catalog.+) 2")]
+    #[test_case("(a).b, a[1]", "(a).b, a[1]")]
+    #[test_case(r#""Foo", "select", "left"(1)"#, r#""Foo", "select", "left"(1)"#)]
+    #[test_case("'it''s'", "'it''s'")]
+    #[test_case("a = b is null, a isnull is not true", "a = b IS NULL, (a IS NULL) IS NOT TRUE")]
+    #[test_case("(a is null) = b, -(a collate \"C\")", "(a IS NULL) = b, - (a COLLATE \"C\")")]
+    #[test_case("a between b + 1 and c and d", "a BETWEEN b + 1 AND c AND d")]
+    #[test_case("a not between symmetric (b and c) and d", "a NOT BETWEEN SYMMETRIC (b AND c) AND d")]
+    #[test_case("a not similar to b escape c", "a NOT SIMILAR TO b ESCAPE c")]
+    #[test_case("a like b escape c", "a LIKE pg_catalog.like_escape(b, c)")]
+    #[test_case("a = any(array[1]), a like all (b)", "a = ANY (ARRAY[1]), a LIKE ALL (b)")]
+    #[test_case("(a at time zone b) at local", "a AT TIME ZONE b AT LOCAL")]
+    #[test_case("a at time zone (b at time zone c)", "a AT TIME ZONE (b AT TIME ZONE c)")]
+    fn test_expr(source: &str, expected: &str) {
+
+        let source = format!("select {source}");
+        let mut result = Parser::new(&source, ParserConfig::default()).parse().result;
+        let stmt = result.remove(0).unwrap();
+
+        assert_eq!(format!("SELECT {expected}"), stmt.source().sql().to_string())
+    }
+}
+
+use self::Associativity::Left;
+use self::Associativity::Non;
+use self::Associativity::Right;
+use crate::write_list;
+use crate::Deparse;
+use crate::Ident;
+use crate::Label;
+use crate::List;
+use crate::NumLit;
+use crate::QualName;
+use crate::StrLit;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Write;
+use pg_ast::ArrayCompareKind;
+use pg_ast::BoolExpr;
+use pg_ast::BoolTestKind;
+use pg_ast::CaseExpr;
+use pg_ast::ColumnRef;
+use pg_ast::ExprKind;
+use pg_ast::ExprKind::*;
+use pg_ast::ExprNode;
+use pg_ast::Indirection;
+use pg_ast::NamedValue;
+use pg_ast::Operator;
+use pg_ast::QualifiedOperator;
+use pg_ast::RowCompareKind;
+use pg_ast::SubLink;
+use pg_ast::SubLinkKind;
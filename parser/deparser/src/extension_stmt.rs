@@ -0,0 +1,63 @@
+impl Deparse for CreateExtensionStmt {
+    /// Alias: `CreateExtensionStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        f.write_str("CREATE EXTENSION ")?;
+        if self.existence() == Presence::Ignore {
+            f.write_str("IF NOT EXISTS ")?;
+        }
+        Ident(self.name()).fmt(f)?;
+
+        for option in self.options().unwrap_or_default() {
+            match option {
+                CreateExtensionOption::Schema(schema) => write!(f, " SCHEMA {}", Ident(schema))?,
+                CreateExtensionOption::Version(version) => write!(f, " VERSION {}", StrLit(version))?,
+                CreateExtensionOption::Cascade => f.write_str(" CASCADE")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Deparse for AlterExtensionStmt {
+    /// Alias: `AlterExtensionStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        write!(f, "ALTER EXTENSION {} UPDATE", Ident(self.name()))?;
+
+        for version in self.options().unwrap_or_default() {
+            write!(f, " TO {}", StrLit(version))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deparse for AlterEventTrigStmt {
+    /// Alias: `AlterEventTrigStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        let state = match self.state() {
+            EventTriggerState::FiresOnReplica => "ENABLE REPLICA",
+            EventTriggerState::FiresOnOrigin => "ENABLE",
+            EventTriggerState::FiresAlways => "ENABLE ALWAYS",
+            EventTriggerState::Disabled => "DISABLE",
+        };
+
+        write!(f, "ALTER EVENT TRIGGER {} {state}", Ident(self.trigger()))
+    }
+}
+
+use crate::Deparse;
+use crate::Ident;
+use crate::StrLit;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use pg_ast::AlterEventTrigStmt;
+use pg_ast::AlterExtensionStmt;
+use pg_ast::CreateExtensionOption;
+use pg_ast::CreateExtensionStmt;
+use pg_ast::EventTriggerState;
+use pg_ast::Presence;
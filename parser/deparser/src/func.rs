@@ -0,0 +1,236 @@
+impl Deparse for FuncCall {
+    /// Alias: `func_application`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}(", QualName(self.name()))?;
+
+        let order_within_group = match self.args() {
+            FuncArgsKind::Empty { order_within_group } => order_within_group.as_deref(),
+            FuncArgsKind::Wildcard { order_within_group } => {
+                f.write_char('*')?;
+                order_within_group.as_deref()
+            },
+            FuncArgsKind::All { args, order } => {
+                write_list(f, args, |f, Located(arg, _)| arg.deparse(f))?;
+                match order.as_ref().map(|Located(order, _)| order) {
+                    None => None,
+                    Some(FuncArgsOrder::OrderBy(order)) => {
+                        write_sort_clause(f, order)?;
+                        None
+                    },
+                    Some(FuncArgsOrder::WithinGroup(order)) => Some(&**order),
+                }
+            },
+            FuncArgsKind::Variadic { args, order } => {
+                if let Some((last, args)) = args.split_last() {
+                    for arg in args {
+                        write!(f, "{}, ", arg.sql())?;
+                    }
+                    write!(f, "VARIADIC {}", last.sql())?;
+                }
+                if let Some(order) = order {
+                    write_sort_clause(f, order)?;
+                }
+                None
+            },
+            FuncArgsKind::Distinct { args, order } => {
+                write!(f, "DISTINCT {}", List(args))?;
+                if let Some(order) = order {
+                    write_sort_clause(f, order)?;
+                }
+                None
+            },
+        };
+
+        f.write_char(')')?;
+
+        if let Some(order) = order_within_group {
+            write!(f, " WITHIN GROUP (ORDER BY {})", List(order))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deparse for FuncCallExpr {
+    /// Alias: `func_expr`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        self.func().deparse(f)?;
+
+        if let Some(filter) = self.agg_filter() {
+            write_filter_clause(f, filter)?;
+        }
+
+        match self.null_treatment() {
+            Some(NullTreatment::Ignore) => f.write_str(" IGNORE NULLS")?,
+            Some(NullTreatment::Respect) => f.write_str(" RESPECT NULLS")?,
+            None => {},
+        }
+
+        if let Some(over) = self.over() {
+            write_over_clause(f, over)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deparse for WindowDefinition {
+    /// Alias: `window_specification`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        let mut sep = "";
+        f.write_char('(')?;
+
+        if let Some(name) = self.name() {
+            Ident(name).fmt(f)?;
+            sep = " ";
+        }
+
+        if let Some(partition) = self.partition_clause() {
+            write!(f, "{sep}PARTITION BY {}", List(partition))?;
+            sep = " ";
+        }
+
+        if let Some(order) = self.order_clause() {
+            write!(f, "{sep}ORDER BY {}", List(order))?;
+            sep = " ";
+        }
+
+        if let Some(frame) = self.frame_clause() {
+            write!(f, "{sep}{}", frame.sql())?;
+        }
+
+        f.write_char(')')
+    }
+}
+
+impl Deparse for WindowFrame {
+    /// Alias: `frame_clause`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        let kind = match self.kind() {
+            WindowFrameKind::Range => "RANGE",
+            WindowFrameKind::Rows => "ROWS",
+            WindowFrameKind::Groups => "GROUPS",
+        };
+        write!(f, "{kind} ")?;
+
+        match self.frame_extent() {
+            FrameExtent::Unbounded { end: None } => f.write_str("UNBOUNDED PRECEDING")?,
+            FrameExtent::Unbounded { end: Some(end) } => {
+                write!(f, "BETWEEN UNBOUNDED PRECEDING AND {}", PrecedingEndBound(end))?
+            },
+            FrameExtent::CurrentRow { end: None } => f.write_str("CURRENT ROW")?,
+            FrameExtent::CurrentRow { end: Some(end) } => {
+                f.write_str("BETWEEN CURRENT ROW AND ")?;
+                match end {
+                    CurrentRowEnd::Unbounded => f.write_str("UNBOUNDED FOLLOWING")?,
+                    CurrentRowEnd::CurrentRow => f.write_str("CURRENT ROW")?,
+                    CurrentRowEnd::Following(end) => write!(f, "{} FOLLOWING", end.sql())?,
+                }
+            },
+            FrameExtent::Preceding { start, end: None } => write!(f, "{} PRECEDING", start.sql())?,
+            FrameExtent::Preceding { start, end: Some(end) } => {
+                write!(f, "BETWEEN {} PRECEDING AND {}", start.sql(), PrecedingEndBound(end))?
+            },
+            FrameExtent::Following { start, end } => {
+                write!(f, "BETWEEN {} FOLLOWING AND ", start.sql())?;
+                match end {
+                    FollowingEnd::Unbounded => f.write_str("UNBOUNDED FOLLOWING")?,
+                    FollowingEnd::Following(end) => write!(f, "{} FOLLOWING", end.sql())?,
+                }
+            },
+        }
+
+        match self.window_exclusion() {
+            WindowExclusion::NoOthers => Ok(()),
+            WindowExclusion::CurrentRow => f.write_str(" EXCLUDE CURRENT ROW"),
+            WindowExclusion::Group => f.write_str(" EXCLUDE GROUP"),
+            WindowExclusion::Ties => f.write_str(" EXCLUDE TIES"),
+        }
+    }
+}
+
+impl Deparse for SortBy {
+    /// Alias: `sortby`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        self.expr().deparse(f)?;
+
+        match self.direction() {
+            Some(SortDirection::Ascending) => f.write_str(" ASC")?,
+            Some(SortDirection::Descending) => f.write_str(" DESC")?,
+            Some(SortDirection::Using(op)) => write!(f, " USING {}", AllOp(op))?,
+            None => {},
+        }
+
+        match self.nulls() {
+            Some(SortNulls::NullsFirst) => f.write_str(" NULLS FIRST"),
+            Some(SortNulls::NullsLast) => f.write_str(" NULLS LAST"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// `( sort_clause )?`, with a leading space.
+pub(crate) fn write_sort_clause(f: &mut Formatter<'_>, order: &[SortBy]) -> fmt::Result {
+    write!(f, " ORDER BY {}", List(order))
+}
+
+/// `filter_clause`, with a leading space.
+pub(crate) fn write_filter_clause(f: &mut Formatter<'_>, filter: &ExprNode) -> fmt::Result {
+    write!(f, " FILTER (WHERE {})", filter.sql())
+}
+
+/// `over_clause`, with a leading space.
+pub(crate) fn write_over_clause(f: &mut Formatter<'_>, over: &OverClause) -> fmt::Result {
+    match over {
+        OverClause::WindowName(name) => write!(f, " OVER {}", Ident(name)),
+        OverClause::WindowDefinition(definition) => write!(f, " OVER {}", definition.sql()),
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct PrecedingEndBound<'a>(&'a PrecedingEnd);
+
+impl Display for PrecedingEndBound<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            PrecedingEnd::Unbounded => f.write_str("UNBOUNDED FOLLOWING"),
+            PrecedingEnd::CurrentRow => f.write_str("CURRENT ROW"),
+            PrecedingEnd::Preceding(end) => write!(f, "{} PRECEDING", end.sql()),
+            PrecedingEnd::Following(end) => write!(f, "{} FOLLOWING", end.sql()),
+        }
+    }
+}
+
+use crate::write_list;
+use crate::AllOp;
+use crate::Deparse;
+use crate::Ident;
+use crate::List;
+use crate::QualName;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Write;
+use pg_ast::CurrentRowEnd;
+use pg_ast::ExprNode;
+use pg_ast::FollowingEnd;
+use pg_ast::FrameExtent;
+use pg_ast::FuncArgsKind;
+use pg_ast::FuncArgsOrder;
+use pg_ast::FuncCall;
+use pg_ast::FuncCallExpr;
+use pg_ast::NullTreatment;
+use pg_ast::OverClause;
+use pg_ast::PrecedingEnd;
+use pg_ast::SortBy;
+use pg_ast::SortDirection;
+use pg_ast::SortNulls;
+use pg_ast::WindowDefinition;
+use pg_ast::WindowExclusion;
+use pg_ast::WindowFrame;
+use pg_ast::WindowFrameKind;
+use pg_basics::Located;
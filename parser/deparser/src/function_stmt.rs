@@ -0,0 +1,52 @@
+impl Deparse for AlterFunctionStmt {
+    /// Alias: `AlterFunctionStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        let kind = match self.kind() {
+            AlterFunctionKind::Function => "FUNCTION",
+            AlterFunctionKind::Procedure => "PROCEDURE",
+            AlterFunctionKind::Routine => "ROUTINE",
+        };
+
+        write!(f, "ALTER {kind} {}", self.name().sql())?;
+
+        for action in self.actions() {
+            write!(f, " {}", action.sql())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deparse for AlterFunctionOption {
+    /// Alias: `common_func_opt_item`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cost(cost) => write!(f, "COST {}", cost.sql()),
+            Self::Leakproof(true) => f.write_str("LEAKPROOF"),
+            Self::Leakproof(false) => f.write_str("NOT LEAKPROOF"),
+            Self::Parallel(mode) => write!(f, "PARALLEL {}", Ident(mode)),
+            Self::Reset(target) => write!(f, "RESET {}", target.sql()),
+            Self::Rows(rows) => write!(f, "ROWS {}", rows.sql()),
+            Self::Security(true) => f.write_str("SECURITY DEFINER"),
+            Self::Security(false) => f.write_str("SECURITY INVOKER"),
+            Self::Set(set_rest) => write!(f, "SET {}", set_rest.sql()),
+            Self::Strict(true) => f.write_str("STRICT"),
+            Self::Strict(false) => f.write_str("CALLED ON NULL INPUT"),
+            Self::Support(name) => write!(f, "SUPPORT {}", QualName(name)),
+            Self::Volatility(Volatility::Immutable) => f.write_str("IMMUTABLE"),
+            Self::Volatility(Volatility::Stable) => f.write_str("STABLE"),
+            Self::Volatility(Volatility::Volatile) => f.write_str("VOLATILE"),
+        }
+    }
+}
+
+use crate::Deparse;
+use crate::Ident;
+use crate::QualName;
+use core::fmt;
+use core::fmt::Formatter;
+use pg_ast::AlterFunctionKind;
+use pg_ast::AlterFunctionOption;
+use pg_ast::AlterFunctionStmt;
+use pg_ast::Volatility;
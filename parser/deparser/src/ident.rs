@@ -0,0 +1,177 @@
+/// Alias: `ColId`
+///
+/// Quoted, unless it's a lowercase identifier, that isn't a keyword, or it's an unreserved keyword.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Ident<'a>(pub &'a str);
+
+impl Display for Ident<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        let bare = is_bare_identifier(self.0)
+            && Keyword::find(self.0).is_none_or(|kw| kw.category() == Unreserved);
+
+        write_identifier(f, self.0, bare)
+    }
+}
+
+/// Alias: `ColLabel`
+///
+/// Any keyword is accepted as a label, so it's only quoted when it isn't a lowercase identifier.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Label<'a>(pub &'a str);
+
+impl Display for Label<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_identifier(f, self.0, is_bare_identifier(self.0))
+    }
+}
+
+/// Alias: `any_name`
+///
+/// E.g.: `catalog.schema.name`
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct QualName<'a>(pub &'a [Str]);
+
+impl Display for QualName<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        let Some((first, attrs)) = self.0.split_first() else {
+            return Ok(())
+        };
+
+        Ident(first).fmt(f)?;
+        for attr in attrs {
+            write!(f, ".{}", Label(attr))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Alias: `IDENT`
+///
+/// Quoted when it's any keyword, for positions that don't accept keywords, or that give some of them a special meaning.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Identifier<'a>(pub &'a str);
+
+impl Display for Identifier<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let bare = is_bare_identifier(self.0) && Keyword::find(self.0).is_none();
+        write_identifier(f, self.0, bare)
+    }
+}
+
+/// Alias: `var_name`
+///
+/// Keywords are always quoted, because `SET` has special forms for some of them (e.g.: `SET NAMES`).
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct VarName<'a>(pub &'a [Str]);
+
+impl Display for VarName<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, name) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_char('.')?;
+            }
+            Identifier(name).fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Comma separated `ColId`s.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Idents<'a>(pub &'a [Str]);
+
+impl Display for Idents<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_list(f, self.0, |f, name| Ident(name).fmt(f))
+    }
+}
+
+/// Comma separated nodes.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct List<'a, T>(pub &'a [T]);
+
+impl<T: Deparse> Display for List<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_list(f, self.0, |f, item| item.deparse(f))
+    }
+}
+
+pub(crate) fn write_list<T>(
+    f: &mut Formatter<'_>,
+    items: &[T],
+    mut write_item: impl FnMut(&mut Formatter<'_>, &T) -> fmt::Result
+)
+    -> fmt::Result
+{
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        write_item(f, item)?;
+    }
+
+    Ok(())
+}
+
+/// Same rule as `quote_identifier()`: lowercase letters, digits and underscores, not starting with a digit.
+fn is_bare_identifier(ident: &str) -> bool {
+
+    let mut chars = ident.chars();
+
+    chars.next().is_some_and(|c| c.is_ascii_lowercase() || c == '_')
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+fn write_identifier(f: &mut Formatter<'_>, ident: &str, bare: bool) -> fmt::Result {
+
+    if bare {
+        return f.write_str(ident)
+    }
+
+    f.write_char('"')?;
+    for c in ident.chars() {
+        if c == '"' {
+            f.write_char('"')?;
+        }
+        f.write_char(c)?;
+    }
+    f.write_char('"')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("foo", "foo")]
+    #[test_case("_foo1", "_foo1")]
+    #[test_case("Foo", r#""Foo""#)]
+    #[test_case("1foo", r#""1foo""#)]
+    #[test_case("foo bar", r#""foo bar""#)]
+    #[test_case(r#"a"b"#, r#""a""b""#)]
+    #[test_case("abort", "abort")]
+    #[test_case("char", r#""char""#)]
+    #[test_case("left", r#""left""#)]
+    #[test_case("select", r#""select""#)]
+    fn test_ident(ident: &str, expected: &str) {
+        assert_eq!(expected, Ident(ident).to_string())
+    }
+
+    #[test_case("select", "select")]
+    #[test_case("Select", r#""Select""#)]
+    fn test_label(label: &str, expected: &str) {
+        assert_eq!(expected, Label(label).to_string())
+    }
+}
+
+use crate::Deparse;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Write;
+use pg_basics::Str;
+use pg_lexer::Keyword;
+use pg_lexer::KeywordCategory::Unreserved;
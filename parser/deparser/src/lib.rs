@@ -0,0 +1,38 @@
+//! Renders the AST back into SQL text.
+//!
+//! The output is normalized (uppercase keywords, minimal parenthesis and quoting),
+//! so it's not the original source, but it parses back into the same AST.
+
+pg_basics::reexport! { pub
+    deparse,
+}
+
+pg_basics::reexport! { pub(crate)
+    define_stmt,
+    expr,
+    func,
+    ident,
+    literal,
+    object_stmt,
+    sequence_stmt,
+    sql_function,
+    stmt,
+}
+
+mod create_stmt;
+mod database_stmt;
+mod extension_stmt;
+mod function_stmt;
+mod operator_stmt;
+mod privilege;
+mod prop_graph_stmt;
+#[cfg(test)]
+mod round_trip;
+mod role_stmt;
+mod select;
+mod signature;
+mod set_stmt;
+mod table_ref;
+mod text_search_stmt;
+mod type_name;
+mod type_stmt;
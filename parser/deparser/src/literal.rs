@@ -0,0 +1,103 @@
+/// Alias: `SCONST`
+///
+/// Uses the escape syntax (`E'...'`) only when the value has backslashes.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct StrLit<'a>(pub &'a str);
+
+impl Display for StrLit<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        let escape = self.0.contains('\\');
+        if escape {
+            f.write_char('E')?;
+        }
+
+        f.write_char('\'')?;
+        for c in self.0.chars() {
+            match c {
+                '\'' => f.write_str("''")?,
+                '\\' => f.write_str("\\\\")?,
+                c => f.write_char(c)?,
+            }
+        }
+        f.write_char('\'')
+    }
+}
+
+/// `ICONST` or `FCONST`, including the radix prefix.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct NumLit<'a> {
+    pub value: &'a str,
+    pub radix: NumberRadix,
+}
+
+impl Display for NumLit<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        let (sign, digits) = match self.value.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", self.value),
+        };
+
+        let prefix = match self.radix {
+            NumberRadix::Binary => "0b",
+            NumberRadix::Octal => "0o",
+            NumberRadix::Decimal => "",
+            NumberRadix::Hexadecimal => "0x",
+        };
+
+        let has_prefix = !prefix.is_empty()
+            && digits.get(..2).is_some_and(|p| p.eq_ignore_ascii_case(prefix));
+
+        if has_prefix {
+            write!(f, "{sign}{digits}")
+        }
+        else {
+            write!(f, "{sign}{prefix}{digits}")
+        }
+    }
+}
+
+impl Deparse for SignedNumber {
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IntegerConst(int) => write!(f, "{int}"),
+            Self::NumericConst { value, radix, negative } => {
+                if *negative {
+                    f.write_char('-')?;
+                }
+                NumLit { value, radix: *radix }.fmt(f)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("foo", "'foo'")]
+    #[test_case("it's", "'it''s'")]
+    #[test_case(r"a\b", r"E'a\\b'")]
+    #[test_case(r"it's a\b", r"E'it''s a\\b'")]
+    fn test_str_lit(value: &str, expected: &str) {
+        assert_eq!(expected, StrLit(value).to_string())
+    }
+
+    #[test_case("1.5", NumberRadix::Decimal, "1.5")]
+    #[test_case("-1.5", NumberRadix::Decimal, "-1.5")]
+    #[test_case("FFFFFFFFFF", NumberRadix::Hexadecimal, "0xFFFFFFFFFF")]
+    #[test_case("0xFFFFFFFFFF", NumberRadix::Hexadecimal, "0xFFFFFFFFFF")]
+    fn test_num_lit(value: &str, radix: NumberRadix, expected: &str) {
+        assert_eq!(expected, NumLit { value, radix }.to_string())
+    }
+}
+
+use crate::Deparse;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Write;
+use pg_ast::SignedNumber;
+use pg_basics::NumberRadix;
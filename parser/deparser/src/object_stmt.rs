@@ -0,0 +1,509 @@
+impl Deparse for CommentStmt {
+    /// Alias: `CommentStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "COMMENT ON {} IS ", self.target().sql())?;
+        write_text_or_null(f, self.comment())
+    }
+}
+
+impl Deparse for CommentTarget {
+    /// Alias: `comment_target`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AccessMethod(name) => write!(f, "ACCESS METHOD {}", Ident(name)),
+            Self::Aggregate(aggregate) => write!(f, "AGGREGATE {}", aggregate.sql()),
+            Self::Collation(name) => write!(f, "COLLATION {}", QualName(name)),
+            Self::Column(name) => write!(f, "COLUMN {}", QualName(name)),
+            Self::Conversion(name) => write!(f, "CONVERSION {}", QualName(name)),
+            Self::Database(name) => write!(f, "DATABASE {}", Ident(name)),
+            Self::Domain(typ) => write!(f, "DOMAIN {}", typ.sql()),
+            Self::DomainConstraint { domain, constraint } => {
+                write!(f, "CONSTRAINT {} ON DOMAIN {}", Ident(constraint), domain.sql())
+            },
+            Self::EventTrigger(name) => write!(f, "EVENT TRIGGER {}", Ident(name)),
+            Self::ExtendedStatistics(name) => write!(f, "STATISTICS {}", QualName(name)),
+            Self::Extension(name) => write!(f, "EXTENSION {}", Ident(name)),
+            Self::ForeignDataWrapper(name) => write!(f, "FOREIGN DATA WRAPPER {}", Ident(name)),
+            Self::ForeignServer(name) => write!(f, "SERVER {}", Ident(name)),
+            Self::ForeignTable(name) => write!(f, "FOREIGN TABLE {}", QualName(name)),
+            Self::Function(function) => write!(f, "FUNCTION {}", function.sql()),
+            Self::Index(name) => write!(f, "INDEX {}", QualName(name)),
+            Self::Language(name) => write!(f, "LANGUAGE {}", Ident(name)),
+            Self::LargeObject(oid) => write!(f, "LARGE OBJECT {}", oid.sql()),
+            Self::MaterializedView(name) => write!(f, "MATERIALIZED VIEW {}", QualName(name)),
+            Self::Operator(operator) => write!(f, "OPERATOR {}", operator.sql()),
+            Self::OperatorClass { name, index_method } => {
+                write!(f, "OPERATOR CLASS {} USING {}", QualName(name), Ident(index_method))
+            },
+            Self::OperatorFamily { name, index_method } => {
+                write!(f, "OPERATOR FAMILY {} USING {}", QualName(name), Ident(index_method))
+            },
+            Self::Policy { name, table } => write!(f, "POLICY {} ON {}", Ident(name), QualName(table)),
+            Self::Procedure(procedure) => write!(f, "PROCEDURE {}", procedure.sql()),
+            Self::Publication(name) => write!(f, "PUBLICATION {}", Ident(name)),
+            Self::Role(name) => write!(f, "ROLE {}", Ident(name)),
+            Self::Routine(routine) => write!(f, "ROUTINE {}", routine.sql()),
+            Self::Rule { name, table } => write!(f, "RULE {} ON {}", Ident(name), QualName(table)),
+            Self::Schema(name) => write!(f, "SCHEMA {}", Ident(name)),
+            Self::Sequence(name) => write!(f, "SEQUENCE {}", QualName(name)),
+            Self::Subscription(name) => write!(f, "SUBSCRIPTION {}", Ident(name)),
+            Self::Table(name) => write!(f, "TABLE {}", QualName(name)),
+            Self::TableConstraint { table, constraint } => {
+                write!(f, "CONSTRAINT {} ON {}", Ident(constraint), QualName(table))
+            },
+            Self::Tablespace(name) => write!(f, "TABLESPACE {}", Ident(name)),
+            Self::TextSearchConfiguration(name) => write!(f, "TEXT SEARCH CONFIGURATION {}", QualName(name)),
+            Self::TextSearchDictionary(name) => write!(f, "TEXT SEARCH DICTIONARY {}", QualName(name)),
+            Self::TextSearchParser(name) => write!(f, "TEXT SEARCH PARSER {}", QualName(name)),
+            Self::TextSearchTemplate(name) => write!(f, "TEXT SEARCH TEMPLATE {}", QualName(name)),
+            Self::Transform(transform) => transform.deparse(f),
+            Self::Trigger { name, table } => write!(f, "TRIGGER {} ON {}", Ident(name), QualName(table)),
+            Self::Type(typ) => write!(f, "TYPE {}", typ.sql()),
+            Self::Typecast(typecast) => typecast.deparse(f),
+            Self::View(name) => write!(f, "VIEW {}", QualName(name)),
+        }
+    }
+}
+
+impl Deparse for SecurityLabelStmt {
+    /// Alias: `SecLabelStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        f.write_str("SECURITY LABEL ")?;
+        if let Some(provider) = self.provider() {
+            write!(f, "FOR {} ", StrLit(provider))?;
+        }
+
+        write!(f, "ON {} IS ", self.target().sql())?;
+        write_text_or_null(f, self.label())
+    }
+}
+
+impl Deparse for SecurityLabelTarget {
+    /// Alias: `label_target`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AccessMethod(name) => write!(f, "ACCESS METHOD {}", Ident(name)),
+            Self::Aggregate(aggregate) => write!(f, "AGGREGATE {}", aggregate.sql()),
+            Self::Collation(name) => write!(f, "COLLATION {}", QualName(name)),
+            Self::Column(name) => write!(f, "COLUMN {}", QualName(name)),
+            Self::Conversion(name) => write!(f, "CONVERSION {}", QualName(name)),
+            Self::Database(name) => write!(f, "DATABASE {}", Ident(name)),
+            Self::Domain(typ) => write!(f, "DOMAIN {}", typ.sql()),
+            Self::EventTrigger(name) => write!(f, "EVENT TRIGGER {}", Ident(name)),
+            Self::ExtendedStatistics(name) => write!(f, "STATISTICS {}", QualName(name)),
+            Self::Extension(name) => write!(f, "EXTENSION {}", Ident(name)),
+            Self::ForeignDataWrapper(name) => write!(f, "FOREIGN DATA WRAPPER {}", Ident(name)),
+            Self::ForeignServer(name) => write!(f, "SERVER {}", Ident(name)),
+            Self::ForeignTable(name) => write!(f, "FOREIGN TABLE {}", QualName(name)),
+            Self::Function(function) => write!(f, "FUNCTION {}", function.sql()),
+            Self::Index(name) => write!(f, "INDEX {}", QualName(name)),
+            Self::Language(name) => write!(f, "LANGUAGE {}", Ident(name)),
+            Self::LargeObject(oid) => write!(f, "LARGE OBJECT {}", oid.sql()),
+            Self::MaterializedView(name) => write!(f, "MATERIALIZED VIEW {}", QualName(name)),
+            Self::Procedure(procedure) => write!(f, "PROCEDURE {}", procedure.sql()),
+            Self::Publication(name) => write!(f, "PUBLICATION {}", Ident(name)),
+            Self::Role(name) => write!(f, "ROLE {}", Ident(name)),
+            Self::Routine(routine) => write!(f, "ROUTINE {}", routine.sql()),
+            Self::Schema(name) => write!(f, "SCHEMA {}", Ident(name)),
+            Self::Sequence(name) => write!(f, "SEQUENCE {}", QualName(name)),
+            Self::Subscription(name) => write!(f, "SUBSCRIPTION {}", Ident(name)),
+            Self::Table(name) => write!(f, "TABLE {}", QualName(name)),
+            Self::Tablespace(name) => write!(f, "TABLESPACE {}", Ident(name)),
+            Self::TextSearchConfiguration(name) => write!(f, "TEXT SEARCH CONFIGURATION {}", QualName(name)),
+            Self::TextSearchDictionary(name) => write!(f, "TEXT SEARCH DICTIONARY {}", QualName(name)),
+            Self::TextSearchParser(name) => write!(f, "TEXT SEARCH PARSER {}", QualName(name)),
+            Self::TextSearchTemplate(name) => write!(f, "TEXT SEARCH TEMPLATE {}", QualName(name)),
+            Self::Type(typ) => write!(f, "TYPE {}", typ.sql()),
+            Self::View(name) => write!(f, "VIEW {}", QualName(name)),
+        }
+    }
+}
+
+impl Deparse for DropStmt {
+    /// Alias: `DropStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        let (kind, names) = match self.target() {
+            DropTarget::PropertyGraph(names) => ("PROPERTY GRAPH", names),
+        };
+
+        write!(f, "DROP {kind} ")?;
+        write_if_exists(f, self.existence() == Presence::Ignore)?;
+        write_list(f, names, |f, name| QualName(name).fmt(f))?;
+        write_drop_behavior(f, self.behavior())
+    }
+}
+
+impl Deparse for RenameStmt {
+    /// Alias: `RenameStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        f.write_str("ALTER ")?;
+
+        match self.target() {
+            RenameTarget::Aggregate(aggregate) => write!(f, "AGGREGATE {}", aggregate.sql())?,
+            RenameTarget::Collation(name) => write!(f, "COLLATION {}", QualName(name))?,
+            RenameTarget::Conversion(name) => write!(f, "CONVERSION {}", QualName(name))?,
+            RenameTarget::Database(name) => write!(f, "DATABASE {}", Ident(name))?,
+            RenameTarget::Domain(name) => write!(f, "DOMAIN {}", QualName(name))?,
+            RenameTarget::DomainConstraint { domain, constraint } => {
+                write!(f, "DOMAIN {} RENAME CONSTRAINT {}", QualName(domain), Ident(constraint))?
+            },
+            RenameTarget::EventTrigger(name) => write!(f, "EVENT TRIGGER {}", Ident(name))?,
+            RenameTarget::ForeignDataWrapper(name) => write!(f, "FOREIGN DATA WRAPPER {}", Ident(name))?,
+            RenameTarget::ForeignServer(name) => write!(f, "SERVER {}", Ident(name))?,
+            RenameTarget::ForeignTable { target, missing_ok } => {
+                f.write_str("FOREIGN TABLE ")?;
+                write_if_exists(f, *missing_ok)?;
+                target.deparse(f)?;
+            },
+            RenameTarget::ForeignTableColumn { table, column, missing_ok } => {
+                f.write_str("FOREIGN TABLE ")?;
+                write_if_exists(f, *missing_ok)?;
+                write!(f, "{} RENAME COLUMN {}", table.sql(), Ident(column))?;
+            },
+            RenameTarget::Function(function) => write!(f, "FUNCTION {}", function.sql())?,
+            RenameTarget::Index { target, missing_ok } => {
+                f.write_str("INDEX ")?;
+                write_if_exists(f, *missing_ok)?;
+                QualName(target).fmt(f)?;
+            },
+            RenameTarget::Language(name) => write!(f, "LANGUAGE {}", Ident(name))?,
+            RenameTarget::MaterializedView { target, missing_ok } => {
+                f.write_str("MATERIALIZED VIEW ")?;
+                write_if_exists(f, *missing_ok)?;
+                QualName(target).fmt(f)?;
+            },
+            RenameTarget::MaterializedViewColumn { view, column, missing_ok } => {
+                f.write_str("MATERIALIZED VIEW ")?;
+                write_if_exists(f, *missing_ok)?;
+                write!(f, "{} RENAME COLUMN {}", QualName(view), QualName(column))?;
+            },
+            RenameTarget::OperatorClass(name) => write_op_class(f, "CLASS", name)?,
+            RenameTarget::OperatorFamily(name) => write_op_class(f, "FAMILY", name)?,
+            RenameTarget::Policy { table, policy, missing_ok } => {
+                f.write_str("POLICY ")?;
+                write_if_exists(f, *missing_ok)?;
+                write!(f, "{} ON {}", Ident(policy), QualName(table))?;
+            },
+            RenameTarget::Procedure(procedure) => write!(f, "PROCEDURE {}", procedure.sql())?,
+            RenameTarget::PropertyGraph(name) => write!(f, "PROPERTY GRAPH {}", QualName(name))?,
+            RenameTarget::Publication(name) => write!(f, "PUBLICATION {}", Ident(name))?,
+            RenameTarget::Role(name) => write!(f, "ROLE {}", Ident(name))?,
+            RenameTarget::Routine(routine) => write!(f, "ROUTINE {}", routine.sql())?,
+            RenameTarget::Rule { relation, rule } => {
+                write!(f, "RULE {} ON {}", Ident(rule), QualName(relation))?
+            },
+            RenameTarget::Schema(name) => write!(f, "SCHEMA {}", Ident(name))?,
+            RenameTarget::Sequence { target, missing_ok } => {
+                f.write_str("SEQUENCE ")?;
+                write_if_exists(f, *missing_ok)?;
+                QualName(target).fmt(f)?;
+            },
+            RenameTarget::Statistic(name) => write!(f, "STATISTICS {}", QualName(name))?,
+            RenameTarget::Subscription(name) => write!(f, "SUBSCRIPTION {}", Ident(name))?,
+            RenameTarget::Table { target, missing_ok } => {
+                f.write_str("TABLE ")?;
+                write_if_exists(f, *missing_ok)?;
+                target.deparse(f)?;
+            },
+            RenameTarget::TableColumn { table, column, missing_ok } => {
+                f.write_str("TABLE ")?;
+                write_if_exists(f, *missing_ok)?;
+                write!(f, "{} RENAME COLUMN {}", table.sql(), Ident(column))?;
+            },
+            RenameTarget::TableConstraint { table, constraint, missing_ok } => {
+                f.write_str("TABLE ")?;
+                write_if_exists(f, *missing_ok)?;
+                write!(f, "{} RENAME CONSTRAINT {}", table.sql(), Ident(constraint))?;
+            },
+            RenameTarget::Tablespace(name) => write!(f, "TABLESPACE {}", Ident(name))?,
+            RenameTarget::TextSearchConfiguration(name) => write!(f, "TEXT SEARCH CONFIGURATION {}", QualName(name))?,
+            RenameTarget::TextSearchDictionary(name) => write!(f, "TEXT SEARCH DICTIONARY {}", QualName(name))?,
+            RenameTarget::TextSearchParser(name) => write!(f, "TEXT SEARCH PARSER {}", QualName(name))?,
+            RenameTarget::TextSearchTemplate(name) => write!(f, "TEXT SEARCH TEMPLATE {}", QualName(name))?,
+            RenameTarget::Trigger { table, trigger } => {
+                write!(f, "TRIGGER {} ON {}", Ident(trigger), QualName(table))?
+            },
+            RenameTarget::Type(name) => write!(f, "TYPE {}", QualName(name))?,
+            RenameTarget::TypeAttribute { typ, attribute } => {
+                write!(f, "TYPE {} RENAME ATTRIBUTE {}", QualName(typ), Ident(attribute))?
+            },
+            RenameTarget::View { target, missing_ok } => {
+                f.write_str("VIEW ")?;
+                write_if_exists(f, *missing_ok)?;
+                QualName(target).fmt(f)?;
+            },
+            RenameTarget::ViewColumn { view, column, missing_ok } => {
+                f.write_str("VIEW ")?;
+                write_if_exists(f, *missing_ok)?;
+                write!(f, "{} RENAME COLUMN {}", QualName(view), Ident(column))?;
+            },
+        }
+
+        // Sub-object renames already include the `RENAME <kind> name` prefix.
+        let renames_sub_object = matches!(self.target(),
+            RenameTarget::DomainConstraint { .. }
+            | RenameTarget::ForeignTableColumn { .. }
+            | RenameTarget::MaterializedViewColumn { .. }
+            | RenameTarget::TableColumn { .. }
+            | RenameTarget::TableConstraint { .. }
+            | RenameTarget::TypeAttribute { .. }
+            | RenameTarget::ViewColumn { .. }
+        );
+
+        if renames_sub_object {
+            write!(f, " TO {}", Ident(self.new_name()))
+        }
+        else {
+            write!(f, " RENAME TO {}", Ident(self.new_name()))
+        }
+    }
+}
+
+impl Deparse for AlterOwnerStmt {
+    /// Alias: `AlterOwnerStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        f.write_str("ALTER ")?;
+
+        match self.target() {
+            AlterOwnerTarget::Aggregate(aggregate) => write!(f, "AGGREGATE {}", aggregate.sql())?,
+            AlterOwnerTarget::Collation(name) => write!(f, "COLLATION {}", QualName(name))?,
+            AlterOwnerTarget::Conversion(name) => write!(f, "CONVERSION {}", QualName(name))?,
+            AlterOwnerTarget::Database(name) => write!(f, "DATABASE {}", Ident(name))?,
+            AlterOwnerTarget::Domain(name) => write!(f, "DOMAIN {}", QualName(name))?,
+            AlterOwnerTarget::EventTrigger(name) => write!(f, "EVENT TRIGGER {}", Ident(name))?,
+            AlterOwnerTarget::ForeignDataWrapper(name) => write!(f, "FOREIGN DATA WRAPPER {}", Ident(name))?,
+            AlterOwnerTarget::ForeignServer(name) => write!(f, "SERVER {}", Ident(name))?,
+            AlterOwnerTarget::Function(function) => write!(f, "FUNCTION {}", function.sql())?,
+            AlterOwnerTarget::Language(name) => write!(f, "LANGUAGE {}", Ident(name))?,
+            AlterOwnerTarget::LargeObject(oid) => write!(f, "LARGE OBJECT {}", oid.sql())?,
+            AlterOwnerTarget::Operator(operator) => write!(f, "OPERATOR {}", operator.sql())?,
+            AlterOwnerTarget::OperatorClass(name) => write_op_class(f, "CLASS", name)?,
+            AlterOwnerTarget::OperatorFamily(name) => write_op_class(f, "FAMILY", name)?,
+            AlterOwnerTarget::Procedure(procedure) => write!(f, "PROCEDURE {}", procedure.sql())?,
+            AlterOwnerTarget::PropertyGraph(name) => write!(f, "PROPERTY GRAPH {}", QualName(name))?,
+            AlterOwnerTarget::Publication(name) => write!(f, "PUBLICATION {}", Ident(name))?,
+            AlterOwnerTarget::Routine(routine) => write!(f, "ROUTINE {}", routine.sql())?,
+            AlterOwnerTarget::Schema(name) => write!(f, "SCHEMA {}", Ident(name))?,
+            AlterOwnerTarget::Statistic(name) => write!(f, "STATISTICS {}", QualName(name))?,
+            AlterOwnerTarget::Subscription(name) => write!(f, "SUBSCRIPTION {}", Ident(name))?,
+            AlterOwnerTarget::Tablespace(name) => write!(f, "TABLESPACE {}", Ident(name))?,
+            AlterOwnerTarget::TextSearchConfiguration(name) => write!(f, "TEXT SEARCH CONFIGURATION {}", QualName(name))?,
+            AlterOwnerTarget::TextSearchDictionary(name) => write!(f, "TEXT SEARCH DICTIONARY {}", QualName(name))?,
+            AlterOwnerTarget::Type(name) => write!(f, "TYPE {}", QualName(name))?,
+        }
+
+        write!(f, " OWNER TO {}", self.new_owner().sql())
+    }
+}
+
+impl Deparse for AlterObjectSchemaStmt {
+    /// Alias: `AlterObjectSchemaStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        f.write_str("ALTER ")?;
+
+        match self.target() {
+            AlterObjectSchemaTarget::Aggregate(aggregate) => write!(f, "AGGREGATE {}", aggregate.sql())?,
+            AlterObjectSchemaTarget::Collation(name) => write!(f, "COLLATION {}", QualName(name))?,
+            AlterObjectSchemaTarget::Conversion(name) => write!(f, "CONVERSION {}", QualName(name))?,
+            AlterObjectSchemaTarget::Domain(name) => write!(f, "DOMAIN {}", QualName(name))?,
+            AlterObjectSchemaTarget::Extension(name) => write!(f, "EXTENSION {}", Ident(name))?,
+            AlterObjectSchemaTarget::ForeignTable { target, missing_ok } => {
+                f.write_str("FOREIGN TABLE ")?;
+                write_if_exists(f, *missing_ok)?;
+                target.deparse(f)?;
+            },
+            AlterObjectSchemaTarget::Function(function) => write!(f, "FUNCTION {}", function.sql())?,
+            AlterObjectSchemaTarget::MaterializedView { target, missing_ok } => {
+                f.write_str("MATERIALIZED VIEW ")?;
+                write_if_exists(f, *missing_ok)?;
+                QualName(target).fmt(f)?;
+            },
+            AlterObjectSchemaTarget::Operator(operator) => write!(f, "OPERATOR {}", operator.sql())?,
+            AlterObjectSchemaTarget::OperatorClass(name) => write_op_class(f, "CLASS", name)?,
+            AlterObjectSchemaTarget::OperatorFamily(name) => write_op_class(f, "FAMILY", name)?,
+            AlterObjectSchemaTarget::Procedure(procedure) => write!(f, "PROCEDURE {}", procedure.sql())?,
+            AlterObjectSchemaTarget::PropertyGraph { target, missing_ok } => {
+                f.write_str("PROPERTY GRAPH ")?;
+                write_if_exists(f, *missing_ok)?;
+                QualName(target).fmt(f)?;
+            },
+            AlterObjectSchemaTarget::Routine(routine) => write!(f, "ROUTINE {}", routine.sql())?,
+            AlterObjectSchemaTarget::Sequence { target, missing_ok } => {
+                f.write_str("SEQUENCE ")?;
+                write_if_exists(f, *missing_ok)?;
+                QualName(target).fmt(f)?;
+            },
+            AlterObjectSchemaTarget::Statistic(name) => write!(f, "STATISTICS {}", QualName(name))?,
+            AlterObjectSchemaTarget::Table { target, missing_ok } => {
+                f.write_str("TABLE ")?;
+                write_if_exists(f, *missing_ok)?;
+                target.deparse(f)?;
+            },
+            AlterObjectSchemaTarget::TextSearchConfiguration(name) => write!(f, "TEXT SEARCH CONFIGURATION {}", QualName(name))?,
+            AlterObjectSchemaTarget::TextSearchDictionary(name) => write!(f, "TEXT SEARCH DICTIONARY {}", QualName(name))?,
+            AlterObjectSchemaTarget::TextSearchParser(name) => write!(f, "TEXT SEARCH PARSER {}", QualName(name))?,
+            AlterObjectSchemaTarget::TextSearchTemplate(name) => write!(f, "TEXT SEARCH TEMPLATE {}", QualName(name))?,
+            AlterObjectSchemaTarget::Type(name) => write!(f, "TYPE {}", QualName(name))?,
+            AlterObjectSchemaTarget::View { target, missing_ok } => {
+                f.write_str("VIEW ")?;
+                write_if_exists(f, *missing_ok)?;
+                QualName(target).fmt(f)?;
+            },
+        }
+
+        write!(f, " SET SCHEMA {}", Ident(self.new_schema()))
+    }
+}
+
+impl Deparse for AlterObjectDependsStmt {
+    /// Alias: `AlterObjectDependsStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        f.write_str("ALTER ")?;
+
+        match self.target() {
+            AlterObjectDependsTarget::Function(function) => write!(f, "FUNCTION {}", function.sql())?,
+            AlterObjectDependsTarget::Index(name) => write!(f, "INDEX {}", QualName(name))?,
+            AlterObjectDependsTarget::MaterializedView(name) => write!(f, "MATERIALIZED VIEW {}", QualName(name))?,
+            AlterObjectDependsTarget::Procedure(procedure) => write!(f, "PROCEDURE {}", procedure.sql())?,
+            AlterObjectDependsTarget::Routine(routine) => write!(f, "ROUTINE {}", routine.sql())?,
+            AlterObjectDependsTarget::Trigger { name, table } => {
+                write!(f, "TRIGGER {} ON {}", Ident(name), QualName(table))?
+            },
+        }
+
+        if self.action() == AddDrop::Drop {
+            f.write_str(" NO")?;
+        }
+
+        write!(f, " DEPENDS ON EXTENSION {}", Ident(self.extension()))
+    }
+}
+
+impl Deparse for AlterExtensionContentsStmt {
+    /// Alias: `AlterExtensionContentsStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        let action = match self.action() {
+            AddDrop::Add => "ADD",
+            AddDrop::Drop => "DROP",
+        };
+
+        write!(f, "ALTER EXTENSION {} {action} {}", Ident(self.name()), self.target().sql())
+    }
+}
+
+impl Deparse for AlterExtensionContentsTarget {
+    /// Alias: `alter_extension_target`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AccessMethod(name) => write!(f, "ACCESS METHOD {}", Ident(name)),
+            Self::Aggregate(aggregate) => write!(f, "AGGREGATE {}", aggregate.sql()),
+            Self::Collation(name) => write!(f, "COLLATION {}", QualName(name)),
+            Self::Conversion(name) => write!(f, "CONVERSION {}", QualName(name)),
+            Self::Database(name) => write!(f, "DATABASE {}", Ident(name)),
+            Self::Domain(typ) => write!(f, "DOMAIN {}", typ.sql()),
+            Self::EventTrigger(name) => write!(f, "EVENT TRIGGER {}", Ident(name)),
+            Self::ExtendedStatistics(name) => write!(f, "STATISTICS {}", QualName(name)),
+            Self::Extension(name) => write!(f, "EXTENSION {}", Ident(name)),
+            Self::ForeignDataWrapper(name) => write!(f, "FOREIGN DATA WRAPPER {}", Ident(name)),
+            Self::ForeignServer(name) => write!(f, "SERVER {}", Ident(name)),
+            Self::ForeignTable(name) => write!(f, "FOREIGN TABLE {}", QualName(name)),
+            Self::Function(function) => write!(f, "FUNCTION {}", function.sql()),
+            Self::Index(name) => write!(f, "INDEX {}", QualName(name)),
+            Self::Language(name) => write!(f, "LANGUAGE {}", Ident(name)),
+            Self::MaterializedView(name) => write!(f, "MATERIALIZED VIEW {}", QualName(name)),
+            Self::Operator(operator) => write!(f, "OPERATOR {}", operator.sql()),
+            Self::OperatorClass { name, index_method } => {
+                write!(f, "OPERATOR CLASS {} USING {}", QualName(name), Ident(index_method))
+            },
+            Self::OperatorFamily { name, index_method } => {
+                write!(f, "OPERATOR FAMILY {} USING {}", QualName(name), Ident(index_method))
+            },
+            Self::Procedure(procedure) => write!(f, "PROCEDURE {}", procedure.sql()),
+            Self::PropertyGraph(name) => write!(f, "PROPERTY GRAPH {}", QualName(name)),
+            Self::Publication(name) => write!(f, "PUBLICATION {}", Ident(name)),
+            Self::Role(name) => write!(f, "ROLE {}", Ident(name)),
+            Self::Routine(routine) => write!(f, "ROUTINE {}", routine.sql()),
+            Self::Schema(name) => write!(f, "SCHEMA {}", Ident(name)),
+            Self::Sequence(name) => write!(f, "SEQUENCE {}", QualName(name)),
+            Self::Subscription(name) => write!(f, "SUBSCRIPTION {}", Ident(name)),
+            Self::Table(name) => write!(f, "TABLE {}", QualName(name)),
+            Self::Tablespace(name) => write!(f, "TABLESPACE {}", Ident(name)),
+            Self::TextSearchConfiguration(name) => write!(f, "TEXT SEARCH CONFIGURATION {}", QualName(name)),
+            Self::TextSearchDictionary(name) => write!(f, "TEXT SEARCH DICTIONARY {}", QualName(name)),
+            Self::TextSearchParser(name) => write!(f, "TEXT SEARCH PARSER {}", QualName(name)),
+            Self::TextSearchTemplate(name) => write!(f, "TEXT SEARCH TEMPLATE {}", QualName(name)),
+            Self::Transform(transform) => transform.deparse(f),
+            Self::Type(typ) => write!(f, "TYPE {}", typ.sql()),
+            Self::Typecast(typecast) => typecast.deparse(f),
+            Self::View(name) => write!(f, "VIEW {}", QualName(name)),
+        }
+    }
+}
+
+pub(crate) fn write_if_exists(f: &mut Formatter<'_>, missing_ok: bool) -> fmt::Result {
+    if missing_ok {
+        f.write_str("IF EXISTS ")?;
+    }
+    Ok(())
+}
+
+/// Writes a leading space, when it's not the default behavior.
+pub(crate) fn write_drop_behavior(f: &mut Formatter<'_>, behavior: DropBehavior) -> fmt::Result {
+    match behavior {
+        DropBehavior::Restrict => Ok(()),
+        DropBehavior::Cascade => f.write_str(" CASCADE"),
+    }
+}
+
+fn write_text_or_null(f: &mut Formatter<'_>, text: Option<&str>) -> fmt::Result {
+    match text {
+        Some(text) => StrLit(text).fmt(f),
+        None => f.write_str("NULL"),
+    }
+}
+
+/// Like in PostgreSQL, the index method is stored as the first element of the name.
+fn write_op_class(f: &mut Formatter<'_>, kind: &str, name: &[Str]) -> fmt::Result {
+    match name.split_first() {
+        Some((index_method, name)) => {
+            write!(f, "OPERATOR {kind} {} USING {}", QualName(name), Ident(index_method))
+        },
+        None => write!(f, "OPERATOR {kind}"),
+    }
+}
+
+use crate::write_list;
+use crate::Deparse;
+use crate::Ident;
+use crate::QualName;
+use crate::StrLit;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use pg_ast::AddDrop;
+use pg_ast::AlterExtensionContentsStmt;
+use pg_ast::AlterExtensionContentsTarget;
+use pg_ast::AlterObjectDependsStmt;
+use pg_ast::AlterObjectDependsTarget;
+use pg_ast::AlterObjectSchemaStmt;
+use pg_ast::AlterObjectSchemaTarget;
+use pg_ast::AlterOwnerStmt;
+use pg_ast::AlterOwnerTarget;
+use pg_ast::CommentStmt;
+use pg_ast::CommentTarget;
+use pg_ast::DropBehavior;
+use pg_ast::DropStmt;
+use pg_ast::DropTarget;
+use pg_ast::Presence;
+use pg_ast::RenameStmt;
+use pg_ast::RenameTarget;
+use pg_ast::SecurityLabelStmt;
+use pg_ast::SecurityLabelTarget;
+use pg_basics::Str;
@@ -0,0 +1,101 @@
+impl Deparse for AlterOperatorStmt {
+    /// Alias: `AlterOperatorStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "ALTER OPERATOR {} SET ({})", self.operator().sql(), List(self.options()))
+    }
+}
+
+impl Deparse for CreateOpClassStmt {
+    /// Alias: `CreateOpClassStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        write!(f, "CREATE OPERATOR CLASS {}", QualName(self.name()))?;
+        if self.is_default() {
+            f.write_str(" DEFAULT")?;
+        }
+
+        write!(f, " FOR TYPE {} USING {}", self.data_type().sql(), Ident(self.index_method()))?;
+
+        if let Some(family) = self.family() {
+            write!(f, " FAMILY {}", QualName(family))?;
+        }
+
+        write!(f, " AS {}", List(self.items()))
+    }
+}
+
+impl Deparse for CreateOpFamilyStmt {
+    /// Alias: `CreateOpFamilyStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "CREATE OPERATOR FAMILY {} USING {}", QualName(self.name()), Ident(self.index_method()))
+    }
+}
+
+impl Deparse for AlterOpFamilyStmt {
+    /// Alias: `AlterOpFamilyStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        write!(f, "ALTER OPERATOR FAMILY {} USING {}", QualName(self.name()), Ident(self.index_method()))?;
+
+        match self.action() {
+            AlterOpFamilyAction::Add(items) => write!(f, " ADD {}", List(items)),
+            AlterOpFamilyAction::Drop(items) => write!(f, " DROP {}", List(items)),
+        }
+    }
+}
+
+impl Deparse for OpClassItem {
+    /// Alias: `opclass_item`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Operator { strategy, operator, args, purpose } => {
+                write!(f, "OPERATOR {strategy} {}", AnyOp(operator))?;
+                match args {
+                    None => {},
+                    Some(OneOrBoth::Left(left)) => write!(f, " ({}, NONE)", left.sql())?,
+                    Some(OneOrBoth::Right(right)) => write!(f, " (NONE, {})", right.sql())?,
+                    Some(OneOrBoth::Both(left, right)) => write!(f, " ({}, {})", left.sql(), right.sql())?,
+                }
+                match purpose {
+                    OpClassPurpose::Search => Ok(()),
+                    OpClassPurpose::OrderBy(family) => write!(f, " FOR ORDER BY {}", QualName(family)),
+                }
+            },
+            Self::Function { support, class_args, function } => {
+                write!(f, "FUNCTION {support} ")?;
+                if let Some(class_args) = class_args {
+                    write!(f, "({}) ", List(class_args))?;
+                }
+                function.deparse(f)
+            },
+            Self::Storage(typ) => write!(f, "STORAGE {}", typ.sql()),
+        }
+    }
+}
+
+impl Deparse for OpClassDrop {
+    /// Alias: `opclass_drop`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Operator { strategy, args } => write!(f, "OPERATOR {strategy} ({})", List(args)),
+            Self::Function { support, args } => write!(f, "FUNCTION {support} ({})", List(args)),
+        }
+    }
+}
+
+use crate::AnyOp;
+use crate::Deparse;
+use crate::Ident;
+use crate::List;
+use crate::QualName;
+use core::fmt;
+use core::fmt::Formatter;
+use pg_ast::AlterOpFamilyAction;
+use pg_ast::AlterOpFamilyStmt;
+use pg_ast::AlterOperatorStmt;
+use pg_ast::CreateOpClassStmt;
+use pg_ast::CreateOpFamilyStmt;
+use pg_ast::OneOrBoth;
+use pg_ast::OpClassDrop;
+use pg_ast::OpClassItem;
+use pg_ast::OpClassPurpose;
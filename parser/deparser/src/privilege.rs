@@ -0,0 +1,126 @@
+impl Deparse for AlterDefaultPrivilegesStmt {
+    /// Alias: `AlterDefaultPrivilegesStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        f.write_str("ALTER DEFAULT PRIVILEGES")?;
+
+        for option in self.options() {
+            match option {
+                AclOption::Schemas(schemas) => write!(f, " IN SCHEMA {}", Idents(schemas))?,
+                AclOption::Roles(roles) => write!(f, " FOR ROLE {}", List(roles))?,
+            }
+        }
+
+        write!(f, " {}", self.action().sql())
+    }
+}
+
+impl Deparse for GrantStmt {
+    /// Alias: `DefACLAction`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        let with_grant_option = self.grant_option() == GrantOption::WithGrant;
+
+        if self.is_grant() {
+            write!(f,
+                "GRANT {} ON {} TO {}",
+                self.privileges().sql(),
+                self.object_type().sql(),
+                List(self.grantees())
+            )?;
+            if with_grant_option {
+                f.write_str(" WITH GRANT OPTION")?;
+            }
+            return Ok(())
+        }
+
+        f.write_str("REVOKE ")?;
+        if with_grant_option {
+            f.write_str("GRANT OPTION FOR ")?;
+        }
+        write!(f,
+            "{} ON {} FROM {}",
+            self.privileges().sql(),
+            self.object_type().sql(),
+            List(self.grantees())
+        )?;
+        write_drop_behavior(f, self.drop_behavior())
+    }
+}
+
+impl Deparse for AccessPrivilege {
+    /// Alias: `privileges`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::All { columns } => {
+                f.write_str("ALL PRIVILEGES")?;
+                write_columns(f, columns.as_deref())
+            },
+            Self::Specific(privileges) => List(privileges).fmt(f),
+        }
+    }
+}
+
+impl Deparse for SpecificAccessPrivilege {
+    /// Alias: `privilege`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AlterSystem => f.write_str("ALTER SYSTEM"),
+            Self::Create { columns } => {
+                f.write_str("CREATE")?;
+                write_columns(f, columns.as_deref())
+            },
+            Self::References { columns } => {
+                f.write_str("REFERENCES")?;
+                write_columns(f, columns.as_deref())
+            },
+            Self::Select { columns } => {
+                f.write_str("SELECT")?;
+                write_columns(f, columns.as_deref())
+            },
+            Self::Named { privilege, columns } => {
+                Ident(privilege).fmt(f)?;
+                write_columns(f, columns.as_deref())
+            },
+        }
+    }
+}
+
+impl Deparse for PrivilegeDefaultsTarget {
+    /// Alias: `defacl_privilege_target`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let target = match self {
+            Self::Functions => "FUNCTIONS",
+            Self::LargeObjects => "LARGE OBJECTS",
+            Self::Schemas => "SCHEMAS",
+            Self::Sequences => "SEQUENCES",
+            Self::Tables => "TABLES",
+            Self::Types => "TYPES",
+        };
+        f.write_str(target)
+    }
+}
+
+fn write_columns(f: &mut Formatter<'_>, columns: Option<&[Str]>) -> fmt::Result {
+    match columns {
+        Some(columns) => write!(f, " ({})", Idents(columns)),
+        None => Ok(()),
+    }
+}
+
+use crate::write_drop_behavior;
+use crate::Deparse;
+use crate::Ident;
+use crate::Idents;
+use crate::List;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use pg_ast::AccessPrivilege;
+use pg_ast::AclOption;
+use pg_ast::AlterDefaultPrivilegesStmt;
+use pg_ast::GrantOption;
+use pg_ast::GrantStmt;
+use pg_ast::PrivilegeDefaultsTarget;
+use pg_ast::SpecificAccessPrivilege;
+use pg_basics::Str;
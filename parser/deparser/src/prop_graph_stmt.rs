@@ -0,0 +1,202 @@
+impl Deparse for CreatePropGraphStmt {
+    /// Alias: `CreatePropGraphStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        f.write_str("CREATE ")?;
+        write_persistence(f, self.name().persistence())?;
+        write!(f, "PROPERTY GRAPH {}", self.name().relation().sql())?;
+
+        if let Some(vertex_tables) = self.vertex_tables() {
+            write!(f, " VERTEX TABLES ({})", List(vertex_tables))?;
+        }
+        if let Some(edge_tables) = self.edge_tables() {
+            write!(f, " EDGE TABLES ({})", List(edge_tables))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deparse for AlterPropGraphStmt {
+    /// Alias: `AlterPropGraphStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        write!(f, "ALTER PROPERTY GRAPH {} ", QualName(self.name()))?;
+
+        match self.action() {
+            AlterPropGraphAction::AddTables { vertex_tables, edge_tables } => {
+                if let Some(vertex_tables) = vertex_tables {
+                    write!(f, "ADD VERTEX TABLES ({})", List(vertex_tables))?;
+                    if edge_tables.is_some() {
+                        f.write_char(' ')?;
+                    }
+                }
+                if let Some(edge_tables) = edge_tables {
+                    write!(f, "ADD EDGE TABLES ({})", List(edge_tables))?;
+                }
+                Ok(())
+            },
+            AlterPropGraphAction::DropTables { kind, tables, behavior } => {
+                write!(f, "DROP {} TABLES ({})", element_kind(*kind), Idents(tables))?;
+                write_drop_behavior(f, *behavior)
+            },
+            AlterPropGraphAction::AlterTable { kind, table, action } => {
+                write!(f, "ALTER {} TABLE {} {}", element_kind(*kind), Ident(table), action.sql())
+            },
+        }
+    }
+}
+
+impl Deparse for AlterPropGraphTableAction {
+    /// Alias: `alter_table_action`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AddLabels(labels) => {
+                for (i, label) in labels.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(' ')?;
+                    }
+                    write!(f, "ADD {}", label.sql())?;
+                }
+                Ok(())
+            },
+            Self::DropLabel { label, behavior } => {
+                write!(f, "DROP LABEL {}", Ident(label))?;
+                write_drop_behavior(f, *behavior)
+            },
+            Self::AddProperties { label, properties } => {
+                write!(f, "ALTER LABEL {} ADD PROPERTIES (", Ident(label))?;
+                write_list(f, properties, |f, property| LabeledExpr(property).fmt(f))?;
+                f.write_char(')')
+            },
+            Self::DropProperties { label, properties, behavior } => {
+                write!(f, "ALTER LABEL {} DROP PROPERTIES ({})", Ident(label), Idents(properties))?;
+                write_drop_behavior(f, *behavior)
+            },
+        }
+    }
+}
+
+impl Deparse for PropGraphVertexTable {
+    /// Alias: `vertex_table_definition`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_element_table(f, self.table(), self.alias(), self.key())?;
+        write_labels(f, self.labels())
+    }
+}
+
+impl Deparse for PropGraphEdgeTable {
+    /// Alias: `edge_table_definition`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_element_table(f, self.table(), self.alias(), self.key())?;
+        write!(f, " SOURCE {} DESTINATION {}", self.source().sql(), self.destination().sql())?;
+        write_labels(f, self.labels())
+    }
+}
+
+impl Deparse for PropGraphVertexReference {
+    /// Aliases:
+    /// * `source_vertex_table`
+    /// * `destination_vertex_table`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.key() {
+            Some((columns, referenced)) => write!(f,
+                "KEY ({}) REFERENCES {} ({})",
+                Idents(columns),
+                Ident(self.vertex()),
+                Idents(referenced)
+            ),
+            None => Ident(self.vertex()).fmt(f),
+        }
+    }
+}
+
+impl Deparse for PropGraphLabel {
+    /// Alias: `label_and_properties`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        match self.name() {
+            Some(name) => write!(f, "LABEL {}", Ident(name))?,
+            None => f.write_str("DEFAULT LABEL")?,
+        }
+
+        match self.properties() {
+            // The default, when the properties are omitted.
+            PropGraphProperties::AllColumns => Ok(()),
+            properties => write!(f, " {}", properties.sql()),
+        }
+    }
+}
+
+impl Deparse for PropGraphProperties {
+    /// Alias: `element_table_properties`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AllColumns => f.write_str("PROPERTIES ALL COLUMNS"),
+            Self::None => f.write_str("NO PROPERTIES"),
+            Self::List(properties) => {
+                f.write_str("PROPERTIES (")?;
+                write_list(f, properties, |f, property| LabeledExpr(property).fmt(f))?;
+                f.write_char(')')
+            },
+        }
+    }
+}
+
+fn element_kind(kind: PropGraphElementKind) -> &'static str {
+    match kind {
+        PropGraphElementKind::Vertex => "VERTEX",
+        PropGraphElementKind::Edge => "EDGE",
+    }
+}
+
+fn write_element_table(
+    f: &mut Formatter<'_>,
+    table: &RelationName,
+    alias: Option<&str>,
+    key: Option<&[Str]>
+)
+    -> fmt::Result
+{
+    table.deparse(f)?;
+    if let Some(alias) = alias {
+        write!(f, " AS {}", Ident(alias))?;
+    }
+    if let Some(key) = key {
+        write!(f, " KEY ({})", Idents(key))?;
+    }
+    Ok(())
+}
+
+fn write_labels(f: &mut Formatter<'_>, labels: Option<&[PropGraphLabel]>) -> fmt::Result {
+    for label in labels.unwrap_or_default() {
+        write!(f, " {}", label.sql())?;
+    }
+    Ok(())
+}
+
+use crate::write_drop_behavior;
+use crate::write_list;
+use crate::write_persistence;
+use crate::Deparse;
+use crate::Ident;
+use crate::Idents;
+use crate::LabeledExpr;
+use crate::List;
+use crate::QualName;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Write;
+use pg_ast::AlterPropGraphAction;
+use pg_ast::AlterPropGraphStmt;
+use pg_ast::AlterPropGraphTableAction;
+use pg_ast::CreatePropGraphStmt;
+use pg_ast::PropGraphEdgeTable;
+use pg_ast::PropGraphElementKind;
+use pg_ast::PropGraphLabel;
+use pg_ast::PropGraphProperties;
+use pg_ast::PropGraphVertexReference;
+use pg_ast::PropGraphVertexTable;
+use pg_ast::RelationName;
+use pg_basics::Str;
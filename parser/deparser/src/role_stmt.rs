@@ -0,0 +1,198 @@
+impl Deparse for RoleSpec {
+    /// Alias: `RoleSpec`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Public => f.write_str("public"),
+            Self::CurrentRole => f.write_str("CURRENT_ROLE"),
+            Self::CurrentUser => f.write_str("CURRENT_USER"),
+            Self::SessionUser => f.write_str("SESSION_USER"),
+            Self::Name(name) => Ident(name).fmt(f),
+        }
+    }
+}
+
+impl Deparse for CreateRoleStmt {
+    /// Aliases:
+    /// * `CreateRoleStmt`
+    /// * `CreateUserStmt`
+    /// * `CreateGroupStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        let kind = match self.kind() {
+            RoleKind::Role => "ROLE",
+            RoleKind::User => "USER",
+            RoleKind::Group => "GROUP",
+        };
+        write!(f, "CREATE {kind} {}", Ident(self.name()))?;
+
+        for option in self.options().unwrap_or_default() {
+            write!(f, " {}", option.sql())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deparse for CreateRoleOption {
+    /// Alias: `CreateOptRoleElem`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RoleMembers(members) => write!(f, "USER {}", List(members)),
+            Self::Password(password) => write_password(f, password.as_deref()),
+            Self::Inherit(inherit) => write_flag(f, *inherit, "INHERIT", "NOINHERIT"),
+            Self::ConnectionLimit(limit) => write!(f, "CONNECTION LIMIT {limit}"),
+            Self::ValidUntil(valid_until) => write!(f, "VALID UNTIL {}", StrLit(valid_until)),
+            Self::SuperUser(value) => write_flag(f, *value, "SUPERUSER", "NOSUPERUSER"),
+            Self::CreateRole(value) => write_flag(f, *value, "CREATEROLE", "NOCREATEROLE"),
+            Self::IsReplication(value) => write_flag(f, *value, "REPLICATION", "NOREPLICATION"),
+            Self::CreateDatabase(value) => write_flag(f, *value, "CREATEDB", "NOCREATEDB"),
+            Self::CanLogin(value) => write_flag(f, *value, "LOGIN", "NOLOGIN"),
+            Self::BypassRls(value) => write_flag(f, *value, "BYPASSRLS", "NOBYPASSRLS"),
+            Self::SysId(id) => write!(f, "SYSID {}", id.unwrap()),
+            Self::AdminMembers(members) => write!(f, "ADMIN {}", List(members)),
+            Self::AddRoleTo(roles) => write!(f, "IN ROLE {}", List(roles)),
+        }
+    }
+}
+
+impl Deparse for AlterRoleStmt {
+    /// Aliases:
+    /// * `AlterRoleStmt`
+    /// * `AlterGroupStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        let options = self.options().unwrap_or_default();
+
+        // Removing members is only possible with `ALTER GROUP`.
+        if let [AlterRoleOption::RoleMembers { action: AddDrop::Drop, members }] = options {
+            return write!(f, "ALTER GROUP {} DROP USER {}", self.role().sql(), List(members))
+        }
+
+        write!(f, "ALTER ROLE {}", self.role().sql())?;
+
+        for option in options {
+            write!(f, " {}", option.sql())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deparse for AlterRoleOption {
+    /// Alias: `AlterOptRoleElem`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RoleMembers { members, .. } => write!(f, "USER {}", List(members)),
+            Self::Password(password) => write_password(f, password.as_deref()),
+            Self::Inherit(inherit) => write_flag(f, *inherit, "INHERIT", "NOINHERIT"),
+            Self::ConnectionLimit(limit) => write!(f, "CONNECTION LIMIT {limit}"),
+            Self::ValidUntil(valid_until) => write!(f, "VALID UNTIL {}", StrLit(valid_until)),
+            Self::SuperUser(value) => write_flag(f, *value, "SUPERUSER", "NOSUPERUSER"),
+            Self::CreateRole(value) => write_flag(f, *value, "CREATEROLE", "NOCREATEROLE"),
+            Self::IsReplication(value) => write_flag(f, *value, "REPLICATION", "NOREPLICATION"),
+            Self::CreateDatabase(value) => write_flag(f, *value, "CREATEDB", "NOCREATEDB"),
+            Self::CanLogin(value) => write_flag(f, *value, "LOGIN", "NOLOGIN"),
+            Self::BypassRls(value) => write_flag(f, *value, "BYPASSRLS", "NOBYPASSRLS"),
+        }
+    }
+}
+
+impl Deparse for AlterRoleSetStmt {
+    /// Alias: `AlterRoleSetStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        match self.role() {
+            OneOrAll::All => f.write_str("ALTER ROLE ALL")?,
+            OneOrAll::One(role) => write!(f, "ALTER ROLE {}", role.sql())?,
+        }
+
+        if let Some(database) = self.database() {
+            write!(f, " IN DATABASE {}", Ident(database))?;
+        }
+
+        write!(f, " {}", self.set_stmt().sql())
+    }
+}
+
+impl Deparse for CreateUserMappingStmt {
+    /// Alias: `CreateUserMappingStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        f.write_str("CREATE USER MAPPING ")?;
+        if self.existence() == Presence::Ignore {
+            f.write_str("IF NOT EXISTS ")?;
+        }
+        write!(f, "FOR {} SERVER {}", self.user().sql(), Ident(self.server()))?;
+
+        if let Some(options) = self.options() {
+            write!(f, " OPTIONS ({})", List(options))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deparse for AlterUserMappingStmt {
+    /// Alias: `AlterUserMappingStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f,
+            "ALTER USER MAPPING FOR {} SERVER {} OPTIONS ({})",
+            self.user().sql(),
+            Ident(self.server_name()),
+            List(self.options())
+        )
+    }
+}
+
+impl Deparse for GenericOption {
+    /// Alias: `generic_option_elem`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", Label(self.name()), StrLit(self.arg()))
+    }
+}
+
+impl Deparse for GenericOptionKind {
+    /// Alias: `alter_generic_option_elem`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unspecified(option) => option.deparse(f),
+            Self::Set(option) => write!(f, "SET {}", option.sql()),
+            Self::Add(option) => write!(f, "ADD {}", option.sql()),
+            Self::Drop(name) => write!(f, "DROP {}", Label(name)),
+        }
+    }
+}
+
+fn write_password(f: &mut Formatter<'_>, password: Option<&str>) -> fmt::Result {
+    match password {
+        Some(password) => write!(f, "PASSWORD {}", StrLit(password)),
+        None => f.write_str("PASSWORD NULL"),
+    }
+}
+
+fn write_flag(f: &mut Formatter<'_>, value: bool, on: &str, off: &str) -> fmt::Result {
+    f.write_str(if value { on } else { off })
+}
+
+use crate::Deparse;
+use crate::Ident;
+use crate::Label;
+use crate::List;
+use crate::StrLit;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use pg_ast::AddDrop;
+use pg_ast::AlterRoleOption;
+use pg_ast::AlterRoleSetStmt;
+use pg_ast::AlterRoleStmt;
+use pg_ast::AlterUserMappingStmt;
+use pg_ast::CreateRoleOption;
+use pg_ast::CreateRoleStmt;
+use pg_ast::CreateUserMappingStmt;
+use pg_ast::GenericOption;
+use pg_ast::GenericOptionKind;
+use pg_ast::OneOrAll;
+use pg_ast::Presence;
+use pg_ast::RoleKind;
+use pg_ast::RoleSpec;
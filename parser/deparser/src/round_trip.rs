@@ -0,0 +1,101 @@
+//! Checks that `parse(deparse(ast)) == ast` for the statements in `round_trip.sql`.
+//!
+//! New grammar should add its statements to the corpus.
+
+#[test]
+fn test_round_trip() {
+
+    let result = Parser::new(CORPUS, ParserConfig::default()).parse().result;
+
+    let failures: Vec<_> = result.into_iter()
+        .filter_map(|stmt| {
+            let stmt = match stmt {
+                Ok(stmt) => stmt,
+                Err(err) => return Some(format!("round_trip.sql: failed to parse: {err}")),
+            };
+            let range = stmt.location().range();
+            let source = &CORPUS[range.start as usize..range.end as usize];
+            round_trip(source).err()
+        })
+        .collect();
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Statements terminated by `;`, which should cover every production of the grammar.
+/// A statement that doesn't parse fails the test.
+const CORPUS: &str = include_str!("round_trip.sql");
+
+fn round_trip(source: &str) -> Result<(), String> {
+
+    let expected = parse(source)
+        .ok_or_else(|| format!("{source}: failed to parse the source"))?;
+
+    let sql = deparse(&expected);
+    let actual = parse(&sql)
+        .ok_or_else(|| format!("{source}: failed to parse the deparsed SQL: {sql}"))?;
+
+    if actual != expected {
+        return Err(format!("{source}: the deparsed SQL parsed into a different AST: {sql}"))
+    }
+
+    // Deparsing is idempotent.
+    let resql = deparse(&actual);
+    if resql != sql {
+        return Err(format!("{source}: deparsing isn't idempotent: {sql} != {resql}"))
+    }
+
+    Ok(())
+}
+
+fn parse(source: &str) -> Option<Vec<RawStmt>> {
+
+    let result = Parser::new(source, ParserConfig::default()).parse().result;
+
+    result.into_iter()
+        .map(|stmt| {
+            let mut stmt = stmt.ok()?.into_source();
+            let _ = stmt.accept_mut(&mut ClearLocations);
+            Some(stmt)
+        })
+        .collect()
+}
+
+fn deparse(stmts: &[RawStmt]) -> String {
+    stmts.iter()
+        .map(|stmt| stmt.sql().to_string())
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Locations of the original source can't survive a round trip.
+struct ClearLocations;
+
+impl VisitMut for ClearLocations {
+    type Break = ();
+
+    fn visit_func_args_kind(&mut self, node: &mut FuncArgsKind) -> ControlFlow<()> {
+
+        if let FuncArgsKind::All { args, order } = node {
+            for arg in args.iter_mut() {
+                arg.1 = Location::new(0..0, 0, 0);
+            }
+            if let Some(order) = order {
+                order.1 = Location::new(0..0, 0, 0);
+            }
+        }
+
+        node.walk_mut(self)
+    }
+}
+
+use crate::Deparse;
+use core::ops::ControlFlow;
+use pg_ast::Accept;
+use pg_ast::FuncArgsKind;
+use pg_ast::RawStmt;
+use pg_ast::VisitMut;
+use pg_ast::Walk;
+use pg_basics::Location;
+use pg_parser::Parser;
+use pg_parser_core::ParserConfig;
@@ -0,0 +1,977 @@
+((select 1) order by 1 limit 1);
+((select 1) union all select 2);
+((select 1) union select 2);
+((select 1));
+(select 1 order by 1) limit 2 offset 3;
+(select 1 union select 2) intersect select 3;
+(select 1);
+(table foo);
+(values (1));
+(values (1)) union table foo;
+SECURITY LABEL FOR 'some_label' ON access method some_method IS 'foo';
+SECURITY LABEL ON access method some_method IS 'foo';
+abort;
+abort and chain;
+abort and no chain;
+abort transaction;
+abort transaction and chain;
+abort transaction and no chain;
+alter aggregate aggregate_name(*) owner to current_user;
+alter collation some_name refresh version;
+alter conversion some_conversion rename to new_conversion;
+alter database the_db refresh collation version;
+alter default privileges in schema some_schema grant all on tables to public;
+alter domain some_domain add constraint positive check (1) not valid;
+alter domain some_domain drop constraint if exists positive cascade;
+alter domain some_domain drop default;
+alter domain some_domain owner to session_user;
+alter domain some_domain rename constraint positive to negative;
+alter domain some_domain rename to other_domain;
+alter domain some_domain set schema some_schema;
+alter domain some_domain validate constraint positive;
+alter event trigger some_trigger owner to current_user;
+alter event trigger trigger_name enable;
+alter event trigger trigger_name owner to public;
+alter event trigger trigger_name rename to another_trigger;
+alter extension foo set schema some_schema;
+alter extension some_extension add aggregate some_aggregate(*);
+alter extension some_extension drop function some_function;
+alter extension some_extension set schema some_schema;
+alter extension some_extension update to 'option1';
+alter function my_func rename to new_name;
+alter function my_func set schema 'new_schema';
+alter function my_func() depends on extension my_extension;
+alter function some_function() owner to current_user;
+alter group some_group add user current_role, new_user;
+alter group some_group add user public;
+alter group some_group drop user session_user, public;
+alter group some_group rename to new_group_name;
+alter language lang owner to session_user;
+alter language some_language rename to new_lang;
+alter large object -127 owner to public;
+alter operator < (int, int) owner to current_user;
+alter operator < (int, int) set (restrict = none);
+alter operator < (int, int) set schema some_schema;
+alter operator family some_family using btree add operator 1 < (int, int);
+alter operator family some_family using btree drop function 1 (int);
+alter operator family some_family using btree drop operator 1 (int, int), function 1 (int);
+alter procedural language some_language owner to public;
+alter procedure my_func leakproof cost 100 restrict;
+alter procedure my_func set schema new_schema;
+alter procedure my_func() no depends on extension my_extension;
+alter property graph g add vertex tables (persons);
+alter property graph g alter edge table friends add label knows add label likes no properties;
+alter property graph g alter vertex table persons alter label person add properties (1 as one);
+alter property graph g alter vertex table persons alter label person drop properties (one) cascade;
+alter property graph g alter vertex table persons drop label person;
+alter property graph g drop node tables (persons, cities) cascade;
+alter property graph g owner to current_user;
+alter property graph g rename to h;
+alter property graph if exists g set schema some_schema;
+alter routine my_func cost 100 not leakproof set foo='bar';
+alter routine my_func owner to current_user;
+alter routine my_func set schema 'new_schema' restrict;
+alter sequence if exists some_seq restart with 3 no maxvalue;
+alter sequence some_seq increment by 2;
+alter system reset all;
+alter system reset some_.name_;
+alter system set var_._name = 'x';
+alter system set var_._name to default;
+alter text search configuration some_config add mapping for word, asciiword with english_stem, simple;
+alter text search configuration some_config alter mapping for word replace english with simple;
+alter text search configuration some_config alter mapping for word with simple;
+alter text search configuration some_config alter mapping replace english with simple;
+alter text search configuration some_config drop mapping if exists for word;
+alter text search configuration some_config owner to current_user;
+alter text search dictionary some_dict rename to other_dict;
+alter text search parser some_parser rename to other_parser;
+alter text search parser some_parser set schema some_schema;
+alter text search template some_template rename to other_template;
+alter type complex add attribute r int, drop attribute if exists i cascade, alter attribute x set data type json collate some_collation;
+alter type mood add value 'happy';
+alter type mood add value 'happy' after 'ok';
+alter type mood add value if not exists 'happy' before 'ok';
+alter type mood rename value 'sad' to 'blue';
+alter type some_type owner to current_user;
+alter type some_type rename attribute foo to bar cascade;
+alter type some_type rename to new_type;
+alter type some_type rename to other_type;
+alter type some_type set schema some_schema;
+alter user public;
+begin;
+begin read only, read write deferrable;
+begin transaction;
+begin transaction read only;
+begin transaction read write;
+begin work;
+begin work isolation level serializable;
+begin; commit;
+checkpoint;
+checkpoint(analyze);
+close abort;
+close all;
+close ident;
+comment on access method some_method is null;
+comment on aggregate some_aggregate(*) is null;
+comment on cast (int as varchar) is null;
+comment on collation some_collation is null;
+comment on column some_column is null;
+comment on constraint some_constraint on domain int is null;
+comment on constraint some_constraint on some_table is null;
+comment on event trigger some_trigger is null;
+comment on foreign data wrapper some_wrapper is null;
+comment on foreign table some_table is null;
+comment on large object 123 is null;
+comment on materialized view some_view is null;
+comment on operator class some_class using some_method is null;
+comment on policy some_policy on some_table is null;
+comment on rule some_rule on some_table is null;
+comment on text search configuration foo is null;
+comment on text search configuration some_configuration is null;
+comment on text search dictionary foo is null;
+comment on text search dictionary some_dictionary is null;
+comment on text search parser foo is null;
+comment on text search parser some_parser is null;
+comment on text search template foo is null;
+comment on text search template some_template is null;
+comment on transform for int language some_language is null;
+comment on trigger some_trigger on some_table is null;
+comment on type int is 'comment';
+commit;
+commit and chain;
+commit and no chain;
+commit transaction;
+commit transaction and chain;
+commit transaction and no chain;
+create access method foo type table handler bar;
+create aggregate my_sum (basetype = int, sfunc = int4pl, stype = int);
+create aggregate my_sum(int) (sfunc = int4pl, stype = int);
+create cast (int as text) with inout;
+create collation french (locale = 'fr_FR.utf8');
+create collation if not exists some_schema.german from "de_DE";
+create collation some_coll from "C";
+create conversion conv_name for 'for-encoding' to 'to-encoding' from func_name;
+create conversion foo for 'bar' to 'baz' from qux;
+create database new_db oid = 1;
+create database the_db_name ALLOW_CONNECTIONS = default CONNECTION LIMIT 5 IS_TEMPLATE = false TABLESPACE tbspace;
+create database the_db_name with ALLOW_CONNECTIONS default CONNECTION LIMIT = +5 IS_TEMPLATE false TABLESPACE = tbspace;
+create default conversion foo for 'bar' to 'baz' from qux;
+create domain some_domain add not null;
+create domain some_domain as int collate some_collation;
+create domain some_domain drop not null;
+create domain some_domain int;
+create domain some_domain int constraint positive check (1) not null null default 2;
+create domain some_domain int not null;
+create domain some_domain set default 1;
+create domain some_domain set not null;
+create extension if not exists some_extension with schema some_schema version '1.2' cascade;
+create extension some_extension;
+create group test_role in role public;
+create language plsample handler call_handler no validator;
+create language plsample handler some_schema.call_handler inline inline_handler validator validator_fn;
+create operator +(int, int);
+create operator class some_class default for type int using btree family some_family as operator 1 <, storage int;
+create operator family some_family using btree;
+create operator family some_family using gist;
+create operator family some_family using some_method;
+create operator some_schema.=== (leftarg = int, procedure = some_func);
+create or replace aggregate agg(int) (sfunc = f, stype = int);
+create or replace aggregate my_count(*) (sfunc = int8inc, stype = int8);
+create or replace rule r as on insert to t where true do instead notify c;
+create or replace rule r as on update to t do nothing;
+create or replace transform for json language plperl (to sql with function g);
+create or replace trusted procedural language plsample handler plsample_call_handler;
+create procedural language some_language;
+create property graph g;
+create property graph some_prop_graph;
+create role new_role with superuser;
+create role test_role with sysid 42;
+create rule r as on delete to t do also (notify a; ; notify b;);
+create rule r as on update to t do nothing;
+create schema if not exists authorization current_user;
+create schema some_schema;
+create schema some_schema authorization public;
+create schema some_schema create sequence some_seq create sequence other_seq;
+create sequence some_seq restart;
+create statistics if not exists s.stats (ndistinct, mcv) on (1), now() from t;
+create statistics on a, b from t;
+create statistics s on a, b from t;
+create tablespace dbspace location '/data/dbs';
+create tablespace indexspace owner genevieve location '/data/indexes' with (random_page_cost = 1);
+create tablespace ts location '/data';
+create temp property graph g vertex tables (persons);
+create temp property graph g vertex tables (persons) edge tables (friends source persons destination persons);
+create temp sequence if not exists some_seq increment by 2 cycle;
+create temp sequence new_seq start 1;
+create text search configuration some_config (copy = english);
+create text search dictionary some_dict (copy = english);
+create text search dictionary some_dict (maxlen = 10);
+create text search dictionary some_dict (template = simple);
+create text search parser some_parser (copy = english);
+create text search template some_template (copy = english);
+create transform for int language plfoo (from sql with function f);
+create transform for json language plpython3u (from sql with function f, to sql with function g);
+create trusted language plfoo handler foo_handler;
+create type base_type (input = base_in, passedbyvalue);
+create type complex as (r int, i json collate some_collation);
+create type empty_composite as ();
+create type empty_enum as enum ();
+create type float_range as range (subtype = double precision);
+create type some_schema.mood as enum ('sad', 'ok');
+create type some_type as enum ('foo');
+create unlogged sequence some_schema.some_seq;
+create user mapping for foo server bar;
+create user new_user with password 'password';
+deallocate abort;
+deallocate all;
+deallocate prepare all;
+deallocate prepare ident;
+discard all;
+discard plans;
+discard sequences;
+discard temp;
+discard temporary;
+drop property graph g;
+drop property graph if exists g, some_schema.h cascade;
+end;
+end and chain;
+end and no chain;
+end transaction;
+end transaction and chain;
+end transaction and no chain;
+listen abort;
+listen ident;
+load 'test string';
+notify test_ident, 'test-payload';
+prepare transaction 'tx id';
+reassign owned by public, test_role to target_role;
+release savepoint test_ident;
+reset all;
+reset time zone;
+rollback;
+rollback and chain;
+rollback and no chain;
+rollback prepared 'test-string';
+rollback to savepoint test_ident;
+rollback to test_ident;
+rollback transaction;
+rollback transaction and chain;
+rollback transaction and no chain;
+rollback transaction to savepoint test_ident;
+rollback transaction to test_ident;
+savepoint test_ident;
+security label for 'foo' on type int is 'bar';
+select;
+select "quoted";
+select $3;
+select $dollar$a $ string$dollar$;
+select '+01:00';
+select 'abc';
+select 'bar';
+select 'basic ''string'''
+' concatenation';
+select 'basic string';
+select 'foo';
+select 'foo' as bar;
+select 'foo', 'bar';
+select 'foo', 'bar', 'baz';
+select 'some string';
+select 'some_string';
+select 'string literal';
+select 'test-string';
+select 'utf8';
+select 'value';
+select ( a );
+select ((1));
+select ((select 1)) a;
+select (1);
+select (1, 2);
+select (a);
+select (a).b;
+select (a, b) <> (select 1);
+select (a, b) = (1, 2);
+select (a, b) = any (select 1);
+select (a, b) @> (1, 2);
+select (a, b) in (select 1);
+select (foo);
+select (foo, bar);
+select (select 1 limit 1) limit 2;
+select (select 1 offset 1) offset 2;
+select (select 1 order by 1) order by 1;
+select (select 1) a;
+select (select rank() over w from u window w as ()) from t;
+select * from ((a cross join b) cross join c) d;
+select * from ((select 1) a cross join b);
+select * from (a cross join b) as j cross join c;
+select * from (a natural join (values (1)) v);
+select * from (bar cross join baz);
+select * from (values (1), (2)) as v (a);
+select * from a cross join b;
+select * from a full join b using (x, y) as j;
+select * from a join b join c on 1 on 2;
+select * from a join b on 1;
+select * from a natural join b;
+select * from a natural left outer join b;
+select * from a right join b on 1 inner join c on 2;
+select * from bar tablesample bernoulli (1);
+select * from bar() with ordinality;
+select * from bar(1) as (a int);
+select * from baz tablesample g(2);
+select * from baz() as t(x);
+select * from bigint;
+select * from bit;
+select * from bit varying;
+select * from boolean;
+select * from char;
+select * from char varying;
+select * from character;
+select * from character varying;
+select * from coalesce;
+select * from dec;
+select * from decimal;
+select * from double precision;
+select * from extract;
+select * from float;
+select * from foo *;
+select * from foo as t tablesample f(1);
+select * from foo.bar * as baz;
+select * from foo.bar(1) with ordinality;
+select * from graph_table;
+select * from graph_table (g match (a) columns (a.id));
+select * from greatest;
+select * from inout;
+select * from int;
+select * from integer;
+select * from interval;
+select * from interval day;
+select * from json;
+select * from json json;
+select * from json_arrayagg;
+select * from json_exists;
+select * from json_object;
+select * from json_objectagg;
+select * from json_query;
+select * from json_scalar;
+select * from json_serialize;
+select * from json_table('doc', 'path' columns(bar for ordinality)) as j;
+select * from json_table('foo', 'bar' columns(qux for ordinality));
+select * from json_table('umpus' format json, 'wawas' as foo passing 'lorem' as ipsum columns(qux for ordinality) null on error);
+select * from json_value;
+select * from lateral (select 1) as a;
+select * from lateral foo();
+select * from least;
+select * from merge_action;
+select * from national char;
+select * from national character;
+select * from nchar;
+select * from nchar varying;
+select * from none;
+select * from normalize;
+select * from nullif;
+select * from numeric;
+select * from only bar;
+select * from only foo;
+select * from only qux;
+select * from only(foo);
+select * from out;
+select * from overlay;
+select * from position;
+select * from precision;
+select * from qux() with ordinality as s(y);
+select * from real;
+select * from rows from ( baz() ) with ordinality;
+select * from rows from ( foo() );
+select * from rows from ( foo() ) with ordinality as s(y);
+select * from rows from ( foo(1), bar(*) );
+select * from rows from ( qux() ) as t(x);
+select * from setof int;
+select * from setof json;
+select * from smallint;
+select * from substring;
+select * from t cross join lateral (select t.a) as s, (values (1, 2)) v (x, y);
+select * from time;
+select * from time zone;
+select * from timestamp;
+select * from treat;
+select * from trim;
+select * from varchar;
+select * from xmlconcat;
+select * from xmlelement;
+select * from xmlexists;
+select * from xmlforest;
+select * from xmlparse;
+select * from xmlpi;
+select * from xmlroot;
+select * from xmlserialize;
+select * from xmltable('foo' passing 'bar' columns qux int);
+select * from xmltable('path' passing by ref 'doc' columns a for ordinality, b int) as x(y, z);
+select * from xmltable(xmlnamespaces(default 'foo', 'bar' as x), 'path' passing 'doc' by value columns a int, b for ordinality);
+select + 1;
+select +123;
+select +2.02;
+select +202;
+select +321;
+select - a;
+select - a  ;
+select -1;
+select -1 ^ 2;
+select -10;
+select -123;
+select -3.03;
+select -303;
+select 1;
+select 1 + 2 * 3;
+select 1 + 2 in (3);
+select 1 - 2 - 3;
+select 1 following;
+select 1 intersect all (select 2 limit 1);
+select 1 preceding;
+select 1 union all select 2 except distinct select 3;
+select 1 union select 2;
+select 1 union select 2 intersect select 3;
+select 1 union select 2 order by 1 limit 3;
+select 1, 2, 3;
+select 1.01;
+select 1.1;
+select 101;
+select 11;
+select 123;
+select 123.45;
+select 1; select 2;
+select 2 as x;
+select 3 desc;
+select 4;
+select CURRENT_USER;
+select CURRENT_role;
+select CuRrEnT_rOlE;
+select SESSION_USER;
+select __pg__is_not_null 'foo';
+select _ident;
+select _relation;
+select _var from current;
+select a;
+select a < b + 1;
+select a = (select 1);
+select a = any (select 1);
+select a > all (select 1);
+select a and b;
+select a from t window w as () order by rank() over w;
+select a from t, u where true group by distinct a having false;
+select a in (1, 2);
+select a in (select 1);
+select a is not distinct from b;
+select a like some (select 1);
+select a not in (1);
+select a not in (select 1);
+select a not like 'x%';
+select a or b and not c;
+select a, (select max(b) from u where u.a = t.a) from t where (a, b) in (select 1, 2) and not exists (select);
+select a, b;
+select a::int;
+select a::int[] , b;
+select a[:3] = b[2:];
+select action;
+select admin public;
+select all;
+select all (column_name);
+select all *;
+select all privileges;
+select allow_connections;
+select allow_connections DEFAULT;
+select alter system;
+select another_name(column_name);
+select array (select 1);
+select array[1, 2];
+select array[1];
+select array[7];
+select array[[1, 2], [3, 4]];
+select array[[1], [2]];
+select array[[1], [2]] || (array[3])[1];
+select array[[], [[5]]];
+select array[];
+select array[array[1], array[2]];
+select b'0101';
+select b'0110';
+select b'0110'
+'1010'
+'0101';
+select bar;
+select bar as s;
+select bar int;
+select baz();
+select baz(lorem);
+select begin commit; commit;
+select between.qux;
+select between.qux '123';
+select between.qux() filter (where 1);
+select between.qux(1) '123';
+select bigint '1';
+select bit varying(6) '7';
+select boolean 'true';
+select breadth;
+select builtin_locale;
+select by;
+select by ref;
+select by value;
+select bypassrls;
+select cascade;
+select case when 1 then 2 end;
+select cast ('1' as int);
+select cast(null as authorization);
+select cast(null as bit varying(55));
+select cast(null as bit(77));
+select cast(null as char varying(5));
+select cast(null as char(4));
+select cast(null as character varying(2));
+select cast(null as character(2));
+select cast(null as collation);
+select cast(null as dec(20));
+select cast(null as decimal(10));
+select cast(null as empty array);
+select cast(null as float(17));
+select cast(null as float(44));
+select cast(null as full.type_func_name);
+select cast(null as int[]);
+select cast(null as interval(5));
+select cast(null as national char varying(5));
+select cast(null as national char varying);
+select cast(null as national char(7));
+select cast(null as national character varying(3));
+select cast(null as national character varying);
+select cast(null as national character(8));
+select cast(null as nchar varying(7));
+select cast(null as nchar(9));
+select cast(null as numeric(30));
+select cast(null as setof double precision[10][]);
+select cast(null as setof int[]);
+select cast(null as setof some_.qualified_name);
+select cast(null as time with time zone);
+select cast(null as time without time zone);
+select cast(null as time(5));
+select cast(null as time(7) without time zone);
+select cast(null as time(9) with time zone);
+select cast(null as timestamp with time zone);
+select cast(null as timestamp without time zone);
+select cast(null as timestamp(5));
+select cast(null as timestamp(7) without time zone);
+select cast(null as timestamp(9) with time zone);
+select cast(null as varchar(3));
+select catalog 'def';
+select character varying 'foo';
+select coalesce('foo', 'bar');
+select coalesce(1);
+select collation for ('foo');
+select collation for (5);
+select collation for(1);
+select collation();
+select collation() filter (where 1);
+select collation_version;
+select connection limit 5;
+select conversion some_conversion;
+select createdb;
+select createrole;
+select current row;
+select current_USER;
+select current_catalog;
+select current_date;
+select current_role;
+select current_schema;
+select current_schema();
+select current_schema(1) 'foo';
+select current_time;
+select current_time(3);
+select current_timestamp;
+select current_timestamp(7);
+select current_user;
+select cycle;
+select database a, b;
+select database some_database;
+select day;
+select deferred;
+select disable;
+select distinct a, 1 as b, 2 c;
+select distinct on (a) * from t;
+select domain a, b;
+select domain int;
+select double;
+select double '123';
+select double int;
+select double precision '1.23';
+select double();
+select double(1) '123';
+select double.baz;
+select double.baz '123';
+select double.baz() filter (where 1);
+select double.baz(1) '123';
+select double.double;
+select double.trouble();
+select double.unreserved;
+select double.unreserved(55);
+select drop some_opt;
+select e'\u0061n extended string';
+select e'extended string'
+' concatenation';
+select empty;
+select empty object;
+select enable;
+select enable always;
+select enable replica;
+select encoding;
+select error;
+select exclude ties;
+select exists (select 1);
+select extension some_extension;
+select extension some_extension from old_version;
+select extract(month from 1);
+select extract(year from 'foo');
+select false;
+select float(25) '123.45';
+select float.boat;
+select float.point();
+select foo;
+select foo '123';
+select foo 'bar';
+select foo false;
+select foo();
+select foo(*);
+select foo(1);
+select foo(1) '123';
+select foo(1, 2) is not distinct from b;
+select foo, bar;
+select foo.bar;
+select foo.bar '123';
+select foo.bar(1) '123';
+select format;
+select format 'json';
+select format json;
+select from t window w as ();
+select function;
+select function a, b;
+select function some_function;
+select functions;
+select global temp;
+select global temporary;
+select greatest(1);
+select greatest(1, 2);
+select grouping(1);
+select groups current;
+select groups groups;
+select groups partition;
+select groups range;
+select groups rows;
+select groups unbounded;
+select hour;
+select icu_locale;
+select icu_rules;
+select ident;
+select ident();
+select ident.qualified_();
+select identif(33);
+select identif.attrib;
+select ignore nulls;
+select immediate;
+select immutable;
+select indent;
+select index;
+select index some_index;
+select inherit;
+select inner();
+select int '42';
+select integer '420';
+select interval '1 day';
+select interval '1970-01' year to month;
+select interval '5' hour;
+select interval(3) '1 day';
+select interval(3) '5';
+select is 'abc';
+select is_template;
+select json '{}';
+select json('bar' format json encoding UTF8 with unique keys);
+select json('foo');
+select json('{}');
+select json_arrayagg(1);
+select json_arrayagg(2 order by 3 null on null returning bigint);
+select json_exists('{}', 'foo' passing 1 as a null on error);
+select json_exists('{}', 'foo');
+select json_object('foo');
+select json_object();
+select json_objectagg('bar': 2 absent on null with unique returning int);
+select json_objectagg('foo': 1);
+select json_query('{}', 'foo' passing 1 as a returning int with wrapper keep quotes error on empty);
+select json_query('{}', 'foo');
+select json_scalar(1);
+select json_serialize(1 returning int);
+select json_serialize(1);
+select json_value('{}', 'foo' passing 1 as a returning int null on error);
+select json_value('{}', 'foo');
+select keep quotes;
+select language;
+select language a, b;
+select language plsample;
+select language some_language;
+select large objects;
+select lc_collate;
+select lc_ctype;
+select leakproof;
+select least(1);
+select least(1, 2);
+select limit 1;
+select limit 1 offset 2;
+select limit all;
+select local;
+select local temp;
+select local temporary;
+select locale;
+select locale_provider;
+select localtime;
+select localtime(6);
+select localtimestamp;
+select localtimestamp(4);
+select location;
+select logged;
+select login;
+select merge_action();
+select minute;
+select minvalue -100;
+select month;
+select name;
+select names;
+select names 'utf8';
+select names default;
+select narslog;
+select nfc;
+select nfd;
+select nfkc;
+select nfkd;
+select no cycle;
+select no indent;
+select no maxvalue;
+select no minvalue;
+select no_match;
+select nobypassrls;
+select nocreatedb;
+select nocreaterole;
+select noinherit;
+select nologin;
+select noreplication;
+select normalize('foo');
+select normalize('foo', nfc);
+select nosuperuser;
+select not a = b;
+select not leakproof;
+select not null;
+select null;
+select nullif(1, 2);
+select nullif(null, 'foo');
+select nulls distinct;
+select nulls first;
+select nulls last;
+select numeric '123.45';
+select off;
+select offset 2 limit 1;
+select offset 2 rows;
+select oid;
+select oid = 54321;
+select omit quotes;
+select over (foo);
+select over foo;
+select overlay('foo');
+select overlay();
+select overlay(1);
+select owner;
+select parallel safe;
+select parameter a, b;
+select partition by;
+select partition groups;
+select partition partition;
+select partition range;
+select partition rows;
+select passing 'foo';
+select password 'password1';
+select password null;
+select path 'foo';
+select position('f' in 'foo');
+select preserve whitespace;
+select procedure;
+select procedure a, b;
+select procedure some_procedure;
+select public;
+select publication some_publication;
+select qualif.ident;
+select qualified.name;
+select range current;
+select range groups;
+select range partition;
+select range range;
+select range rows;
+select range unbounded;
+select rank() over (w rows current row) from t window w as (partition by a);
+select rank() over w from t window w as (order by a);
+select read committed;
+select read only;
+select read uncommitted;
+select read write;
+select real '42.0';
+select repeatable (1);
+select repeatable read;
+select replication;
+select respect nulls;
+select restart;
+select restrict;
+select role;
+select role action;
+select role foo;
+select role public;
+select role some_role;
+select routine;
+select routine a, b;
+select routine some_routine;
+select routines;
+select row();
+select row(1);
+select row(1, 'foo');
+select row(a, b) < row(1, 2);
+select rows current;
+select rows groups;
+select rows partition;
+select rows range;
+select rows rows;
+select rows unbounded;
+select sOmE_iDeNtIfIeR;
+select schema 'ghi';
+select schema a, b;
+select schema some_schema;
+select schemas;
+select second;
+select second(3);
+select security definer;
+select security invoker;
+select sequence a, b;
+select sequence some_seq;
+select sequence some_sequence;
+select sequences;
+select serializable;
+select server some_server;
+select session authorization;
+select session_user;
+select smallint '11';
+select some_name;
+select some_opt 'foo';
+select something else;
+select stable;
+select standalone no;
+select standalone yes;
+select statistics some_statistics;
+select storage int;
+select strategy;
+select strict;
+select strip whitespace;
+select subscription some_subscription;
+select substring('foo');
+select substring();
+select superuser;
+select support some_function;
+select system_user;
+select tables;
+select tablespace;
+select tablespace a, b;
+select tablespace some_tablespace;
+select temp;
+select template;
+select temporary;
+select time(1) with time zone 'foo';
+select timestamp with time zone 'foo';
+select treat(1 as int);
+select treat(123 as int);
+select trim('foo' from 'bar');
+select trim('foo');
+select trim.something;
+select true;
+select type a, b;
+select type int;
+select type shell_type;
+select types;
+select u&"d!0061ta" UESCAPE '!';
+select u&"d\0061ta";
+select u&'!0061n escaped unicode string!0021' UESCAPE '!';
+select u&'*002a extended unicode *002a' UESCAPE e'*';
+select u&'\0061n unicode string';
+select u&'unicode esc!0061pe concatenation' UESCAPE ''
+''
+'!';
+select u&'unicode string'
+' concatenation';
+select uSeR;
+select umpus(x);
+select unbounded following;
+select unbounded preceding;
+select unknown;
+select unlogged;
+select user;
+select user public;
+select utf8;
+select verbose 'foo';
+select version '1.0';
+select view some_view;
+select volatile;
+select without;
+select without function;
+select without unique;
+select without wrapper;
+select x'19af';
+select x'abcd';
+select x'abcd'
+'4321'
+'f765';
+select xmlconcat('foo');
+select xmlconcat('foo', 'bar');
+select xmlelement(name foo);
+select xmlelement(name foo, 'bar');
+select xmlelement(name foo, xmlattributes(1 as a), 'foo');
+select xmlexists('foo' passing 'bar');
+select xmlforest('foo');
+select xmlforest('foo', 'bar' as baz);
+select xmlparse(content 'foo');
+select xmlparse(document 'foo' preserve whitespace);
+select xmlparse(document 'foo');
+select xmlpi(name bar, 'baz');
+select xmlpi(name foo);
+select xmlroot('foo', version '1.0', standalone yes);
+select xmlroot('foo', version no value);
+select xmlserialize(content 'foo' as int);
+select xmlserialize(document '123' as int);
+select xmlserialize(document 'bar' as bigint indent);
+select xxyyzz;
+select year;
+select(column_name);
+set _var to default;
+set day to hour;
+set day to second;
+set hour to minute;
+set hour to second;
+set schema 'abc123';
+set session transaction snapshot 'abc';
+set time zone default;
+set time zone local;
+set transaction read only;
+set transaction snapshot 'abc';
+set xml option document;
+set year to month;
+show all;
+start transaction;
+start transaction read only, read write deferrable;
+table foo;
+table some_table;
+unlisten *;
+unlisten test_name;
+values (1);
+select a is null, a is not null, a isnull, a notnull;
+select a is true, a is not false, a is unknown, a is not unknown;
+select a is distinct from b, a is not distinct from b;
+select a between b and c, a not between symmetric b and c;
+select a similar to b, a not similar to b escape c;
+select a like b escape c, a not ilike b;
+select a at time zone 'UTC', a at local;
+select a collate "C", a || b collate pg_catalog."default";
+select a = any(b), a < all(array[1, 2]), a = some(select 1);
+select (a is null) is not true, - (a collate "C");
@@ -0,0 +1,150 @@
+impl Deparse for SelectStmt {
+    /// Alias: `SelectStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        match self.body() {
+            SelectBody::Simple(select) => select.deparse(f)?,
+            SelectBody::Values(rows) => {
+                f.write_str("VALUES ")?;
+                write_list(f, rows, |f, row| write!(f, "({})", List(row)))?;
+            },
+            SelectBody::SetOperation { op, all, left, right } => {
+
+                let left_parens = has_select_options(left)
+                    || (
+                        *op == SetOperation::Intersect
+                        && matches!(left.body(), SelectBody::SetOperation { op, .. } if *op != SetOperation::Intersect)
+                    );
+
+                // Set operations are left-associative, but `INTERSECT` binds tighter than `UNION` and `EXCEPT`.
+                let right_parens = has_select_options(right)
+                    || match right.body() {
+                        SelectBody::SetOperation { op: right_op, .. } => {
+                            *op == SetOperation::Intersect || *right_op != SetOperation::Intersect
+                        },
+                        _ => false,
+                    };
+
+                write_operand(f, left, left_parens)?;
+
+                f.write_str(match op {
+                    SetOperation::Union => " UNION ",
+                    SetOperation::Intersect => " INTERSECT ",
+                    SetOperation::Except => " EXCEPT ",
+                })?;
+                if *all {
+                    f.write_str("ALL ")?;
+                }
+
+                write_operand(f, right, right_parens)?;
+            },
+        }
+
+        if let Some(sort_clause) = self.sort_clause() {
+            write_sort_clause(f, sort_clause)?;
+        }
+
+        match self.limit_count() {
+            Some(count) if matches!(count.kind(), ExprKind::NullConst) => f.write_str(" LIMIT ALL")?,
+            Some(count) => write!(f, " LIMIT {}", count.sql())?,
+            None => {},
+        }
+
+        if let Some(offset) = self.limit_offset() {
+            write!(f, " OFFSET {}", offset.sql())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deparse for SimpleSelect {
+    /// Alias: `simple_select`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        f.write_str("SELECT")?;
+
+        match self.distinct_clause() {
+            Some(DistinctClause::Distinct) => f.write_str(" DISTINCT")?,
+            Some(DistinctClause::DistinctOn(exprs)) => write!(f, " DISTINCT ON ({})", List(exprs))?,
+            None => {},
+        }
+
+        if !self.target_list().is_empty() {
+            write!(f, " {}", List(self.target_list()))?;
+        }
+
+        if let Some(from_clause) = self.from_clause() {
+            write!(f, " FROM {}", List(from_clause))?;
+        }
+
+        if let Some(where_clause) = self.where_clause() {
+            write!(f, " WHERE {}", where_clause.sql())?;
+        }
+
+        if let Some(group_clause) = self.group_clause() {
+            f.write_str(" GROUP BY ")?;
+            if self.group_distinct() {
+                f.write_str("DISTINCT ")?;
+            }
+            List(group_clause).fmt(f)?;
+        }
+
+        if let Some(having_clause) = self.having_clause() {
+            write!(f, " HAVING {}", having_clause.sql())?;
+        }
+
+        if let Some(window_clause) = self.window_clause() {
+            f.write_str(" WINDOW ")?;
+            write_list(f, window_clause, |f, window| {
+                write!(f, "{} AS {}", Ident(window.name()), window.definition().sql())
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deparse for ResTarget {
+    /// Alias: `target_el`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Star => f.write_char('*'),
+            Self::Expr { value, name: None } => value.deparse(f),
+            Self::Expr { value, name: Some(name) } => write!(f, "{} AS {}", value.sql(), Label(name)),
+        }
+    }
+}
+
+fn has_select_options(stmt: &SelectStmt) -> bool {
+    stmt.sort_clause().is_some()
+        || stmt.limit_count().is_some()
+        || stmt.limit_offset().is_some()
+}
+
+fn write_operand(f: &mut Formatter<'_>, stmt: &SelectStmt, parens: bool) -> fmt::Result {
+    if parens {
+        write!(f, "({})", stmt.sql())
+    }
+    else {
+        stmt.deparse(f)
+    }
+}
+
+use crate::write_list;
+use crate::write_sort_clause;
+use crate::Deparse;
+use crate::Ident;
+use crate::Label;
+use crate::List;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Write;
+use pg_ast::DistinctClause;
+use pg_ast::ExprKind;
+use pg_ast::ResTarget;
+use pg_ast::SelectBody;
+use pg_ast::SelectStmt;
+use pg_ast::SetOperation;
+use pg_ast::SimpleSelect;
@@ -0,0 +1,81 @@
+impl Deparse for CreateSeqStmt {
+    /// Alias: `CreateSeqStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        f.write_str("CREATE ")?;
+        write_persistence(f, self.sequence().persistence())?;
+        f.write_str("SEQUENCE ")?;
+        if self.existence() == Presence::Ignore {
+            f.write_str("IF NOT EXISTS ")?;
+        }
+        self.sequence().relation().deparse(f)?;
+
+        for option in self.options().unwrap_or_default() {
+            write!(f, " {}", option.sql())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deparse for AlterSeqStmt {
+    /// Alias: `AlterSeqStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        f.write_str("ALTER SEQUENCE ")?;
+        write_if_exists(f, self.existence() == Presence::Ignore)?;
+        self.sequence().deparse(f)?;
+
+        for option in self.options() {
+            write!(f, " {}", option.sql())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deparse for SeqOptElem {
+    /// Alias: `SeqOptElem`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::As(type_name) => write!(f, "AS {}", type_name.sql()),
+            Self::Cache(cache) => write!(f, "CACHE {}", cache.sql()),
+            Self::Cycle(true) => f.write_str("CYCLE"),
+            Self::Cycle(false) => f.write_str("NO CYCLE"),
+            Self::Increment(increment) => write!(f, "INCREMENT BY {}", increment.sql()),
+            Self::MaxValue(Some(value)) => write!(f, "MAXVALUE {}", value.sql()),
+            Self::MaxValue(None) => f.write_str("NO MAXVALUE"),
+            Self::MinValue(Some(value)) => write!(f, "MINVALUE {}", value.sql()),
+            Self::MinValue(None) => f.write_str("NO MINVALUE"),
+            Self::OwnedBy(column) => write!(f, "OWNED BY {}", QualName(column)),
+            Self::SequenceName(name) => write!(f, "SEQUENCE NAME {}", QualName(name)),
+            Self::Start(start) => write!(f, "START WITH {}", start.sql()),
+            Self::Restart(Some(restart)) => write!(f, "RESTART WITH {}", restart.sql()),
+            Self::Restart(None) => f.write_str("RESTART"),
+            Self::Logged(true) => f.write_str("LOGGED"),
+            Self::Logged(false) => f.write_str("UNLOGGED"),
+        }
+    }
+}
+
+/// Alias: `OptTemp`
+///
+/// Writes a trailing space, when it's not the default persistence.
+pub(crate) fn write_persistence(f: &mut Formatter<'_>, persistence: RelationPersistence) -> fmt::Result {
+    match persistence {
+        RelationPersistence::Permanent => Ok(()),
+        RelationPersistence::Unlogged => f.write_str("UNLOGGED "),
+        RelationPersistence::Temp => f.write_str("TEMPORARY "),
+    }
+}
+
+use crate::write_if_exists;
+use crate::Deparse;
+use crate::QualName;
+use core::fmt;
+use core::fmt::Formatter;
+use pg_ast::AlterSeqStmt;
+use pg_ast::CreateSeqStmt;
+use pg_ast::Presence;
+use pg_ast::RelationPersistence;
+use pg_ast::SeqOptElem;
@@ -0,0 +1,164 @@
+impl Deparse for VariableSetStmt {
+    /// Alias: `VariableSetStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("SET ")?;
+        if self.is_local() {
+            f.write_str("LOCAL ")?;
+        }
+        self.stmt().deparse(f)
+    }
+}
+
+impl Deparse for SetRest {
+    /// Alias: `set_rest`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SessionTransactionCharacteristics(modes) => {
+                f.write_str("SESSION CHARACTERISTICS AS TRANSACTION")?;
+                write_transaction_modes(f, modes)
+            },
+            Self::LocalTransactionCharacteristics(modes) => {
+                f.write_str("TRANSACTION")?;
+                write_transaction_modes(f, modes)
+            },
+            Self::SessionAuthorization { user } => write_session_authorization(f, user),
+            Self::TransactionSnapshot(snapshot) => write!(f, "TRANSACTION SNAPSHOT {}", StrLit(snapshot)),
+            Self::TimeZone(zone) => write!(f, "TIME ZONE {}", zone.sql()),
+            Self::Catalog(catalog) => write!(f, "CATALOG {}", StrLit(catalog)),
+            Self::Schema(schema) => write!(f, "SCHEMA {}", StrLit(schema)),
+            Self::ClientEncoding(encoding) => write_names(f, encoding),
+            Self::Role(role) => write!(f, "ROLE {}", StrLit(role)),
+            Self::XmlOption(option) => write_xml_option(f, *option),
+            Self::FromCurrent { name } => write!(f, "{} FROM CURRENT", VarName(name)),
+            Self::ConfigurationParameter { name, value } => write_generic_set(f, name, value),
+        }
+    }
+}
+
+impl Deparse for SetRestMore {
+    /// Alias: `set_rest_more`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SessionAuthorization { user } => write_session_authorization(f, user),
+            Self::TransactionSnapshot(snapshot) => write!(f, "TRANSACTION SNAPSHOT {}", StrLit(snapshot)),
+            Self::TimeZone(zone) => write!(f, "TIME ZONE {}", zone.sql()),
+            Self::Catalog(catalog) => write!(f, "CATALOG {}", StrLit(catalog)),
+            Self::Schema(schema) => write!(f, "SCHEMA {}", StrLit(schema)),
+            Self::ClientEncoding(encoding) => write_names(f, encoding),
+            Self::Role(role) => write!(f, "ROLE {}", StrLit(role)),
+            Self::XmlOption(option) => write_xml_option(f, *option),
+            Self::FromCurrent { name } => write!(f, "{} FROM CURRENT", VarName(name)),
+            Self::ConfigurationParameter { name, value } => write_generic_set(f, name, value),
+        }
+    }
+}
+
+impl Deparse for ZoneValue {
+    /// Alias: `zone_value`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Local => f.write_str("LOCAL"),
+            Self::String(zone) => StrLit(zone).fmt(f),
+            Self::Numeric(zone) => zone.deparse(f),
+            Self::Interval { value, range: IntervalRange::Full { precision: Some(precision) } } => {
+                write!(f, "INTERVAL({precision}) {}", StrLit(value))
+            },
+            Self::Interval { value, range } => {
+                write!(f, "INTERVAL {}", StrLit(value))?;
+                if !matches!(range, IntervalRange::Full { .. }) {
+                    write!(f, " {}", range.sql())?;
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+impl Deparse for VariableTarget {
+    /// Alias: `reset_rest`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::All => f.write_str("ALL"),
+            Self::SessionAuthorization => f.write_str("SESSION AUTHORIZATION"),
+            Self::TransactionIsolation => f.write_str("TRANSACTION ISOLATION LEVEL"),
+            Self::TimeZone => f.write_str("TIME ZONE"),
+            Self::Variable { name } => VarName(name).fmt(f),
+        }
+    }
+}
+
+impl Deparse for SetResetClause {
+    /// Alias: `SetResetClause`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Set(set_rest) => write!(f, "SET {}", set_rest.sql()),
+            Self::Reset(target) => write!(f, "RESET {}", target.sql()),
+        }
+    }
+}
+
+impl Deparse for AlterSystemStmt {
+    /// Alias: `AlterSystemStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ResetAll => f.write_str("ALTER SYSTEM RESET ALL"),
+            Self::Reset { name } => write!(f, "ALTER SYSTEM RESET {}", VarName(name)),
+            Self::SetDefault { name } => write!(f, "ALTER SYSTEM SET {} TO DEFAULT", VarName(name)),
+            Self::SetNull { name } => write!(f, "ALTER SYSTEM SET {} TO NULL", VarName(name)),
+            Self::Set { name, values } => write!(f, "ALTER SYSTEM SET {} TO {}", VarName(name), List(values)),
+        }
+    }
+}
+
+fn write_session_authorization(f: &mut Formatter<'_>, user: &ValueOrDefault<Str>) -> fmt::Result {
+    match user {
+        ValueOrDefault::Default => f.write_str("SESSION AUTHORIZATION DEFAULT"),
+        ValueOrDefault::Value(user) => write!(f, "SESSION AUTHORIZATION {}", StrLit(user)),
+    }
+}
+
+fn write_names(f: &mut Formatter<'_>, encoding: &ValueOrDefault<Box<str>>) -> fmt::Result {
+    match encoding {
+        ValueOrDefault::Default => f.write_str("NAMES DEFAULT"),
+        ValueOrDefault::Value(encoding) => write!(f, "NAMES {}", StrLit(encoding)),
+    }
+}
+
+fn write_xml_option(f: &mut Formatter<'_>, option: XmlNodeKind) -> fmt::Result {
+    match option {
+        XmlNodeKind::Document => f.write_str("XML OPTION DOCUMENT"),
+        XmlNodeKind::Content => f.write_str("XML OPTION CONTENT"),
+    }
+}
+
+/// `var_name generic_set_tail`
+fn write_generic_set(f: &mut Formatter<'_>, name: &[Str], value: &DefaultableValue<Vec<VarValue>>) -> fmt::Result {
+    write!(f, "{} TO ", VarName(name))?;
+    match value {
+        DefaultableValue::Default => f.write_str("DEFAULT"),
+        DefaultableValue::Null => f.write_str("NULL"),
+        DefaultableValue::Value(values) => List(values).fmt(f),
+    }
+}
+
+use crate::write_transaction_modes;
+use crate::Deparse;
+use crate::List;
+use crate::StrLit;
+use crate::VarName;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use pg_ast::AlterSystemStmt;
+use pg_ast::DefaultableValue;
+use pg_ast::IntervalRange;
+use pg_ast::SetResetClause;
+use pg_ast::SetRest;
+use pg_ast::SetRestMore;
+use pg_ast::ValueOrDefault;
+use pg_ast::VarValue;
+use pg_ast::VariableSetStmt;
+use pg_ast::VariableTarget;
+use pg_ast::XmlNodeKind;
+use pg_ast::ZoneValue;
+use pg_basics::Str;
@@ -0,0 +1,102 @@
+impl Deparse for AggregateWithArgs {
+    /// Alias: `aggregate_with_argtypes`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        write!(f, "{}(", QualName(self.name()))?;
+
+        match (self.args(), self.order_by()) {
+            ([], []) => f.write_char('*')?,
+            ([], order_by) => write!(f, "ORDER BY {}", List(order_by))?,
+            (args, []) => List(args).fmt(f)?,
+            (args, order_by) => write!(f, "{} ORDER BY {}", List(args), List(order_by))?,
+        }
+
+        f.write_char(')')
+    }
+}
+
+impl Deparse for FunctionWithArgs {
+    /// Alias: `function_with_argtypes`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        QualName(self.name()).fmt(f)?;
+
+        match self.args() {
+            None => Ok(()),
+            Some(None) => f.write_str("()"),
+            Some(Some(args)) => write!(f, "({})", List(args)),
+        }
+    }
+}
+
+impl Deparse for FunctionParameter {
+    /// Alias: `func_arg`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        let mode = match self.mode() {
+            FunctionParameterMode::Default | FunctionParameterMode::Table => None,
+            FunctionParameterMode::In => Some("IN"),
+            FunctionParameterMode::Out => Some("OUT"),
+            FunctionParameterMode::InOut => Some("INOUT"),
+            FunctionParameterMode::Variadic => Some("VARIADIC"),
+        };
+
+        if let Some(mode) = mode {
+            write!(f, "{mode} ")?;
+        }
+
+        // Argument names are ambiguous with type names, so keywords are always quoted.
+        if let Some(name) = self.name() {
+            write!(f, "{} ", Identifier(name))?;
+        }
+
+        self.arg_type().deparse(f)
+    }
+}
+
+impl Deparse for OperatorWithArgs {
+    /// Alias: `operator_with_argtypes`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        AnyOp(self.name()).fmt(f)?;
+
+        match self.args() {
+            OneOrBoth::Left(left) => write!(f, " ({}, NONE)", left.sql()),
+            OneOrBoth::Right(right) => write!(f, " (NONE, {})", right.sql()),
+            OneOrBoth::Both(left, right) => write!(f, " ({}, {})", left.sql(), right.sql()),
+        }
+    }
+}
+
+impl Deparse for Typecast {
+    /// `CAST '(' Typename AS Typename ')'`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "CAST ({} AS {})", self.from_type().sql(), self.to_type().sql())
+    }
+}
+
+impl Deparse for Transform {
+    /// `TRANSFORM FOR Typename LANGUAGE ColId`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "TRANSFORM FOR {} LANGUAGE {}", self.for_type().sql(), Ident(self.language()))
+    }
+}
+
+use crate::AnyOp;
+use crate::Deparse;
+use crate::Ident;
+use crate::Identifier;
+use crate::List;
+use crate::QualName;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Write;
+use pg_ast::AggregateWithArgs;
+use pg_ast::FunctionParameter;
+use pg_ast::FunctionParameterMode;
+use pg_ast::FunctionWithArgs;
+use pg_ast::OneOrBoth;
+use pg_ast::OperatorWithArgs;
+use pg_ast::Transform;
+use pg_ast::Typecast;
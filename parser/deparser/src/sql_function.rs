@@ -0,0 +1,445 @@
+impl Deparse for SqlFunction {
+    /// Alias: `func_expr_common_subexpr`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Coalesce(args) => write!(f, "COALESCE({})", List(args)),
+            Self::CollationFor(arg) => write!(f, "COLLATION FOR ({})", arg.sql()),
+            Self::CurrentCatalog => f.write_str("CURRENT_CATALOG"),
+            Self::CurrentSchema => f.write_str("CURRENT_SCHEMA"),
+            Self::Greatest(args) => write!(f, "GREATEST({})", List(args)),
+            Self::Least(args) => write!(f, "LEAST({})", List(args)),
+            Self::MergeAction => f.write_str("MERGE_ACTION()"),
+            Self::NullIf(left, right) => write!(f, "NULLIF({}, {})", left.sql(), right.sql()),
+            Self::Treat(typecast) => {
+                write!(f, "TREAT({} AS {})", typecast.arg().sql(), typecast.type_name().sql())
+            },
+            Self::Typecast(typecast) => {
+                write!(f, "CAST({} AS {})", typecast.arg().sql(), typecast.type_name().sql())
+            },
+            Self::Normalize(func) => {
+                write!(f, "NORMALIZE({}", func.expr().sql())?;
+                if let Some(normal_form) = func.normal_form() {
+                    let normal_form = match normal_form {
+                        UnicodeNormalForm::CanonicalComposition => "NFC",
+                        UnicodeNormalForm::CanonicalDecomposition => "NFD",
+                        UnicodeNormalForm::CompatibilityComposition => "NFKC",
+                        UnicodeNormalForm::CompatibilityDecomposition => "NFKD",
+                    };
+                    write!(f, ", {normal_form}")?;
+                }
+                f.write_char(')')
+            },
+            Self::Overlay(OverlayFunc::ExplicitCall(args)) => {
+                write!(f, "OVERLAY({})", List(args.as_deref().unwrap_or_default()))
+            },
+            Self::Overlay(OverlayFunc::SqlSyntax(args)) => {
+                write!(f,
+                    "OVERLAY({} PLACING {} FROM {}",
+                    args.arg().sql(),
+                    args.placing().sql(),
+                    args.from().sql()
+                )?;
+                if let Some(for_expr) = args.for_expr() {
+                    write!(f, " FOR {}", for_expr.sql())?;
+                }
+                f.write_char(')')
+            },
+            Self::Position(func) => {
+                write!(f, "POSITION({} IN {})", BExpr(func.needle()), BExpr(func.haystack()))
+            },
+            Self::Substring(SubstringFunc::ExplicitCall(args)) => {
+                write!(f, "SUBSTRING({})", List(args.as_deref().unwrap_or_default()))
+            },
+            Self::Substring(SubstringFunc::SqlSyntax(arg, from, for_expr)) => {
+                write!(f, "SUBSTRING({} FROM {}", arg.sql(), from.sql())?;
+                if let Some(for_expr) = for_expr {
+                    write!(f, " FOR {}", for_expr.sql())?;
+                }
+                f.write_char(')')
+            },
+            Self::Trim(func) => {
+                f.write_str("TRIM(")?;
+                match func.trim_side() {
+                    TrimSide::Both => {},
+                    TrimSide::Leading => f.write_str("LEADING ")?,
+                    TrimSide::Trailing => f.write_str("TRAILING ")?,
+                }
+                // The parser appends the argument before `FROM` to the list after it.
+                match func.args().split_last() {
+                    Some((last, [])) => last.deparse(f)?,
+                    Some((last, args)) => write!(f, "{} FROM {}", last.sql(), List(args))?,
+                    None => {},
+                }
+                f.write_char(')')
+            },
+            Self::CurrentDate => f.write_str("CURRENT_DATE"),
+            Self::CurrentTime { precision } => write_time_func(f, "CURRENT_TIME", *precision),
+            Self::CurrentTimestamp { precision } => write_time_func(f, "CURRENT_TIMESTAMP", *precision),
+            Self::LocalTime { precision } => write_time_func(f, "LOCALTIME", *precision),
+            Self::LocalTimestamp { precision } => write_time_func(f, "LOCALTIMESTAMP", *precision),
+            Self::Extract(func) => {
+                f.write_str("EXTRACT(")?;
+                match func.field() {
+                    ExtractArg::Year => f.write_str("YEAR")?,
+                    ExtractArg::Month => f.write_str("MONTH")?,
+                    ExtractArg::Day => f.write_str("DAY")?,
+                    ExtractArg::Hour => f.write_str("HOUR")?,
+                    ExtractArg::Minute => f.write_str("MINUTE")?,
+                    ExtractArg::Second => f.write_str("SECOND")?,
+                    ExtractArg::Named(field) => StrLit(field).fmt(f)?,
+                }
+                write!(f, " FROM {})", func.target().sql())
+            },
+            Self::CurrentRole => f.write_str("CURRENT_ROLE"),
+            Self::CurrentUser => f.write_str("CURRENT_USER"),
+            Self::SessionUser => f.write_str("SESSION_USER"),
+            Self::SystemUser => f.write_str("SYSTEM_USER"),
+            Self::User => f.write_str("USER"),
+            Self::Json(func) => {
+                write!(f, "JSON({}", func.value().sql())?;
+                if func.is_unique() {
+                    f.write_str(" WITH UNIQUE KEYS")?;
+                }
+                f.write_char(')')
+            },
+            Self::JsonArrayAgg(agg) => agg.deparse(f),
+            Self::JsonExists(func) => {
+                write!(f, "JSON_EXISTS({}, {}", func.context_item().sql(), func.path_spec().sql())?;
+                write_json_passing(f, func.passing())?;
+                if let Some(on_error) = func.on_error() {
+                    write!(f, " {} ON ERROR", on_error.sql())?;
+                }
+                f.write_char(')')
+            },
+            Self::JsonObject(JsonObjectExpr::ExplicitCall(args)) => {
+                write!(f, "JSON_OBJECT({})", List(args.as_deref().unwrap_or_default()))
+            },
+            Self::JsonObject(JsonObjectExpr::SqlSyntax(args)) => {
+                f.write_str("JSON_OBJECT(")?;
+                let exprs = args.exprs().unwrap_or_default();
+                List(exprs).fmt(f)?;
+                if !exprs.is_empty() {
+                    if args.absent_on_null() {
+                        f.write_str(" ABSENT ON NULL")?;
+                    }
+                    if args.unique() {
+                        f.write_str(" WITH UNIQUE KEYS")?;
+                    }
+                }
+                if let Some(output) = args.output() {
+                    if !exprs.is_empty() {
+                        f.write_char(' ')?;
+                    }
+                    output.deparse(f)?;
+                }
+                f.write_char(')')
+            },
+            Self::JsonObjectAgg(agg) => agg.deparse(f),
+            Self::JsonQuery(func) => {
+                write!(f, "JSON_QUERY({}, {}", func.context_item().sql(), func.path_spec().sql())?;
+                write_json_passing(f, func.passing())?;
+                if let Some(output) = func.output() {
+                    write!(f, " {}", output.sql())?;
+                }
+                match func.wrapper() {
+                    Some(JsonWrapperBehavior::Without) => f.write_str(" WITHOUT WRAPPER")?,
+                    Some(JsonWrapperBehavior::Conditional) => f.write_str(" WITH CONDITIONAL WRAPPER")?,
+                    Some(JsonWrapperBehavior::Unconditional) => f.write_str(" WITH UNCONDITIONAL WRAPPER")?,
+                    None => {},
+                }
+                match func.quotes() {
+                    Some(JsonQuotes::Keep) => f.write_str(" KEEP QUOTES")?,
+                    Some(JsonQuotes::Omit) => f.write_str(" OMIT QUOTES")?,
+                    None => {},
+                }
+                if let Some(behavior) = func.behavior() {
+                    behavior.deparse(f)?;
+                }
+                f.write_char(')')
+            },
+            Self::JsonScalar(arg) => write!(f, "JSON_SCALAR({})", arg.sql()),
+            Self::JsonSerialize(func) => {
+                write!(f, "JSON_SERIALIZE({}", func.expr().sql())?;
+                if let Some(output) = func.output() {
+                    write!(f, " {}", output.sql())?;
+                }
+                f.write_char(')')
+            },
+            Self::JsonValue(func) => {
+                write!(f, "JSON_VALUE({}, {}", func.context_item().sql(), func.path_spec().sql())?;
+                write_json_passing(f, func.passing())?;
+                if let Some(output) = func.output() {
+                    write!(f, " {}", output.sql())?;
+                }
+                func.behavior().deparse(f)?;
+                f.write_char(')')
+            },
+            Self::XmlConcat(args) => write!(f, "XMLCONCAT({})", List(args)),
+            Self::XmlElement(el) => {
+                write!(f, "XMLELEMENT(NAME {}", Label(el.name()))?;
+                if let Some(attributes) = el.attributes() {
+                    f.write_str(", XMLATTRIBUTES(")?;
+                    write_list(f, attributes, |f, attr| LabeledExpr(attr).fmt(f))?;
+                    f.write_char(')')?;
+                }
+                if let Some(content) = el.content() {
+                    write!(f, ", {}", List(content))?;
+                }
+                f.write_char(')')
+            },
+            Self::XmlExists(func) => {
+                write!(f, "XMLEXISTS({} PASSING {})", CExpr(func.path_spec()), CExpr(func.content()))
+            },
+            Self::XmlForest(args) => {
+                f.write_str("XMLFOREST(")?;
+                write_list(f, args, |f, arg| LabeledExpr(arg).fmt(f))?;
+                f.write_char(')')
+            },
+            Self::XmlParse(func) => {
+                write!(f, "XMLPARSE({} {}", XmlNodeKindKw(func.kind()), func.content().sql())?;
+                if func.whitespace() == XmlWhitespaceOption::Preserve {
+                    f.write_str(" PRESERVE WHITESPACE")?;
+                }
+                f.write_char(')')
+            },
+            Self::XmlProcessingInstruction(func) => {
+                write!(f, "XMLPI(NAME {}", Label(func.name()))?;
+                if let Some(value) = func.value() {
+                    write!(f, ", {}", value.sql())?;
+                }
+                f.write_char(')')
+            },
+            Self::XmlRoot(func) => {
+                write!(f, "XMLROOT({}, VERSION ", func.content().sql())?;
+                match func.version() {
+                    version if matches!(version.kind(), ExprKind::NullConst) => f.write_str("NO VALUE")?,
+                    version => version.deparse(f)?,
+                }
+                match func.standalone() {
+                    Some(XmlStandalone::Yes) => f.write_str(", STANDALONE YES")?,
+                    Some(XmlStandalone::No) => f.write_str(", STANDALONE NO")?,
+                    Some(XmlStandalone::NoValue) => f.write_str(", STANDALONE NO VALUE")?,
+                    None => {},
+                }
+                f.write_char(')')
+            },
+            Self::XmlSerialize(func) => {
+                write!(f,
+                    "XMLSERIALIZE({} {} AS {}",
+                    XmlNodeKindKw(func.kind()),
+                    func.content().sql(),
+                    func.type_name().sql()
+                )?;
+                if func.indent() {
+                    f.write_str(" INDENT")?;
+                }
+                f.write_char(')')
+            },
+        }
+    }
+}
+
+impl Deparse for JsonArrayAgg {
+    /// Alias: `json_arrayagg`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "JSON_ARRAYAGG({}", self.arg().sql())?;
+        if let Some(sort) = self.sort() {
+            write_sort_clause(f, sort)?;
+        }
+        if !self.absent_on_null() {
+            f.write_str(" NULL ON NULL")?;
+        }
+        if let Some(output) = self.output() {
+            write!(f, " {}", output.sql())?;
+        }
+        f.write_char(')')
+    }
+}
+
+impl Deparse for JsonObjectAgg {
+    /// Alias: `json_objectagg`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "JSON_OBJECTAGG({}", self.arg().sql())?;
+        if self.absent_on_null() {
+            f.write_str(" ABSENT ON NULL")?;
+        }
+        if self.unique() {
+            f.write_str(" WITH UNIQUE KEYS")?;
+        }
+        if let Some(output) = self.output() {
+            write!(f, " {}", output.sql())?;
+        }
+        f.write_char(')')
+    }
+}
+
+impl Deparse for JsonArrayAggExpr {
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.agg().deparse(f)?;
+        write_agg_tail(f, self.filter(), self.over_clause())
+    }
+}
+
+impl Deparse for JsonObjectAggExpr {
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.agg().deparse(f)?;
+        write_agg_tail(f, self.filter(), self.over_clause())
+    }
+}
+
+impl Deparse for JsonValueExpr {
+    /// Alias: `json_value_expr`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.expr().deparse(f)?;
+        write_json_format(f, self.format())
+    }
+}
+
+impl Deparse for JsonKeyValue {
+    /// Alias: `json_name_and_value`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} VALUE {}", self.key().sql(), self.value().sql())
+    }
+}
+
+impl Deparse for JsonOutput {
+    /// Alias: `json_returning_clause`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "RETURNING {}", self.type_name().sql())?;
+        write_json_format(f, self.format())
+    }
+}
+
+impl Deparse for JsonBehavior {
+    /// Alias: `json_behavior`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Null => f.write_str("NULL"),
+            Self::Error => f.write_str("ERROR"),
+            Self::True => f.write_str("TRUE"),
+            Self::False => f.write_str("FALSE"),
+            Self::Unknown => f.write_str("UNKNOWN"),
+            Self::EmptyArray => f.write_str("EMPTY ARRAY"),
+            Self::EmptyObject => f.write_str("EMPTY OBJECT"),
+            Self::Default(expr) => write!(f, "DEFAULT {}", expr.sql()),
+        }
+    }
+}
+
+impl Deparse for JsonBehaviorClause {
+    /// Alias: `json_behavior_clause_opt`
+    ///
+    /// Each behavior has a leading space.
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(on_empty) = self.on_empty() {
+            write!(f, " {} ON EMPTY", on_empty.sql())?;
+        }
+        if let Some(on_error) = self.on_error() {
+            write!(f, " {} ON ERROR", on_error.sql())?;
+        }
+        Ok(())
+    }
+}
+
+/// `json_format_clause_opt`, with a leading space.
+pub(crate) fn write_json_format(f: &mut Formatter<'_>, format: JsonFormat) -> fmt::Result {
+
+    if format.kind().is_none() {
+        return Ok(())
+    }
+
+    f.write_str(" FORMAT JSON")?;
+    match format.encoding() {
+        Some(JsonEncoding::UTF8) => f.write_str(" ENCODING utf8"),
+        Some(JsonEncoding::UTF16) => f.write_str(" ENCODING utf16"),
+        Some(JsonEncoding::UTF32) => f.write_str(" ENCODING utf32"),
+        None => Ok(()),
+    }
+}
+
+/// `json_passing_clause_opt`, with a leading space.
+pub(crate) fn write_json_passing(f: &mut Formatter<'_>, passing: Option<&[JsonArgument]>) -> fmt::Result {
+
+    let Some(passing) = passing else { return Ok(()) };
+
+    f.write_str(" PASSING ")?;
+    write_list(f, passing, |f, (name, value)|
+        write!(f, "{} AS {}", value.sql(), Label(name))
+    )
+}
+
+fn write_agg_tail(f: &mut Formatter<'_>, filter: Option<&ExprNode>, over: Option<&OverClause>) -> fmt::Result {
+
+    if let Some(filter) = filter {
+        write_filter_clause(f, filter)?;
+    }
+
+    if let Some(over) = over {
+        write_over_clause(f, over)?;
+    }
+
+    Ok(())
+}
+
+fn write_time_func(f: &mut Formatter<'_>, name: &str, precision: Option<i32>) -> fmt::Result {
+    f.write_str(name)?;
+    match precision {
+        Some(precision) => write!(f, "({precision})"),
+        None => Ok(()),
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct XmlNodeKindKw(XmlNodeKind);
+
+impl Display for XmlNodeKindKw {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            XmlNodeKind::Document => f.write_str("DOCUMENT"),
+            XmlNodeKind::Content => f.write_str("CONTENT"),
+        }
+    }
+}
+
+use crate::write_filter_clause;
+use crate::write_list;
+use crate::write_over_clause;
+use crate::write_sort_clause;
+use crate::BExpr;
+use crate::CExpr;
+use crate::Deparse;
+use crate::Label;
+use crate::LabeledExpr;
+use crate::List;
+use crate::StrLit;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Write;
+use pg_ast::ExprKind;
+use pg_ast::ExprNode;
+use pg_ast::ExtractArg;
+use pg_ast::JsonArgument;
+use pg_ast::JsonArrayAgg;
+use pg_ast::JsonArrayAggExpr;
+use pg_ast::JsonBehavior;
+use pg_ast::JsonBehaviorClause;
+use pg_ast::JsonEncoding;
+use pg_ast::JsonFormat;
+use pg_ast::JsonKeyValue;
+use pg_ast::JsonObjectAgg;
+use pg_ast::JsonObjectAggExpr;
+use pg_ast::JsonObjectExpr;
+use pg_ast::JsonOutput;
+use pg_ast::JsonQuotes;
+use pg_ast::JsonValueExpr;
+use pg_ast::JsonWrapperBehavior;
+use pg_ast::OverClause;
+use pg_ast::OverlayFunc;
+use pg_ast::SqlFunction;
+use pg_ast::SubstringFunc;
+use pg_ast::TrimSide;
+use pg_ast::UnicodeNormalForm;
+use pg_ast::XmlNodeKind;
+use pg_ast::XmlStandalone;
+use pg_ast::XmlWhitespaceOption;
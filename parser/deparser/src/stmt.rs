@@ -0,0 +1,268 @@
+impl Deparse for RawStmt {
+    /// Alias: `stmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AlterCompositeTypeStmt(stmt) => stmt.deparse(f),
+            Self::AlterDatabaseRefreshCollStmt(name) => {
+                write!(f, "ALTER DATABASE {} REFRESH COLLATION VERSION", Ident(name))
+            },
+            Self::AlterDatabaseSetStmt(stmt) => stmt.deparse(f),
+            Self::AlterDatabaseStmt(stmt) => stmt.deparse(f),
+            Self::AlterDefaultPrivilegesStmt(stmt) => stmt.deparse(f),
+            Self::AlterDomainStmt(stmt) => stmt.deparse(f),
+            Self::AlterEnumStmt(stmt) => stmt.deparse(f),
+            Self::AlterEventTrigStmt(stmt) => stmt.deparse(f),
+            Self::AlterExtensionContentsStmt(stmt) => stmt.deparse(f),
+            Self::AlterExtensionStmt(stmt) => stmt.deparse(f),
+            Self::AlterFunctionStmt(stmt) => stmt.deparse(f),
+            Self::AlterObjectDependsStmt(stmt) => stmt.deparse(f),
+            Self::AlterObjectSchemaStmt(stmt) => stmt.deparse(f),
+            Self::AlterOpFamilyStmt(stmt) => stmt.deparse(f),
+            Self::AlterOperatorStmt(stmt) => stmt.deparse(f),
+            Self::AlterOwnerStmt(stmt) => stmt.deparse(f),
+            Self::AlterPropGraphStmt(stmt) => stmt.deparse(f),
+            Self::AlterRoleSetStmt(stmt) => stmt.deparse(f),
+            Self::AlterRoleStmt(stmt) => stmt.deparse(f),
+            Self::AlterSeqStmt(stmt) => stmt.deparse(f),
+            Self::AlterSystemStmt(stmt) => stmt.deparse(f),
+            Self::AlterTextSearchConfigurationStmt(stmt) => stmt.deparse(f),
+            Self::AlterTextSearchDictionaryStmt(stmt) => stmt.deparse(f),
+            Self::AlterUserMappingStmt(stmt) => stmt.deparse(f),
+            Self::CheckPointStmt(options) => {
+                f.write_str("CHECKPOINT")?;
+                if let Some(options) = options {
+                    write!(f, " ({})", List(options))?;
+                }
+                Ok(())
+            },
+            Self::ClosePortalStmt(OneOrAll::All) => f.write_str("CLOSE ALL"),
+            Self::ClosePortalStmt(OneOrAll::One(name)) => write!(f, "CLOSE {}", Ident(name)),
+            Self::CommentStmt(stmt) => stmt.deparse(f),
+            Self::CompositeTypeStmt(stmt) => stmt.deparse(f),
+            Self::ConstraintsSetStmt(stmt) => stmt.deparse(f),
+            Self::CreateAccessMethodStmt(stmt) => stmt.deparse(f),
+            Self::CreateCastStmt(stmt) => stmt.deparse(f),
+            Self::CreateConversionStmt(stmt) => stmt.deparse(f),
+            Self::CreateDatabaseStmt(stmt) => stmt.deparse(f),
+            Self::CreateDomainStmt(stmt) => stmt.deparse(f),
+            Self::CreateEnumStmt(stmt) => stmt.deparse(f),
+            Self::CreateExtensionStmt(stmt) => stmt.deparse(f),
+            Self::CreateOpClassStmt(stmt) => stmt.deparse(f),
+            Self::CreateOpFamilyStmt(stmt) => stmt.deparse(f),
+            Self::CreatePLangStmt(stmt) => stmt.deparse(f),
+            Self::CreatePropGraphStmt(stmt) => stmt.deparse(f),
+            Self::CreateRangeStmt(stmt) => stmt.deparse(f),
+            Self::CreateRoleStmt(stmt) => stmt.deparse(f),
+            Self::CreateSchemaStmt(stmt) => stmt.deparse(f),
+            Self::CreateSeqStmt(stmt) => stmt.deparse(f),
+            Self::CreateStatsStmt(stmt) => stmt.deparse(f),
+            Self::CreateTableSpaceStmt(stmt) => stmt.deparse(f),
+            Self::CreateTransformStmt(stmt) => stmt.deparse(f),
+            Self::CreateUserMappingStmt(stmt) => stmt.deparse(f),
+            Self::DeallocateStmt(OneOrAll::All) => f.write_str("DEALLOCATE ALL"),
+            Self::DeallocateStmt(OneOrAll::One(name)) => write!(f, "DEALLOCATE {}", Ident(name)),
+            Self::DefineStmt(stmt) => stmt.deparse(f),
+            Self::DiscardStmt(stmt) => stmt.deparse(f),
+            Self::DropStmt(stmt) => stmt.deparse(f),
+            Self::ListenStmt(channel) => write!(f, "LISTEN {}", Ident(channel)),
+            Self::LoadStmt(lib_name) => write!(f, "LOAD {}", StrLit(lib_name)),
+            Self::NotifyStmt(stmt) => stmt.deparse(f),
+            Self::PrepareStmt(stmt) => stmt.deparse(f),
+            Self::PrepareTransactionStmt(tx_id) => write!(f, "PREPARE TRANSACTION {}", StrLit(tx_id)),
+            Self::ReassignOwnedStmt(stmt) => stmt.deparse(f),
+            Self::RefreshCollationVersionStmt(name) => {
+                write!(f, "ALTER COLLATION {} REFRESH VERSION", QualName(name))
+            },
+            Self::RenameStmt(stmt) => stmt.deparse(f),
+            Self::RuleStmt(stmt) => stmt.deparse(f),
+            Self::SecurityLabelStmt(stmt) => stmt.deparse(f),
+            Self::SelectStmt(stmt) => stmt.deparse(f),
+            Self::TransactionStmt(stmt) => stmt.deparse(f),
+            Self::UnlistenStmt(OneOrAll::All) => f.write_str("UNLISTEN *"),
+            Self::UnlistenStmt(OneOrAll::One(channel)) => write!(f, "UNLISTEN {}", Ident(channel)),
+            Self::VariableResetStmt(target) => write!(f, "RESET {}", target.sql()),
+            Self::VariableSetStmt(stmt) => stmt.deparse(f),
+            Self::VariableShowStmt(target) => write!(f, "SHOW {}", target.sql()),
+        }
+    }
+}
+
+impl Deparse for UtilityOption {
+    /// Alias: `utility_option_elem`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        match self.name() {
+            UtilityOptionName::Analyze => f.write_str("ANALYZE")?,
+            UtilityOptionName::Format => f.write_str("FORMAT")?,
+            UtilityOptionName::Generic(name) => Ident(name).fmt(f)?,
+        }
+
+        if let Some(value) = self.value() {
+            write!(f, " {}", value.sql())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deparse for NotifyStmt {
+    /// Alias: `NotifyStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "NOTIFY {}", Ident(self.condition_name()))?;
+        if let Some(payload) = self.payload() {
+            write!(f, ", {}", StrLit(payload))?;
+        }
+        Ok(())
+    }
+}
+
+impl Deparse for PrepareStmt {
+    /// Alias: `PrepareStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "PREPARE {}", Ident(self.name()))?;
+        if !self.arg_types().is_empty() {
+            write!(f, "({})", List(self.arg_types()))?;
+        }
+        write!(f, " AS {}", self.query().sql())
+    }
+}
+
+impl Deparse for DiscardStmt {
+    /// Alias: `DiscardStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::All => "DISCARD ALL",
+            Self::Plans => "DISCARD PLANS",
+            Self::Sequences => "DISCARD SEQUENCES",
+            Self::Temporary => "DISCARD TEMPORARY",
+        })
+    }
+}
+
+impl Deparse for TransactionStmt {
+    /// Aliases:
+    /// * `TransactionStmt`
+    /// * `TransactionStmtLegacy`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Begin(modes) => {
+                f.write_str("BEGIN")?;
+                write_transaction_modes(f, modes)
+            },
+            Self::Start(modes) => {
+                f.write_str("START TRANSACTION")?;
+                write_transaction_modes(f, modes)
+            },
+            Self::Commit { chain } => {
+                f.write_str("COMMIT")?;
+                write_chain(f, *chain)
+            },
+            Self::CommitPrepared(tx_id) => write!(f, "COMMIT PREPARED {}", StrLit(tx_id)),
+            Self::Savepoint(name) => write!(f, "SAVEPOINT {}", Ident(name)),
+            Self::Release(name) => write!(f, "RELEASE SAVEPOINT {}", Ident(name)),
+            Self::Prepare(tx_id) => write!(f, "PREPARE TRANSACTION {}", StrLit(tx_id)),
+            Self::Rollback { chain } => {
+                f.write_str("ROLLBACK")?;
+                write_chain(f, *chain)
+            },
+            Self::RollbackTo(name) => write!(f, "ROLLBACK TO SAVEPOINT {}", Ident(name)),
+            Self::RollbackPrepared(tx_id) => write!(f, "ROLLBACK PREPARED {}", StrLit(tx_id)),
+        }
+    }
+}
+
+impl Deparse for TransactionMode {
+    /// Alias: `transaction_mode_item`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::IsolationLevel(IsolationLevel::ReadUncommitted) => "ISOLATION LEVEL READ UNCOMMITTED",
+            Self::IsolationLevel(IsolationLevel::ReadCommitted) => "ISOLATION LEVEL READ COMMITTED",
+            Self::IsolationLevel(IsolationLevel::RepeatableRead) => "ISOLATION LEVEL REPEATABLE READ",
+            Self::IsolationLevel(IsolationLevel::Serializable) => "ISOLATION LEVEL SERIALIZABLE",
+            Self::ReadOnly => "READ ONLY",
+            Self::ReadWrite => "READ WRITE",
+            Self::Deferrable => "DEFERRABLE",
+            Self::NotDeferrable => "NOT DEFERRABLE",
+        })
+    }
+}
+
+impl Deparse for ConstraintsSetStmt {
+    /// Alias: `ConstraintsSetStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        f.write_str("SET CONSTRAINTS ")?;
+
+        match self.constraints() {
+            OneOrAll::All => f.write_str("ALL")?,
+            OneOrAll::One(constraints) => List(constraints).fmt(f)?,
+        }
+
+        match self.mode() {
+            ConstraintsSetMode::Immediate => f.write_str(" IMMEDIATE"),
+            ConstraintsSetMode::Deferred => f.write_str(" DEFERRED"),
+        }
+    }
+}
+
+impl Deparse for ReassignOwnedStmt {
+    /// Alias: `ReassignOwnedStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f,
+            "REASSIGN OWNED BY {} TO {}",
+            List(self.roles()),
+            self.new_role().sql()
+        )
+    }
+}
+
+impl Deparse for VarValue {
+    /// Alias: `var_value`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Boolean(true) => f.write_str("TRUE"),
+            Self::Boolean(false) => f.write_str("FALSE"),
+            Self::Number(number) => number.deparse(f),
+            Self::String(value) => StrLit(value).fmt(f),
+        }
+    }
+}
+
+/// `transaction_mode_list`, with a leading space.
+pub(crate) fn write_transaction_modes(f: &mut Formatter<'_>, modes: &[TransactionMode]) -> fmt::Result {
+    if modes.is_empty() {
+        return Ok(())
+    }
+    write!(f, " {}", List(modes))
+}
+
+/// `opt_transaction_chain`, with a leading space.
+fn write_chain(f: &mut Formatter<'_>, chain: bool) -> fmt::Result {
+    if chain {
+        f.write_str(" AND CHAIN")?;
+    }
+    Ok(())
+}
+
+use crate::Deparse;
+use crate::Ident;
+use crate::List;
+use crate::QualName;
+use crate::StrLit;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use pg_ast::ConstraintsSetMode;
+use pg_ast::ConstraintsSetStmt;
+use pg_ast::DiscardStmt;
+use pg_ast::IsolationLevel;
+use pg_ast::NotifyStmt;
+use pg_ast::OneOrAll;
+use pg_ast::PrepareStmt;
+use pg_ast::RawStmt;
+use pg_ast::ReassignOwnedStmt;
+use pg_ast::TransactionMode;
+use pg_ast::TransactionStmt;
+use pg_ast::UtilityOption;
+use pg_ast::UtilityOptionName;
+use pg_ast::VarValue;
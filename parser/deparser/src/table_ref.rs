@@ -0,0 +1,473 @@
+impl Deparse for TableRef {
+    /// Alias: `table_ref`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Relation(table_ref) => {
+                table_ref.relation().deparse(f)?;
+                write_alias(f, table_ref.alias())
+            },
+            Self::Join(join) => join.deparse(f),
+            Self::XmlTable(table) => table.deparse(f),
+            Self::JsonTable(table) => table.deparse(f),
+            Self::Rows(rows) => {
+                write_lateral(f, rows.lateral())?;
+                write!(f, "ROWS FROM ({})", List(rows.functions()))?;
+                write_func_table_tail(f, rows.ordinality(), rows.alias())
+            },
+            Self::Function(func) => {
+                write_lateral(f, func.lateral())?;
+                func.function().deparse(f)?;
+                write_func_table_tail(f, func.ordinality(), func.alias())
+            },
+            Self::TableSample(sample) => {
+                sample.relation().deparse(f)?;
+                write_alias(f, sample.alias())?;
+                if let Some(table_sample) = sample.table_sample() {
+                    write!(f,
+                        " TABLESAMPLE {}({})",
+                        QualName(table_sample.function_name()),
+                        List(table_sample.args())
+                    )?;
+                    if let Some(repeatable) = table_sample.repeatable() {
+                        write!(f, " REPEATABLE ({})", repeatable.sql())?;
+                    }
+                }
+                Ok(())
+            },
+            Self::Subselect(subselect) => {
+                write_lateral(f, subselect.lateral())?;
+                write!(f, "({})", subselect.select().sql())?;
+                write_alias(f, subselect.alias())
+            },
+            Self::GraphTable(table) => table.deparse(f),
+        }
+    }
+}
+
+impl Deparse for JoinTableRef {
+    /// Alias: `joined_table`
+    ///
+    /// Joins are left-associative, so only the right side is parenthesized when it's another join.
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        if self.alias().is_some() {
+            f.write_char('(')?;
+        }
+
+        self.left().deparse(f)?;
+
+        let join_type = match self.join_type() {
+            JoinType::Inner => "",
+            JoinType::Left => "LEFT ",
+            JoinType::Right => "RIGHT ",
+            JoinType::Full => "FULL ",
+        };
+
+        match self.condition() {
+            JoinCondition::Cross => f.write_str(" CROSS JOIN ")?,
+            JoinCondition::Natural => write!(f, " NATURAL {join_type}JOIN ")?,
+            JoinCondition::Using { .. } | JoinCondition::On(_) => write!(f, " {join_type}JOIN ")?,
+        }
+
+        match self.right() {
+            right @ TableRef::Join(join) if join.alias().is_none() => write!(f, "({})", right.sql())?,
+            right => right.deparse(f)?,
+        }
+
+        match self.condition() {
+            JoinCondition::Cross | JoinCondition::Natural => {},
+            JoinCondition::Using { columns, alias } => {
+                write!(f, " USING ({})", Idents(columns))?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {}", Ident(alias))?;
+                }
+            },
+            JoinCondition::On(condition) => write!(f, " ON {}", condition.sql())?,
+        }
+
+        if self.alias().is_some() {
+            f.write_char(')')?;
+        }
+
+        write_alias(f, self.alias())
+    }
+}
+
+impl Deparse for RelationExpr {
+    /// Alias: `relation_expr`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if !self.inherited() {
+            f.write_str("ONLY ")?;
+        }
+        self.name().deparse(f)
+    }
+}
+
+impl Deparse for RelationName {
+    /// Alias: `qualified_name`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        if let Some(schema) = self.schema() {
+            match schema.catalog() {
+                Some(catalog) => write!(f, "{}.{}.", Ident(catalog), Label(schema.name()))?,
+                None => write!(f, "{}.", Ident(schema.name()))?,
+            }
+            return Label(self.name()).fmt(f)
+        }
+
+        Ident(self.name()).fmt(f)
+    }
+}
+
+impl Deparse for Alias {
+    /// Alias: `alias_clause`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "AS {}", Ident(self.alias()))?;
+        if let Some(columns) = self.columns() {
+            write!(f, "({})", Idents(columns))?;
+        }
+        Ok(())
+    }
+}
+
+impl Deparse for FuncExprWindowless {
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SqlFunction(func) => func.deparse(f),
+            Self::FuncCall(func) => func.deparse(f),
+        }
+    }
+}
+
+impl Deparse for RangeFunction {
+    /// Alias: `rowsfrom_item`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.func_call().deparse(f)?;
+        if let Some(columns) = self.column_definitions() {
+            write!(f, " AS ({})", List(columns))?;
+        }
+        Ok(())
+    }
+}
+
+impl Deparse for SimpleColumnDefinition {
+    /// Alias: `TableFuncElement`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", Ident(self.name()), self.type_name().sql())?;
+        if let Some(collation) = self.collation() {
+            write!(f, " COLLATE {}", QualName(collation))?;
+        }
+        Ok(())
+    }
+}
+
+impl Deparse for FuncAliasColumn {
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Ident(self.name()).fmt(f)?;
+        if let Some(type_name) = self.type_name() {
+            write!(f, " {}", type_name.sql())?;
+        }
+        if let Some(collation) = self.collation() {
+            write!(f, " COLLATE {}", QualName(collation))?;
+        }
+        Ok(())
+    }
+}
+
+impl Deparse for XmlTable {
+    /// Alias: `xmltable`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        write_lateral(f, self.lateral())?;
+        f.write_str("XMLTABLE(")?;
+
+        if let Some(namespaces) = self.namespaces() {
+            f.write_str("XMLNAMESPACES(")?;
+            write_list(f, namespaces, |f, namespace| match namespace.name() {
+                Some(name) => write!(f, "{} AS {}", BExpr(namespace.value()), Label(name)),
+                None => write!(f, "DEFAULT {}", BExpr(namespace.value())),
+            })?;
+            f.write_str("), ")?;
+        }
+
+        write!(f,
+            "{} PASSING {} COLUMNS ",
+            CExpr(self.row_spec()),
+            CExpr(self.doc())
+        )?;
+
+        write_list(f, self.columns(), |f, column| {
+            Ident(column.name()).fmt(f)?;
+            let XmlTableColumnKind::ColumnDefinition(definition) = column.kind() else {
+                return f.write_str(" FOR ORDINALITY")
+            };
+            write!(f, " {}", definition.type_name().sql())?;
+            if let Some(default_value) = definition.default_value() {
+                write!(f, " DEFAULT {}", BExpr(default_value))?;
+            }
+            if let Some(path_spec) = definition.path_spec() {
+                write!(f, " PATH {}", BExpr(path_spec))?;
+            }
+            if definition.is_not_null() {
+                f.write_str(" NOT NULL")?;
+            }
+            Ok(())
+        })?;
+
+        f.write_char(')')?;
+        write_alias(f, self.alias())
+    }
+}
+
+impl Deparse for JsonTable {
+    /// Alias: `json_table`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        write_lateral(f, self.lateral())?;
+        write!(f, "JSON_TABLE({}, {}", self.context().sql(), self.path_spec().sql())?;
+        write_json_passing(f, self.passing())?;
+        write!(f, " COLUMNS ({})", List(self.columns()))?;
+
+        if let Some(on_error) = self.on_error() {
+            write!(f, " {} ON ERROR", on_error.sql())?;
+        }
+
+        f.write_char(')')?;
+        write_alias(f, self.alias())
+    }
+}
+
+impl Deparse for JsonTablePathSpec {
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        StrLit(self.path_spec()).fmt(f)?;
+        if let Some(name) = self.name() {
+            write!(f, " AS {}", Ident(name))?;
+        }
+        Ok(())
+    }
+}
+
+impl Deparse for JsonTableColumnDefinition {
+    /// Alias: `json_table_column_definition`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ForOrdinality { column_name } => write!(f, "{} FOR ORDINALITY", Ident(column_name)),
+            Self::Regular(column) => {
+                write!(f, "{} {}", Ident(column.column_name()), column.type_name().sql())?;
+                if let Some(format) = column.format() {
+                    write_json_format(f, *format)?;
+                }
+                if let Some(path_spec) = column.path_spec() {
+                    write!(f, " PATH {}", StrLit(path_spec.path_spec()))?;
+                }
+                match column.wrapper() {
+                    JsonWrapperBehavior::Without => f.write_str(" WITHOUT WRAPPER")?,
+                    JsonWrapperBehavior::Conditional => f.write_str(" WITH CONDITIONAL WRAPPER")?,
+                    JsonWrapperBehavior::Unconditional => f.write_str(" WITH UNCONDITIONAL WRAPPER")?,
+                }
+                match column.quotes() {
+                    Some(JsonQuotes::Keep) => f.write_str(" KEEP QUOTES")?,
+                    Some(JsonQuotes::Omit) => f.write_str(" OMIT QUOTES")?,
+                    None => {},
+                }
+                if let Some(behavior) = column.behavior() {
+                    behavior.deparse(f)?;
+                }
+                Ok(())
+            },
+            Self::Exists(column) => {
+                write!(f, "{} {} EXISTS", Ident(column.column_name()), column.type_name().sql())?;
+                if let Some(path_spec) = column.path_spec() {
+                    write!(f, " PATH {}", StrLit(path_spec.path_spec()))?;
+                }
+                if let Some(on_error) = column.on_error() {
+                    write!(f, " {} ON ERROR", on_error.sql())?;
+                }
+                Ok(())
+            },
+            Self::Nested(column) => {
+                write!(f,
+                    "NESTED PATH {} COLUMNS ({})",
+                    column.path_spec().sql(),
+                    List(column.columns())
+                )
+            },
+        }
+    }
+}
+
+impl Deparse for GraphTable {
+    /// Alias: `RangeGraphTable`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        write!(f, "GRAPH_TABLE({} MATCH ", self.graph().sql())?;
+
+        let pattern = self.pattern();
+        write_list(f, pattern.paths(), |f, path| write_path(f, path))?;
+        if let Some(where_clause) = pattern.where_clause() {
+            write!(f, " WHERE {}", where_clause.sql())?;
+        }
+
+        f.write_str(" COLUMNS (")?;
+        write_list(f, self.columns(), |f, column| LabeledExpr(column).fmt(f))?;
+        f.write_str("))")?;
+
+        write_alias(f, self.alias())
+    }
+}
+
+/// Elements are separated by spaces, so that consecutive `-` edges don't become a comment.
+fn write_path(f: &mut Formatter<'_>, path: &[PathElement]) -> fmt::Result {
+
+    for (i, element) in path.iter().enumerate() {
+        if i > 0 {
+            f.write_char(' ')?;
+        }
+
+        match element {
+            PathElement::Vertex(pattern) => write!(f, "({})", ElementPatternContent(pattern))?,
+            PathElement::Edge { direction, pattern } => {
+                let (start, end) = match direction {
+                    EdgeDirection::Right => ("-", "->"),
+                    EdgeDirection::Left => ("<-", "-"),
+                    EdgeDirection::Any => ("-", "-"),
+                };
+                if *pattern == ElementPattern::default() {
+                    match direction {
+                        EdgeDirection::Right => f.write_str("->")?,
+                        EdgeDirection::Left => f.write_str("<-")?,
+                        EdgeDirection::Any => f.write_str("-")?,
+                    }
+                }
+                else {
+                    write!(f, "{start}[{}]{end}", ElementPatternContent(pattern))?
+                }
+            },
+            PathElement::Subpath { path, where_clause } => {
+                f.write_char('(')?;
+                write_path(f, path)?;
+                if let Some(where_clause) = where_clause {
+                    write!(f, " WHERE {}", where_clause.sql())?;
+                }
+                f.write_char(')')?;
+            },
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Copy, Clone)]
+struct ElementPatternContent<'a>(&'a ElementPattern);
+
+impl Display for ElementPatternContent<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        let mut sep = "";
+
+        if let Some(variable) = self.0.variable() {
+            Ident(variable).fmt(f)?;
+            sep = " ";
+        }
+
+        if let Some(label) = self.0.label() {
+            write!(f, "{sep}IS ")?;
+            write_label_expression(f, label)?;
+            sep = " ";
+        }
+
+        if let Some(where_clause) = self.0.where_clause() {
+            write!(f, "{sep}WHERE {}", where_clause.sql())?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_label_expression(f: &mut Formatter<'_>, label: &LabelExpression) -> fmt::Result {
+    match label {
+        LabelExpression::Label(label) => Ident(label).fmt(f),
+        LabelExpression::Disjunction(labels) => {
+            for (i, label) in labels.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(" | ")?;
+                }
+                write_label_expression(f, label)?;
+            }
+            Ok(())
+        },
+    }
+}
+
+/// `opt_alias_clause`, with a leading space.
+fn write_alias(f: &mut Formatter<'_>, alias: Option<&Alias>) -> fmt::Result {
+    match alias {
+        Some(alias) => write!(f, " {}", alias.sql()),
+        None => Ok(()),
+    }
+}
+
+fn write_lateral(f: &mut Formatter<'_>, lateral: bool) -> fmt::Result {
+    if lateral {
+        f.write_str("LATERAL ")?;
+    }
+    Ok(())
+}
+
+/// `( opt_ordinality )? ( func_alias_clause )?`
+fn write_func_table_tail(f: &mut Formatter<'_>, ordinality: bool, alias: Option<&FuncAlias>) -> fmt::Result {
+
+    if ordinality {
+        f.write_str(" WITH ORDINALITY")?;
+    }
+
+    match alias {
+        None => Ok(()),
+        Some(OneOrBoth::Left(alias)) => write!(f, " AS {}", Ident(alias)),
+        Some(OneOrBoth::Right(columns)) => write!(f, " AS ({})", List(columns)),
+        Some(OneOrBoth::Both(alias, columns)) => write!(f, " AS {}({})", Ident(alias), List(columns)),
+    }
+}
+
+use crate::write_json_format;
+use crate::write_json_passing;
+use crate::write_list;
+use crate::BExpr;
+use crate::CExpr;
+use crate::Deparse;
+use crate::Ident;
+use crate::Idents;
+use crate::Label;
+use crate::LabeledExpr;
+use crate::List;
+use crate::QualName;
+use crate::StrLit;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Write;
+use pg_ast::Alias;
+use pg_ast::EdgeDirection;
+use pg_ast::ElementPattern;
+use pg_ast::FuncAlias;
+use pg_ast::FuncAliasColumn;
+use pg_ast::FuncExprWindowless;
+use pg_ast::GraphTable;
+use pg_ast::JoinCondition;
+use pg_ast::JoinTableRef;
+use pg_ast::JoinType;
+use pg_ast::JsonQuotes;
+use pg_ast::JsonTable;
+use pg_ast::JsonTableColumnDefinition;
+use pg_ast::JsonTablePathSpec;
+use pg_ast::JsonWrapperBehavior;
+use pg_ast::LabelExpression;
+use pg_ast::OneOrBoth;
+use pg_ast::PathElement;
+use pg_ast::RangeFunction;
+use pg_ast::RelationExpr;
+use pg_ast::RelationName;
+use pg_ast::SimpleColumnDefinition;
+use pg_ast::TableRef;
+use pg_ast::XmlTable;
+use pg_ast::XmlTableColumnKind;
@@ -0,0 +1,55 @@
+impl Deparse for AlterTextSearchConfigurationStmt {
+    /// Alias: `AlterTSConfigurationStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        write!(f, "ALTER TEXT SEARCH CONFIGURATION {} ", QualName(self.configuration()))?;
+
+        match self.action() {
+            TextSearchMappingAction::Add { token_types, dictionaries } => {
+                write!(f, "ADD MAPPING FOR {} WITH ", Idents(token_types))?;
+                write_list(f, dictionaries, |f, name| QualName(name).fmt(f))
+            },
+            TextSearchMappingAction::Alter { token_types, dictionaries } => {
+                write!(f, "ALTER MAPPING FOR {} WITH ", Idents(token_types))?;
+                write_list(f, dictionaries, |f, name| QualName(name).fmt(f))
+            },
+            TextSearchMappingAction::Replace { token_types, old_dictionary, new_dictionary } => {
+                f.write_str("ALTER MAPPING ")?;
+                if let Some(token_types) = token_types {
+                    write!(f, "FOR {} ", Idents(token_types))?;
+                }
+                write!(f, "REPLACE {} WITH {}", QualName(old_dictionary), QualName(new_dictionary))
+            },
+            TextSearchMappingAction::Drop { token_types, existence } => {
+                f.write_str("DROP MAPPING ")?;
+                write_if_exists(f, *existence == Presence::Ignore)?;
+                write!(f, "FOR {}", Idents(token_types))
+            },
+        }
+    }
+}
+
+impl Deparse for AlterTextSearchDictionaryStmt {
+    /// Alias: `AlterTSDictionaryStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f,
+            "ALTER TEXT SEARCH DICTIONARY {} ({})",
+            QualName(self.dictionary()),
+            List(self.options())
+        )
+    }
+}
+
+use crate::write_if_exists;
+use crate::write_list;
+use crate::Deparse;
+use crate::Idents;
+use crate::List;
+use crate::QualName;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use pg_ast::AlterTextSearchConfigurationStmt;
+use pg_ast::AlterTextSearchDictionaryStmt;
+use pg_ast::Presence;
+use pg_ast::TextSearchMappingAction;
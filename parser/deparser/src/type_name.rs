@@ -0,0 +1,177 @@
+impl Deparse for Type {
+    /// Alias: `Typename`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        if self.mult() == SetOf::Table {
+            f.write_str("SETOF ")?;
+        }
+
+        self.name().deparse(f)?;
+
+        for bound in self.array_bounds().unwrap_or_default() {
+            match bound {
+                Some(len) => write!(f, "[{len}]")?,
+                None => f.write_str("[]")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Deparse for TypeName {
+    /// Alias: `SimpleTypename`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json => f.write_str("JSON"),
+            Self::Bool => f.write_str("BOOLEAN"),
+            Self::Int2 => f.write_str("SMALLINT"),
+            Self::Int4 => f.write_str("INT"),
+            Self::Int8 => f.write_str("BIGINT"),
+            Self::Float4 => f.write_str("REAL"),
+            Self::Float8 => f.write_str("DOUBLE PRECISION"),
+            Self::Numeric(type_modifiers) => {
+                f.write_str("NUMERIC")?;
+                write_type_modifiers(f, type_modifiers.as_deref())
+            },
+            Self::Bpchar { length } => {
+                f.write_str("CHAR")?;
+                write_precision(f, *length)
+            },
+            Self::Varchar { max_length } => {
+                f.write_str("VARCHAR")?;
+                write_precision(f, *max_length)
+            },
+            Self::Bit(type_modifiers) => {
+                f.write_str("BIT")?;
+                write_type_modifiers(f, type_modifiers.as_deref())
+            },
+            Self::Varbit(type_modifiers) => {
+                f.write_str("BIT VARYING")?;
+                write_type_modifiers(f, type_modifiers.as_deref())
+            },
+            Self::Time { precision } => {
+                f.write_str("TIME")?;
+                write_precision(f, *precision)
+            },
+            Self::TimeTz { precision } => {
+                f.write_str("TIME")?;
+                write_precision(f, *precision)?;
+                f.write_str(" WITH TIME ZONE")
+            },
+            Self::Timestamp { precision } => {
+                f.write_str("TIMESTAMP")?;
+                write_precision(f, *precision)
+            },
+            Self::TimestampTz { precision } => {
+                f.write_str("TIMESTAMP")?;
+                write_precision(f, *precision)?;
+                f.write_str(" WITH TIME ZONE")
+            },
+            Self::Interval(IntervalRange::Full { precision }) => {
+                f.write_str("INTERVAL")?;
+                write_precision(f, *precision)
+            },
+            Self::Interval(range) => write!(f, "INTERVAL {}", range.sql()),
+            // There's no SQL syntax for type OIDs. They're only built programmatically.
+            Self::Oid(oid) => write!(f, "{oid}"),
+            Self::Generic { name, type_modifiers } => {
+                QualName(name).fmt(f)?;
+                write_type_modifiers(f, type_modifiers.as_deref())
+            },
+        }
+    }
+}
+
+impl Deparse for IntervalRange {
+    /// Alias: `opt_interval`
+    ///
+    /// `Full` has no fields, so it's empty.
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        let (range, precision) = match *self {
+            Self::Full { .. } => return Ok(()),
+            Self::Year => ("YEAR", None),
+            Self::YearToMonth => ("YEAR TO MONTH", None),
+            Self::Month => ("MONTH", None),
+            Self::Day => ("DAY", None),
+            Self::DayToHour => ("DAY TO HOUR", None),
+            Self::DayToMinute => ("DAY TO MINUTE", None),
+            Self::DayToSecond { precision } => ("DAY TO SECOND", precision),
+            Self::Hour => ("HOUR", None),
+            Self::HourToMinute => ("HOUR TO MINUTE", None),
+            Self::HourToSecond { precision } => ("HOUR TO SECOND", precision),
+            Self::Minute => ("MINUTE", None),
+            Self::MinuteToSecond { precision } => ("MINUTE TO SECOND", precision),
+            Self::Second { precision } => ("SECOND", precision),
+        };
+
+        f.write_str(range)?;
+        write_precision(f, precision)
+    }
+}
+
+impl Deparse for FuncType {
+    /// Alias: `func_type`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Type(typ) => typ.deparse(f),
+            Self::Reference(reference) => {
+                if reference.mult() == SetOf::Table {
+                    f.write_str("SETOF ")?;
+                }
+                write!(f, "{}%TYPE", QualName(reference.field()))
+            },
+        }
+    }
+}
+
+impl Deparse for StringTypecastExpr {
+    /// E.g.: `INTERVAL '1' DAY`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        let value = StrLit(self.arg());
+
+        match self.type_name().name() {
+            TypeName::Interval(IntervalRange::Full { precision: Some(precision) }) => {
+                write!(f, "INTERVAL({precision}) {value}")
+            },
+            TypeName::Interval(IntervalRange::Full { precision: None }) => {
+                write!(f, "INTERVAL {value}")
+            },
+            TypeName::Interval(range) => {
+                write!(f, "INTERVAL {value} {}", range.sql())
+            },
+            type_name => write!(f, "{} {value}", type_name.sql()),
+        }
+    }
+}
+
+fn write_precision(f: &mut Formatter<'_>, precision: Option<i32>) -> fmt::Result {
+    match precision {
+        Some(precision) => write!(f, "({precision})"),
+        None => Ok(()),
+    }
+}
+
+fn write_type_modifiers(f: &mut Formatter<'_>, type_modifiers: Option<&[ExprNode]>) -> fmt::Result {
+    match type_modifiers {
+        Some(type_modifiers) => write!(f, "({})", List(type_modifiers)),
+        None => Ok(()),
+    }
+}
+
+use crate::Deparse;
+use crate::List;
+use crate::QualName;
+use crate::StrLit;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use pg_ast::ExprNode;
+use pg_ast::FuncType;
+use pg_ast::IntervalRange;
+use pg_ast::SetOf;
+use pg_ast::StringTypecastExpr;
+use pg_ast::Type;
+use pg_ast::TypeName;
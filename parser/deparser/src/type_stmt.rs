@@ -0,0 +1,176 @@
+impl Deparse for CompositeTypeStmt {
+    /// Alias: `CompositeTypeStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f,
+            "CREATE TYPE {} AS ({})",
+            QualName(self.type_name()),
+            List(self.attributes().unwrap_or_default())
+        )
+    }
+}
+
+impl Deparse for AlterCompositeTypeStmt {
+    /// Alias: `AlterCompositeTypeStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "ALTER TYPE {} {}", QualName(self.type_name()), List(self.actions()))
+    }
+}
+
+impl Deparse for AlterTypeCmd {
+    /// Alias: `alter_type_cmd`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AddAttribute { attribute, behavior } => {
+                write!(f, "ADD ATTRIBUTE {}", attribute.sql())?;
+                write_drop_behavior(f, *behavior)
+            },
+            Self::DropAttribute { name, existence, behavior } => {
+                f.write_str("DROP ATTRIBUTE ")?;
+                write_if_exists(f, *existence == Presence::Ignore)?;
+                Ident(name).fmt(f)?;
+                write_drop_behavior(f, *behavior)
+            },
+            Self::AlterAttributeType { name, type_name, collation, behavior } => {
+                write!(f, "ALTER ATTRIBUTE {} TYPE {}", Ident(name), type_name.sql())?;
+                if let Some(collation) = collation {
+                    write!(f, " COLLATE {}", QualName(collation))?;
+                }
+                write_drop_behavior(f, *behavior)
+            },
+        }
+    }
+}
+
+impl Deparse for CreateEnumStmt {
+    /// Alias: `CreateEnumStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "CREATE TYPE {} AS ENUM (", QualName(self.type_name()))?;
+        write_list(f, self.values().unwrap_or_default(), |f, value| StrLit(value).fmt(f))?;
+        f.write_char(')')
+    }
+}
+
+impl Deparse for AlterEnumStmt {
+    /// Alias: `AlterEnumStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        write!(f, "ALTER TYPE {} ", QualName(self.type_name()))?;
+
+        match self.action() {
+            AlterEnumAction::AddValue { value, position, existence } => {
+                f.write_str("ADD VALUE ")?;
+                if *existence == Presence::Ignore {
+                    f.write_str("IF NOT EXISTS ")?;
+                }
+                StrLit(value).fmt(f)?;
+                match position {
+                    None => Ok(()),
+                    Some(EnumValuePosition::Before(value)) => write!(f, " BEFORE {}", StrLit(value)),
+                    Some(EnumValuePosition::After(value)) => write!(f, " AFTER {}", StrLit(value)),
+                }
+            },
+            AlterEnumAction::RenameValue { old_value, new_value } => {
+                write!(f, "RENAME VALUE {} TO {}", StrLit(old_value), StrLit(new_value))
+            },
+        }
+    }
+}
+
+impl Deparse for CreateRangeStmt {
+    /// Alias: `CreateRangeStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "CREATE TYPE {} AS RANGE ({})", QualName(self.type_name()), List(self.params()))
+    }
+}
+
+impl Deparse for CreateDomainStmt {
+    /// Alias: `CreateDomainStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        write!(f, "CREATE DOMAIN {} AS {}", QualName(self.domain()), self.type_name().sql())?;
+
+        if let Some(collation) = self.collation() {
+            write!(f, " COLLATE {}", QualName(collation))?;
+        }
+
+        for constraint in self.constraints().unwrap_or_default() {
+            write!(f, " {}", constraint.sql())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deparse for AlterDomainStmt {
+    /// Alias: `AlterDomainStmt`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        write!(f, "ALTER DOMAIN {} ", QualName(self.domain()))?;
+
+        match self.action() {
+            AlterDomainAction::SetDefault(expr) => write!(f, "SET DEFAULT {}", expr.sql()),
+            AlterDomainAction::DropDefault => f.write_str("DROP DEFAULT"),
+            AlterDomainAction::SetNotNull => f.write_str("SET NOT NULL"),
+            AlterDomainAction::DropNotNull => f.write_str("DROP NOT NULL"),
+            AlterDomainAction::AddConstraint { constraint, not_valid } => {
+                write!(f, "ADD {}", constraint.sql())?;
+                if *not_valid {
+                    f.write_str(" NOT VALID")?;
+                }
+                Ok(())
+            },
+            AlterDomainAction::DropConstraint { name, existence, behavior } => {
+                f.write_str("DROP CONSTRAINT ")?;
+                write_if_exists(f, *existence == Presence::Ignore)?;
+                Ident(name).fmt(f)?;
+                write_drop_behavior(f, *behavior)
+            },
+            AlterDomainAction::ValidateConstraint(name) => write!(f, "VALIDATE CONSTRAINT {}", Ident(name)),
+        }
+    }
+}
+
+impl Deparse for DomainConstraint {
+    /// Alias: `DomainConstraint`
+    fn deparse(&self, f: &mut Formatter<'_>) -> fmt::Result {
+
+        if let Some(name) = self.name() {
+            write!(f, "CONSTRAINT {} ", Ident(name))?;
+        }
+
+        match self.kind() {
+            DomainConstraintKind::Check(expr) => write!(f, "CHECK ({})", expr.sql()),
+            DomainConstraintKind::Default(expr) => write!(f, "DEFAULT {}", BExpr(expr)),
+            DomainConstraintKind::NotNull => f.write_str("NOT NULL"),
+            DomainConstraintKind::Null => f.write_str("NULL"),
+        }
+    }
+}
+
+use crate::write_drop_behavior;
+use crate::write_if_exists;
+use crate::write_list;
+use crate::BExpr;
+use crate::Deparse;
+use crate::Ident;
+use crate::List;
+use crate::QualName;
+use crate::StrLit;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Write;
+use pg_ast::AlterCompositeTypeStmt;
+use pg_ast::AlterDomainAction;
+use pg_ast::AlterDomainStmt;
+use pg_ast::AlterEnumAction;
+use pg_ast::AlterEnumStmt;
+use pg_ast::AlterTypeCmd;
+use pg_ast::CompositeTypeStmt;
+use pg_ast::CreateDomainStmt;
+use pg_ast::CreateEnumStmt;
+use pg_ast::CreateRangeStmt;
+use pg_ast::DomainConstraint;
+use pg_ast::DomainConstraintKind;
+use pg_ast::EnumValuePosition;
+use pg_ast::Presence;
@@ -0,0 +1,128 @@
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FormatterConfig {
+    parser_config: ParserConfig,
+    keyword_case: KeywordCase,
+    indent_width: usize,
+    line_width: usize,
+    comma_position: CommaPosition,
+    join_alignment: JoinAlignment,
+}
+
+impl FormatterConfig {
+
+    pub const DEFAULT_INDENT_WIDTH: usize = 4;
+
+    pub const DEFAULT_LINE_WIDTH: usize = 80;
+
+    pub const fn new(parser_config: ParserConfig) -> Self {
+        Self {
+            parser_config,
+            keyword_case: KeywordCase::Upper,
+            indent_width: Self::DEFAULT_INDENT_WIDTH,
+            line_width: Self::DEFAULT_LINE_WIDTH,
+            comma_position: CommaPosition::Trailing,
+            join_alignment: JoinAlignment::Indented,
+        }
+    }
+
+    pub const fn with_keyword_case(mut self, keyword_case: KeywordCase) -> Self {
+        self.keyword_case = keyword_case;
+        self
+    }
+
+    pub const fn with_indent_width(mut self, indent_width: usize) -> Self {
+        self.indent_width = indent_width;
+        self
+    }
+
+    /// Lists and conditions that don't fit in a line are split into one item per line.
+    ///
+    /// It's a best effort: a single item can still be longer than the line width.
+    pub const fn with_line_width(mut self, line_width: usize) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    pub const fn with_comma_position(mut self, comma_position: CommaPosition) -> Self {
+        self.comma_position = comma_position;
+        self
+    }
+
+    pub const fn with_join_alignment(mut self, join_alignment: JoinAlignment) -> Self {
+        self.join_alignment = join_alignment;
+        self
+    }
+
+    pub fn parser_config(&self) -> ParserConfig {
+        self.parser_config
+    }
+
+    pub fn keyword_case(&self) -> KeywordCase {
+        self.keyword_case
+    }
+
+    pub fn indent_width(&self) -> usize {
+        self.indent_width
+    }
+
+    pub fn line_width(&self) -> usize {
+        self.line_width
+    }
+
+    pub fn comma_position(&self) -> CommaPosition {
+        self.comma_position
+    }
+
+    pub fn join_alignment(&self) -> JoinAlignment {
+        self.join_alignment
+    }
+}
+
+impl Default for FormatterConfig {
+    fn default() -> Self {
+        Self::new(ParserConfig::default())
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum KeywordCase {
+    #[default]
+    Upper,
+    Lower,
+}
+
+/// Where the commas go, when a list is split into one item per line.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum CommaPosition {
+    /// ```sql
+    /// SELECT
+    ///     a,
+    ///     b
+    /// ```
+    #[default]
+    Trailing,
+    /// ```sql
+    /// SELECT
+    ///     a
+    ///     , b
+    /// ```
+    Leading,
+}
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum JoinAlignment {
+    /// ```sql
+    /// FROM a
+    ///     JOIN b ON a.id = b.id
+    /// ```
+    #[default]
+    Indented,
+    /// ```sql
+    /// FROM a
+    /// JOIN b
+    ///     ON a.id = b.id
+    /// ```
+    Aligned,
+}
+
+use pg_parser_core::ParserConfig;
@@ -0,0 +1,292 @@
+/// Formats all the statements in `source`, each terminated by `;`, and separated by an empty line.
+///
+/// Comments are anchored to the statement they're in, or that they precede,
+/// and they're written on their own lines, before it.
+/// A comment that follows a statement, in the same line, stays after it.
+///
+/// Fails with the first statement that has a syntax error.
+pub fn format(source: &str, config: &FormatterConfig) -> LocatedResult<String> {
+
+    let stmts = Parser::new(source, config.parser_config())
+        .parse()
+        .result
+        .into_iter()
+        .map(|stmt| stmt.map(Located::into_source))
+        .collect::<LocatedResult<Vec<_>>>()?;
+
+    let scan = Scan::new(source, config);
+
+    let mut leading = vec![Vec::new(); stmts.len()];
+    let mut trailing = vec![Vec::new(); stmts.len()];
+    let mut rest = Vec::new();
+
+    for comment in &scan.comments {
+        let text = comment.slice(source);
+
+        // When the statements don't match the scan (which shouldn't happen), all comments go first.
+        if scan.spans.len() != stmts.len() {
+            match leading.first_mut() {
+                Some(comments) => comments.push(text),
+                None => rest.push(text),
+            }
+            continue
+        }
+
+        let start = comment.range().start;
+        let index = scan.spans.partition_point(|span| span.end <= start);
+
+        if let Some(prev) = index.checked_sub(1).map(|i| &scan.spans[i])
+            && prev.end_line == comment.line()
+        {
+            trailing[index - 1].push(text);
+        }
+        else if index < stmts.len() {
+            leading[index].push(text);
+        }
+        else {
+            rest.push(text);
+        }
+    }
+
+    let mut layout = Layout::new(config, scan.dollar_strings);
+    let mut output = String::new();
+
+    for (i, stmt) in stmts.iter().enumerate() {
+
+        if i > 0 {
+            output.push('\n');
+        }
+
+        for comment in &leading[i] {
+            output.push_str(comment);
+            output.push('\n');
+        }
+
+        output.push_str(&layout.statement(stmt));
+        output.push(';');
+
+        for comment in &trailing[i] {
+            output.push(' ');
+            output.push_str(comment);
+        }
+
+        output.push('\n');
+    }
+
+    if !rest.is_empty() && !output.is_empty() {
+        output.push('\n');
+    }
+    for comment in rest {
+        output.push_str(comment);
+        output.push('\n');
+    }
+
+    Ok(output)
+}
+
+/// What the parser doesn't keep from the source.
+struct Scan<'src> {
+    /// Where each statement is, in source order.
+    spans: Vec<Span>,
+    comments: Vec<Location>,
+    /// Dollar-quoted strings, as `(value, text)`.
+    dollar_strings: Vec<(&'src str, &'src str)>,
+}
+
+#[derive(Debug)]
+struct Span {
+    /// End of the statement's `;`, or of its last token.
+    end: u32,
+    end_line: u32,
+}
+
+impl<'src> Scan<'src> {
+
+    fn new(source: &'src str, config: &FormatterConfig) -> Self {
+
+        let mut lexer = Lexer::new(source)
+            .with_standard_conforming_strings(config.parser_config().standard_conforming_strings())
+            .with_comments(true);
+
+        let mut spans = Vec::new();
+        let mut dollar_strings = Vec::new();
+        let mut depth = 0usize;
+        let mut last: Option<Location> = None;
+
+        // The parser already failed on errors, so there shouldn't be any.
+        for Located(kind, loc) in lexer.by_ref().map_while(Result::ok) {
+            match kind {
+                Operator(OpenParenthesis) => depth += 1,
+                Operator(CloseParenthesis) => depth = depth.saturating_sub(1),
+                Operator(Semicolon) if depth == 0 => {
+                    // Empty statements aren't returned by the parser.
+                    if last.is_some() {
+                        spans.push(Span { end: loc.range().end, end_line: loc.line() });
+                    }
+                    last = None;
+                    continue
+                },
+                StringLiteral(StringKind::Dollar) => {
+                    let text = loc.slice(source);
+                    let delimiter = text[1..].find('$').map_or(1, |index| index + 2);
+                    dollar_strings.push((&text[delimiter..text.len() - delimiter], text));
+                },
+                _ => {},
+            }
+            last = Some(loc);
+        }
+
+        if let Some(loc) = last {
+            spans.push(Span { end: loc.range().end, end_line: loc.line() });
+        }
+
+        Self {
+            spans,
+            comments: lexer.take_comments(),
+            dollar_strings,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CommaPosition;
+    use crate::JoinAlignment;
+    use crate::KeywordCase;
+    use pg_deparser::Deparse;
+    use pg_parser_core::ParserConfig;
+    use test_case::test_case;
+
+    const QUERY: &str = "select a, b, some_really_long_column_name, another_really_long_column_name from t1 join t2 on t1.id = t2.id where a = 1 and b = 2";
+
+    #[test]
+    fn test_default() {
+        let expected = "\
+SELECT a, b, some_really_long_column_name, another_really_long_column_name
+FROM t1
+    JOIN t2 ON t1.id = t2.id
+WHERE a = 1 AND b = 2;
+";
+        assert_eq!(Ok(expected.into()), format(QUERY, &FormatterConfig::default()))
+    }
+
+    #[test]
+    fn test_style() {
+        let config = FormatterConfig::default()
+            .with_keyword_case(KeywordCase::Lower)
+            .with_indent_width(2)
+            .with_line_width(30)
+            .with_comma_position(CommaPosition::Leading)
+            .with_join_alignment(JoinAlignment::Aligned);
+
+        let expected = "\
+select
+  a
+  , b
+  , some_really_long_column_name
+  , another_really_long_column_name
+from t1
+join t2
+  on t1.id = t2.id
+where a = 1 and b = 2;
+";
+        assert_eq!(Ok(expected.into()), format(QUERY, &config))
+    }
+
+    #[test]
+    fn test_conditions() {
+        let config = FormatterConfig::default().with_line_width(20);
+
+        let expected = "\
+SELECT a
+FROM t
+WHERE a = 1
+    AND b = 2
+    OR c = 3;
+";
+        assert_eq!(Ok(expected.into()), format("select a from t where a = 1 and b = 2 or c = 3", &config))
+    }
+
+    #[test]
+    fn test_subquery() {
+        let config = FormatterConfig::default().with_line_width(20);
+
+        let expected = "\
+SELECT *
+FROM (
+    SELECT a, b
+    FROM tab
+) AS sub;
+";
+        assert_eq!(Ok(expected.into()), format("select * from (select a, b from tab) sub", &config))
+    }
+
+    #[test]
+    fn test_comments() {
+        let source = "\
+-- first
+select 1; -- trailing
+select /* inside */ 2;
+/* last */";
+
+        let expected = "\
+-- first
+SELECT 1; -- trailing
+
+/* inside */
+SELECT 2;
+
+/* last */
+";
+        assert_eq!(Ok(expected.into()), format(source, &FormatterConfig::default()))
+    }
+
+    #[test]
+    fn test_dollar_strings() {
+        let source = "select $body$ it's $body$, $$x$$, 'y'";
+        let expected = "SELECT $body$ it's $body$, $$x$$, 'y';\n";
+        assert_eq!(Ok(expected.into()), format(source, &FormatterConfig::default()))
+    }
+
+    #[test]
+    fn test_syntax_error() {
+        assert!(format("select 1; select from where", &FormatterConfig::default()).is_err())
+    }
+
+    #[test_case(QUERY)]
+    #[test_case("select json_object('a' value 1), xmlelement(name foo, 'x')")]
+    #[test_case("select a from x union all select 2 order by 1 limit 3")]
+    #[test_case("select a, count(*) from t left join u using (id) group by a having count(*) > 1")]
+    #[test_case("select * from t where a in (select b from u where c = 1 and d = 2) or e = 0")]
+    #[test_case("values (1, 'some_really_long_string'), (2, 'another_really_long_string')")]
+    fn test_same_statements(source: &str) {
+        let config = FormatterConfig::default().with_line_width(30);
+        let formatted = format(source, &config).unwrap();
+
+        assert_eq!(deparse(source), deparse(&formatted))
+    }
+
+    fn deparse(source: &str) -> Vec<String> {
+        Parser::new(source, ParserConfig::default())
+            .parse()
+            .result
+            .into_iter()
+            .map(|stmt| stmt.unwrap().source().sql().to_string())
+            .collect()
+    }
+}
+
+use crate::layout::Layout;
+use crate::FormatterConfig;
+use pg_basics::Located;
+use pg_basics::Location;
+use pg_elog::LocatedResult;
+use pg_lexer::Lexer;
+use pg_lexer::OperatorKind::CloseParenthesis;
+use pg_lexer::OperatorKind::OpenParenthesis;
+use pg_lexer::OperatorKind::Semicolon;
+use pg_lexer::RawTokenKind::Operator;
+use pg_lexer::RawTokenKind::StringLiteral;
+use pg_lexer::StringKind;
+use pg_parser::Parser;
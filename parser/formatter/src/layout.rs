@@ -0,0 +1,410 @@
+/// Lays out the deparsed SQL of statements.
+///
+/// The deparser already writes normalized spacing, so the layout only decides
+/// where a space becomes a line break, and the case of the keywords.
+pub(crate) struct Layout<'a> {
+    config: &'a FormatterConfig,
+    /// Dollar-quoted strings of the source, as `(value, text)`, that weren't used yet.
+    dollar_strings: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> Layout<'a> {
+
+    pub fn new(config: &'a FormatterConfig, dollar_strings: Vec<(&'a str, &'a str)>) -> Self {
+        Self { config, dollar_strings }
+    }
+
+    pub fn statement(&mut self, stmt: &RawStmt) -> String {
+
+        let sql = stmt.sql().to_string();
+        let nodes = self.nodes(&sql);
+
+        let mut lines = Vec::new();
+        match stmt {
+            RawStmt::SelectStmt(_) => self.query(&nodes, 0, &mut lines),
+            RawStmt::PrepareStmt(prepare) if matches!(prepare.query(), RawStmt::SelectStmt(_)) => {
+                // `PREPARE name AS` on its own line, followed by the query.
+                let split = nodes.iter()
+                    .position(|node| node.keyword() == Some(As))
+                    .map_or(nodes.len(), |index| index + 1);
+                let (header, query) = nodes.split_at(split);
+                lines.push(self.inline(header, 0, false));
+                self.query(query, 0, &mut lines);
+            },
+            _ => lines.push(self.inline(&nodes, 0, false)),
+        }
+
+        lines.join("\n")
+    }
+
+    fn nodes<'s>(&mut self, sql: &'s str) -> Vec<Node<'s>>
+    where
+        'a: 's
+    {
+        let mut stack = vec![(false, Vec::new())];
+        let mut prev_end = 0;
+
+        for Located(kind, loc) in Lexer::new(sql).filter_map(Result::ok) {
+
+            let range = loc.range();
+            let space_before = range.start > prev_end;
+            prev_end = range.end;
+
+            match kind {
+                Operator(OpenParenthesis) => stack.push((space_before, Vec::new())),
+                Operator(CloseParenthesis) if stack.len() > 1 => {
+                    let (space_before, children) = stack.pop().expect("stack has more than one frame");
+                    let group = Node::Group { space_before, children };
+                    stack.last_mut().expect("stack isn't empty").1.push(group);
+                },
+                _ => {
+                    let text = match kind {
+                        StringLiteral(StringKind::Basic { .. }) => self.dollar_string(loc.slice(sql)),
+                        _ => loc.slice(sql),
+                    };
+                    let token = Token { kind, text, space_before };
+                    stack.last_mut().expect("stack isn't empty").1.push(Node::Token(token));
+                },
+            }
+        }
+
+        // Unbalanced parenthesis aren't expected, since the SQL was deparsed.
+        while stack.len() > 1 {
+            let (space_before, children) = stack.pop().expect("stack has more than one frame");
+            stack.last_mut().expect("stack isn't empty").1.push(Node::Group { space_before, children });
+        }
+
+        stack.pop().map(|(_, nodes)| nodes).unwrap_or_default()
+    }
+
+    /// Swaps a `'...'` string for the source's dollar-quoted string with the same value, if there's one.
+    fn dollar_string<'s>(&mut self, text: &'s str) -> &'s str
+    where
+        'a: 's
+    {
+        let value = text[1..text.len() - 1].replace("''", "'");
+
+        match self.dollar_strings.iter().position(|(dollar_value, _)| *dollar_value == value) {
+            Some(index) => self.dollar_strings.remove(index).1,
+            None => text,
+        }
+    }
+
+    /// Writes each clause on its own line.
+    fn query(&self, nodes: &[Node<'_>], indent: usize, lines: &mut Vec<String>) {
+        for clause in clauses(nodes) {
+            self.clause(clause, indent, lines);
+        }
+    }
+
+    fn clause(&self, (head, body): (&[Node<'_>], &[Node<'_>]), indent: usize, lines: &mut Vec<String>) {
+
+        let width = self.config.indent_width();
+        let head_text = self.inline(head, indent, true);
+
+        if head.iter().any(|node| node.keyword() == Some(Join)) {
+            return self.join(&head_text, body, indent, lines)
+        }
+
+        let line = match (head.is_empty(), body.is_empty()) {
+            (true, _) => format!("{}{}", spaces(indent), self.inline(body, indent, false)),
+            (false, true) => format!("{}{head_text}", spaces(indent)),
+            (false, false) => format!("{}{head_text} {}", spaces(indent), self.inline(body, indent, false)),
+        };
+
+        if !line.contains('\n') && line.chars().count() <= self.config.line_width() {
+            lines.push(line);
+            return
+        }
+
+        let items = split_list(body);
+        if items.len() > 1 && !head.is_empty() {
+            lines.push(format!("{}{head_text}", spaces(indent)));
+            let last = items.len() - 1;
+            for (i, item) in items.into_iter().enumerate() {
+                let item = self.inline(item, indent + width, false);
+                let line = match self.config.comma_position() {
+                    CommaPosition::Trailing if i < last => format!("{}{item},", spaces(indent + width)),
+                    CommaPosition::Leading if i > 0 => format!("{}, {item}", spaces(indent + width)),
+                    _ => format!("{}{item}", spaces(indent + width)),
+                };
+                lines.push(line);
+            }
+            return
+        }
+
+        let conditions = split_conditions(body);
+        if conditions.len() > 1 && matches!(head.first().and_then(Node::keyword), Some(Where | Having)) {
+            let (first, rest) = conditions.split_first().expect("there's more than one condition");
+            lines.push(format!("{}{head_text} {}", spaces(indent), self.inline(first, indent, false)));
+            for condition in rest {
+                lines.push(format!("{}{}", spaces(indent + width), self.inline(condition, indent + width, false)));
+            }
+            return
+        }
+
+        lines.push(line);
+    }
+
+    fn join(&self, head: &str, body: &[Node<'_>], indent: usize, lines: &mut Vec<String>) {
+
+        let width = self.config.indent_width();
+
+        match self.config.join_alignment() {
+            JoinAlignment::Indented => {
+                let indent = indent + width;
+                lines.push(format!("{}{head} {}", spaces(indent), self.inline(body, indent, false)));
+            },
+            JoinAlignment::Aligned => {
+                let split = body.iter()
+                    .position(|node| node.keyword() == Some(On))
+                    .unwrap_or(body.len());
+                let (table, condition) = body.split_at(split);
+                lines.push(format!("{}{head} {}", spaces(indent), self.inline(table, indent, false)));
+                if !condition.is_empty() {
+                    lines.push(format!("{}{}", spaces(indent + width), self.inline(condition, indent + width, false)));
+                }
+            },
+        }
+    }
+
+    /// Writes the nodes in a single line, unless a sub-query doesn't fit in it.
+    ///
+    /// `indent` is the indentation of the line where the nodes start.
+    fn inline(&self, nodes: &[Node<'_>], indent: usize, flat: bool) -> String {
+
+        let mut text = String::new();
+
+        for (i, node) in nodes.iter().enumerate() {
+            if i > 0 && node.space_before() {
+                text.push(' ');
+            }
+
+            match node {
+                Node::Token(token) => text.push_str(&self.token(token)),
+                Node::Group { children, .. } => {
+                    let group = format!("({})", self.inline(children, indent, true));
+                    // Lines after the first one already include their indentation.
+                    let column = match text.rsplit_once('\n') {
+                        Some((_, line)) => line.chars().count(),
+                        None => indent + text.chars().count(),
+                    };
+                    let fits = column + group.chars().count() <= self.config.line_width();
+
+                    if flat || fits || !is_query(children) {
+                        text.push_str(&group);
+                        continue
+                    }
+
+                    let mut lines = Vec::new();
+                    self.query(children, indent + self.config.indent_width(), &mut lines);
+                    text.push_str("(\n");
+                    text.push_str(&lines.join("\n"));
+                    text.push('\n');
+                    text.push_str(&spaces(indent));
+                    text.push(')');
+                },
+            }
+        }
+
+        text
+    }
+
+    fn token(&self, token: &Token<'_>) -> String {
+        match (token.keyword(), self.config.keyword_case()) {
+            (Some(_), KeywordCase::Lower) => token.text.to_lowercase(),
+            _ => token.text.to_owned(),
+        }
+    }
+}
+
+/// A token of the deparsed SQL, or a parenthesized group of them.
+#[derive(Debug)]
+enum Node<'s> {
+    Token(Token<'s>),
+    Group {
+        space_before: bool,
+        children: Vec<Node<'s>>,
+    },
+}
+
+impl Node<'_> {
+
+    fn space_before(&self) -> bool {
+        match self {
+            Self::Token(token) => token.space_before,
+            Self::Group { space_before, .. } => *space_before,
+        }
+    }
+
+    fn keyword(&self) -> Option<Keyword> {
+        match self {
+            Self::Token(token) => token.keyword(),
+            Self::Group { .. } => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Token<'s> {
+    kind: RawTokenKind,
+    text: &'s str,
+    space_before: bool,
+}
+
+impl Token<'_> {
+
+    /// The deparser writes keywords in uppercase, and identifiers in lowercase,
+    /// so a lowercase keyword token is an identifier.
+    fn keyword(&self) -> Option<Keyword> {
+        match self.kind {
+            Kw(keyword) if !self.text.bytes().any(|c| c.is_ascii_lowercase()) => Some(keyword),
+            _ => None,
+        }
+    }
+}
+
+/// Splits the nodes of a query into `(head, body)` clauses.
+/// The first clause has no head, if the query doesn't start with a clause keyword.
+fn clauses<'n, 's>(nodes: &'n [Node<'s>]) -> Vec<(&'n [Node<'s>], &'n [Node<'s>])> {
+
+    let mut starts: Vec<_> = (0..nodes.len())
+        .filter(|&i| is_clause_start(nodes, i))
+        .collect();
+
+    if starts.first() != Some(&0) {
+        starts.insert(0, 0);
+    }
+
+    starts.iter()
+        .enumerate()
+        .map(|(i, &start)| {
+            let end = starts.get(i + 1).copied().unwrap_or(nodes.len());
+            let head_end = if is_clause_start(nodes, start) {
+                let continuation = nodes[start + 1..end].iter()
+                    .take_while(|node| matches!(
+                        node.keyword(),
+                        Some(All | By | Cross | Distinct | Full | Inner | Join | Left | Outer | Right)
+                    ))
+                    .count();
+                start + 1 + continuation
+            }
+            else {
+                start
+            };
+            (&nodes[start..head_end], &nodes[head_end..end])
+        })
+        .collect()
+}
+
+fn is_clause_start(nodes: &[Node<'_>], index: usize) -> bool {
+
+    let Some(keyword) = nodes[index].keyword() else { return false };
+    let prev = index.checked_sub(1).and_then(|i| nodes[i].keyword());
+    let next = nodes.get(index + 1).and_then(Node::keyword);
+
+    match keyword {
+        Except | Fetch | Having | Intersect | Limit | Offset | Select | Union | Values | Where | Window => true,
+        // `IS [NOT] DISTINCT FROM`
+        FromKw => prev != Some(Distinct),
+        Group | Order => next == Some(By),
+        With => index == 0,
+        Cross | Full | Inner | Join | Left | Natural | Right => !matches!(
+            prev,
+            Some(Cross | Full | Inner | Left | Natural | Outer | Right)
+        ),
+        _ => false,
+    }
+}
+
+fn is_query(nodes: &[Node<'_>]) -> bool {
+    match nodes.first() {
+        Some(Node::Group { children, .. }) => is_query(children),
+        Some(node) => matches!(node.keyword(), Some(Select | Table | Values | With)),
+        None => false,
+    }
+}
+
+/// Splits at the top-level commas, which are dropped.
+fn split_list<'n, 's>(nodes: &'n [Node<'s>]) -> Vec<&'n [Node<'s>]> {
+    nodes
+        .split(|node| matches!(node, Node::Token(Token { kind: Operator(Comma), .. })))
+        .collect()
+}
+
+/// Splits before the top-level `AND` and `OR`, except the `AND` of `BETWEEN`.
+fn split_conditions<'n, 's>(nodes: &'n [Node<'s>]) -> Vec<&'n [Node<'s>]> {
+
+    let mut conditions = Vec::new();
+    let mut start = 0;
+    let mut between = false;
+
+    for (i, node) in nodes.iter().enumerate() {
+        match node.keyword() {
+            Some(Between) => between = true,
+            Some(And) if between => between = false,
+            Some(And | Or) => {
+                conditions.push(&nodes[start..i]);
+                start = i;
+            },
+            _ => {},
+        }
+    }
+
+    conditions.push(&nodes[start..]);
+    conditions
+}
+
+fn spaces(count: usize) -> String {
+    " ".repeat(count)
+}
+
+use crate::CommaPosition;
+use crate::FormatterConfig;
+use crate::JoinAlignment;
+use crate::KeywordCase;
+use pg_ast::RawStmt;
+use pg_basics::Located;
+use pg_deparser::Deparse;
+use pg_lexer::Keyword;
+use pg_lexer::Keyword::All;
+use pg_lexer::Keyword::And;
+use pg_lexer::Keyword::As;
+use pg_lexer::Keyword::Between;
+use pg_lexer::Keyword::By;
+use pg_lexer::Keyword::Cross;
+use pg_lexer::Keyword::Distinct;
+use pg_lexer::Keyword::Except;
+use pg_lexer::Keyword::Fetch;
+use pg_lexer::Keyword::FromKw;
+use pg_lexer::Keyword::Full;
+use pg_lexer::Keyword::Group;
+use pg_lexer::Keyword::Having;
+use pg_lexer::Keyword::Inner;
+use pg_lexer::Keyword::Intersect;
+use pg_lexer::Keyword::Join;
+use pg_lexer::Keyword::Left;
+use pg_lexer::Keyword::Limit;
+use pg_lexer::Keyword::Natural;
+use pg_lexer::Keyword::Offset;
+use pg_lexer::Keyword::On;
+use pg_lexer::Keyword::Or;
+use pg_lexer::Keyword::Order;
+use pg_lexer::Keyword::Outer;
+use pg_lexer::Keyword::Right;
+use pg_lexer::Keyword::Select;
+use pg_lexer::Keyword::Table;
+use pg_lexer::Keyword::Union;
+use pg_lexer::Keyword::Values;
+use pg_lexer::Keyword::Where;
+use pg_lexer::Keyword::Window;
+use pg_lexer::Keyword::With;
+use pg_lexer::Lexer;
+use pg_lexer::OperatorKind::CloseParenthesis;
+use pg_lexer::OperatorKind::Comma;
+use pg_lexer::OperatorKind::OpenParenthesis;
+use pg_lexer::RawTokenKind;
+use pg_lexer::RawTokenKind::Keyword as Kw;
+use pg_lexer::RawTokenKind::Operator;
+use pg_lexer::RawTokenKind::StringLiteral;
+use pg_lexer::StringKind;
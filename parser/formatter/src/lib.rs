@@ -0,0 +1,11 @@
+//! Pretty-prints SQL source.
+//!
+//! The source is parsed, and each statement is deparsed and laid out again,
+//! so the output is normalized, but keeps the comments and dollar-quoted strings of the source.
+
+pg_basics::reexport! { pub
+    config,
+    format,
+}
+
+mod layout;
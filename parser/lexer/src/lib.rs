@@ -14,6 +14,9 @@ type Result<T = RawTokenKind> = lexer::Result<T>;
 pub struct Lexer<'src> {
     buffer: CharBuffer<'src>,
     peeked: Option<Option<LocatedResult>>,
+    standard_conforming_strings: bool,
+    /// `None` when comments aren't being collected.
+    comments: Option<Vec<Location>>,
 }
 
 impl Iterator for Lexer<'_> {
@@ -41,10 +44,39 @@ impl<'src> Lexer<'src> {
     pub fn new(source: &'src str) -> Self {
         Self {
             buffer: CharBuffer::new(source),
-            peeked: None
+            peeked: None,
+            standard_conforming_strings: true,
+            comments: None,
         }
     }
 
+    /// When `false`, backslashes are escapes in `'...'` strings too,
+    /// which are then lexed as `Extended` strings.
+    pub fn with_standard_conforming_strings(mut self, standard_conforming_strings: bool) -> Self {
+        self.standard_conforming_strings = standard_conforming_strings;
+        self
+    }
+
+    pub fn standard_conforming_strings(&self) -> bool {
+        self.standard_conforming_strings
+    }
+
+    /// When `true`, the locations of skipped comments are collected,
+    /// and can be retrieved with [`take_comments()`](Self::take_comments).
+    ///
+    /// The location of a `--` comment excludes its terminating new line.
+    pub fn with_comments(mut self, comments: bool) -> Self {
+        self.comments = comments.then(Vec::new);
+        self
+    }
+
+    /// Comments skipped so far, in source order.
+    pub fn take_comments(&mut self) -> Vec<Location> {
+        self.comments.as_mut()
+            .map(mem::take)
+            .unwrap_or_default()
+    }
+
     pub fn source(&self) -> &'src str {
         self.buffer.source()
     }
@@ -128,7 +160,12 @@ impl<'src> Lexer<'src> {
                 _ => Err(UnexpectedChar { unknown: '$' }),
             }
             '\'' => {
-                self.lex_quote_string(StringKind::Basic { concatenable: concatenable_string })
+                if self.standard_conforming_strings {
+                    self.lex_quote_string(StringKind::Basic { concatenable: concatenable_string })
+                }
+                else {
+                    self.lex_extended_string(concatenable_string)
+                }
             }
             '"' => self.lex_quote_ident(Quoted),
             'b' | 'B' => {
@@ -226,6 +263,25 @@ impl<'src> Lexer<'src> {
         // SAFETY: Length is guaranteed to be at least 1.
         let mut op = self.buffer.slice(start_index);
 
+        if !pg_op {
+            // Custom operators that only have SQL-standard chars
+            // cannot have '+' or '-' as suffixes.
+            // E.g., '=-' should be tokenized as '=' and '-' separately,
+            // and '<-' as '<' and '-'.
+            let num = op.as_bytes()
+                .iter()
+                .rev()
+                .take_while(|c| **c == b'+' || **c == b'-')
+                .count()
+                // At least 1 char always remains.
+                .min(op.len() - 1);
+            // SAFETY: only returns ASCII chars ('+' and '-')
+            self.buffer.seek(self.buffer.current_index() - num as u32);
+
+            let len = op.len() - num;
+            op = &op[..len];
+        }
+
         match op {
             "%"  => Ok(Operator(Percent)),
             "*"  => Ok(Operator(Mul)),
@@ -243,33 +299,10 @@ impl<'src> Lexer<'src> {
             "!=" => Ok(Operator(NotEquals)),
             "<>" => Ok(Operator(NotEquals)),
             "->" => Ok(Operator(RightArrow)),
-            _ => {
-                // Custom operator with PG op chars can have '+' or '-' as suffixes.
-                // E.g., '?-' is a valid operator.
-
-                if !pg_op {
-                    // Custom operators that only have SQL-standard chars
-                    // cannot have '+' or '-' as suffixes.
-                    // E.g., '=-' should be tokenized as '=' and '-' separately.
-                    let num = op.as_bytes()
-                        .iter()
-                        .rev()
-                        .take_while(|c| **c == b'+' || **c == b'-')
-                        .count();
-                    // SAFETY: only returns ASCII chars ('+' and '-')
-                    self.buffer.seek(self.buffer.current_index() - num as u32);
-
-                    let len = op.len() - num;
-                    op = &op[..len];
-                }
-
-                if op.len() >= NAMEDATALEN {
-                    Err(OperatorTooLong)
-                }
-                else {
-                    Ok(UserDefinedOperator)
-                }
-            }
+            // Custom operator with PG op chars can have '+' or '-' as suffixes.
+            // E.g., '?-' is a valid operator.
+            _ if op.len() >= NAMEDATALEN => Err(OperatorTooLong),
+            _ => Ok(UserDefinedOperator),
         }
     }
 
@@ -657,15 +690,17 @@ impl<'src> Lexer<'src> {
     #[inline] // Only called from a single place
     fn skip_comment(&mut self) -> bool {
 
+        let start_index = self.buffer.current_index();
+
         if !self.buffer.consume_string("--") {
             return false
         }
 
-        while let Some(c) = self.buffer.consume_one() {
-            if is_new_line(c) {
-                break
-            }
-        }
+        self.buffer.consume_while(|c| !is_new_line(c));
+        self.push_comment(start_index);
+
+        // The new line, if not EOF.
+        self.buffer.consume_one();
 
         true
     }
@@ -674,13 +709,26 @@ impl<'src> Lexer<'src> {
 
         let start_index = self.buffer.current_index();
 
+        let consumed = self.consume_block_comment()?;
+        if consumed {
+            // Nested comments are part of the outermost one.
+            self.push_comment(start_index);
+        }
+
+        Ok(consumed)
+    }
+
+    fn consume_block_comment(&mut self) -> lexer::LocatedResult<bool> {
+
+        let start_index = self.buffer.current_index();
+
         if !self.buffer.consume_string("/*") {
             return Ok(false)
         }
 
         loop {
             if self.buffer.remainder().starts_with("/*") {
-                self.skip_block_comment()?;
+                self.consume_block_comment()?;
                 continue
             }
 
@@ -697,6 +745,12 @@ impl<'src> Lexer<'src> {
             self.buffer.consume_one();
         }
     }
+
+    fn push_comment(&mut self, start_index: u32) {
+        if let Some(comments) = self.comments.as_mut() {
+            comments.push(self.buffer.location_starting_at(start_index));
+        }
+    }
 }
 
 #[cfg(test)]
@@ -722,6 +776,23 @@ mod tests {
         assert_eq!(None, lex.next());
     }
 
+    #[test]
+    fn test_comments() {
+        let source = "-- line\nselect /* block /* nested */ */ 1 --eof";
+        let mut lex = Lexer::new(source).with_comments(true);
+
+        assert_matches!(lex.next(), Some(Ok(Located(Kw(Select), _))));
+        assert_matches!(lex.next(), Some(Ok(Located(NumberLiteral(_), _))));
+        assert_eq!(None, lex.next());
+
+        let comments: Vec<_> = lex.take_comments()
+            .iter()
+            .map(|loc| loc.slice(source))
+            .collect();
+
+        assert_eq!(vec!["-- line", "/* block /* nested */ */", "--eof"], comments);
+    }
+
     #[test]
     fn test_unknown_char() {
         let source = "\x00";
@@ -782,6 +853,19 @@ mod tests {
         assert_eq!(None, lex.next());
     }
 
+    #[test]
+    fn test_operators_with_sign_suffix() {
+        let source = "<- <=+-";
+        let mut lex = Lexer::new(source);
+
+        assert_tok(Operator(Less), 0..1, 1, 1, lex.next());
+        assert_tok(Operator(Minus), 1..2, 1, 2, lex.next());
+        assert_tok(Operator(LessEquals), 3..5, 1, 4, lex.next());
+        assert_tok(Operator(Plus), 5..6, 1, 6, lex.next());
+        assert_tok(Operator(Minus), 6..7, 1, 7, lex.next());
+        assert_eq!(None, lex.next());
+    }
+
     #[test]
     fn test_param() {
         let source = "$0123";
@@ -923,6 +1007,17 @@ mod tests {
         assert_eq!(None, lex.next());
     }
 
+    #[test]
+    fn test_nonstandard_strings() {
+        let source = "'\\'' 'x'\n'y'";
+        let mut lex = Lexer::new(source).with_standard_conforming_strings(false);
+
+        assert_tok(StringLiteral(Extended { concatenable: false }), 0..4, 1, 1, lex.next());
+        assert_tok(StringLiteral(Extended { concatenable: false }), 5..8, 1, 6, lex.next());
+        assert_tok(StringLiteral(Extended { concatenable: true }), 9..12, 2, 1, lex.next());
+        assert_eq!(None, lex.next());
+    }
+
     #[test]
     fn test_bit_string() {
         let source = "b'0_156e_wf' x'048_96a_f_d'"; // lexer doesn't validate chars
@@ -1081,6 +1176,7 @@ use crate::RawTokenKind::UserDefinedOperator;
 use crate::StringKind::Dollar;
 use crate::StringKind::Extended;
 use core::iter::FusedIterator;
+use core::mem;
 use pg_basics::ascii::is_bin_digit;
 use pg_basics::ascii::is_decimal_digit;
 use pg_basics::ascii::is_dollar_quote_cont;
@@ -0,0 +1,42 @@
+/// A literal that was replaced by a parameter.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Constant {
+    param: i32,
+    value: Literal,
+    location: Location,
+}
+
+impl Constant {
+    pub fn new(param: i32, value: Literal, location: Location) -> Self {
+        Self { param, value, location }
+    }
+
+    /// The `n` of the `$n` that replaced the constant.
+    pub fn param(&self) -> i32 {
+        self.param
+    }
+
+    pub fn value(&self) -> &Literal {
+        &self.value
+    }
+
+    /// Where the constant is in the original source, including the sign of negative numbers.
+    pub fn location(&self) -> &Location {
+        &self.location
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Literal {
+    /// The decoded value, e.g., without escapes, and with continuations joined.
+    String(Box<str>),
+    Number(SignedNumber),
+    BitString {
+        value: Box<str>,
+        kind: BitStringKind,
+    },
+}
+
+use pg_ast::SignedNumber;
+use pg_basics::Location;
+use pg_lexer::BitStringKind;
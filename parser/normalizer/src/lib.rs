@@ -0,0 +1,10 @@
+//! Replaces the constants of SQL source with parameters (`$1`, `$2`, ...), like `pg_stat_statements` does,
+//! e.g., to keep query texts without the values in them.
+//!
+//! Constants are found with the tokens of the source, and the parsed statements tell which literals
+//! are values (e.g., not type modifiers, or DDL options), so the rest of the source is kept as it is.
+
+pg_basics::reexport! { pub
+    constant,
+    normalize,
+}
@@ -0,0 +1,301 @@
+/// The source with its constants replaced by parameters, and the constants that were replaced.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NormalizedQuery {
+    query: String,
+    constants: Vec<Constant>,
+}
+
+impl NormalizedQuery {
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// In source order, so parameters are increasing.
+    pub fn constants(&self) -> &[Constant] {
+        &self.constants
+    }
+
+    pub fn into_query(self) -> String {
+        self.query
+    }
+}
+
+/// Replaces the literals (strings, numbers and bit strings) in `source` with parameters, e.g.:
+/// `select a from t where b = 'x' and c > -1` becomes `select a from t where b = $1 and c > $2`.
+///
+/// Parameters are numbered after the highest one that's already in the source.
+///
+/// Like `pg_stat_statements`, only constants in expressions and the values of `SET` are replaced,
+/// so the literals of DDL and utility options (e.g., `ENCODING 'UTF8'`, `LANGUAGE 'sql'`) are kept.
+/// Literals in expressions that aren't values are kept too:
+/// type modifiers (e.g., `varchar(10)`), and positions in `GROUP BY` and `ORDER BY`.
+///
+/// Fails with the first statement that has a syntax error.
+pub fn normalize(source: &str, config: ParserConfig) -> LocatedResult<NormalizedQuery> {
+
+    let stmts = Parser::new(source, config)
+        .parse()
+        .result
+        .into_iter()
+        .collect::<LocatedResult<Vec<_>>>()?;
+
+    let mut values = Values::new(source);
+    for Located(stmt, location) in &stmts {
+        // All the literals of `SET` are its values, e.g.: `ALTER ROLE r SET search_path = 'a'`.
+        if let RawStmt::VariableSetStmt(_)
+            | RawStmt::AlterSystemStmt(_)
+            | RawStmt::AlterRoleSetStmt(_)
+            | RawStmt::AlterDatabaseSetStmt(_) = stmt
+        {
+            values.values.push(location.range().clone());
+        }
+        let _ = stmt.accept(&mut values);
+    }
+
+    let mut stream = TokenStream::new(source, config);
+    let mut max_param = 0;
+    let mut literals = Vec::new();
+
+    while !stream.eof() {
+
+        let location = stream.current_location();
+
+        // The source was already parsed, so there aren't any errors.
+        let Ok(token) = stream.peek() else { break };
+
+        let literal = match token {
+            TokenValue::Param { index } => {
+                max_param = max_param.max(*index);
+                None
+            },
+            TokenValue::String(value) => Some(Literal::String(value.clone())),
+            TokenValue::UnsignedNumber(value) => Some(Literal::Number(value.clone().into())),
+            TokenValue::BitString { value, kind } => {
+                Some(Literal::BitString { value: value.clone(), kind: *kind })
+            },
+            _ => None,
+        };
+
+        stream.next();
+
+        literals.extend(literal.and_then(|literal| values.constant(literal, location)));
+    }
+
+    let constants: Vec<_> = literals.into_iter()
+        .zip(max_param + 1..)
+        .map(|((value, location), param)| Constant::new(param, value, location))
+        .collect();
+
+    let mut query = String::with_capacity(source.len());
+    let mut last = 0;
+    for constant in &constants {
+        let range = constant.location().range();
+        query.push_str(&source[last..range.start as usize]);
+        query.push('$');
+        query.push_str(&constant.param().to_string());
+        last = range.end as usize;
+    }
+    query.push_str(&source[last..]);
+
+    Ok(NormalizedQuery { query, constants })
+}
+
+/// Where the literals that are values are, from the parsed statements.
+#[derive(Debug)]
+struct Values<'src> {
+    source: &'src str,
+    /// Only literals in these ranges can be values, e.g., constant expressions.
+    values: Vec<Range<u32>>,
+    /// Literals in these ranges are kept, e.g., type modifiers.
+    kept: Vec<Range<u32>>,
+    /// Only strings are values in these ranges,
+    /// e.g., in `timestamp(3) '2000-01-01'`, where the type doesn't have a location.
+    strings_only: Vec<Range<u32>>,
+    /// Negative numbers, which start at their `-`.
+    negative: Vec<Location>,
+}
+
+impl<'src> Values<'src> {
+
+    fn new(source: &'src str) -> Self {
+        Self {
+            source,
+            values: Vec::new(),
+            kept: Vec::new(),
+            strings_only: Vec::new(),
+            negative: Vec::new(),
+        }
+    }
+
+    /// Returns the constant that a literal token is,
+    /// or `None` when the literal isn't a value.
+    fn constant(&self, literal: Literal, location: Location) -> Option<(Literal, Location)> {
+
+        let range = location.range();
+        let contains = |outer: &Range<u32>| outer.start <= range.start && range.end <= outer.end;
+
+        if !self.values.iter().any(contains) || self.kept.iter().any(contains) {
+            return None
+        }
+
+        if !matches!(literal, Literal::String(_)) && self.strings_only.iter().any(contains) {
+            return None
+        }
+
+        let negative = self.negative.iter().find(|negative| negative.range().end == range.end);
+        match (literal, negative) {
+            (Literal::Number(number), Some(negative)) => Some((Literal::Number(-number), negative.clone())),
+            (literal, _) => Some((literal, location)),
+        }
+    }
+
+    /// `GROUP BY 1` and `ORDER BY 1` are positions in the target list.
+    fn positions(&mut self, exprs: &[ExprNode]) {
+        let positions = exprs.iter()
+            .filter(|expr| matches!(expr.kind(), ExprKind::IntegerConst(_)))
+            .filter_map(|expr| expr.span().location())
+            .map(|location| location.range().clone());
+        self.kept.extend(positions);
+    }
+}
+
+impl Visit for Values<'_> {
+    type Break = ();
+
+    fn visit_type(&mut self, node: &Type) -> ControlFlow<()> {
+        if let Some(location) = node.span().location() {
+            self.kept.push(location.range().clone());
+        }
+        Continue(())
+    }
+
+    fn visit_simple_select(&mut self, node: &SimpleSelect) -> ControlFlow<()> {
+        self.positions(node.group_clause().unwrap_or_default());
+        node.walk(self)
+    }
+
+    fn visit_sort_by(&mut self, node: &SortBy) -> ControlFlow<()> {
+        self.positions(slice::from_ref(node.expr()));
+        node.walk(self)
+    }
+
+    fn visit_expr_node(&mut self, node: &ExprNode) -> ControlFlow<()> {
+
+        if let Some(location) = node.span().location() {
+            match node.kind() {
+                ExprKind::StringTypecast(_) => {
+                    self.values.push(location.range().clone());
+                    self.strings_only.push(location.range().clone());
+                },
+                ExprKind::IntegerConst(_) | ExprKind::NumericConst { .. } => {
+                    self.values.push(location.range().clone());
+                    if location.slice(self.source).starts_with('-') {
+                        self.negative.push(location.clone())
+                    }
+                },
+                ExprKind::StringConst(_) | ExprKind::BinaryStringConst(_) | ExprKind::HexStringConst(_) => {
+                    self.values.push(location.range().clone())
+                },
+                _ => {},
+            }
+        }
+
+        node.walk(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pg_ast::SignedNumber;
+    use pg_basics::NumberRadix;
+    use test_case::test_case;
+
+    #[test]
+    fn test_constants() {
+        let source = "select a from t where b = e'x\\n' and c > - 1 and d = 1.5";
+        let actual = normalize(source, ParserConfig::default()).unwrap();
+
+        assert_eq!("select a from t where b = $1 and c > $2 and d = $3", actual.query());
+
+        let expected = vec![
+            Constant::new(1, Literal::String("x\n".into()), Location::new(26..32, 1, 27)),
+            Constant::new(2, Literal::Number(SignedNumber::IntegerConst(-1)), Location::new(41..44, 1, 42)),
+            Constant::new(
+                3,
+                Literal::Number(SignedNumber::NumericConst {
+                    value: "1.5".into(),
+                    radix: NumberRadix::Decimal,
+                    negative: false,
+                }),
+                Location::new(53..56, 1, 54)
+            ),
+        ];
+        assert_eq!(expected, actual.constants());
+    }
+
+    #[test_case("select $2, 'a', $1", "select $2, $3, $1")]
+    #[test_case("select 'a'\n'b', b'101', x'1f'", "select $1, $2, $3")]
+    #[test_case("select 1; select 'a'", "select $1; select $2")]
+    #[test_case(
+        "select '1'::varchar(10), timestamp(3) '2000-01-01', cast(1 as numeric(10, 2))",
+        "select $1::varchar(10), timestamp(3) $2, cast($3 as numeric(10, 2))"
+    )]
+    #[test_case(
+        "select a, 1 from t group by 1, a order by 2 desc limit 10",
+        "select a, $1 from t group by 1, a order by 2 desc limit $2"
+    )]
+    #[test_case("create domain d as int default 1 check (value > 0)", "create domain d as int default $1 check (value > $2)")]
+    #[test_case("create rule r as on insert to t do instead select 1", "create rule r as on insert to t do instead select $1")]
+    #[test_case("set search_path = 'a', 'b'", "set search_path = $1, $2")]
+    #[test_case("set local time zone 'UTC'", "set local time zone $1")]
+    #[test_case("alter role r set x = 'y'", "alter role r set x = $1")]
+    #[test_case("alter system set x to 1", "alter system set x to $1")]
+    fn test_normalize(source: &str, expected: &str) {
+        let actual = normalize(source, ParserConfig::default()).unwrap();
+        assert_eq!(expected, actual.query());
+    }
+
+    #[test_case("create role r password 'secret'")]
+    #[test_case("create database d encoding 'UTF8' connection limit 10")]
+    #[test_case("create extension e version '1.0'")]
+    #[test_case("create collation c (locale = 'C')")]
+    #[test_case("create conversion c for 'UTF8' to 'LATIN1' from f")]
+    #[test_case("comment on table t is 'x'")]
+    #[test_case("security label on table t is 'x'")]
+    #[test_case("alter sequence s increment by 2 restart with 10")]
+    #[test_case("select a from t")]
+    fn test_unchanged(source: &str) {
+        let actual = normalize(source, ParserConfig::default()).unwrap();
+        assert_eq!(source, actual.query());
+        assert_eq!(&[] as &[Constant], actual.constants());
+    }
+
+    #[test]
+    fn test_syntax_error() {
+        assert!(normalize("select 1; select from where", ParserConfig::default()).is_err())
+    }
+}
+
+use crate::Constant;
+use crate::Literal;
+use core::ops::ControlFlow;
+use core::ops::ControlFlow::Continue;
+use core::ops::Range;
+use core::slice;
+use pg_ast::Accept;
+use pg_ast::ExprKind;
+use pg_ast::ExprNode;
+use pg_ast::RawStmt;
+use pg_ast::SimpleSelect;
+use pg_ast::SortBy;
+use pg_ast::Type;
+use pg_ast::Visit;
+use pg_ast::Walk;
+use pg_basics::Located;
+use pg_basics::Location;
+use pg_elog::LocatedResult;
+use pg_parser::Parser;
+use pg_parser_core::stream::TokenStream;
+use pg_parser_core::stream::TokenValue;
+use pg_parser_core::ParserConfig;
@@ -9,3 +9,17 @@ macro_rules! located {
         })
     };
 }
+
+/// Same as `located!`, but the location also includes the rest of the tokens consumed by `$parser`.
+#[macro_export]
+macro_rules! spanned {
+    ($parser:expr) => {
+        $crate::combinators::core::parser(|ctx| {
+            let start = ctx.stream_mut().current_location();
+            let p = $parser;
+            let result = $crate::combinators::core::Combinator::parse(&p, ctx)?;
+            let loc = ctx.stream_mut().location_from(start);
+            Ok(pg_basics::Located(result, loc))
+        })
+    };
+}
@@ -0,0 +1,173 @@
+/// Alias: `definition`
+pub(super) fn definition(ctx: &mut ParserContext) -> scan::Result<Vec<DefinitionElem>> {
+
+    /*
+        '(' def_list ')'
+    */
+
+    paren!(
+        many!(sep = Comma, def_elem)
+    ).parse(ctx)
+}
+
+/// Alias: `operator_def_list`
+pub(super) fn operator_def_list(ctx: &mut ParserContext) -> scan::Result<Vec<DefinitionElem>> {
+
+    /*
+        operator_def_elem ( ',' operator_def_elem )*
+    */
+
+    many!(sep = Comma, operator_def_elem).parse(ctx)
+}
+
+/// Alias: `operator_def_elem`
+fn operator_def_elem(ctx: &mut ParserContext) -> scan::Result<DefinitionElem> {
+
+    /*
+        ColLabel '=' ( NONE | operator_def_arg )
+    */
+
+    let (name, _, arg) = seq!(col_label, Equals, def_arg)
+        .parse(ctx)?;
+
+    Ok(DefinitionElem::new(name, Some(arg)))
+}
+
+/// Alias: `def_elem`
+fn def_elem(ctx: &mut ParserContext) -> scan::Result<DefinitionElem> {
+
+    /*
+        ColLabel ( '=' def_arg )?
+    */
+
+    let (name, arg) = seq!(
+        col_label,
+        seq!(Equals, def_arg).optional()
+    ).parse(ctx)?;
+
+    let arg = arg.map(|(_, arg)| arg);
+    Ok(DefinitionElem::new(name, arg))
+}
+
+/// Aliases:
+/// * `def_arg`
+/// * `operator_def_arg`
+pub(super) fn def_arg(ctx: &mut ParserContext) -> scan::Result<DefinitionArg> {
+
+    /*
+          func_type
+        | reserved_keyword
+        | qual_all_Op
+        | NumericOnly
+        | SCONST
+        | NONE
+    */
+
+    alt!(
+        func_type.map(From::from),
+        Reserved.map(|kw| DefinitionArg::ReservedWord(kw.into())),
+        def_number.map(From::from),
+        qual_all_op.map(From::from),
+        string.map(DefinitionArg::String),
+        NoneKw.map(|_| DefinitionArg::None)
+    ).parse(ctx)
+}
+
+fn def_number(ctx: &mut ParserContext) -> scan::Result<SignedNumber> {
+
+    // A lone sign is an operator (e.g.: `commutator = -`),
+    // but a sign followed by a number is a signed number.
+    let is_lone_sign = matches!(ctx.stream_mut().peek(), Ok(Operator(Minus | Plus)))
+        && !matches!(ctx.stream_mut().peek2(), Ok((_, UnsignedNumber(_))));
+
+    if is_lone_sign {
+        return no_match(ctx)
+    }
+
+    signed_number(ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        FuncType,
+        Operator::Addition,
+        SignedNumber::IntegerConst,
+        TypeName::{self, Int4},
+    };
+    use test_case::test_case;
+
+    #[test]
+    fn test_definition() {
+        test_parser!(
+            source = "(input = foo_in, internallength = variable, passedbyvalue)",
+            parser = definition,
+            expected = vec![
+                DefinitionElem::new(
+                    "input",
+                    Some(DefinitionArg::Type(FuncType::Type(
+                        TypeName::Generic { name: vec!["foo_in".into()], type_modifiers: None }.into()
+                    )))
+                ),
+                DefinitionElem::new(
+                    "internallength",
+                    Some(DefinitionArg::Type(FuncType::Type(
+                        TypeName::Generic { name: vec!["variable".into()], type_modifiers: None }.into()
+                    )))
+                ),
+                DefinitionElem::new("passedbyvalue", None),
+            ]
+        )
+    }
+
+    #[test]
+    fn test_operator_def_list() {
+        test_parser!(
+            source = "restrict = none, hashes = true",
+            parser = operator_def_list,
+            expected = vec![
+                DefinitionElem::new("restrict", Some(DefinitionArg::None)),
+                DefinitionElem::new("hashes", Some(DefinitionArg::ReservedWord("true".into()))),
+            ]
+        )
+    }
+
+    #[test_case("int" => Ok(DefinitionArg::Type(FuncType::Type(Int4.into()))))]
+    #[test_case("table" => Ok(DefinitionArg::ReservedWord("table".into())))]
+    #[test_case("-1" => Ok(DefinitionArg::Number(IntegerConst(-1))))]
+    #[test_case("+ 1" => Ok(DefinitionArg::Number(IntegerConst(1))))]
+    #[test_case("+" => Ok(DefinitionArg::Operator(Addition.into())))]
+    #[test_case("'abc'" => Ok(DefinitionArg::String("abc".into())))]
+    #[test_case("none" => Ok(DefinitionArg::None))]
+    fn test_def_arg(source: &str) -> scan::Result<DefinitionArg> {
+        test_parser!(source, def_arg)
+    }
+}
+
+use crate::alt;
+use crate::combinators::col_label;
+use crate::combinators::core::string;
+use crate::combinators::core::Combinator;
+use crate::combinators::func_type;
+use crate::combinators::qual_all_op;
+use crate::combinators::signed_number;
+use crate::many;
+use crate::no_match;
+use crate::paren;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::DefinitionArg;
+use pg_ast::DefinitionElem;
+use pg_ast::SignedNumber;
+use pg_lexer::Keyword::NoneKw;
+use pg_lexer::KeywordCategory::Reserved;
+use pg_lexer::OperatorKind::Comma;
+use pg_lexer::OperatorKind::Equals;
+use pg_lexer::OperatorKind::Minus;
+use pg_lexer::OperatorKind::Plus;
+use pg_parser_core::scan;
+use pg_parser_core::stream::TokenValue::Operator;
+use pg_parser_core::stream::TokenValue::UnsignedNumber;
@@ -0,0 +1,930 @@
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum ExprRule {
+    /// `a_expr`
+    A,
+    /// `b_expr`: no boolean operators, `IN` and `LIKE`.
+    B,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum InfixOp {
+    Or,
+    And,
+    /// `IS [ NOT ] { DISTINCT FROM | NULL | TRUE | FALSE | UNKNOWN }`
+    Is,
+    IsNull,
+    NotNull,
+    In,
+    NotIn,
+    Like,
+    NotLike,
+    ILike,
+    NotILike,
+    Between,
+    NotBetween,
+    SimilarTo,
+    NotSimilarTo,
+    /// `AT TIME ZONE` or `AT LOCAL`
+    At,
+    Collate,
+    Typecast,
+    /// Any other operator, including comparisons and `OPERATOR(...)`.
+    Operator,
+}
+
+// Same precedences as in PG's grammar, from lowest to highest.
+const OR: Associativity = Left(1);
+const AND: Associativity = Left(2);
+const NOT: Associativity = Right(3);
+/// `IS`, `ISNULL` and `NOTNULL`
+const IS: Associativity = Non(4);
+const COMPARISON: Associativity = Non(5);
+/// `BETWEEN`, `IN`, `LIKE`, `ILIKE` and `SIMILAR`
+const IN_LIKE: Associativity = Non(6);
+const QUAL_OP: Associativity = Left(7);
+const ADDITIVE: Associativity = Left(8);
+const MULTIPLICATIVE: Associativity = Left(9);
+const EXPONENTIATION: Associativity = Left(10);
+/// `AT TIME ZONE` and `AT LOCAL`
+const AT: Associativity = Left(11);
+const COLLATE: Associativity = Left(12);
+const UNARY_MINUS: Associativity = Right(13);
+const TYPECAST: Associativity = Left(14);
+
+/// Alias: `a_expr`
+pub(in crate::combinators) fn a_expr(ctx: &mut ParserContext) -> scan::Result<ExprNode> {
+
+    // TODO: IS [NOT] { DOCUMENT | NORMALIZED | JSON }
+
+    operator_expr(ExprRule::A, 0, ctx)
+}
+
+/// Alias: `b_expr`
+pub(in crate::combinators) fn b_expr(ctx: &mut ParserContext) -> scan::Result<ExprNode> {
+    operator_expr(ExprRule::B, 0, ctx)
+}
+
+fn operator_expr(kind: ExprRule, min_precedence: i16, ctx: &mut ParserContext) -> scan::Result<ExprNode> {
+    ctx.nested(|ctx| operator_expr_inner(kind, min_precedence, ctx))
+}
+
+fn operator_expr_inner(kind: ExprRule, min_precedence: i16, ctx: &mut ParserContext) -> scan::Result<ExprNode> {
+
+    let start = ctx.stream_mut().current_location();
+
+    let mut expr = prefix_expr(kind, ctx)?;
+    // Parenthesized expressions keep the span of their content.
+    if expr.span().location().is_none() {
+        expr.set_span(ctx.stream_mut().location_from(start.clone()).into());
+    }
+
+    let mut max_precedence = i16::MAX;
+
+    while let Some((op, assoc)) = infix_op(kind, ctx) {
+
+        let precedence = assoc.precedence();
+        if precedence < min_precedence || precedence > max_precedence {
+            break
+        }
+
+        expr = infix_expr(kind, op, assoc, expr, ctx)?;
+        expr.set_span(ctx.stream_mut().location_from(start.clone()).into());
+
+        // Non-associative operators can't be chained, e.g.: `a < b < c`.
+        // Postfix tests are complete once parsed though, so `a IS NULL IS NULL` is valid.
+        max_precedence = match (assoc, expr.kind()) {
+            (Non(_), IsNull(_) | IsNotNull(_) | BooleanTestExpr(_)) => i16::MAX,
+            (Non(_), _) => assoc.max_precedence(),
+            _ => i16::MAX,
+        };
+    }
+
+    Ok(expr)
+}
+
+fn prefix_expr(kind: ExprRule, ctx: &mut ParserContext) -> scan::Result<ExprNode> {
+
+    /*
+          NOT a_expr
+        | '+' a_expr
+        | '-' a_expr
+        | qual_Op a_expr
+        | c_expr
+    */
+
+    match ctx.stream_mut().peek() {
+        Ok(K(Not)) if kind == ExprRule::A => {
+            ctx.stream_mut().next();
+            let operand = operator_expr(kind, NOT.right_precedence(), ctx).required()?;
+            Ok(BoolExpr::not(operand).into())
+        },
+        Ok(Op(Plus)) => {
+            ctx.stream_mut().next();
+            let operand = operator_expr(kind, UNARY_MINUS.right_precedence(), ctx).required()?;
+            Ok(UnaryExpr::new(Addition, operand).into())
+        },
+        Ok(Op(Minus)) => {
+            ctx.stream_mut().next();
+            let operand = operator_expr(kind, UNARY_MINUS.right_precedence(), ctx).required()?;
+            Ok(negate(operand))
+        },
+        Ok(UserDefinedOperator(_) | K(OperatorKw)) => {
+            let op = qual_op(ctx)?;
+            let operand = operator_expr(kind, QUAL_OP.right_precedence(), ctx).required()?;
+            Ok(UnaryExpr::new(op, operand).into())
+        },
+        _ => expr_primary(ctx),
+    }
+}
+
+/// Alias: `doNegate`
+///
+/// Numeric constants are negated in place, instead of wrapping them in a unary expression.
+fn negate(operand: ExprNode) -> ExprNode {
+    match operand.kind() {
+        IntegerConst(value) => IntegerConst(-value).into(),
+        NumericConst { value, radix } => NumericConst {
+            value: format!("-{value}").into(),
+            radix: *radix
+        }.into(),
+        _ => UnaryExpr::new(Subtraction, operand).into(),
+    }
+}
+
+fn infix_op(kind: ExprRule, ctx: &mut ParserContext) -> Option<(InfixOp, Associativity)> {
+
+    let op = match ctx.stream_mut().peek().ok()? {
+        K(Or) if kind == ExprRule::A => (InfixOp::Or, OR),
+        K(And) if kind == ExprRule::A => (InfixOp::And, AND),
+        K(Is) if kind == ExprRule::A => (InfixOp::Is, IS),
+        // `b_expr` only has `IS [ NOT ] DISTINCT FROM`.
+        K(Is) => match ctx.stream_mut().peek2().ok()? {
+            (_, K(DistinctKw | Not)) => (InfixOp::Is, IS),
+            _ => return None,
+        },
+        K(Isnull) if kind == ExprRule::A => (InfixOp::IsNull, IS),
+        K(Notnull) if kind == ExprRule::A => (InfixOp::NotNull, IS),
+        K(Not) if kind == ExprRule::A => match ctx.stream_mut().peek2().ok()? {
+            (_, K(In)) => (InfixOp::NotIn, IN_LIKE),
+            (_, K(Like)) => (InfixOp::NotLike, IN_LIKE),
+            (_, K(Ilike)) => (InfixOp::NotILike, IN_LIKE),
+            (_, K(Between)) => (InfixOp::NotBetween, IN_LIKE),
+            (_, K(Similar)) => (InfixOp::NotSimilarTo, IN_LIKE),
+            _ => return None,
+        },
+        K(In) if kind == ExprRule::A => (InfixOp::In, IN_LIKE),
+        K(Like) if kind == ExprRule::A => (InfixOp::Like, IN_LIKE),
+        K(Ilike) if kind == ExprRule::A => (InfixOp::ILike, IN_LIKE),
+        K(Between) if kind == ExprRule::A => (InfixOp::Between, IN_LIKE),
+        // `SIMILAR` without `TO` belongs to `substring()`, e.g.: `substring('a' SIMILAR 'b' ESCAPE 'c')`.
+        K(Similar) if kind == ExprRule::A => match ctx.stream_mut().peek2().ok()? {
+            (_, K(To)) => (InfixOp::SimilarTo, IN_LIKE),
+            _ => return None,
+        },
+        K(At) if kind == ExprRule::A => match ctx.stream_mut().peek2().ok()? {
+            (_, K(Time | Local)) => (InfixOp::At, AT),
+            _ => return None,
+        },
+        K(Collate) if kind == ExprRule::A => (InfixOp::Collate, COLLATE),
+        Op(Less | Greater | Equals | LessEquals | GreaterEquals | NotEquals) => (InfixOp::Operator, COMPARISON),
+        UserDefinedOperator(_) | K(OperatorKw) => (InfixOp::Operator, QUAL_OP),
+        Op(Plus | Minus) => (InfixOp::Operator, ADDITIVE),
+        Op(Mul | Div | Percent) => (InfixOp::Operator, MULTIPLICATIVE),
+        Op(Circumflex) => (InfixOp::Operator, EXPONENTIATION),
+        Op(Typecast) => (InfixOp::Typecast, TYPECAST),
+        _ => return None,
+    };
+
+    Some(op)
+}
+
+fn infix_expr(
+    kind: ExprRule,
+    op: InfixOp,
+    assoc: Associativity,
+    left: ExprNode,
+    ctx: &mut ParserContext
+) -> scan::Result<ExprNode> {
+
+    let right_precedence = assoc.right_precedence();
+
+    let expr = match op {
+        InfixOp::Or => {
+            ctx.stream_mut().next();
+            let right = operator_expr(kind, right_precedence, ctx).required()?;
+            BoolExpr::or(left, right).into()
+        },
+        InfixOp::And => {
+            ctx.stream_mut().next();
+            let right = operator_expr(kind, right_precedence, ctx).required()?;
+            BoolExpr::and(left, right).into()
+        },
+        InfixOp::Is => is_expr(kind, left, right_precedence, ctx)?,
+        InfixOp::IsNull => {
+            ctx.stream_mut().next();
+            IsNull(Box::new(left)).into()
+        },
+        InfixOp::NotNull => {
+            ctx.stream_mut().next();
+            IsNotNull(Box::new(left)).into()
+        },
+        InfixOp::In | InfixOp::NotIn => {
+            if op == InfixOp::NotIn {
+                ctx.stream_mut().next();
+            }
+            ctx.stream_mut().next();
+            in_expr(left, op == InfixOp::NotIn, ctx)?
+        },
+        InfixOp::Like | InfixOp::NotLike | InfixOp::ILike | InfixOp::NotILike => {
+            let operator = match op {
+                InfixOp::Like => Operator::Like,
+                InfixOp::NotLike => Operator::NotLike,
+                InfixOp::ILike => Operator::ILike,
+                _ => Operator::NotILike,
+            };
+            let op_location = ctx.stream_mut().current_location();
+            let n = if matches!(op, InfixOp::NotLike | InfixOp::NotILike) { 2 } else { 1 };
+            ctx.stream_mut().skip(n);
+
+            if let Some(sub_type) = sub_type(ctx) {
+                quantified_expr(sub_type, left, operator.into(), ctx)?
+            }
+            else {
+                let pattern = like_pattern(op_location, right_precedence, ctx)?;
+                BinaryExpr::new(operator, left, pattern).into()
+            }
+        },
+        InfixOp::Between | InfixOp::NotBetween => {
+            if op == InfixOp::NotBetween {
+                ctx.stream_mut().next();
+            }
+            ctx.stream_mut().next();
+            between_expr(left, op == InfixOp::NotBetween, right_precedence, ctx)?
+        },
+        InfixOp::SimilarTo | InfixOp::NotSimilarTo => {
+            if op == InfixOp::NotSimilarTo {
+                ctx.stream_mut().next();
+            }
+            seq!(Similar, To).parse(ctx).required()?;
+            let pattern = operator_expr(kind, right_precedence, ctx).required()?;
+            let escape = escape(right_precedence, ctx)?;
+
+            let mut expr = SimilarToExpr::new(left, pattern).with_not(op == InfixOp::NotSimilarTo);
+            expr.set_escape(escape);
+            expr.into()
+        },
+        InfixOp::At => {
+            ctx.stream_mut().next();
+            let local = alt!(
+                Local.map(|_| true),
+                seq!(Time, Zone).map(|_| false)
+            ).parse(ctx).required()?;
+
+            if local {
+                AtLocal(Box::new(left)).into()
+            }
+            else {
+                let zone = operator_expr(kind, right_precedence, ctx).required()?;
+                AtTimeZone(Box::new((left, zone))).into()
+            }
+        },
+        InfixOp::Collate => {
+            let collation = collate_clause(ctx).required()?;
+            CollateExpr::new(left, collation).into()
+        },
+        InfixOp::Typecast => {
+            ctx.stream_mut().next();
+            let type_name = typename(ctx).required()?;
+            TypecastExpr::<ExprNode>::new(left, type_name).into()
+        },
+        InfixOp::Operator => {
+            let operator = qual_all_op(ctx)?;
+            operator_tail(kind, left, operator, right_precedence, ctx)?
+        },
+    };
+
+    Ok(expr)
+}
+
+/// Parses the right operand of an operator, which can also be a subquery.
+fn operator_tail(
+    kind: ExprRule,
+    left: ExprNode,
+    operator: QualifiedOperator,
+    right_precedence: i16,
+    ctx: &mut ParserContext
+) -> scan::Result<ExprNode> {
+
+    /*
+          a_expr subquery_Op sub_type select_with_parens
+        | a_expr subquery_Op sub_type '(' a_expr ')'
+        | a_expr qual_Op a_expr
+    */
+
+    if kind == ExprRule::A
+        && let Some(sub_type) = sub_type(ctx)
+    {
+        return quantified_expr(sub_type, left, operator, ctx)
+    }
+
+    let right = operator_expr(kind, right_precedence, ctx).required()?;
+
+    // Row comparisons against a subquery, e.g.: `(a, b) = (SELECT 1, 2)`.
+    if let (Row(Some(_)), SubLinkExpr(sub_link)) = (left.kind(), right.kind())
+        && sub_link.kind() == SubLinkKind::Expr
+    {
+        let SubLinkExpr(sub_link) = right.into_kind() else { unreachable!() };
+        let subselect = sub_link.into_subselect();
+        return Ok(SubLink::row_compare(left, operator, subselect).into())
+    }
+
+    // Row-wise comparisons, e.g.: `ROW(a, b) < ROW(c, d)`.
+    if let (Row(Some(_)), Row(Some(_))) = (left.kind(), right.kind())
+        && let QualifiedOperator(schema, op) = &operator
+        && schema.is_empty()
+        && let Ok(kind) = RowCompareKind::try_from(op)
+    {
+        let (Row(Some(left)), Row(Some(right))) = (left.into_kind(), right.into_kind()) else { unreachable!() };
+        return Ok(RowCompareExpr::new(kind, left, right).into())
+    }
+
+    Ok(BinaryExpr::new(operator, left, right).into())
+}
+
+/// Alias: `sub_type`
+///
+/// Only consumes the keyword when it's followed by a parenthesis,
+/// since `ANY`, `SOME` and `ALL` can't start an expression.
+fn sub_type(ctx: &mut ParserContext) -> Option<SubLinkKind> {
+
+    /*
+          ANY
+        | SOME
+        | ALL
+    */
+
+    let sub_type = match ctx.stream_mut().peek2() {
+        Ok((K(Any | SomeKw), Op(OpenParenthesis))) => SubLinkKind::Any,
+        Ok((K(All), Op(OpenParenthesis))) => SubLinkKind::All,
+        _ => return None,
+    };
+
+    ctx.stream_mut().next();
+    Some(sub_type)
+}
+
+/// The parenthesized operand after `sub_type`, which is either a subquery or an array.
+fn quantified_expr(
+    sub_type: SubLinkKind,
+    left: ExprNode,
+    operator: QualifiedOperator,
+    ctx: &mut ParserContext
+) -> scan::Result<ExprNode> {
+
+    /*
+          select_with_parens
+        | '(' a_expr ')'
+    */
+
+    OpenParenthesis.parse(ctx).required()?;
+
+    let expr = if select_stmt_starts(ctx) {
+        let subselect = select_stmt(ctx)?;
+        SubLink::new(sub_type, Some(left), Some(operator), subselect).into()
+    }
+    else {
+        let right = a_expr(ctx).required()?;
+        let kind = match sub_type {
+            SubLinkKind::All => ArrayCompareKind::All,
+            _ => ArrayCompareKind::Any,
+        };
+        ArrayCompareExpr::new(kind, operator, left, right).into()
+    };
+
+    CloseParenthesis.parse(ctx).required()?;
+
+    Ok(expr)
+}
+
+/// Parses what follows `IS`, including the keyword.
+fn is_expr(
+    kind: ExprRule,
+    left: ExprNode,
+    right_precedence: i16,
+    ctx: &mut ParserContext
+) -> scan::Result<ExprNode> {
+
+    /*
+          a_expr IS ( NOT )? DISTINCT FROM a_expr
+        | a_expr IS ( NOT )? NULL
+        | a_expr IS ( NOT )? TRUE
+        | a_expr IS ( NOT )? FALSE
+        | a_expr IS ( NOT )? UNKNOWN
+    */
+
+    let (_, not) = seq!(Is, Not.optional()).parse(ctx).required()?;
+    let not = not.is_some();
+
+    if kind == ExprRule::B || matches!(ctx.stream_mut().peek(), Ok(K(DistinctKw))) {
+        seq!(DistinctKw, FromKw).parse(ctx).required()?;
+        let right = operator_expr(kind, right_precedence, ctx).required()?;
+        let operands = Box::new((left, right));
+        let expr = if not { NotDistinct(operands) } else { Distinct(operands) };
+        return Ok(expr.into())
+    }
+
+    let test = alt!(
+        Null.map(|_| None),
+        True.map(|_| Some(if not { IsNotTrue } else { IsTrue })),
+        False.map(|_| Some(if not { IsNotFalse } else { IsFalse })),
+        Unknown.map(|_| Some(if not { IsNotUnknown } else { IsUnknown })),
+    ).parse(ctx).required()?;
+
+    let expr = match test {
+        Some(test) => BooleanTest::new(test, left).into(),
+        None if not => IsNotNull(Box::new(left)).into(),
+        None => IsNull(Box::new(left)).into(),
+    };
+
+    Ok(expr)
+}
+
+/// Alias: `in_expr`
+fn in_expr(left: ExprNode, not: bool, ctx: &mut ParserContext) -> scan::Result<ExprNode> {
+
+    /*
+          select_with_parens
+        | '(' expr_list ')'
+    */
+
+    OpenParenthesis.parse(ctx).required()?;
+
+    let expr = if select_stmt_starts(ctx) {
+        let subselect = select_stmt(ctx)?;
+        let expr = SubLink::in_subquery(left, subselect).into();
+        // `NOT IN` is the negation of `= ANY`.
+        if not { BoolExpr::not(expr).into() } else { expr }
+    }
+    else {
+        let list = expr_list(ctx).required()?;
+        InExpr::new(left, list).with_not(not).into()
+    };
+
+    CloseParenthesis.parse(ctx).required()?;
+
+    Ok(expr)
+}
+
+/// The pattern of `LIKE` and `ILIKE`, with its optional `ESCAPE` clause.
+///
+/// Like PG, an escape wraps the pattern in a `pg_catalog.like_escape()` call, located at the operator.
+fn like_pattern(op_location: Location, right_precedence: i16, ctx: &mut ParserContext) -> scan::Result<ExprNode> {
+
+    /*
+        a_expr ( ESCAPE a_expr )?
+    */
+
+    let pattern_location = ctx.stream_mut().current_location();
+    let pattern = operator_expr(ExprRule::A, right_precedence, ctx).required()?;
+
+    if Escape.optional().parse(ctx)?.is_none() {
+        return Ok(pattern)
+    }
+
+    let escape_location = ctx.stream_mut().current_location();
+    let escape = operator_expr(ExprRule::A, right_precedence, ctx).required()?;
+
+    let args = vec![
+        Located(NamedValue::unnamed(pattern), pattern_location),
+        Located(NamedValue::unnamed(escape), escape_location),
+    ];
+    let args = FuncArgsKind::All { args, order: None };
+    let func_call = FuncCall::new(vec!["pg_catalog".into(), "like_escape".into()], args);
+
+    Ok(ExprNode::new(FuncCallExpr::new(func_call), op_location.into()))
+}
+
+fn escape(right_precedence: i16, ctx: &mut ParserContext) -> scan::Result<Option<ExprNode>> {
+
+    /*
+        ( ESCAPE a_expr )?
+    */
+
+    if Escape.optional().parse(ctx)?.is_none() {
+        return Ok(None)
+    }
+
+    let escape = operator_expr(ExprRule::A, right_precedence, ctx).required()?;
+    Ok(Some(escape))
+}
+
+/// Parses what follows `[ NOT ] BETWEEN`.
+fn between_expr(left: ExprNode, not: bool, right_precedence: i16, ctx: &mut ParserContext) -> scan::Result<ExprNode> {
+
+    /*
+        ( ASYMMETRIC | SYMMETRIC )? b_expr AND a_expr
+    */
+
+    let symmetric = alt!(
+        Asymmetric.map(|_| false),
+        Symmetric.map(|_| true)
+    ).optional().parse(ctx)?.unwrap_or(false);
+
+    let low = b_expr(ctx).required()?;
+    And.parse(ctx).required()?;
+    let high = operator_expr(ExprRule::A, right_precedence, ctx).required()?;
+
+    let expr = BetweenExpr::new(left, low, high)
+        .with_not(not)
+        .with_symmetric(symmetric);
+
+    Ok(expr.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        BoolTestKind::{IsNotUnknown, IsTrue},
+        ColumnRef,
+        ExprKind,
+        ExprKind::{Array, StringConst},
+        Indirection::{Index, Slice},
+        IndirectionExpr,
+        Operator::{Exponentiation, Multiplication},
+        ResTarget,
+        SelectStmt,
+        SimpleSelect,
+        TypeName::Int4,
+    };
+    use pg_basics::Located;
+    use pg_elog::parser::Error::StackDepthLimitExceeded;
+    use pg_elog::Error::Parser;
+    use scan::Error::ScanErr;
+    use test_case::test_case;
+
+    fn col(name: &'static str) -> ExprNode {
+        ColumnRef::SingleName(name.into()).into()
+    }
+
+    fn select(value: i32) -> SelectStmt {
+        SimpleSelect::new(vec![ResTarget::from(IntegerConst(value))]).into()
+    }
+
+    fn binary(op: Operator, left: ExprNode, right: ExprNode) -> ExprNode {
+        BinaryExpr::new(op, left, right).into()
+    }
+
+    #[test_case("1 + 2 * 3",
+        binary(Addition, IntegerConst(1).into(), binary(Multiplication, IntegerConst(2).into(), IntegerConst(3).into()))
+    )]
+    #[test_case("1 - 2 - 3",
+        binary(Subtraction, binary(Subtraction, IntegerConst(1).into(), IntegerConst(2).into()), IntegerConst(3).into())
+    )]
+    #[test_case("-1 ^ 2",
+        binary(Exponentiation, IntegerConst(-1).into(), IntegerConst(2).into())
+    )]
+    #[test_case("- a",
+        UnaryExpr::new(Subtraction, col("a")).into()
+    )]
+    #[test_case("a or b and not c",
+        BoolExpr::or(col("a"), BoolExpr::and(col("b"), BoolExpr::not(col("c")).into()).into()).into()
+    )]
+    #[test_case("not a = b",
+        BoolExpr::not(binary(Operator::Equals, col("a"), col("b"))).into()
+    )]
+    #[test_case("a < b + 1",
+        binary(Operator::Less, col("a"), binary(Addition, col("b"), IntegerConst(1).into()))
+    )]
+    #[test_case("a not like 'x%'",
+        binary(Operator::NotLike, col("a"), StringConst("x%".into()).into())
+    )]
+    #[test_case("a is not distinct from b",
+        NotDistinct(Box::new((col("a"), col("b")))).into()
+    )]
+    #[test_case("a::int",
+        TypecastExpr::<ExprNode>::new(col("a"), Int4).into()
+    )]
+    #[test_case("a in (1, 2)",
+        InExpr::new(col("a"), vec![IntegerConst(1).into(), IntegerConst(2).into()]).into()
+    )]
+    #[test_case("a not in (1)",
+        InExpr::new(col("a"), vec![IntegerConst(1).into()]).with_not(true).into()
+    )]
+    #[test_case("a in (select 1)",
+        SubLink::in_subquery(col("a"), select(1)).into()
+    )]
+    #[test_case("a not in (select 1)",
+        BoolExpr::not(SubLink::in_subquery(col("a"), select(1)).into()).into()
+    )]
+    #[test_case("a = any (select 1)",
+        SubLink::any(col("a"), Operator::Equals, select(1)).into()
+    )]
+    #[test_case("a like some (select 1)",
+        SubLink::any(col("a"), Operator::Like, select(1)).into()
+    )]
+    #[test_case("a > all (select 1)",
+        SubLink::all(col("a"), Operator::Greater, select(1)).into()
+    )]
+    #[test_case("(a, b) in (select 1)",
+        SubLink::in_subquery(Row(Some(vec![col("a"), col("b")])).into(), select(1)).into()
+    )]
+    #[test_case("(a, b) = any (select 1)",
+        SubLink::any(Row(Some(vec![col("a"), col("b")])).into(), Operator::Equals, select(1)).into()
+    )]
+    #[test_case("(a, b) <> (select 1)",
+        SubLink::row_compare(Row(Some(vec![col("a"), col("b")])).into(), Operator::NotEquals, select(1)).into()
+    )]
+    #[test_case("row(a, b) < row(1, 2)",
+        RowCompareExpr::new(RowCompareKind::Less, vec![col("a"), col("b")], vec![IntegerConst(1).into(), IntegerConst(2).into()]).into()
+    )]
+    #[test_case("(a, b) = (1, 2)",
+        RowCompareExpr::new(RowCompareKind::Equals, vec![col("a"), col("b")], vec![IntegerConst(1).into(), IntegerConst(2).into()]).into()
+    )]
+    #[test_case("(a, b) @> (1, 2)",
+        binary(
+            Operator::UserDefined("@>".into()),
+            Row(Some(vec![col("a"), col("b")])).into(),
+            Row(Some(vec![IntegerConst(1).into(), IntegerConst(2).into()])).into()
+        )
+    )]
+    #[test_case("a[:3] = b[2:]",
+        binary(
+            Operator::Equals,
+            ColumnRef::Indirection { name: vec!["a".into()], indirection: vec![Slice(None, Some(IntegerConst(3).into()))] }.into(),
+            ColumnRef::Indirection { name: vec!["b".into()], indirection: vec![Slice(Some(IntegerConst(2).into()), None)] }.into()
+        )
+    )]
+    #[test_case("array[[1], [2]] || (array[3])[1]",
+        binary(
+            Operator::UserDefined("||".into()),
+            Array(vec![Array(vec![IntegerConst(1).into()]).into(), Array(vec![IntegerConst(2).into()]).into()]).into(),
+            IndirectionExpr::new(Array(vec![IntegerConst(3).into()]).into(), vec![Index(IntegerConst(1).into())]).into()
+        )
+    )]
+    #[test_case("a = (select 1)",
+        binary(Operator::Equals, col("a"), SubLink::expr(select(1)).into())
+    )]
+    #[test_case("a = any(array[1,2])",
+        ArrayCompareExpr::any(Operator::Equals, col("a"), Array(vec![IntegerConst(1).into(), IntegerConst(2).into()]).into()).into()
+    )]
+    #[test_case("a not like all (b)",
+        ArrayCompareExpr::all(Operator::NotLike, col("a"), col("b")).into()
+    )]
+    #[test_case("a is null",
+        IsNull(Box::new(col("a"))).into()
+    )]
+    #[test_case("a notnull",
+        IsNotNull(Box::new(col("a"))).into()
+    )]
+    #[test_case("a = b is not null",
+        IsNotNull(Box::new(binary(Operator::Equals, col("a"), col("b")))).into()
+    )]
+    #[test_case("a isnull is not unknown",
+        BooleanTest::new(IsNotUnknown, IsNull(Box::new(col("a"))).into()).into()
+    )]
+    #[test_case("not a is true",
+        BoolExpr::not(BooleanTest::new(IsTrue, col("a")).into()).into()
+    )]
+    #[test_case("a between 1 and 2",
+        BetweenExpr::new(col("a"), IntegerConst(1).into(), IntegerConst(2).into()).into()
+    )]
+    #[test_case("a not between symmetric 1 + 2 and b and c",
+        BoolExpr::and(
+            BetweenExpr::new(col("a"), binary(Addition, IntegerConst(1).into(), IntegerConst(2).into()), col("b"))
+                .with_not(true)
+                .with_symmetric(true)
+                .into(),
+            col("c")
+        ).into()
+    )]
+    #[test_case("a similar to b",
+        SimilarToExpr::new(col("a"), col("b")).into()
+    )]
+    #[test_case("a not similar to 'x' escape '!'",
+        SimilarToExpr::new(col("a"), StringConst("x".into()).into())
+            .with_escape(StringConst("!".into()).into())
+            .with_not(true)
+            .into()
+    )]
+    #[test_case("a at time zone 'UTC' at local",
+        AtLocal(Box::new(AtTimeZone(Box::new((col("a"), StringConst("UTC".into()).into()))).into())).into()
+    )]
+    #[test_case(r#"a collate "C""#,
+        CollateExpr::new(col("a"), vec!["C".into()]).into()
+    )]
+    #[test_case(r#"- a collate "C" at time zone b"#,
+        AtTimeZone(Box::new((
+            CollateExpr::new(UnaryExpr::new(Subtraction, col("a")).into(), vec!["C".into()]).into(),
+            col("b")
+        ))).into()
+    )]
+    fn test_a_expr(source: &str, expected: ExprNode) {
+        test_parser!(source, a_expr, expected)
+    }
+
+    #[test_case("1 + 2 * 3" => Some("1 + 2 * 3"))]
+    #[test_case("- a  " => Some("- a"))]
+    #[test_case("( a )" => Some("a"))]
+    #[test_case("foo(1, 2) is not distinct from b" => Some("foo(1, 2) is not distinct from b"))]
+    #[test_case("a::int[] , b" => Some("a::int[]"))]
+    #[test_case("array[[1], [2]]" => Some("array[[1], [2]]"))]
+    fn test_a_expr_span(source: &str) -> Option<&str> {
+        let mut ctx = ParserContext::new(source);
+        let expr = a_expr(&mut ctx).unwrap();
+        expr.span().slice(source)
+    }
+
+    #[test]
+    fn test_like_escape() {
+        let source = "a like 'x' escape '!'";
+        let mut ctx = ParserContext::new(source);
+        let expr = a_expr(&mut ctx).unwrap();
+
+        let args = vec![
+            Located(NamedValue::unnamed(StringConst("x".into()).into()), Location::new(7..10, 1, 8)),
+            Located(NamedValue::unnamed(StringConst("!".into()).into()), Location::new(18..21, 1, 19)),
+        ];
+        let args = FuncArgsKind::All { args, order: None };
+        let like_escape = FuncCall::new(vec!["pg_catalog".into(), "like_escape".into()], args);
+        let like_escape = FuncCallExpr::new(like_escape);
+        assert_eq!(binary(Operator::Like, col("a"), like_escape.into()), expr);
+
+        let ExprKind::BinaryExpr(expr) = expr.kind() else { panic!("expected a binary expression") };
+        assert_eq!(Some("like"), expr.right_operand().span().slice(source));
+    }
+
+    #[test]
+    fn test_a_expr_operand_spans() {
+        let source = "a + foo(b) * 2";
+        let mut ctx = ParserContext::new(source);
+        let expr = a_expr(&mut ctx).unwrap();
+
+        let ExprKind::BinaryExpr(expr) = expr.kind() else { panic!("expected a binary expression") };
+        assert_eq!(Some("a"), expr.left_operand().span().slice(source));
+        assert_eq!(Some("foo(b) * 2"), expr.right_operand().span().slice(source));
+
+        let ExprKind::BinaryExpr(expr) = expr.right_operand().kind() else { panic!("expected a binary expression") };
+        let ExprKind::FuncCallExpr(func) = expr.left_operand().kind() else { panic!("expected a function call") };
+        assert_eq!(Some("foo(b)"), func.func().span().slice(source));
+    }
+
+    #[test_case("a < b < c")]
+    #[test_case("a is distinct from b is null")]
+    #[test_case("a between 1 and 2 between 3 and 4")]
+    #[test_case("a like b escape c like d")]
+    #[test_case("a is 1")]
+    #[test_case("a = any (select 1) escape b")]
+    fn test_invalid_a_expr(source: &str) {
+        let mut ctx = ParserContext::new(source);
+        let result = a_expr(&mut ctx);
+        assert!(result.is_err() || !ctx.stream_mut().eof());
+    }
+
+    #[test_case("a and b", col("a"))]
+    #[test_case("1 + 2 in (3)", binary(Addition, IntegerConst(1).into(), IntegerConst(2).into()))]
+    #[test_case("a is not distinct from b", NotDistinct(Box::new((col("a"), col("b")))).into())]
+    #[test_case("a is null", col("a"))]
+    #[test_case("a between 1 and 2", col("a"))]
+    #[test_case(r#"a collate "C""#, col("a"))]
+    fn test_b_expr(source: &str, expected: ExprNode) {
+        test_parser!(source, b_expr, expected)
+    }
+
+    #[test_case("(", "1)")]
+    #[test_case("- ", "1")]
+    #[test_case("not ", "true")]
+    #[test_case("array[", "1]")]
+    #[test_case("f(", ")")]
+    #[test_case("(select ", ")")]
+    fn test_stack_depth_limit_exceeded(prefix: &str, suffix: &str) {
+
+        // Runs with the default limit on a 2MB test thread, so it also checks that the error happens before an overflow.
+        let n = 10_000;
+        let source = prefix.repeat(n) + &suffix.repeat(n);
+
+        assert_matches!(
+            test_parser!(source.as_str(), a_expr),
+            Err(ScanErr(Located(Parser(StackDepthLimitExceeded), _)))
+        );
+    }
+
+    #[test_case("1" => matches Ok(_))]
+    #[test_case("(1)" => matches Err(_))]
+    fn test_max_stack_depth(source: &str) -> scan::Result<ExprNode> {
+        let mut ctx = ParserContext::new(source).with_max_stack_depth(0);
+        a_expr(&mut ctx)
+    }
+}
+
+use super::associativity::Associativity;
+use super::associativity::Associativity::Left;
+use super::associativity::Associativity::Non;
+use super::associativity::Associativity::Right;
+use crate::alt;
+use crate::combinators::collate_clause;
+use crate::combinators::core::Combinator;
+use crate::combinators::expr::expr_primary;
+use crate::combinators::expr_list;
+use crate::combinators::qual_all_op;
+use crate::combinators::qual_op;
+use crate::combinators::stmt::select_stmt;
+use crate::combinators::stmt::select_stmt_starts;
+use crate::combinators::typename;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::ArrayCompareExpr;
+use pg_ast::ArrayCompareKind;
+use pg_ast::BetweenExpr;
+use pg_ast::BinaryExpr;
+use pg_ast::BoolExpr;
+use pg_ast::BoolTestKind::IsFalse;
+use pg_ast::BoolTestKind::IsNotFalse;
+use pg_ast::BoolTestKind::IsNotTrue;
+use pg_ast::BoolTestKind::IsNotUnknown;
+use pg_ast::BoolTestKind::IsTrue;
+use pg_ast::BoolTestKind::IsUnknown;
+use pg_ast::BooleanTest;
+use pg_ast::CollateExpr;
+use pg_ast::ExprKind::AtLocal;
+use pg_ast::ExprKind::AtTimeZone;
+use pg_ast::ExprKind::BooleanTest as BooleanTestExpr;
+use pg_ast::ExprKind::Distinct;
+use pg_ast::ExprKind::IntegerConst;
+use pg_ast::ExprKind::IsNotNull;
+use pg_ast::ExprKind::IsNull;
+use pg_ast::ExprKind::NotDistinct;
+use pg_ast::ExprKind::NumericConst;
+use pg_ast::ExprKind::Row;
+use pg_ast::ExprKind::SubLink as SubLinkExpr;
+use pg_ast::ExprNode;
+use pg_ast::FuncArgsKind;
+use pg_ast::FuncCall;
+use pg_ast::FuncCallExpr;
+use pg_ast::InExpr;
+use pg_ast::NamedValue;
+use pg_ast::Operator;
+use pg_ast::Operator::Addition;
+use pg_ast::Operator::Subtraction;
+use pg_ast::QualifiedOperator;
+use pg_ast::RowCompareExpr;
+use pg_ast::RowCompareKind;
+use pg_ast::SimilarToExpr;
+use pg_ast::SubLink;
+use pg_ast::SubLinkKind;
+use pg_ast::TypecastExpr;
+use pg_ast::UnaryExpr;
+use pg_basics::Located;
+use pg_basics::Location;
+use pg_lexer::Keyword::All;
+use pg_lexer::Keyword::And;
+use pg_lexer::Keyword::Any;
+use pg_lexer::Keyword::Asymmetric;
+use pg_lexer::Keyword::At;
+use pg_lexer::Keyword::Between;
+use pg_lexer::Keyword::Collate;
+use pg_lexer::Keyword::Distinct as DistinctKw;
+use pg_lexer::Keyword::Escape;
+use pg_lexer::Keyword::False;
+use pg_lexer::Keyword::FromKw;
+use pg_lexer::Keyword::Ilike;
+use pg_lexer::Keyword::In;
+use pg_lexer::Keyword::Is;
+use pg_lexer::Keyword::Isnull;
+use pg_lexer::Keyword::Like;
+use pg_lexer::Keyword::Local;
+use pg_lexer::Keyword::Not;
+use pg_lexer::Keyword::Notnull;
+use pg_lexer::Keyword::Null;
+use pg_lexer::Keyword::Operator as OperatorKw;
+use pg_lexer::Keyword::Or;
+use pg_lexer::Keyword::Similar;
+use pg_lexer::Keyword::SomeKw;
+use pg_lexer::Keyword::Symmetric;
+use pg_lexer::Keyword::Time;
+use pg_lexer::Keyword::To;
+use pg_lexer::Keyword::True;
+use pg_lexer::Keyword::Unknown;
+use pg_lexer::Keyword::Zone;
+use pg_lexer::OperatorKind::Circumflex;
+use pg_lexer::OperatorKind::CloseParenthesis;
+use pg_lexer::OperatorKind::Div;
+use pg_lexer::OperatorKind::Equals;
+use pg_lexer::OperatorKind::Greater;
+use pg_lexer::OperatorKind::GreaterEquals;
+use pg_lexer::OperatorKind::Less;
+use pg_lexer::OperatorKind::LessEquals;
+use pg_lexer::OperatorKind::Minus;
+use pg_lexer::OperatorKind::Mul;
+use pg_lexer::OperatorKind::NotEquals;
+use pg_lexer::OperatorKind::OpenParenthesis;
+use pg_lexer::OperatorKind::Percent;
+use pg_lexer::OperatorKind::Plus;
+use pg_lexer::OperatorKind::Typecast;
+use pg_parser_core::scan;
+use pg_parser_core::stream::TokenValue::Keyword as K;
+use pg_parser_core::stream::TokenValue::Operator as Op;
+use pg_parser_core::stream::TokenValue::UserDefinedOperator;
+use pg_parser_core::Required;
@@ -14,8 +14,8 @@ pub(super) fn expr_const(ctx: &mut ParserContext) -> scan::Result<ExprNode> {
     */
 
     alt!(
-        const_typename.map(ExprNode::from),
-        number.map(ExprNode::from),
+        const_typename.map(ExprKind::from),
+        number.map(ExprKind::from),
         string.map(StringConst),
         bit_string
             .map(|(kind, value)| match kind {
@@ -25,7 +25,9 @@ pub(super) fn expr_const(ctx: &mut ParserContext) -> scan::Result<ExprNode> {
         True.map(|_| BooleanConst(true)),
         False.map(|_| BooleanConst(false)),
         Null.map(|_| NullConst),
-    ).parse(ctx)
+    )
+        .map(ExprNode::from)
+        .parse(ctx)
 }
 
 /// Alias: `ConstTypename`
@@ -137,7 +139,7 @@ fn interval_typecast(ctx: &mut ParserContext) -> scan::Result<StringTypecastExpr
 mod tests {
     use super::*;
     use crate::test_parser;
-    use pg_ast::ExprNode::*;
+    use pg_ast::ExprKind::*;
     use test_case::test_case;
     #[allow(unused_imports)]
     use {
@@ -146,14 +148,14 @@ mod tests {
         pg_basics::NumberRadix::Decimal,
     };
 
-    #[test_case("123", IntegerConst(123))]
-    #[test_case("123.45", NumericConst { radix: Decimal, value: "123.45".into() })]
-    #[test_case("true", BooleanConst(true))]
-    #[test_case("false", BooleanConst(false))]
-    #[test_case("null", NullConst)]
-    #[test_case("b'0101'", BinaryStringConst("0101".into()))]
-    #[test_case("x'19af'", HexStringConst("19af".into()))]
-    #[test_case("'string literal'", StringConst("string literal".into()))]
+    #[test_case("123", IntegerConst(123).into())]
+    #[test_case("123.45", NumericConst { radix: Decimal, value: "123.45".into() }.into())]
+    #[test_case("true", BooleanConst(true).into())]
+    #[test_case("false", BooleanConst(false).into())]
+    #[test_case("null", NullConst.into())]
+    #[test_case("b'0101'", BinaryStringConst("0101".into()).into())]
+    #[test_case("x'19af'", HexStringConst("19af".into()).into())]
+    #[test_case("'string literal'", StringConst("string literal".into()).into())]
     #[test_case("double precision '1.23'", StringTypecastExpr::new("1.23", Float8).into())]
     fn test_expr_const(source: &str, expected: ExprNode) {
         test_parser!(source, expr_const, expected)
@@ -171,7 +173,7 @@ mod tests {
     #[test_case("real '42.0'"                      => Ok(StringTypecastExpr::new("42.0", Float4)))]
     #[test_case("numeric '123.45'"                 => Ok(StringTypecastExpr::new("123.45", Numeric(None))))]
     #[test_case("float(25) '123.45'"               => Ok(StringTypecastExpr::new("123.45", Float8)))]
-    #[test_case("bit varying(6) '7'"               => Ok(StringTypecastExpr::new("7", Varbit(Some(vec![IntegerConst(6)])))))]
+    #[test_case("bit varying(6) '7'"               => Ok(StringTypecastExpr::new("7", Varbit(Some(vec![IntegerConst(6).into()])))))]
     #[test_case("character varying 'foo'"          => Ok(StringTypecastExpr::new("foo", Varchar { max_length: None })))]
     #[test_case("timestamp with time zone 'foo'"   => Ok(StringTypecastExpr::new("foo", TimestampTz { precision: None })))]
     #[test_case("time(1) with time zone 'foo'"     => Ok(StringTypecastExpr::new("foo", TimeTz { precision: Some(1) })))]
@@ -196,12 +198,13 @@ use crate::combinators::simple_typename;
 use crate::no_match;
 use crate::seq;
 use crate::ParserContext;
+use pg_ast::ExprKind;
+use pg_ast::ExprKind::BinaryStringConst;
+use pg_ast::ExprKind::BooleanConst;
+use pg_ast::ExprKind::HexStringConst;
+use pg_ast::ExprKind::NullConst;
+use pg_ast::ExprKind::StringConst;
 use pg_ast::ExprNode;
-use pg_ast::ExprNode::BinaryStringConst;
-use pg_ast::ExprNode::BooleanConst;
-use pg_ast::ExprNode::HexStringConst;
-use pg_ast::ExprNode::NullConst;
-use pg_ast::ExprNode::StringConst;
 use pg_ast::IntervalRange::Full;
 use pg_ast::StringTypecastExpr;
 use pg_ast::TypeName;
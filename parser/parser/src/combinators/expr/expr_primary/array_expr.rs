@@ -0,0 +1,93 @@
+/// Inlined: `ARRAY array_expr`
+pub(super) fn array_expr(ctx: &mut ParserContext) -> scan::Result<ExprNode> {
+
+    /*
+        ARRAY array_expr
+    */
+
+    if ! matches!(ctx.stream_mut().peek2(), Ok((K(ArrayKw), Op(OpenBracket)))) {
+        return no_match(ctx)
+    }
+
+    let (_, elements) = seq!(skip(1), array_elements)
+        .parse(ctx)?;
+
+    Ok(Array(elements).into())
+}
+
+/// Alias: `array_expr`
+fn array_elements(ctx: &mut ParserContext) -> scan::Result<Vec<ExprNode>> {
+
+    /*
+          '[' expr_list ']'
+        | '[' array_expr_list ']'
+        | '[' ']'
+    */
+
+    ctx.nested(|ctx|
+        brackets!(
+            alt!(
+                many!(sep = Comma,
+                    spanned!(array_elements)
+                        .map(|Located(elements, loc)| ExprNode::new(Array(elements), loc.into()))
+                ),
+                expr_list
+            )
+            .optional()
+            .map(Option::unwrap_or_default)
+        ).parse(ctx)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::ExprKind::IntegerConst;
+    use test_case::test_case;
+
+    #[test_case("array[]", Array(vec![]).into())]
+    #[test_case("array[1, 2]", Array(vec![IntegerConst(1).into(), IntegerConst(2).into()]).into())]
+    #[test_case("array[[1, 2], [3, 4]]",
+        Array(vec![
+            Array(vec![IntegerConst(1).into(), IntegerConst(2).into()]).into(),
+            Array(vec![IntegerConst(3).into(), IntegerConst(4).into()]).into(),
+        ]).into()
+    )]
+    #[test_case("array[[], [[5]]]",
+        Array(vec![
+            Array(vec![]).into(),
+            Array(vec![Array(vec![IntegerConst(5).into()]).into()]).into(),
+        ]).into()
+    )]
+    #[test_case("array[array[1], array[2]]",
+        Array(vec![
+            Array(vec![IntegerConst(1).into()]).into(),
+            Array(vec![IntegerConst(2).into()]).into(),
+        ]).into()
+    )]
+    fn test_array_expr(source: &str, expected: ExprNode) {
+        test_parser!(source, array_expr, expected)
+    }
+}
+
+use crate::alt;
+use crate::brackets;
+use crate::combinators::core::skip;
+use crate::combinators::core::Combinator;
+use crate::combinators::expr_list;
+use crate::many;
+use crate::no_match;
+use crate::seq;
+use crate::spanned;
+use crate::ParserContext;
+use pg_ast::ExprKind::Array;
+use pg_ast::ExprNode;
+use pg_basics::Located;
+use pg_lexer::Keyword::Array as ArrayKw;
+use pg_lexer::OperatorKind::Comma;
+use pg_lexer::OperatorKind::OpenBracket;
+use pg_parser_core::scan;
+use pg_parser_core::stream::TokenValue::Keyword as K;
+use pg_parser_core::stream::TokenValue::Operator as Op;
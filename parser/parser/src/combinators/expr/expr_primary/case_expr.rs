@@ -7,11 +7,12 @@ pub(super) fn case_expr(ctx: &mut ParserContext) -> scan::Result<CaseExpr> {
         END
     */
 
-    let (_, target, when_clauses, default) = seq!(
+    let (_, target, when_clauses, default, _) = seq!(
         Case,
         a_expr.optional(),
         many!(when_clause),
-        else_clause.optional()
+        else_clause.optional(),
+        End
     ).parse(ctx)?;
 
     let expr = CaseExpr::new(target, when_clauses, default);
@@ -38,6 +39,8 @@ fn else_clause(ctx: &mut ParserContext) -> scan::Result<ExprNode> {
 mod tests {
     use super::*;
     use crate::test_parser;
+    use pg_ast::ExprKind;
+    use pg_lexer::OperatorKind::Comma;
 
     #[test]
     fn test_case_value_when_else() {
@@ -50,14 +53,14 @@ mod tests {
             ",
             parser = case_expr,
             expected = CaseExpr::new(
-                Some(ExprNode::StringConst("foo".into())),
+                Some(ExprKind::StringConst("foo".into()).into()),
                 vec![
                     CaseWhen::new(
-                        ExprNode::IntegerConst(1),
-                        ExprNode::IntegerConst(2),
+                        ExprKind::IntegerConst(1).into(),
+                        ExprKind::IntegerConst(2).into(),
                     ),
                 ],
-                Some(ExprNode::IntegerConst(3)),
+                Some(ExprKind::IntegerConst(3).into()),
             )
         )
     }
@@ -75,8 +78,26 @@ mod tests {
                 None,
                 vec![
                     CaseWhen::new(
-                        ExprNode::IntegerConst(1),
-                        ExprNode::IntegerConst(2),
+                        ExprKind::IntegerConst(1).into(),
+                        ExprKind::IntegerConst(2).into(),
+                    ),
+                ],
+                None
+            )
+        )
+    }
+
+    #[test]
+    fn test_case_consumes_end() {
+        test_parser!(
+            source = "case when 1 then 2 end, 3",
+            parser = seq!(case_expr, Comma).map(|(expr, _)| expr),
+            expected = CaseExpr::new(
+                None,
+                vec![
+                    CaseWhen::new(
+                        ExprKind::IntegerConst(1).into(),
+                        ExprKind::IntegerConst(2).into(),
                     ),
                 ],
                 None
@@ -95,6 +116,7 @@ use pg_ast::CaseWhen;
 use pg_ast::ExprNode;
 use pg_lexer::Keyword::Case;
 use pg_lexer::Keyword::Else;
+use pg_lexer::Keyword::End;
 use pg_lexer::Keyword::Then;
 use pg_lexer::Keyword::When;
 use pg_parser_core::scan;
@@ -11,19 +11,19 @@ pub(super) fn explicit_row(ctx: &mut ParserContext) -> scan::Result<ExprNode> {
     let (_, col_values) = seq!(skip(1), paren!(expr_list.optional()))
         .parse(ctx)?;
 
-    Ok(Row(col_values))
+    Ok(Row(col_values).into())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::{IntegerConst, StringConst};
+    use pg_ast::ExprKind::{IntegerConst, StringConst};
     use test_case::test_case;
 
-    #[test_case("row()" => Ok(Row(None)))]
-    #[test_case("row(1)" => Ok(Row(Some(vec![IntegerConst(1)]))))]
-    #[test_case("row(1, 'foo')" => Ok(Row(Some(vec![IntegerConst(1), StringConst("foo".into())]))))]
+    #[test_case("row()" => Ok(Row(None).into()))]
+    #[test_case("row(1)" => Ok(Row(Some(vec![IntegerConst(1).into()])).into()))]
+    #[test_case("row(1, 'foo')" => Ok(Row(Some(vec![IntegerConst(1).into(), StringConst("foo".into()).into()])).into()))]
     fn test_explicit_row(source: &str) -> scan::Result<ExprNode> {
         let mut ctx = ParserContext::new(source);
         explicit_row(&mut ctx)
@@ -37,8 +37,8 @@ use crate::no_match;
 use crate::paren;
 use crate::seq;
 use crate::ParserContext;
+use pg_ast::ExprKind::Row;
 use pg_ast::ExprNode;
-use pg_ast::ExprNode::Row;
 use pg_lexer::Keyword as Kw;
 use pg_lexer::OperatorKind::OpenParenthesis;
 use pg_parser_core::scan;
@@ -17,13 +17,14 @@ fn filter_clause(ctx: &mut ParserContext) -> scan::Result<ExprNode> {
 mod tests {
     use super::*;
     use crate::test_parser;
+    use pg_ast::ExprKind;
 
     #[test]
     fn test_filter_clause() {
         test_parser!(
             source = "filter (where true)",
             parser = filter_clause,
-            expected = ExprNode::BooleanConst(true)
+            expected = ExprKind::BooleanConst(true)
         )
     }
 }
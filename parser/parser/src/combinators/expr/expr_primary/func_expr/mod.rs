@@ -46,7 +46,7 @@ mod tests {
     use test_case::test_case;
     #[allow(unused_imports)]
     use {
-        pg_ast::ExprNode::{IntegerConst, StringConst},
+        pg_ast::ExprKind::{IntegerConst, StringConst},
         pg_ast::JsonArrayAgg,
         pg_ast::JsonKeyValue,
         pg_ast::JsonObjectAgg,
@@ -69,7 +69,7 @@ mod tests {
         JsonObjectAggExpr::new(
             JsonObjectAgg::new(
                 JsonKeyValue::new(
-                    StringConst("foo".into()),
+                    StringConst("foo".into()).into(),
                     JsonValueExpr::from(IntegerConst(1)),
                 ),
                 None,
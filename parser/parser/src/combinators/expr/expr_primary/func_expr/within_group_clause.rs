@@ -15,7 +15,7 @@ fn within_group_clause(ctx: &mut ParserContext) -> scan::Result<Vec<SortBy>> {
 mod tests {
     use super::*;
     use crate::test_parser;
-    use pg_ast::ExprNode::IntegerConst;
+    use pg_ast::ExprKind::IntegerConst;
 
     #[test]
     fn test_within_group_clause() {
@@ -23,7 +23,7 @@ mod tests {
             source = "within group (order by 1)",
             parser = within_group_clause,
             expected = vec![
-                SortBy::new(IntegerConst(1), None, None)
+                SortBy::new(IntegerConst(1).into(), None, None)
             ]
         );
     }
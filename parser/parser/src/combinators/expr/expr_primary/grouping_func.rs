@@ -11,7 +11,7 @@ pub(super) fn grouping_func(ctx: &mut ParserContext) -> scan::Result<ExprNode> {
     let (_, args) = seq!(skip(1), paren!(expr_list))
         .parse(ctx)?;
 
-    Ok(GroupingFunc(args))
+    Ok(GroupingFunc(args).into())
 }
 
 #[cfg(test)]
@@ -23,7 +23,7 @@ mod tests {
     fn test_grouping_func() {
         let mut ctx = ParserContext::new("grouping(foo)");
         let actual = grouping_func(&mut ctx);
-        assert_eq!(Ok(GroupingFunc(vec![SingleName("foo".into()).into()])), actual)
+        assert_eq!(Ok(GroupingFunc(vec![SingleName("foo".into()).into()]).into()), actual)
     }
 }
 
@@ -34,8 +34,8 @@ use crate::no_match;
 use crate::paren;
 use crate::seq;
 use crate::ParserContext;
+use pg_ast::ExprKind::GroupingFunc;
 use pg_ast::ExprNode;
-use pg_ast::ExprNode::GroupingFunc;
 use pg_lexer::Keyword::Grouping;
 use pg_lexer::OperatorKind::OpenParenthesis;
 use pg_parser_core::scan;
@@ -1,26 +1,40 @@
 pg_basics::reexport! {
+    array_expr,
     case_expr,
     explicit_row,
     func_expr,
     grouping_func,
     param_expr,
+    parenthesized_expr,
     prefixed_expr_const,
+    sublink_expr,
 }
 
 /// Alias: `c_expr`
 pub(in crate::combinators) fn expr_primary(ctx: &mut ParserContext) -> scan::Result<ExprNode> {
-    alt!(
+
+    let Located(mut expr, loc) = spanned!(alt!(
         param_expr,
         expr_const,
         case_expr.map(From::from),
+        array_expr,
         func_expr,
         explicit_row,
         grouping_func,
+        parenthesized_expr,
+        sublink_expr,
 
         // ❗ Must be after most other productions,
         // due to conflicts with the 1st keyword.
         prefixed_expr_const,
-    ).parse(ctx)
+    )).parse(ctx)?;
+
+    // Parenthesized expressions keep the span of their content.
+    if expr.span().location().is_none() {
+        expr.set_span(loc.into());
+    }
+
+    Ok(expr)
 }
 
 #[cfg(test)]
@@ -34,9 +48,12 @@ mod tests {
             "$3",                     // param_expr
             "true",                   // expr_const
             "case when 1 then 2 end", // case_expr
+            "array[1]",               // array_expr
             "user",                   // func_expr
             "row()",                  // explicit_row
             "grouping(1)",            // explicit_row
+            "(1)",                    // parenthesized_expr
+            "exists (select 1)",      // sublink_expr
             "current_schema",         // prefix_expr
         ]
         => matches Ok(_)
@@ -50,6 +67,8 @@ mod tests {
 use crate::alt;
 use crate::combinators::core::Combinator;
 use crate::combinators::expr::expr_const;
+use crate::spanned;
 use crate::ParserContext;
 use pg_ast::ExprNode;
+use pg_basics::Located;
 use pg_parser_core::scan;
@@ -11,7 +11,7 @@ pub(super) fn param_expr(ctx: &mut ParserContext) -> scan::Result<ExprNode> {
         located!(indirection).optional()
     ).parse(ctx)?;
 
-    let param = ParamRef { index };
+    let param = ParamRef { index }.into();
     let expr = match indirection {
         None => param,
         Some(indirection) => {
@@ -35,7 +35,7 @@ mod tests {
             source = "$5[:]",
             parser = param_expr,
             expected = IndirectionExpr::new(
-                ParamRef { index: 5 },
+                ParamRef { index: 5 }.into(),
                 vec![Slice(None, None)]
             )
         )
@@ -49,7 +49,7 @@ use crate::combinators::expr::indirection;
 use crate::located;
 use crate::seq;
 use crate::ParserContext;
+use pg_ast::ExprKind::ParamRef;
 use pg_ast::ExprNode;
-use pg_ast::ExprNode::ParamRef;
 use pg_ast::IndirectionExpr;
 use pg_parser_core::scan;
@@ -0,0 +1,127 @@
+/// Inlined:
+/// * `'(' a_expr ')' opt_indirection`
+/// * `implicit_row`
+/// * `select_with_parens ( indirection )?`
+pub(super) fn parenthesized_expr(ctx: &mut ParserContext) -> scan::Result<ExprNode> {
+
+    /*
+          '(' a_expr ')' ( indirection )?
+        | '(' expr_list ',' a_expr ')'
+        | select_with_parens ( indirection )?
+    */
+
+    let (expr, indirection) = seq!(
+        paren!(parenthesized_content),
+        indirection.optional()
+    ).parse(ctx)?;
+
+    let expr = match indirection {
+        Some(indirection) => IndirectionExpr::new(expr, indirection).into(),
+        None => expr,
+    };
+
+    Ok(expr)
+}
+
+fn parenthesized_content(ctx: &mut ParserContext) -> scan::Result<ExprNode> {
+
+    if select_stmt_starts(ctx) {
+        let subselect = select_stmt(ctx)?;
+        return Ok(SubLink::expr(subselect).into())
+    }
+
+    let mut exprs = many!(sep = Comma, a_expr).parse(ctx)?;
+
+    if exprs.len() > 1 {
+        return Ok(Row(Some(exprs)).into())
+    }
+
+    let expr = exprs.pop().expect("`many!` should return at least 1 element");
+
+    // The first operand of a set operation can be a parenthesized select,
+    // e.g.: `((SELECT 1) UNION SELECT 2)`.
+    if let ExprKind::SubLink(sub_link) = expr.kind()
+        && sub_link.kind() == SubLinkKind::Expr
+        && matches!(
+            ctx.stream_mut().peek(),
+            Ok(K(Union | Intersect | Except | Order | Limit | Offset))
+        )
+    {
+        let ExprKind::SubLink(sub_link) = expr.into_kind() else { unreachable!() };
+        let subselect = select_stmt_tail(sub_link.into_subselect(), ctx)?;
+        return Ok(SubLink::expr(subselect).into())
+    }
+
+    Ok(expr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        ColumnRef,
+        ExprKind::IntegerConst,
+        Indirection,
+        ResTarget,
+        SelectBody,
+        SelectStmt,
+        SetOperation,
+        SimpleSelect,
+    };
+    use test_case::test_case;
+
+    fn select(value: i32) -> SelectStmt {
+        SimpleSelect::new(vec![ResTarget::from(IntegerConst(value))]).into()
+    }
+
+    #[test_case("(1)", IntegerConst(1).into())]
+    #[test_case("((1))", IntegerConst(1).into())]
+    #[test_case("(1, 2)", Row(Some(vec![IntegerConst(1).into(), IntegerConst(2).into()])).into())]
+    #[test_case("(select 1)", SubLink::expr(select(1)).into())]
+    #[test_case("((select 1))", SubLink::expr(select(1)).into())]
+    #[test_case("((select 1) union all select 2)",
+        SubLink::expr(SelectStmt::new(SelectBody::SetOperation {
+            op: SetOperation::Union,
+            all: true,
+            left: Box::new(select(1)),
+            right: Box::new(select(2)),
+        })).into()
+    )]
+    #[test_case("(a).b",
+        IndirectionExpr::new(
+            ColumnRef::SingleName("a".into()).into(),
+            vec![Indirection::Property("b".into())]
+        ).into()
+    )]
+    fn test_parenthesized_expr(source: &str, expected: ExprNode) {
+        test_parser!(source, parenthesized_expr, expected)
+    }
+}
+
+use crate::combinators::core::Combinator;
+use crate::combinators::expr::a_expr;
+use crate::combinators::expr::indirection;
+use crate::combinators::stmt::select_stmt;
+use crate::combinators::stmt::select_stmt_starts;
+use crate::combinators::stmt::select_stmt_tail;
+use crate::many;
+use crate::paren;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::ExprKind;
+use pg_ast::ExprKind::Row;
+use pg_ast::ExprNode;
+use pg_ast::IndirectionExpr;
+use pg_ast::SubLink;
+use pg_ast::SubLinkKind;
+use pg_lexer::Keyword::Except;
+use pg_lexer::Keyword::Intersect;
+use pg_lexer::Keyword::Limit;
+use pg_lexer::Keyword::Offset;
+use pg_lexer::Keyword::Order;
+use pg_lexer::Keyword::Union;
+use pg_lexer::OperatorKind::Comma;
+use pg_parser_core::scan;
+use pg_parser_core::stream::TokenValue::Keyword as K;
@@ -154,7 +154,7 @@ mod tests {
     use test_case::test_case;
     #[allow(unused_imports)]
     use {
-        pg_ast::ExprNode::IntegerConst,
+        pg_ast::ExprKind::IntegerConst,
         pg_ast::NamedValue,
         pg_ast::NullTreatment,
         pg_basics::Location,
@@ -179,14 +179,14 @@ mod tests {
     #[test_case("(1) 'foo'" => Ok(
         AttrTail::Typecast {
             value: "foo".into(),
-            type_modifiers: Some(vec![IntegerConst(1)]),
+            type_modifiers: Some(vec![IntegerConst(1).into()]),
         }
     ))]
     #[test_case("(1) over bar" => Ok(
         AttrTail::FuncTail {
             args: FuncArgsKind::All {
                 args: vec![Located(
-                    NamedValue::unnamed(IntegerConst(1)),
+                    NamedValue::unnamed(IntegerConst(1).into()),
                     Location::new(1..2, 1, 2)
                 )],
                 order: None
@@ -233,13 +233,13 @@ mod tests {
     #[test_case("within group (order by 1) filter (where 2) ignore nulls over foo",
         Some(Located(
             SortBy::new(
-                IntegerConst(1),
+                IntegerConst(1).into(),
                 None,
                 None
             ),
             Location::new(0..6, 1, 1)
         )),
-        Some(IntegerConst(2)),
+        Some(IntegerConst(2).into()),
         Some(NullTreatment::Ignore),
         Some("foo".into())
     )]
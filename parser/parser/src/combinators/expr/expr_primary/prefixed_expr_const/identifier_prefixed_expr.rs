@@ -9,6 +9,7 @@ pub(super) fn identifier_prefixed_expr(ctx: &mut ParserContext) -> scan::Result<
         )
     */
 
+    let start = ctx.stream_mut().current_location();
     let column_ref = column_ref(ctx)?;
 
     let name = match QualifiedName::try_from(column_ref) {
@@ -29,7 +30,8 @@ pub(super) fn identifier_prefixed_expr(ctx: &mut ParserContext) -> scan::Result<
         return Ok(expr.into())
     };
 
-    let expr = tailed_expr(name, tail);
+    let span = ctx.stream_mut().location_from(start).into();
+    let expr = tailed_expr(name, tail, span);
     Ok(expr)
 }
 
@@ -64,7 +66,7 @@ mod tests {
     use crate::test_parser;
     #[allow(unused_imports)]
     use pg_ast::{
-        ExprNode::{IntegerConst, StringConst},
+        ExprKind::{IntegerConst, StringConst},
         FuncArgsKind,
         FuncCall,
         FuncCallExpr,
@@ -157,7 +159,7 @@ mod tests {
             "123",
             TypeName::Generic {
                 name: vec!["foo".into()],
-                type_modifiers: Some(vec![IntegerConst(1)]),
+                type_modifiers: Some(vec![IntegerConst(1).into()]),
             }
         ).into()
     ))]
@@ -166,7 +168,7 @@ mod tests {
             "123",
             TypeName::Generic {
                 name: vec!["double".into()],
-                type_modifiers: Some(vec![IntegerConst(1)]),
+                type_modifiers: Some(vec![IntegerConst(1).into()]),
             }
         ).into()
     ))]
@@ -175,7 +177,7 @@ mod tests {
             "123",
             TypeName::Generic {
                 name: vec!["foo".into(), "bar".into()],
-                type_modifiers: Some(vec![IntegerConst(1)]),
+                type_modifiers: Some(vec![IntegerConst(1).into()]),
             }
         ).into()
     ))]
@@ -184,7 +186,7 @@ mod tests {
             "123",
             TypeName::Generic {
                 name: vec!["double".into(), "baz".into()],
-                type_modifiers: Some(vec![IntegerConst(1)]),
+                type_modifiers: Some(vec![IntegerConst(1).into()]),
             }
         ).into()
     ))]
@@ -193,7 +195,7 @@ mod tests {
             "123",
             TypeName::Generic {
                 name: vec!["between".into(), "qux".into()],
-                type_modifiers: Some(vec![IntegerConst(1)]),
+                type_modifiers: Some(vec![IntegerConst(1).into()]),
             }
         ).into()
     ))]
@@ -230,7 +232,7 @@ mod tests {
                 FuncArgsKind::Empty { order_within_group: None },
             )
         )
-        .with_agg_filter(IntegerConst(1))
+        .with_agg_filter(IntegerConst(1).into())
         .into()
     ))]
     #[test_case("between.qux() filter (where 1)" => Ok(
@@ -240,7 +242,7 @@ mod tests {
                 FuncArgsKind::Empty { order_within_group: None },
             )
         )
-        .with_agg_filter(IntegerConst(1))
+        .with_agg_filter(IntegerConst(1).into())
         .into()
     ))]
     fn test_identifier_prefixed_expr(source: &str) -> scan::Result<ExprNode> {
@@ -1,4 +1,5 @@
-pub(super) fn tailed_expr(name: Vec<Str>, tail: AttrTail) -> ExprNode {
+/// `span` is where the whole expression is, and it's only kept by function calls.
+pub(super) fn tailed_expr(name: Vec<Str>, tail: AttrTail, span: Span) -> ExprNode {
     match tail {
         AttrTail::Typecast { type_modifiers, value } => {
             // AexprConst
@@ -14,7 +15,9 @@ pub(super) fn tailed_expr(name: Vec<Str>, tail: AttrTail) -> ExprNode {
         },
         AttrTail::FuncTail { args, filter, null_treatment, over } => {
             // func_expr
-            let mut func_call = FuncCallExpr::from(FuncCall::new(name, args));
+            let mut func_call = FuncCallExpr::from(
+                FuncCall::new(name, args).with_span(span)
+            );
             func_call.set_agg_filter(filter)
                 .set_null_treatment(null_treatment)
                 .set_over(over);
@@ -28,7 +31,8 @@ mod tests {
     use super::*;
     #[allow(unused_imports)]
     use pg_ast::{
-        ExprNode::IntegerConst,
+        ExprKind,
+        ExprKind::IntegerConst,
         FuncArgsKind,
         FuncCallExpr,
         NullTreatment::Respect,
@@ -39,39 +43,39 @@ mod tests {
     #[test_case(
         AttrTail::Typecast {
             value: "123".into(),
-            type_modifiers: Some(vec![IntegerConst(234)]),
+            type_modifiers: Some(vec![IntegerConst(234).into()]),
         }
-        => ExprNode::from(
+        => ExprKind::from(
             StringTypecastExpr::new(
                 "123",
                 TypeName::Generic {
                     name: vec!["foo".into()],
-                    type_modifiers: Some(vec![IntegerConst(234)]),
+                    type_modifiers: Some(vec![IntegerConst(234).into()]),
                 }
             )
-        )
+        ).into()
     )]
     #[test_case(
         AttrTail::FuncTail {
             args: FuncArgsKind::Wildcard { order_within_group: None },
-            filter: Some(IntegerConst(123)),
+            filter: Some(IntegerConst(123).into()),
             null_treatment: Some(Respect),
             over: Some(WindowName("bar".into()))
         }
-        => ExprNode::from(
+        => ExprKind::from(
             FuncCallExpr::from(
                 FuncCall::new(
                     vec!["foo".into()],
                     FuncArgsKind::Wildcard { order_within_group: None }
                 )
             )
-            .with_agg_filter(IntegerConst(123))
+            .with_agg_filter(IntegerConst(123).into())
             .with_null_treatment(Respect)
             .with_over(WindowName("bar".into()))
-        )
+        ).into()
     )]
     fn test_tailed_expr(tail: AttrTail) -> ExprNode {
-        tailed_expr(vec!["foo".into()], tail)
+        tailed_expr(vec!["foo".into()], tail, Span::default())
     }
 }
 
@@ -79,6 +83,7 @@ use super::attr_tail::AttrTail;
 use pg_ast::ExprNode;
 use pg_ast::FuncCall;
 use pg_ast::FuncCallExpr;
+use pg_ast::Span;
 use pg_ast::StringTypecastExpr;
 use pg_ast::TypeName;
 use pg_basics::Str;
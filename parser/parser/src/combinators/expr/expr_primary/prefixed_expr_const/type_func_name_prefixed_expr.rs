@@ -9,10 +9,10 @@ pub(super) fn type_func_name_prefixed_expr(ctx: &mut ParserContext) -> scan::Res
         )
     */
 
-    let (kw, tail) = seq!(TypeFuncName, attr_tail).parse(ctx)?;
+    let Located((kw, tail), loc) = spanned!(seq!(TypeFuncName, attr_tail)).parse(ctx)?;
     let name = vec![Str::from(kw)];
 
-    let expr = tailed_expr(name, tail);
+    let expr = tailed_expr(name, tail, loc.into());
     Ok(expr)
 }
 
@@ -22,7 +22,7 @@ mod tests {
     use crate::test_parser;
     #[allow(unused_imports)]
     use pg_ast::{
-        ExprNode::{IntegerConst, StringConst},
+        ExprKind::{IntegerConst, StringConst},
         FuncArgsKind,
         FuncCall,
         FuncCallExpr,
@@ -45,7 +45,7 @@ mod tests {
             "foo",
             TypeName::Generic {
                 name: vec![Str::from("current_schema")],
-                type_modifiers: Some(vec![IntegerConst(1)]),
+                type_modifiers: Some(vec![IntegerConst(1).into()]),
             }
         ).into()
     ))]
@@ -56,7 +56,7 @@ mod tests {
                 FuncArgsKind::Empty { order_within_group: None }
             )
         )
-        .with_agg_filter(IntegerConst(1))
+        .with_agg_filter(IntegerConst(1).into())
         .into()
     ))]
     fn test_type_func_name_prefixed_expr(source: &str) -> scan::Result<ExprNode> {
@@ -68,8 +68,10 @@ use super::attr_tail;
 use super::tailed_expr;
 use crate::combinators::core::Combinator;
 use crate::seq;
+use crate::spanned;
 use crate::ParserContext;
 use pg_ast::ExprNode;
+use pg_basics::Located;
 use pg_basics::Str;
 use pg_lexer::KeywordCategory::TypeFuncName;
 use pg_parser_core::scan;
@@ -0,0 +1,64 @@
+/// Inlined:
+/// * `EXISTS select_with_parens`
+/// * `ARRAY select_with_parens`
+pub(super) fn sublink_expr(ctx: &mut ParserContext) -> scan::Result<ExprNode> {
+
+    /*
+          EXISTS select_with_parens
+        | ARRAY select_with_parens
+    */
+
+    // `EXISTS` is a column name keyword, so it's only a subquery when followed by `(`.
+    let kind = match ctx.stream_mut().peek2() {
+        Ok((K(Exists), Op(OpenParenthesis))) => SubLinkKind::Exists,
+        Ok((K(Array), Op(OpenParenthesis))) => SubLinkKind::Array,
+        _ => return no_match(ctx),
+    };
+
+    let (_, subselect) = seq!(skip(1), paren!(select_stmt))
+        .parse(ctx)?;
+
+    let sub_link = SubLink::new(kind, None, None, subselect);
+    Ok(sub_link.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        ExprKind::IntegerConst,
+        ResTarget,
+        SelectStmt,
+        SimpleSelect,
+    };
+    use test_case::test_case;
+
+    fn select(value: i32) -> SelectStmt {
+        SimpleSelect::new(vec![ResTarget::from(IntegerConst(value))]).into()
+    }
+
+    #[test_case("exists (select 1)", SubLink::exists(select(1)).into())]
+    #[test_case("array (select 1)", SubLink::array(select(1)).into())]
+    fn test_sublink_expr(source: &str, expected: ExprNode) {
+        test_parser!(source, sublink_expr, expected)
+    }
+}
+
+use crate::combinators::core::skip;
+use crate::combinators::core::Combinator;
+use crate::combinators::stmt::select_stmt;
+use crate::no_match;
+use crate::paren;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::ExprNode;
+use pg_ast::SubLink;
+use pg_ast::SubLinkKind;
+use pg_lexer::Keyword::Array;
+use pg_lexer::Keyword::Exists;
+use pg_lexer::OperatorKind::OpenParenthesis;
+use pg_parser_core::scan;
+use pg_parser_core::stream::TokenValue::Keyword as K;
+use pg_parser_core::stream::TokenValue::Operator as Op;
@@ -17,7 +17,7 @@ pub(super) fn cast_expr(ctx: &mut ParserContext) -> scan::Result<TypecastExpr> {
 mod tests {
     use super::*;
     use crate::test_parser;
-    use pg_ast::ExprNode;
+    use pg_ast::ExprKind;
     use pg_ast::TypeName::Varchar;
 
     #[test]
@@ -26,7 +26,7 @@ mod tests {
             source = "cast (1 as varchar)",
             parser = cast_expr,
             expected = TypecastExpr::new(
-                ExprNode::IntegerConst(1),
+                ExprKind::IntegerConst(1),
                 Varchar { max_length: None }
             )
         )
@@ -16,13 +16,13 @@ pub(super) fn coalesce_expr(ctx: &mut ParserContext) -> scan::Result<SqlFunction
 mod tests {
     use super::*;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::StringConst;
+    use pg_ast::ExprKind::StringConst;
     use test_case::test_case;
 
     #[test_case("coalesce('foo', 'bar')" => Ok(
         Coalesce(vec![
-            StringConst("foo".into()),
-            StringConst("bar".into())
+            StringConst("foo".into()).into(),
+            StringConst("bar".into()).into()
         ])
     ))]
     fn test_coalesce_expr(source: &str) -> scan::Result<SqlFunction> {
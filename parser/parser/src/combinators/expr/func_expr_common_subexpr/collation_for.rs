@@ -17,12 +17,12 @@ mod tests {
     use super::*;
     use crate::test_parser;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::StringConst;
+    use pg_ast::ExprKind::StringConst;
     use test_case::test_case;
 
     #[test_case("collation for ('foo')" => Ok(
         CollationFor(
-            StringConst("foo".into())
+            StringConst("foo".into()).into()
         )
     ))]
     fn test_collation_for(source: &str) -> scan::Result<SqlFunction> {
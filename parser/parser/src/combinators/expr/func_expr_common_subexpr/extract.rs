@@ -56,13 +56,13 @@ mod tests {
     use super::*;
     use crate::test_parser;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::{IntegerConst, StringConst};
+    use pg_ast::ExprKind::{IntegerConst, StringConst};
     use test_case::test_case;
 
     #[test_case("extract(year from 'foo')" => Ok(
         ExtractFunc::new(
             ExtractArg::Year,
-            StringConst("foo".into())
+            StringConst("foo".into()).into()
         )
     ))]
     fn test_extract_func(source: &str) -> scan::Result<ExtractFunc> {
@@ -71,15 +71,15 @@ mod tests {
 
     #[test_case("second from 1" => Ok(ExtractFunc::new(
         Second,
-        IntegerConst(1)
+        IntegerConst(1).into()
     )))]
     #[test_case("foo from 2" => Ok(ExtractFunc::new(
         Named("foo".into()),
-        IntegerConst(2)
+        IntegerConst(2).into()
     )))]
     #[test_case("'bar' from 'foo'" => Ok(ExtractFunc::new(
         Named("bar".into()),
-        StringConst("foo".into())
+        StringConst("foo".into()).into()
     )))]
     fn test_extract_args(source: &str) -> scan::Result<ExtractFunc> {
         test_parser!(source, extract_args)
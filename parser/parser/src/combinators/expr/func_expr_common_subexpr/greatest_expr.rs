@@ -16,13 +16,13 @@ pub(super) fn greatest_expr(ctx: &mut ParserContext) -> scan::Result<SqlFunction
 mod tests {
     use super::*;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::IntegerConst;
+    use pg_ast::ExprKind::IntegerConst;
     use test_case::test_case;
 
     #[test_case("greatest(1, 2)" => Ok(
         Greatest(vec![
-            IntegerConst(1),
-            IntegerConst(2)
+            IntegerConst(1).into(),
+            IntegerConst(2).into()
         ])
     ))]
     fn test_greatest_expr(source: &str) -> scan::Result<SqlFunction> {
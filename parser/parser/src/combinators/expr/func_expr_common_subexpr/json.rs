@@ -25,7 +25,7 @@ mod tests {
     use crate::test_parser;
     #[allow(unused_imports)]
     use pg_ast::{
-        ExprNode::StringConst,
+        ExprKind::StringConst,
         JsonEncoding,
         JsonFormat,
         JsonValueExpr,
@@ -41,7 +41,7 @@ mod tests {
     #[test_case("json('bar' format json encoding UTF8 with unique keys)" => Ok(
         JsonFunc::new(
             JsonValueExpr::new(
-                StringConst("bar".into()),
+                StringConst("bar".into()).into(),
                 JsonFormat::text()
                     .with_encoding(JsonEncoding::UTF8)
             ),
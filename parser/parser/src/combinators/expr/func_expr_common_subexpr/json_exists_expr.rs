@@ -44,19 +44,19 @@ mod tests {
     use test_case::test_case;
     #[allow(unused_imports)]
     use {
-        pg_ast::ExprNode::{IntegerConst, StringConst},
+        pg_ast::ExprKind::{IntegerConst, StringConst},
         pg_ast::JsonBehavior,
         pg_ast::JsonValueExpr,
     };
 
     #[test_case("json_exists('{}', 'foo')" => Ok(JsonExistsExpr::new(
         JsonValueExpr::from(StringConst("{}".into())),
-        StringConst("foo".into())
+        StringConst("foo".into()).into()
     )))]
     #[test_case("json_exists('{}', 'foo' passing 1 as a null on error)" => Ok(
         JsonExistsExpr::new(
             JsonValueExpr::from(StringConst("{}".into())),
-            StringConst("foo".into())
+            StringConst("foo".into()).into()
         )
         .with_passing(vec![
             ("a".into(), JsonValueExpr::from(IntegerConst(1)))
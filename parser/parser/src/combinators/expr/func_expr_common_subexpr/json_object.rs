@@ -103,7 +103,7 @@ mod tests {
     use test_case::test_case;
     #[allow(unused_imports)]
     use {
-        pg_ast::ExprNode::{IntegerConst, StringConst},
+        pg_ast::ExprKind::{IntegerConst, StringConst},
         pg_ast::JsonOutput,
         pg_ast::JsonValueExpr,
         pg_ast::NamedValue,
@@ -121,15 +121,15 @@ mod tests {
             .with_output(JsonOutput::from(Int4))
     )))]
     #[test_case("1, foo := 2, bar => 3" => Ok(ExplicitCall(Some(vec![
-        NamedValue::unnamed(IntegerConst(1)),
-        NamedValue::new(Some("foo".into()), IntegerConst(2)),
-        NamedValue::new(Some("bar".into()), IntegerConst(3)),
+        NamedValue::unnamed(IntegerConst(1).into()),
+        NamedValue::new(Some("foo".into()), IntegerConst(2).into()),
+        NamedValue::new(Some("bar".into()), IntegerConst(3).into()),
     ]))))]
     #[test_case("'bar': 2" => Ok(SqlSyntax(
         JsonObjectArgs::new()
             .with_exprs(vec![
                 JsonKeyValue::new(
-                    StringConst("bar".into()),
+                    StringConst("bar".into()).into(),
                     JsonValueExpr::from(IntegerConst(2))
                 )
             ])
@@ -139,7 +139,7 @@ mod tests {
         JsonObjectArgs::new()
             .with_exprs(vec![
                 JsonKeyValue::new(
-                    StringConst("baz".into()),
+                    StringConst("baz".into()).into(),
                     JsonValueExpr::from(IntegerConst(3))
                 )
             ])
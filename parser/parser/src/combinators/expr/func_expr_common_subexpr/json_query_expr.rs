@@ -53,7 +53,7 @@ mod tests {
     use test_case::test_case;
     #[allow(unused_imports)]
     use {
-        pg_ast::ExprNode::{IntegerConst, StringConst},
+        pg_ast::ExprKind::{IntegerConst, StringConst},
         pg_ast::JsonBehavior,
         pg_ast::JsonBehaviorClause,
         pg_ast::JsonQuotes,
@@ -65,13 +65,13 @@ mod tests {
     #[test_case("json_query('{}', 'foo')" => Ok(
         JsonQueryExpr::new(
             JsonValueExpr::from(StringConst("{}".into())),
-            StringConst("foo".into())
+            StringConst("foo".into()).into()
         )
     ))]
     #[test_case("json_query('{}', 'foo' passing 1 as a returning int with wrapper keep quotes error on empty)" => Ok(
         JsonQueryExpr::new(
             JsonValueExpr::from(StringConst("{}".into())),
-            StringConst("foo".into())
+            StringConst("foo".into()).into()
         )
         .with_passing(vec![
             ("a".into(), JsonValueExpr::from(IntegerConst(1)))
@@ -17,11 +17,11 @@ mod tests {
     use super::*;
     use crate::test_parser;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::IntegerConst;
+    use pg_ast::ExprKind::IntegerConst;
     use test_case::test_case;
 
     #[test_case("json_scalar(1)" => Ok(
-        JsonScalar(IntegerConst(1))
+        JsonScalar(IntegerConst(1).into())
     ))]
     fn test_json_scalar(source: &str) -> scan::Result<SqlFunction> {
         test_parser!(source, json_scalar)
@@ -47,7 +47,7 @@ mod tests {
     use test_case::test_case;
     #[allow(unused_imports)]
     use {
-        pg_ast::ExprNode::{IntegerConst, StringConst},
+        pg_ast::ExprKind::{IntegerConst, StringConst},
         pg_ast::JsonBehavior,
         pg_ast::JsonBehaviorClause,
         pg_ast::JsonOutput,
@@ -57,12 +57,12 @@ mod tests {
 
     #[test_case("json_value('{}', 'foo')" => Ok(JsonValueFunc::new(
         JsonValueExpr::from(StringConst("{}".into())),
-        StringConst("foo".into())
+        StringConst("foo".into()).into()
     )))]
     #[test_case("json_value('{}', 'foo' passing 1 as a returning int null on error)" => Ok(
         JsonValueFunc::new(
             JsonValueExpr::from(StringConst("{}".into())),
-            StringConst("foo".into())
+            StringConst("foo".into()).into()
         )
         .with_passing(vec![
             ("a".into(), JsonValueExpr::from(IntegerConst(1)))
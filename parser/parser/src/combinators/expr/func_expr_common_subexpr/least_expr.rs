@@ -18,14 +18,14 @@ mod tests {
     use test_case::test_case;
     #[allow(unused_imports)]
     use {
-        pg_ast::ExprNode::IntegerConst,
+        pg_ast::ExprKind::IntegerConst,
         scan::Error::NoMatch,
     };
 
     #[test_case("least(1, 2)" => Ok(
         Least(vec![
-            IntegerConst(1),
-            IntegerConst(2)
+            IntegerConst(1).into(),
+            IntegerConst(2).into()
         ])
     ))]
     fn test_greatest_expr(source: &str) -> scan::Result<SqlFunction> {
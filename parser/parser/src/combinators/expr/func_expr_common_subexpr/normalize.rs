@@ -28,19 +28,19 @@ mod tests {
     use test_case::test_case;
     #[allow(unused_imports)]
     use {
-        pg_ast::ExprNode::StringConst,
+        pg_ast::ExprKind::StringConst,
         pg_ast::UnicodeNormalForm::CanonicalComposition
     };
 
     #[test_case("normalize('foo')" => Ok(
         NormalizeFunc::new(
-            StringConst("foo".into()),
+            StringConst("foo".into()).into(),
             None
         )
     ))]
     #[test_case("normalize('foo', nfc)" => Ok(
         NormalizeFunc::new(
-            StringConst("foo".into()),
+            StringConst("foo".into()).into(),
             Some(CanonicalComposition)
         )
     ))]
@@ -19,14 +19,14 @@ mod tests {
     use super::*;
     use crate::test_parser;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::{NullConst, StringConst};
+    use pg_ast::ExprKind::{NullConst, StringConst};
     use pg_ast::SqlFunction;
     use test_case::test_case;
 
     #[test_case("nullif(null, 'foo')" => Ok(
         NullIf(
-            NullConst,
-            StringConst("foo".into())
+            NullConst.into(),
+            StringConst("foo".into()).into()
         )
     ))]
     fn test_nullif_expr(source: &str) -> scan::Result<SqlFunction> {
@@ -30,7 +30,7 @@ fn overlay_args(ctx: &mut ParserContext) -> scan::Result<OverlayFunc> {
         && arg.name().is_none()
         && let Some((placing, from, r#for)) = overlay_list(ctx).optional()?
     {
-        let (_, arg) = mem::replace(arg, NamedValue::unnamed(NullConst)).into();
+        let (_, arg) = mem::replace(arg, NamedValue::unnamed(NullConst.into())).into();
         let args = OverlaySqlArgs::new(arg, placing, from, r#for);
         let args = OverlayFunc::SqlSyntax(args);
         return Ok(args);
@@ -57,14 +57,14 @@ mod tests {
     use super::*;
     use crate::test_parser;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::{IntegerConst, StringConst};
+    use pg_ast::ExprKind::{IntegerConst, StringConst};
     use test_case::test_case;
 
     #[test_case("overlay()" => Ok(OverlayFunc::ExplicitCall(None)))]
     #[test_case("overlay(1)" => Ok(
         OverlayFunc::ExplicitCall(
             Some(vec![
-                NamedValue::unnamed(IntegerConst(1))
+                NamedValue::unnamed(IntegerConst(1).into())
             ])
         )
     ))]
@@ -75,25 +75,25 @@ mod tests {
     #[test_case("'foo'" => Ok(
         OverlayFunc::ExplicitCall(
             Some(vec![
-                NamedValue::unnamed(StringConst("foo".into()))
+                NamedValue::unnamed(StringConst("foo".into()).into())
             ])
         )
     ))]
     #[test_case("'foo', bar := 1, baz => 2" => Ok(
         OverlayFunc::ExplicitCall(
             Some(vec![
-                NamedValue::unnamed(StringConst("foo".into())),
-                NamedValue::new(Some("bar".into()), IntegerConst(1)),
-                NamedValue::new(Some("baz".into()), IntegerConst(2)),
+                NamedValue::unnamed(StringConst("foo".into()).into()),
+                NamedValue::new(Some("bar".into()), IntegerConst(1).into()),
+                NamedValue::new(Some("baz".into()), IntegerConst(2).into()),
             ])
         )
     ))]
     #[test_case("'foo' placing 'bar' from 1" => Ok(
         OverlayFunc::SqlSyntax(
             OverlaySqlArgs::new(
-                StringConst("foo".into()),
-                StringConst("bar".into()),
-                IntegerConst(1),
+                StringConst("foo".into()).into(),
+                StringConst("bar".into()).into(),
+                IntegerConst(1).into(),
                 None
             )
         )
@@ -101,10 +101,10 @@ mod tests {
     #[test_case("'foo' placing 'bar' from 1 for 2" => Ok(
         OverlayFunc::SqlSyntax(
             OverlaySqlArgs::new(
-                StringConst("foo".into()),
-                StringConst("bar".into()),
-                IntegerConst(1),
-                Some(IntegerConst(2))
+                StringConst("foo".into()).into(),
+                StringConst("bar".into()).into(),
+                IntegerConst(1).into(),
+                Some(IntegerConst(2).into())
             )
         )
     ))]
@@ -122,8 +122,8 @@ use crate::paren;
 use crate::seq;
 use crate::ParserContext;
 use core::mem;
+use pg_ast::ExprKind::NullConst;
 use pg_ast::ExprNode;
-use pg_ast::ExprNode::NullConst;
 use pg_ast::NamedValue;
 use pg_ast::OverlayFunc;
 use pg_ast::OverlaySqlArgs;
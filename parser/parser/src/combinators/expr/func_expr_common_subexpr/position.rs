@@ -25,13 +25,13 @@ mod tests {
     use super::*;
     use crate::test_parser;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::StringConst;
+    use pg_ast::ExprKind::StringConst;
     use test_case::test_case;
 
     #[test_case("position('f' in 'foo')" => Ok(
         PositionFunc::new(
-            StringConst("f".into()),
-            StringConst("foo".into())
+            StringConst("f".into()).into(),
+            StringConst("foo".into()).into()
         )
     ))]
     fn test_position(source: &str) -> scan::Result<PositionFunc> {
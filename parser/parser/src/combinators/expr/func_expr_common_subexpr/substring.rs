@@ -30,7 +30,7 @@ fn substring_args(ctx: &mut ParserContext) -> scan::Result<SubstringFunc> {
         && arg.name().is_none()
         && let Some((from, r#for)) = substring_list(ctx).optional()?
     {
-        let (_, arg) = mem::replace(arg, NamedValue::unnamed(NullConst)).into();
+        let (_, arg) = mem::replace(arg, NamedValue::unnamed(NullConst.into())).into();
         let args = SubstringFunc::SqlSyntax(arg, from, r#for);
         return Ok(args)
     }
@@ -113,7 +113,7 @@ fn for_from_args(ctx: &mut ParserContext) -> scan::Result<(ExprNode, Option<Expr
                 is unknown or doesn't have an implicit cast to int4.
             */
 
-            let from = IntegerConst(1);
+            let from = IntegerConst(1).into();
             let r#for = Typecast(TypecastExpr::new(r#for, TypeName::Int4)).into();
             (from, Some(r#for))
         },
@@ -127,7 +127,7 @@ mod tests {
     use super::*;
     use crate::test_parser;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::{IntegerConst, StringConst};
+    use pg_ast::ExprKind::{IntegerConst, StringConst};
     use test_case::test_case;
 
     #[test_case("substring()" => Ok(
@@ -136,7 +136,7 @@ mod tests {
     #[test_case("substring('foo')" => Ok(
         SubstringFunc::ExplicitCall(
             Some(vec![
-                NamedValue::unnamed(StringConst("foo".into()))
+                NamedValue::unnamed(StringConst("foo".into()).into())
             ])
         )
     ))]
@@ -146,47 +146,47 @@ mod tests {
 
     #[test_case("'foo'" => Ok(
         SubstringFunc::ExplicitCall(Some(vec![
-            NamedValue::unnamed(StringConst("foo".into())),
+            NamedValue::unnamed(StringConst("foo".into()).into()),
         ]))
     ))]
     #[test_case("'foo', bar => 1" => Ok(
         SubstringFunc::ExplicitCall(Some(vec![
-            NamedValue::unnamed(StringConst("foo".into())),
-            NamedValue::new(Some("bar".into()), IntegerConst(1))
+            NamedValue::unnamed(StringConst("foo".into()).into()),
+            NamedValue::new(Some("bar".into()), IntegerConst(1).into())
         ]))
     ))]
     #[test_case("'foo' similar 'bar' escape 'baz'" => Ok(
         SubstringFunc::SqlSyntax(
-            StringConst("foo".into()),
-            StringConst("bar".into()),
-            Some(StringConst("baz".into()))
+            StringConst("foo".into()).into(),
+            StringConst("bar".into()).into(),
+            Some(StringConst("baz".into()).into())
         )
     ))]
     #[test_case("'foo' from 1 for 2" => Ok(
         SubstringFunc::SqlSyntax(
-            StringConst("foo".into()),
-            IntegerConst(1),
-            Some(IntegerConst(2))
+            StringConst("foo".into()).into(),
+            IntegerConst(1).into(),
+            Some(IntegerConst(2).into())
         )
     ))]
     #[test_case("'foo' for 2 from 1" => Ok(
         SubstringFunc::SqlSyntax(
-            StringConst("foo".into()),
-            IntegerConst(1),
-            Some(IntegerConst(2))
+            StringConst("foo".into()).into(),
+            IntegerConst(1).into(),
+            Some(IntegerConst(2).into())
         )
     ))]
     #[test_case("'foo' from 1" => Ok(
         SubstringFunc::SqlSyntax(
-            StringConst("foo".into()),
-            IntegerConst(1),
+            StringConst("foo".into()).into(),
+            IntegerConst(1).into(),
             None
         )
     ))]
     #[test_case("'foo' for 2" => Ok(
         SubstringFunc::SqlSyntax(
-            StringConst("foo".into()),
-            IntegerConst(1),
+            StringConst("foo".into()).into(),
+            IntegerConst(1).into(),
             Some(
                 Typecast(TypecastExpr::new(
                     IntegerConst(2),
@@ -209,9 +209,9 @@ use crate::paren;
 use crate::seq;
 use crate::ParserContext;
 use core::mem;
+use pg_ast::ExprKind::IntegerConst;
+use pg_ast::ExprKind::NullConst;
 use pg_ast::ExprNode;
-use pg_ast::ExprNode::IntegerConst;
-use pg_ast::ExprNode::NullConst;
 use pg_ast::NamedValue;
 use pg_ast::SqlFunction::Typecast;
 use pg_ast::SubstringFunc;
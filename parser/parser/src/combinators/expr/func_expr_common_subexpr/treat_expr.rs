@@ -29,7 +29,7 @@ mod tests {
     use test_case::test_case;
     #[allow(unused_imports)]
     use {
-        pg_ast::ExprNode::IntegerConst,
+        pg_ast::ExprKind::IntegerConst,
         pg_ast::TypeName::Int4,
     };
 
@@ -76,13 +76,13 @@ fn trim_list(ctx: &mut ParserContext) -> scan::Result<Vec<ExprNode>> {
 mod tests {
     use super::*;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::StringConst;
+    use pg_ast::ExprKind::StringConst;
     use test_case::test_case;
 
     #[test_case("trim('foo' from 'bar')" => Ok(
         TrimFunc::new(
             Both,
-            vec![StringConst("bar".into()), StringConst("foo".into())]
+            vec![StringConst("bar".into()).into(), StringConst("foo".into()).into()]
         )
     ))]
     fn test_trim(source: &str) -> scan::Result<TrimFunc> {
@@ -92,48 +92,48 @@ mod tests {
 
     #[test_case("leading from 'foo'" => Ok(TrimFunc::new(
         Leading,
-        vec![StringConst("foo".into())]
+        vec![StringConst("foo".into()).into()]
     )))]
     #[test_case("trailing 'foo' from 'bar'" => Ok(TrimFunc::new(
         Trailing,
-        vec![StringConst("bar".into()), StringConst("foo".into())]
+        vec![StringConst("bar".into()).into(), StringConst("foo".into()).into()]
     )))]
     #[test_case("both 'foo'" => Ok(TrimFunc::new(
         Both,
-        vec![StringConst("foo".into())]
+        vec![StringConst("foo".into()).into()]
     )))]
     #[test_case("'foo', 'bar'" => Ok(TrimFunc::new(
         Both,
-        vec![StringConst("foo".into()), StringConst("bar".into())]
+        vec![StringConst("foo".into()).into(), StringConst("bar".into()).into()]
     )))]
     fn test_trim_args(source: &str) -> scan::Result<TrimFunc> {
         let mut ctx = ParserContext::new(source);
         trim_args(&mut ctx)
     }
 
-    #[test_case("from 'foo'" => Ok(vec![StringConst("foo".into())]))]
+    #[test_case("from 'foo'" => Ok(vec![StringConst("foo".into()).into()]))]
     #[test_case("from 'foo', 'bar'" => Ok(vec![
-        StringConst("foo".into()),
-        StringConst("bar".into())
+        StringConst("foo".into()).into(),
+        StringConst("bar".into()).into()
     ]))]
-    #[test_case("'foo'" => Ok(vec![StringConst("foo".into())]))]
+    #[test_case("'foo'" => Ok(vec![StringConst("foo".into()).into()]))]
     #[test_case("'foo' from 'bar'" => Ok(vec![
-        StringConst("bar".into()),
-        StringConst("foo".into())
+        StringConst("bar".into()).into(),
+        StringConst("foo".into()).into()
     ]))]
     #[test_case("'foo' from 'bar', 'baz'" => Ok(vec![
-        StringConst("bar".into()),
-        StringConst("baz".into()),
-        StringConst("foo".into())
+        StringConst("bar".into()).into(),
+        StringConst("baz".into()).into(),
+        StringConst("foo".into()).into()
     ]))]
     #[test_case("'foo', 'bar'" => Ok(vec![
-        StringConst("foo".into()),
-        StringConst("bar".into())
+        StringConst("foo".into()).into(),
+        StringConst("bar".into()).into()
     ]))]
     #[test_case("'foo', 'bar', 'baz'" => Ok(vec![
-        StringConst("foo".into()),
-        StringConst("bar".into()),
-        StringConst("baz".into()),
+        StringConst("foo".into()).into(),
+        StringConst("bar".into()).into(),
+        StringConst("baz".into()).into(),
     ]))]
     fn test_trim_list(source: &str) -> scan::Result<Vec<ExprNode>> {
         let mut ctx = ParserContext::new(source);
@@ -16,14 +16,14 @@ pub(super) fn xml_concat(ctx: &mut ParserContext) -> scan::Result<SqlFunction> {
 mod tests {
     use super::*;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::StringConst;
+    use pg_ast::ExprKind::StringConst;
     use pg_ast::SqlFunction;
     use test_case::test_case;
 
     #[test_case("xmlconcat('foo', 'bar')" => Ok(
         XmlConcat(vec![
-            StringConst("foo".into()),
-            StringConst("bar".into()),
+            StringConst("foo".into()).into(),
+            StringConst("bar".into()).into(),
         ])
     ))]
     fn test_xml_concat(source: &str) -> scan::Result<SqlFunction> {
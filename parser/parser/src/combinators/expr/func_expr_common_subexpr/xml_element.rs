@@ -77,7 +77,7 @@ fn xml_attributes(ctx: &mut ParserContext) -> scan::Result<Vec<NamedValue>> {
 mod tests {
     use super::*;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::{IntegerConst, StringConst};
+    use pg_ast::ExprKind::{IntegerConst, StringConst};
     use test_case::test_case;
 
     #[test_case("xmlelement(name foo)" => Ok(
@@ -86,16 +86,16 @@ mod tests {
     #[test_case("xmlelement(name foo, xmlattributes(1 as a), 'foo')" => Ok(
         XmlElement::new("foo".into())
             .with_attributes(vec![
-                NamedValue::new(Some("a".into()), IntegerConst(1))
+                NamedValue::new(Some("a".into()), IntegerConst(1).into())
             ])
             .with_content(vec![
-                StringConst("foo".into())
+                StringConst("foo".into()).into()
             ])
     ))]
     #[test_case("xmlelement(name foo, 'bar')" => Ok(
         XmlElement::new("foo".into())
             .with_content(vec![
-                StringConst("bar".into())
+                StringConst("bar".into()).into()
             ])
     ))]
     fn test_xml_element(source: &str) -> scan::Result<XmlElement> {
@@ -104,8 +104,8 @@ mod tests {
     }
 
     #[test_case("xmlattributes(1, 2 as x)" => Ok(vec![
-        NamedValue::unnamed(IntegerConst(1)),
-        NamedValue::new(Some("x".into()), IntegerConst(2)),
+        NamedValue::unnamed(IntegerConst(1).into()),
+        NamedValue::new(Some("x".into()), IntegerConst(2).into()),
     ]))]
     fn test_xml_attributes(source: &str) -> scan::Result<Vec<NamedValue>> {
         let mut ctx = ParserContext::new(source);
@@ -113,12 +113,12 @@ mod tests {
     }
 }
 
-use super::labeled_expr_list;
 use crate::alt;
 use crate::combinators::col_label;
 use crate::combinators::core::skip;
 use crate::combinators::core::Combinator;
 use crate::combinators::expr_list;
+use crate::combinators::labeled_expr_list;
 use crate::paren;
 use crate::seq;
 use crate::ParserContext;
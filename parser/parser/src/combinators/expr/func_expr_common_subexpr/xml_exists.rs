@@ -20,13 +20,13 @@ mod tests {
     use super::*;
     use crate::test_parser;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::StringConst;
+    use pg_ast::ExprKind::StringConst;
     use test_case::test_case;
 
     #[test_case("xmlexists('foo' passing 'bar')" => Ok(
         XmlExists::new(
-            StringConst("foo".into()),
-            StringConst("bar".into())
+            StringConst("foo".into()).into(),
+            StringConst("bar".into()).into()
         )
     ))]
     fn test_xml_exists(source: &str) -> scan::Result<XmlExists> {
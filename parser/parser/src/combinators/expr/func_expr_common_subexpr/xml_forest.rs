@@ -19,14 +19,14 @@ mod tests {
     use test_case::test_case;
     #[allow(unused_imports)]
     use {
-        pg_ast::ExprNode::StringConst,
+        pg_ast::ExprKind::StringConst,
         pg_ast::NamedValue,
     };
 
     #[test_case("xmlforest('foo', 'bar' as baz)" => Ok(
         XmlForest(vec![
-            NamedValue::unnamed(StringConst("foo".into())),
-            NamedValue::new(Some("baz".into()), StringConst("bar".into())),
+            NamedValue::unnamed(StringConst("foo".into()).into()),
+            NamedValue::new(Some("baz".into()), StringConst("bar".into()).into()),
         ])
     ))]
     fn test_xml_forest(source: &str) -> scan::Result<SqlFunction> {
@@ -34,9 +34,9 @@ mod tests {
     }
 }
 
-use super::labeled_expr_list;
 use crate::combinators::core::skip;
 use crate::combinators::core::Combinator;
+use crate::combinators::labeled_expr_list;
 use crate::paren;
 use crate::seq;
 use crate::ParserContext;
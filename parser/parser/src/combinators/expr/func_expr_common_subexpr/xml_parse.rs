@@ -48,21 +48,21 @@ mod tests {
     use test_case::test_case;
     #[allow(unused_imports)]
     use {
-        pg_ast::ExprNode::StringConst,
+        pg_ast::ExprKind::StringConst,
         pg_ast::XmlNodeKind,
     };
 
     #[test_case("xmlparse(content 'foo')" => Ok(
         XmlParse::new(
             XmlNodeKind::Content,
-            StringConst("foo".into()),
+            StringConst("foo".into()).into(),
             Strip
         )
     ))]
     #[test_case("xmlparse(document 'foo' preserve whitespace)" => Ok(
         XmlParse::new(
             XmlNodeKind::Document,
-            StringConst("foo".into()),
+            StringConst("foo".into()).into(),
             Preserve
         )
     ))]
@@ -33,7 +33,7 @@ fn xml_root_version(ctx: &mut ParserContext) -> scan::Result<ExprNode> {
 
     /*
         VERSION (
-            | NO VALUE // => ExprNode::NullConst
+            | NO VALUE // => ExprKind::NullConst
             | a_expr
         )
     */
@@ -60,7 +60,7 @@ fn version_no_value(ctx: &mut ParserContext) -> scan::Result<ExprNode> {
     }
 
     ctx.stream_mut().skip(2);
-    Ok(ExprNode::NullConst)
+    Ok(ExprKind::NullConst.into())
 }
 
 /// Alias: `opt_xml_root_standalone`
@@ -92,28 +92,28 @@ mod tests {
     use super::*;
     use crate::test_parser;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::{NullConst, StringConst};
+    use pg_ast::ExprKind::{NullConst, StringConst};
     use test_case::test_case;
 
     #[test_case("xmlroot('foo', version '1.0', standalone yes)" => Ok(
         XmlRoot::new(
-            StringConst("foo".into()),
-            StringConst("1.0".into())
+            StringConst("foo".into()).into(),
+            StringConst("1.0".into()).into()
         )
             .with_standalone(XmlStandalone::Yes)
     ))]
     #[test_case("xmlroot('foo', version no value)" => Ok(
         XmlRoot::new(
-            StringConst("foo".into()),
-            NullConst
+            StringConst("foo".into()).into(),
+            NullConst.into()
         )
     ))]
     fn test_xml_root(source: &str) -> scan::Result<XmlRoot> {
         test_parser!(source, xml_root)
     }
 
-    #[test_case("version '1.0'" => Ok(StringConst("1.0".into())))]
-    #[test_case("version no value" => Ok(NullConst))]
+    #[test_case("version '1.0'" => Ok(StringConst("1.0".into()).into()))]
+    #[test_case("version no value" => Ok(NullConst.into()))]
     fn test_xml_root_version(source: &str) -> scan::Result<ExprNode> {
         test_parser!(source, xml_root_version)
     }
@@ -134,6 +134,7 @@ use crate::no_match;
 use crate::paren;
 use crate::seq;
 use crate::ParserContext;
+use pg_ast::ExprKind;
 use pg_ast::ExprNode;
 use pg_ast::XmlRoot;
 use pg_ast::XmlStandalone;
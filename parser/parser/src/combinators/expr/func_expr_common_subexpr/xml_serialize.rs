@@ -50,7 +50,7 @@ mod tests {
     use test_case::test_case;
     #[allow(unused_imports)]
     use {
-        pg_ast::ExprNode::StringConst,
+        pg_ast::ExprKind::StringConst,
         pg_ast::TypeName::{Int4, Int8},
         pg_ast::XmlNodeKind,
     };
@@ -58,14 +58,14 @@ mod tests {
     #[test_case("xmlserialize(content 'foo' as int)" => Ok(
         XmlSerialize::new(
             XmlNodeKind::Content,
-            StringConst("foo".into()),
+            StringConst("foo".into()).into(),
             Int4
         )
     ))]
     #[test_case("xmlserialize(document 'bar' as bigint indent)" => Ok(
         XmlSerialize::new(
             XmlNodeKind::Document,
-            StringConst("bar".into()),
+            StringConst("bar".into()).into(),
             Int8
         )
         .with_indent(true)
@@ -105,7 +105,7 @@ mod tests {
     use super::*;
     use crate::test_parser;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::IntegerConst;
+    use pg_ast::ExprKind::IntegerConst;
     use pg_ast::Indirection;
     use pg_ast::Indirection::Property;
     use pg_ast::Indirection::Slice;
@@ -115,10 +115,10 @@ mod tests {
     #[test_case(".*", Wildcard)]
     #[test_case(".some_property", Property("some_property".into()))]
     #[test_case("[:]", Slice(None, None))]
-    #[test_case("[:1]", Slice(None, Some(IntegerConst(1))))]
-    #[test_case("[2]", Index(IntegerConst(2)))]
-    #[test_case("[3:]", Slice(Some(IntegerConst(3)), None))]
-    #[test_case("[4:5]", Slice(Some(IntegerConst(4)), Some(IntegerConst(5))))]
+    #[test_case("[:1]", Slice(None, Some(IntegerConst(1).into())))]
+    #[test_case("[2]", Index(IntegerConst(2).into()))]
+    #[test_case("[3:]", Slice(Some(IntegerConst(3).into()), None))]
+    #[test_case("[4:5]", Slice(Some(IntegerConst(4).into()), Some(IntegerConst(5).into())))]
     fn test_indirection_el(source: &str, expected: Indirection) {
         test_parser!(source, indirection_el, expected)
     }
@@ -1,6 +1,7 @@
 mod associativity;
 
 pg_basics::reexport! { pub(super)
+    a_expr,
     expr_primary,
     func_expr_common_subexpr,
 }
@@ -10,17 +11,3 @@ pg_basics::reexport! {
     indirection,
     unicode_normal_form,
 }
-
-pub(super) fn a_expr(ctx: &mut ParserContext) -> scan::Result<ExprNode> {
-    // TODO
-    expr_primary(ctx)
-}
-
-pub(super) fn b_expr(ctx: &mut ParserContext) -> scan::Result<ExprNode> {
-    // TODO
-    expr_primary(ctx)
-}
-
-use crate::ParserContext;
-use pg_ast::ExprNode;
-use pg_parser_core::scan;
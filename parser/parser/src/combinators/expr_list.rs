@@ -11,6 +11,7 @@ pub(crate) fn expr_list(ctx: &mut ParserContext) -> scan::Result<Vec<ExprNode>>
 mod tests {
     use super::*;
     use crate::test_parser;
+    use pg_ast::ExprKind;
 
     #[test]
     fn test_expr_list() {
@@ -18,9 +19,9 @@ mod tests {
             source = "1, 2, 3",
             parser = expr_list,
             expected = vec![
-                ExprNode::IntegerConst(1),
-                ExprNode::IntegerConst(2),
-                ExprNode::IntegerConst(3),
+                ExprKind::IntegerConst(1).into(),
+                ExprKind::IntegerConst(2).into(),
+                ExprKind::IntegerConst(3).into(),
             ]
         )
     }
@@ -4,10 +4,10 @@ pub(super) fn func_application(ctx: &mut ParserContext) -> scan::Result<FuncCall
         func_name func_application_args
     */
 
-    let (name, args) = seq!(func_name, func_application_args)
+    let Located((name, args), loc) = spanned!(seq!(func_name, func_application_args))
         .parse(ctx)?;
 
-    let func_call = FuncCall::new(name, args);
+    let func_call = FuncCall::new(name, args).with_span(loc.into());
     Ok(func_call)
 }
 
@@ -187,7 +187,7 @@ fn variadic_arg(ctx: &mut ParserContext) -> scan::Result<(Located<NamedValue>, O
 mod tests {
     use super::*;
     use crate::test_parser;
-    use pg_ast::ExprNode::IntegerConst;
+    use pg_ast::ExprKind::IntegerConst;
     use test_case::test_case;
 
     #[test]
@@ -205,13 +205,13 @@ mod tests {
     #[test_case("(*)" => Ok(Wildcard { order_within_group: None }))]
     #[test_case("(distinct 1, 2)" => Ok(Distinct {
         args: vec![
-            NamedValue::unnamed(IntegerConst(1)),
-            NamedValue::unnamed(IntegerConst(2)),
+            NamedValue::unnamed(IntegerConst(1).into()),
+            NamedValue::unnamed(IntegerConst(2).into()),
         ],
         order: None
     }))]
     #[test_case("(variadic 1)" => Ok(Variadic {
-        args: vec![NamedValue::unnamed(IntegerConst(1))],
+        args: vec![NamedValue::unnamed(IntegerConst(1).into())],
         order: None
     }))]
     #[test_case("()" => Ok(Empty { order_within_group: None }))]
@@ -234,8 +234,8 @@ mod tests {
 
         assert_eq!(
             vec![
-                NamedValue::unnamed(IntegerConst(1)),
-                NamedValue::unnamed(IntegerConst(2)),
+                NamedValue::unnamed(IntegerConst(1).into()),
+                NamedValue::unnamed(IntegerConst(2).into()),
             ],
             args
         )
@@ -256,9 +256,9 @@ mod tests {
 
         assert_eq!(
             vec![
-                NamedValue::unnamed(IntegerConst(1)),
-                NamedValue::unnamed(IntegerConst(2)),
-                NamedValue::unnamed(IntegerConst(3)),
+                NamedValue::unnamed(IntegerConst(1).into()),
+                NamedValue::unnamed(IntegerConst(2).into()),
+                NamedValue::unnamed(IntegerConst(3).into()),
             ],
             args
         )
@@ -266,17 +266,17 @@ mod tests {
 
     #[test_case("1, 2, variadic 3" => Ok((
         vec![
-            NamedValue::unnamed(IntegerConst(1)),
-            NamedValue::unnamed(IntegerConst(2)),
-            NamedValue::unnamed(IntegerConst(3)),
+            NamedValue::unnamed(IntegerConst(1).into()),
+            NamedValue::unnamed(IntegerConst(2).into()),
+            NamedValue::unnamed(IntegerConst(3).into()),
         ],
         true
     )))]
     #[test_case("1, 2, 3" => Ok((
         vec![
-            NamedValue::unnamed(IntegerConst(1)),
-            NamedValue::unnamed(IntegerConst(2)),
-            NamedValue::unnamed(IntegerConst(3)),
+            NamedValue::unnamed(IntegerConst(1).into()),
+            NamedValue::unnamed(IntegerConst(2).into()),
+            NamedValue::unnamed(IntegerConst(3).into()),
         ],
         false
     )))]
@@ -306,29 +306,29 @@ mod tests {
                         .collect::<Vec<_>>()
                 ),
             expected = vec![
-                NamedValue::unnamed(IntegerConst(1)),
-                NamedValue::unnamed(IntegerConst(2)),
-                NamedValue::unnamed(IntegerConst(3)),
-                NamedValue::new(Some("foo".into()), IntegerConst(4)),
-                NamedValue::new(Some("bar".into()), IntegerConst(5)),
+                NamedValue::unnamed(IntegerConst(1).into()),
+                NamedValue::unnamed(IntegerConst(2).into()),
+                NamedValue::unnamed(IntegerConst(3).into()),
+                NamedValue::new(Some("foo".into()), IntegerConst(4).into()),
+                NamedValue::new(Some("bar".into()), IntegerConst(5).into()),
             ]
         )
     }
 
     #[test_case("1" => Ok((
-        NamedValue::unnamed(IntegerConst(1)),
+        NamedValue::unnamed(IntegerConst(1).into()),
         false
     )))]
     #[test_case("VARIADIC 2" => Ok((
-        NamedValue::unnamed(IntegerConst(2)),
+        NamedValue::unnamed(IntegerConst(2).into()),
         true
     )))]
     #[test_case("foo := 3" => Ok((
-        NamedValue::new(Some("foo".into()), IntegerConst(3)),
+        NamedValue::new(Some("foo".into()), IntegerConst(3).into()),
         false
     )))]
     #[test_case("VARIADIC bar => 4" => Ok((
-        NamedValue::new(Some("bar".into()), IntegerConst(4)),
+        NamedValue::new(Some("bar".into()), IntegerConst(4).into()),
         true
     )))]
     fn test_variadic_arg(source: &str) -> scan::Result<(NamedValue, bool)> {
@@ -349,6 +349,7 @@ use crate::located;
 use crate::many;
 use crate::paren;
 use crate::seq;
+use crate::spanned;
 use crate::ParserContext;
 use pg_ast::FuncArgsKind;
 use pg_ast::FuncArgsKind::All;
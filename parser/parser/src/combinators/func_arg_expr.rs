@@ -49,12 +49,12 @@ mod tests {
     use super::*;
     use crate::test_parser;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::IntegerConst;
+    use pg_ast::ExprKind::IntegerConst;
     use test_case::test_case;
 
-    #[test_case("1" => Ok(NamedValue::unnamed(IntegerConst(1))))]
-    #[test_case("foo := 2" => Ok(NamedValue::new(Some("foo".into()), IntegerConst(2))))]
-    #[test_case("bar => 3" => Ok(NamedValue::new(Some("bar".into()), IntegerConst(3))))]
+    #[test_case("1" => Ok(NamedValue::unnamed(IntegerConst(1).into())))]
+    #[test_case("foo := 2" => Ok(NamedValue::new(Some("foo".into()), IntegerConst(2).into())))]
+    #[test_case("bar => 3" => Ok(NamedValue::new(Some("bar".into()), IntegerConst(3).into())))]
     fn test_func_arg_expr(source: &str) -> scan::Result<NamedValue> {
         test_parser!(
             source,
@@ -0,0 +1,66 @@
+/// Inlined: `generated_when`
+// TODO: Remove the `allow` once column constraints (`ColConstraintElem`) are parsed.
+#[allow(dead_code)]
+pub(super) fn identity_constraint(ctx: &mut ParserContext) -> scan::Result<IdentityConstraint> {
+
+    /*
+        GENERATED ( ALWAYS | BY DEFAULT ) AS IDENTITY ( OptParenthesizedSeqOptList )?
+    */
+
+    let (_, generated, _, _, options) = seq!(
+        Generated,
+        alt!(
+            Always.map(|_| GeneratedWhen::Always),
+            seq!(By, DefaultKw).map(|_| GeneratedWhen::ByDefault)
+        ),
+        As,
+        Identity,
+        parenthesized_seq_opt_list.optional()
+    ).parse(ctx)?;
+
+    let constraint = IdentityConstraint::new(generated, options);
+    Ok(constraint)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        SeqOptElem::{Increment, Start},
+        SignedNumber::IntegerConst,
+    };
+    use test_case::test_case;
+
+    #[test_case("generated always as identity",
+        IdentityConstraint::new(GeneratedWhen::Always, None)
+    )]
+    #[test_case("generated by default as identity (start with 10 increment by 5)",
+        IdentityConstraint::new(
+            GeneratedWhen::ByDefault,
+            Some(vec![
+                Start(IntegerConst(10)),
+                Increment(IntegerConst(5)),
+            ])
+        )
+    )]
+    fn test_identity_constraint(source: &str, expected: IdentityConstraint) {
+        test_parser!(source, identity_constraint, expected)
+    }
+}
+
+use crate::alt;
+use crate::combinators::core::Combinator;
+use crate::combinators::parenthesized_seq_opt_list;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::GeneratedWhen;
+use pg_ast::IdentityConstraint;
+use pg_lexer::Keyword::Always;
+use pg_lexer::Keyword::As;
+use pg_lexer::Keyword::By;
+use pg_lexer::Keyword::DefaultKw;
+use pg_lexer::Keyword::Generated;
+use pg_lexer::Keyword::Identity;
+use pg_parser_core::scan;
@@ -101,7 +101,7 @@ mod tests {
     use test_case::test_case;
     #[allow(unused_imports)]
     use {
-        pg_ast::ExprNode::{IntegerConst, StringConst},
+        pg_ast::ExprKind::{IntegerConst, StringConst},
         pg_ast::JsonKeyValue,
         pg_ast::JsonOutput,
         pg_ast::JsonValueExpr,
@@ -113,7 +113,7 @@ mod tests {
     #[test_case("json_objectagg('foo': 1)" => Ok(JsonAggFunc::Object(
         JsonObjectAgg::new(
             JsonKeyValue::new(
-                StringConst("foo".into()),
+                StringConst("foo".into()).into(),
                 JsonValueExpr::from(IntegerConst(1))
             ),
             None,
@@ -124,7 +124,7 @@ mod tests {
     #[test_case("json_objectagg('bar': 2 absent on null with unique returning int)" => Ok(JsonAggFunc::Object(
         JsonObjectAgg::new(
             JsonKeyValue::new(
-                StringConst("bar".into()),
+                StringConst("bar".into()).into(),
                 JsonValueExpr::from(IntegerConst(2))
             ),
             Some(JsonOutput::from(Int4)),
@@ -146,7 +146,7 @@ mod tests {
             Some(JsonOutput::from(Int8)),
             false,
             Some(vec![SortBy::new(
-                IntegerConst(3),
+                IntegerConst(3).into(),
                 None,
                 None
             )])
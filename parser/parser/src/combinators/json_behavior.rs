@@ -106,7 +106,7 @@ mod tests {
     use super::*;
     use crate::test_parser;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::{IntegerConst, StringConst};
+    use pg_ast::ExprKind::{IntegerConst, StringConst};
     use pg_ast::JsonBehavior;
     use test_case::test_case;
 
@@ -140,7 +140,7 @@ mod tests {
     #[test_case("empty" => Ok(EmptyArray))]
     #[test_case("empty array" => Ok(EmptyArray))]
     #[test_case("empty object" => Ok(EmptyObject))]
-    #[test_case("default 1" => Ok(JsonBehavior::Default(IntegerConst(1))))]
+    #[test_case("default 1" => Ok(JsonBehavior::Default(IntegerConst(1).into())))]
     fn test_json_behavior(source: &str) -> scan::Result<JsonBehavior> {
         test_parser!(source, json_behavior)
     }
@@ -33,23 +33,23 @@ mod tests {
     use crate::test_parser;
     #[allow(unused_imports)]
     use pg_ast::{
-        ExprNode::{IntegerConst, StringConst},
+        ExprKind::{IntegerConst, StringConst},
         JsonFormat,
         JsonValueExpr,
     };
     use test_case::test_case;
 
     #[test_case("'foo' : 1" => Ok(JsonKeyValue::new(
-        StringConst("foo".into()),
+        StringConst("foo".into()).into(),
         JsonValueExpr::new(
-            IntegerConst(1),
+            IntegerConst(1).into(),
             JsonFormat::default()
         )
     )))]
     #[test_case("'foo' : 1 format json" => Ok(JsonKeyValue::new(
-        StringConst("foo".into()),
+        StringConst("foo".into()).into(),
         JsonValueExpr::new(
-            IntegerConst(1),
+            IntegerConst(1).into(),
             JsonFormat::text()
         )
     )))]
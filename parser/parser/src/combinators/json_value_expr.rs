@@ -28,7 +28,7 @@ mod tests {
     use crate::test_parser;
     #[allow(unused_imports)]
     use pg_ast::{
-        ExprNode::StringConst,
+        ExprKind::StringConst,
         JsonEncoding::UTF8,
         JsonFormat,
     };
@@ -36,19 +36,19 @@ mod tests {
 
     #[test_case("'foo'" => Ok(
         JsonValueExpr::new(
-            StringConst("foo".into()),
+            StringConst("foo".into()).into(),
             JsonFormat::default()
         )
     ))]
     #[test_case("'foo' format json" => Ok(
         JsonValueExpr::new(
-            StringConst("foo".into()),
+            StringConst("foo".into()).into(),
             JsonFormat::text()
         )
     ))]
     #[test_case("'foo' format json encoding utf8" => Ok(
         JsonValueExpr::new(
-            StringConst("foo".into()),
+            StringConst("foo".into()).into(),
             JsonFormat::text().with_encoding(UTF8)
         )
     ))]
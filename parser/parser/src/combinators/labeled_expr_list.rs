@@ -28,14 +28,14 @@ mod tests {
     use super::*;
     use crate::test_parser;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::IntegerConst;
+    use pg_ast::ExprKind::IntegerConst;
     use test_case::test_case;
 
     #[test_case("1" => Ok(
-        NamedValue::unnamed(IntegerConst(1))
+        NamedValue::unnamed(IntegerConst(1).into())
     ))]
     #[test_case("2 as x" => Ok(
-        NamedValue::new(Some("x".into()), IntegerConst(2))
+        NamedValue::new(Some("x".into()), IntegerConst(2).into())
     ))]
     fn test_labeled_expr(source: &str) -> scan::Result<NamedValue> {
         test_parser!(source, labeled_expr)
@@ -22,6 +22,7 @@ pg_basics::reexport! {
     collate_clause,
     const_numeric,
     core,
+    definition,
     document_or_content,
     drop_behavior,
     func_application,
@@ -33,6 +34,7 @@ pg_basics::reexport! {
     function_with_argtypes,
     generic_set_tail,
     i32_literal_paren,
+    identity_constraint,
     if_exists,
     if_not_exists,
     interval,
@@ -47,16 +49,21 @@ pg_basics::reexport! {
     json_returning_clause,
     json_value_expr,
     json_wrapper_behavior,
+    labeled_expr_list,
     make_column_ref,
     non_reserved_word,
     non_reserved_word_or_sconst,
     nulls_order,
     operators,
+    or_replace,
     precision,
     privilege,
     qualified_name,
     relation_expr,
+    relation_persistence,
+    reloptions,
     role,
+    seq_opt_list,
     sort_clause,
     stmt,
     string_or_null,
@@ -68,6 +75,7 @@ pg_basics::reexport! {
     unique_null_treatment,
     var_value,
     variable_target,
+    where_clause,
     window_specification,
     work_or_transaction,
     xmlexists_argument,
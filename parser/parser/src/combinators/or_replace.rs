@@ -0,0 +1,61 @@
+/// Alias: `opt_or_replace`
+fn or_replace(ctx: &mut ParserContext) -> scan::Result<()> {
+
+    /*
+        OR REPLACE
+    */
+
+    seq!(Or, Replace).skip().parse(ctx)
+}
+
+/// Parses `( OR REPLACE )?`, and then the statement that follows it.
+///
+/// The flag is passed to `stmt`, which must always return `NoMatch` for statements that don't
+/// support it, so that `OR REPLACE` followed by anything else becomes a syntax error.
+pub(super) fn with_or_replace<F, P>(stmt: F) -> impl Combinator<Output = P::Output>
+where
+    F: Fn(bool) -> P,
+    P: Combinator,
+{
+    parser(move |ctx| {
+        let replace = or_replace.optional().parse(ctx)?.is_some();
+
+        match stmt(replace).parse(ctx) {
+            Err(Eof(loc) | NoMatch(loc)) if replace => {
+                // `OR REPLACE` was consumed, so this is a partial match.
+                Err(syntax(loc))
+            },
+            result => result,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    use pg_lexer::Keyword::Language;
+    use test_case::test_case;
+
+    #[test_case("or replace language" => Ok(true))]
+    #[test_case("language" => Ok(false))]
+    fn test_with_or_replace(source: &str) -> scan::Result<bool> {
+        test_parser!(source, with_or_replace(|replace| Language.map(move |_| replace)))
+    }
+
+    #[test_case("or replace table" => matches Err(scan::Error::ScanErr(_)))]
+    fn test_partial_or_replace(source: &str) -> scan::Result<bool> {
+        test_parser!(source, with_or_replace(|replace| Language.map(move |_| replace)))
+    }
+}
+
+use crate::combinators::core::parser;
+use crate::combinators::core::Combinator;
+use crate::seq;
+use crate::ParserContext;
+use pg_lexer::Keyword::Or;
+use pg_lexer::Keyword::Replace;
+use pg_parser_core::scan;
+use pg_parser_core::scan::Error::Eof;
+use pg_parser_core::scan::Error::NoMatch;
+use pg_parser_core::syntax;
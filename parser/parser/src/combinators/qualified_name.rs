@@ -13,19 +13,23 @@ pub(super) fn qualified_name(ctx: &mut ParserContext) -> scan::Result<RelationNa
         (col_id attrs){1,3}
     */
 
-    let Located(mut qn, loc) = located!(any_name).parse(ctx)?;
+    let name = spanned!(any_name).parse(ctx)?;
+    make_relation_name(name)
+}
+
+/// Converts a `any_name` into a `RelationName`, failing if it has more than 3 parts.
+pub(super) fn make_relation_name(Located(mut qn, loc): Located<QualifiedName>) -> scan::Result<RelationName> {
 
-    match qn.as_mut_slice() {
+    let name = match qn.as_mut_slice() {
         [relation] => {
             let relation = mem::take(relation);
-            Ok(RelationName::new(relation))
+            RelationName::new(relation)
         },
         [schema, relation] => {
             let schema = mem::take(schema);
             let relation = mem::take(relation);
-            Ok(RelationName::new(relation)
+            RelationName::new(relation)
                 .with_schema(schema)
-            )
         },
         [catalog, schema, relation] => {
             let catalog = mem::take(catalog);
@@ -35,14 +39,15 @@ pub(super) fn qualified_name(ctx: &mut ParserContext) -> scan::Result<RelationNa
             let schema = SchemaName::new(schema)
                 .with_catalog(catalog);
 
-            Ok(RelationName::new(relation)
+            RelationName::new(relation)
                 .with_schema(schema)
-            )
         },
         _ => {
-            Err(ImproperQualifiedName(NameList(qn)).at_location(loc).into())
+            return Err(ImproperQualifiedName(NameList(qn)).at_location(loc).into())
         }
-    }
+    };
+
+    Ok(name.with_span(loc.into()))
 }
 
 #[cfg(test)]
@@ -80,18 +85,27 @@ mod tests {
                 )
         )
     }
+
+    #[test]
+    fn test_qualified_name_span() {
+        let source = "some_schema . some_relation, other";
+        let mut ctx = ParserContext::new(source);
+        let actual = qualified_name(&mut ctx).unwrap();
+        assert_eq!(Some("some_schema . some_relation"), actual.span().slice(source))
+    }
 }
 
 use crate::combinators::any_name;
 use crate::combinators::core::Combinator;
-use crate::located;
 use crate::many;
+use crate::spanned;
 use crate::ParserContext;
 use core::mem;
 use pg_ast::RelationName;
 use pg_ast::SchemaName;
 use pg_basics::IntoLocated;
 use pg_basics::Located;
+use pg_basics::QualifiedName;
 use pg_elog::parser::Error::ImproperQualifiedName;
 use pg_elog::parser::NameList;
 use pg_lexer::OperatorKind::Comma;
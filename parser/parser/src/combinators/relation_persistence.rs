@@ -0,0 +1,110 @@
+/// Alias: `OptTemp`
+pub(super) fn relation_persistence(ctx: &mut ParserContext) -> scan::Result<RelationPersistence> {
+
+    /*
+          ( LOCAL | GLOBAL )? ( TEMPORARY | TEMP )
+        | UNLOGGED
+    */
+
+    alt!(
+        temp,
+        Kw::Unlogged.map(|_| Unlogged)
+    ).parse(ctx)
+}
+
+fn temp(ctx: &mut ParserContext) -> scan::Result<RelationPersistence> {
+
+    /*
+        ( LOCAL | GLOBAL )? ( TEMPORARY | TEMP )
+    */
+
+    let (scope, _) = seq!(
+        located!(alt!(Local, Global)).optional(),
+        alt!(Temporary, TempKw)
+    ).parse(ctx)?;
+
+    if let Some(Located(Global, loc)) = scope {
+        ctx.add_warning(DeprecatedGlobalTemporaryTable.at_location(loc));
+    }
+
+    Ok(Temp)
+}
+
+/// Parses `( OptTemp )?`, and then the statement that follows it.
+///
+/// Several `CREATE` statements share this prefix, so it has to be parsed before
+/// the object type can be known.
+pub(super) fn with_relation_persistence<F, P>(stmt: F) -> impl Combinator<Output = P::Output>
+where
+    F: Fn(RelationPersistence) -> P,
+    P: Combinator,
+{
+    parser(move |ctx| {
+        let persistence = relation_persistence.optional().parse(ctx)?;
+
+        match stmt(persistence.unwrap_or_default()).parse(ctx) {
+            Err(Eof(loc) | NoMatch(loc)) if persistence.is_some() => {
+                // `OptTemp` was consumed, so this is a partial match.
+                Err(syntax(loc))
+            },
+            result => result,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    use pg_elog::parser::Warning;
+    use test_case::test_case;
+
+    #[test_case("temp", Temp)]
+    #[test_case("temporary", Temp)]
+    #[test_case("local temp", Temp)]
+    #[test_case("local temporary", Temp)]
+    #[test_case("global temp", Temp)]
+    #[test_case("global temporary", Temp)]
+    #[test_case("unlogged", Unlogged)]
+    fn test_relation_persistence(source: &str, expected: RelationPersistence) {
+        test_parser!(source, relation_persistence, expected)
+    }
+
+    #[test_case("global temp", Some(DeprecatedGlobalTemporaryTable))]
+    #[test_case("local temp", None)]
+    #[test_case("temp", None)]
+    fn test_global_temp_warning(source: &str, expected: Option<Warning>) {
+        let mut ctx = ParserContext::from(source);
+        relation_persistence(&mut ctx).unwrap();
+
+        let actual = ctx.stream_mut()
+            .take_warnings()
+            .into_iter()
+            .map(|Located(warning, _)| warning)
+            .next();
+
+        assert_eq!(expected, actual);
+    }
+}
+
+use crate::alt;
+use crate::combinators::core::parser;
+use crate::combinators::core::Combinator;
+use crate::located;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::RelationPersistence;
+use pg_ast::RelationPersistence::Temp;
+use pg_ast::RelationPersistence::Unlogged;
+use pg_basics::IntoLocated;
+use pg_basics::Located;
+use pg_elog::parser::Warning::DeprecatedGlobalTemporaryTable;
+use pg_lexer::Keyword as Kw;
+use pg_lexer::Keyword::Global;
+use pg_lexer::Keyword::Local;
+use pg_lexer::Keyword::Temp as TempKw;
+use pg_lexer::Keyword::Temporary;
+use pg_parser_core::scan;
+use pg_parser_core::scan::Error::Eof;
+use pg_parser_core::scan::Error::NoMatch;
+use pg_parser_core::syntax;
@@ -0,0 +1,72 @@
+/// Alias: `reloptions`
+pub(super) fn reloptions(ctx: &mut ParserContext) -> scan::Result<Vec<RelOption>> {
+
+    /*
+        '(' reloption_list ')'
+    */
+
+    paren!(
+        many!(sep = Comma, reloption_elem)
+    ).parse(ctx)
+}
+
+/// Alias: `reloption_elem`
+fn reloption_elem(ctx: &mut ParserContext) -> scan::Result<RelOption> {
+
+    /*
+        ColLabel ( '.' ColLabel )? ( '=' def_arg )?
+    */
+
+    let (first, second, value) = seq!(
+        col_label,
+        seq!(Dot, col_label).optional(),
+        seq!(Equals, def_arg).optional()
+    ).parse(ctx)?;
+
+    let value = value.map(|(_, value)| value);
+
+    let option = match second {
+        Some((_, name)) => RelOption::new(name, value).with_namespace(first),
+        None => RelOption::new(first, value),
+    };
+
+    Ok(option)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        DefinitionArg,
+        SignedNumber::IntegerConst,
+    };
+
+    #[test]
+    fn test_reloptions() {
+        test_parser!(
+            source = "(fillfactor = 70, toast.autovacuum_enabled = false, autovacuum_enabled)",
+            parser = reloptions,
+            expected = vec![
+                RelOption::new("fillfactor", Some(DefinitionArg::Number(IntegerConst(70)))),
+                RelOption::new("autovacuum_enabled", Some(DefinitionArg::ReservedWord("false".into())))
+                    .with_namespace("toast"),
+                RelOption::new("autovacuum_enabled", None),
+            ]
+        )
+    }
+}
+
+use crate::combinators::col_label;
+use crate::combinators::core::Combinator;
+use crate::combinators::def_arg;
+use crate::many;
+use crate::paren;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::RelOption;
+use pg_lexer::OperatorKind::Comma;
+use pg_lexer::OperatorKind::Dot;
+use pg_lexer::OperatorKind::Equals;
+use pg_parser_core::scan;
@@ -0,0 +1,179 @@
+/// Alias: `SeqOptList`
+pub(super) fn seq_opt_list(ctx: &mut ParserContext) -> scan::Result<Vec<SeqOptElem>> {
+
+    /*
+        ( SeqOptElem )+
+    */
+
+    many!(seq_opt_elem).parse(ctx)
+}
+
+/// Alias: `OptParenthesizedSeqOptList`
+// TODO: Remove the `allow` once column constraints (`ColConstraintElem`) are parsed.
+#[allow(dead_code)]
+pub(super) fn parenthesized_seq_opt_list(ctx: &mut ParserContext) -> scan::Result<Vec<SeqOptElem>> {
+
+    /*
+        '(' SeqOptList ')'
+    */
+
+    paren!(seq_opt_list).parse(ctx)
+}
+
+/// Alias: `SeqOptElem`
+pub(super) fn seq_opt_elem(ctx: &mut ParserContext) -> scan::Result<SeqOptElem> {
+
+    /*
+          AS SimpleTypename
+        | CACHE NumericOnly
+        | CYCLE
+        | NO CYCLE
+        | NO MAXVALUE
+        | NO MINVALUE
+        | INCREMENT ( BY )? NumericOnly
+        | LOGGED
+        | UNLOGGED
+        | MAXVALUE NumericOnly
+        | MINVALUE NumericOnly
+        | OWNED BY any_name
+        | SEQUENCE NAME any_name
+        | START ( WITH )? NumericOnly
+        | RESTART ( ( WITH )? NumericOnly )?
+    */
+
+    alt!(
+        seq!(As, simple_typename)
+            .map(|(_, type_name)| SeqOptElem::As(type_name)),
+        seq!(Kw::Cache, signed_number)
+            .map(|(_, cache)| Cache(cache)),
+        Kw::Cycle
+            .map(|_| Cycle(true)),
+        seq!(
+            No,
+            alt!(
+                Kw::Cycle.map(|_| Cycle(false)),
+                Maxvalue.map(|_| MaxValue(None)),
+                Minvalue.map(|_| MinValue(None))
+            )
+        ).map(|(_, option)| option),
+        seq!(Kw::Increment, By.optional(), signed_number)
+            .map(|(.., increment)| Increment(increment)),
+        Kw::Logged
+            .map(|_| Logged(true)),
+        Unlogged
+            .map(|_| Logged(false)),
+        seq!(Maxvalue, signed_number)
+            .map(|(_, max_value)| MaxValue(Some(max_value))),
+        seq!(Minvalue, signed_number)
+            .map(|(_, min_value)| MinValue(Some(min_value))),
+        seq!(OwnedKw, By, any_name)
+            .map(|(.., column)| OwnedBy(column)),
+        seq!(Sequence, Name, any_name)
+            .map(|(.., name)| SequenceName(name)),
+        seq!(Kw::Start, With.optional(), signed_number)
+            .map(|(.., start)| Start(start)),
+        seq!(
+            Kw::Restart,
+            seq!(With.optional(), signed_number)
+                .map(|(_, restart)| restart)
+                .optional()
+        ).map(|(_, restart)| Restart(restart))
+    ).parse(ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        SignedNumber::IntegerConst,
+        TypeName::Int8,
+    };
+    use test_case::test_case;
+
+    #[test]
+    fn test_seq_opt_list() {
+        test_parser!(
+            source = "increment by 2 minvalue 1 no maxvalue start 5 cache 10 no cycle",
+            parser = seq_opt_list,
+            expected = vec![
+                Increment(IntegerConst(2)),
+                MinValue(Some(IntegerConst(1))),
+                MaxValue(None),
+                Start(IntegerConst(5)),
+                Cache(IntegerConst(10)),
+                Cycle(false),
+            ]
+        )
+    }
+
+    #[test]
+    fn test_parenthesized_seq_opt_list() {
+        test_parser!(
+            source = "(start with 10 increment -1)",
+            parser = parenthesized_seq_opt_list,
+            expected = vec![
+                Start(IntegerConst(10)),
+                Increment(IntegerConst(-1)),
+            ]
+        )
+    }
+
+    #[test_case("as bigint", SeqOptElem::As(Int8))]
+    #[test_case("cache 20", Cache(IntegerConst(20)))]
+    #[test_case("cycle", Cycle(true))]
+    #[test_case("no cycle", Cycle(false))]
+    #[test_case("increment 3", Increment(IntegerConst(3)))]
+    #[test_case("increment by -3", Increment(IntegerConst(-3)))]
+    #[test_case("logged", Logged(true))]
+    #[test_case("unlogged", Logged(false))]
+    #[test_case("maxvalue 100", MaxValue(Some(IntegerConst(100))))]
+    #[test_case("no maxvalue", MaxValue(None))]
+    #[test_case("minvalue -100", MinValue(Some(IntegerConst(-100))))]
+    #[test_case("no minvalue", MinValue(None))]
+    #[test_case("owned by some_table.some_column", OwnedBy(vec!["some_table".into(), "some_column".into()]))]
+    #[test_case("owned by none", OwnedBy(vec!["none".into()]))]
+    #[test_case("sequence name some_schema.some_seq", SequenceName(vec!["some_schema".into(), "some_seq".into()]))]
+    #[test_case("start 1", Start(IntegerConst(1)))]
+    #[test_case("start with 1", Start(IntegerConst(1)))]
+    #[test_case("restart", Restart(None))]
+    #[test_case("restart 7", Restart(Some(IntegerConst(7))))]
+    #[test_case("restart with 7", Restart(Some(IntegerConst(7))))]
+    fn test_seq_opt_elem(source: &str, expected: SeqOptElem) {
+        test_parser!(source, seq_opt_elem, expected)
+    }
+}
+
+use crate::alt;
+use crate::combinators::any_name;
+use crate::combinators::core::Combinator;
+use crate::combinators::signed_number;
+use crate::combinators::simple_typename;
+use crate::many;
+use crate::paren;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::SeqOptElem;
+use pg_ast::SeqOptElem::Cache;
+use pg_ast::SeqOptElem::Cycle;
+use pg_ast::SeqOptElem::Increment;
+use pg_ast::SeqOptElem::Logged;
+use pg_ast::SeqOptElem::MaxValue;
+use pg_ast::SeqOptElem::MinValue;
+use pg_ast::SeqOptElem::OwnedBy;
+use pg_ast::SeqOptElem::Restart;
+use pg_ast::SeqOptElem::SequenceName;
+use pg_ast::SeqOptElem::Start;
+use pg_lexer::Keyword as Kw;
+use pg_lexer::Keyword::As;
+use pg_lexer::Keyword::By;
+use pg_lexer::Keyword::Maxvalue;
+use pg_lexer::Keyword::Minvalue;
+use pg_lexer::Keyword::Name;
+use pg_lexer::Keyword::No;
+use pg_lexer::Keyword::OwnedKw;
+use pg_lexer::Keyword::Sequence;
+use pg_lexer::Keyword::Unlogged;
+use pg_lexer::Keyword::With;
+use pg_parser_core::scan;
@@ -48,7 +48,7 @@ fn sortby(ctx: &mut ParserContext) -> scan::Result<SortBy> {
 mod tests {
     use super::*;
     use crate::test_parser;
-    use pg_ast::ExprNode::IntegerConst;
+    use pg_ast::ExprKind::IntegerConst;
     use pg_ast::Operator::Less;
     use pg_ast::SortDirection::Ascending;
     use pg_ast::SortNulls::NullsLast;
@@ -66,8 +66,8 @@ mod tests {
         let Located(actual, _) = sort_clause(&mut ctx).unwrap();
 
         let expected = vec![
-            SortBy::new(IntegerConst(1), None, None),
-            SortBy::new(IntegerConst(2), None, None),
+            SortBy::new(IntegerConst(1).into(), None, None),
+            SortBy::new(IntegerConst(2).into(), None, None),
         ];
 
         assert_eq!(expected, actual);
@@ -79,36 +79,36 @@ mod tests {
             source = "1, 2 nulls last, 3 using <, 4 asc",
             parser = sortby_list,
             expected = vec![
-                SortBy::new(IntegerConst(1), None, None),
-                SortBy::new(IntegerConst(2), None, Some(NullsLast)),
-                SortBy::new(IntegerConst(3), Some(Using(Less.into())), None),
-                SortBy::new(IntegerConst(4), Some(Ascending), None),
+                SortBy::new(IntegerConst(1).into(), None, None),
+                SortBy::new(IntegerConst(2).into(), None, Some(NullsLast)),
+                SortBy::new(IntegerConst(3).into(), Some(Using(Less.into())), None),
+                SortBy::new(IntegerConst(4).into(), Some(Ascending), None),
             ]
         )
     }
 
     #[test_case("1 using < nulls first" => Ok(SortBy::new(
-        IntegerConst(1),
+        IntegerConst(1).into(),
         Some(Using(Less.into())),
         Some(NullsFirst)
     )))]
     #[test_case("2 asc nulls last" => Ok(SortBy::new(
-        IntegerConst(2),
+        IntegerConst(2).into(),
         Some(Ascending),
         Some(NullsLast)
     )))]
     #[test_case("3 desc" => Ok(SortBy::new(
-        IntegerConst(3),
+        IntegerConst(3).into(),
         Some(SortDirection::Descending),
         None
     )))]
     #[test_case("4" => Ok(SortBy::new(
-        IntegerConst(4),
+        IntegerConst(4).into(),
         None,
         None
     )))]
     #[test_case("5 nulls first" => Ok(SortBy::new(
-        IntegerConst(5),
+        IntegerConst(5).into(),
         None,
         Some(NullsFirst)
     )))]
@@ -0,0 +1,149 @@
+enum AggregateArgs {
+    OldStyle(Vec<DefinitionElem>),
+    Args(Vec<FunctionParameter>, Vec<FunctionParameter>),
+}
+
+/// Alias: `DefineStmt` (only `AGGREGATE`)
+///
+/// `opt_or_replace` is parsed by `with_or_replace`.
+pub(in crate::combinators::stmt) fn create_aggregate_stmt(replace: bool) -> impl Combinator<Output = DefineStmt> {
+
+    /*
+          AGGREGATE func_name aggr_args definition
+        | AGGREGATE func_name old_aggr_definition
+    */
+
+    parser(move |ctx| {
+        let (_, name, args) = seq!(
+            Aggregate,
+            func_name,
+            paren!(alt!(
+                old_aggr_list.map(AggregateArgs::OldStyle),
+                any_aggr_arg.map(|(args, order_by)| AggregateArgs::Args(args, order_by))
+            ))
+        ).parse(ctx)?;
+
+        let stmt = match args {
+            AggregateArgs::OldStyle(definition) => DefineStmt::OldStyleAggregate { name, definition, replace },
+            AggregateArgs::Args(args, order_by) => {
+                let definition = definition(ctx).required()?;
+                let aggregate = AggregateWithArgs::new(name, args, order_by);
+                DefineStmt::Aggregate { aggregate, definition, replace }
+            },
+        };
+
+        Ok(stmt)
+    })
+}
+
+/// Alias: `old_aggr_list`
+fn old_aggr_list(ctx: &mut ParserContext) -> scan::Result<Vec<DefinitionElem>> {
+
+    /*
+        old_aggr_elem ( ',' old_aggr_elem )*
+    */
+
+    // Identifiers are also valid argument types, so the `=` is what identifies the old-style syntax.
+    if ! matches!(ctx.stream_mut().peek2(), Ok((Identifier(_), Operator(Equals)))) {
+        return no_match(ctx)
+    }
+
+    many!(sep = Comma, old_aggr_elem).parse(ctx)
+}
+
+/// Alias: `old_aggr_elem`
+fn old_aggr_elem(ctx: &mut ParserContext) -> scan::Result<DefinitionElem> {
+
+    /*
+        IDENT '=' def_arg
+    */
+
+    let (name, _, arg) = seq!(identifier, Equals, def_arg)
+        .parse(ctx)?;
+
+    Ok(DefinitionElem::new(name, Some(arg)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combinators::with_or_replace;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        DefinitionArg,
+        FuncType,
+        TypeName::{self, Int4},
+    };
+    use test_case::test_case;
+
+    fn generic_type(name: &'static str) -> DefinitionArg {
+        DefinitionArg::Type(FuncType::Type(
+            TypeName::Generic { name: vec![name.into()], type_modifiers: None }.into()
+        ))
+    }
+
+    #[test_case("aggregate my_sum(int) (sfunc = int4pl, stype = int)",
+        DefineStmt::Aggregate {
+            aggregate: AggregateWithArgs::new(
+                vec!["my_sum".into()],
+                vec![FuncType::Type(Int4.into()).into()],
+                vec![]
+            ),
+            definition: vec![
+                DefinitionElem::new("sfunc", Some(generic_type("int4pl"))),
+                DefinitionElem::new("stype", Some(DefinitionArg::Type(FuncType::Type(Int4.into())))),
+            ],
+            replace: false
+        }
+    )]
+    #[test_case("or replace aggregate my_count(*) (sfunc = int8inc, stype = int8)",
+        DefineStmt::Aggregate {
+            aggregate: AggregateWithArgs::new(vec!["my_count".into()], vec![], vec![]),
+            definition: vec![
+                DefinitionElem::new("sfunc", Some(generic_type("int8inc"))),
+                DefinitionElem::new("stype", Some(generic_type("int8"))),
+            ],
+            replace: true
+        }
+    )]
+    #[test_case("aggregate my_sum (basetype = int, sfunc = int4pl, stype = int)",
+        DefineStmt::OldStyleAggregate {
+            name: vec!["my_sum".into()],
+            definition: vec![
+                DefinitionElem::new("basetype", Some(DefinitionArg::Type(FuncType::Type(Int4.into())))),
+                DefinitionElem::new("sfunc", Some(generic_type("int4pl"))),
+                DefinitionElem::new("stype", Some(DefinitionArg::Type(FuncType::Type(Int4.into())))),
+            ],
+            replace: false
+        }
+    )]
+    fn test_create_aggregate_stmt(source: &str, expected: DefineStmt) {
+        test_parser!(source, with_or_replace(create_aggregate_stmt), expected)
+    }
+}
+
+use crate::alt;
+use crate::combinators::core::identifier;
+use crate::combinators::core::parser;
+use crate::combinators::core::Combinator;
+use crate::combinators::def_arg;
+use crate::combinators::definition;
+use crate::combinators::func_name;
+use crate::combinators::stmt::any_aggr_arg;
+use crate::many;
+use crate::no_match;
+use crate::paren;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::AggregateWithArgs;
+use pg_ast::DefineStmt;
+use pg_ast::DefinitionElem;
+use pg_ast::FunctionParameter;
+use pg_lexer::Keyword::Aggregate;
+use pg_lexer::OperatorKind::Comma;
+use pg_lexer::OperatorKind::Equals;
+use pg_parser_core::scan;
+use pg_parser_core::stream::TokenValue::Identifier;
+use pg_parser_core::stream::TokenValue::Operator;
+use pg_parser_core::Required;
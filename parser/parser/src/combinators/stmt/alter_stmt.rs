@@ -8,13 +8,19 @@ pub(super) fn alter_stmt(ctx: &mut ParserContext) -> scan::Result<RawStmt> {
             alter_conversion_stmt,
             alter_database_stmt.map(From::from),
             alter_default_privileges_stmt.map(From::from),
+            alter_domain_stmt,
             alter_event_trigger_stmt,
             alter_extension_stmt,
             alter_function_stmt,
             alter_group_stmt.map(From::from),
             alter_language_stmt,
             alter_large_object_stmt,
+            alter_operator_stmt,
+            alter_prop_graph_stmt,
+            alter_sequence_stmt.map(From::from),
             alter_system_stmt.map(From::from),
+            alter_text_search_stmt,
+            alter_type_stmt,
             alter_user_stmt.map(From::from),
         )
     ).parse(ctx)?;
@@ -37,13 +43,19 @@ mod tests {
             "alter conversion some_conversion rename to new_conversion",
             "alter database the_db refresh collation version",
             "alter default privileges in schema some_schema grant all on tables to public",
+            "alter domain some_domain drop default",
             "alter event trigger some_trigger owner to current_user",
             "alter extension foo set schema some_schema",
             "alter function some_function() owner to current_user",
             "alter group some_group rename to new_group_name",
             "alter language lang owner to session_user",
             "alter large object -127 owner to public",
+            "alter operator family some_family using btree drop function 1 (int)",
+            "alter property graph g rename to h",
+            "alter sequence some_seq increment by 2",
             "alter system reset all",
+            "alter text search parser some_parser rename to other_parser",
+            "alter type some_type rename to new_type",
             "alter user public",
         ]
         => matches Ok(_)
@@ -60,13 +72,19 @@ use crate::combinators::stmt::alter_collation_stmt;
 use crate::combinators::stmt::alter_conversion_stmt;
 use crate::combinators::stmt::alter_database_stmt;
 use crate::combinators::stmt::alter_default_privileges_stmt;
+use crate::combinators::stmt::alter_domain_stmt;
 use crate::combinators::stmt::alter_event_trigger_stmt;
 use crate::combinators::stmt::alter_extension_stmt;
 use crate::combinators::stmt::alter_function_stmt;
 use crate::combinators::stmt::alter_group_stmt;
 use crate::combinators::stmt::alter_language_stmt;
 use crate::combinators::stmt::alter_large_object_stmt;
+use crate::combinators::stmt::alter_operator_stmt;
+use crate::combinators::stmt::alter_prop_graph_stmt;
+use crate::combinators::stmt::alter_sequence_stmt;
 use crate::combinators::stmt::alter_system_stmt;
+use crate::combinators::stmt::alter_text_search_stmt;
+use crate::combinators::stmt::alter_type_stmt;
 use crate::combinators::stmt::alter_user_stmt;
 use crate::seq;
 use crate::ParserContext;
@@ -0,0 +1,70 @@
+/// Alias: `DefineStmt` (only `COLLATION`)
+pub(in crate::combinators::stmt) fn create_collation_stmt(ctx: &mut ParserContext) -> scan::Result<DefineStmt> {
+
+    /*
+        COLLATION ( IF NOT EXISTS )? any_name (
+              definition
+            | FROM any_name
+        )
+    */
+
+    let (_, existence, name, definition) = seq!(
+        Collation,
+        if_not_exists.optional()
+            .map(Option::unwrap_or_default),
+        any_name,
+        alt!(
+            definition.map(CollationDefinition::Definition),
+            seq!(FromKw, any_name)
+                .map(|(_, collation)| CollationDefinition::From(collation))
+        )
+    ).parse(ctx)?;
+
+    Ok(DefineStmt::Collation { name, definition, existence })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        DefinitionArg,
+        DefinitionElem,
+        Presence,
+    };
+    use test_case::test_case;
+
+    #[test_case("collation french (locale = 'fr_FR.utf8')",
+        DefineStmt::Collation {
+            name: vec!["french".into()],
+            definition: CollationDefinition::Definition(vec![
+                DefinitionElem::new("locale", Some(DefinitionArg::String("fr_FR.utf8".into())))
+            ]),
+            existence: Presence::Fail
+        }
+    )]
+    #[test_case("collation if not exists some_schema.german from \"de_DE\"",
+        DefineStmt::Collation {
+            name: vec!["some_schema".into(), "german".into()],
+            definition: CollationDefinition::From(vec!["de_DE".into()]),
+            existence: Presence::Ignore
+        }
+    )]
+    fn test_create_collation_stmt(source: &str, expected: DefineStmt) {
+        test_parser!(source, create_collation_stmt, expected)
+    }
+}
+
+use crate::alt;
+use crate::combinators::any_name;
+use crate::combinators::core::Combinator;
+use crate::combinators::definition;
+use crate::combinators::if_not_exists;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::CollationDefinition;
+use pg_ast::DefineStmt;
+use pg_lexer::Keyword::Collation;
+use pg_lexer::Keyword::FromKw;
+use pg_parser_core::scan;
@@ -5,10 +5,29 @@ pub(super) fn create_stmt(ctx: &mut ParserContext) -> scan::Result<RawStmt> {
         alt!(
             create_access_method_stmt.map(From::from),
             create_cast_stmt.map(From::from),
+            create_collation_stmt.map(From::from),
             create_conversion_stmt.map(From::from),
             create_database_stmt.map(From::from),
+            create_domain_stmt.map(From::from),
+            create_extension_stmt.map(From::from),
+            create_operator_stmt,
             create_role_stmt.map(From::from),
+            create_schema_stmt.map(From::from),
+            create_stats_stmt.map(From::from),
+            create_table_space_stmt.map(From::from),
+            create_text_search_stmt.map(From::from),
+            create_type_stmt,
             create_user_stmt.map(From::from),
+            with_or_replace(|replace| alt!(
+                create_aggregate_stmt(replace).map(From::from),
+                create_plang_stmt(replace).map(From::from),
+                create_rule_stmt(replace).map(From::from),
+                create_transform_stmt(replace).map(From::from)
+            )),
+            with_relation_persistence(|persistence| alt!(
+                create_prop_graph_stmt(persistence).map(From::from),
+                create_sequence_stmt(persistence).map(From::from)
+            )),
         )
     ).parse(ctx)?;
 
@@ -24,10 +43,25 @@ mod tests {
     #[test_matrix(
         [
             "create access method foo type table handler bar",
+            "create or replace aggregate agg(int) (sfunc = f, stype = int)",
             "create cast (int as text) with inout",
+            "create collation some_coll from \"C\"",
             "create conversion conv_name for 'for-encoding' to 'to-encoding' from func_name",
             "create database new_db oid = 1",
+            "create domain some_domain int not null",
+            "create extension some_extension",
+            "create trusted language plfoo handler foo_handler",
+            "create operator family some_family using btree",
+            "create temp property graph g vertex tables (persons)",
             "create role new_role with superuser",
+            "create or replace rule r as on update to t do nothing",
+            "create schema some_schema",
+            "create temp sequence new_seq start 1",
+            "create statistics s on a, b from t",
+            "create tablespace ts location '/data'",
+            "create text search dictionary some_dict (template = simple)",
+            "create transform for int language plfoo (from sql with function f)",
+            "create type some_type as enum ('foo')",
             "create user new_user with password 'password'",
         ]
         => matches Ok(_)
@@ -40,11 +74,28 @@ mod tests {
 use crate::alt;
 use crate::combinators::core::Combinator;
 use crate::combinators::stmt::create_access_method_stmt;
+use crate::combinators::stmt::create_aggregate_stmt;
 use crate::combinators::stmt::create_cast_stmt;
+use crate::combinators::stmt::create_collation_stmt;
 use crate::combinators::stmt::create_conversion_stmt;
 use crate::combinators::stmt::create_database_stmt;
+use crate::combinators::stmt::create_domain_stmt;
+use crate::combinators::stmt::create_extension_stmt;
+use crate::combinators::stmt::create_operator_stmt;
+use crate::combinators::stmt::create_plang_stmt;
+use crate::combinators::stmt::create_prop_graph_stmt;
 use crate::combinators::stmt::create_role_stmt;
+use crate::combinators::stmt::create_rule_stmt;
+use crate::combinators::stmt::create_schema_stmt;
+use crate::combinators::stmt::create_sequence_stmt;
+use crate::combinators::stmt::create_stats_stmt;
+use crate::combinators::stmt::create_table_space_stmt;
+use crate::combinators::stmt::create_text_search_stmt;
+use crate::combinators::stmt::create_transform_stmt;
+use crate::combinators::stmt::create_type_stmt;
 use crate::combinators::stmt::create_user_stmt;
+use crate::combinators::with_or_replace;
+use crate::combinators::with_relation_persistence;
 use crate::seq;
 use crate::ParserContext;
 use pg_ast::RawStmt;
@@ -0,0 +1,278 @@
+enum Change {
+    Action(AlterDomainAction),
+    Owner(RoleSpec),
+    Name(Str),
+    Constraint { constraint: Str, new_name: Str },
+    Schema(Str),
+}
+
+/// Aliases:
+/// * `AlterDomainStmt`
+/// * `AlterOwnerStmt` (only `DOMAIN`)
+/// * `AlterObjectSchemaStmt` (only `DOMAIN`)
+/// * `RenameStmt` (only `DOMAIN`)
+pub(in crate::combinators::stmt) fn alter_domain_stmt(ctx: &mut ParserContext) -> scan::Result<RawStmt> {
+
+    /*
+        DOMAIN any_name (
+              SET DEFAULT a_expr
+            | DROP DEFAULT
+            | SET NOT NULL
+            | DROP NOT NULL
+            | ADD DomainConstraint
+            | DROP CONSTRAINT ( IF EXISTS )? ColId ( drop_behavior )?
+            | VALIDATE CONSTRAINT ColId
+            | OWNER TO RoleSpec
+            | RENAME TO ColId
+            | RENAME CONSTRAINT ColId TO ColId
+            | SET SCHEMA ColId
+        )
+    */
+
+    let (_, domain, change) = seq!(Domain, any_name, change)
+        .parse(ctx)?;
+
+    let stmt = match change {
+        Change::Action(action) => AlterDomainStmt::new(domain, action).into(),
+        Change::Owner(new_owner) => {
+            AlterOwnerStmt::new(
+                AlterOwnerTarget::Domain(domain),
+                new_owner
+            ).into()
+        },
+        Change::Name(new_name) => {
+            RenameStmt::new(
+                RenameTarget::Domain(domain),
+                new_name
+            ).into()
+        },
+        Change::Constraint { constraint, new_name } => {
+            RenameStmt::new(
+                RenameTarget::DomainConstraint { domain, constraint },
+                new_name
+            ).into()
+        },
+        Change::Schema(new_schema) => {
+            AlterObjectSchemaStmt::new(
+                AlterObjectSchemaTarget::Domain(domain),
+                new_schema
+            ).into()
+        },
+    };
+
+    Ok(stmt)
+}
+
+fn change(ctx: &mut ParserContext) -> scan::Result<Change> {
+    alt!(
+        seq!(
+            Set,
+            alt!(
+                seq!(DefaultKw, a_expr)
+                    .map(|(_, expr)| Change::Action(AlterDomainAction::SetDefault(expr))),
+                seq!(Not, Null)
+                    .map(|_| Change::Action(AlterDomainAction::SetNotNull)),
+                seq!(Schema, col_id)
+                    .map(|(_, new_schema)| Change::Schema(new_schema))
+            )
+        ).map(|(_, change)| change),
+        seq!(
+            DropKw,
+            alt!(
+                DefaultKw
+                    .map(|_| AlterDomainAction::DropDefault),
+                seq!(Not, Null)
+                    .map(|_| AlterDomainAction::DropNotNull),
+                seq!(
+                    Constraint,
+                    if_exists.optional()
+                        .map(Option::unwrap_or_default),
+                    col_id,
+                    drop_behavior.optional()
+                        .map(Option::unwrap_or_default)
+                ).map(|(_, existence, name, behavior)|
+                    AlterDomainAction::DropConstraint { name, existence, behavior }
+                )
+            )
+        ).map(|(_, action)| Change::Action(action)),
+        seq!(Add, domain_constraint, seq!(Not, Valid).optional())
+            .map(|(_, constraint, not_valid)|
+                Change::Action(AlterDomainAction::AddConstraint {
+                    constraint,
+                    not_valid: not_valid.is_some()
+                })
+            ),
+        seq!(Validate, Constraint, col_id)
+            .map(|(.., name)| Change::Action(AlterDomainAction::ValidateConstraint(name))),
+        seq!(Owner, To, role_spec)
+            .map(|(.., new_owner)| Change::Owner(new_owner)),
+        seq!(
+            Rename,
+            alt!(
+                seq!(To, col_id)
+                    .map(|(_, new_name)| Change::Name(new_name)),
+                seq!(Constraint, col_id, To, col_id)
+                    .map(|(_, constraint, _, new_name)| Change::Constraint { constraint, new_name })
+            )
+        ).map(|(_, change)| change)
+    ).parse(ctx)
+}
+
+/// Alias: `DomainConstraint`
+fn domain_constraint(ctx: &mut ParserContext) -> scan::Result<DomainConstraint> {
+
+    /*
+        ( CONSTRAINT ColId )? (
+              CHECK '(' a_expr ')'
+            | NOT NULL
+        )
+    */
+
+    let (name, kind) = seq!(
+        constraint_name.optional(),
+        alt!(
+            check_constraint,
+            seq!(Not, Null).map(|_| DomainConstraintKind::NotNull)
+        )
+    ).parse(ctx)?;
+
+    Ok(DomainConstraint::new(name, kind))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        DropBehavior,
+        ExprKind::IntegerConst,
+        Presence,
+        RoleSpec::SessionUser,
+    };
+    use test_case::test_case;
+
+    #[test_case("domain some_domain set default 1",
+        AlterDomainStmt::new(
+            vec!["some_domain".into()],
+            AlterDomainAction::SetDefault(IntegerConst(1).into())
+        ).into()
+    )]
+    #[test_case("domain some_domain drop default",
+        AlterDomainStmt::new(vec!["some_domain".into()], AlterDomainAction::DropDefault).into()
+    )]
+    #[test_case("domain some_domain set not null",
+        AlterDomainStmt::new(vec!["some_domain".into()], AlterDomainAction::SetNotNull).into()
+    )]
+    #[test_case("domain some_domain drop not null",
+        AlterDomainStmt::new(vec!["some_domain".into()], AlterDomainAction::DropNotNull).into()
+    )]
+    #[test_case("domain some_domain add constraint positive check (1) not valid",
+        AlterDomainStmt::new(
+            vec!["some_domain".into()],
+            AlterDomainAction::AddConstraint {
+                constraint: DomainConstraint::new(
+                    Some("positive".into()),
+                    DomainConstraintKind::Check(IntegerConst(1).into())
+                ),
+                not_valid: true
+            }
+        ).into()
+    )]
+    #[test_case("domain some_domain add not null",
+        AlterDomainStmt::new(
+            vec!["some_domain".into()],
+            AlterDomainAction::AddConstraint {
+                constraint: DomainConstraintKind::NotNull.into(),
+                not_valid: false
+            }
+        ).into()
+    )]
+    #[test_case("domain some_domain drop constraint if exists positive cascade",
+        AlterDomainStmt::new(
+            vec!["some_domain".into()],
+            AlterDomainAction::DropConstraint {
+                name: "positive".into(),
+                existence: Presence::Ignore,
+                behavior: DropBehavior::Cascade
+            }
+        ).into()
+    )]
+    #[test_case("domain some_domain validate constraint positive",
+        AlterDomainStmt::new(
+            vec!["some_domain".into()],
+            AlterDomainAction::ValidateConstraint("positive".into())
+        ).into()
+    )]
+    #[test_case("domain some_domain owner to session_user",
+        AlterOwnerStmt::new(
+            AlterOwnerTarget::Domain(vec!["some_domain".into()]),
+            SessionUser
+        ).into()
+    )]
+    #[test_case("domain some_domain rename to other_domain",
+        RenameStmt::new(
+            RenameTarget::Domain(vec!["some_domain".into()]),
+            "other_domain"
+        ).into()
+    )]
+    #[test_case("domain some_domain rename constraint positive to negative",
+        RenameStmt::new(
+            RenameTarget::DomainConstraint {
+                domain: vec!["some_domain".into()],
+                constraint: "positive".into()
+            },
+            "negative"
+        ).into()
+    )]
+    #[test_case("domain some_domain set schema some_schema",
+        AlterObjectSchemaStmt::new(
+            AlterObjectSchemaTarget::Domain(vec!["some_domain".into()]),
+            "some_schema"
+        ).into()
+    )]
+    fn test_alter_domain_stmt(source: &str, expected: RawStmt) {
+        test_parser!(source, alter_domain_stmt, expected)
+    }
+}
+
+use super::check_constraint;
+use super::constraint_name;
+use crate::alt;
+use crate::combinators::any_name;
+use crate::combinators::col_id;
+use crate::combinators::core::Combinator;
+use crate::combinators::drop_behavior;
+use crate::combinators::expr::a_expr;
+use crate::combinators::if_exists;
+use crate::combinators::role_spec;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::AlterDomainAction;
+use pg_ast::AlterDomainStmt;
+use pg_ast::AlterObjectSchemaStmt;
+use pg_ast::AlterObjectSchemaTarget;
+use pg_ast::AlterOwnerStmt;
+use pg_ast::AlterOwnerTarget;
+use pg_ast::DomainConstraint;
+use pg_ast::DomainConstraintKind;
+use pg_ast::RawStmt;
+use pg_ast::RenameStmt;
+use pg_ast::RenameTarget;
+use pg_ast::RoleSpec;
+use pg_basics::Str;
+use pg_lexer::Keyword::Add;
+use pg_lexer::Keyword::Constraint;
+use pg_lexer::Keyword::DefaultKw;
+use pg_lexer::Keyword::Domain;
+use pg_lexer::Keyword::DropKw;
+use pg_lexer::Keyword::Not;
+use pg_lexer::Keyword::Null;
+use pg_lexer::Keyword::Owner;
+use pg_lexer::Keyword::Rename;
+use pg_lexer::Keyword::Schema;
+use pg_lexer::Keyword::Set;
+use pg_lexer::Keyword::To;
+use pg_lexer::Keyword::Valid;
+use pg_lexer::Keyword::Validate;
+use pg_parser_core::scan;
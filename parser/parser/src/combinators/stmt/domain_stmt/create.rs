@@ -0,0 +1,172 @@
+enum DomainQual {
+    Constraint(DomainConstraint),
+    Collation(Located<QualifiedName>),
+}
+
+/// Alias: `CreateDomainStmt`
+pub(in crate::combinators::stmt) fn create_domain_stmt(ctx: &mut ParserContext) -> scan::Result<CreateDomainStmt> {
+
+    /*
+        DOMAIN any_name ( AS )? Typename ( ColQualList )?
+    */
+
+    let (_, domain, _, type_name, quals) = seq!(
+        Domain,
+        any_name,
+        As.optional(),
+        typename,
+        many!(domain_qual).optional()
+    ).parse(ctx)?;
+
+    let mut collation = None;
+    let mut constraints = Vec::new();
+
+    for qual in quals.into_iter().flatten() {
+        match qual {
+            DomainQual::Constraint(constraint) => constraints.push(constraint),
+            DomainQual::Collation(Located(_, loc)) if collation.is_some() => {
+                return Err(MultipleCollateClauses.at_location(loc).into())
+            },
+            DomainQual::Collation(Located(name, _)) => collation = Some(name),
+        }
+    }
+
+    let mut stmt = CreateDomainStmt::new(domain, type_name);
+    stmt.set_collation(collation)
+        .set_constraints(Some(constraints));
+
+    Ok(stmt)
+}
+
+/// Alias: `ColConstraint` (only what domains allow)
+fn domain_qual(ctx: &mut ParserContext) -> scan::Result<DomainQual> {
+
+    /*
+          ( CONSTRAINT ColId )? ColConstraintElem
+        | COLLATE any_name
+    */
+
+    alt!(
+        located!(collate_clause).map(DomainQual::Collation),
+        domain_constraint.map(DomainQual::Constraint)
+    ).parse(ctx)
+}
+
+fn domain_constraint(ctx: &mut ParserContext) -> scan::Result<DomainConstraint> {
+
+    /*
+        ( CONSTRAINT ColId )? (
+              NOT NULL
+            | NULL
+            | CHECK '(' a_expr ')'
+            | DEFAULT b_expr
+        )
+    */
+
+    let (name, kind) = seq!(
+        constraint_name.optional(),
+        alt!(
+            seq!(Not, Null).map(|_| DomainConstraintKind::NotNull),
+            Null.map(|_| DomainConstraintKind::Null),
+            check_constraint,
+            seq!(DefaultKw, b_expr)
+                .map(|(_, expr)| DomainConstraintKind::Default(expr))
+        )
+    ).parse(ctx)?;
+
+    Ok(DomainConstraint::new(name, kind))
+}
+
+pub(super) fn constraint_name(ctx: &mut ParserContext) -> scan::Result<Str> {
+
+    /*
+        CONSTRAINT ColId
+    */
+
+    let (_, name) = seq!(Constraint, col_id).parse(ctx)?;
+    Ok(name)
+}
+
+pub(super) fn check_constraint(ctx: &mut ParserContext) -> scan::Result<DomainConstraintKind> {
+
+    /*
+        CHECK '(' a_expr ')'
+    */
+
+    let (_, expr) = seq!(Check, paren!(a_expr)).parse(ctx)?;
+    Ok(DomainConstraintKind::Check(expr))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        ExprKind::IntegerConst,
+        TypeName::Int4,
+    };
+    use pg_elog::Error::Parser;
+    use scan::Error::ScanErr;
+    use test_case::test_case;
+
+    #[test_case("domain some_domain int",
+        CreateDomainStmt::new(vec!["some_domain".into()], Int4)
+    )]
+    #[test_case("domain some_domain as int collate some_collation",
+        CreateDomainStmt::new(vec!["some_domain".into()], Int4)
+            .with_collation(vec!["some_collation".into()])
+    )]
+    #[test_case("domain some_domain int constraint positive check (1) not null null default 2",
+        CreateDomainStmt::new(vec!["some_domain".into()], Int4)
+            .with_constraints(vec![
+                DomainConstraint::new(
+                    Some("positive".into()),
+                    DomainConstraintKind::Check(IntegerConst(1).into())
+                ),
+                DomainConstraintKind::NotNull.into(),
+                DomainConstraintKind::Null.into(),
+                DomainConstraintKind::Default(IntegerConst(2).into()).into(),
+            ])
+    )]
+    fn test_create_domain_stmt(source: &str, expected: CreateDomainStmt) {
+        test_parser!(source, create_domain_stmt, expected)
+    }
+
+    #[test_case("domain some_domain int collate foo collate bar" => matches Err(ScanErr(
+        Located(Parser(MultipleCollateClauses), _)
+    )))]
+    fn test_multiple_collate_clauses(source: &str) -> scan::Result<CreateDomainStmt> {
+        test_parser!(source, create_domain_stmt)
+    }
+}
+
+use crate::alt;
+use crate::combinators::any_name;
+use crate::combinators::col_id;
+use crate::combinators::collate_clause;
+use crate::combinators::core::Combinator;
+use crate::combinators::expr::a_expr;
+use crate::combinators::expr::b_expr;
+use crate::combinators::typename;
+use crate::located;
+use crate::many;
+use crate::paren;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::CreateDomainStmt;
+use pg_ast::DomainConstraint;
+use pg_ast::DomainConstraintKind;
+use pg_basics::IntoLocated;
+use pg_basics::Located;
+use pg_basics::QualifiedName;
+use pg_basics::Str;
+use pg_elog::parser::Error::MultipleCollateClauses;
+use pg_lexer::Keyword::As;
+use pg_lexer::Keyword::Check;
+use pg_lexer::Keyword::Constraint;
+use pg_lexer::Keyword::DefaultKw;
+use pg_lexer::Keyword::Domain;
+use pg_lexer::Keyword::Not;
+use pg_lexer::Keyword::Null;
+use pg_parser_core::scan;
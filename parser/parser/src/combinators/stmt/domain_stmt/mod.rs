@@ -0,0 +1,4 @@
+pg_basics::reexport! { pub(super)
+    alter,
+    create,
+}
@@ -16,16 +16,95 @@ pub(super) fn drop_stmt(ctx: &mut ParserContext) -> scan::Result<RawStmt> {
         | DROP TYPE_P type_name_list ( drop_behavior )?
     */
 
-    let (_, stmt) = seq!(DropKw, parser(|_| todo!()))
-        .parse(ctx)?;
+    let (_, stmt) = seq!(
+        DropKw,
+        alt!(
+            drop_any_name.map(From::from),
+            // TODO: the remaining object types
+            parser(|_| todo!())
+        )
+    ).parse(ctx)?;
 
     Ok(stmt)
 }
 
+fn drop_any_name(ctx: &mut ParserContext) -> scan::Result<DropStmt> {
+
+    /*
+        object_type_any_name ( IF EXISTS )? any_name_list ( drop_behavior )?
+    */
+
+    let (target, existence, names, behavior) = seq!(
+        object_type_any_name,
+        if_exists.optional()
+            .map(Option::unwrap_or_default),
+        any_name_list,
+        drop_behavior.optional()
+            .map(Option::unwrap_or_default)
+    ).parse(ctx)?;
+
+    let stmt = DropStmt::new(target(names), existence, behavior);
+    Ok(stmt)
+}
+
+/// Alias: `object_type_any_name`
+fn object_type_any_name(ctx: &mut ParserContext) -> scan::Result<fn(Vec<QualifiedName>) -> DropTarget> {
+
+    /*
+        PROPERTY GRAPH
+    */
+
+    seq!(Property, Graph)
+        .map(|_| DropTarget::PropertyGraph as fn(_) -> _)
+        .parse(ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        DropBehavior,
+        Presence,
+    };
+    use test_case::test_case;
+
+    #[test_case("drop property graph g",
+        DropStmt::new(
+            DropTarget::PropertyGraph(vec![vec!["g".into()]]),
+            Presence::Fail,
+            DropBehavior::Restrict
+        ).into()
+    )]
+    #[test_case("drop property graph if exists g, some_schema.h cascade",
+        DropStmt::new(
+            DropTarget::PropertyGraph(vec![
+                vec!["g".into()],
+                vec!["some_schema".into(), "h".into()]
+            ]),
+            Presence::Ignore,
+            DropBehavior::Cascade
+        ).into()
+    )]
+    fn test_drop_stmt(source: &str, expected: RawStmt) {
+        test_parser!(source, drop_stmt, expected)
+    }
+}
+
+use crate::alt;
+use crate::combinators::any_name_list;
 use crate::combinators::core::parser;
 use crate::combinators::core::Combinator;
+use crate::combinators::drop_behavior;
+use crate::combinators::if_exists;
 use crate::seq;
 use crate::ParserContext;
+use pg_ast::DropStmt;
+use pg_ast::DropTarget;
 use pg_ast::RawStmt;
+use pg_basics::QualifiedName;
 use pg_lexer::Keyword::DropKw;
+use pg_lexer::Keyword::Graph;
+use pg_lexer::Keyword::Property;
 use pg_parser_core::scan;
@@ -3,7 +3,7 @@ enum Change {
     Options(Option<Vec<Str>>),
     Contents {
         action: AddDrop,
-        target: AlterExtensionContentsTarget,
+        target: Box<AlterExtensionContentsTarget>,
     }
 }
 
@@ -41,7 +41,7 @@ pub(in crate::combinators::stmt) fn alter_extension_stmt(ctx: &mut ParserContext
             AlterExtensionStmt::new(extension, options).into()
         },
         Change::Contents { action, target } => {
-            AlterExtensionContentsStmt::new(extension, action, target).into()
+            AlterExtensionContentsStmt::new(extension, action, *target).into()
         },
     };
 
@@ -73,7 +73,7 @@ fn change_content(ctx: &mut ParserContext) -> scan::Result<Change> {
         alter_extension_target
     ).parse(ctx)?;
 
-    Ok(Change::Contents { action, target })
+    Ok(Change::Contents { action, target: Box::new(target) })
 }
 
 /// Alias: `alter_extension_opt_list`
@@ -0,0 +1,102 @@
+/// Alias: `CreateExtensionStmt`
+pub(in crate::combinators::stmt) fn create_extension_stmt(ctx: &mut ParserContext) -> scan::Result<CreateExtensionStmt> {
+
+    /*
+        EXTENSION ( IF NOT EXISTS )? ColId ( WITH )? create_extension_opt_list
+    */
+
+    let (_, existence, name, _, options) = seq!(
+        Kw::Extension,
+        if_not_exists.optional()
+            .map(Option::unwrap_or_default),
+        col_id,
+        Kw::With.optional(),
+        many!(create_extension_option).optional()
+    ).parse(ctx)?;
+
+    let stmt = CreateExtensionStmt::new(name, options, existence);
+    Ok(stmt)
+}
+
+/// Alias: `create_extension_opt_item`
+fn create_extension_option(ctx: &mut ParserContext) -> scan::Result<CreateExtensionOption> {
+
+    /*
+          SCHEMA ColId
+        | VERSION NonReservedWord_or_Sconst
+        | FROM NonReservedWord_or_Sconst
+        | CASCADE
+    */
+
+    alt!(
+        seq!(Kw::Schema, col_id)
+            .map(|(_, schema)| Schema(schema)),
+        seq!(Kw::Version, non_reserved_word_or_sconst)
+            .map(|(_, version)| Version(version)),
+        from_version,
+        Kw::Cascade.map(|_| Cascade)
+    ).parse(ctx)
+}
+
+fn from_version(ctx: &mut ParserContext) -> scan::Result<CreateExtensionOption> {
+
+    let (Located(_, loc), _) = seq!(located!(Kw::FromKw), non_reserved_word_or_sconst)
+        .parse(ctx)?;
+
+    Err(CreateExtensionFrom.at_location(loc).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    use pg_ast::Presence;
+    use pg_elog::Error::Parser;
+    use scan::Error::ScanErr;
+    use test_case::test_case;
+
+    #[test_case("extension some_extension",
+        CreateExtensionStmt::new("some_extension", None, Presence::Fail)
+    )]
+    #[test_case("extension if not exists some_extension with schema some_schema version '1.2' cascade",
+        CreateExtensionStmt::new(
+            "some_extension",
+            Some(vec![
+                Schema("some_schema".into()),
+                Version("1.2".into()),
+                Cascade,
+            ]),
+            Presence::Ignore
+        )
+    )]
+    fn test_create_extension_stmt(source: &str, expected: CreateExtensionStmt) {
+        test_parser!(source, create_extension_stmt, expected)
+    }
+
+    #[test_case("extension some_extension from old_version" => matches Err(ScanErr(
+        Located(Parser(CreateExtensionFrom), _)
+    )))]
+    fn test_create_extension_from(source: &str) -> scan::Result<CreateExtensionStmt> {
+        test_parser!(source, create_extension_stmt)
+    }
+}
+
+use crate::alt;
+use crate::combinators::col_id;
+use crate::combinators::core::Combinator;
+use crate::combinators::if_not_exists;
+use crate::combinators::non_reserved_word_or_sconst;
+use crate::located;
+use crate::many;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::CreateExtensionOption;
+use pg_ast::CreateExtensionOption::Cascade;
+use pg_ast::CreateExtensionOption::Schema;
+use pg_ast::CreateExtensionOption::Version;
+use pg_ast::CreateExtensionStmt;
+use pg_basics::IntoLocated;
+use pg_basics::Located;
+use pg_elog::parser::Error::CreateExtensionFrom;
+use pg_lexer::Keyword as Kw;
+use pg_parser_core::scan;
@@ -0,0 +1,118 @@
+/// Alias: `CreatePLangStmt`
+///
+/// `opt_or_replace` is parsed by `with_or_replace`.
+pub(in crate::combinators::stmt) fn create_plang_stmt(replace: bool) -> impl Combinator<Output = CreatePLangStmt> {
+
+    /*
+        ( TRUSTED )? ( PROCEDURAL )? LANGUAGE ColId (
+            HANDLER handler_name
+            ( INLINE handler_name )?
+            ( validator_clause )?
+        )?
+    */
+
+    parser(move |ctx| {
+        let (trusted, _, _, name, handler) = seq!(
+            Trusted.optional(),
+            Procedural.optional(),
+            Language,
+            col_id,
+            handler_clause.optional()
+        ).parse(ctx)?;
+
+        let mut stmt = CreatePLangStmt::new(name)
+            .with_trusted(trusted.is_some())
+            .with_replace(replace);
+
+        stmt.set_handler(handler);
+        Ok(stmt)
+    })
+}
+
+fn handler_clause(ctx: &mut ParserContext) -> scan::Result<PLangHandler> {
+
+    /*
+        HANDLER handler_name ( INLINE handler_name )? ( validator_clause )?
+    */
+
+    let (_, handler, inline_handler, validator) = seq!(
+        Handler,
+        any_name,
+        seq!(Inline, any_name).optional(),
+        validator_clause.optional()
+    ).parse(ctx)?;
+
+    let mut handler = PLangHandler::new(handler);
+    handler.set_inline_handler(inline_handler.map(|(_, inline_handler)| inline_handler))
+        .set_validator(validator.flatten());
+
+    Ok(handler)
+}
+
+/// Alias: `validator_clause`
+fn validator_clause(ctx: &mut ParserContext) -> scan::Result<Option<QualifiedName>> {
+
+    /*
+          VALIDATOR handler_name
+        | NO VALIDATOR
+    */
+
+    alt!(
+        seq!(Validator, any_name)
+            .map(|(_, validator)| Some(validator)),
+        seq!(No, Validator)
+            .map(|_| None)
+    ).parse(ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combinators::with_or_replace;
+    use crate::test_parser;
+    use test_case::test_case;
+
+    #[test_case("language plsample",
+        CreatePLangStmt::new("plsample")
+    )]
+    #[test_case("or replace trusted procedural language plsample handler plsample_call_handler",
+        CreatePLangStmt::new("plsample")
+            .with_trusted(true)
+            .with_replace(true)
+            .with_handler(PLangHandler::new(vec!["plsample_call_handler".into()]))
+    )]
+    #[test_case("language plsample handler some_schema.call_handler inline inline_handler validator validator_fn",
+        CreatePLangStmt::new("plsample")
+            .with_handler(
+                PLangHandler::new(vec!["some_schema".into(), "call_handler".into()])
+                    .with_inline_handler(vec!["inline_handler".into()])
+                    .with_validator(vec!["validator_fn".into()])
+            )
+    )]
+    #[test_case("language plsample handler call_handler no validator",
+        CreatePLangStmt::new("plsample")
+            .with_handler(PLangHandler::new(vec!["call_handler".into()]))
+    )]
+    fn test_create_plang_stmt(source: &str, expected: CreatePLangStmt) {
+        test_parser!(source, with_or_replace(create_plang_stmt), expected)
+    }
+}
+
+use crate::alt;
+use crate::combinators::any_name;
+use crate::combinators::col_id;
+use crate::combinators::core::parser;
+use crate::combinators::core::Combinator;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::CreatePLangStmt;
+use pg_ast::PLangHandler;
+use pg_basics::QualifiedName;
+use pg_lexer::Keyword::Handler;
+use pg_lexer::Keyword::Inline;
+use pg_lexer::Keyword::Language;
+use pg_lexer::Keyword::No;
+use pg_lexer::Keyword::Procedural;
+use pg_lexer::Keyword::Trusted;
+use pg_lexer::Keyword::Validator;
+use pg_parser_core::scan;
@@ -1,3 +1,4 @@
 pg_basics::reexport! { pub(super)
-    alter
+    alter,
+    create,
 }
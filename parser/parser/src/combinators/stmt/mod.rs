@@ -31,6 +31,7 @@ pub(super) fn stmt(ctx: &mut ParserContext) -> scan::Result<RawStmt> {
         reset_stmt.map(VariableResetStmt),
         revoke_stmt,
         security_label_stmt.map(From::from),
+        select_stmt.map(From::from),
         set_stmt,
         show_stmt.map(VariableShowStmt),
         transaction_stmt.map(From::from),
@@ -65,6 +66,8 @@ mod tests {
             "reassign owned by public, test_role to target_role",
             "reset time zone",
             "security label for 'foo' on type int is 'bar'",
+            "select 1",
+            "(values (1)) union table foo",
             "set schema 'abc123'",
             "show all",
             "start transaction read only, read write deferrable",
@@ -97,6 +100,7 @@ pg_basics::reexport! {
     deallocate_stmt,
     default_privileges_stmt,
     discard_stmt,
+    domain_stmt,
     do_stmt,
     drop_stmt,
     event_trigger_stmt,
@@ -113,21 +117,31 @@ pg_basics::reexport! {
     lock_stmt,
     move_stmt,
     notify_stmt,
+    operator_stmt,
     operator_with_argtypes,
     prepare_stmt,
+    property_graph_stmt,
     reassign_owner_stmt,
     reindex_stmt,
     repack_stmt,
     reset_stmt,
     revoke_stmt,
     role_stmt,
+    rule_stmt,
+    schema_stmt,
     security_label_stmt,
+    sequence_stmt,
     set_reset_clause,
     set_rest,
     set_stmt,
     show_stmt,
+    statistics_stmt,
     system_stmt,
+    tablespace_stmt,
+    text_search_stmt,
+    transform_stmt,
     truncate_stmt,
+    type_stmt,
     unlisten_stmt,
     utility_options,
     vacuum_stmt,
@@ -139,7 +153,7 @@ pub(in crate::combinators) use transaction_stmt::transaction_stmt_legacy;
 use transaction_stmt::{transaction_mode_list, transaction_stmt};
 
 mod select_stmt;
-pub(in crate::combinators) use select_stmt::select_stmt;
+pub(in crate::combinators) use select_stmt::{select_stmt, select_stmt_starts, select_stmt_tail};
 
 use object_type_name::{
     access_method::*,
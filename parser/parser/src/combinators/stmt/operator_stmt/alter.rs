@@ -0,0 +1,198 @@
+enum Change {
+    Options(Vec<DefinitionElem>),
+    Owner(RoleSpec),
+    Schema(Str),
+}
+
+/// Aliases:
+/// * `AlterOperatorStmt`
+/// * `AlterOpFamilyStmt`
+/// * `AlterOwnerStmt` (only `OPERATOR`)
+/// * `AlterObjectSchemaStmt` (only `OPERATOR`)
+pub(in crate::combinators::stmt) fn alter_operator_stmt(ctx: &mut ParserContext) -> scan::Result<RawStmt> {
+
+    /*
+        OPERATOR (
+              FAMILY any_name USING ColId (
+                    ADD opclass_item_list
+                  | DROP opclass_drop_list
+              )
+            | operator_with_argtypes (
+                    SET '(' operator_def_list ')'
+                  | OWNER TO RoleSpec
+                  | SET SCHEMA ColId
+              )
+        )
+    */
+
+    let (_, stmt) = seq!(
+        Operator,
+        alt!(
+            alter_op_family.map(From::from),
+            alter_operator
+        )
+    ).parse(ctx)?;
+
+    Ok(stmt)
+}
+
+fn alter_op_family(ctx: &mut ParserContext) -> scan::Result<AlterOpFamilyStmt> {
+
+    let (_, name, _, index_method, action) = seq!(
+        Family,
+        any_name,
+        Using,
+        col_id,
+        alt!(
+            seq!(Add, op_class_item_list)
+                .map(|(_, items)| AlterOpFamilyAction::Add(items)),
+            seq!(DropKw, op_class_drop_list)
+                .map(|(_, items)| AlterOpFamilyAction::Drop(items))
+        )
+    ).parse(ctx)?;
+
+    Ok(AlterOpFamilyStmt::new(name, index_method, action))
+}
+
+fn alter_operator(ctx: &mut ParserContext) -> scan::Result<RawStmt> {
+
+    let (operator, change) = seq!(operator_with_argtypes, change)
+        .parse(ctx)?;
+
+    let stmt = match change {
+        Change::Options(options) => AlterOperatorStmt::new(operator, options).into(),
+        Change::Owner(new_owner) => {
+            AlterOwnerStmt::new(
+                AlterOwnerTarget::Operator(operator),
+                new_owner
+            ).into()
+        },
+        Change::Schema(new_schema) => {
+            AlterObjectSchemaStmt::new(
+                AlterObjectSchemaTarget::Operator(operator),
+                new_schema
+            ).into()
+        },
+    };
+
+    Ok(stmt)
+}
+
+fn change(ctx: &mut ParserContext) -> scan::Result<Change> {
+    alt!(
+        seq!(
+            Set,
+            alt!(
+                paren!(operator_def_list)
+                    .map(Change::Options),
+                seq!(Schema, col_id)
+                    .map(|(_, new_schema)| Change::Schema(new_schema))
+            )
+        ).map(|(_, change)| change),
+        seq!(Owner, To, role_spec)
+            .map(|(.., new_owner)| Change::Owner(new_owner))
+    ).parse(ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        DefinitionArg,
+        OneOrBoth,
+        OpClassDrop,
+        OpClassItem,
+        OpClassPurpose,
+        Operator::Less,
+        OperatorWithArgs,
+        RoleSpec::CurrentUser,
+        TypeName::Int4,
+    };
+    use test_case::test_case;
+
+    #[test_case("operator family some_family using btree add operator 1 < (int, int)",
+        AlterOpFamilyStmt::new(
+            vec!["some_family".into()],
+            "btree",
+            AlterOpFamilyAction::Add(vec![
+                OpClassItem::Operator {
+                    strategy: 1,
+                    operator: Less.into(),
+                    args: Some(OneOrBoth::Both(Int4.into(), Int4.into())),
+                    purpose: OpClassPurpose::Search
+                }
+            ])
+        ).into()
+    )]
+    #[test_case("operator family some_family using btree drop operator 1 (int, int), function 1 (int)",
+        AlterOpFamilyStmt::new(
+            vec!["some_family".into()],
+            "btree",
+            AlterOpFamilyAction::Drop(vec![
+                OpClassDrop::Operator { strategy: 1, args: vec![Int4.into(), Int4.into()] },
+                OpClassDrop::Function { support: 1, args: vec![Int4.into()] },
+            ])
+        ).into()
+    )]
+    #[test_case("operator < (int, int) set (restrict = none)",
+        AlterOperatorStmt::new(
+            OperatorWithArgs::new(Less, OneOrBoth::Both(Int4.into(), Int4.into())),
+            vec![DefinitionElem::new("restrict", Some(DefinitionArg::None))]
+        ).into()
+    )]
+    #[test_case("operator < (int, int) owner to current_user",
+        AlterOwnerStmt::new(
+            AlterOwnerTarget::Operator(
+                OperatorWithArgs::new(Less, OneOrBoth::Both(Int4.into(), Int4.into()))
+            ),
+            CurrentUser
+        ).into()
+    )]
+    #[test_case("operator < (int, int) set schema some_schema",
+        AlterObjectSchemaStmt::new(
+            AlterObjectSchemaTarget::Operator(
+                OperatorWithArgs::new(Less, OneOrBoth::Both(Int4.into(), Int4.into()))
+            ),
+            "some_schema"
+        ).into()
+    )]
+    fn test_alter_operator_stmt(source: &str, expected: RawStmt) {
+        test_parser!(source, alter_operator_stmt, expected)
+    }
+}
+
+use super::op_class_drop_list;
+use super::op_class_item_list;
+use crate::alt;
+use crate::combinators::any_name;
+use crate::combinators::col_id;
+use crate::combinators::core::Combinator;
+use crate::combinators::operator_def_list;
+use crate::combinators::role_spec;
+use crate::combinators::stmt::operator_with_argtypes;
+use crate::paren;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::AlterObjectSchemaStmt;
+use pg_ast::AlterObjectSchemaTarget;
+use pg_ast::AlterOpFamilyAction;
+use pg_ast::AlterOpFamilyStmt;
+use pg_ast::AlterOperatorStmt;
+use pg_ast::AlterOwnerStmt;
+use pg_ast::AlterOwnerTarget;
+use pg_ast::DefinitionElem;
+use pg_ast::RawStmt;
+use pg_ast::RoleSpec;
+use pg_basics::Str;
+use pg_lexer::Keyword::Add;
+use pg_lexer::Keyword::DropKw;
+use pg_lexer::Keyword::Family;
+use pg_lexer::Keyword::Operator;
+use pg_lexer::Keyword::Owner;
+use pg_lexer::Keyword::Schema;
+use pg_lexer::Keyword::Set;
+use pg_lexer::Keyword::To;
+use pg_lexer::Keyword::Using;
+use pg_parser_core::scan;
@@ -0,0 +1,137 @@
+/// Aliases:
+/// * `CreateOpClassStmt`
+/// * `CreateOpFamilyStmt`
+/// * `DefineStmt` (only `OPERATOR`)
+pub(in crate::combinators::stmt) fn create_operator_stmt(ctx: &mut ParserContext) -> scan::Result<RawStmt> {
+
+    /*
+        OPERATOR (
+              CLASS any_name ( DEFAULT )? FOR TYPE Typename USING ColId ( FAMILY any_name )? AS opclass_item_list
+            | FAMILY any_name USING ColId
+            | any_operator definition
+        )
+    */
+
+    let (_, stmt) = seq!(
+        Operator,
+        alt!(
+            create_op_class.map(From::from),
+            create_op_family.map(From::from),
+            seq!(any_operator, definition)
+                .map(|(name, definition)| DefineStmt::Operator { name, definition }.into())
+        )
+    ).parse(ctx)?;
+
+    Ok(stmt)
+}
+
+fn create_op_class(ctx: &mut ParserContext) -> scan::Result<CreateOpClassStmt> {
+
+    let (_, name, is_default, (.., data_type), (_, index_method), family, _, items) = seq!(
+        Class,
+        any_name,
+        DefaultKw.optional()
+            .map(|default| default.is_some()),
+        seq!(For, Type, typename),
+        seq!(Using, col_id),
+        seq!(Family, any_name).optional()
+            .map(|family| family.map(|(_, family)| family)),
+        As,
+        op_class_item_list
+    ).parse(ctx)?;
+
+    let mut stmt = CreateOpClassStmt::new(name, data_type, index_method, items, is_default);
+    stmt.set_family(family);
+
+    Ok(stmt)
+}
+
+fn create_op_family(ctx: &mut ParserContext) -> scan::Result<CreateOpFamilyStmt> {
+
+    let (_, name, _, index_method) = seq!(Family, any_name, Using, col_id)
+        .parse(ctx)?;
+
+    Ok(CreateOpFamilyStmt::new(name, index_method))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        DefinitionArg,
+        DefinitionElem,
+        FuncType,
+        OpClassItem,
+        OpClassPurpose,
+        Operator::{Less, UserDefined},
+        QualifiedOperator,
+        TypeName::{self, Int4},
+    };
+    use test_case::test_case;
+
+    #[test_case("operator some_schema.=== (leftarg = int, procedure = some_func)",
+        DefineStmt::Operator {
+            name: QualifiedOperator(vec!["some_schema".into()], UserDefined("===".into())),
+            definition: vec![
+                DefinitionElem::new("leftarg", Some(DefinitionArg::Type(FuncType::Type(Int4.into())))),
+                DefinitionElem::new(
+                    "procedure",
+                    Some(DefinitionArg::Type(FuncType::Type(
+                        TypeName::Generic { name: vec!["some_func".into()], type_modifiers: None }.into()
+                    )))
+                ),
+            ]
+        }.into()
+    )]
+    #[test_case("operator class some_class default for type int using btree family some_family as operator 1 <, storage int",
+        CreateOpClassStmt::new(
+            vec!["some_class".into()],
+            Int4,
+            "btree",
+            vec![
+                OpClassItem::Operator {
+                    strategy: 1,
+                    operator: Less.into(),
+                    args: None,
+                    purpose: OpClassPurpose::Search
+                },
+                OpClassItem::Storage(Int4.into()),
+            ],
+            true
+        )
+            .with_family(vec!["some_family".into()])
+            .into()
+    )]
+    #[test_case("operator family some_family using gist",
+        CreateOpFamilyStmt::new(vec!["some_family".into()], "gist").into()
+    )]
+    fn test_create_operator_stmt(source: &str, expected: RawStmt) {
+        test_parser!(source, create_operator_stmt, expected)
+    }
+}
+
+use super::op_class_item_list;
+use crate::alt;
+use crate::combinators::any_name;
+use crate::combinators::any_operator;
+use crate::combinators::col_id;
+use crate::combinators::core::Combinator;
+use crate::combinators::definition;
+use crate::combinators::typename;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::CreateOpClassStmt;
+use pg_ast::CreateOpFamilyStmt;
+use pg_ast::DefineStmt;
+use pg_ast::RawStmt;
+use pg_lexer::Keyword::As;
+use pg_lexer::Keyword::Class;
+use pg_lexer::Keyword::DefaultKw;
+use pg_lexer::Keyword::Family;
+use pg_lexer::Keyword::For;
+use pg_lexer::Keyword::Operator;
+use pg_lexer::Keyword::Type;
+use pg_lexer::Keyword::Using;
+use pg_parser_core::scan;
@@ -0,0 +1,8 @@
+pg_basics::reexport! { pub(super)
+    alter,
+    create,
+}
+
+pg_basics::reexport! {
+    op_class_item,
+}
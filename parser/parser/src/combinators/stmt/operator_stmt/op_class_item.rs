@@ -0,0 +1,173 @@
+/// Alias: `opclass_item_list`
+pub(super) fn op_class_item_list(ctx: &mut ParserContext) -> scan::Result<Vec<OpClassItem>> {
+
+    /*
+        opclass_item ( ',' opclass_item )*
+    */
+
+    many!(sep = Comma, op_class_item).parse(ctx)
+}
+
+/// Alias: `opclass_item`
+fn op_class_item(ctx: &mut ParserContext) -> scan::Result<OpClassItem> {
+
+    /*
+          OPERATOR ICONST any_operator ( oper_argtypes )? opclass_purpose
+        | FUNCTION ICONST ( '(' type_list ')' )? function_with_argtypes
+        | STORAGE Typename
+    */
+
+    alt!(
+        seq!(
+            Operator,
+            i32_literal,
+            any_operator,
+            oper_argtypes.optional(),
+            op_class_purpose.optional()
+                .map(Option::unwrap_or_default)
+        ).map(|(_, strategy, operator, args, purpose)|
+            OpClassItem::Operator { strategy, operator, args, purpose }
+        ),
+        seq!(
+            Function,
+            i32_literal,
+            paren!(type_list).optional(),
+            function_with_argtypes
+        ).map(|(_, support, class_args, function)|
+            OpClassItem::Function { support, class_args, function }
+        ),
+        seq!(Storage, typename)
+            .map(|(_, typ)| OpClassItem::Storage(typ))
+    ).parse(ctx)
+}
+
+/// Alias: `opclass_purpose`
+fn op_class_purpose(ctx: &mut ParserContext) -> scan::Result<OpClassPurpose> {
+
+    /*
+          FOR SEARCH
+        | FOR ORDER BY any_name
+    */
+
+    let (_, purpose) = seq!(
+        For,
+        alt!(
+            Search.map(|_| OpClassPurpose::Search),
+            seq!(Order, By, any_name)
+                .map(|(.., family)| OpClassPurpose::OrderBy(family))
+        )
+    ).parse(ctx)?;
+
+    Ok(purpose)
+}
+
+/// Alias: `opclass_drop_list`
+pub(super) fn op_class_drop_list(ctx: &mut ParserContext) -> scan::Result<Vec<OpClassDrop>> {
+
+    /*
+        opclass_drop ( ',' opclass_drop )*
+    */
+
+    many!(sep = Comma, op_class_drop).parse(ctx)
+}
+
+/// Alias: `opclass_drop`
+fn op_class_drop(ctx: &mut ParserContext) -> scan::Result<OpClassDrop> {
+
+    /*
+          OPERATOR ICONST '(' type_list ')'
+        | FUNCTION ICONST '(' type_list ')'
+    */
+
+    alt!(
+        seq!(Operator, i32_literal, paren!(type_list))
+            .map(|(_, strategy, args)| OpClassDrop::Operator { strategy, args }),
+        seq!(Function, i32_literal, paren!(type_list))
+            .map(|(_, support, args)| OpClassDrop::Function { support, args })
+    ).parse(ctx)
+}
+
+/// Alias: `type_list`
+fn type_list(ctx: &mut ParserContext) -> scan::Result<Vec<Type>> {
+    many!(sep = Comma, typename).parse(ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        FunctionWithArgs,
+        OneOrBoth,
+        Operator::{Less, Equals},
+        TypeName::{Int4, Int8},
+    };
+    use test_case::test_case;
+
+    #[test_case("operator 1 <",
+        OpClassItem::Operator {
+            strategy: 1,
+            operator: Less.into(),
+            args: None,
+            purpose: OpClassPurpose::Search
+        }
+    )]
+    #[test_case("operator 3 = (int, bigint) for order by some_family",
+        OpClassItem::Operator {
+            strategy: 3,
+            operator: Equals.into(),
+            args: Some(OneOrBoth::Both(Int4.into(), Int8.into())),
+            purpose: OpClassPurpose::OrderBy(vec!["some_family".into()])
+        }
+    )]
+    #[test_case("function 1 (int, int) some_func",
+        OpClassItem::Function {
+            support: 1,
+            class_args: Some(vec![Int4.into(), Int4.into()]),
+            function: FunctionWithArgs::new(vec!["some_func".into()], None)
+        }
+    )]
+    #[test_case("storage int",
+        OpClassItem::Storage(Int4.into())
+    )]
+    fn test_op_class_item(source: &str, expected: OpClassItem) {
+        test_parser!(source, op_class_item, expected)
+    }
+
+    #[test_case("operator 1 (int, int)",
+        OpClassDrop::Operator { strategy: 1, args: vec![Int4.into(), Int4.into()] }
+    )]
+    #[test_case("function 2 (bigint)",
+        OpClassDrop::Function { support: 2, args: vec![Int8.into()] }
+    )]
+    fn test_op_class_drop(source: &str, expected: OpClassDrop) {
+        test_parser!(source, op_class_drop, expected)
+    }
+}
+
+use crate::alt;
+use crate::combinators::any_name;
+use crate::combinators::any_operator;
+use crate::combinators::core::Combinator;
+use crate::combinators::function_with_argtypes;
+use crate::combinators::i32_literal;
+use crate::combinators::stmt::oper_argtypes;
+use crate::combinators::typename;
+use crate::many;
+use crate::paren;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::OpClassDrop;
+use pg_ast::OpClassItem;
+use pg_ast::OpClassPurpose;
+use pg_ast::Type;
+use pg_lexer::Keyword::By;
+use pg_lexer::Keyword::For;
+use pg_lexer::Keyword::Function;
+use pg_lexer::Keyword::Operator;
+use pg_lexer::Keyword::Order;
+use pg_lexer::Keyword::Search;
+use pg_lexer::Keyword::Storage;
+use pg_lexer::OperatorKind::Comma;
+use pg_parser_core::scan;
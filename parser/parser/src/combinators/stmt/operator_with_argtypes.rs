@@ -18,7 +18,7 @@ pub(super) fn operator_with_argtypes(ctx: &mut ParserContext) -> scan::Result<Op
     Ok(OperatorWithArgs::new(name, args))
 }
 
-fn oper_argtypes(ctx: &mut ParserContext) -> scan::Result<OneOrBoth<Type>> {
+pub(super) fn oper_argtypes(ctx: &mut ParserContext) -> scan::Result<OneOrBoth<Type>> {
 
     /*
           '(' NONE ',' Typename ')'
@@ -0,0 +1,342 @@
+enum Change {
+    Action(AlterPropGraphAction),
+    Owner(RoleSpec),
+    Name(Str),
+    Schema(Str),
+}
+
+enum PropertiesChange {
+    Add(Vec<NamedValue>),
+    Drop(Vec<Str>, DropBehavior),
+}
+
+/// Aliases:
+/// * `AlterPropGraphStmt`
+/// * `AlterOwnerStmt` (only `PROPERTY GRAPH`)
+/// * `AlterObjectSchemaStmt` (only `PROPERTY GRAPH`)
+/// * `RenameStmt` (only `PROPERTY GRAPH`)
+pub(in crate::combinators::stmt) fn alter_prop_graph_stmt(ctx: &mut ParserContext) -> scan::Result<RawStmt> {
+
+    /*
+        PROPERTY GRAPH (
+              IF EXISTS any_name SET SCHEMA ColId
+            | any_name (
+                  ADD vertex_tables_clause ( ADD edge_tables_clause )?
+                | ADD edge_tables_clause
+                | DROP element_kind TABLES '(' name_list ')' ( drop_behavior )?
+                | ALTER element_kind TABLE ColId alter_table_action
+                | OWNER TO RoleSpec
+                | RENAME TO ColId
+                | SET SCHEMA ColId
+            )
+        )
+    */
+
+    let (.., stmt) = seq!(
+        Property,
+        Graph,
+        alt!(
+            seq!(if_exists, any_name, Set, Schema, col_id)
+                .map(|(_, graph, .., new_schema)|
+                    AlterObjectSchemaStmt::new(
+                        AlterObjectSchemaTarget::PropertyGraph { target: graph, missing_ok: true },
+                        new_schema
+                    ).into()
+                ),
+            seq!(any_name, change)
+                .map(|(graph, change)| match change {
+                    Change::Action(action) => AlterPropGraphStmt::new(graph, action).into(),
+                    Change::Owner(new_owner) => {
+                        AlterOwnerStmt::new(
+                            AlterOwnerTarget::PropertyGraph(graph),
+                            new_owner
+                        ).into()
+                    },
+                    Change::Name(new_name) => {
+                        RenameStmt::new(
+                            RenameTarget::PropertyGraph(graph),
+                            new_name
+                        ).into()
+                    },
+                    Change::Schema(new_schema) => {
+                        AlterObjectSchemaStmt::new(
+                            AlterObjectSchemaTarget::PropertyGraph { target: graph, missing_ok: false },
+                            new_schema
+                        ).into()
+                    },
+                })
+        )
+    ).parse(ctx)?;
+
+    Ok(stmt)
+}
+
+fn change(ctx: &mut ParserContext) -> scan::Result<Change> {
+    alt!(
+        seq!(
+            Add,
+            alt!(
+                seq!(
+                    vertex_tables_clause,
+                    seq!(Add, edge_tables_clause).optional()
+                ).map(|(vertex_tables, edge_tables)|
+                    AlterPropGraphAction::AddTables {
+                        vertex_tables: Some(vertex_tables),
+                        edge_tables: edge_tables.map(|(_, tables)| tables)
+                    }
+                ),
+                edge_tables_clause
+                    .map(|edge_tables|
+                        AlterPropGraphAction::AddTables {
+                            vertex_tables: None,
+                            edge_tables: Some(edge_tables)
+                        }
+                    )
+            )
+        ).map(|(_, action)| Change::Action(action)),
+        seq!(
+            DropKw,
+            element_kind,
+            Tables,
+            paren!(name_list),
+            drop_behavior.optional()
+                .map(Option::unwrap_or_default)
+        ).map(|(_, kind, _, tables, behavior)|
+            Change::Action(AlterPropGraphAction::DropTables { kind, tables, behavior })
+        ),
+        seq!(Alter, element_kind, Table, col_id, alter_table_action)
+            .map(|(_, kind, _, table, action)|
+                Change::Action(AlterPropGraphAction::AlterTable { kind, table, action })
+            ),
+        seq!(Owner, To, role_spec)
+            .map(|(.., new_owner)| Change::Owner(new_owner)),
+        seq!(Rename, To, col_id)
+            .map(|(.., new_name)| Change::Name(new_name)),
+        seq!(Set, Schema, col_id)
+            .map(|(.., new_schema)| Change::Schema(new_schema))
+    ).parse(ctx)
+}
+
+fn element_kind(ctx: &mut ParserContext) -> scan::Result<PropGraphElementKind> {
+
+    /*
+          VERTEX
+        | NODE
+        | EDGE
+        | RELATIONSHIP
+    */
+
+    alt!(
+        alt!(Vertex, Node).map(|_| PropGraphElementKind::Vertex),
+        alt!(Edge, Relationship).map(|_| PropGraphElementKind::Edge)
+    ).parse(ctx)
+}
+
+fn alter_table_action(ctx: &mut ParserContext) -> scan::Result<AlterPropGraphTableAction> {
+
+    /*
+          ( ADD label_and_properties )+
+        | DROP LABEL ColId ( drop_behavior )?
+        | ALTER LABEL ColId ADD PROPERTIES '(' labeled_expr_list ')'
+        | ALTER LABEL ColId DROP PROPERTIES '(' name_list ')' ( drop_behavior )?
+    */
+
+    alt!(
+        many!(
+            seq!(Add, label_and_properties)
+                .map(|(_, label)| label)
+        ).map(AlterPropGraphTableAction::AddLabels),
+        seq!(
+            DropKw,
+            Label,
+            col_id,
+            drop_behavior.optional()
+                .map(Option::unwrap_or_default)
+        ).map(|(.., label, behavior)|
+            AlterPropGraphTableAction::DropLabel { label, behavior }
+        ),
+        seq!(
+            Alter,
+            Label,
+            col_id,
+            alt!(
+                seq!(Add, Properties, paren!(labeled_expr_list))
+                    .map(|(.., properties)| PropertiesChange::Add(properties)),
+                seq!(
+                    DropKw,
+                    Properties,
+                    paren!(name_list),
+                    drop_behavior.optional()
+                        .map(Option::unwrap_or_default)
+                ).map(|(.., properties, behavior)| PropertiesChange::Drop(properties, behavior))
+            )
+        ).map(|(.., label, change)| match change {
+            PropertiesChange::Add(properties) => {
+                AlterPropGraphTableAction::AddProperties { label, properties }
+            },
+            PropertiesChange::Drop(properties, behavior) => {
+                AlterPropGraphTableAction::DropProperties { label, properties, behavior }
+            },
+        })
+    ).parse(ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        ExprKind::IntegerConst,
+        PropGraphLabel,
+        PropGraphProperties,
+        PropGraphVertexTable,
+        RoleSpec::CurrentUser,
+    };
+    use test_case::test_case;
+
+    #[test_case("property graph g add vertex tables (persons)",
+        AlterPropGraphStmt::new(
+            vec!["g".into()],
+            AlterPropGraphAction::AddTables {
+                vertex_tables: Some(vec![PropGraphVertexTable::new("persons")]),
+                edge_tables: None
+            }
+        ).into()
+    )]
+    #[test_case("property graph g drop node tables (persons, cities) cascade",
+        AlterPropGraphStmt::new(
+            vec!["g".into()],
+            AlterPropGraphAction::DropTables {
+                kind: PropGraphElementKind::Vertex,
+                tables: vec!["persons".into(), "cities".into()],
+                behavior: DropBehavior::Cascade
+            }
+        ).into()
+    )]
+    #[test_case("property graph g alter edge table friends add label knows add label likes no properties",
+        AlterPropGraphStmt::new(
+            vec!["g".into()],
+            AlterPropGraphAction::AlterTable {
+                kind: PropGraphElementKind::Edge,
+                table: "friends".into(),
+                action: AlterPropGraphTableAction::AddLabels(vec![
+                    PropGraphLabel::new(Some("knows".into()), PropGraphProperties::AllColumns),
+                    PropGraphLabel::new(Some("likes".into()), PropGraphProperties::None),
+                ])
+            }
+        ).into()
+    )]
+    #[test_case("property graph g alter vertex table persons drop label person",
+        AlterPropGraphStmt::new(
+            vec!["g".into()],
+            AlterPropGraphAction::AlterTable {
+                kind: PropGraphElementKind::Vertex,
+                table: "persons".into(),
+                action: AlterPropGraphTableAction::DropLabel {
+                    label: "person".into(),
+                    behavior: DropBehavior::Restrict
+                }
+            }
+        ).into()
+    )]
+    #[test_case("property graph g alter vertex table persons alter label person add properties (1 as one)",
+        AlterPropGraphStmt::new(
+            vec!["g".into()],
+            AlterPropGraphAction::AlterTable {
+                kind: PropGraphElementKind::Vertex,
+                table: "persons".into(),
+                action: AlterPropGraphTableAction::AddProperties {
+                    label: "person".into(),
+                    properties: vec![NamedValue::new(Some("one".into()), IntegerConst(1).into())]
+                }
+            }
+        ).into()
+    )]
+    #[test_case("property graph g alter vertex table persons alter label person drop properties (one) cascade",
+        AlterPropGraphStmt::new(
+            vec!["g".into()],
+            AlterPropGraphAction::AlterTable {
+                kind: PropGraphElementKind::Vertex,
+                table: "persons".into(),
+                action: AlterPropGraphTableAction::DropProperties {
+                    label: "person".into(),
+                    properties: vec!["one".into()],
+                    behavior: DropBehavior::Cascade
+                }
+            }
+        ).into()
+    )]
+    #[test_case("property graph g owner to current_user",
+        AlterOwnerStmt::new(
+            AlterOwnerTarget::PropertyGraph(vec!["g".into()]),
+            CurrentUser
+        ).into()
+    )]
+    #[test_case("property graph g rename to h",
+        RenameStmt::new(
+            RenameTarget::PropertyGraph(vec!["g".into()]),
+            "h"
+        ).into()
+    )]
+    #[test_case("property graph if exists g set schema some_schema",
+        AlterObjectSchemaStmt::new(
+            AlterObjectSchemaTarget::PropertyGraph { target: vec!["g".into()], missing_ok: true },
+            "some_schema"
+        ).into()
+    )]
+    fn test_alter_prop_graph_stmt(source: &str, expected: RawStmt) {
+        test_parser!(source, alter_prop_graph_stmt, expected)
+    }
+}
+
+use super::edge_tables_clause;
+use super::label_and_properties;
+use super::vertex_tables_clause;
+use crate::alt;
+use crate::combinators::any_name;
+use crate::combinators::col_id;
+use crate::combinators::core::Combinator;
+use crate::combinators::drop_behavior;
+use crate::combinators::if_exists;
+use crate::combinators::labeled_expr_list;
+use crate::combinators::name_list;
+use crate::combinators::role_spec;
+use crate::many;
+use crate::paren;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::AlterObjectSchemaStmt;
+use pg_ast::AlterObjectSchemaTarget;
+use pg_ast::AlterOwnerStmt;
+use pg_ast::AlterOwnerTarget;
+use pg_ast::AlterPropGraphAction;
+use pg_ast::AlterPropGraphStmt;
+use pg_ast::AlterPropGraphTableAction;
+use pg_ast::DropBehavior;
+use pg_ast::NamedValue;
+use pg_ast::PropGraphElementKind;
+use pg_ast::RawStmt;
+use pg_ast::RenameStmt;
+use pg_ast::RenameTarget;
+use pg_ast::RoleSpec;
+use pg_basics::Str;
+use pg_lexer::Keyword::Add;
+use pg_lexer::Keyword::Alter;
+use pg_lexer::Keyword::DropKw;
+use pg_lexer::Keyword::Edge;
+use pg_lexer::Keyword::Graph;
+use pg_lexer::Keyword::Label;
+use pg_lexer::Keyword::Node;
+use pg_lexer::Keyword::Owner;
+use pg_lexer::Keyword::Properties;
+use pg_lexer::Keyword::Property;
+use pg_lexer::Keyword::Relationship;
+use pg_lexer::Keyword::Rename;
+use pg_lexer::Keyword::Schema;
+use pg_lexer::Keyword::Set;
+use pg_lexer::Keyword::Table;
+use pg_lexer::Keyword::Tables;
+use pg_lexer::Keyword::To;
+use pg_lexer::Keyword::Vertex;
+use pg_parser_core::scan;
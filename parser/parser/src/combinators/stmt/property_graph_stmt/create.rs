@@ -0,0 +1,90 @@
+/// Alias: `CreatePropGraphStmt`
+///
+/// `OptTemp` is parsed by `with_relation_persistence`.
+pub(in crate::combinators::stmt) fn create_prop_graph_stmt(persistence: RelationPersistence) -> impl Combinator<Output = CreatePropGraphStmt> {
+
+    /*
+        PROPERTY GRAPH qualified_name ( vertex_tables_clause )? ( edge_tables_clause )?
+    */
+
+    parser(move |ctx| {
+        let (Located(_, loc), _, name, vertex_tables, edge_tables) = seq!(
+            located!(Property),
+            Graph,
+            qualified_name,
+            vertex_tables_clause.optional(),
+            edge_tables_clause.optional()
+        ).parse(ctx)?;
+
+        if persistence == Unlogged {
+            return Err(UnloggedPropertyGraph.at_location(loc).into())
+        }
+
+        let mut stmt = CreatePropGraphStmt::new(RangeVar::with_persistence(name, persistence));
+        stmt.set_vertex_tables(vertex_tables)
+            .set_edge_tables(edge_tables);
+
+        Ok(stmt)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combinators::with_relation_persistence;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        PropGraphEdgeTable,
+        PropGraphVertexReference,
+        PropGraphVertexTable,
+        RelationName,
+        RelationPersistence::{Permanent, Temp},
+    };
+    use pg_elog::Error::Parser;
+    use pg_parser_core::scan;
+    use scan::Error::ScanErr;
+    use test_case::test_case;
+
+    #[test_case("property graph g",
+        CreatePropGraphStmt::new(RangeVar::with_persistence(RelationName::new("g"), Permanent))
+    )]
+    #[test_case("temp property graph g vertex tables (persons) edge tables (friends source persons destination persons)",
+        CreatePropGraphStmt::new(RangeVar::with_persistence(RelationName::new("g"), Temp))
+            .with_vertex_tables(vec![PropGraphVertexTable::new("persons")])
+            .with_edge_tables(vec![
+                PropGraphEdgeTable::new(
+                    "friends",
+                    PropGraphVertexReference::new("persons"),
+                    PropGraphVertexReference::new("persons")
+                )
+            ])
+    )]
+    fn test_create_prop_graph_stmt(source: &str, expected: CreatePropGraphStmt) {
+        test_parser!(source, with_relation_persistence(create_prop_graph_stmt), expected)
+    }
+
+    #[test_case("unlogged property graph g" => matches Err(ScanErr(
+        Located(Parser(UnloggedPropertyGraph), _)
+    )))]
+    fn test_unlogged_property_graph(source: &str) -> scan::Result<CreatePropGraphStmt> {
+        test_parser!(source, with_relation_persistence(create_prop_graph_stmt))
+    }
+}
+
+use super::edge_tables_clause;
+use super::vertex_tables_clause;
+use crate::combinators::core::parser;
+use crate::combinators::core::Combinator;
+use crate::combinators::qualified_name;
+use crate::located;
+use crate::seq;
+use pg_ast::CreatePropGraphStmt;
+use pg_ast::RangeVar;
+use pg_ast::RelationPersistence;
+use pg_ast::RelationPersistence::Unlogged;
+use pg_basics::IntoLocated;
+use pg_basics::Located;
+use pg_elog::parser::Error::UnloggedPropertyGraph;
+use pg_lexer::Keyword::Graph;
+use pg_lexer::Keyword::Property;
@@ -0,0 +1,293 @@
+/// Alias: `vertex_tables_clause`
+pub(super) fn vertex_tables_clause(ctx: &mut ParserContext) -> scan::Result<Vec<PropGraphVertexTable>> {
+
+    /*
+        ( VERTEX | NODE ) TABLES '(' vertex_table_definition ( ',' vertex_table_definition )* ')'
+    */
+
+    let (.., tables) = seq!(
+        alt!(Vertex, Node),
+        Tables,
+        paren!(many!(sep = Comma, vertex_table_definition))
+    ).parse(ctx)?;
+
+    Ok(tables)
+}
+
+/// Alias: `edge_tables_clause`
+pub(super) fn edge_tables_clause(ctx: &mut ParserContext) -> scan::Result<Vec<PropGraphEdgeTable>> {
+
+    /*
+        ( EDGE | RELATIONSHIP ) TABLES '(' edge_table_definition ( ',' edge_table_definition )* ')'
+    */
+
+    let (.., tables) = seq!(
+        alt!(Edge, Relationship),
+        Tables,
+        paren!(many!(sep = Comma, edge_table_definition))
+    ).parse(ctx)?;
+
+    Ok(tables)
+}
+
+/// Alias: `vertex_table_definition`
+fn vertex_table_definition(ctx: &mut ParserContext) -> scan::Result<PropGraphVertexTable> {
+
+    /*
+        qualified_name ( AS ColId )? ( KEY '(' columnList ')' )? ( element_table_label_and_properties )?
+    */
+
+    let (table, alias, key, labels) = seq!(
+        qualified_name,
+        table_alias.optional(),
+        table_key.optional(),
+        label_and_properties_list.optional()
+    ).parse(ctx)?;
+
+    let mut table = PropGraphVertexTable::new(table);
+    table.set_alias(alias)
+        .set_key(key)
+        .set_labels(labels);
+
+    Ok(table)
+}
+
+/// Alias: `edge_table_definition`
+fn edge_table_definition(ctx: &mut ParserContext) -> scan::Result<PropGraphEdgeTable> {
+
+    /*
+        qualified_name ( AS ColId )? ( KEY '(' columnList ')' )?
+        source_vertex_table
+        destination_vertex_table
+        ( element_table_label_and_properties )?
+    */
+
+    let (table, alias, key, (_, source), (_, destination), labels) = seq!(
+        qualified_name,
+        table_alias.optional(),
+        table_key.optional(),
+        seq!(Source, vertex_reference),
+        seq!(Destination, vertex_reference),
+        label_and_properties_list.optional()
+    ).parse(ctx)?;
+
+    let mut table = PropGraphEdgeTable::new(table, source, destination);
+    table.set_alias(alias)
+        .set_key(key)
+        .set_labels(labels);
+
+    Ok(table)
+}
+
+/// Alias: `opt_propgraph_table_alias`
+fn table_alias(ctx: &mut ParserContext) -> scan::Result<Str> {
+
+    /*
+        AS ColId
+    */
+
+    let (_, alias) = seq!(As, col_id).parse(ctx)?;
+    Ok(alias)
+}
+
+/// Alias: `graph_table_key_clause`
+fn table_key(ctx: &mut ParserContext) -> scan::Result<Vec<Str>> {
+
+    /*
+        KEY '(' columnList ')'
+    */
+
+    let (_, key) = seq!(Key, paren!(name_list)).parse(ctx)?;
+    Ok(key)
+}
+
+/// Aliases:
+/// * `source_vertex_table`
+/// * `destination_vertex_table`
+fn vertex_reference(ctx: &mut ParserContext) -> scan::Result<PropGraphVertexReference> {
+
+    /*
+          KEY '(' columnList ')' REFERENCES ColId '(' columnList ')'
+        | ColId
+    */
+
+    alt!(
+        seq!(table_key, References, col_id, paren!(name_list))
+            .map(|(key, _, vertex, referenced)|
+                PropGraphVertexReference::new(vertex).with_key(key, referenced)
+            ),
+        col_id.map(PropGraphVertexReference::new)
+    ).parse(ctx)
+}
+
+/// Alias: `element_table_label_and_properties`
+fn label_and_properties_list(ctx: &mut ParserContext) -> scan::Result<Vec<PropGraphLabel>> {
+
+    /*
+          element_table_properties
+        | ( label_and_properties )+
+    */
+
+    alt!(
+        // Properties without a label belong to the default label.
+        element_table_properties
+            .map(|properties| vec![PropGraphLabel::new(None, properties)]),
+        many!(label_and_properties)
+    ).parse(ctx)
+}
+
+/// Alias: `label_and_properties`
+pub(super) fn label_and_properties(ctx: &mut ParserContext) -> scan::Result<PropGraphLabel> {
+
+    /*
+        element_table_label_clause ( element_table_properties )?
+    */
+
+    let (name, properties) = seq!(
+        label_clause,
+        element_table_properties.optional()
+            .map(Option::unwrap_or_default)
+    ).parse(ctx)?;
+
+    Ok(PropGraphLabel::new(name, properties))
+}
+
+/// Alias: `element_table_label_clause`
+fn label_clause(ctx: &mut ParserContext) -> scan::Result<Option<Str>> {
+
+    /*
+          LABEL ColId
+        | DEFAULT LABEL
+    */
+
+    alt!(
+        seq!(Label, col_id)
+            .map(|(_, name)| Some(name)),
+        seq!(DefaultKw, Label)
+            .map(|_| None)
+    ).parse(ctx)
+}
+
+/// Alias: `element_table_properties`
+fn element_table_properties(ctx: &mut ParserContext) -> scan::Result<PropGraphProperties> {
+
+    /*
+          NO PROPERTIES
+        | PROPERTIES ALL COLUMNS
+        | PROPERTIES '(' labeled_expr_list ')'
+    */
+
+    alt!(
+        seq!(No, Properties)
+            .map(|_| PropGraphProperties::None),
+        seq!(
+            Properties,
+            alt!(
+                seq!(All, Columns)
+                    .map(|_| PropGraphProperties::AllColumns),
+                paren!(labeled_expr_list)
+                    .map(PropGraphProperties::List)
+            )
+        ).map(|(_, properties)| properties)
+    ).parse(ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        ExprKind::IntegerConst,
+        NamedValue,
+        RelationName,
+    };
+    use test_case::test_case;
+
+    #[test]
+    fn test_vertex_tables_clause() {
+        test_parser!(
+            source = "vertex tables (persons, some_schema.cities as towns key (id) label city no properties)",
+            parser = vertex_tables_clause,
+            expected = vec![
+                PropGraphVertexTable::new("persons"),
+                PropGraphVertexTable::new(RelationName::new("cities").with_schema("some_schema"))
+                    .with_alias("towns")
+                    .with_key(vec!["id".into()])
+                    .with_labels(vec![
+                        PropGraphLabel::new(Some("city".into()), PropGraphProperties::None)
+                    ]),
+            ]
+        )
+    }
+
+    #[test]
+    fn test_edge_tables_clause() {
+        test_parser!(
+            source = "relationship tables (friends source key (a) references persons (id) destination persons)",
+            parser = edge_tables_clause,
+            expected = vec![
+                PropGraphEdgeTable::new(
+                    "friends",
+                    PropGraphVertexReference::new("persons")
+                        .with_key(vec!["a".into()], vec!["id".into()]),
+                    PropGraphVertexReference::new("persons")
+                ),
+            ]
+        )
+    }
+
+    #[test_case("properties all columns",
+        vec![PropGraphLabel::new(None, PropGraphProperties::AllColumns)]
+    )]
+    #[test_case("properties (1 as one)",
+        vec![PropGraphLabel::new(
+            None,
+            PropGraphProperties::List(vec![NamedValue::new(Some("one".into()), IntegerConst(1).into())])
+        )]
+    )]
+    #[test_case("default label label foo no properties",
+        vec![
+            PropGraphLabel::new(None, PropGraphProperties::AllColumns),
+            PropGraphLabel::new(Some("foo".into()), PropGraphProperties::None),
+        ]
+    )]
+    fn test_label_and_properties_list(source: &str, expected: Vec<PropGraphLabel>) {
+        test_parser!(source, label_and_properties_list, expected)
+    }
+}
+
+use crate::alt;
+use crate::combinators::col_id;
+use crate::combinators::core::Combinator;
+use crate::combinators::labeled_expr_list;
+use crate::combinators::name_list;
+use crate::combinators::qualified_name;
+use crate::many;
+use crate::paren;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::PropGraphEdgeTable;
+use pg_ast::PropGraphLabel;
+use pg_ast::PropGraphProperties;
+use pg_ast::PropGraphVertexReference;
+use pg_ast::PropGraphVertexTable;
+use pg_basics::Str;
+use pg_lexer::Keyword::All;
+use pg_lexer::Keyword::As;
+use pg_lexer::Keyword::Columns;
+use pg_lexer::Keyword::DefaultKw;
+use pg_lexer::Keyword::Destination;
+use pg_lexer::Keyword::Edge;
+use pg_lexer::Keyword::Key;
+use pg_lexer::Keyword::Label;
+use pg_lexer::Keyword::No;
+use pg_lexer::Keyword::Node;
+use pg_lexer::Keyword::Properties;
+use pg_lexer::Keyword::References;
+use pg_lexer::Keyword::Relationship;
+use pg_lexer::Keyword::Source;
+use pg_lexer::Keyword::Tables;
+use pg_lexer::Keyword::Vertex;
+use pg_lexer::OperatorKind::Comma;
+use pg_parser_core::scan;
@@ -20,7 +20,7 @@ fn create_role_option(ctx: &mut ParserContext) -> scan::Result<CreateRoleOption>
         sysid,
         admin,
         role,
-        inherit,
+        in_role,
         alter_role_option.map(CreateRoleOption::from)
     ).parse(ctx)
 }
@@ -37,13 +37,13 @@ fn admin(ctx: &mut ParserContext) -> scan::Result<CreateRoleOption> {
 
 fn role(ctx: &mut ParserContext) -> scan::Result<CreateRoleOption> {
     let (_, roles) = seq!(Role, role_list).parse(ctx)?;
-    Ok(CreateRoleOption::AddRoleTo(roles))
+    Ok(CreateRoleOption::RoleMembers(roles))
 }
 
-fn inherit(ctx: &mut ParserContext) -> scan::Result<CreateRoleOption> {
+fn in_role(ctx: &mut ParserContext) -> scan::Result<CreateRoleOption> {
 
     let (.., roles) = seq!(
-        Inherit,
+        In,
         alt!(Role, Group),
         role_list
     ).parse(ctx)?;
@@ -61,12 +61,12 @@ mod tests {
     #[test]
     fn test_create_role_options() {
         test_parser!(
-            source = "sysid 42 admin public role public inherit group public",
+            source = "sysid 42 admin public role public in group public",
             parser = create_role_options,
             expected = vec![
                 CreateRoleOption::SysId(42.into()),
                 CreateRoleOption::AdminMembers(vec![Public]),
-                CreateRoleOption::AddRoleTo(vec![Public]),
+                CreateRoleOption::RoleMembers(vec![Public]),
                 CreateRoleOption::AddRoleTo(vec![Public])
             ]
         );
@@ -74,9 +74,9 @@ mod tests {
 
     #[test_case("sysid 42", CreateRoleOption::SysId(42.into()))]
     #[test_case("admin public", CreateRoleOption::AdminMembers(vec![Public]))]
-    #[test_case("role public", CreateRoleOption::AddRoleTo(vec![Public]))]
-    #[test_case("inherit role public", CreateRoleOption::AddRoleTo(vec![Public]))]
-    #[test_case("inherit group public", CreateRoleOption::AddRoleTo(vec![Public]))]
+    #[test_case("role public", CreateRoleOption::RoleMembers(vec![Public]))]
+    #[test_case("in role public", CreateRoleOption::AddRoleTo(vec![Public]))]
+    #[test_case("in group public", CreateRoleOption::AddRoleTo(vec![Public]))]
     #[test_case("password null", CreateRoleOption::Password(None))]
     fn test_create_role_option(source: &str, expected: CreateRoleOption) {
         test_parser!(source, create_role_option, expected);
@@ -94,7 +94,7 @@ use crate::ParserContext;
 use pg_ast::CreateRoleOption;
 use pg_lexer::Keyword::Admin;
 use pg_lexer::Keyword::Group;
-use pg_lexer::Keyword::Inherit;
+use pg_lexer::Keyword::In;
 use pg_lexer::Keyword::Role;
 use pg_lexer::Keyword::Sysid;
 use pg_parser_core::scan;
@@ -44,7 +44,7 @@ mod tests {
         CreateRoleStmt::new("test_role", RoleKind::Role)
             .with_options(vec![CreateRoleOption::SysId(42.into())])
     ))]
-    #[test_case("group test_role inherit role public" => Ok(
+    #[test_case("group test_role in role public" => Ok(
         CreateRoleStmt::new("test_role", RoleKind::Group)
             .with_options(vec![CreateRoleOption::AddRoleTo(vec![Public])])
     ))]
@@ -0,0 +1,167 @@
+/// Alias: `RuleStmt`
+///
+/// `opt_or_replace` is parsed by `with_or_replace`.
+pub(super) fn create_rule_stmt(replace: bool) -> impl Combinator<Output = RuleStmt> {
+
+    /*
+        RULE ColId AS ON event TO qualified_name ( where_clause )?
+        DO ( INSTEAD | ALSO )? RuleActionList
+    */
+
+    parser(move |ctx| {
+        let (_, name, _, _, event, _, relation) = seq!(Rule, col_id, As, On, event, To, qualified_name)
+            .parse(ctx)?;
+
+        let (where_clause, _, instead, actions) = seq!(
+            where_clause.optional(),
+            Do,
+            alt!(
+                Instead.map(|_| true),
+                Also.map(|_| false)
+            ).optional(),
+            rule_action_list
+        ).parse(ctx).required()?;
+
+        let mut stmt = RuleStmt::new(name, relation, event, actions)
+            .with_instead(instead.unwrap_or_default())
+            .with_replace(replace);
+
+        stmt.set_where_clause(where_clause);
+        Ok(stmt)
+    })
+}
+
+/// Alias: `event`
+fn event(ctx: &mut ParserContext) -> scan::Result<RuleEvent> {
+
+    /*
+          SELECT
+        | UPDATE
+        | DELETE
+        | INSERT
+    */
+
+    alt!(
+        Select.map(|_| RuleEvent::Select),
+        Update.map(|_| RuleEvent::Update),
+        Delete.map(|_| RuleEvent::Delete),
+        Insert.map(|_| RuleEvent::Insert)
+    ).parse(ctx)
+}
+
+/// Alias: `RuleActionList`
+fn rule_action_list(ctx: &mut ParserContext) -> scan::Result<Vec<RawStmt>> {
+
+    /*
+          NOTHING
+        | RuleActionStmt
+        | '(' RuleActionMulti ')'
+    */
+
+    // `'(' RuleActionMulti ')'` must be before `RuleActionStmt`, because `RuleActionStmt` can also start with `'('`,
+    // and a parenthesized `SelectStmt` is a valid `RuleActionMulti` anyway.
+    alt!(
+        Nothing.map(|_| Vec::new()),
+        paren!(rule_action_multi),
+        rule_action_stmt.map(|stmt| vec![stmt])
+    ).parse(ctx)
+}
+
+/// Alias: `RuleActionMulti`
+fn rule_action_multi(ctx: &mut ParserContext) -> scan::Result<Vec<RawStmt>> {
+
+    /*
+        ( RuleActionStmt )? ( ';' ( RuleActionStmt )? )*
+    */
+
+    let actions = many!(sep = Semicolon, rule_action_stmt.optional())
+        .parse(ctx)?
+        .into_iter()
+        .flatten()
+        .collect();
+
+    Ok(actions)
+}
+
+/// Alias: `RuleActionStmt`
+fn rule_action_stmt(ctx: &mut ParserContext) -> scan::Result<RawStmt> {
+
+    /*
+          SelectStmt
+        | InsertStmt
+        | UpdateStmt
+        | DeleteStmt
+        | NotifyStmt
+    */
+
+    // TODO: InsertStmt, UpdateStmt and DeleteStmt
+    alt!(
+        select_stmt.map(From::from),
+        notify_stmt.map(From::from)
+    ).parse(ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combinators::with_or_replace;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        ExprKind::BooleanConst,
+        NotifyStmt,
+    };
+    use test_case::test_case;
+
+    #[test_case("rule r as on update to t do nothing",
+        RuleStmt::new("r", "t", RuleEvent::Update, vec![])
+    )]
+    #[test_case("or replace rule r as on insert to t where true do instead notify c",
+        RuleStmt::new("r", "t", RuleEvent::Insert, vec![NotifyStmt::new("c").into()])
+            .with_where_clause(BooleanConst(true).into())
+            .with_instead(true)
+            .with_replace(true)
+    )]
+    #[test_case("rule r as on delete to t do also (notify a; ; notify b;)",
+        RuleStmt::new(
+            "r",
+            "t",
+            RuleEvent::Delete,
+            vec![NotifyStmt::new("a").into(), NotifyStmt::new("b").into()]
+        )
+    )]
+    fn test_create_rule_stmt(source: &str, expected: RuleStmt) {
+        test_parser!(source, with_or_replace(create_rule_stmt), expected)
+    }
+}
+
+use crate::alt;
+use crate::combinators::col_id;
+use crate::combinators::core::parser;
+use crate::combinators::core::Combinator;
+use crate::combinators::qualified_name;
+use crate::combinators::stmt::notify_stmt;
+use crate::combinators::stmt::select_stmt;
+use crate::combinators::where_clause;
+use crate::many;
+use crate::paren;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::RawStmt;
+use pg_ast::RuleEvent;
+use pg_ast::RuleStmt;
+use pg_lexer::Keyword::Also;
+use pg_lexer::Keyword::As;
+use pg_lexer::Keyword::Delete;
+use pg_lexer::Keyword::Do;
+use pg_lexer::Keyword::Insert;
+use pg_lexer::Keyword::Instead;
+use pg_lexer::Keyword::Nothing;
+use pg_lexer::Keyword::On;
+use pg_lexer::Keyword::Rule;
+use pg_lexer::Keyword::Select;
+use pg_lexer::Keyword::To;
+use pg_lexer::Keyword::Update;
+use pg_lexer::OperatorKind::Semicolon;
+use pg_parser_core::scan;
+use pg_parser_core::Required;
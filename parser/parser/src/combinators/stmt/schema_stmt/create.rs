@@ -0,0 +1,215 @@
+/// Alias: `CreateSchemaStmt`
+///
+/// Only `CREATE SEQUENCE` schema elements are supported, because it's the only element
+/// whose statement is parsed on its own. `CREATE TABLE`, `CREATE VIEW`, `CREATE INDEX`,
+/// `CREATE TRIGGER` and `GRANT` elements fail with [`UnsupportedSchemaElement`],
+/// until those statements exist.
+pub(in crate::combinators::stmt) fn create_schema_stmt(ctx: &mut ParserContext) -> scan::Result<CreateSchemaStmt> {
+
+    /*
+        SCHEMA ( IF NOT EXISTS )? (
+              ( ColId )? AUTHORIZATION RoleSpec
+            | ColId
+        ) ( schema_stmt )*
+    */
+
+    let (_, existence, (name, authorization), elements) = seq!(
+        Schema,
+        if_not_exists.optional()
+            .map(Option::unwrap_or_default),
+        alt!(
+            authorization
+                .map(|role| (None, Some(role))),
+            seq!(col_id, authorization.optional())
+                .map(|(name, role)| (Some(name), role))
+        ),
+        located!(many!(schema_stmt)).optional()
+    ).parse(ctx)?;
+
+    let elements = match elements {
+        Some(Located(_, loc)) if existence == Presence::Ignore => {
+            return Err(SchemaElementsWithIfNotExists.at_location(loc).into())
+        },
+        Some(Located(elements, _)) => Some(elements),
+        None => None,
+    };
+
+    let mut stmt = CreateSchemaStmt::new(name, authorization, existence);
+    stmt.set_elements(elements);
+
+    Ok(stmt)
+}
+
+fn authorization(ctx: &mut ParserContext) -> scan::Result<RoleSpec> {
+
+    let (_, role) = seq!(Authorization, role_spec)
+        .parse(ctx)?;
+
+    Ok(role)
+}
+
+/// Alias: `schema_stmt`
+fn schema_stmt(ctx: &mut ParserContext) -> scan::Result<RawStmt> {
+
+    /*
+          CreateStmt
+        | IndexStmt
+        | CreateSeqStmt
+        | CreateTrigStmt
+        | GrantStmt
+        | ViewStmt
+    */
+
+    // The other elements are still recognized,
+    // so that they fail with an explicit error, instead of a syntax error after the schema.
+    let Located(stmt, loc) = located!(alt!(
+        seq!(
+            Create,
+            alt!(
+                with_relation_persistence(|persistence| alt!(
+                    create_sequence_stmt(persistence).map(|stmt| Ok(stmt.into())),
+                    Table.map(|_| Err("CREATE TABLE")),
+                    view.map(Err)
+                )),
+                seq!(Unique.optional(), Index).map(|_| Err("CREATE INDEX")),
+                with_or_replace(|_| alt!(
+                    trigger,
+                    with_relation_persistence(|_| view)
+                )).map(Err)
+            )
+        ).map(|(_, stmt)| stmt),
+        Grant.map(|_| Err("GRANT"))
+    )).parse(ctx)?;
+
+    stmt.map_err(|stmt| UnsupportedSchemaElement(stmt).at_location(loc).into())
+}
+
+fn trigger(ctx: &mut ParserContext) -> scan::Result<&'static str> {
+
+    /*
+        ( CONSTRAINT )? TRIGGER
+    */
+
+    seq!(Constraint.optional(), Trigger)
+        .map(|_| "CREATE TRIGGER")
+        .parse(ctx)
+}
+
+fn view(ctx: &mut ParserContext) -> scan::Result<&'static str> {
+
+    /*
+        ( RECURSIVE )? VIEW
+    */
+
+    seq!(Recursive.optional(), View)
+        .map(|_| "CREATE VIEW")
+        .parse(ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        CreateSeqStmt,
+        RangeVar,
+        RelationName,
+        RelationPersistence::Permanent,
+        RoleSpec::{CurrentUser, Public},
+    };
+    use pg_elog::Error::Parser;
+    use scan::Error::ScanErr;
+    use test_case::test_case;
+
+    #[test_case("schema some_schema",
+        CreateSchemaStmt::new(Some("some_schema".into()), None, Presence::Fail)
+    )]
+    #[test_case("schema if not exists authorization current_user",
+        CreateSchemaStmt::new(None, Some(CurrentUser), Presence::Ignore)
+    )]
+    #[test_case("schema some_schema authorization public",
+        CreateSchemaStmt::new(Some("some_schema".into()), Some(Public), Presence::Fail)
+    )]
+    #[test_case("schema some_schema create sequence some_seq create sequence other_seq",
+        CreateSchemaStmt::new(Some("some_schema".into()), None, Presence::Fail)
+            .with_elements(vec![
+                CreateSeqStmt::new(
+                    RangeVar::with_persistence(RelationName::new("some_seq"), Permanent),
+                    None,
+                    Presence::Fail
+                ).into(),
+                CreateSeqStmt::new(
+                    RangeVar::with_persistence(RelationName::new("other_seq"), Permanent),
+                    None,
+                    Presence::Fail
+                ).into(),
+            ])
+    )]
+    fn test_create_schema_stmt(source: &str, expected: CreateSchemaStmt) {
+        test_parser!(source, create_schema_stmt, expected)
+    }
+
+    #[test_case("schema if not exists some_schema create sequence some_seq" => matches Err(ScanErr(
+        Located(Parser(SchemaElementsWithIfNotExists), _)
+    )))]
+    fn test_schema_elements_with_if_not_exists(source: &str) -> scan::Result<CreateSchemaStmt> {
+        test_parser!(source, create_schema_stmt)
+    }
+
+    #[test_case("create table t (a int)", "CREATE TABLE")]
+    #[test_case("create temp table t (a int)", "CREATE TABLE")]
+    #[test_case("create index on t (a)", "CREATE INDEX")]
+    #[test_case("create unique index i on t (a)", "CREATE INDEX")]
+    #[test_case("create trigger tr after insert on t execute function f()", "CREATE TRIGGER")]
+    #[test_case("create or replace constraint trigger tr after insert on t execute function f()", "CREATE TRIGGER")]
+    #[test_case("create view v as select 1", "CREATE VIEW")]
+    #[test_case("create or replace temp recursive view v (a) as select 1", "CREATE VIEW")]
+    #[test_case("grant select on t to public", "GRANT")]
+    fn test_unsupported_schema_element(source: &str, expected: &'static str) {
+
+        let source = format!("schema s create sequence q {source}");
+        let actual = test_parser!(source.as_str(), create_schema_stmt);
+
+        let Err(ScanErr(Located(Parser(UnsupportedSchemaElement(actual)), loc))) = actual else {
+            panic!("expected an unsupported schema element error, but got: {actual:?}")
+        };
+
+        assert_eq!(expected, actual);
+        // The error is at the element's first keyword.
+        assert_eq!(27, loc.range().start);
+    }
+}
+
+use crate::alt;
+use crate::combinators::col_id;
+use crate::combinators::core::Combinator;
+use crate::combinators::if_not_exists;
+use crate::combinators::role_spec;
+use crate::combinators::stmt::create_sequence_stmt;
+use crate::combinators::with_or_replace;
+use crate::combinators::with_relation_persistence;
+use crate::located;
+use crate::many;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::CreateSchemaStmt;
+use pg_ast::Presence;
+use pg_ast::RawStmt;
+use pg_ast::RoleSpec;
+use pg_basics::IntoLocated;
+use pg_basics::Located;
+use pg_elog::parser::Error::SchemaElementsWithIfNotExists;
+use pg_elog::parser::Error::UnsupportedSchemaElement;
+use pg_lexer::Keyword::Authorization;
+use pg_lexer::Keyword::Constraint;
+use pg_lexer::Keyword::Create;
+use pg_lexer::Keyword::Grant;
+use pg_lexer::Keyword::Index;
+use pg_lexer::Keyword::Recursive;
+use pg_lexer::Keyword::Schema;
+use pg_lexer::Keyword::Table;
+use pg_lexer::Keyword::Trigger;
+use pg_lexer::Keyword::Unique;
+use pg_lexer::Keyword::View;
+use pg_parser_core::scan;
@@ -0,0 +1,3 @@
+pg_basics::reexport! { pub(super)
+    create,
+}
@@ -0,0 +1,293 @@
+pg_basics::reexport! {
+    select_limit,
+    simple_select,
+    values_clause,
+}
+
+/// Alias: `SelectStmt`
+pub(in crate::combinators) fn select_stmt(ctx: &mut ParserContext) -> scan::Result<SelectStmt> {
+
+    /*
+          select_no_parens
+        | select_with_parens
+    */
+
+    ctx.nested(|ctx| {
+        let first = select_primary(ctx)?;
+        select_stmt_tail(first, ctx)
+    })
+}
+
+/// Whether the next token can only start a `SelectStmt`,
+/// and not an expression or a table reference.
+pub(in crate::combinators) fn select_stmt_starts(ctx: &mut ParserContext) -> bool {
+    matches!(ctx.stream_mut().peek(), Ok(K(Select | Values | Table)))
+}
+
+/// Parses the rest of a `SelectStmt`, after its first operand was already consumed.
+///
+/// Used when the caller can't know in advance whether a parenthesized select
+/// is a complete `select_with_parens`, or only the first operand of a `select_no_parens`.
+pub(in crate::combinators) fn select_stmt_tail(first: SelectStmt, ctx: &mut ParserContext) -> scan::Result<SelectStmt> {
+
+    /*
+        ( ( UNION | EXCEPT ) set_quantifier select_clause )*
+        ( sort_clause )?
+        ( select_limit )?
+    */
+
+    let stmt = set_operations(first, ctx)?;
+
+    let (sort_clause, limit) = seq!(
+        sort_clause.optional(),
+        select_limit.optional()
+    ).parse(ctx)?;
+
+    insert_select_options(stmt, sort_clause, limit)
+}
+
+/// Alias: `select_clause`
+fn select_primary(ctx: &mut ParserContext) -> scan::Result<SelectStmt> {
+
+    /*
+          select_with_parens
+        | simple_select
+        | values_clause
+        | TABLE relation_expr
+    */
+
+    alt!(
+        paren!(select_stmt),
+        simple_select.map(From::from),
+        values_clause.map(|values| SelectStmt::new(SelectBody::Values(values))),
+        seq!(Table, relation_expr)
+            .map(|(_, relation)| {
+                SimpleSelect::new(vec![ResTarget::Star])
+                    .with_from_clause(vec![RelationTableRef::new(relation).into()])
+                    .into()
+            })
+    ).parse(ctx)
+}
+
+/// `UNION` and `EXCEPT` are left-associative, and bind looser than `INTERSECT`.
+fn set_operations(first: SelectStmt, ctx: &mut ParserContext) -> scan::Result<SelectStmt> {
+
+    let mut left = intersect_operations(first, ctx)?;
+
+    while let Some((op, all)) = seq!(
+        alt!(
+            Union.map(|_| SetOperation::Union),
+            Except.map(|_| SetOperation::Except)
+        ),
+        set_quantifier.optional()
+    ).optional().parse(ctx)? {
+        let right = select_primary(ctx).required()?;
+        let right = intersect_operations(right, ctx)?;
+        left = make_set_operation(op, all.unwrap_or_default(), left, right);
+    }
+
+    Ok(left)
+}
+
+fn intersect_operations(first: SelectStmt, ctx: &mut ParserContext) -> scan::Result<SelectStmt> {
+
+    let mut left = first;
+
+    while let Some((_, all)) = seq!(Intersect, set_quantifier.optional()).optional().parse(ctx)? {
+        let right = select_primary(ctx).required()?;
+        left = make_set_operation(SetOperation::Intersect, all.unwrap_or_default(), left, right);
+    }
+
+    Ok(left)
+}
+
+/// Alias: `set_quantifier`
+///
+/// Returns `true` for `ALL`.
+fn set_quantifier(ctx: &mut ParserContext) -> scan::Result<bool> {
+
+    /*
+          ALL
+        | DISTINCT
+    */
+
+    alt!(
+        All.map(|_| true),
+        Distinct.map(|_| false)
+    ).parse(ctx)
+}
+
+fn make_set_operation(op: SetOperation, all: bool, left: SelectStmt, right: SelectStmt) -> SelectStmt {
+    SelectStmt::new(SelectBody::SetOperation {
+        op,
+        all,
+        left: Box::new(left),
+        right: Box::new(right),
+    })
+}
+
+/// Alias: `insertSelectOptions`
+fn insert_select_options(
+    mut stmt: SelectStmt,
+    sort_clause: Option<Located<Vec<SortBy>>>,
+    limit: Option<SelectLimit>
+) -> scan::Result<SelectStmt> {
+
+    // A parenthesized select can already have its own clauses, e.g.: `(SELECT 1 ORDER BY 1) ORDER BY 1`.
+
+    if let Some(Located(sort_clause, loc)) = sort_clause {
+        if stmt.sort_clause().is_some() {
+            return Err(MultipleSortClauses.at_location(loc).into())
+        }
+        if let SelectBody::Simple(select) = stmt.body() {
+            // Window functions in ORDER BY can reference the windows of the SELECT.
+            check_window_references(&sort_clause, select.window_clause().unwrap_or_default(), loc)?;
+        }
+        stmt.set_sort_clause(Some(sort_clause));
+    }
+
+    let Some(limit) = limit else { return Ok(stmt) };
+
+    if let Some(Located(offset, loc)) = limit.offset {
+        if stmt.limit_offset().is_some() {
+            return Err(MultipleOffsetClauses.at_location(loc).into())
+        }
+        stmt.set_limit_offset(Some(offset));
+    }
+
+    if let Some(Located(count, loc)) = limit.count {
+        if stmt.limit_count().is_some() {
+            return Err(MultipleLimitClauses.at_location(loc).into())
+        }
+        stmt.set_limit_count(Some(count));
+    }
+
+    Ok(stmt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::ExprKind::IntegerConst;
+    use pg_elog::parser::Error::UndefinedWindow;
+    use pg_elog::Error::Parser;
+    use scan::Error::ScanErr;
+    use test_case::test_case;
+
+    fn select(value: i32) -> SelectStmt {
+        SimpleSelect::new(vec![IntegerConst(value).into()]).into()
+    }
+
+    fn set_operation(op: SetOperation, all: bool, left: SelectStmt, right: SelectStmt) -> SelectStmt {
+        make_set_operation(op, all, left, right)
+    }
+
+    #[test_case("select 1", select(1))]
+    #[test_case("(select 1)", select(1))]
+    #[test_case("((select 1))", select(1))]
+    #[test_case("values (1)",
+        SelectStmt::new(SelectBody::Values(vec![vec![IntegerConst(1).into()]]))
+    )]
+    #[test_case("select 1 union select 2",
+        set_operation(SetOperation::Union, false, select(1), select(2))
+    )]
+    #[test_case("select 1 union all select 2 except distinct select 3",
+        set_operation(
+            SetOperation::Except,
+            false,
+            set_operation(SetOperation::Union, true, select(1), select(2)),
+            select(3)
+        )
+    )]
+    #[test_case("select 1 union select 2 intersect select 3",
+        set_operation(
+            SetOperation::Union,
+            false,
+            select(1),
+            set_operation(SetOperation::Intersect, false, select(2), select(3))
+        )
+    )]
+    #[test_case("(select 1 union select 2) intersect select 3",
+        set_operation(
+            SetOperation::Intersect,
+            false,
+            set_operation(SetOperation::Union, false, select(1), select(2)),
+            select(3)
+        )
+    )]
+    #[test_case("select 1 union select 2 order by 1 limit 3",
+        set_operation(SetOperation::Union, false, select(1), select(2))
+            .with_sort_clause(vec![SortBy::new(IntegerConst(1).into(), None, None)])
+            .with_limit_count(IntegerConst(3).into())
+    )]
+    #[test_case("(select 1 order by 1) limit 2 offset 3",
+        select(1)
+            .with_sort_clause(vec![SortBy::new(IntegerConst(1).into(), None, None)])
+            .with_limit_count(IntegerConst(2).into())
+            .with_limit_offset(IntegerConst(3).into())
+    )]
+    fn test_select_stmt(source: &str, expected: SelectStmt) {
+        test_parser!(source, select_stmt, expected)
+    }
+
+    #[test_case("table foo" => matches Ok(_))]
+    #[test_case("select 1 intersect all (select 2 limit 1)" => matches Ok(_))]
+    fn test_select_stmt_matches(source: &str) -> scan::Result<SelectStmt> {
+        test_parser!(source, select_stmt)
+    }
+
+    #[test_case("(select 1 order by 1) order by 1" => matches Err(ScanErr(
+        Located(Parser(MultipleSortClauses), _)
+    )))]
+    #[test_case("(select 1 offset 1) offset 2" => matches Err(ScanErr(
+        Located(Parser(MultipleOffsetClauses), _)
+    )))]
+    #[test_case("(select 1 limit 1) limit 2" => matches Err(ScanErr(
+        Located(Parser(MultipleLimitClauses), _)
+    )))]
+    fn test_multiple_select_options(source: &str) -> scan::Result<SelectStmt> {
+        test_parser!(source, select_stmt)
+    }
+
+    #[test_case("select a from t window w as () order by rank() over w" => matches Ok(_))]
+    #[test_case("select a from t window w as () order by rank() over v" => matches Err(ScanErr(
+        Located(Parser(UndefinedWindow(_)), _)
+    )))]
+    fn test_sort_clause_window_references(source: &str) -> scan::Result<SelectStmt> {
+        test_parser!(source, select_stmt)
+    }
+}
+
+use crate::alt;
+use crate::combinators::check_window_references;
+use crate::combinators::core::Combinator;
+use crate::combinators::relation_expr;
+use crate::combinators::sort_clause;
+use crate::paren;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::RelationTableRef;
+use pg_ast::ResTarget;
+use pg_ast::SelectBody;
+use pg_ast::SelectStmt;
+use pg_ast::SetOperation;
+use pg_ast::SimpleSelect;
+use pg_ast::SortBy;
+use pg_basics::IntoLocated;
+use pg_basics::Located;
+use pg_elog::parser::Error::MultipleLimitClauses;
+use pg_elog::parser::Error::MultipleOffsetClauses;
+use pg_elog::parser::Error::MultipleSortClauses;
+use pg_lexer::Keyword::All;
+use pg_lexer::Keyword::Distinct;
+use pg_lexer::Keyword::Except;
+use pg_lexer::Keyword::Intersect;
+use pg_lexer::Keyword::Select;
+use pg_lexer::Keyword::Table;
+use pg_lexer::Keyword::Union;
+use pg_lexer::Keyword::Values;
+use pg_parser_core::scan;
+use pg_parser_core::stream::TokenValue::Keyword as K;
+use pg_parser_core::Required;
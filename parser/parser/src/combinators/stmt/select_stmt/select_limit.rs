@@ -0,0 +1,123 @@
+pub(super) struct SelectLimit {
+    pub offset: Option<Located<ExprNode>>,
+    pub count: Option<Located<ExprNode>>,
+}
+
+/// Alias: `select_limit`
+pub(super) fn select_limit(ctx: &mut ParserContext) -> scan::Result<SelectLimit> {
+
+    /*
+          limit_clause ( offset_clause )?
+        | offset_clause ( limit_clause )?
+    */
+
+    // TODO: FETCH FIRST ... { ONLY | WITH TIES }
+
+    alt!(
+        seq!(located!(limit_clause), located!(offset_clause).optional())
+            .map(|(count, offset)| SelectLimit { offset, count: Some(count) }),
+        seq!(located!(offset_clause), located!(limit_clause).optional())
+            .map(|(offset, count)| SelectLimit { offset: Some(offset), count })
+    ).parse(ctx)
+}
+
+/// Alias: `limit_clause`
+fn limit_clause(ctx: &mut ParserContext) -> scan::Result<ExprNode> {
+
+    /*
+        LIMIT select_limit_value ( ',' select_offset_value )?
+    */
+
+    let (_, count, offset) = seq!(
+        Limit,
+        select_limit_value,
+        located!(seq!(Comma, a_expr)).optional()
+    ).parse(ctx)?;
+
+    if let Some(Located(_, loc)) = offset {
+        return Err(LimitOffsetSyntax.at_location(loc).into())
+    }
+
+    Ok(count)
+}
+
+/// Alias: `select_limit_value`
+///
+/// `ALL` is returned as `NullConst`.
+fn select_limit_value(ctx: &mut ParserContext) -> scan::Result<ExprNode> {
+
+    /*
+          a_expr
+        | ALL
+    */
+
+    alt!(
+        located!(All).map(|Located(_, loc)| ExprNode::new(NullConst, loc.into())),
+        a_expr
+    ).parse(ctx)
+}
+
+/// Alias: `offset_clause`
+fn offset_clause(ctx: &mut ParserContext) -> scan::Result<ExprNode> {
+
+    /*
+        OFFSET select_offset_value ( row_or_rows )?
+    */
+
+    let (_, offset, _) = seq!(
+        Offset,
+        a_expr,
+        alt!(Row, Rows).optional()
+    ).parse(ctx)?;
+
+    Ok(offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    use pg_ast::ExprKind;
+    use pg_elog::Error::Parser;
+    use scan::Error::ScanErr;
+    use test_case::test_case;
+
+    #[test_case("limit 1", None, Some(ExprKind::IntegerConst(1).into()))]
+    #[test_case("limit all", None, Some(ExprKind::NullConst.into()))]
+    #[test_case("offset 2 rows", Some(ExprKind::IntegerConst(2).into()), None)]
+    #[test_case("limit 1 offset 2", Some(ExprKind::IntegerConst(2).into()), Some(ExprKind::IntegerConst(1).into()))]
+    #[test_case("offset 2 limit 1", Some(ExprKind::IntegerConst(2).into()), Some(ExprKind::IntegerConst(1).into()))]
+    fn test_select_limit(source: &str, offset: Option<ExprNode>, count: Option<ExprNode>) {
+
+        let limit = test_parser!(source, select_limit).unwrap();
+
+        assert_eq!(offset, limit.offset.map(|Located(offset, _)| offset));
+        assert_eq!(count, limit.count.map(|Located(count, _)| count));
+    }
+
+    #[test_case("limit 1, 2" => matches Err(ScanErr(
+        Located(Parser(LimitOffsetSyntax), _)
+    )))]
+    fn test_limit_offset_syntax(source: &str) -> scan::Result<ExprNode> {
+        test_parser!(source, limit_clause)
+    }
+}
+
+use crate::alt;
+use crate::combinators::core::Combinator;
+use crate::combinators::expr::a_expr;
+use crate::located;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::ExprKind::NullConst;
+use pg_ast::ExprNode;
+use pg_basics::IntoLocated;
+use pg_basics::Located;
+use pg_elog::parser::Error::LimitOffsetSyntax;
+use pg_lexer::Keyword::All;
+use pg_lexer::Keyword::Limit;
+use pg_lexer::Keyword::Offset;
+use pg_lexer::Keyword::Row;
+use pg_lexer::Keyword::Rows;
+use pg_lexer::OperatorKind::Comma;
+use pg_parser_core::scan;
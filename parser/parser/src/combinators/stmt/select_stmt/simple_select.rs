@@ -0,0 +1,285 @@
+/// Alias: `simple_select`
+///
+/// Only the `SELECT ...` production. The other ones are handled by `select_stmt`.
+pub(super) fn simple_select(ctx: &mut ParserContext) -> scan::Result<SimpleSelect> {
+
+    /*
+        SELECT
+        ( ALL | distinct_clause )?
+        ( target_list )?
+        ( from_clause )?
+        ( where_clause )?
+        ( group_clause )?
+        ( having_clause )?
+        ( window_clause )?
+    */
+
+    // TODO: into_clause
+
+    let (Located(_, loc), distinct_clause, target_list, from_clause, where_clause, group_clause, having_clause, window_clause) = seq!(
+        located!(Select),
+        alt!(
+            All.map(|_| None),
+            distinct_clause.map(Some)
+        ).optional(),
+        target_list.optional(),
+        from_clause.optional(),
+        where_clause.optional(),
+        group_clause.optional(),
+        having_clause.optional(),
+        window_clause.optional()
+    ).parse(ctx)?;
+
+    let mut stmt = SimpleSelect::new(target_list.unwrap_or_default());
+    stmt.set_distinct_clause(distinct_clause.flatten())
+        .set_from_clause(from_clause)
+        .set_where_clause(where_clause)
+        .set_having_clause(having_clause)
+        .set_window_clause(window_clause);
+
+    if let Some((group_distinct, group_clause)) = group_clause {
+        stmt.set_group_clause(Some(group_clause))
+            .set_group_distinct(group_distinct);
+    }
+
+    check_window_references(&stmt, stmt.window_clause().unwrap_or_default(), loc)?;
+
+    Ok(stmt)
+}
+
+/// Alias: `distinct_clause`
+fn distinct_clause(ctx: &mut ParserContext) -> scan::Result<DistinctClause> {
+
+    /*
+        DISTINCT ( ON '(' expr_list ')' )?
+    */
+
+    let (_, on) = seq!(
+        Distinct,
+        seq!(On, paren!(expr_list)).optional()
+    ).parse(ctx)?;
+
+    let distinct = match on {
+        Some((_, exprs)) => DistinctClause::DistinctOn(exprs),
+        None => DistinctClause::Distinct,
+    };
+
+    Ok(distinct)
+}
+
+/// Alias: `target_list`
+fn target_list(ctx: &mut ParserContext) -> scan::Result<Vec<ResTarget>> {
+
+    /*
+        target_el ( ',' target_el )*
+    */
+
+    many!(sep = Comma, target_el).parse(ctx)
+}
+
+/// Alias: `target_el`
+fn target_el(ctx: &mut ParserContext) -> scan::Result<ResTarget> {
+
+    /*
+          '*'
+        | a_expr AS ColLabel
+        | a_expr BareColLabel
+        | a_expr
+    */
+
+    alt!(
+        Mul.map(|_| ResTarget::Star),
+        seq!(
+            a_expr,
+            alt!(
+                seq!(As, col_label).map(|(_, name)| name),
+                bare_col_label
+            ).optional()
+        ).map(|(value, name)| ResTarget::Expr { value, name })
+    ).parse(ctx)
+}
+
+/// Alias: `from_clause`
+fn from_clause(ctx: &mut ParserContext) -> scan::Result<Vec<TableRef>> {
+
+    /*
+        FROM from_list
+    */
+
+    let (_, from_list) = seq!(FromKw, many!(sep = Comma, table_ref))
+        .parse(ctx)?;
+
+    Ok(from_list)
+}
+
+/// Alias: `group_clause`
+///
+/// Returns `true` for `GROUP BY DISTINCT`.
+fn group_clause(ctx: &mut ParserContext) -> scan::Result<(bool, Vec<ExprNode>)> {
+
+    /*
+        GROUP BY ( ALL | DISTINCT )? group_by_list
+    */
+
+    // TODO: `()`, ROLLUP, CUBE and GROUPING SETS
+
+    let (_, _, distinct, group_by_list) = seq!(
+        Group,
+        By,
+        alt!(
+            All.map(|_| false),
+            Distinct.map(|_| true)
+        ).optional(),
+        expr_list
+    ).parse(ctx)?;
+
+    Ok((distinct.unwrap_or_default(), group_by_list))
+}
+
+/// Alias: `having_clause`
+fn having_clause(ctx: &mut ParserContext) -> scan::Result<ExprNode> {
+
+    /*
+        HAVING a_expr
+    */
+
+    let (_, expr) = seq!(Having, a_expr).parse(ctx)?;
+    Ok(expr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        ColumnRef,
+        ExprKind::{BooleanConst, IntegerConst},
+        RelationTableRef,
+    };
+    use pg_elog::parser::Error::UndefinedWindow;
+    use pg_elog::parser::Error::WindowFrameCopy;
+    use pg_elog::parser::Error::WindowOrderOverride;
+    use pg_elog::parser::Error::WindowPartitionOverride;
+    use pg_elog::Error::Parser;
+    use scan::Error::ScanErr;
+    use test_case::test_case;
+
+    fn column(name: &'static str) -> ExprNode {
+        ColumnRef::SingleName(name.into()).into()
+    }
+
+    #[test_case("select",
+        SimpleSelect::new(vec![])
+    )]
+    #[test_case("select all *",
+        SimpleSelect::new(vec![ResTarget::Star])
+    )]
+    #[test_case("select distinct a, 1 as b, 2 c",
+        SimpleSelect::new(vec![
+            column("a").into(),
+            ResTarget::Expr { value: IntegerConst(1).into(), name: Some("b".into()) },
+            ResTarget::Expr { value: IntegerConst(2).into(), name: Some("c".into()) },
+        ])
+        .with_distinct_clause(DistinctClause::Distinct)
+    )]
+    #[test_case("select distinct on (a) * from t",
+        SimpleSelect::new(vec![ResTarget::Star])
+            .with_distinct_clause(DistinctClause::DistinctOn(vec![column("a")]))
+            .with_from_clause(vec![RelationTableRef::new("t").into()])
+    )]
+    #[test_case("select a from t, u where true group by distinct a having false",
+        SimpleSelect::new(vec![column("a").into()])
+            .with_from_clause(vec![
+                RelationTableRef::new("t").into(),
+                RelationTableRef::new("u").into(),
+            ])
+            .with_where_clause(BooleanConst(true).into())
+            .with_group_clause(vec![column("a")])
+            .with_group_distinct(true)
+            .with_having_clause(BooleanConst(false).into())
+    )]
+    fn test_simple_select(source: &str, expected: SimpleSelect) {
+        test_parser!(source, simple_select, expected)
+    }
+
+    #[test_case("select from t window w as ()" => matches Ok(_))]
+    #[test_case("select a, (select max(b) from u where u.a = t.a) from t where (a, b) in (select 1, 2) and not exists (select)" => matches Ok(_))]
+    #[test_case("select * from t cross join lateral (select t.a) as s, (values (1, 2)) v (x, y)" => matches Ok(_))]
+    fn test_simple_select_clauses(source: &str) -> scan::Result<SimpleSelect> {
+        test_parser!(source, simple_select)
+    }
+
+    #[test_case("select rank() over w from t window w as (order by a)" => matches Ok(_))]
+    #[test_case("select rank() over (w rows current row) from t window w as (partition by a)" => matches Ok(_))]
+    #[test_case("select (select rank() over w from u window w as ()) from t" => matches Ok(_))]
+    #[test_case("select rank() over w from t" => matches Err(ScanErr(
+        Located(Parser(UndefinedWindow(_)), _)
+    )))]
+    #[test_case("select a from t having sum(a) over (v) > 1 window w as ()" => matches Err(ScanErr(
+        Located(Parser(UndefinedWindow(_)), _)
+    )))]
+    #[test_case("select rank() over (w partition by b) from t window w as (partition by a)" => matches Err(ScanErr(
+        Located(Parser(WindowPartitionOverride(_)), _)
+    )))]
+    #[test_case("select rank() over (w order by b) from t window w as (order by a)" => matches Err(ScanErr(
+        Located(Parser(WindowOrderOverride(_)), _)
+    )))]
+    #[test_case("select rank() over (w) from t window w as (rows current row)" => matches Err(ScanErr(
+        Located(Parser(WindowFrameCopy(_)), _)
+    )))]
+    // Subqueries can't reference the windows of the outer query.
+    #[test_case("select (select rank() over w from u) from t window w as ()" => matches Err(ScanErr(
+        Located(Parser(UndefinedWindow(_)), _)
+    )))]
+    fn test_window_references(source: &str) -> scan::Result<SimpleSelect> {
+        test_parser!(source, simple_select)
+    }
+
+    #[test]
+    fn test_undefined_window_location() {
+        let source = "select a, rank() over w from t";
+        let actual = test_parser!(source, simple_select);
+
+        let Err(ScanErr(Located(Parser(UndefinedWindow(name)), loc))) = actual else {
+            panic!("expected an undefined window error, but got: {actual:?}")
+        };
+
+        assert_eq!("w", name.as_ref());
+        assert_eq!("rank() over w", loc.slice(source));
+    }
+}
+
+use crate::alt;
+use crate::combinators::bare_col_label;
+use crate::combinators::check_window_references;
+use crate::combinators::col_label;
+use crate::combinators::core::Combinator;
+use crate::combinators::expr::a_expr;
+use crate::combinators::expr_list;
+use crate::combinators::table_ref;
+use crate::combinators::where_clause;
+use crate::combinators::window_clause;
+use crate::located;
+use crate::many;
+use crate::paren;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::DistinctClause;
+use pg_ast::ExprNode;
+use pg_ast::ResTarget;
+use pg_ast::SimpleSelect;
+use pg_ast::TableRef;
+use pg_basics::Located;
+use pg_lexer::Keyword::All;
+use pg_lexer::Keyword::As;
+use pg_lexer::Keyword::By;
+use pg_lexer::Keyword::Distinct;
+use pg_lexer::Keyword::FromKw;
+use pg_lexer::Keyword::Group;
+use pg_lexer::Keyword::Having;
+use pg_lexer::Keyword::On;
+use pg_lexer::Keyword::Select;
+use pg_lexer::OperatorKind::Comma;
+use pg_lexer::OperatorKind::Mul;
+use pg_parser_core::scan;
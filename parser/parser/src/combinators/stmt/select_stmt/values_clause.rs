@@ -0,0 +1,45 @@
+/// Alias: `values_clause`
+pub(super) fn values_clause(ctx: &mut ParserContext) -> scan::Result<Vec<Vec<ExprNode>>> {
+
+    /*
+        VALUES '(' expr_list ')' ( ',' '(' expr_list ')' )*
+    */
+
+    let (_, values) = seq!(
+        Values,
+        many!(sep = Comma, paren!(expr_list))
+    ).parse(ctx)?;
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    use pg_ast::ExprKind::IntegerConst;
+    use pg_ast::ExprKind::StringConst;
+
+    #[test]
+    fn test_values_clause() {
+        test_parser!(
+            source = "values (1, 'one'), (2, 'two')",
+            parser = values_clause,
+            expected = vec![
+                vec![IntegerConst(1).into(), StringConst("one".into()).into()],
+                vec![IntegerConst(2).into(), StringConst("two".into()).into()],
+            ]
+        )
+    }
+}
+
+use crate::combinators::core::Combinator;
+use crate::combinators::expr_list;
+use crate::many;
+use crate::paren;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::ExprNode;
+use pg_lexer::Keyword::Values;
+use pg_lexer::OperatorKind::Comma;
+use pg_parser_core::scan;
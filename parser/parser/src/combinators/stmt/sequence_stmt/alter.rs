@@ -0,0 +1,60 @@
+/// Alias: `AlterSeqStmt`
+pub(in crate::combinators::stmt) fn alter_sequence_stmt(ctx: &mut ParserContext) -> scan::Result<AlterSeqStmt> {
+
+    /*
+        SEQUENCE ( IF EXISTS )? qualified_name SeqOptList
+    */
+
+    let (_, existence, name, options) = seq!(
+        Sequence,
+        if_exists.optional()
+            .map(Option::unwrap_or_default),
+        qualified_name,
+        seq_opt_list
+    ).parse(ctx)?;
+
+    let stmt = AlterSeqStmt::new(name, options, existence);
+    Ok(stmt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        Presence,
+        RelationName,
+        SeqOptElem::{MaxValue, Restart},
+        SignedNumber::IntegerConst,
+    };
+    use test_case::test_case;
+
+    #[test_case("sequence some_seq restart",
+        AlterSeqStmt::new(
+            RelationName::new("some_seq"),
+            vec![Restart(None)],
+            Presence::Fail
+        )
+    )]
+    #[test_case("sequence if exists some_seq restart with 3 no maxvalue",
+        AlterSeqStmt::new(
+            RelationName::new("some_seq"),
+            vec![Restart(Some(IntegerConst(3))), MaxValue(None)],
+            Presence::Ignore
+        )
+    )]
+    fn test_alter_sequence_stmt(source: &str, expected: AlterSeqStmt) {
+        test_parser!(source, alter_sequence_stmt, expected)
+    }
+}
+
+use crate::combinators::core::Combinator;
+use crate::combinators::if_exists;
+use crate::combinators::qualified_name;
+use crate::combinators::seq_opt_list;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::AlterSeqStmt;
+use pg_lexer::Keyword::Sequence;
+use pg_parser_core::scan;
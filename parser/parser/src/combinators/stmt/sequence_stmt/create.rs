@@ -0,0 +1,78 @@
+/// Alias: `CreateSeqStmt`
+///
+/// `OptTemp` is parsed by `with_relation_persistence`.
+pub(in crate::combinators::stmt) fn create_sequence_stmt(persistence: RelationPersistence) -> impl Combinator<Output = CreateSeqStmt> {
+
+    /*
+        SEQUENCE ( IF NOT EXISTS )? qualified_name ( SeqOptList )?
+    */
+
+    parser(move |ctx| {
+        let (_, existence, name, options) = seq!(
+            Sequence,
+            if_not_exists.optional()
+                .map(Option::unwrap_or_default),
+            qualified_name,
+            seq_opt_list.optional()
+        ).parse(ctx)?;
+
+        let sequence = RangeVar::with_persistence(name, persistence);
+        let stmt = CreateSeqStmt::new(sequence, options, existence);
+        Ok(stmt)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combinators::with_relation_persistence;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        Presence,
+        RelationName,
+        RelationPersistence::{Permanent, Temp, Unlogged},
+        SeqOptElem::{Cycle, Increment},
+        SignedNumber::IntegerConst,
+    };
+    use test_case::test_case;
+
+    #[test_case("sequence some_seq",
+        CreateSeqStmt::new(
+            RangeVar::with_persistence(RelationName::new("some_seq"), Permanent),
+            None,
+            Presence::Fail
+        )
+    )]
+    #[test_case("temp sequence if not exists some_seq increment by 2 cycle",
+        CreateSeqStmt::new(
+            RangeVar::with_persistence(RelationName::new("some_seq"), Temp),
+            Some(vec![Increment(IntegerConst(2)), Cycle(true)]),
+            Presence::Ignore
+        )
+    )]
+    #[test_case("unlogged sequence some_schema.some_seq",
+        CreateSeqStmt::new(
+            RangeVar::with_persistence(
+                RelationName::new("some_seq").with_schema("some_schema"),
+                Unlogged
+            ),
+            None,
+            Presence::Fail
+        )
+    )]
+    fn test_create_sequence_stmt(source: &str, expected: CreateSeqStmt) {
+        test_parser!(source, with_relation_persistence(create_sequence_stmt), expected)
+    }
+}
+
+use crate::combinators::core::parser;
+use crate::combinators::core::Combinator;
+use crate::combinators::if_not_exists;
+use crate::combinators::qualified_name;
+use crate::combinators::seq_opt_list;
+use crate::seq;
+use pg_ast::CreateSeqStmt;
+use pg_ast::RangeVar;
+use pg_ast::RelationPersistence;
+use pg_lexer::Keyword::Sequence;
@@ -0,0 +1,134 @@
+/// Alias: `CreateStatsStmt`
+pub(in crate::combinators::stmt) fn create_stats_stmt(ctx: &mut ParserContext) -> scan::Result<CreateStatsStmt> {
+
+    /*
+        STATISTICS ( ( IF NOT EXISTS )? any_name )? ( '(' name_list ')' )?
+        ON stats_params
+        FROM from_list
+    */
+
+    let (_, name, kinds, _, exprs, _, relations) = seq!(
+        Statistics,
+        stats_name.optional(),
+        paren!(name_list).optional(),
+        On,
+        many!(sep = Comma, stats_param),
+        FromKw,
+        many!(sep = Comma, table_ref)
+    ).parse(ctx)?;
+
+    let mut stmt = CreateStatsStmt::new(exprs, relations);
+    stmt.set_kinds(kinds);
+
+    if let Some((existence, name)) = name {
+        stmt.set_name(Some(name))
+            .set_existence(existence);
+    }
+
+    Ok(stmt)
+}
+
+fn stats_name(ctx: &mut ParserContext) -> scan::Result<(Presence, QualifiedName)> {
+
+    /*
+          IF NOT EXISTS any_name
+        | any_name
+    */
+
+    alt!(
+        seq!(if_not_exists, any_name),
+        any_name.map(|name| (Presence::Fail, name))
+    ).parse(ctx)
+}
+
+/// Alias: `stats_param`
+fn stats_param(ctx: &mut ParserContext) -> scan::Result<StatsElem> {
+
+    /*
+          '(' a_expr ')'
+        | ColId
+        | func_expr_windowless
+    */
+
+    alt!(
+        paren!(a_expr).map(StatsElem::Expr),
+        stats_column,
+        func_expr_windowless.map(|func| StatsElem::Expr(func.into()))
+    ).parse(ctx)
+}
+
+fn stats_column(ctx: &mut ParserContext) -> scan::Result<StatsElem> {
+
+    // Function names can be qualified, but column names can't.
+    if matches!(ctx.stream_mut().peek2(), Ok((_, Operator(OpenParenthesis | Dot)))) {
+        return no_match(ctx)
+    }
+
+    col_id.map(StatsElem::Column).parse(ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        ExprKind::IntegerConst,
+        FuncArgsKind,
+        FuncCall,
+        RelationTableRef,
+    };
+    use test_case::test_case;
+
+    #[test_case("statistics on a, b from t",
+        CreateStatsStmt::new(
+            vec![StatsElem::Column("a".into()), StatsElem::Column("b".into())],
+            vec![RelationTableRef::new("t").into()]
+        )
+    )]
+    #[test_case("statistics if not exists s.stats (ndistinct, mcv) on (1), now() from t",
+        CreateStatsStmt::new(
+            vec![
+                StatsElem::Expr(IntegerConst(1).into()),
+                StatsElem::Expr(FuncCall::new(
+                    vec!["now".into()],
+                    FuncArgsKind::Empty { order_within_group: None }
+                ).into()),
+            ],
+            vec![RelationTableRef::new("t").into()]
+        )
+        .with_name(vec!["s".into(), "stats".into()])
+        .with_kinds(vec!["ndistinct".into(), "mcv".into()])
+        .with_existence(Presence::Ignore)
+    )]
+    fn test_create_stats_stmt(source: &str, expected: CreateStatsStmt) {
+        test_parser!(source, create_stats_stmt, expected)
+    }
+}
+
+use crate::alt;
+use crate::combinators::any_name;
+use crate::combinators::col_id;
+use crate::combinators::core::Combinator;
+use crate::combinators::expr::a_expr;
+use crate::combinators::func_expr_windowless;
+use crate::combinators::if_not_exists;
+use crate::combinators::name_list;
+use crate::combinators::table_ref;
+use crate::many;
+use crate::no_match;
+use crate::paren;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::CreateStatsStmt;
+use pg_ast::Presence;
+use pg_ast::StatsElem;
+use pg_basics::QualifiedName;
+use pg_lexer::Keyword::FromKw;
+use pg_lexer::Keyword::On;
+use pg_lexer::Keyword::Statistics;
+use pg_lexer::OperatorKind::Comma;
+use pg_lexer::OperatorKind::Dot;
+use pg_lexer::OperatorKind::OpenParenthesis;
+use pg_parser_core::scan;
+use pg_parser_core::stream::TokenValue::Operator;
@@ -0,0 +1,64 @@
+/// Alias: `CreateTableSpaceStmt`
+pub(in crate::combinators::stmt) fn create_table_space_stmt(ctx: &mut ParserContext) -> scan::Result<CreateTableSpaceStmt> {
+
+    /*
+        TABLESPACE ColId ( OWNER RoleSpec )? LOCATION SCONST ( WITH reloptions )?
+    */
+
+    let (_, name, owner, _, location, options) = seq!(
+        Tablespace,
+        col_id,
+        seq!(Owner, role_spec).optional(),
+        LocationKw,
+        string,
+        seq!(With, reloptions).optional()
+    ).parse(ctx)?;
+
+    let mut stmt = CreateTableSpaceStmt::new(name, location);
+    stmt.set_owner(owner.map(|(_, owner)| owner))
+        .set_options(options.map(|(_, options)| options));
+
+    Ok(stmt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        DefinitionArg,
+        RelOption,
+        RoleSpec,
+        SignedNumber::IntegerConst,
+    };
+    use test_case::test_case;
+
+    #[test_case("tablespace dbspace location '/data/dbs'",
+        CreateTableSpaceStmt::new("dbspace", "/data/dbs")
+    )]
+    #[test_case("tablespace indexspace owner genevieve location '/data/indexes' with (random_page_cost = 1)",
+        CreateTableSpaceStmt::new("indexspace", "/data/indexes")
+            .with_owner(RoleSpec::Name("genevieve".into()))
+            .with_options(vec![
+                RelOption::new("random_page_cost", Some(DefinitionArg::Number(IntegerConst(1))))
+            ])
+    )]
+    fn test_create_table_space_stmt(source: &str, expected: CreateTableSpaceStmt) {
+        test_parser!(source, create_table_space_stmt, expected)
+    }
+}
+
+use crate::combinators::col_id;
+use crate::combinators::core::string;
+use crate::combinators::core::Combinator;
+use crate::combinators::reloptions;
+use crate::combinators::role_spec;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::CreateTableSpaceStmt;
+use pg_lexer::Keyword::LocationKw;
+use pg_lexer::Keyword::Owner;
+use pg_lexer::Keyword::Tablespace;
+use pg_lexer::Keyword::With;
+use pg_parser_core::scan;
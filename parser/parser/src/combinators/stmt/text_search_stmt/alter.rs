@@ -0,0 +1,397 @@
+enum Change<T> {
+    Action(T),
+    Owner(RoleSpec),
+    Name(NameChange),
+}
+
+enum NameChange {
+    Rename(Str),
+    Schema(Str),
+}
+
+impl NameChange {
+    fn into_stmt(
+        self,
+        name: QualifiedName,
+        rename_target: fn(QualifiedName) -> RenameTarget,
+        schema_target: fn(QualifiedName) -> AlterObjectSchemaTarget
+    ) -> RawStmt {
+        match self {
+            Self::Rename(new_name) => RenameStmt::new(rename_target(name), new_name).into(),
+            Self::Schema(new_schema) => AlterObjectSchemaStmt::new(schema_target(name), new_schema).into(),
+        }
+    }
+}
+
+/// Aliases:
+/// * `AlterTSConfigurationStmt`
+/// * `AlterTSDictionaryStmt`
+/// * `AlterOwnerStmt` (only `TEXT SEARCH`)
+/// * `AlterObjectSchemaStmt` (only `TEXT SEARCH`)
+/// * `RenameStmt` (only `TEXT SEARCH`)
+pub(in crate::combinators::stmt) fn alter_text_search_stmt(ctx: &mut ParserContext) -> scan::Result<RawStmt> {
+
+    /*
+        TEXT SEARCH (
+              CONFIGURATION any_name (
+                    ts_mapping_action
+                  | OWNER TO RoleSpec
+                  | RENAME TO ColId
+                  | SET SCHEMA ColId
+              )
+            | DICTIONARY any_name (
+                    definition
+                  | OWNER TO RoleSpec
+                  | RENAME TO ColId
+                  | SET SCHEMA ColId
+              )
+            | PARSER any_name (
+                    RENAME TO ColId
+                  | SET SCHEMA ColId
+              )
+            | TEMPLATE any_name (
+                    RENAME TO ColId
+                  | SET SCHEMA ColId
+              )
+        )
+    */
+
+    let (.., stmt) = seq!(
+        Text,
+        Search,
+        alt!(
+            alter_configuration,
+            alter_dictionary,
+            alter_parser,
+            alter_template
+        )
+    ).parse(ctx)?;
+
+    Ok(stmt)
+}
+
+fn alter_configuration(ctx: &mut ParserContext) -> scan::Result<RawStmt> {
+
+    let (_, name, change) = seq!(
+        Configuration,
+        any_name,
+        alt!(
+            ts_mapping_action.map(Change::Action),
+            owner_change,
+            name_change.map(Change::Name)
+        )
+    ).parse(ctx)?;
+
+    let stmt = match change {
+        Change::Action(action) => AlterTextSearchConfigurationStmt::new(name, action).into(),
+        Change::Owner(new_owner) => {
+            AlterOwnerStmt::new(
+                AlterOwnerTarget::TextSearchConfiguration(name),
+                new_owner
+            ).into()
+        },
+        Change::Name(change) => change.into_stmt(
+            name,
+            RenameTarget::TextSearchConfiguration,
+            AlterObjectSchemaTarget::TextSearchConfiguration
+        ),
+    };
+
+    Ok(stmt)
+}
+
+fn alter_dictionary(ctx: &mut ParserContext) -> scan::Result<RawStmt> {
+
+    let (_, name, change) = seq!(
+        Dictionary,
+        any_name,
+        alt!(
+            definition.map(Change::Action),
+            owner_change,
+            name_change.map(Change::Name)
+        )
+    ).parse(ctx)?;
+
+    let stmt = match change {
+        Change::Action(options) => AlterTextSearchDictionaryStmt::new(name, options).into(),
+        Change::Owner(new_owner) => {
+            AlterOwnerStmt::new(
+                AlterOwnerTarget::TextSearchDictionary(name),
+                new_owner
+            ).into()
+        },
+        Change::Name(change) => change.into_stmt(
+            name,
+            RenameTarget::TextSearchDictionary,
+            AlterObjectSchemaTarget::TextSearchDictionary
+        ),
+    };
+
+    Ok(stmt)
+}
+
+fn alter_parser(ctx: &mut ParserContext) -> scan::Result<RawStmt> {
+
+    let (_, name, change) = seq!(ParserKw, any_name, name_change)
+        .parse(ctx)?;
+
+    let stmt = change.into_stmt(
+        name,
+        RenameTarget::TextSearchParser,
+        AlterObjectSchemaTarget::TextSearchParser
+    );
+
+    Ok(stmt)
+}
+
+fn alter_template(ctx: &mut ParserContext) -> scan::Result<RawStmt> {
+
+    let (_, name, change) = seq!(Template, any_name, name_change)
+        .parse(ctx)?;
+
+    let stmt = change.into_stmt(
+        name,
+        RenameTarget::TextSearchTemplate,
+        AlterObjectSchemaTarget::TextSearchTemplate
+    );
+
+    Ok(stmt)
+}
+
+fn owner_change<T>(ctx: &mut ParserContext) -> scan::Result<Change<T>> {
+
+    let (.., new_owner) = seq!(Owner, To, role_spec)
+        .parse(ctx)?;
+
+    Ok(Change::Owner(new_owner))
+}
+
+fn name_change(ctx: &mut ParserContext) -> scan::Result<NameChange> {
+
+    /*
+          RENAME TO ColId
+        | SET SCHEMA ColId
+    */
+
+    alt!(
+        seq!(Rename, To, col_id)
+            .map(|(.., new_name)| NameChange::Rename(new_name)),
+        seq!(Set, Schema, col_id)
+            .map(|(.., new_schema)| NameChange::Schema(new_schema))
+    ).parse(ctx)
+}
+
+fn ts_mapping_action(ctx: &mut ParserContext) -> scan::Result<TextSearchMappingAction> {
+
+    /*
+          ADD MAPPING FOR name_list WITH any_name_list
+        | ALTER MAPPING FOR name_list WITH any_name_list
+        | ALTER MAPPING ( FOR name_list )? REPLACE any_name WITH any_name
+        | DROP MAPPING ( IF EXISTS )? FOR name_list
+    */
+
+    alt!(
+        seq!(Add, Mapping, For, name_list, With, any_name_list)
+            .map(|(.., token_types, _, dictionaries)|
+                TextSearchMappingAction::Add { token_types, dictionaries }
+            ),
+        seq!(Alter, Mapping, alter_mapping)
+            .map(|(.., action)| action),
+        seq!(
+            DropKw,
+            Mapping,
+            if_exists.optional()
+                .map(Option::unwrap_or_default),
+            For,
+            name_list
+        ).map(|(_, _, existence, _, token_types)|
+            TextSearchMappingAction::Drop { token_types, existence }
+        )
+    ).parse(ctx)
+}
+
+fn alter_mapping(ctx: &mut ParserContext) -> scan::Result<TextSearchMappingAction> {
+
+    /*
+          FOR name_list WITH any_name_list
+        | ( FOR name_list )? REPLACE any_name WITH any_name
+    */
+
+    alt!(
+        seq!(
+            For,
+            name_list,
+            alt!(
+                seq!(With, any_name_list)
+                    .map(|(_, dictionaries)| Ok(dictionaries)),
+                replace_dictionary
+                    .map(Err)
+            )
+        ).map(|(_, token_types, mapping)| match mapping {
+            Ok(dictionaries) => TextSearchMappingAction::Alter { token_types, dictionaries },
+            Err((old_dictionary, new_dictionary)) => TextSearchMappingAction::Replace {
+                token_types: Some(token_types),
+                old_dictionary,
+                new_dictionary
+            },
+        }),
+        replace_dictionary
+            .map(|(old_dictionary, new_dictionary)| TextSearchMappingAction::Replace {
+                token_types: None,
+                old_dictionary,
+                new_dictionary
+            })
+    ).parse(ctx)
+}
+
+fn replace_dictionary(ctx: &mut ParserContext) -> scan::Result<(QualifiedName, QualifiedName)> {
+
+    /*
+        REPLACE any_name WITH any_name
+    */
+
+    let (_, old_dictionary, _, new_dictionary) = seq!(Replace, any_name, With, any_name)
+        .parse(ctx)?;
+
+    Ok((old_dictionary, new_dictionary))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        DefinitionArg,
+        DefinitionElem,
+        Presence,
+        RoleSpec::CurrentUser,
+        SignedNumber::IntegerConst,
+    };
+    use test_case::test_case;
+
+    #[test_case("text search configuration some_config add mapping for word, asciiword with english_stem, simple",
+        AlterTextSearchConfigurationStmt::new(
+            vec!["some_config".into()],
+            TextSearchMappingAction::Add {
+                token_types: vec!["word".into(), "asciiword".into()],
+                dictionaries: vec![vec!["english_stem".into()], vec!["simple".into()]]
+            }
+        ).into()
+    )]
+    #[test_case("text search configuration some_config alter mapping for word with simple",
+        AlterTextSearchConfigurationStmt::new(
+            vec!["some_config".into()],
+            TextSearchMappingAction::Alter {
+                token_types: vec!["word".into()],
+                dictionaries: vec![vec!["simple".into()]]
+            }
+        ).into()
+    )]
+    #[test_case("text search configuration some_config alter mapping replace english with simple",
+        AlterTextSearchConfigurationStmt::new(
+            vec!["some_config".into()],
+            TextSearchMappingAction::Replace {
+                token_types: None,
+                old_dictionary: vec!["english".into()],
+                new_dictionary: vec!["simple".into()]
+            }
+        ).into()
+    )]
+    #[test_case("text search configuration some_config alter mapping for word replace english with simple",
+        AlterTextSearchConfigurationStmt::new(
+            vec!["some_config".into()],
+            TextSearchMappingAction::Replace {
+                token_types: Some(vec!["word".into()]),
+                old_dictionary: vec!["english".into()],
+                new_dictionary: vec!["simple".into()]
+            }
+        ).into()
+    )]
+    #[test_case("text search configuration some_config drop mapping if exists for word",
+        AlterTextSearchConfigurationStmt::new(
+            vec!["some_config".into()],
+            TextSearchMappingAction::Drop {
+                token_types: vec!["word".into()],
+                existence: Presence::Ignore
+            }
+        ).into()
+    )]
+    #[test_case("text search configuration some_config owner to current_user",
+        AlterOwnerStmt::new(
+            AlterOwnerTarget::TextSearchConfiguration(vec!["some_config".into()]),
+            CurrentUser
+        ).into()
+    )]
+    #[test_case("text search dictionary some_dict (maxlen = 10)",
+        AlterTextSearchDictionaryStmt::new(
+            vec!["some_dict".into()],
+            vec![DefinitionElem::new("maxlen", Some(DefinitionArg::Number(IntegerConst(10))))]
+        ).into()
+    )]
+    #[test_case("text search dictionary some_dict rename to other_dict",
+        RenameStmt::new(
+            RenameTarget::TextSearchDictionary(vec!["some_dict".into()]),
+            "other_dict"
+        ).into()
+    )]
+    #[test_case("text search parser some_parser set schema some_schema",
+        AlterObjectSchemaStmt::new(
+            AlterObjectSchemaTarget::TextSearchParser(vec!["some_parser".into()]),
+            "some_schema"
+        ).into()
+    )]
+    #[test_case("text search template some_template rename to other_template",
+        RenameStmt::new(
+            RenameTarget::TextSearchTemplate(vec!["some_template".into()]),
+            "other_template"
+        ).into()
+    )]
+    fn test_alter_text_search_stmt(source: &str, expected: RawStmt) {
+        test_parser!(source, alter_text_search_stmt, expected)
+    }
+}
+
+use crate::alt;
+use crate::combinators::any_name;
+use crate::combinators::any_name_list;
+use crate::combinators::col_id;
+use crate::combinators::core::Combinator;
+use crate::combinators::definition;
+use crate::combinators::if_exists;
+use crate::combinators::name_list;
+use crate::combinators::role_spec;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::AlterObjectSchemaStmt;
+use pg_ast::AlterObjectSchemaTarget;
+use pg_ast::AlterOwnerStmt;
+use pg_ast::AlterOwnerTarget;
+use pg_ast::AlterTextSearchConfigurationStmt;
+use pg_ast::AlterTextSearchDictionaryStmt;
+use pg_ast::RawStmt;
+use pg_ast::RenameStmt;
+use pg_ast::RenameTarget;
+use pg_ast::RoleSpec;
+use pg_ast::TextSearchMappingAction;
+use pg_basics::QualifiedName;
+use pg_basics::Str;
+use pg_lexer::Keyword::Add;
+use pg_lexer::Keyword::Alter;
+use pg_lexer::Keyword::Configuration;
+use pg_lexer::Keyword::Dictionary;
+use pg_lexer::Keyword::DropKw;
+use pg_lexer::Keyword::For;
+use pg_lexer::Keyword::Mapping;
+use pg_lexer::Keyword::Owner;
+use pg_lexer::Keyword::ParserKw;
+use pg_lexer::Keyword::Rename;
+use pg_lexer::Keyword::Replace;
+use pg_lexer::Keyword::Schema;
+use pg_lexer::Keyword::Search;
+use pg_lexer::Keyword::Set;
+use pg_lexer::Keyword::Template;
+use pg_lexer::Keyword::Text;
+use pg_lexer::Keyword::To;
+use pg_lexer::Keyword::With;
+use pg_parser_core::scan;
@@ -0,0 +1,74 @@
+/// Alias: `DefineStmt` (only `TEXT SEARCH`)
+pub(in crate::combinators::stmt) fn create_text_search_stmt(ctx: &mut ParserContext) -> scan::Result<DefineStmt> {
+
+    /*
+        TEXT SEARCH (
+              CONFIGURATION
+            | DICTIONARY
+            | PARSER
+            | TEMPLATE
+        ) any_name definition
+    */
+
+    let (object, definition) = seq!(text_search, definition)
+        .parse(ctx)?;
+
+    let stmt = match object {
+        TextSearch::Configuration(name) => DefineStmt::TextSearchConfiguration { name, definition },
+        TextSearch::Dictionary(name) => DefineStmt::TextSearchDictionary { name, definition },
+        TextSearch::Parser(name) => DefineStmt::TextSearchParser { name, definition },
+        TextSearch::Template(name) => DefineStmt::TextSearchTemplate { name, definition },
+    };
+
+    Ok(stmt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        DefinitionArg,
+        DefinitionElem,
+        FuncType,
+        TypeName,
+    };
+    use test_case::test_case;
+
+    fn some_definition() -> Vec<DefinitionElem> {
+        vec![
+            DefinitionElem::new(
+                "copy",
+                Some(DefinitionArg::Type(FuncType::Type(
+                    TypeName::Generic { name: vec!["english".into()], type_modifiers: None }.into()
+                )))
+            )
+        ]
+    }
+
+    #[test_case("text search configuration some_config (copy = english)",
+        DefineStmt::TextSearchConfiguration { name: vec!["some_config".into()], definition: some_definition() }
+    )]
+    #[test_case("text search dictionary some_dict (copy = english)",
+        DefineStmt::TextSearchDictionary { name: vec!["some_dict".into()], definition: some_definition() }
+    )]
+    #[test_case("text search parser some_parser (copy = english)",
+        DefineStmt::TextSearchParser { name: vec!["some_parser".into()], definition: some_definition() }
+    )]
+    #[test_case("text search template some_template (copy = english)",
+        DefineStmt::TextSearchTemplate { name: vec!["some_template".into()], definition: some_definition() }
+    )]
+    fn test_create_text_search_stmt(source: &str, expected: DefineStmt) {
+        test_parser!(source, create_text_search_stmt, expected)
+    }
+}
+
+use crate::combinators::core::Combinator;
+use crate::combinators::definition;
+use crate::combinators::stmt::text_search;
+use crate::combinators::stmt::TextSearch;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::DefineStmt;
+use pg_parser_core::scan;
@@ -0,0 +1,141 @@
+enum TransformFunction {
+    FromSql(FunctionWithArgs),
+    ToSql(FunctionWithArgs),
+}
+
+/// Alias: `CreateTransformStmt`
+///
+/// `opt_or_replace` is parsed by `with_or_replace`.
+pub(super) fn create_transform_stmt(replace: bool) -> impl Combinator<Output = CreateTransformStmt> {
+
+    /*
+        TRANSFORM FOR Typename LANGUAGE ColId '(' transform_element_list ')'
+    */
+
+    parser(move |ctx| {
+        let (transform, functions) = seq!(
+            transform,
+            paren!(transform_element_list)
+        ).parse(ctx)?;
+
+        let mut stmt = CreateTransformStmt::new(transform)
+            .with_replace(replace);
+
+        for function in functions {
+            match function {
+                TransformFunction::FromSql(function) => stmt.set_from_sql(Some(function)),
+                TransformFunction::ToSql(function) => stmt.set_to_sql(Some(function)),
+            };
+        }
+
+        Ok(stmt)
+    })
+}
+
+/// Alias: `transform_element_list`
+fn transform_element_list(ctx: &mut ParserContext) -> scan::Result<Vec<TransformFunction>> {
+
+    /*
+          FROM SQL WITH FUNCTION function_with_argtypes ( ',' TO SQL WITH FUNCTION function_with_argtypes )?
+        | TO SQL WITH FUNCTION function_with_argtypes ( ',' FROM SQL WITH FUNCTION function_with_argtypes )?
+    */
+
+    let (first, second) = alt!(
+        seq!(
+            from_sql.map(TransformFunction::FromSql),
+            seq!(Comma, to_sql.map(TransformFunction::ToSql)).optional()
+        ),
+        seq!(
+            to_sql.map(TransformFunction::ToSql),
+            seq!(Comma, from_sql.map(TransformFunction::FromSql)).optional()
+        )
+    ).parse(ctx)?;
+
+    let mut functions = vec![first];
+    functions.extend(second.map(|(_, function)| function));
+
+    Ok(functions)
+}
+
+fn from_sql(ctx: &mut ParserContext) -> scan::Result<FunctionWithArgs> {
+
+    /*
+        FROM SQL WITH FUNCTION function_with_argtypes
+    */
+
+    let (.., function) = seq!(FromKw, Sql, With, Function, function_with_argtypes)
+        .parse(ctx)?;
+
+    Ok(function)
+}
+
+fn to_sql(ctx: &mut ParserContext) -> scan::Result<FunctionWithArgs> {
+
+    /*
+        TO SQL WITH FUNCTION function_with_argtypes
+    */
+
+    let (.., function) = seq!(To, Sql, With, Function, function_with_argtypes)
+        .parse(ctx)?;
+
+    Ok(function)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combinators::with_or_replace;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        Transform,
+        TypeName::Json,
+    };
+    use pg_basics::Located;
+    use pg_elog::Error::Parser;
+    use scan::Error::ScanErr;
+    use test_case::test_case;
+
+    fn function(name: &'static str) -> FunctionWithArgs {
+        FunctionWithArgs::new(vec![name.into()], None)
+    }
+
+    #[test_case("transform for json language plpython3u (from sql with function f, to sql with function g)",
+        CreateTransformStmt::new(Transform::new(Json, "plpython3u"))
+            .with_from_sql(function("f"))
+            .with_to_sql(function("g"))
+    )]
+    #[test_case("or replace transform for json language plperl (to sql with function g)",
+        CreateTransformStmt::new(Transform::new(Json, "plperl"))
+            .with_to_sql(function("g"))
+            .with_replace(true)
+    )]
+    fn test_create_transform_stmt(source: &str, expected: CreateTransformStmt) {
+        test_parser!(source, with_or_replace(create_transform_stmt), expected)
+    }
+
+    #[test_case("transform for json language plperl (to sql with function g, to sql with function g)" => matches Err(ScanErr(
+        Located(Parser(_), _)
+    )))]
+    fn test_duplicate_transform_function(source: &str) -> scan::Result<CreateTransformStmt> {
+        test_parser!(source, with_or_replace(create_transform_stmt))
+    }
+}
+
+use crate::alt;
+use crate::combinators::core::parser;
+use crate::combinators::core::Combinator;
+use crate::combinators::function_with_argtypes;
+use crate::combinators::stmt::transform;
+use crate::paren;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::CreateTransformStmt;
+use pg_ast::FunctionWithArgs;
+use pg_lexer::Keyword::FromKw;
+use pg_lexer::Keyword::Function;
+use pg_lexer::Keyword::Sql;
+use pg_lexer::Keyword::To;
+use pg_lexer::Keyword::With;
+use pg_lexer::OperatorKind::Comma;
+use pg_parser_core::scan;
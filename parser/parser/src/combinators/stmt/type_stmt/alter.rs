@@ -0,0 +1,369 @@
+enum Change {
+    Enum(AlterEnumAction),
+    Commands(Vec<AlterTypeCmd>),
+    Owner(RoleSpec),
+    Name(Str),
+    Attribute { attribute: Str, new_name: Str },
+    Schema(Str),
+}
+
+/// Aliases:
+/// * `AlterCompositeTypeStmt`
+/// * `AlterEnumStmt`
+/// * `AlterOwnerStmt` (only `TYPE`)
+/// * `AlterObjectSchemaStmt` (only `TYPE`)
+/// * `RenameStmt` (only `TYPE`)
+pub(in crate::combinators::stmt) fn alter_type_stmt(ctx: &mut ParserContext) -> scan::Result<RawStmt> {
+
+    /*
+        TYPE any_name (
+              ADD VALUE ( IF NOT EXISTS )? SCONST ( ( BEFORE | AFTER ) SCONST )?
+            | RENAME VALUE SCONST TO SCONST
+            | DROP VALUE SCONST
+            | OWNER TO RoleSpec
+            | RENAME TO ColId
+            | RENAME ATTRIBUTE ColId TO ColId ( drop_behavior )?
+            | SET SCHEMA ColId
+            | alter_type_cmds
+        )
+    */
+
+    let (_, type_name, change) = seq!(Type, any_name, change)
+        .parse(ctx)?;
+
+    let stmt = match change {
+        Change::Enum(action) => AlterEnumStmt::new(type_name, action).into(),
+        Change::Commands(actions) => AlterCompositeTypeStmt::new(type_name, actions).into(),
+        Change::Owner(new_owner) => {
+            AlterOwnerStmt::new(
+                AlterOwnerTarget::Type(type_name),
+                new_owner
+            ).into()
+        },
+        Change::Name(new_name) => {
+            RenameStmt::new(
+                RenameTarget::Type(type_name),
+                new_name
+            ).into()
+        },
+        Change::Attribute { attribute, new_name } => {
+            RenameStmt::new(
+                RenameTarget::TypeAttribute { typ: type_name, attribute },
+                new_name
+            ).into()
+        },
+        Change::Schema(new_schema) => {
+            AlterObjectSchemaStmt::new(
+                AlterObjectSchemaTarget::Type(type_name),
+                new_schema
+            ).into()
+        },
+    };
+
+    Ok(stmt)
+}
+
+fn change(ctx: &mut ParserContext) -> scan::Result<Change> {
+    alt!(
+        add_enum_value.map(Change::Enum),
+        drop_enum_value.map(Change::Enum),
+        seq!(Owner, To, role_spec)
+            .map(|(.., new_owner)| Change::Owner(new_owner)),
+        seq!(
+            Rename,
+            alt!(
+                seq!(Value, string, To, string)
+                    .map(|(_, old_value, _, new_value)|
+                        Change::Enum(AlterEnumAction::RenameValue { old_value, new_value })
+                    ),
+                seq!(To, col_id)
+                    .map(|(_, new_name)| Change::Name(new_name)),
+                seq!(
+                    Attribute,
+                    col_id,
+                    To,
+                    col_id,
+                    // `RenameStmt` doesn't keep the behavior, since it only matters for typed tables.
+                    drop_behavior.optional()
+                ).map(|(_, attribute, _, new_name, _)| Change::Attribute { attribute, new_name })
+            )
+        ).map(|(_, change)| change),
+        seq!(Set, Schema, col_id)
+            .map(|(.., new_schema)| Change::Schema(new_schema)),
+        alter_type_cmds.map(Change::Commands)
+    ).parse(ctx)
+}
+
+fn add_enum_value(ctx: &mut ParserContext) -> scan::Result<AlterEnumAction> {
+
+    /*
+        ADD VALUE ( IF NOT EXISTS )? SCONST ( ( BEFORE | AFTER ) SCONST )?
+    */
+
+    // `ADD ATTRIBUTE` is handled by `alter_type_cmds`.
+    if ! matches!(ctx.stream_mut().peek2(), Ok((K(Add), K(Value)))) {
+        return no_match(ctx)
+    }
+
+    let (.., existence, value, position) = seq!(
+        Add,
+        Value,
+        if_not_exists.optional()
+            .map(Option::unwrap_or_default),
+        string,
+        enum_value_position.optional()
+    ).parse(ctx)?;
+
+    Ok(AlterEnumAction::AddValue { value, position, existence })
+}
+
+fn enum_value_position(ctx: &mut ParserContext) -> scan::Result<EnumValuePosition> {
+
+    /*
+          BEFORE SCONST
+        | AFTER SCONST
+    */
+
+    alt!(
+        seq!(Before, string)
+            .map(|(_, value)| EnumValuePosition::Before(value)),
+        seq!(After, string)
+            .map(|(_, value)| EnumValuePosition::After(value))
+    ).parse(ctx)
+}
+
+fn drop_enum_value(ctx: &mut ParserContext) -> scan::Result<AlterEnumAction> {
+
+    /*
+        DROP VALUE SCONST
+    */
+
+    // `DROP ATTRIBUTE` is handled by `alter_type_cmds`.
+    if ! matches!(ctx.stream_mut().peek2(), Ok((K(DropKw), K(Value)))) {
+        return no_match(ctx)
+    }
+
+    let (Located(_, loc), ..) = seq!(located!(DropKw), Value, string)
+        .parse(ctx)?;
+
+    Err(DropEnumValue.at_location(loc).into())
+}
+
+/// Alias: `alter_type_cmds`
+fn alter_type_cmds(ctx: &mut ParserContext) -> scan::Result<Vec<AlterTypeCmd>> {
+    many!(sep = Comma, alter_type_cmd).parse(ctx)
+}
+
+/// Alias: `alter_type_cmd`
+fn alter_type_cmd(ctx: &mut ParserContext) -> scan::Result<AlterTypeCmd> {
+
+    /*
+          ADD ATTRIBUTE TableFuncElement ( drop_behavior )?
+        | DROP ATTRIBUTE ( IF EXISTS )? ColId ( drop_behavior )?
+        | ALTER ATTRIBUTE ColId ( SET DATA )? TYPE Typename ( collate_clause )? ( drop_behavior )?
+    */
+
+    alt!(
+        seq!(
+            Add,
+            Attribute,
+            table_func_element,
+            drop_behavior.optional()
+                .map(Option::unwrap_or_default)
+        ).map(|(.., attribute, behavior)|
+            AlterTypeCmd::AddAttribute { attribute, behavior }
+        ),
+        seq!(
+            DropKw,
+            Attribute,
+            if_exists.optional()
+                .map(Option::unwrap_or_default),
+            col_id,
+            drop_behavior.optional()
+                .map(Option::unwrap_or_default)
+        ).map(|(.., existence, name, behavior)|
+            AlterTypeCmd::DropAttribute { name, existence, behavior }
+        ),
+        seq!(
+            Alter,
+            Attribute,
+            col_id,
+            seq!(Set, Data).optional(),
+            Type,
+            typename,
+            collate_clause.optional(),
+            drop_behavior.optional()
+                .map(Option::unwrap_or_default)
+        ).map(|(_, _, name, _, _, type_name, collation, behavior)|
+            AlterTypeCmd::AlterAttributeType { name, type_name, collation, behavior }
+        )
+    ).parse(ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        DropBehavior,
+        Presence,
+        SimpleColumnDefinition,
+        TypeName::{Int4, Json},
+    };
+    use pg_ast::RoleSpec::CurrentUser;
+    use pg_elog::Error::Parser;
+    use scan::Error::ScanErr;
+    use test_case::test_case;
+
+    #[test_case("type mood add value 'happy'",
+        AlterEnumStmt::new(
+            vec!["mood".into()],
+            AlterEnumAction::AddValue {
+                value: "happy".into(),
+                position: None,
+                existence: Presence::Fail
+            }
+        ).into()
+    )]
+    #[test_case("type mood add value if not exists 'happy' before 'ok'",
+        AlterEnumStmt::new(
+            vec!["mood".into()],
+            AlterEnumAction::AddValue {
+                value: "happy".into(),
+                position: Some(EnumValuePosition::Before("ok".into())),
+                existence: Presence::Ignore
+            }
+        ).into()
+    )]
+    #[test_case("type mood add value 'happy' after 'ok'",
+        AlterEnumStmt::new(
+            vec!["mood".into()],
+            AlterEnumAction::AddValue {
+                value: "happy".into(),
+                position: Some(EnumValuePosition::After("ok".into())),
+                existence: Presence::Fail
+            }
+        ).into()
+    )]
+    #[test_case("type mood rename value 'sad' to 'blue'",
+        AlterEnumStmt::new(
+            vec!["mood".into()],
+            AlterEnumAction::RenameValue {
+                old_value: "sad".into(),
+                new_value: "blue".into()
+            }
+        ).into()
+    )]
+    #[test_case("type some_type owner to current_user",
+        AlterOwnerStmt::new(
+            AlterOwnerTarget::Type(vec!["some_type".into()]),
+            CurrentUser
+        ).into()
+    )]
+    #[test_case("type some_type rename to other_type",
+        RenameStmt::new(
+            RenameTarget::Type(vec!["some_type".into()]),
+            "other_type"
+        ).into()
+    )]
+    #[test_case("type some_type rename attribute foo to bar cascade",
+        RenameStmt::new(
+            RenameTarget::TypeAttribute {
+                typ: vec!["some_type".into()],
+                attribute: "foo".into()
+            },
+            "bar"
+        ).into()
+    )]
+    #[test_case("type some_type set schema some_schema",
+        AlterObjectSchemaStmt::new(
+            AlterObjectSchemaTarget::Type(vec!["some_type".into()]),
+            "some_schema"
+        ).into()
+    )]
+    #[test_case("type complex add attribute r int, drop attribute if exists i cascade, alter attribute x set data type json collate some_collation",
+        AlterCompositeTypeStmt::new(
+            vec!["complex".into()],
+            vec![
+                AlterTypeCmd::AddAttribute {
+                    attribute: SimpleColumnDefinition::new("r", Int4),
+                    behavior: DropBehavior::Restrict
+                },
+                AlterTypeCmd::DropAttribute {
+                    name: "i".into(),
+                    existence: Presence::Ignore,
+                    behavior: DropBehavior::Cascade
+                },
+                AlterTypeCmd::AlterAttributeType {
+                    name: "x".into(),
+                    type_name: Json.into(),
+                    collation: Some(vec!["some_collation".into()]),
+                    behavior: DropBehavior::Restrict
+                },
+            ]
+        ).into()
+    )]
+    fn test_alter_type_stmt(source: &str, expected: RawStmt) {
+        test_parser!(source, alter_type_stmt, expected)
+    }
+
+    #[test_case("type mood drop value 'sad'" => matches Err(ScanErr(
+        Located(Parser(DropEnumValue), _)
+    )))]
+    fn test_drop_enum_value(source: &str) -> scan::Result<RawStmt> {
+        test_parser!(source, alter_type_stmt)
+    }
+}
+
+use crate::alt;
+use crate::combinators::any_name;
+use crate::combinators::col_id;
+use crate::combinators::collate_clause;
+use crate::combinators::core::string;
+use crate::combinators::core::Combinator;
+use crate::combinators::drop_behavior;
+use crate::combinators::if_exists;
+use crate::combinators::if_not_exists;
+use crate::combinators::role_spec;
+use crate::combinators::table_func_element;
+use crate::combinators::typename;
+use crate::located;
+use crate::many;
+use crate::no_match;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::AlterCompositeTypeStmt;
+use pg_ast::AlterEnumAction;
+use pg_ast::AlterEnumStmt;
+use pg_ast::AlterObjectSchemaStmt;
+use pg_ast::AlterObjectSchemaTarget;
+use pg_ast::AlterOwnerStmt;
+use pg_ast::AlterOwnerTarget;
+use pg_ast::AlterTypeCmd;
+use pg_ast::EnumValuePosition;
+use pg_ast::RawStmt;
+use pg_ast::RenameStmt;
+use pg_ast::RenameTarget;
+use pg_ast::RoleSpec;
+use pg_basics::IntoLocated;
+use pg_basics::Located;
+use pg_basics::Str;
+use pg_elog::parser::Error::DropEnumValue;
+use pg_lexer::Keyword::Add;
+use pg_lexer::Keyword::After;
+use pg_lexer::Keyword::Alter;
+use pg_lexer::Keyword::Attribute;
+use pg_lexer::Keyword::Before;
+use pg_lexer::Keyword::Data;
+use pg_lexer::Keyword::DropKw;
+use pg_lexer::Keyword::Owner;
+use pg_lexer::Keyword::Rename;
+use pg_lexer::Keyword::Schema;
+use pg_lexer::Keyword::Set;
+use pg_lexer::Keyword::To;
+use pg_lexer::Keyword::Type;
+use pg_lexer::Keyword::Value;
+use pg_lexer::OperatorKind::Comma;
+use pg_parser_core::scan;
+use pg_parser_core::stream::TokenValue::Keyword as K;
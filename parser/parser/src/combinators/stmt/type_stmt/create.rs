@@ -0,0 +1,157 @@
+enum Definition {
+    Enum(Option<Vec<Box<str>>>),
+    Range(Vec<DefinitionElem>),
+    Composite(Option<Vec<SimpleColumnDefinition>>),
+    Base(Vec<DefinitionElem>),
+}
+
+/// Aliases:
+/// * `CompositeTypeStmt`
+/// * `CreateEnumStmt`
+/// * `CreateRangeStmt`
+/// * `DefineStmt` (only `TYPE`)
+pub(in crate::combinators::stmt) fn create_type_stmt(ctx: &mut ParserContext) -> scan::Result<RawStmt> {
+
+    /*
+        TYPE any_name (
+              AS ENUM '(' ( enum_val_list )? ')'
+            | AS RANGE definition
+            | AS '(' ( OptTableFuncElementList )? ')'
+            | definition
+        )?
+    */
+
+    let (_, type_name, definition) = seq!(
+        Type,
+        any_name,
+        type_definition.optional()
+    ).parse(ctx)?;
+
+    let stmt = match definition {
+        None => DefineStmt::Type { name: type_name, definition: None }.into(),
+        Some(Definition::Base(definition)) => {
+            DefineStmt::Type { name: type_name, definition: Some(definition) }.into()
+        },
+        Some(Definition::Enum(values)) => CreateEnumStmt::new(type_name, values).into(),
+        Some(Definition::Range(params)) => CreateRangeStmt::new(type_name, params).into(),
+        Some(Definition::Composite(attributes)) => CompositeTypeStmt::new(type_name, attributes).into(),
+    };
+
+    Ok(stmt)
+}
+
+fn type_definition(ctx: &mut ParserContext) -> scan::Result<Definition> {
+    alt!(
+        seq!(
+            As,
+            alt!(
+                seq!(Enum, paren!(enum_val_list.optional()))
+                    .map(|(_, values)| Definition::Enum(values)),
+                seq!(RangeKw, definition)
+                    .map(|(_, params)| Definition::Range(params)),
+                paren!(table_func_element_list.optional())
+                    .map(Definition::Composite)
+            )
+        ).map(|(_, def)| def),
+        definition.map(Definition::Base)
+    ).parse(ctx)
+}
+
+/// Alias: `enum_val_list`
+fn enum_val_list(ctx: &mut ParserContext) -> scan::Result<Vec<Box<str>>> {
+    many!(sep = Comma, string).parse(ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        DefinitionArg,
+        FuncType,
+        TypeName::{self, Int4, Json},
+    };
+    use test_case::test_case;
+
+    #[test_case("type shell_type",
+        DefineStmt::Type {
+            name: vec!["shell_type".into()],
+            definition: None
+        }.into()
+    )]
+    #[test_case("type base_type (input = base_in, passedbyvalue)",
+        DefineStmt::Type {
+            name: vec!["base_type".into()],
+            definition: Some(vec![
+                DefinitionElem::new(
+                    "input",
+                    Some(DefinitionArg::Type(FuncType::Type(
+                        TypeName::Generic { name: vec!["base_in".into()], type_modifiers: None }.into()
+                    )))
+                ),
+                DefinitionElem::new("passedbyvalue", None),
+            ])
+        }.into()
+    )]
+    #[test_case("type some_schema.mood as enum ('sad', 'ok')",
+        CreateEnumStmt::new(
+            vec!["some_schema".into(), "mood".into()],
+            Some(vec!["sad".into(), "ok".into()])
+        ).into()
+    )]
+    #[test_case("type empty_enum as enum ()",
+        CreateEnumStmt::new(vec!["empty_enum".into()], None).into()
+    )]
+    #[test_case("type float_range as range (subtype = double precision)",
+        CreateRangeStmt::new(
+            vec!["float_range".into()],
+            vec![
+                DefinitionElem::new(
+                    "subtype",
+                    Some(DefinitionArg::Type(FuncType::Type(TypeName::Float8.into())))
+                )
+            ]
+        ).into()
+    )]
+    #[test_case("type complex as (r int, i json collate some_collation)",
+        CompositeTypeStmt::new(
+            vec!["complex".into()],
+            Some(vec![
+                SimpleColumnDefinition::new("r", Int4),
+                SimpleColumnDefinition::new("i", Json)
+                    .with_collation(vec!["some_collation".into()]),
+            ])
+        ).into()
+    )]
+    #[test_case("type empty_composite as ()",
+        CompositeTypeStmt::new(vec!["empty_composite".into()], None).into()
+    )]
+    fn test_create_type_stmt(source: &str, expected: RawStmt) {
+        test_parser!(source, create_type_stmt, expected)
+    }
+}
+
+use crate::alt;
+use crate::combinators::any_name;
+use crate::combinators::core::string;
+use crate::combinators::core::Combinator;
+use crate::combinators::definition;
+use crate::combinators::table_func_element_list;
+use crate::many;
+use crate::paren;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::CompositeTypeStmt;
+use pg_ast::CreateEnumStmt;
+use pg_ast::CreateRangeStmt;
+use pg_ast::DefineStmt;
+use pg_ast::DefinitionElem;
+use pg_ast::RawStmt;
+use pg_ast::SimpleColumnDefinition;
+use pg_lexer::Keyword::As;
+use pg_lexer::Keyword::Enum;
+use pg_lexer::Keyword::RangeKw;
+use pg_lexer::Keyword::Type;
+use pg_lexer::OperatorKind::Comma;
+use pg_parser_core::scan;
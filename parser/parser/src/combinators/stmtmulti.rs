@@ -1,33 +1,114 @@
-pub(crate) fn stmtmulti(ctx: &mut ParserContext) -> scan::Result<Vec<RawStmt>> {
+/// Parses each statement on its own, so that an error in one statement doesn't discard the others.
+///
+/// After an error, parsing resumes after the statement's terminating `;`, unless `fail_fast` is set,
+/// in which case the error is the last element.
+pub(crate) fn stmtmulti(ctx: &mut ParserContext, fail_fast: bool) -> Vec<LocatedResult<Located<RawStmt>>> {
 
-    // This production is slightly cheating, not because it's more efficient,
-    // but helps simplify capturing the combinator.
     // Production:
     //     (';')* ( toplevel_stmt ( (';')+ toplevel_stmt? )* )?
     // Original production:
     //     toplevel_stmt? ( ';' toplevel_stmt? )*
 
-    let (_, stmts) = seq!(
-        semicolons.optional(),
-        many!(sep = semicolons, toplevel_stmt).optional()
-    ).parse(ctx)?;
-
-    // Note that `many` returns `NoMatch` if the Vec would be empty.
-    match stmts {
-        Some(stmts) => Ok(stmts),
-        None if ctx.stream_mut().eof() => {
-            // The content didn't have any statements.
-            // The stream is either empty, or whitespaces and/or semicolons.
+    let mut results = Vec::new();
+
+    loop {
+        // Any error, besides `NoMatch`, is reported when parsing the statement.
+        let _ = semicolons(ctx);
+
+        if ctx.stream_mut().eof() {
+            // Empty input, or only whitespaces and/or semicolons, is valid.
+            break
+        }
+
+        let start = ctx.stream_mut().current_location().range().start;
+
+        let result = terminated_stmt(ctx);
+        let failed = result.is_err();
+        results.push(result);
+
+        if failed {
+            if fail_fast {
+                break
+            }
+
+            let stream = ctx.stream_mut();
+            let end = statement_end(stream.source(), start, stream.standard_conforming_strings());
+            ctx.stream_mut().skip_to(end);
+        }
+    }
+
+    results
+}
+
+/// A statement must be followed by `;`, or the end of the source.
+///
+/// The location spans the statement, without the `;`, like PG's `stmt_location` and `stmt_len`.
+fn terminated_stmt(ctx: &mut ParserContext) -> LocatedResult<Located<RawStmt>> {
+
+    let start = ctx.stream_mut().current_location();
+
+    let stmt = match toplevel_stmt(ctx) {
+        Ok(stmt) => stmt,
+        Err(ScanErr(err)) => return Err(err),
+        Err(Eof(loc)) => return Err(syntax(loc)),
+        Err(NoMatch(_)) => {
+            // Mark the current location as a Syntax error.
             let loc = ctx.stream_mut().current_location();
-            Err(Eof(loc))
+            return Err(syntax(loc))
         },
-        None => {
-            // It's not Eof, so there was a syntax error.
-            no_match(ctx)
+    };
+
+    match ctx.stream_mut().peek().map_err(scan::Error::from) {
+        Ok(Operator(Semicolon)) | Err(Eof(_)) => {
+            let loc = ctx.stream_mut().location_from(start);
+            Ok(Located(stmt, loc))
+        },
+        Err(ScanErr(err)) => Err(err),
+        Ok(_) | Err(NoMatch(_)) => {
+            let loc = ctx.stream_mut().current_location();
+            Err(syntax(loc))
         },
     }
 }
 
+/// Returns the index right after the `;` that terminates the statement starting at `start`,
+/// or the length of the source, if there's no such `;`.
+///
+/// Like `psql`, semicolons within parenthesis, or within `BEGIN ATOMIC ... END` bodies,
+/// don't terminate the statement.
+/// Quoted and dollar-quoted strings are single tokens, so their content is never inspected,
+/// as long as they're lexed with the same `standard_conforming_strings` as the statement.
+fn statement_end(source: &str, start: u32, standard_conforming_strings: bool) -> u32 {
+
+    let lexer = Lexer::new(&source[start as usize..])
+        .with_standard_conforming_strings(standard_conforming_strings);
+
+    let mut parens = 0usize;
+    // Within a `BEGIN ATOMIC` body, `BEGIN` and `CASE` are matched by `END`.
+    let mut atomic_depth = 0usize;
+    let mut after_begin = false;
+
+    // Lexer errors always consume input, so they're simply ignored.
+    for Located(tok, loc) in lexer.flatten() {
+
+        match tok {
+            RawTokenKind::Operator(OpenParenthesis) => parens += 1,
+            RawTokenKind::Operator(CloseParenthesis) => parens = parens.saturating_sub(1),
+            RawTokenKind::Operator(Semicolon) if parens == 0 && atomic_depth == 0 => {
+                return start + loc.range().end
+            },
+            RawTokenKind::Keyword(Atomic) if after_begin && atomic_depth == 0 => atomic_depth = 1,
+            RawTokenKind::Keyword(Begin | Case) if atomic_depth > 0 => atomic_depth += 1,
+            RawTokenKind::Keyword(End) if atomic_depth > 0 => atomic_depth -= 1,
+            _ => {},
+        }
+
+        after_begin = matches!(tok, RawTokenKind::Keyword(Begin));
+    }
+
+    source.len() as u32
+}
+
 /// Returns `Ok` if it consumed at least 1 `;` (semicolon).
 fn semicolons(ctx: &mut ParserContext) -> scan::Result<()> {
 
@@ -55,6 +136,9 @@ mod tests {
     use crate::test_parser;
     #[allow(unused_imports)]
     use pg_ast::TransactionMode::ReadOnly;
+    use pg_parser_core::stream::TokenStream;
+    use pg_parser_core::ParserConfig;
+    use test_case::test_case;
     use test_case::test_matrix;
 
     // This only quickly tests that statement types aren't missing.
@@ -70,6 +154,72 @@ mod tests {
     fn test_toplevel_stmt(source: &str) -> scan::Result<RawStmt> {
         test_parser!(source, toplevel_stmt)
     }
+
+    #[test_case("", false => Vec::<bool>::new())]
+    #[test_case(" ; ;; ", false => Vec::<bool>::new())]
+    #[test_case("begin; commit", false => vec![true, true])]
+    #[test_case("begin; begin foo; commit;", false => vec![true, false, true])]
+    #[test_case("begin; begin foo; commit;", true => vec![true, false])]
+    #[test_case("begin commit; commit", false => vec![false, true])]
+    #[test_case("begin; 0x; commit", false => vec![true, false, true])]
+    #[test_case("begin (; commit); commit", false => vec![false, true])]
+    fn test_stmtmulti(source: &str, fail_fast: bool) -> Vec<bool> {
+        let mut ctx = ParserContext::from(source);
+        stmtmulti(&mut ctx, fail_fast)
+            .iter()
+            .map(Result::is_ok)
+            .collect()
+    }
+
+    #[test]
+    fn test_stmtmulti_nonstandard_strings() {
+        // The `;` within the string doesn't terminate the statement with the error.
+        let source = r"select 'a\';' +; select 2";
+        let config = ParserConfig::default()
+            .with_standard_conforming_strings(false);
+        let mut ctx = ParserContext::new(TokenStream::new(source, config));
+
+        let actual: Vec<_> = stmtmulti(&mut ctx, false)
+            .iter()
+            .map(Result::is_ok)
+            .collect();
+
+        assert_eq!(vec![false, true], actual)
+    }
+
+    #[test]
+    fn test_stmtmulti_locations() {
+        let source = " ; select  1 ;;\nbegin\n";
+        let mut ctx = ParserContext::from(source);
+
+        let actual: Vec<_> = stmtmulti(&mut ctx, false)
+            .into_iter()
+            .map(|stmt| stmt.unwrap().1.slice(source))
+            .collect();
+
+        assert_eq!(vec!["select  1", "begin"], actual)
+    }
+
+    #[test_case("select 1" => 8)]
+    #[test_case("select 1; select 2" => 9)]
+    #[test_case("select (1;2); select 3" => 13)]
+    #[test_case("select $$;$$, ';', \";\"; x" => 23)]
+    #[test_case("create function f() begin atomic select 1; end; x" => 47)]
+    #[test_case("create function f() begin atomic select case when true then 1 end; select 2; end; x" => 81)]
+    fn test_statement_end(source: &str) -> u32 {
+        statement_end(source, 0, true)
+    }
+
+    #[test_case(r"select 'a\';'; x" => 14)]
+    #[test_case(r"select 'a\'; x'; y" => 16)]
+    fn test_statement_end_nonstandard_strings(source: &str) -> u32 {
+        statement_end(source, 0, false)
+    }
+
+    #[test]
+    fn test_statement_end_with_start() {
+        assert_eq!(19, statement_end("select 1; select 2; select 3", 10, true));
+    }
 }
 
 use crate::alt;
@@ -77,10 +227,22 @@ use crate::combinators::core::Combinator;
 use crate::combinators::stmt;
 use crate::combinators::stmt::transaction_stmt_legacy;
 use crate::many;
-use crate::no_match;
-use crate::seq;
 use crate::ParserContext;
 use pg_ast::RawStmt;
+use pg_basics::Located;
+use pg_elog::LocatedResult;
+use pg_lexer::Keyword::Atomic;
+use pg_lexer::Keyword::Begin;
+use pg_lexer::Keyword::Case;
+use pg_lexer::Keyword::End;
+use pg_lexer::Lexer;
+use pg_lexer::OperatorKind::CloseParenthesis;
+use pg_lexer::OperatorKind::OpenParenthesis;
 use pg_lexer::OperatorKind::Semicolon;
+use pg_lexer::RawTokenKind;
 use pg_parser_core::scan;
 use pg_parser_core::scan::Error::Eof;
+use pg_parser_core::scan::Error::NoMatch;
+use pg_parser_core::scan::Error::ScanErr;
+use pg_parser_core::stream::TokenValue::Operator;
+use pg_parser_core::syntax;
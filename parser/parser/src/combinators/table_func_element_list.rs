@@ -10,8 +10,8 @@ pub(super) fn table_func_element_list(ctx: &mut ParserContext) -> scan::Result<V
     many!(sep = Comma, table_func_element).parse(ctx)
 }
 
-/// Alias: `TableFuncElement ( ',' TableFuncElement )*`
-fn table_func_element(ctx: &mut ParserContext) -> scan::Result<SimpleColumnDefinition> {
+/// Alias: `TableFuncElement`
+pub(super) fn table_func_element(ctx: &mut ParserContext) -> scan::Result<SimpleColumnDefinition> {
 
     /*
         col_id typename ( collate_clause )?
@@ -19,7 +19,7 @@ fn table_func_element(ctx: &mut ParserContext) -> scan::Result<SimpleColumnDefin
 
     let (name, type_name, collation) = seq!(
         col_id,
-        simple_typename,
+        typename,
         collate_clause.optional()
     ).parse(ctx)?;
 
@@ -53,7 +53,7 @@ mod tests {
 use crate::combinators::col_id;
 use crate::combinators::collate_clause;
 use crate::combinators::core::Combinator;
-use crate::combinators::simple_typename;
+use crate::combinators::typename;
 use crate::many;
 use crate::seq;
 use crate::ParserContext;
@@ -0,0 +1,251 @@
+/// Alias: `graph_pattern`
+pub(super) fn graph_pattern(ctx: &mut ParserContext) -> scan::Result<GraphPattern> {
+
+    /*
+        path_pattern ( ',' path_pattern )* ( where_clause )?
+    */
+
+    let (paths, where_clause) = seq!(
+        many!(sep = Comma, path_term),
+        where_clause.optional()
+    ).parse(ctx)?;
+
+    let mut pattern = GraphPattern::new(paths);
+    pattern.set_where_clause(where_clause);
+
+    Ok(pattern)
+}
+
+/// Aliases:
+/// * `path_pattern`
+/// * `path_pattern_expression`
+/// * `path_term`
+fn path_term(ctx: &mut ParserContext) -> scan::Result<PathPattern> {
+
+    /*
+        ( path_primary )+
+    */
+
+    many!(path_primary).parse(ctx)
+}
+
+/// Aliases:
+/// * `path_factor`
+/// * `path_primary`
+fn path_primary(ctx: &mut ParserContext) -> scan::Result<PathElement> {
+
+    /*
+          '(' path_term ( where_clause )? ')'
+        | vertex_pattern
+        | edge_pattern
+    */
+
+    alt!(
+        subpath,
+        paren!(element_pattern).map(PathElement::Vertex),
+        edge_pattern
+    ).parse(ctx)
+}
+
+fn subpath(ctx: &mut ParserContext) -> scan::Result<PathElement> {
+
+    /*
+        '(' path_term ( where_clause )? ')'
+    */
+
+    // Both vertex patterns and parenthesized paths start with `(`,
+    // but only the latter can be followed by another element.
+    let is_subpath = matches!(
+        ctx.stream_mut().peek2(),
+        Ok((Operator(OpenParenthesis), Operator(OpenParenthesis | Minus | Less | RightArrow)))
+    );
+
+    if !is_subpath {
+        return no_match(ctx)
+    }
+
+    let (path, where_clause) = paren!(seq!(
+        path_term,
+        where_clause.optional()
+    )).parse(ctx)?;
+
+    Ok(PathElement::Subpath { path, where_clause })
+}
+
+/// Aliases:
+/// * `edge_pattern`
+/// * `full_edge_pattern`
+/// * `abbreviated_edge_pattern`
+fn edge_pattern(ctx: &mut ParserContext) -> scan::Result<PathElement> {
+
+    /*
+          '-' '[' element_pattern ']' '->'
+        | '-' '[' element_pattern ']' '-'
+        | '<' '-' '[' element_pattern ']' '-'
+        | '->'
+        | '<' '-'
+        | '-'
+    */
+
+    let (direction, pattern) = alt!(
+        seq!(
+            Minus,
+            seq!(
+                brackets!(element_pattern),
+                alt!(
+                    RightArrow.map(|_| EdgeDirection::Right),
+                    Minus.map(|_| EdgeDirection::Any)
+                )
+            ).optional()
+        ).map(|(_, edge)| match edge {
+            Some((pattern, direction)) => (direction, pattern),
+            None => (EdgeDirection::Any, ElementPattern::default()),
+        }),
+        seq!(
+            Less,
+            Minus,
+            seq!(brackets!(element_pattern), Minus).optional()
+        ).map(|(.., edge)| {
+            let pattern = edge.map(|(pattern, _)| pattern).unwrap_or_default();
+            (EdgeDirection::Left, pattern)
+        }),
+        RightArrow.map(|_| (EdgeDirection::Right, ElementPattern::default()))
+    ).parse(ctx)?;
+
+    Ok(PathElement::Edge { direction, pattern })
+}
+
+/// The content of a vertex's parenthesis, or an edge's brackets.
+fn element_pattern(ctx: &mut ParserContext) -> scan::Result<ElementPattern> {
+
+    /*
+        ( ColId )? ( IS label_expression )? ( where_clause )?
+    */
+
+    let (variable, label, where_clause) = seq!(
+        col_id.optional(),
+        seq!(Is, label_expression)
+            .map(|(_, label)| label)
+            .optional(),
+        where_clause.optional()
+    ).parse(ctx)?;
+
+    let mut pattern = ElementPattern::new();
+    pattern.set_variable(variable)
+        .set_label(label)
+        .set_where_clause(where_clause);
+
+    Ok(pattern)
+}
+
+/// Alias: `label_expression`
+fn label_expression(ctx: &mut ParserContext) -> scan::Result<LabelExpression> {
+
+    /*
+        ColId ( '|' ColId )*
+    */
+
+    let mut labels = many!(sep = Pipe, col_id.map(LabelExpression::Label))
+        .parse(ctx)?;
+
+    let label = if labels.len() == 1 {
+        labels.remove(0)
+    } else {
+        LabelExpression::Disjunction(labels)
+    };
+
+    Ok(label)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::ExprKind::IntegerConst;
+    use test_case::test_case;
+
+    #[test]
+    fn test_graph_pattern() {
+        test_parser!(
+            source = "(a), (b) where 1",
+            parser = graph_pattern,
+            expected = GraphPattern::new(vec![
+                vec![PathElement::Vertex(ElementPattern::new().with_variable("a"))],
+                vec![PathElement::Vertex(ElementPattern::new().with_variable("b"))],
+            ]).with_where_clause(IntegerConst(1).into())
+        )
+    }
+
+    #[test_case("(a is person where 1)",
+        PathElement::Vertex(
+            ElementPattern::new()
+                .with_variable("a")
+                .with_label("person")
+                .with_where_clause(IntegerConst(1).into())
+        )
+    )]
+    #[test_case("()", PathElement::Vertex(ElementPattern::new()))]
+    #[test_case("((a)-(b) where 1)",
+        PathElement::Subpath {
+            path: vec![
+                PathElement::Vertex(ElementPattern::new().with_variable("a")),
+                PathElement::Edge { direction: EdgeDirection::Any, pattern: ElementPattern::new() },
+                PathElement::Vertex(ElementPattern::new().with_variable("b")),
+            ],
+            where_clause: Some(IntegerConst(1).into())
+        }
+    )]
+    #[test_case("-[e is knows | likes]->",
+        PathElement::Edge {
+            direction: EdgeDirection::Right,
+            pattern: ElementPattern::new()
+                .with_variable("e")
+                .with_label(LabelExpression::Disjunction(vec!["knows".into(), "likes".into()]))
+        }
+    )]
+    #[test_case("-[e]-",
+        PathElement::Edge {
+            direction: EdgeDirection::Any,
+            pattern: ElementPattern::new().with_variable("e")
+        }
+    )]
+    #[test_case("<-[e]-",
+        PathElement::Edge {
+            direction: EdgeDirection::Left,
+            pattern: ElementPattern::new().with_variable("e")
+        }
+    )]
+    #[test_case("<-", PathElement::Edge { direction: EdgeDirection::Left, pattern: ElementPattern::new() })]
+    #[test_case("->", PathElement::Edge { direction: EdgeDirection::Right, pattern: ElementPattern::new() })]
+    #[test_case("-", PathElement::Edge { direction: EdgeDirection::Any, pattern: ElementPattern::new() })]
+    fn test_path_primary(source: &str, expected: PathElement) {
+        test_parser!(source, path_primary, expected)
+    }
+}
+
+use crate::alt;
+use crate::brackets;
+use crate::combinators::col_id;
+use crate::combinators::core::Combinator;
+use crate::combinators::where_clause;
+use crate::context::ParserContext;
+use crate::many;
+use crate::no_match;
+use crate::paren;
+use crate::seq;
+use pg_ast::EdgeDirection;
+use pg_ast::ElementPattern;
+use pg_ast::GraphPattern;
+use pg_ast::LabelExpression;
+use pg_ast::PathElement;
+use pg_ast::PathPattern;
+use pg_lexer::Keyword::Is;
+use pg_lexer::OperatorKind::Comma;
+use pg_lexer::OperatorKind::Less;
+use pg_lexer::OperatorKind::Minus;
+use pg_lexer::OperatorKind::OpenParenthesis;
+use pg_lexer::OperatorKind::Pipe;
+use pg_lexer::OperatorKind::RightArrow;
+use pg_parser_core::scan;
+use pg_parser_core::stream::TokenValue::Operator;
@@ -0,0 +1,77 @@
+/// Alias: `RangeGraphTable`
+pub(super) fn graph_table(ctx: &mut ParserContext) -> scan::Result<GraphTable> {
+
+    /*
+        GRAPH_TABLE '('
+            qualified_name
+            MATCH graph_pattern
+            COLUMNS '(' labeled_expr_list ')'
+        ')' ( opt_alias_clause )?
+    */
+
+    let (_, (graph, _, pattern, _, columns), alias) = seq!(
+        Kw::GraphTable,
+        paren!(seq!(
+            qualified_name,
+            Match,
+            graph_pattern,
+            Columns,
+            paren!(labeled_expr_list)
+        )),
+        alias_clause.optional()
+    ).parse(ctx)?;
+
+    let mut table_ref = GraphTable::new(graph, pattern, columns);
+    table_ref.set_alias(alias);
+
+    Ok(table_ref)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        EdgeDirection,
+        ElementPattern,
+        ExprKind::IntegerConst,
+        GraphPattern,
+        NamedValue,
+        PathElement,
+    };
+
+    #[test]
+    fn test_graph_table() {
+        test_parser!(
+            source = "graph_table (g match (a)->(b) columns (1 as one)) as t",
+            parser = graph_table,
+            expected = GraphTable::new(
+                "g",
+                GraphPattern::new(vec![vec![
+                    PathElement::Vertex(ElementPattern::new().with_variable("a")),
+                    PathElement::Edge {
+                        direction: EdgeDirection::Right,
+                        pattern: ElementPattern::new()
+                    },
+                    PathElement::Vertex(ElementPattern::new().with_variable("b")),
+                ]]),
+                vec![NamedValue::new(Some("one".into()), IntegerConst(1).into())]
+            ).with_alias("t")
+        )
+    }
+}
+
+use crate::combinators::core::Combinator;
+use crate::combinators::labeled_expr_list;
+use crate::combinators::qualified_name;
+use crate::combinators::table_ref::alias_clause;
+use crate::combinators::table_ref::graph_pattern;
+use crate::context::ParserContext;
+use crate::paren;
+use crate::seq;
+use pg_ast::GraphTable;
+use pg_lexer::Keyword as Kw;
+use pg_lexer::Keyword::Columns;
+use pg_lexer::Keyword::Match;
+use pg_parser_core::scan;
@@ -0,0 +1,242 @@
+enum JoinKind {
+    Cross,
+    Natural(JoinType),
+    Qualified(JoinType),
+}
+
+/// Alias: `joined_table`
+///
+/// Parses all the joins to the right of `left`.
+///
+/// Joins are left-associative, except when a qualified join is still missing its `join_qual`,
+/// in which case the following joins nest to the right, e.g.: `a JOIN b JOIN c ON x ON y`.
+pub(super) fn joined_table(mut left: TableRef, ctx: &mut ParserContext) -> scan::Result<TableRef> {
+
+    /*
+        (
+              CROSS JOIN table_ref
+            | NATURAL ( join_type )? JOIN table_ref
+            | ( join_type )? JOIN table_ref join_qual
+        )*
+    */
+
+    while let Some((kind, right)) = seq!(join_kind, table_ref_1).parse(ctx).optional()? {
+
+        let join = match kind {
+            JoinKind::Cross => JoinTableRef::new(JoinType::Inner, left, right, JoinCondition::Cross),
+            JoinKind::Natural(join_type) => JoinTableRef::new(join_type, left, right, JoinCondition::Natural),
+            JoinKind::Qualified(join_type) => {
+                let right = joined_table(right, ctx)?;
+                let condition = join_qual(ctx).required()?;
+                JoinTableRef::new(join_type, left, right, condition)
+            },
+        };
+
+        left = join.into();
+    }
+
+    Ok(left)
+}
+
+/// Alias: `'(' joined_table ')' alias_clause`
+/// Validates the contents of `'(' joined_table ')'`, and parses its optional `alias_clause`.
+pub(super) fn parenthesized_join(table_ref: TableRef, loc: Location, ctx: &mut ParserContext) -> scan::Result<TableRef> {
+
+    /*
+        '(' joined_table ')' ( alias_clause )?
+    */
+
+    // Only joins can be parenthesized, and an alias is only allowed outside the outermost parenthesis.
+    let TableRef::Join(mut join) = table_ref else {
+        return Err(syntax(loc))
+    };
+
+    if join.alias().is_some() {
+        return Err(syntax(loc))
+    }
+
+    let alias = alias_clause.optional().parse(ctx)?;
+    join.set_alias(alias);
+    Ok(join.into())
+}
+
+fn join_kind(ctx: &mut ParserContext) -> scan::Result<JoinKind> {
+
+    /*
+          CROSS JOIN
+        | NATURAL ( join_type )? JOIN
+        | ( join_type )? JOIN
+    */
+
+    alt!(
+        seq!(Cross, Join)
+            .map(|_| JoinKind::Cross),
+        seq!(Natural, join_type.optional(), Join)
+            .map(|(_, join_type, _)| JoinKind::Natural(join_type.unwrap_or_default())),
+        seq!(join_type.optional(), Join)
+            .map(|(join_type, _)| JoinKind::Qualified(join_type.unwrap_or_default()))
+    ).parse(ctx)
+}
+
+/// Alias: `join_type`
+fn join_type(ctx: &mut ParserContext) -> scan::Result<JoinType> {
+
+    /*
+          FULL ( OUTER )?
+        | LEFT ( OUTER )?
+        | RIGHT ( OUTER )?
+        | INNER
+    */
+
+    alt!(
+        seq!(
+            alt!(
+                Full.map(|_| JoinType::Full),
+                Left.map(|_| JoinType::Left),
+                Kw::Right.map(|_| JoinType::Right)
+            ),
+            Outer.optional()
+        ).map(|(join_type, _)| join_type),
+        Inner.map(|_| JoinType::Inner)
+    ).parse(ctx)
+}
+
+/// Alias: `join_qual`
+fn join_qual(ctx: &mut ParserContext) -> scan::Result<JoinCondition> {
+
+    /*
+          USING '(' name_list ')' ( AS ColId )?
+        | ON a_expr
+    */
+
+    alt!(
+        seq!(
+            Using,
+            paren!(name_list),
+            seq!(As, col_id).optional()
+        ).map(|(_, columns, alias)|
+            JoinCondition::Using {
+                columns,
+                alias: alias.map(|(_, alias)| alias)
+            }
+        ),
+        seq!(On, a_expr)
+            .map(|(_, expr)| JoinCondition::On(expr))
+    ).parse(ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combinators::table_ref::table_ref;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        Alias,
+        ExprKind::IntegerConst,
+        RelationTableRef,
+    };
+    use pg_basics::Located;
+    use pg_elog::Error::Parser;
+    use pg_elog::parser::Error::Syntax;
+    use scan::Error::ScanErr;
+    use test_case::test_case;
+
+    fn rel(name: &'static str) -> RelationTableRef {
+        RelationTableRef::new(name)
+    }
+
+    #[test_case("a cross join b",
+        JoinTableRef::new(JoinType::Inner, rel("a"), rel("b"), JoinCondition::Cross).into()
+    )]
+    #[test_case("a natural left outer join b",
+        JoinTableRef::new(JoinType::Left, rel("a"), rel("b"), JoinCondition::Natural).into()
+    )]
+    #[test_case("a natural join b",
+        JoinTableRef::new(JoinType::Inner, rel("a"), rel("b"), JoinCondition::Natural).into()
+    )]
+    #[test_case("a join b on 1",
+        JoinTableRef::new(JoinType::Inner, rel("a"), rel("b"), JoinCondition::On(IntegerConst(1).into())).into()
+    )]
+    #[test_case("a full join b using (x, y) as j",
+        JoinTableRef::new(
+            JoinType::Full,
+            rel("a"),
+            rel("b"),
+            JoinCondition::Using {
+                columns: vec!["x".into(), "y".into()],
+                alias: Some("j".into())
+            }
+        ).into()
+    )]
+    #[test_case("a right join b on 1 inner join c on 2",
+        JoinTableRef::new(
+            JoinType::Inner,
+            JoinTableRef::new(JoinType::Right, rel("a"), rel("b"), JoinCondition::On(IntegerConst(1).into())),
+            rel("c"),
+            JoinCondition::On(IntegerConst(2).into())
+        ).into()
+    )]
+    #[test_case("a join b join c on 1 on 2",
+        JoinTableRef::new(
+            JoinType::Inner,
+            rel("a"),
+            JoinTableRef::new(JoinType::Inner, rel("b"), rel("c"), JoinCondition::On(IntegerConst(1).into())),
+            JoinCondition::On(IntegerConst(2).into())
+        ).into()
+    )]
+    #[test_case("(a cross join b) as j cross join c",
+        JoinTableRef::new(
+            JoinType::Inner,
+            JoinTableRef::new(JoinType::Inner, rel("a"), rel("b"), JoinCondition::Cross)
+                .with_alias(Alias::new("j")),
+            rel("c"),
+            JoinCondition::Cross
+        ).into()
+    )]
+    #[test_case("a",
+        rel("a").into()
+    )]
+    fn test_table_ref(source: &str, expected: TableRef) {
+        test_parser!(source, table_ref, expected)
+    }
+
+    #[test_case("a join b")]
+    #[test_case("(a)")]
+    #[test_case("((a cross join b) as j)")]
+    fn test_invalid_join(source: &str) {
+        let actual = test_parser!(source, table_ref);
+        assert_matches!(actual, Err(ScanErr(Located(Parser(Syntax), _))));
+    }
+}
+
+use super::alias_clause;
+use super::table_ref_1;
+use crate::alt;
+use crate::combinators::col_id;
+use crate::combinators::core::Combinator;
+use crate::combinators::expr::a_expr;
+use crate::combinators::name_list;
+use crate::context::ParserContext;
+use crate::paren;
+use crate::seq;
+use pg_ast::JoinCondition;
+use pg_ast::JoinTableRef;
+use pg_ast::JoinType;
+use pg_ast::TableRef;
+use pg_basics::Location;
+use pg_lexer::Keyword as Kw;
+use pg_lexer::Keyword::As;
+use pg_lexer::Keyword::Cross;
+use pg_lexer::Keyword::Full;
+use pg_lexer::Keyword::Inner;
+use pg_lexer::Keyword::Join;
+use pg_lexer::Keyword::Left;
+use pg_lexer::Keyword::Natural;
+use pg_lexer::Keyword::On;
+use pg_lexer::Keyword::Outer;
+use pg_lexer::Keyword::Using;
+use pg_parser_core::scan;
+use pg_parser_core::syntax;
+use pg_parser_core::Optional;
+use pg_parser_core::Required;
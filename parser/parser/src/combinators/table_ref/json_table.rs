@@ -45,7 +45,7 @@ fn path_spec(ctx: &mut ParserContext) -> scan::Result<JsonTablePathSpec> {
         alias.optional()
     ).parse(ctx)?;
 
-    let StringConst(path) = path else {
+    let StringConst(path) = path.into_kind() else {
         return Err(NonStringJsonTablePathSpec.at_location(path_loc).into());
     };
 
@@ -289,7 +289,7 @@ mod tests {
         => Ok(
             JsonTable::new(
                 JsonValueExpr::new(
-                    StringConst("umpus".into()),
+                    StringConst("umpus".into()).into(),
                     JsonFormat::text()
                 ),
                 JsonTablePathSpec::new("wawas")
@@ -424,7 +424,7 @@ use crate::paren;
 use crate::seq;
 use crate::ParserContext;
 use derive_more::From;
-use pg_ast::ExprNode::StringConst;
+use pg_ast::ExprKind::StringConst;
 use pg_ast::JsonBehavior;
 use pg_ast::JsonBehaviorClause;
 use pg_ast::JsonFormat;
@@ -8,10 +8,10 @@ pub(super) fn lateral_func_table(ctx: &mut ParserContext) -> scan::Result<TableR
         | function_stmt
     */
 
-    let table_ref = match ctx.stream_mut().peek2()? {
-        (Keyword(Xmltable), Operator(OpenParenthesis)) => xmltable(ctx)?.into(),
-        (Keyword(Kw::JsonTable), Operator(OpenParenthesis)) => json_table(ctx)?.into(),
-        (Keyword(Rows), Keyword(FromKw)) => rows_from_stmt(ctx)?.into(),
+    let table_ref = match ctx.stream_mut().peek2() {
+        Ok((Keyword(Xmltable), Operator(OpenParenthesis))) => xmltable(ctx)?.into(),
+        Ok((Keyword(Kw::JsonTable), Operator(OpenParenthesis))) => json_table(ctx)?.into(),
+        Ok((Keyword(Rows), Keyword(FromKw))) => rows_from_stmt(ctx)?.into(),
 
         // The 1st keyword of the previous productions conflict with function_stmt,
         // so the 2nd token needed to be checked to disambiguate.
@@ -61,6 +61,30 @@ fn function_stmt(ctx: &mut ParserContext) -> scan::Result<FunctionTableRef> {
     Ok(table_ref)
 }
 
+/// Parses the rest of a `function_stmt`, after the qualified name of the function was already consumed.
+pub(super) fn func_application_table(name: Located<QualifiedName>, ctx: &mut ParserContext) -> scan::Result<FunctionTableRef> {
+
+    /*
+        func_application_args ( ordinality )? ( func_alias_clause )?
+    */
+
+    let Located(name, start) = name;
+    let args = func_application_args(ctx)?;
+    let span = ctx.stream_mut().location_from(start).into();
+
+    let (ordinality, alias) = seq!(
+        ordinality.optional(),
+        func_alias_clause.optional()
+    ).parse(ctx)?;
+
+    let function = FuncCall::new(name, args).with_span(span);
+    let mut table_ref = FunctionTableRef::new(function)
+        .with_ordinality(ordinality.is_some());
+
+    table_ref.set_alias(alias);
+    Ok(table_ref)
+}
+
 /// Alias: `opt_ordinality`
 fn ordinality(ctx: &mut ParserContext) -> scan::Result<()> {
 
@@ -79,10 +103,9 @@ mod tests {
     use crate::test_parser;
     #[allow(unused_imports)]
     use pg_ast::{
-        ExprNode::StringConst,
+        ExprKind::StringConst,
         FuncAliasColumn,
         FuncArgsKind,
-        FuncCall,
         FuncExprWindowless,
         JsonTable,
         JsonTableColumnDefinition,
@@ -188,8 +211,8 @@ mod tests {
 
     #[test_case("xmltable('foo' passing 'bar' columns qux int)" => Ok(
         XmlTable::new(
-            StringConst("bar".into()),
-            StringConst("foo".into()),
+            StringConst("bar".into()).into(),
+            StringConst("foo".into()).into(),
             vec![XmlTableColumn::new("qux",
                 XmlTableColumnDefinition::from(Int4)
             )]
@@ -212,6 +235,7 @@ mod tests {
 }
 
 use crate::combinators::core::Combinator;
+use crate::combinators::func_application_args;
 use crate::combinators::func_expr_windowless;
 use crate::combinators::table_ref::func_alias_clause;
 use crate::combinators::table_ref::json_table;
@@ -220,9 +244,12 @@ use crate::combinators::table_ref::xmltable;
 use crate::paren;
 use crate::seq;
 use crate::ParserContext;
+use pg_ast::FuncCall;
 use pg_ast::FunctionTableRef;
 use pg_ast::RowsTableRef;
 use pg_ast::TableRef;
+use pg_basics::Located;
+use pg_basics::QualifiedName;
 use pg_lexer::Keyword as Kw;
 use pg_lexer::Keyword::FromKw;
 use pg_lexer::Keyword::Ordinality;
@@ -1,6 +1,9 @@
 pg_basics::reexport! {
     alias_clause,
     func_alias_clause,
+    graph_pattern,
+    graph_table,
+    joined_table,
     json_table,
     lateral_func_table,
     rowsfrom_list,
@@ -9,16 +12,31 @@ pg_basics::reexport! {
     xmltable,
 }
 
+/// Alias: `table_ref`
+pub(super) fn table_ref(ctx: &mut ParserContext) -> scan::Result<TableRef> {
+
+    /*
+        table_ref_1 ( joined_table )?
+    */
+
+    let left = table_ref_1(ctx)?;
+    joined_table(left, ctx)
+}
+
 fn table_ref_1(ctx: &mut ParserContext) -> scan::Result<TableRef> {
 
     /*
-          LATERAL select_table_ref
+          GRAPH_TABLE '(' ... ')' ( opt_alias_clause )?
+        | LATERAL select_table_ref
         | LATERAL lateral_func_table
         | lateral_func_table
         | tablesample_table_ref
+        | '(' joined_table ')' ( alias_clause )?
+        | select_with_parens ( alias_clause )?
     */
 
     alt!(
+        graph_table_ref,
         seq!(
             Lateral,
             alt!(
@@ -33,24 +51,78 @@ fn table_ref_1(ctx: &mut ParserContext) -> scan::Result<TableRef> {
             TableRef::Subselect(table_ref) => table_ref.with_lateral(true).into(),
             _ => unreachable!(),
         }),
-        lateral_func_table,
-        tablesample_table_ref.map(From::from),
+        parenthesized_table_ref,
+        func_or_relation_table,
     ).parse(ctx)
 }
 
+/// Inlined:
+/// * `func_table`
+/// * `relation_expr opt_alias_clause ( tablesample_clause )?`
+fn func_or_relation_table(ctx: &mut ParserContext) -> scan::Result<TableRef> {
+
+    /*
+          lateral_func_table
+        | tablesample_table_ref
+    */
+
+    // Both productions can start with a qualified name, and it's only the token after it
+    // that decides which one it is, so unless the first 2 tokens are enough, the name is parsed once.
+    match ctx.stream_mut().peek() {
+        Ok(K(Only)) => return tablesample_table_ref(ctx),
+        Ok(K(kw)) if matches!(kw.category(), Reserved | TypeFuncName) => {
+            // Can't be a relation name, e.g.: `CURRENT_DATE`.
+            return lateral_func_table(ctx)
+        },
+        _ => {}
+    }
+
+    if matches!(
+        ctx.stream_mut().peek2(),
+        Ok((_, Operator(OpenParenthesis)) | (K(Rows), K(FromKw)))
+    ) {
+        return lateral_func_table(ctx)
+    }
+
+    let name = spanned!(any_name).parse(ctx)?;
+
+    if matches!(ctx.stream_mut().peek(), Ok(Operator(OpenParenthesis))) {
+        let table_ref = func_application_table(name, ctx)?;
+        return Ok(table_ref.into())
+    }
+
+    let name = make_relation_name(name)?;
+    Mul.optional().parse(ctx)?;
+
+    relation_table_ref(RelationExpr::new(name), ctx)
+}
+
+fn graph_table_ref(ctx: &mut ParserContext) -> scan::Result<TableRef> {
+
+    // `GRAPH_TABLE` is a column name keyword, so it can also be a relation name.
+    if ! matches!(ctx.stream_mut().peek2(), Ok((K(GraphTable), Operator(OpenParenthesis)))) {
+        return no_match(ctx)
+    }
+
+    graph_table.map(From::from).parse(ctx)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::test_parser;
     use test_case::test_case;
 
-    #[ignore]
-    #[test_case("(select 1)" => matches Ok(_))]
-    fn test_table_ref_1_subselect_table_ref(source: &str) -> scan::Result<TableRef> {
-        // TODO: merge with test_table_ref_1, when subselect_stmt is working
-        test_parser!(source, table_ref_1)
-    }
-
+    #[test_case("graph_table (g match (a) columns (a.id))" => matches Ok(TableRef::GraphTable(_)))]
+    #[test_case("graph_table" => matches Ok(TableRef::Relation(_)))]
+    #[test_case("bar tablesample bernoulli (1)" => matches Ok(TableRef::TableSample(_)))]
+    #[test_case("(bar cross join baz)" => matches Ok(TableRef::Join(_)))]
+    #[test_case("(select 1)" => matches Ok(TableRef::Subselect(_)))]
+    #[test_case("lateral (select 1) as a" => matches Ok(TableRef::Subselect(_)))]
+    #[test_case("only bar" => matches Ok(TableRef::Relation(_)))]
+    #[test_case("foo.bar * as baz" => matches Ok(TableRef::Relation(_)))]
+    #[test_case("foo.bar(1) with ordinality" => matches Ok(TableRef::Function(_)))]
+    #[test_case("current_date" => matches Ok(TableRef::Function(_)))]
     #[test_case("lateral foo()" => matches Ok(_))]
     #[test_case("foo()" => matches Ok(_))]
     #[test_case("bar" => matches Ok(_))]
@@ -60,9 +132,24 @@ mod tests {
 }
 
 use crate::alt;
+use crate::combinators::any_name;
 use crate::combinators::core::Combinator;
+use crate::combinators::make_relation_name;
 use crate::context::ParserContext;
+use crate::no_match;
 use crate::seq;
+use crate::spanned;
+use pg_ast::RelationExpr;
 use pg_ast::TableRef;
+use pg_lexer::Keyword::FromKw;
+use pg_lexer::Keyword::GraphTable;
 use pg_lexer::Keyword::Lateral;
+use pg_lexer::Keyword::Only;
+use pg_lexer::Keyword::Rows;
+use pg_lexer::KeywordCategory::Reserved;
+use pg_lexer::KeywordCategory::TypeFuncName;
+use pg_lexer::OperatorKind::Mul;
+use pg_lexer::OperatorKind::OpenParenthesis;
 use pg_parser_core::scan;
+use pg_parser_core::stream::TokenValue::Keyword as K;
+use pg_parser_core::stream::TokenValue::Operator;
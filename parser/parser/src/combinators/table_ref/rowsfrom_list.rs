@@ -43,7 +43,7 @@ mod tests {
     use test_case::test_case;
     #[allow(unused_imports)]
     use {
-        pg_ast::ExprNode::IntegerConst,
+        pg_ast::ExprKind::IntegerConst,
         pg_ast::FuncArgsKind,
         pg_ast::FuncCall,
         pg_ast::NamedValue,
@@ -66,7 +66,7 @@ mod tests {
                 FuncArgsKind::All {
                     args: vec![
                         Located(
-                            NamedValue::unnamed(IntegerConst(1)),
+                            NamedValue::unnamed(IntegerConst(1).into()),
                             Location::new(4..5, 1, 5)
                         )
                     ],
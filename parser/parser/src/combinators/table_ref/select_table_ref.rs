@@ -1,13 +1,85 @@
+/// Alias: `select_with_parens opt_alias_clause`
 pub(super) fn select_table_ref(ctx: &mut ParserContext) -> scan::Result<SubselectTableRef> {
 
     /*
         '(' SelectStmt ')' ( alias_clause )?
     */
 
-    let (subselect, alias) = seq!(
-        paren!(select_stmt),
-        alias_clause.optional()
-    ).parse(ctx)?;
+    let Located(subselect, loc) = located!(paren!(select_stmt)).parse(ctx)?;
+    subselect_table_ref(subselect, loc, ctx)
+}
+
+/// Inlined:
+/// * `select_with_parens opt_alias_clause`
+/// * `'(' joined_table ')' ( alias_clause )?`
+///
+/// Both start with an arbitrary number of `'('`,
+/// so the contents of the parenthesis decide which production it is.
+pub(super) fn parenthesized_table_ref(ctx: &mut ParserContext) -> scan::Result<TableRef> {
+
+    let Located(content, loc) = located!(paren!(parenthesized_content)).parse(ctx)?;
+
+    match content {
+        Parenthesized::Select(subselect) => {
+            let table_ref = subselect_table_ref(subselect, loc, ctx)?;
+            Ok(table_ref.into())
+        },
+        Parenthesized::Table(table_ref) => parenthesized_join(table_ref, loc, ctx),
+    }
+}
+
+enum Parenthesized {
+    Select(SelectStmt),
+    Table(TableRef),
+}
+
+fn parenthesized_content(ctx: &mut ParserContext) -> scan::Result<Parenthesized> {
+    ctx.nested(parenthesized_content_inner)
+}
+
+fn parenthesized_content_inner(ctx: &mut ParserContext) -> scan::Result<Parenthesized> {
+
+    if select_stmt_starts(ctx) {
+        let subselect = select_stmt(ctx)?;
+        return Ok(Parenthesized::Select(subselect))
+    }
+
+    match ctx.stream_mut().peek() {
+        Ok(Operator(OpenParenthesis)) => {},
+        _ => {
+            let table_ref = table_ref(ctx)?;
+            return Ok(Parenthesized::Table(table_ref))
+        },
+    }
+
+    // Nested parenthesis, e.g.: `((SELECT 1) UNION SELECT 2)`, `((SELECT 1) AS a JOIN b ON true)`.
+    let Located(content, loc) = located!(paren!(parenthesized_content)).parse(ctx)?;
+
+    let left = match content {
+        Parenthesized::Select(subselect) => {
+            match ctx.stream_mut().peek() {
+                Ok(Operator(CloseParenthesis)) => return Ok(Parenthesized::Select(subselect)),
+                Ok(K(Union | Intersect | Except | Order | Limit | Offset)) => {
+                    let subselect = select_stmt_tail(subselect, ctx)?;
+                    return Ok(Parenthesized::Select(subselect))
+                },
+                _ => subselect_table_ref(subselect, loc, ctx)?.into(),
+            }
+        },
+        Parenthesized::Table(table_ref) => parenthesized_join(table_ref, loc, ctx)?,
+    };
+
+    let table_ref = joined_table(left, ctx)?;
+    Ok(Parenthesized::Table(table_ref))
+}
+
+fn subselect_table_ref(subselect: SelectStmt, loc: Location, ctx: &mut ParserContext) -> scan::Result<SubselectTableRef> {
+
+    let alias = alias_clause.optional().parse(ctx)?;
+
+    if alias.is_none() && matches!(subselect.body(), SelectBody::Values(_)) {
+        return Err(ValuesWithoutAlias.at_location(loc).into())
+    }
 
     let mut table_ref = SubselectTableRef::new(subselect);
     table_ref.set_alias(alias);
@@ -18,20 +90,81 @@ pub(super) fn select_table_ref(ctx: &mut ParserContext) -> scan::Result<Subselec
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        Alias,
+        ExprKind::IntegerConst,
+        SimpleSelect,
+    };
+    use pg_elog::Error::Parser;
+    use scan::Error::ScanErr;
     use test_case::test_case;
 
-    #[ignore]
-    #[test_case("select 1" => matches Ok(_))]
-    fn test_select_table_ref(source: &str) -> scan::Result<SubselectTableRef> {
-        todo!()
+    #[test_case("(select 1)",
+        SubselectTableRef::new(SimpleSelect::new(vec![IntegerConst(1).into()]).into())
+    )]
+    #[test_case("(values (1), (2)) as v (a)",
+        SubselectTableRef::new(SelectStmt::new(SelectBody::Values(vec![
+            vec![IntegerConst(1).into()],
+            vec![IntegerConst(2).into()],
+        ])))
+        .with_alias(Alias::new("v").with_columns(vec!["a".into()]))
+    )]
+    fn test_select_table_ref(source: &str, expected: SubselectTableRef) {
+        test_parser!(source, select_table_ref, expected)
+    }
+
+    #[test_case("(select 1) a" => matches Ok(TableRef::Subselect(_)))]
+    #[test_case("((select 1)) a" => matches Ok(TableRef::Subselect(_)))]
+    #[test_case("((select 1) union select 2)" => matches Ok(TableRef::Subselect(_)))]
+    #[test_case("((select 1) order by 1 limit 1)" => matches Ok(TableRef::Subselect(_)))]
+    #[test_case("(table foo)" => matches Ok(TableRef::Subselect(_)))]
+    #[test_case("((select 1) a cross join b)" => matches Ok(TableRef::Join(_)))]
+    #[test_case("((a cross join b) cross join c) d" => matches Ok(TableRef::Join(_)))]
+    #[test_case("(a natural join (values (1)) v)" => matches Ok(TableRef::Join(_)))]
+    fn test_parenthesized_table_ref(source: &str) -> scan::Result<TableRef> {
+        test_parser!(source, parenthesized_table_ref)
+    }
+
+    #[test_case("(values (1))" => matches Err(ScanErr(
+        Located(Parser(ValuesWithoutAlias), _)
+    )))]
+    #[test_case("((values (1)) cross join a)" => matches Err(ScanErr(
+        Located(Parser(ValuesWithoutAlias), _)
+    )))]
+    fn test_values_without_alias(source: &str) -> scan::Result<TableRef> {
+        test_parser!(source, parenthesized_table_ref)
     }
 }
 
 use crate::combinators::core::Combinator;
 use crate::combinators::stmt::select_stmt;
+use crate::combinators::stmt::select_stmt_starts;
+use crate::combinators::stmt::select_stmt_tail;
 use crate::combinators::table_ref::alias_clause;
+use crate::combinators::table_ref::joined_table;
+use crate::combinators::table_ref::parenthesized_join;
+use crate::combinators::table_ref::table_ref;
 use crate::context::ParserContext;
+use crate::located;
 use crate::paren;
-use crate::seq;
+use pg_ast::SelectBody;
+use pg_ast::SelectStmt;
 use pg_ast::SubselectTableRef;
+use pg_ast::TableRef;
+use pg_basics::IntoLocated;
+use pg_basics::Located;
+use pg_basics::Location;
+use pg_elog::parser::Error::ValuesWithoutAlias;
+use pg_lexer::Keyword::Except;
+use pg_lexer::Keyword::Intersect;
+use pg_lexer::Keyword::Limit;
+use pg_lexer::Keyword::Offset;
+use pg_lexer::Keyword::Order;
+use pg_lexer::Keyword::Union;
+use pg_lexer::OperatorKind::CloseParenthesis;
+use pg_lexer::OperatorKind::OpenParenthesis;
 use pg_parser_core::scan;
+use pg_parser_core::stream::TokenValue::Keyword as K;
+use pg_parser_core::stream::TokenValue::Operator;
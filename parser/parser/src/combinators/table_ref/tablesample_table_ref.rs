@@ -1,20 +1,37 @@
-pub(super) fn tablesample_table_ref(ctx: &mut ParserContext) -> scan::Result<SampleTableRef> {
+/// Inlined: `relation_expr opt_alias_clause`
+pub(super) fn tablesample_table_ref(ctx: &mut ParserContext) -> scan::Result<TableRef> {
 
     /*
         relation_expr ( alias_clause )? ( tablesample_clause )?
     */
 
-    let (relation, alias, tablesample) = seq!(
-        relation_expr,
+    let relation = relation_expr(ctx)?;
+    relation_table_ref(relation, ctx)
+}
+
+/// Parses the rest of a `tablesample_table_ref`, after its `relation_expr` was already consumed.
+pub(super) fn relation_table_ref(relation: RelationExpr, ctx: &mut ParserContext) -> scan::Result<TableRef> {
+
+    /*
+        ( alias_clause )? ( tablesample_clause )?
+    */
+
+    let (alias, tablesample) = seq!(
         alias_clause.optional(),
         tablesample_clause.optional()
     ).parse(ctx)?;
 
+    let Some(tablesample) = tablesample else {
+        let mut table_ref = RelationTableRef::new(relation);
+        table_ref.set_alias(alias);
+        return Ok(table_ref.into())
+    };
+
     let mut table_ref = SampleTableRef::new(relation);
     table_ref.set_alias(alias)
-        .set_table_sample(tablesample);
+        .set_table_sample(Some(tablesample));
 
-    Ok(table_ref)
+    Ok(table_ref.into())
 }
 
 fn tablesample_clause(ctx: &mut ParserContext) -> scan::Result<TableSample> {
@@ -53,10 +70,8 @@ mod tests {
     use super::*;
     use crate::test_parser;
     #[allow(unused_imports)]
-    use pg_ast::{
-        ExprNode::IntegerConst,
-        RelationExpr
-    };
+    use pg_ast::ExprKind;
+    use pg_ast::ExprKind::IntegerConst;
     use test_case::test_case;
 
     #[test_case("foo as t tablesample f(1)" => Ok(
@@ -65,45 +80,52 @@ mod tests {
             .with_table_sample(
                 TableSample::new(
                     vec!["f".into()],
-                    vec![IntegerConst(1)],
+                    vec![IntegerConst(1).into()],
                 )
             )
+            .into()
     ))]
     #[test_case("bar as s" => Ok(
-        SampleTableRef::new("bar")
+        RelationTableRef::new("bar")
             .with_alias("s")
+            .into()
+    ))]
+    #[test_case("only qux" => Ok(
+        RelationTableRef::new(RelationExpr::new("qux").with_inherited(false))
+            .into()
     ))]
     #[test_case("baz tablesample g(2)" => Ok(
         SampleTableRef::new("baz")
             .with_table_sample(
                 TableSample::new(
                     vec!["g".into()],
-                    vec![IntegerConst(2)],
+                    vec![IntegerConst(2).into()],
                 )
             )
+            .into()
     ))]
-    fn test_tablesample_table_ref(source: &str) -> scan::Result<SampleTableRef> {
+    fn test_tablesample_table_ref(source: &str) -> scan::Result<TableRef> {
         test_parser!(source, tablesample_table_ref)
     }
 
     #[test_case("tablesample foo(1) repeatable (10)" => Ok(
         TableSample::new(
             vec!["foo".into()],
-            vec![IntegerConst(1)],
+            vec![IntegerConst(1).into()],
         )
-        .with_repeatable(IntegerConst(10))
+        .with_repeatable(IntegerConst(10).into())
     ))]
     #[test_case("tablesample bar(2)" => Ok(
         TableSample::new(
             vec!["bar".into()],
-            vec![IntegerConst(2)],
+            vec![IntegerConst(2).into()],
         )
     ))]
     fn test_tablesample_clause(source: &str) -> scan::Result<TableSample> {
         test_parser!(source, tablesample_clause)
     }
 
-    #[test_case("repeatable (1)" => Ok(ExprNode::IntegerConst(1)))]
+    #[test_case("repeatable (1)" => Ok(ExprKind::IntegerConst(1).into()))]
     fn test_repeatable_clause(source: &str) -> scan::Result<ExprNode> {
         test_parser!(source, repeatable_clause)
     }
@@ -119,7 +141,10 @@ use crate::context::ParserContext;
 use crate::paren;
 use crate::seq;
 use pg_ast::ExprNode;
+use pg_ast::RelationExpr;
+use pg_ast::RelationTableRef;
 use pg_ast::SampleTableRef;
+use pg_ast::TableRef;
 use pg_ast::TableSample;
 use pg_lexer::Keyword::Repeatable;
 use pg_lexer::Keyword::Tablesample;
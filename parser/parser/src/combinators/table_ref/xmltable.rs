@@ -189,7 +189,7 @@ mod tests {
     #[allow(unused_imports)]
     use {
         pg_ast::Alias,
-        pg_ast::ExprNode::{IntegerConst, StringConst},
+        pg_ast::ExprKind::{IntegerConst, StringConst},
         pg_ast::TypeName::Int4,
         pg_elog::Error::Parser,
         scan::Error::ScanErr,
@@ -205,8 +205,8 @@ mod tests {
         ) as x(y, z)"
         => Ok(
             XmlTable::new(
-                StringConst("doc".into()),
-                StringConst("path".into()),
+                StringConst("doc".into()).into(),
+                StringConst("path".into()).into(),
                 vec![
                     XmlTableColumn::new("a", ForOrdinality),
                     XmlTableColumn::new("b",
@@ -230,8 +230,8 @@ mod tests {
         )"
         => Ok(
             XmlTable::new(
-                StringConst("doc".into()),
-                StringConst("path".into()),
+                StringConst("doc".into()).into(),
+                StringConst("path".into()).into(),
                 vec![
                     XmlTableColumn::new("a",
                         XmlTableColumnDefinition::from(Int4)
@@ -240,8 +240,8 @@ mod tests {
                 ]
             )
             .with_namespaces(vec![
-                NamedValue::unnamed(StringConst("foo".into())),
-                NamedValue::new(Some("x".into()), StringConst("bar".into())),
+                NamedValue::unnamed(StringConst("foo".into()).into()),
+                NamedValue::new(Some("x".into()), StringConst("bar".into()).into()),
             ])
         )
     )]
@@ -263,7 +263,7 @@ mod tests {
             "baz",
             XmlTableColumnDefinition::from(Int4)
                 .with_not_null(true)
-                .with_default_value(IntegerConst(1))
+                .with_default_value(IntegerConst(1).into())
         )
     ))]
     #[test_case("qux int default 1 default 2" => matches Err(ScanErr(
@@ -290,8 +290,8 @@ mod tests {
 
     #[test_case("null" => Ok(Null))]
     #[test_case("not null" => Ok(NotNull))]
-    #[test_case("default 'foo'" => Ok(DefaultOption(StringConst("foo".into()))))]
-    #[test_case("path 'foo'" => Ok(Path(StringConst("foo".into()))))]
+    #[test_case("default 'foo'" => Ok(DefaultOption(StringConst("foo".into()).into())))]
+    #[test_case("path 'foo'" => Ok(Path(StringConst("foo".into()).into())))]
     #[test_case("foo 'bar'" => matches Err(ScanErr(
         Located(Parser(UnrecognizedColumnOption(_)), _)
     )))]
@@ -304,13 +304,13 @@ mod tests {
 
     #[test_case("default 'foo'" => Ok(
         NamedValue::unnamed(
-            StringConst("foo".into())
+            StringConst("foo".into()).into()
         )
     ))]
     #[test_case("'foo' as bar" => Ok(
         NamedValue::new(
             Some("bar".into()),
-            StringConst("foo".into())
+            StringConst("foo".into()).into()
         )
     ))]
     fn test_xml_namespace_el(source: &str) -> scan::Result<NamedValue> {
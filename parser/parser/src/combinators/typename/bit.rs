@@ -23,7 +23,7 @@ pub(super) fn bit(default_type_modifiers:  Option<i32>) -> impl Combinator<Outpu
 
         modifiers = modifiers.or_else(||
             default_type_modifiers
-                .map(|len| vec![IntegerConst(len)])
+                .map(|len| vec![IntegerConst(len).into()])
         );
 
         Ok(Bit(modifiers))
@@ -37,10 +37,10 @@ mod tests {
     use pg_parser_core::scan;
     use test_case::test_case;
 
-    #[test_case("bit"             => Ok(Bit(Some(vec![IntegerConst(3)]))))]
-    #[test_case("bit(77)"         => Ok(Bit(Some(vec![IntegerConst(77)]))))]
+    #[test_case("bit"             => Ok(Bit(Some(vec![IntegerConst(3).into()]))))]
+    #[test_case("bit(77)"         => Ok(Bit(Some(vec![IntegerConst(77).into()]))))]
     #[test_case("bit varying"     => Ok(Varbit(None)))]
-    #[test_case("bit varying(55)" => Ok(Varbit(Some(vec![IntegerConst(55)]))))]
+    #[test_case("bit varying(55)" => Ok(Varbit(Some(vec![IntegerConst(55).into()]))))]
     fn test_bit(source: &str) -> scan::Result<TypeName> {
         let mut ctx = ParserContext::new(source);
         bit(Some(3)).parse(&mut ctx)
@@ -51,7 +51,7 @@ use super::type_modifiers;
 use crate::combinators::core::parser;
 use crate::combinators::core::Combinator;
 use crate::seq;
-use pg_ast::ExprNode::IntegerConst;
+use pg_ast::ExprKind::IntegerConst;
 use pg_ast::TypeName;
 use pg_ast::TypeName::Bit;
 use pg_ast::TypeName::Varbit;
@@ -28,15 +28,15 @@ pub(super) fn generic_type(ctx: &mut ParserContext) -> scan::Result<TypeName> {
 mod tests {
     use super::*;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::IntegerConst;
+    use pg_ast::ExprKind::IntegerConst;
     use test_case::test_case;
 
     #[test_case("double precision"      => Ok(Float8))]
     #[test_case("identif.attrib"        => Ok(TypeName::Generic { name: vec!["identif".into(), "attrib".into()], type_modifiers: None }))]
-    #[test_case("identif(33)"           => Ok(TypeName::Generic { name: vec!["identif".into()], type_modifiers: Some(vec![IntegerConst(33)]) }))]
+    #[test_case("identif(33)"           => Ok(TypeName::Generic { name: vec!["identif".into()], type_modifiers: Some(vec![IntegerConst(33).into()]) }))]
     #[test_case("double"                => Ok(TypeName::Generic { name: vec!["double".into()], type_modifiers: None }))]
     #[test_case("double.unreserved"     => Ok(TypeName::Generic { name: vec!["double".into(), "unreserved".into()], type_modifiers: None }))]
-    #[test_case("double.unreserved(55)" => Ok(TypeName::Generic { name: vec!["double".into(), "unreserved".into()], type_modifiers: Some(vec![IntegerConst(55)]) }))]
+    #[test_case("double.unreserved(55)" => Ok(TypeName::Generic { name: vec!["double".into(), "unreserved".into()], type_modifiers: Some(vec![IntegerConst(55).into()]) }))]
     #[test_case("full.type_func_name"   => Ok(TypeName::Generic { name: vec!["full".into(), "type_func_name".into()], type_modifiers: None }))]
     fn test_generic_type(source: &str) -> scan::Result<TypeName> {
         let mut ctx = ParserContext::new(source);
@@ -81,7 +81,7 @@ mod tests {
     use test_case::test_case;
     #[allow(unused_imports)]
     use {
-        pg_ast::ExprNode::IntegerConst,
+        pg_ast::ExprKind::IntegerConst,
         pg_elog::Error::Parser,
         pg_parser_core::scan::Error::ScanErr,
     };
@@ -96,11 +96,11 @@ mod tests {
     #[test_case("float(17)"   => Ok(Float4))]
     #[test_case("float(44)"   => Ok(Float8))]
     #[test_case("decimal"     => Ok(Numeric(None)))]
-    #[test_case("decimal(10)" => Ok(Numeric(Some(vec![IntegerConst(10)]))))]
+    #[test_case("decimal(10)" => Ok(Numeric(Some(vec![IntegerConst(10).into()]))))]
     #[test_case("dec"         => Ok(Numeric(None)))]
-    #[test_case("dec(20)"     => Ok(Numeric(Some(vec![IntegerConst(20)]))))]
+    #[test_case("dec(20)"     => Ok(Numeric(Some(vec![IntegerConst(20).into()]))))]
     #[test_case("numeric"     => Ok(Numeric(None)))]
-    #[test_case("numeric(30)" => Ok(Numeric(Some(vec![IntegerConst(30)]))))]
+    #[test_case("numeric(30)" => Ok(Numeric(Some(vec![IntegerConst(30).into()]))))]
     // Test error cases
     #[test_case("float(0)" => matches Err(ScanErr(
         Located(Parser(FloatPrecisionUnderflow(0)), _)
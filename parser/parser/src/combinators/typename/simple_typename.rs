@@ -17,7 +17,7 @@ pub(in crate::combinators) fn simple_typename(ctx: &mut ParserContext) -> scan::
 mod tests {
     use super::*;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::IntegerConst;
+    use pg_ast::ExprKind::IntegerConst;
     use test_case::test_case;
 
     #[test_case("json" => Ok(Json))]
@@ -28,7 +28,7 @@ mod tests {
     #[test_case("time"        => matches Ok(_))]
     #[test_case("identif(33)" => matches Ok(_))]
     // Still quick checks, but confirming the default lengths are set to 1
-    #[test_case("bit" => Ok(TypeName::Bit(Some(vec![IntegerConst(1)]))))]
+    #[test_case("bit" => Ok(TypeName::Bit(Some(vec![IntegerConst(1).into()]))))]
     #[test_case("char" => Ok(TypeName::Bpchar { length: Some(1) }))]
     fn test_simple_typename(source: &str) -> scan::Result<TypeName> {
         let mut ctx = ParserContext::new(source);
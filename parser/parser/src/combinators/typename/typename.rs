@@ -5,16 +5,17 @@ pub(in crate::combinators) fn typename(ctx: &mut ParserContext) -> scan::Result<
         ( SETOF )? SimpleTypename ( array_bounds )?
     */
 
-    let (set_of, type_name, array_bounds) = seq!(
+    let Located((set_of, type_name, array_bounds), loc) = spanned!(seq!(
         Setof.optional(),
         simple_typename,
         array_bounds.optional()
-    ).parse(ctx)?;
+    )).parse(ctx)?;
 
     let mut r#type = Type::from(type_name);
     r#type
         .set_mult(set_of.is_some().into())
-        .set_array_bounds(array_bounds);
+        .set_array_bounds(array_bounds)
+        .set_span(loc.into());
 
     Ok(r#type)
 }
@@ -58,13 +59,23 @@ mod tests {
     fn test_typename(source: &str) -> scan::Result<Type> {
         test_parser!(source, typename)
     }
+
+    #[test]
+    fn test_typename_span() {
+        let source = "setof int[] , text";
+        let mut ctx = ParserContext::new(source);
+        let actual = typename(&mut ctx).unwrap();
+        assert_eq!(Some("setof int[]"), actual.span().slice(source))
+    }
 }
 
 use super::array_bounds;
 use super::simple_typename;
 use crate::combinators::core::Combinator;
 use crate::seq;
+use crate::spanned;
 use crate::ParserContext;
 use pg_ast::Type;
+use pg_basics::Located;
 use pg_lexer::Keyword::Setof;
 use pg_parser_core::scan;
@@ -0,0 +1,34 @@
+/// Alias: `where_clause`
+pub(super) fn where_clause(ctx: &mut ParserContext) -> scan::Result<ExprNode> {
+
+    /*
+        WHERE a_expr
+    */
+
+    let (_, expr) = seq!(Where, a_expr).parse(ctx)?;
+    Ok(expr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    use pg_ast::ExprKind::IntegerConst;
+
+    #[test]
+    fn test_where_clause() {
+        test_parser!(
+            source = "where 1",
+            parser = where_clause,
+            expected = IntegerConst(1)
+        )
+    }
+}
+
+use crate::combinators::core::Combinator;
+use crate::combinators::expr::a_expr;
+use crate::seq;
+use crate::ParserContext;
+use pg_ast::ExprNode;
+use pg_lexer::Keyword::Where;
+use pg_parser_core::scan;
@@ -53,14 +53,14 @@ mod tests {
     use super::*;
     use crate::test_parser;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::IntegerConst;
+    use pg_ast::ExprKind::IntegerConst;
     use test_case::test_case;
 
     #[test_case("unbounded preceding", UnboundedPreceding)]
     #[test_case("unbounded following", UnboundedFollowing)]
     #[test_case("current row", CurrentRow)]
-    #[test_case("1 preceding", OffsetPreceding(IntegerConst(1)))]
-    #[test_case("1 following", OffsetFollowing(IntegerConst(1)))]
+    #[test_case("1 preceding", OffsetPreceding(IntegerConst(1).into()))]
+    #[test_case("1 following", OffsetFollowing(IntegerConst(1).into()))]
     fn test_frame_bound(source: &str, expected: FrameBound) {
         test_parser!(source, frame_bound, expected);
     }
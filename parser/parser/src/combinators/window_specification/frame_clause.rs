@@ -30,7 +30,7 @@ mod tests {
     #[allow(unused_imports)]
     use pg_ast::{
         CurrentRowEnd,
-        ExprNode::IntegerConst,
+        ExprKind::IntegerConst,
         FrameExtent,
         WindowExclusion::{NoOthers, Ties},
     };
@@ -133,7 +133,7 @@ mod tests {
     use super::*;
     use crate::test_parser;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::IntegerConst;
+    use pg_ast::ExprKind::IntegerConst;
     use test_case::test_case;
 
     #[test_case("unbounded preceding",
@@ -144,7 +144,7 @@ mod tests {
     )]
     #[test_case("1 preceding",
         FrameExtent::Preceding {
-            start: IntegerConst(1),
+            start: IntegerConst(1).into(),
             end: None
         }
     )]
@@ -155,10 +155,10 @@ mod tests {
         FrameExtent::Unbounded { end: Some(PrecedingEnd::CurrentRow) }
     )]
     #[test_case("between unbounded preceding and 1 preceding",
-        FrameExtent::Unbounded { end: Some(PrecedingEnd::Preceding(IntegerConst(1))) }
+        FrameExtent::Unbounded { end: Some(PrecedingEnd::Preceding(IntegerConst(1).into())) }
     )]
     #[test_case("between unbounded preceding and 1 following",
-        FrameExtent::Unbounded { end: Some(PrecedingEnd::Following(IntegerConst(1))) }
+        FrameExtent::Unbounded { end: Some(PrecedingEnd::Following(IntegerConst(1).into())) }
     )]
     #[test_case("between current row and unbounded following",
         FrameExtent::CurrentRow { end: Some(CurrentRowEnd::Unbounded) }
@@ -167,42 +167,42 @@ mod tests {
         FrameExtent::CurrentRow { end: Some(CurrentRowEnd::CurrentRow) }
     )]
     #[test_case("between current row and 1 following",
-        FrameExtent::CurrentRow { end: Some(CurrentRowEnd::Following(IntegerConst(1))) }
+        FrameExtent::CurrentRow { end: Some(CurrentRowEnd::Following(IntegerConst(1).into())) }
     )]
     #[test_case("between 1 preceding and unbounded following",
         FrameExtent::Preceding {
-            start: IntegerConst(1),
+            start: IntegerConst(1).into(),
             end: Some(PrecedingEnd::Unbounded)
         }
     )]
     #[test_case("between 1 preceding and current row",
         FrameExtent::Preceding {
-            start: IntegerConst(1),
+            start: IntegerConst(1).into(),
             end: Some(PrecedingEnd::CurrentRow)
         }
     )]
     #[test_case("between 1 preceding and 1 preceding",
         FrameExtent::Preceding {
-            start: IntegerConst(1),
-            end: Some(PrecedingEnd::Preceding(IntegerConst(1)))
+            start: IntegerConst(1).into(),
+            end: Some(PrecedingEnd::Preceding(IntegerConst(1).into()))
         }
     )]
     #[test_case("between 1 preceding and 1 following",
         FrameExtent::Preceding {
-            start: IntegerConst(1),
-            end: Some(PrecedingEnd::Following(IntegerConst(1)))
+            start: IntegerConst(1).into(),
+            end: Some(PrecedingEnd::Following(IntegerConst(1).into()))
         }
     )]
     #[test_case("between 1 following and unbounded following",
         FrameExtent::Following {
-            start: IntegerConst(1),
+            start: IntegerConst(1).into(),
             end: FollowingEnd::Unbounded
         }
     )]
     #[test_case("between 1 following and 1 following",
         FrameExtent::Following {
-            start: IntegerConst(1),
-            end: FollowingEnd::Following(IntegerConst(1))
+            start: IntegerConst(1).into(),
+            end: FollowingEnd::Following(IntegerConst(1).into())
         }
     )]
     fn test_frame_extent(source: &str, expected: FrameExtent) {
@@ -7,6 +7,10 @@ pg_basics::reexport! {
     window_exclusion_clause,
 }
 
+mod window_clause;
+pub(super) use window_clause::check_window_references;
+pub(super) use window_clause::window_clause;
+
 pub(super) fn window_specification(ctx: &mut ParserContext) -> scan::Result<WindowDefinition> {
 
     /*
@@ -36,7 +40,7 @@ mod tests {
     use super::*;
     use pg_ast::ExprNode;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::IntegerConst;
+    use pg_ast::ExprKind::IntegerConst;
     use pg_ast::FrameExtent;
     use pg_ast::SortBy;
     use pg_ast::WindowExclusion;
@@ -66,11 +70,11 @@ mod tests {
     }
 
     fn some_partition() -> Option<Vec<ExprNode>> {
-        Some(vec![IntegerConst(1)])
+        Some(vec![IntegerConst(1).into()])
     }
 
     fn some_order() -> Option<Vec<SortBy>> {
-        Some(vec![SortBy::new(IntegerConst(2), None, None)])
+        Some(vec![SortBy::new(IntegerConst(2).into(), None, None)])
     }
 
     fn some_frame() -> Option<WindowFrame> {
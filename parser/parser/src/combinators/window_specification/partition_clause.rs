@@ -15,10 +15,10 @@ pub(super) fn partition_clause(ctx: &mut ParserContext) -> scan::Result<Vec<Expr
 mod tests {
     use super::*;
     #[allow(unused_imports)]
-    use pg_ast::ExprNode::IntegerConst;
+    use pg_ast::ExprKind::IntegerConst;
     use test_case::test_case;
 
-    #[test_case("partition by 1, 2" => Ok(vec![IntegerConst(1), IntegerConst(2)]))]
+    #[test_case("partition by 1, 2" => Ok(vec![IntegerConst(1).into(), IntegerConst(2).into()]))]
     fn test_partition_clause(source: &str) -> scan::Result<Vec<ExprNode>> {
         let mut ctx = ParserContext::new(source);
         partition_clause(&mut ctx)
@@ -0,0 +1,239 @@
+/// Alias: `window_clause`
+pub(in crate::combinators) fn window_clause(ctx: &mut ParserContext) -> scan::Result<Vec<NamedWindow>> {
+
+    /*
+        WINDOW window_definition_list
+    */
+
+    let (_, definitions) = seq!(Window, window_definition_list)
+        .parse(ctx)?;
+
+    // A window can only reference the windows that were declared before it.
+    let mut windows: Vec<NamedWindow> = Vec::with_capacity(definitions.len());
+    for (Located(name, name_loc), Located(definition, loc)) in definitions {
+
+        if windows.iter().any(|window| window.name() == name.as_ref()) {
+            return Err(DuplicateWindow(name).at_location(name_loc).into())
+        }
+
+        if let Some(ref_name) = definition.name() {
+            let Some(existing) = windows.iter().find(|window| window.name() == ref_name) else {
+                return Err(UndefinedWindow(Str::from_cloning(ref_name)).at_location(loc).into())
+            };
+            check_window_override(&definition, existing.definition(), ref_name)
+                .map_err(|err| err.at_location(loc))?;
+        }
+
+        windows.push(NamedWindow::new(name, definition));
+    }
+
+    Ok(windows)
+}
+
+/// Checks that the windows that `OVER` clauses in `node` reference were declared in `windows`,
+/// and, like in the `WINDOW` clause, that they're only extended.
+///
+/// Nested queries aren't checked, because they can only reference their own `WINDOW` clause.
+/// Errors are at the expression of the window function, or at `location` if it doesn't have one.
+pub(in crate::combinators) fn check_window_references<T>(node: &T, windows: &[NamedWindow], location: Location) -> scan::Result<()>
+where
+    T: Accept
+{
+    let mut visitor = WindowReferences { windows, location };
+
+    match node.accept(&mut visitor) {
+        Continue(()) => Ok(()),
+        Break(err) => Err(err.into()),
+    }
+}
+
+struct WindowReferences<'a> {
+    windows: &'a [NamedWindow],
+    location: Location,
+}
+
+impl WindowReferences<'_> {
+
+    fn find(&self, name: &str) -> Result<&WindowDefinition, LocatedError> {
+        self.windows.iter()
+            .find(|window| window.name() == name)
+            .map(NamedWindow::definition)
+            .ok_or_else(|| UndefinedWindow(Str::from_cloning(name)).at_location(self.location.clone()))
+    }
+}
+
+impl Visit for WindowReferences<'_> {
+    type Break = LocatedError;
+
+    fn visit_select_stmt(&mut self, _: &SelectStmt) -> ControlFlow<LocatedError> {
+        Continue(())
+    }
+
+    fn visit_expr_node(&mut self, node: &ExprNode) -> ControlFlow<LocatedError> {
+
+        let Some(location) = node.span().location() else {
+            return node.walk(self)
+        };
+
+        let outer = mem::replace(&mut self.location, location.clone());
+        let result = node.walk(self);
+        self.location = outer;
+        result
+    }
+
+    fn visit_over_clause(&mut self, node: &OverClause) -> ControlFlow<LocatedError> {
+
+        let result = match node {
+            OverClause::WindowName(name) => self.find(name).map(drop),
+            OverClause::WindowDefinition(definition) => match definition.name() {
+                Some(ref_name) => self.find(ref_name).and_then(|existing|
+                    check_window_override(definition, existing, ref_name)
+                        .map_err(|err| err.at_location(self.location.clone()))
+                ),
+                None => Ok(()),
+            },
+        };
+
+        match result {
+            Ok(()) => node.walk(self),
+            Err(err) => Break(err),
+        }
+    }
+}
+
+/// Checks that `definition` only adds to the clauses of the window it copies.
+fn check_window_override(definition: &WindowDefinition, existing: &WindowDefinition, ref_name: &str) -> pg_elog::parser::Result<()> {
+
+    if definition.partition_clause().is_some() {
+        return Err(WindowPartitionOverride(Str::from_cloning(ref_name)))
+    }
+
+    if definition.order_clause().is_some() && existing.order_clause().is_some() {
+        return Err(WindowOrderOverride(Str::from_cloning(ref_name)))
+    }
+
+    if existing.frame_clause().is_some() {
+        return Err(WindowFrameCopy(Str::from_cloning(ref_name)))
+    }
+
+    Ok(())
+}
+
+/// Alias: `window_definition_list`
+fn window_definition_list(ctx: &mut ParserContext) -> scan::Result<Vec<(Located<Str>, Located<WindowDefinition>)>> {
+
+    /*
+        window_definition ( ',' window_definition )*
+    */
+
+    many!(sep = Comma, window_definition).parse(ctx)
+}
+
+/// Alias: `window_definition`
+fn window_definition(ctx: &mut ParserContext) -> scan::Result<(Located<Str>, Located<WindowDefinition>)> {
+
+    /*
+        ColId AS window_specification
+    */
+
+    let (name, _, definition) = seq!(
+        located!(col_id),
+        As,
+        located!(window_specification)
+    ).parse(ctx)?;
+
+    Ok((name, definition))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_parser;
+    #[allow(unused_imports)]
+    use pg_ast::{
+        ExprKind::IntegerConst,
+        FrameExtent,
+        SortBy,
+        WindowExclusion,
+        WindowFrame,
+        WindowFrameKind,
+    };
+    use pg_elog::Error::Parser;
+    use scan::Error::ScanErr;
+    use test_case::test_case;
+
+    #[test]
+    fn test_window_clause() {
+        test_parser!(
+            source = "window w1 as (partition by 1), w2 as (w1 order by 2 groups current row exclude ties), w3 as (w1)",
+            parser = window_clause,
+            expected = vec![
+                NamedWindow::new("w1", WindowDefinition::new(None, Some(vec![IntegerConst(1).into()]), None, None)),
+                NamedWindow::new("w2", WindowDefinition::new(
+                    Some("w1".into()),
+                    None,
+                    Some(vec![SortBy::new(IntegerConst(2).into(), None, None)]),
+                    Some(WindowFrame::new(
+                        WindowFrameKind::Groups,
+                        FrameExtent::CurrentRow { end: None },
+                        WindowExclusion::Ties
+                    ))
+                )),
+                NamedWindow::new("w3", WindowDefinition::new(Some("w1".into()), None, None, None)),
+            ]
+        )
+    }
+
+    #[test_case("window w as (), w as ()" => matches Err(ScanErr(
+        Located(Parser(DuplicateWindow(_)), _)
+    )))]
+    #[test_case("window w1 as (w2), w2 as ()" => matches Err(ScanErr(
+        Located(Parser(UndefinedWindow(_)), _)
+    )))]
+    #[test_case("window w1 as (partition by 1), w2 as (w1 partition by 2)" => matches Err(ScanErr(
+        Located(Parser(WindowPartitionOverride(_)), _)
+    )))]
+    #[test_case("window w1 as (order by 1), w2 as (w1 order by 2)" => matches Err(ScanErr(
+        Located(Parser(WindowOrderOverride(_)), _)
+    )))]
+    #[test_case("window w1 as (rows current row), w2 as (w1)" => matches Err(ScanErr(
+        Located(Parser(WindowFrameCopy(_)), _)
+    )))]
+    fn test_invalid_window_clause(source: &str) -> scan::Result<Vec<NamedWindow>> {
+        test_parser!(source, window_clause)
+    }
+}
+
+use crate::combinators::col_id;
+use crate::combinators::core::Combinator;
+use crate::combinators::window_specification;
+use crate::located;
+use crate::many;
+use crate::seq;
+use crate::ParserContext;
+use core::mem;
+use core::ops::ControlFlow;
+use core::ops::ControlFlow::Break;
+use core::ops::ControlFlow::Continue;
+use pg_ast::Accept;
+use pg_ast::ExprNode;
+use pg_ast::NamedWindow;
+use pg_ast::OverClause;
+use pg_ast::SelectStmt;
+use pg_ast::Visit;
+use pg_ast::Walk;
+use pg_ast::WindowDefinition;
+use pg_basics::IntoLocated;
+use pg_basics::Located;
+use pg_basics::Location;
+use pg_basics::Str;
+use pg_elog::parser::Error::DuplicateWindow;
+use pg_elog::parser::Error::UndefinedWindow;
+use pg_elog::parser::Error::WindowFrameCopy;
+use pg_elog::parser::Error::WindowOrderOverride;
+use pg_elog::parser::Error::WindowPartitionOverride;
+use pg_elog::parser::LocatedError;
+use pg_lexer::Keyword::As;
+use pg_lexer::Keyword::Window;
+use pg_lexer::OperatorKind::Comma;
+use pg_parser_core::scan;
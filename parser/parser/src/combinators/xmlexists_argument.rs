@@ -37,7 +37,7 @@ mod tests {
     use test_case::test_case;
     #[allow(unused_imports)]
     use {
-        pg_ast::ExprNode::StringConst,
+        pg_ast::ExprKind::StringConst,
         scan::Error::NoMatch,
     };
 
@@ -1,6 +1,10 @@
 #[derive(derive_more::Debug)]
 pub(super) struct ParserContext<'src> {
     stream: TokenStream<'src>,
+    /// Address of the outermost `nested()` call's frame, while one is running.
+    stack_base: Option<usize>,
+    /// In bytes.
+    max_stack_depth: usize,
 }
 
 impl<'src> ParserContext<'src> {
@@ -10,13 +14,50 @@ impl<'src> ParserContext<'src> {
         T: Into<TokenStream<'src>>,
     {
         Self {
-            stream: stream.into()
+            stream: stream.into(),
+            stack_base: None,
+            max_stack_depth: ParserConfig::DEFAULT_MAX_STACK_DEPTH * 1024,
         }
     }
 
+    /// `max_stack_depth` is in kilobytes.
+    pub fn with_max_stack_depth(mut self, max_stack_depth: usize) -> Self {
+        self.max_stack_depth = max_stack_depth.saturating_mul(1024);
+        self
+    }
+
     pub fn stream_mut(&mut self) -> &mut TokenStream<'src> {
         &mut self.stream
     }
+
+    pub fn add_warning(&mut self, warning: Located<Warning>) {
+        self.stream.add_warning(warning);
+    }
+
+    /// Alias: `check_stack_depth()`
+    ///
+    /// Runs `f`, unless the stack grew by more than `max_stack_depth` since the outermost call.
+    /// Fails at the current token in that case.
+    pub fn nested<T>(&mut self, f: impl FnOnce(&mut Self) -> scan::Result<T>) -> scan::Result<T> {
+
+        // Like upstream, the stack depth is the distance between the addresses of two locals.
+        let stack_top = 0u8;
+        let stack_top = &raw const stack_top as usize;
+
+        let Some(stack_base) = self.stack_base else {
+            self.stack_base = Some(stack_top);
+            let result = f(self);
+            self.stack_base = None;
+            return result
+        };
+
+        if stack_top.abs_diff(stack_base) > self.max_stack_depth {
+            let loc = self.stream.current_location();
+            return Err(StackDepthLimitExceeded.at_location(loc).into())
+        }
+
+        f(self)
+    }
 }
 
 impl<'src> From<&'src str> for ParserContext<'src> {
@@ -26,4 +67,10 @@ impl<'src> From<&'src str> for ParserContext<'src> {
     }
 }
 
+use pg_basics::IntoLocated;
+use pg_basics::Located;
+use pg_elog::parser::Error::StackDepthLimitExceeded;
+use pg_elog::parser::Warning;
+use pg_parser_core::scan;
 use pg_parser_core::stream::TokenStream;
+use pg_parser_core::ParserConfig;
@@ -1,10 +1,14 @@
+/// One result per statement.
+///
+/// See [`ParserConfig::with_fail_fast()`] for how statements after an error are handled.
 pub struct ParserResult {
-    pub result: pg_elog::LocatedResult<Option<Vec<RawStmt>>>,
+    pub result: Vec<pg_elog::LocatedResult<Located<RawStmt>>>,
     pub warnings: Option<Vec<Located<parser::Warning>>>,
 }
 
 pub struct Parser<'src> {
     pub(crate) context: ParserContext<'src>,
+    fail_fast: bool,
 }
 
 impl<'src> Parser<'src> {
@@ -13,6 +17,8 @@ impl<'src> Parser<'src> {
         let stream = TokenStream::new(source, config);
         Self {
             context: ParserContext::new(stream)
+                .with_max_stack_depth(config.max_stack_depth()),
+            fail_fast: config.fail_fast(),
         }
     }
 
@@ -20,29 +26,14 @@ impl<'src> Parser<'src> {
     /// The TokenStream state is changed.
     pub fn parse(&mut self) -> ParserResult {
 
-        let mut result = match stmtmulti(&mut self.context) {
-            Ok(stmts) => Ok(Some(stmts)),
-            Err(Eof(_)) => {
-                // Empty input or no statements is valid.
-                Ok(None)
-            },
-            Err(NoMatch(_)) => {
-                // If it's not Eof, then something didn't match properly.
-                // Mark the current location as a Syntax error.
-                let loc = self.context.stream_mut().current_location();
-                Err(syntax(loc))
-            },
-            Err(ScanErr(err)) => Err(err)
-        };
-
-        if !self.context.stream_mut().eof() {
-            let loc = self.context.stream_mut().current_location();
-            result = Err(syntax(loc));
-        }
+        let result = stmtmulti(&mut self.context, self.fail_fast);
+
+        let warnings = self.context.stream_mut().take_warnings();
+        let warnings = (!warnings.is_empty()).then_some(warnings);
 
         ParserResult {
             result,
-            warnings: None
+            warnings
         }
     }
 }
@@ -52,9 +43,5 @@ use crate::ParserContext;
 use pg_ast::RawStmt;
 use pg_basics::Located;
 use pg_elog::parser;
-use pg_parser_core::scan::Error::Eof;
-use pg_parser_core::scan::Error::NoMatch;
-use pg_parser_core::scan::Error::ScanErr;
 use pg_parser_core::stream::TokenStream;
-use pg_parser_core::syntax;
 use pg_parser_core::ParserConfig;
@@ -0,0 +1,62 @@
+/// Converts nodes into their libpg_query JSON.
+///
+/// The conversions are split by node kind, like the deparser's.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Converter<'src> {
+    source: &'src str,
+    standard_conforming_strings: bool,
+}
+
+impl<'src> Converter<'src> {
+
+    pub fn new(source: &'src str, standard_conforming_strings: bool) -> Self {
+        Self { source, standard_conforming_strings }
+    }
+
+    pub fn source(&self) -> &'src str {
+        self.source
+    }
+
+    /// Where the first token after `offset` is, skipping closing parenthesis.
+    ///
+    /// E.g.: the operator after the left operand, which is where PG puts an `A_Expr`.
+    pub fn token_after(&self, offset: Option<u32>) -> Option<i64> {
+
+        let offset = offset?;
+        let rest = self.source.get(offset as usize..)?;
+
+        Lexer::new(rest)
+            .with_standard_conforming_strings(self.standard_conforming_strings)
+            .map_while(Result::ok)
+            .find(|Located(kind, _)| *kind != Operator(CloseParenthesis))
+            .map(|Located(_, loc)| i64::from(offset + loc.range().start))
+    }
+}
+
+pub(crate) fn start(span: &Span) -> Option<i64> {
+    span.location().map(|loc| loc.range().start.into())
+}
+
+pub(crate) fn end(span: &Span) -> Option<u32> {
+    span.location().map(|loc| loc.range().end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(1, Some(1))]
+    #[test_case(2, Some(6))]
+    #[test_case(9, None)]
+    fn test_token_after(offset: u32, expected: Option<i64>) {
+        let converter = Converter::new("(a) ) + b", true);
+        assert_eq!(expected, converter.token_after(Some(offset)));
+    }
+}
+
+use pg_ast::Span;
+use pg_basics::Located;
+use pg_lexer::Lexer;
+use pg_lexer::OperatorKind::CloseParenthesis;
+use pg_lexer::RawTokenKind::Operator;
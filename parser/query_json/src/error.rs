@@ -0,0 +1,17 @@
+pub type Result<T = Value> = core::result::Result<T, Error>;
+
+#[derive(Debug, Clone, PartialEq, From, Display, Error)]
+pub enum Error {
+    #[display("{_0}")]
+    Syntax(LocatedError),
+    /// The node doesn't have a conversion yet.
+    #[display("{_0} can't be converted to a pg_query parse tree")]
+    #[from(ignore)]
+    Unsupported(#[error(not(source))] &'static str),
+}
+
+use derive_more::Display;
+use derive_more::Error;
+use derive_more::From;
+use pg_elog::LocatedError;
+use serde_json::Value;
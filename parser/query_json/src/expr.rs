@@ -0,0 +1,761 @@
+impl Converter<'_> {
+
+    pub fn expr(&self, expr: &ExprNode) -> Result {
+
+        let span = expr.span();
+        let location = start(span);
+
+        let node = match expr.kind() {
+            NullConst => {
+                Fields::new()
+                    .bool("isnull", true)
+                    .location(location)
+                    .into_node("A_Const")
+            },
+            StringConst(value) => string_const(value, location),
+            BinaryStringConst(value) => bit_string_const(&format!("b{value}"), location),
+            HexStringConst(value) => bit_string_const(&format!("x{value}"), location),
+            IntegerConst(value) => int_const(*value, location),
+            NumericConst { value, radix } => float_const(&numeric_text(value, *radix), location),
+            BooleanConst(value) => {
+                let value = Fields::new().bool("boolval", *value);
+                a_const("boolval", value, location)
+            },
+            DefaultExpr => {
+                Fields::new()
+                    .location(location)
+                    .into_node("SetToDefault")
+            },
+            ExprKind::CaseExpr(case) => self.case_expr(case, location)?,
+            ParamRef { index } => {
+                Fields::new()
+                    .int("number", (*index).into())
+                    .location(location)
+                    .into_node("ParamRef")
+            },
+            Row(args) => {
+                let args = self.exprs(args.as_deref().unwrap_or_default())?;
+                row_expr(args, row_format(span, self.source()), location)
+            },
+            Array(elements) => {
+                Fields::new()
+                    .list("elements", self.exprs(elements)?)
+                    .location(location)
+                    .into_node("A_ArrayExpr")
+            },
+            RowCompare(row_compare) => {
+                let op = row_compare_operator(row_compare.kind());
+                let left = row_expr(self.exprs(row_compare.left())?, "COERCE_IMPLICIT_CAST", None);
+                let right = row_expr(self.exprs(row_compare.right())?, "COERCE_IMPLICIT_CAST", None);
+                a_expr("AEXPR_OP", vec![string_node(op)], Some(left), Some(right), location)
+            },
+            StringTypecast(typecast) => {
+                // PG doesn't keep where the type cast is, only where the string is.
+                let type_name = typecast.type_name();
+                let arg = string_const(typecast.arg(), self.token_after(end(type_name.span())));
+                Fields::new()
+                    .node("arg", Some(arg))
+                    .node("typeName", Some(self.type_name(type_name)?))
+                    .location(Some(-1))
+                    .into_node("TypeCast")
+            },
+            BinaryExpr(expr) => {
+                let (left, right) = (expr.left_operand(), expr.right_operand());
+                a_expr(
+                    binary_expr_kind(expr.operator()),
+                    operator_name(expr.operator()),
+                    Some(self.expr(left)?),
+                    Some(self.expr(right)?),
+                    self.token_after(end(left.span()))
+                )
+            },
+            UnaryExpr(expr) => {
+                a_expr(
+                    "AEXPR_OP",
+                    operator_name(expr.operator()),
+                    None,
+                    Some(self.expr(expr.operand())?),
+                    location
+                )
+            },
+            BoolExpr(BoolExpr::Not(operand)) => {
+                Fields::new()
+                    .enum_value("boolop", "NOT_EXPR")
+                    .list("args", vec![self.expr(operand)?])
+                    .location(location)
+                    .into_node("BoolExpr")
+            },
+            BoolExpr(BoolExpr::And(operands)) => self.bool_expr("AND_EXPR", operands)?,
+            BoolExpr(BoolExpr::Or(operands)) => self.bool_expr("OR_EXPR", operands)?,
+            FuncCallExpr(func) => self.func_call_expr(func, location)?,
+            JsonArrayAggExpr(_) => return Err(Unsupported("JsonArrayAgg")),
+            JsonObjectAggExpr(_) => return Err(Unsupported("JsonObjectAgg")),
+            Distinct(operands) => self.distinct_expr("AEXPR_DISTINCT", operands)?,
+            NotDistinct(operands) => self.distinct_expr("AEXPR_NOT_DISTINCT", operands)?,
+            IsNull(operand) => self.null_test("IS_NULL", operand)?,
+            IsNotNull(operand) => self.null_test("IS_NOT_NULL", operand)?,
+            ExprKind::BooleanTest(test) => {
+                Fields::new()
+                    .node("arg", Some(self.expr(test.expr())?))
+                    .enum_value("booltesttype", bool_test_type(test.kind()))
+                    .location(self.token_after(end(test.expr().span())))
+                    .into_node("BooleanTest")
+            },
+            Between(expr) => {
+                let (kind, name) = between_kind(expr);
+                let bounds = list_node(vec![self.expr(expr.low())?, self.expr(expr.high())?]);
+                a_expr(
+                    kind,
+                    vec![string_node(name)],
+                    Some(self.expr(expr.expr())?),
+                    Some(bounds),
+                    self.token_after(end(expr.expr().span()))
+                )
+            },
+            SimilarTo(expr) => {
+                // PG wraps the pattern in `similar_to_escape()`, located at `SIMILAR`, or at `NOT`.
+                let location = self.token_after(end(expr.expr().span()));
+                let mut args = vec![self.expr(expr.pattern())?];
+                if let Some(escape) = expr.escape() {
+                    args.push(self.expr(escape)?);
+                }
+                let pattern = Fields::new()
+                    .list("funcname", vec![string_node("pg_catalog"), string_node("similar_to_escape")])
+                    .list("args", args)
+                    .enum_value("funcformat", "COERCE_EXPLICIT_CALL")
+                    .location(location)
+                    .into_node("FuncCall");
+                let name = if expr.not() { "!~" } else { "~" };
+                a_expr(
+                    "AEXPR_SIMILAR",
+                    vec![string_node(name)],
+                    Some(self.expr(expr.expr())?),
+                    Some(pattern),
+                    location
+                )
+            },
+            ArrayCompare(expr) => {
+                let kind = match expr.kind() {
+                    ArrayCompareKind::Any => "AEXPR_OP_ANY",
+                    ArrayCompareKind::All => "AEXPR_OP_ALL",
+                };
+                a_expr(
+                    kind,
+                    operator_name(expr.operator()),
+                    Some(self.expr(expr.left())?),
+                    Some(self.expr(expr.right())?),
+                    self.token_after(end(expr.left().span()))
+                )
+            },
+            AtTimeZone(operands) => {
+                // `timezone(zone, expr)`
+                let (left, zone) = &**operands;
+                let args = vec![self.expr(zone)?, self.expr(left)?];
+                sql_syntax_call("timezone", args, self.token_after(end(left.span())))
+            },
+            AtLocal(operand) => {
+                let args = vec![self.expr(operand)?];
+                sql_syntax_call("timezone", args, self.token_after(end(operand.span())))
+            },
+            Collate(expr) => {
+                Fields::new()
+                    .node("arg", Some(self.expr(expr.expr())?))
+                    .list("collname", string_nodes(expr.collation()))
+                    .location(self.token_after(end(expr.expr().span())))
+                    .into_node("CollateClause")
+            },
+            ExprKind::Indirection(expr) => {
+                Fields::new()
+                    .node("arg", Some(self.expr(expr.expr())?))
+                    .list("indirection", self.indirection(expr.indirection())?)
+                    .into_node("A_Indirection")
+            },
+            Typecast(typecast) => self.typecast(typecast, self.token_after(end(typecast.arg().span())))?,
+            InExpr(expr) => {
+                let name = if expr.not() { "<>" } else { "=" };
+                a_expr(
+                    "AEXPR_IN",
+                    vec![string_node(name)],
+                    Some(self.expr(expr.expr())?),
+                    Some(list_node(self.exprs(expr.list())?)),
+                    self.token_after(end(expr.expr().span()))
+                )
+            },
+            SubLink(sub_link) => self.sub_link(sub_link, location)?,
+            ExprKind::ColumnRef(column_ref) => self.column_ref(column_ref, location)?,
+            GroupingFunc(args) => {
+                Fields::new()
+                    .list("args", self.exprs(args)?)
+                    .location(location)
+                    .into_node("GroupingFunc")
+            },
+            FuncCall(func) => self.func_call(func)?.location(location).into_node("FuncCall"),
+            SqlFunction(func) => self.sql_function(func, location)?,
+        };
+
+        Ok(node)
+    }
+
+    pub fn exprs(&self, exprs: &[ExprNode]) -> Result<Vec<Value>> {
+        exprs.iter()
+            .map(|expr| self.expr(expr))
+            .collect()
+    }
+
+    pub fn opt_expr(&self, expr: Option<&ExprNode>) -> Result<Option<Value>> {
+        expr.map(|expr| self.expr(expr))
+            .transpose()
+    }
+
+    /// `TypeCast`, for both `::` and `CAST()`.
+    pub fn typecast(&self, typecast: &TypecastExpr, location: Option<i64>) -> Result {
+        let node = Fields::new()
+            .node("arg", Some(self.expr(typecast.arg())?))
+            .node("typeName", Some(self.type_name(typecast.type_name())?))
+            .location(location)
+            .into_node("TypeCast");
+        Ok(node)
+    }
+
+    /// Like PG, `a AND b AND c` is flattened into a single node, but `a AND (b AND c)` isn't.
+    fn bool_expr(&self, boolop: &str, operands: &BinaryOperands) -> Result {
+
+        let mut args = Vec::new();
+        let mut operands = &**operands;
+
+        // The left operand is walked down, so the args are collected in reverse.
+        let location = loop {
+            let (left, right) = operands;
+            args.push(self.expr(right)?);
+
+            match (boolop, left.kind()) {
+                ("AND_EXPR", BoolExpr(BoolExpr::And(inner))) | ("OR_EXPR", BoolExpr(BoolExpr::Or(inner))) => {
+                    operands = inner;
+                },
+                _ => {
+                    args.push(self.expr(left)?);
+                    break self.token_after(end(left.span()))
+                },
+            }
+        };
+
+        args.reverse();
+
+        let node = Fields::new()
+            .enum_value("boolop", boolop)
+            .list("args", args)
+            .location(location)
+            .into_node("BoolExpr");
+        Ok(node)
+    }
+
+    fn distinct_expr(&self, kind: &str, operands: &BinaryOperands) -> Result {
+        let (left, right) = &**operands;
+        let node = a_expr(
+            kind,
+            vec![string_node("=")],
+            Some(self.expr(left)?),
+            Some(self.expr(right)?),
+            self.token_after(end(left.span()))
+        );
+        Ok(node)
+    }
+
+    fn null_test(&self, nulltesttype: &str, operand: &ExprNode) -> Result {
+        let node = Fields::new()
+            .node("arg", Some(self.expr(operand)?))
+            .enum_value("nulltesttype", nulltesttype)
+            .location(self.token_after(end(operand.span())))
+            .into_node("NullTest");
+        Ok(node)
+    }
+
+    fn case_expr(&self, case: &CaseExpr, location: Option<i64>) -> Result {
+
+        let when_clauses = case.when_clauses()
+            .iter()
+            .map(|when| {
+                let node = Fields::new()
+                    .node("expr", Some(self.expr(when.condition())?))
+                    .node("result", Some(self.expr(when.body())?))
+                    .into_node("CaseWhen");
+                Ok(node)
+            })
+            .collect::<Result<_>>()?;
+
+        let node = Fields::new()
+            .node("arg", self.opt_expr(case.target())?)
+            .list("args", when_clauses)
+            .node("defresult", self.opt_expr(case.default())?)
+            .location(location)
+            .into_node("CaseExpr");
+        Ok(node)
+    }
+
+    fn sub_link(&self, sub_link: &SubLink, location: Option<i64>) -> Result {
+
+        // With a test expression, PG's location is the operator's (or `IN`'s).
+        let location = match sub_link.test_expr() {
+            Some(test_expr) => self.token_after(end(test_expr.span())),
+            None => location,
+        };
+
+        let node = Fields::new()
+            .enum_value("subLinkType", sub_link_type(sub_link.kind()))
+            .node("testexpr", self.opt_expr(sub_link.test_expr())?)
+            .list("operName", sub_link.operator().map(operator_name).unwrap_or_default())
+            .node("subselect", Some(self.select_stmt(sub_link.subselect())?.into_node("SelectStmt")))
+            .location(location)
+            .into_node("SubLink");
+        Ok(node)
+    }
+
+    /// Like PG, the fields only go up to the first subscript,
+    /// and the rest of the indirection wraps the `ColumnRef`.
+    fn column_ref(&self, column_ref: &ColumnRef, location: Option<i64>) -> Result {
+
+        let (name, fields, subscripts) = column_ref_parts(column_ref);
+
+        let mut field_nodes = string_nodes(name);
+        field_nodes.extend(self.indirection(fields)?);
+
+        let node = Fields::new()
+            .list("fields", field_nodes)
+            .location(location)
+            .into_node("ColumnRef");
+
+        if subscripts.is_empty() {
+            return Ok(node)
+        }
+
+        let node = Fields::new()
+            .node("arg", Some(node))
+            .list("indirection", self.indirection(subscripts)?)
+            .into_node("A_Indirection");
+        Ok(node)
+    }
+
+    fn indirection(&self, indirection: &[Indirection]) -> Result<Vec<Value>> {
+        indirection.iter()
+            .map(|el| {
+                let node = match el {
+                    Indirection::Wildcard => a_star(),
+                    Indirection::Property(name) => string_node(name),
+                    Indirection::Index(index) => {
+                        Fields::new()
+                            .node("uidx", Some(self.expr(index)?))
+                            .into_node("A_Indices")
+                    },
+                    Indirection::Slice(lower, upper) => {
+                        Fields::new()
+                            .bool("is_slice", true)
+                            .node("lidx", self.opt_expr(lower.as_ref())?)
+                            .node("uidx", self.opt_expr(upper.as_ref())?)
+                            .into_node("A_Indices")
+                    },
+                };
+                Ok(node)
+            })
+            .collect()
+    }
+}
+
+/// `{"A_Const": {"<kind>": {"<kind>": ...}}}`
+fn a_const(kind: &str, value: Fields, location: Option<i64>) -> Value {
+    Fields::new()
+        .node(kind, Some(value.into_value()))
+        .location(location)
+        .into_node("A_Const")
+}
+
+pub(crate) fn int_const(value: i32, location: Option<i64>) -> Value {
+    let value = Fields::new().int("ival", value.into());
+    a_const("ival", value, location)
+}
+
+pub(crate) fn float_const(value: &str, location: Option<i64>) -> Value {
+    let value = Fields::new().string("fval", Some(value));
+    a_const("fval", value, location)
+}
+
+pub(crate) fn string_const(value: &str, location: Option<i64>) -> Value {
+    let value = Fields::new().string("sval", Some(value));
+    a_const("sval", value, location)
+}
+
+fn bit_string_const(value: &str, location: Option<i64>) -> Value {
+    let value = Fields::new().string("bsval", Some(value));
+    a_const("bsval", value, location)
+}
+
+pub(crate) fn a_expr(
+    kind: &str,
+    name: Vec<Value>,
+    lexpr: Option<Value>,
+    rexpr: Option<Value>,
+    location: Option<i64>
+)
+    -> Value
+{
+    Fields::new()
+        .enum_value("kind", kind)
+        .list("name", name)
+        .node("lexpr", lexpr)
+        .node("rexpr", rexpr)
+        .location(location)
+        .into_node("A_Expr")
+}
+
+pub(crate) fn a_star() -> Value {
+    Fields::new().into_node("A_Star")
+}
+
+fn row_expr(args: Vec<Value>, row_format: &str, location: Option<i64>) -> Value {
+    Fields::new()
+        .list("args", args)
+        .enum_value("row_format", row_format)
+        .location(location)
+        .into_node("RowExpr")
+}
+
+/// `(a, b)` is an implicit row, and `ROW(a, b)` an explicit one.
+pub(crate) fn row_format(span: &Span, source: &str) -> &'static str {
+
+    let explicit = span.slice(source)
+        .is_none_or(|sql| sql.get(..3).is_some_and(|kw| kw.eq_ignore_ascii_case("row")));
+
+    if explicit { "COERCE_EXPLICIT_CALL" } else { "COERCE_IMPLICIT_CAST" }
+}
+
+/// The `ColumnRef`'s fields are the name, and the indirection up to the first subscript.
+/// The subscripts are an `A_Indirection` around it.
+pub(crate) fn column_ref_parts(column_ref: &ColumnRef) -> (&[Str], &[Indirection], &[Indirection]) {
+
+    let (name, indirection) = match column_ref {
+        ColumnRef::SingleName(name) => (slice::from_ref(name), &[][..]),
+        ColumnRef::Name(name) => (&name[..], &[][..]),
+        ColumnRef::WildcardName(name) => (&name[..], &[Indirection::Wildcard][..]),
+        ColumnRef::Indirection { name, indirection } => (&name[..], &indirection[..]),
+    };
+
+    let split = indirection.iter()
+        .position(|el| matches!(el, Indirection::Index(_) | Indirection::Slice(..)))
+        .unwrap_or(indirection.len());
+    let (fields, subscripts) = indirection.split_at(split);
+
+    (name, fields, subscripts)
+}
+
+/// `LIKE` and `ILIKE` have their own kind, unless the operator is schema qualified.
+pub(crate) fn binary_expr_kind(op: &QualifiedOperator) -> &'static str {
+    let QualifiedOperator(schema, op) = op;
+    match op {
+        Operator::Like | Operator::NotLike if schema.is_empty() => "AEXPR_LIKE",
+        Operator::ILike | Operator::NotILike if schema.is_empty() => "AEXPR_ILIKE",
+        _ => "AEXPR_OP",
+    }
+}
+
+pub(crate) fn bool_test_type(kind: BoolTestKind) -> &'static str {
+    match kind {
+        BoolTestKind::IsTrue => "IS_TRUE",
+        BoolTestKind::IsNotTrue => "IS_NOT_TRUE",
+        BoolTestKind::IsFalse => "IS_FALSE",
+        BoolTestKind::IsNotFalse => "IS_NOT_FALSE",
+        BoolTestKind::IsUnknown => "IS_UNKNOWN",
+        BoolTestKind::IsNotUnknown => "IS_NOT_UNKNOWN",
+    }
+}
+
+/// The `A_Expr` kind, and its name.
+pub(crate) fn between_kind(expr: &BetweenExpr) -> (&'static str, &'static str) {
+    match (expr.not(), expr.symmetric()) {
+        (false, false) => ("AEXPR_BETWEEN", "BETWEEN"),
+        (true, false) => ("AEXPR_NOT_BETWEEN", "NOT BETWEEN"),
+        (false, true) => ("AEXPR_BETWEEN_SYM", "BETWEEN SYMMETRIC"),
+        (true, true) => ("AEXPR_NOT_BETWEEN_SYM", "NOT BETWEEN SYMMETRIC"),
+    }
+}
+
+pub(crate) fn row_compare_operator(kind: RowCompareKind) -> &'static str {
+    match kind {
+        RowCompareKind::Less => "<",
+        RowCompareKind::LessEquals => "<=",
+        RowCompareKind::Equals => "=",
+        RowCompareKind::GreaterEquals => ">=",
+        RowCompareKind::Greater => ">",
+        RowCompareKind::NotEquals => "<>",
+    }
+}
+
+pub(crate) fn sub_link_type(kind: SubLinkKind) -> &'static str {
+    match kind {
+        SubLinkKind::Exists => "EXISTS_SUBLINK",
+        SubLinkKind::All => "ALL_SUBLINK",
+        SubLinkKind::Any => "ANY_SUBLINK",
+        SubLinkKind::RowCompare => "ROWCOMPARE_SUBLINK",
+        SubLinkKind::Expr => "EXPR_SUBLINK",
+        SubLinkKind::MultiExpr => "MULTIEXPR_SUBLINK",
+        SubLinkKind::Array => "ARRAY_SUBLINK",
+        SubLinkKind::Cte => "CTE_SUBLINK",
+    }
+}
+
+/// Alias: `any_operator`, as a list of names.
+pub(crate) fn operator_name(op: &QualifiedOperator) -> Vec<Value> {
+
+    let QualifiedOperator(schema, op) = op;
+
+    let mut name = string_nodes(schema);
+    name.push(string_node(operator_symbol(op)));
+    name
+}
+
+pub(crate) fn operator_symbol(op: &Operator) -> &str {
+    match op {
+        Operator::Addition => "+",
+        Operator::Subtraction => "-",
+        Operator::Multiplication => "*",
+        Operator::Division => "/",
+        Operator::Modulo => "%",
+        Operator::Exponentiation => "^",
+        Operator::Less => "<",
+        Operator::Greater => ">",
+        Operator::Equals => "=",
+        Operator::LessEquals => "<=",
+        Operator::GreaterEquals => ">=",
+        Operator::NotEquals => "<>",
+        Operator::Like => "~~",
+        Operator::NotLike => "!~~",
+        Operator::ILike => "~~*",
+        Operator::NotILike => "!~~*",
+        Operator::UserDefined(op) => op,
+    }
+}
+
+/// The text of a numeric constant, as PG keeps it, including the radix prefix.
+pub(crate) fn numeric_text(value: &str, radix: NumberRadix) -> String {
+
+    let (sign, digits) = match value.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", value),
+    };
+
+    let prefix = match radix {
+        NumberRadix::Binary => "0b",
+        NumberRadix::Octal => "0o",
+        NumberRadix::Decimal => "",
+        NumberRadix::Hexadecimal => "0x",
+    };
+
+    let has_prefix = digits.get(..2).is_some_and(|p| p.eq_ignore_ascii_case(prefix));
+
+    if prefix.is_empty() || has_prefix {
+        value.to_string()
+    }
+    else {
+        format!("{sign}{prefix}{digits}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+    use pg_parser_core::ParserConfig;
+    use serde_json::json;
+    use test_case::test_case;
+
+    #[test_case("1", json!({"A_Const": {"ival": {"ival": 1}, "location": 7}}))]
+    #[test_case("0", json!({"A_Const": {"ival": {}, "location": 7}}))]
+    #[test_case("1.5", json!({"A_Const": {"fval": {"fval": "1.5"}, "location": 7}}))]
+    #[test_case("'foo'", json!({"A_Const": {"sval": {"sval": "foo"}, "location": 7}}))]
+    #[test_case("b'01'", json!({"A_Const": {"bsval": {"bsval": "b01"}, "location": 7}}))]
+    #[test_case("false", json!({"A_Const": {"boolval": {}, "location": 7}}))]
+    #[test_case("null", json!({"A_Const": {"isnull": true, "location": 7}}))]
+    #[test_case("$1", json!({"ParamRef": {"number": 1, "location": 7}}))]
+    #[test_case("a.b", json!({"ColumnRef": {
+        "fields": [{"String": {"sval": "a"}}, {"String": {"sval": "b"}}],
+        "location": 7
+    }}))]
+    #[test_case("a.*", json!({"ColumnRef": {
+        "fields": [{"String": {"sval": "a"}}, {"A_Star": {}}],
+        "location": 7
+    }}))]
+    #[test_case("a.b[1]", json!({"A_Indirection": {
+        "arg": {"ColumnRef": {
+            "fields": [{"String": {"sval": "a"}}, {"String": {"sval": "b"}}],
+            "location": 7
+        }},
+        "indirection": [{"A_Indices": {"uidx": {"A_Const": {"ival": {"ival": 1}, "location": 11}}}}]
+    }}))]
+    #[test_case("(a) + 1", json!({"A_Expr": {
+        "kind": "AEXPR_OP",
+        "name": [{"String": {"sval": "+"}}],
+        "lexpr": {"ColumnRef": {"fields": [{"String": {"sval": "a"}}], "location": 8}},
+        "rexpr": {"A_Const": {"ival": {"ival": 1}, "location": 13}},
+        "location": 11
+    }}))]
+    #[test_case("a not like 'x'", json!({"A_Expr": {
+        "kind": "AEXPR_LIKE",
+        "name": [{"String": {"sval": "!~~"}}],
+        "lexpr": {"ColumnRef": {"fields": [{"String": {"sval": "a"}}], "location": 7}},
+        "rexpr": {"A_Const": {"sval": {"sval": "x"}, "location": 18}},
+        "location": 9
+    }}))]
+    #[test_case("a like 'x' escape '!'", json!({"A_Expr": {
+        "kind": "AEXPR_LIKE",
+        "name": [{"String": {"sval": "~~"}}],
+        "lexpr": {"ColumnRef": {"fields": [{"String": {"sval": "a"}}], "location": 7}},
+        "rexpr": {"FuncCall": {
+            "funcname": [{"String": {"sval": "pg_catalog"}}, {"String": {"sval": "like_escape"}}],
+            "args": [
+                {"A_Const": {"sval": {"sval": "x"}, "location": 14}},
+                {"A_Const": {"sval": {"sval": "!"}, "location": 25}}
+            ],
+            "funcformat": "COERCE_EXPLICIT_CALL",
+            "location": 9
+        }},
+        "location": 9
+    }}))]
+    #[test_case("a not similar to 'x'", json!({"A_Expr": {
+        "kind": "AEXPR_SIMILAR",
+        "name": [{"String": {"sval": "!~"}}],
+        "lexpr": {"ColumnRef": {"fields": [{"String": {"sval": "a"}}], "location": 7}},
+        "rexpr": {"FuncCall": {
+            "funcname": [{"String": {"sval": "pg_catalog"}}, {"String": {"sval": "similar_to_escape"}}],
+            "args": [{"A_Const": {"sval": {"sval": "x"}, "location": 24}}],
+            "funcformat": "COERCE_EXPLICIT_CALL",
+            "location": 9
+        }},
+        "location": 9
+    }}))]
+    #[test_case("a between symmetric 1 and 2", json!({"A_Expr": {
+        "kind": "AEXPR_BETWEEN_SYM",
+        "name": [{"String": {"sval": "BETWEEN SYMMETRIC"}}],
+        "lexpr": {"ColumnRef": {"fields": [{"String": {"sval": "a"}}], "location": 7}},
+        "rexpr": {"List": {"items": [
+            {"A_Const": {"ival": {"ival": 1}, "location": 27}},
+            {"A_Const": {"ival": {"ival": 2}, "location": 33}}
+        ]}},
+        "location": 9
+    }}))]
+    #[test_case("a = any(b)", json!({"A_Expr": {
+        "kind": "AEXPR_OP_ANY",
+        "name": [{"String": {"sval": "="}}],
+        "lexpr": {"ColumnRef": {"fields": [{"String": {"sval": "a"}}], "location": 7}},
+        "rexpr": {"ColumnRef": {"fields": [{"String": {"sval": "b"}}], "location": 15}},
+        "location": 9
+    }}))]
+    #[test_case("a is not null", json!({"NullTest": {
+        "arg": {"ColumnRef": {"fields": [{"String": {"sval": "a"}}], "location": 7}},
+        "nulltesttype": "IS_NOT_NULL",
+        "location": 9
+    }}))]
+    #[test_case("a is unknown", json!({"BooleanTest": {
+        "arg": {"ColumnRef": {"fields": [{"String": {"sval": "a"}}], "location": 7}},
+        "booltesttype": "IS_UNKNOWN",
+        "location": 9
+    }}))]
+    #[test_case("a at time zone 'UTC'", json!({"FuncCall": {
+        "funcname": [{"String": {"sval": "pg_catalog"}}, {"String": {"sval": "timezone"}}],
+        "args": [
+            {"A_Const": {"sval": {"sval": "UTC"}, "location": 22}},
+            {"ColumnRef": {"fields": [{"String": {"sval": "a"}}], "location": 7}}
+        ],
+        "funcformat": "COERCE_SQL_SYNTAX",
+        "location": 9
+    }}))]
+    #[test_case("a collate \"C\"", json!({"CollateClause": {
+        "arg": {"ColumnRef": {"fields": [{"String": {"sval": "a"}}], "location": 7}},
+        "collname": [{"String": {"sval": "C"}}],
+        "location": 9
+    }}))]
+    #[test_case("a in (1)", json!({"A_Expr": {
+        "kind": "AEXPR_IN",
+        "name": [{"String": {"sval": "="}}],
+        "lexpr": {"ColumnRef": {"fields": [{"String": {"sval": "a"}}], "location": 7}},
+        "rexpr": {"List": {"items": [{"A_Const": {"ival": {"ival": 1}, "location": 13}}]}},
+        "location": 9
+    }}))]
+    #[test_case("a and b and c", json!({"BoolExpr": {
+        "boolop": "AND_EXPR",
+        "args": [
+            {"ColumnRef": {"fields": [{"String": {"sval": "a"}}], "location": 7}},
+            {"ColumnRef": {"fields": [{"String": {"sval": "b"}}], "location": 13}},
+            {"ColumnRef": {"fields": [{"String": {"sval": "c"}}], "location": 19}}
+        ],
+        "location": 9
+    }}))]
+    #[test_case("a::int", json!({"TypeCast": {
+        "arg": {"ColumnRef": {"fields": [{"String": {"sval": "a"}}], "location": 7}},
+        "typeName": {
+            "names": [{"String": {"sval": "pg_catalog"}}, {"String": {"sval": "int4"}}],
+            "typemod": -1,
+            "location": 10
+        },
+        "location": 8
+    }}))]
+    #[test_case("(1, 2)", json!({"RowExpr": {
+        "args": [
+            {"A_Const": {"ival": {"ival": 1}, "location": 8}},
+            {"A_Const": {"ival": {"ival": 2}, "location": 11}}
+        ],
+        "row_format": "COERCE_IMPLICIT_CAST",
+        "location": 7
+    }}))]
+    #[test_case("exists (select)", json!({"SubLink": {
+        "subLinkType": "EXISTS_SUBLINK",
+        "subselect": {"SelectStmt": {"limitOption": "LIMIT_OPTION_DEFAULT", "op": "SETOP_NONE"}},
+        "location": 7
+    }}))]
+    fn test_expr(source: &str, expected: Value) {
+
+        let source = format!("select {source}");
+        let tree = parse(&source, ParserConfig::default()).unwrap();
+
+        let actual = &tree["stmts"][0]["stmt"]["SelectStmt"]["targetList"][0]["ResTarget"]["val"];
+        assert_eq!(&expected, actual);
+    }
+
+    #[test_case("1.5", NumberRadix::Decimal, "1.5")]
+    #[test_case("-1.5", NumberRadix::Decimal, "-1.5")]
+    #[test_case("FFFFFFFFFF", NumberRadix::Hexadecimal, "0xFFFFFFFFFF")]
+    #[test_case("0xFFFFFFFFFF", NumberRadix::Hexadecimal, "0xFFFFFFFFFF")]
+    fn test_numeric_text(value: &str, radix: NumberRadix, expected: &str) {
+        assert_eq!(expected, numeric_text(value, radix))
+    }
+}
+
+use crate::converter::end;
+use crate::func::sql_syntax_call;
+use crate::converter::start;
+use crate::list_node;
+use crate::string_node;
+use crate::string_nodes;
+use crate::Converter;
+use crate::Error::Unsupported;
+use crate::Fields;
+use crate::Result;
+use core::slice;
+use pg_ast::ArrayCompareKind;
+use pg_ast::BetweenExpr;
+use pg_ast::BinaryOperands;
+use pg_ast::BoolExpr;
+use pg_ast::BoolTestKind;
+use pg_ast::CaseExpr;
+use pg_ast::ColumnRef;
+use pg_ast::ExprKind;
+use pg_ast::ExprKind::*;
+use pg_ast::ExprNode;
+use pg_ast::Indirection;
+use pg_ast::Operator;
+use pg_ast::QualifiedOperator;
+use pg_ast::RowCompareKind;
+use pg_ast::Span;
+use pg_ast::SubLink;
+use pg_ast::SubLinkKind;
+use pg_ast::TypecastExpr;
+use pg_basics::NumberRadix;
+use pg_basics::Str;
+use serde_json::Value;
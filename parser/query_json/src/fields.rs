@@ -0,0 +1,137 @@
+/// The fields of a node, without the default values, like libpg_query writes them.
+#[derive(Debug, Default)]
+pub(crate) struct Fields(Map<String, Value>);
+
+impl Fields {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn node(mut self, name: &str, value: Option<Value>) -> Self {
+        if let Some(value) = value {
+            self.0.insert(name.into(), value);
+        }
+        self
+    }
+
+    pub fn list(mut self, name: &str, items: Vec<Value>) -> Self {
+        if !items.is_empty() {
+            self.0.insert(name.into(), Value::Array(items));
+        }
+        self
+    }
+
+    pub fn string(mut self, name: &str, value: Option<&str>) -> Self {
+        if let Some(value) = value {
+            self.0.insert(name.into(), value.into());
+        }
+        self
+    }
+
+    pub fn bool(mut self, name: &str, value: bool) -> Self {
+        if value {
+            self.0.insert(name.into(), value.into());
+        }
+        self
+    }
+
+    pub fn int(mut self, name: &str, value: i64) -> Self {
+        if value != 0 {
+            self.0.insert(name.into(), value.into());
+        }
+        self
+    }
+
+    /// Enums are always written, even when they have the default value.
+    pub fn enum_value(mut self, name: &str, value: &str) -> Self {
+        self.0.insert(name.into(), value.into());
+        self
+    }
+
+    pub fn location(self, location: Option<i64>) -> Self {
+        self.int("location", location.unwrap_or_default())
+    }
+
+    /// E.g.: `{"A_Const": {...}}`
+    pub fn into_node(self, name: &str) -> Value {
+        let mut node = Map::new();
+        node.insert(name.into(), self.into_value());
+        Value::Object(node)
+    }
+
+    /// Fields that always hold the same node type (e.g., `TypeName`) don't have the node name.
+    pub fn into_value(self) -> Value {
+        Value::Object(self.0)
+    }
+}
+
+/// `{"String": {"sval": ...}}`
+pub(crate) fn string_node(value: &str) -> Value {
+    Fields::new()
+        .string("sval", Some(value))
+        .into_node("String")
+}
+
+/// `{"Integer": {"ival": ...}}`
+pub(crate) fn integer_node(value: i32) -> Value {
+    Fields::new()
+        .int("ival", value.into())
+        .into_node("Integer")
+}
+
+/// A list that's an item of another list, e.g., a row of `VALUES`.
+pub(crate) fn list_node(items: Vec<Value>) -> Value {
+    Fields::new()
+        .list("items", items)
+        .into_node("List")
+}
+
+pub(crate) fn string_nodes<T: AsRef<str>>(values: &[T]) -> Vec<Value> {
+    values.iter()
+        .map(|value| string_node(value.as_ref()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_defaults_are_omitted() {
+        let actual = Fields::new()
+            .node("arg", None)
+            .list("args", vec![])
+            .string("name", None)
+            .bool("is_local", false)
+            .int("ival", 0)
+            .location(None)
+            .enum_value("op", "SETOP_NONE")
+            .into_node("SelectStmt");
+
+        assert_eq!(json!({"SelectStmt": {"op": "SETOP_NONE"}}), actual);
+    }
+
+    #[test]
+    fn test_values() {
+        let actual = Fields::new()
+            .list("names", string_nodes(&["pg_catalog", "int4"]))
+            .bool("setof", true)
+            .int("typemod", -1)
+            .location(Some(7))
+            .into_value();
+
+        let expected = json!({
+            "names": [{"String": {"sval": "pg_catalog"}}, {"String": {"sval": "int4"}}],
+            "setof": true,
+            "typemod": -1,
+            "location": 7
+        });
+
+        assert_eq!(expected, actual);
+    }
+}
+
+use serde_json::Map;
+use serde_json::Value;
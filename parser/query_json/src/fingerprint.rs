@@ -0,0 +1,1515 @@
+/// A 64-bit id of the shape of a query, like pg_query's `fingerprint()`.
+///
+/// Like pg_query's, it's displayed as 16 hex digits.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Display)]
+#[display("{_0:016x}")]
+pub struct Fingerprint(u64);
+
+impl Fingerprint {
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Fingerprints all the statements in `source`, with pg_query's rules, so that
+/// queries that only differ in their constants, parameter numbers, aliases,
+/// the order of their target lists, or the length of their `IN (...)` lists have the same fingerprint.
+///
+/// pg_query defines the fingerprint over its own parse tree, so the AST is written with the
+/// node names, field names and enum values that [`parse()`] converts it into.
+///
+/// Like [`parse()`], only part of the grammar is supported: `SELECT`, `PREPARE`, `CREATE EXTENSION`,
+/// `DROP PROPERTY GRAPH` and the utility statements that [`parse()`] converts.
+/// Every other statement fails with [`Error::Unsupported`].
+///
+/// Fails like [`parse()`].
+pub fn fingerprint(source: &str, config: ParserConfig) -> Result<Fingerprint> {
+
+    let stmts = Parser::new(source, config)
+        .parse()
+        .result
+        .into_iter()
+        .collect::<LocatedResult<Vec<_>>>()?;
+
+    let mut fingerprinter = Fingerprinter::new(source);
+    for Located(stmt, _) in &stmts {
+        if let Break(err) = stmt.accept(&mut fingerprinter) {
+            return Err(err)
+        }
+    }
+
+    Ok(Fingerprint(hash(&fingerprinter.tokens)))
+}
+
+/// The version of pg_query's fingerprint, which is the seed of the hash.
+const FINGERPRINT_VERSION: u64 = 3;
+
+/// Like pg_query's, some trees are too deep, so they're consistently cut off.
+const MAX_DEPTH: usize = 100;
+
+/// The items of these lists are sorted, and duplicates are removed,
+/// so that e.g., `IN (1, 2)` and `IN (3)` have the same fingerprint.
+const SORTED_LISTS: &[&str] = &["fromClause", "targetList", "cols", "rexpr", "valuesLists", "args"];
+
+fn hash(tokens: &[Cow<'_, str>]) -> u64 {
+    let mut hasher = Xxh3::with_seed(FINGERPRINT_VERSION);
+    for token in tokens {
+        hasher.update(token.as_bytes());
+    }
+    hasher.digest()
+}
+
+/// Writes the tokens of pg_query's parse tree: node names, and the fields in alphabetical order,
+/// without the ones that don't change the shape of the query,
+/// e.g., locations, aliases, and the names of prepared statements, cursors and savepoints.
+///
+/// Constants, parameters and `DEFAULT` aren't written at all.
+struct Fingerprinter<'src> {
+    source: &'src str,
+    tokens: Vec<Cow<'static, str>>,
+    depth: usize,
+}
+
+type Flow = ControlFlow<Error>;
+
+impl<'src> Fingerprinter<'src> {
+
+    fn new(source: &'src str) -> Self {
+        Self {
+            source,
+            tokens: Vec::new(),
+            depth: 0,
+        }
+    }
+
+    /// Writes the node name, and then its fields.
+    fn node<F>(&mut self, name: &'static str, fields: F) -> Flow
+    where
+        F: FnOnce(&mut Self) -> Flow
+    {
+        if self.depth >= MAX_DEPTH {
+            return Continue(())
+        }
+
+        self.tokens.push(name.into());
+        self.fields(fields)
+    }
+
+    /// Fields that always hold the same node type (e.g., `typeName`) don't have the node name.
+    fn fields<F>(&mut self, fields: F) -> Flow
+    where
+        F: FnOnce(&mut Self) -> Flow
+    {
+        if self.depth >= MAX_DEPTH {
+            return Continue(())
+        }
+
+        self.depth += 1;
+        let result = fields(self);
+        self.depth -= 1;
+        result
+    }
+
+    /// A field's name is only written when its value writes something.
+    fn field<F>(&mut self, name: &'static str, value: F) -> Flow
+    where
+        F: FnOnce(&mut Self) -> Flow
+    {
+        let len = self.tokens.len();
+        self.tokens.push(name.into());
+
+        value(self)?;
+
+        if self.tokens.len() == len + 1 {
+            self.tokens.truncate(len);
+        }
+        Continue(())
+    }
+
+    fn list<T, F>(&mut self, name: &'static str, items: &[T], item: F) -> Flow
+    where
+        F: FnMut(&mut Self, &T) -> Flow
+    {
+        self.field(name, |fp| fp.items(name, items, item))
+    }
+
+    /// Lists aren't nodes in the fingerprint, so nested lists (e.g., the rows of `VALUES`)
+    /// are written like the items of the field they're in.
+    fn items<T, F>(&mut self, field: &str, items: &[T], mut item: F) -> Flow
+    where
+        F: FnMut(&mut Self, &T) -> Flow
+    {
+        if !SORTED_LISTS.contains(&field) {
+            for value in items {
+                item(self, value)?;
+            }
+            return Continue(())
+        }
+
+        let mut sorted = Vec::with_capacity(items.len());
+        for value in items {
+            let outer = mem::take(&mut self.tokens);
+            let result = item(self, value);
+            let item_tokens = mem::replace(&mut self.tokens, outer);
+            result?;
+            sorted.push((hash(&item_tokens), item_tokens));
+        }
+
+        sorted.sort_by_key(|(hash, _)| *hash);
+        sorted.dedup_by_key(|(hash, _)| *hash);
+
+        self.tokens.extend(sorted.into_iter().flat_map(|(_, item_tokens)| item_tokens));
+        Continue(())
+    }
+
+    fn exprs(&mut self, name: &'static str, exprs: &[ExprNode]) -> Flow {
+        self.list(name, exprs, |fp, expr| expr.accept(fp))
+    }
+
+    fn opt_expr(&mut self, name: &'static str, expr: Option<&ExprNode>) -> Flow {
+        match expr {
+            Some(expr) => self.field(name, |fp| expr.accept(fp)),
+            None => Continue(()),
+        }
+    }
+
+    fn string(&mut self, name: &'static str, value: Option<&str>) {
+        if let Some(value) = value {
+            self.tokens.extend([name.into(), value.to_owned().into()]);
+        }
+    }
+
+    /// Enums are always written, even when they have the default value.
+    fn enum_value(&mut self, name: &'static str, value: &'static str) {
+        self.tokens.extend([name.into(), value.into()]);
+    }
+
+    fn bool(&mut self, name: &'static str, value: bool) {
+        if value {
+            self.tokens.extend([name.into(), "true".into()]);
+        }
+    }
+
+    fn int(&mut self, name: &'static str, value: i64) {
+        if value != 0 {
+            self.tokens.extend([name.into(), value.to_string().into()]);
+        }
+    }
+
+    /// Like pg_query, the value field is written as `str`, which is its name before PG 15.
+    fn string_node(&mut self, value: &str) -> Flow {
+        self.node("String", |fp| {
+            fp.string("str", Some(value));
+            Continue(())
+        })
+    }
+
+    fn integer_node(&mut self, value: i64) -> Flow {
+        // Like the field, a 0 isn't written.
+        if value == 0 {
+            return Continue(())
+        }
+        self.node("Integer", |fp| {
+            fp.int("ival", value);
+            Continue(())
+        })
+    }
+
+    fn string_nodes<T: AsRef<str>>(&mut self, name: &'static str, values: &[T]) -> Flow {
+        self.list(name, values, |fp, value| fp.string_node(value.as_ref()))
+    }
+
+    fn a_star(&mut self) -> Flow {
+        self.node("A_Star", |_| Continue(()))
+    }
+
+    /// The node of a statement, without its `RawStmt`.
+    ///
+    /// Only `SELECT`, `PREPARE`, `DROP PROPERTY GRAPH`, `CREATE EXTENSION` and the utility statements below
+    /// are fingerprinted. The rest are [`Unsupported`].
+    fn stmt(&mut self, node: &RawStmt) -> Flow {
+        match node {
+            RawStmt::SelectStmt(stmt) => stmt.accept(self),
+            RawStmt::PrepareStmt(stmt) => stmt.accept(self),
+            RawStmt::DropStmt(stmt) => stmt.accept(self),
+            RawStmt::CreateExtensionStmt(stmt) => stmt.accept(self),
+            RawStmt::TransactionStmt(stmt) => stmt.accept(self),
+            RawStmt::PrepareTransactionStmt(_) => {
+                self.node("TransactionStmt", |fp| {
+                    fp.enum_value("kind", "TRANS_STMT_PREPARE");
+                    Continue(())
+                })
+            },
+            RawStmt::VariableSetStmt(stmt) => stmt.accept(self),
+            RawStmt::VariableResetStmt(VariableTarget::All) => {
+                self.node("VariableSetStmt", |fp| {
+                    fp.enum_value("kind", "VAR_RESET_ALL");
+                    Continue(())
+                })
+            },
+            RawStmt::VariableResetStmt(target) => {
+                self.node("VariableSetStmt", |fp| {
+                    fp.enum_value("kind", "VAR_RESET");
+                    fp.string("name", Some(&variable_name(target)));
+                    Continue(())
+                })
+            },
+            RawStmt::VariableShowStmt(target) => {
+                self.node("VariableShowStmt", |fp| {
+                    fp.string("name", Some(&variable_name(target)));
+                    Continue(())
+                })
+            },
+            RawStmt::ListenStmt(channel) => {
+                self.node("ListenStmt", |fp| {
+                    fp.string("conditionname", Some(channel));
+                    Continue(())
+                })
+            },
+            RawStmt::UnlistenStmt(channel) => {
+                self.node("UnlistenStmt", |fp| {
+                    fp.string("conditionname", one(channel));
+                    Continue(())
+                })
+            },
+            RawStmt::NotifyStmt(stmt) => stmt.accept(self),
+            RawStmt::LoadStmt(filename) => {
+                self.node("LoadStmt", |fp| {
+                    fp.string("filename", Some(filename));
+                    Continue(())
+                })
+            },
+            RawStmt::DiscardStmt(stmt) => stmt.accept(self),
+            RawStmt::DeallocateStmt(name) => {
+                self.node("DeallocateStmt", |fp| {
+                    fp.bool("isall", matches!(name, OneOrAll::All));
+                    Continue(())
+                })
+            },
+            RawStmt::ClosePortalStmt(_) => self.node("ClosePortalStmt", |_| Continue(())),
+            RawStmt::CheckPointStmt(options) => {
+                self.node("CheckPointStmt", |fp| {
+                    fp.list("options", options.as_deref().unwrap_or_default(), |fp, option| option.accept(fp))
+                })
+            },
+            RawStmt::AlterDatabaseRefreshCollStmt(name) => {
+                self.node("AlterDatabaseRefreshCollStmt", |fp| {
+                    fp.string("dbname", Some(name));
+                    Continue(())
+                })
+            },
+            _ => Break(Unsupported(node_name(node))),
+        }
+    }
+
+    /// A `TypeName` in a list, which has the node name, unlike a `typeName` field.
+    fn type_name_node(&mut self, type_name: &Type) -> Flow {
+        self.node("TypeName", |fp| type_name.accept(fp))
+    }
+
+    /// Alias: `SelectStmt`
+    fn select_stmt(&mut self, stmt: &SelectStmt) -> Flow {
+
+        let select = match stmt.body() {
+            SelectBody::Simple(select) => Some(select),
+            _ => None,
+        };
+
+        let (op, all, larg, rarg) = match stmt.body() {
+            SelectBody::SetOperation { op, all, left, right } => {
+                (set_operation(*op), *all, Some(left), Some(right))
+            },
+            _ => ("SETOP_NONE", false, None, None),
+        };
+
+        self.bool("all", all);
+
+        if let Some(select) = select {
+            match select.distinct_clause() {
+                // Like PG's `list_make1(NIL)` (a plain `DISTINCT`), the list isn't empty.
+                Some(DistinctClause::Distinct) => self.tokens.push("distinctClause".into()),
+                Some(DistinctClause::DistinctOn(exprs)) => self.exprs("distinctClause", exprs)?,
+                None => {},
+            }
+            self.list("fromClause", select.from_clause().unwrap_or_default(), |fp, table_ref| table_ref.accept(fp))?;
+            self.exprs("groupClause", select.group_clause().unwrap_or_default())?;
+            self.bool("groupDistinct", select.group_distinct());
+            self.opt_expr("havingClause", select.having_clause())?;
+        }
+
+        if let Some(larg) = larg {
+            self.field("larg", |fp| fp.fields(|fp| fp.select_stmt(larg)))?;
+        }
+
+        self.opt_expr("limitCount", stmt.limit_count())?;
+        self.opt_expr("limitOffset", stmt.limit_offset())?;
+        self.enum_value("limitOption", limit_option(stmt));
+        self.enum_value("op", op);
+
+        if let Some(rarg) = rarg {
+            self.field("rarg", |fp| fp.fields(|fp| fp.select_stmt(rarg)))?;
+        }
+
+        self.list("sortClause", stmt.sort_clause().unwrap_or_default(), |fp, sort_by| sort_by.accept(fp))?;
+
+        if let Some(select) = select {
+            self.list("targetList", select.target_list(), |fp, target| target.accept(fp))?;
+        }
+
+        if let SelectBody::Values(rows) = stmt.body() {
+            self.list("valuesLists", rows, |fp, row| {
+                fp.items("valuesLists", row, |fp, expr| expr.accept(fp))
+            })?;
+        }
+
+        if let Some(select) = select {
+            self.opt_expr("whereClause", select.where_clause())?;
+            self.list("windowClause", select.window_clause().unwrap_or_default(), |fp, window| window.accept(fp))?;
+        }
+
+        Continue(())
+    }
+
+    /// The fields of a `RangeFunction`, where column definitions in the alias are its `coldeflist`.
+    fn range_function(
+        &mut self,
+        functions: &[(&FuncExprWindowless, Option<&[SimpleColumnDefinition]>)],
+        func_alias: Option<&FuncAlias>,
+        ordinality: bool,
+        lateral: bool,
+        is_rowsfrom: bool
+    )
+        -> Flow
+    {
+        let columns = match func_alias {
+            Some(OneOrBoth::Right(columns) | OneOrBoth::Both(_, columns)) => &columns[..],
+            Some(OneOrBoth::Left(_)) | None => &[][..],
+        };
+
+        // Without types, the columns are only aliases.
+        if columns.iter().any(|column| column.type_name().is_some()) {
+            self.list("coldeflist", columns, |fp, column| {
+                fp.column_def(column.name(), column.type_name(), column.collation())
+            })?;
+        }
+
+        self.list("functions", functions, |fp, (func, column_definitions)| {
+            func.accept(fp)?;
+            fp.items("functions", column_definitions.unwrap_or_default(), |fp, column| {
+                fp.column_def(column.name(), Some(column.type_name()), column.collation())
+            })
+        })?;
+
+        self.bool("is_rowsfrom", is_rowsfrom);
+        self.bool("lateral", lateral);
+        self.bool("ordinality", ordinality);
+        Continue(())
+    }
+
+    /// Alias: `TableFuncElement`
+    fn column_def(&mut self, name: &str, type_name: Option<&Type>, collation: Option<&[Str]>) -> Flow {
+        self.node("ColumnDef", |fp| {
+            if let Some(collation) = collation {
+                fp.field("collClause", |fp| fp.fields(|fp| fp.string_nodes("collname", collation)))?;
+            }
+            fp.string("colname", Some(name));
+            fp.bool("is_local", true);
+            if let Some(type_name) = type_name {
+                fp.field("typeName", |fp| type_name.accept(fp))?;
+            }
+            Continue(())
+        })
+    }
+
+    fn a_expr<L, R>(&mut self, kind: &'static str, op: (&[Str], &str), lexpr: L, rexpr: R) -> Flow
+    where
+        L: FnOnce(&mut Self) -> Flow,
+        R: FnOnce(&mut Self) -> Flow,
+    {
+        let (schema, symbol) = op;
+        self.node("A_Expr", |fp| {
+            fp.enum_value("kind", kind);
+            fp.field("lexpr", lexpr)?;
+            fp.field("name", |fp| {
+                for name in schema {
+                    fp.string_node(name)?;
+                }
+                fp.string_node(symbol)
+            })?;
+            fp.field("rexpr", rexpr)
+        })
+    }
+
+    fn distinct_expr(&mut self, kind: &'static str, operands: &BinaryOperands) -> Flow {
+        let (left, right) = &**operands;
+        self.a_expr(kind, (&[], "="), |fp| left.accept(fp), |fp| right.accept(fp))
+    }
+
+    fn null_test(&mut self, nulltesttype: &'static str, operand: &ExprNode) -> Flow {
+        self.node("NullTest", |fp| {
+            fp.field("arg", |fp| operand.accept(fp))?;
+            fp.enum_value("nulltesttype", nulltesttype);
+            Continue(())
+        })
+    }
+
+    fn row_expr(&mut self, args: &[ExprNode], row_format: &'static str) -> Flow {
+        self.node("RowExpr", |fp| {
+            fp.exprs("args", args)?;
+            fp.enum_value("row_format", row_format);
+            Continue(())
+        })
+    }
+
+    /// Like PG, `a AND b AND c` is flattened into a single node, but `a AND (b AND c)` isn't.
+    fn bool_expr(&mut self, boolop: &'static str, operands: &BinaryOperands) -> Flow {
+
+        // The left operand is walked down. The order doesn't matter, because `args` are sorted.
+        let mut args = Vec::new();
+        let mut operands = &**operands;
+        loop {
+            let (left, right) = operands;
+            args.push(right);
+
+            match (boolop, left.kind()) {
+                ("AND_EXPR", ExprKind::BoolExpr(BoolExpr::And(inner)))
+                | ("OR_EXPR", ExprKind::BoolExpr(BoolExpr::Or(inner))) => operands = inner,
+                _ => {
+                    args.push(left);
+                    break
+                },
+            }
+        }
+
+        self.node("BoolExpr", |fp| {
+            fp.list("args", &args, |fp, arg| arg.accept(fp))?;
+            fp.enum_value("boolop", boolop);
+            Continue(())
+        })
+    }
+
+    /// Alias: `func_application`
+    fn func_call(&mut self, func: &FuncCall, agg_filter: Option<&ExprNode>, over: Option<&OverClause>) -> Flow {
+
+        let (args, order, within_group): (Vec<&NamedValue>, _, _) = match func.args() {
+            FuncArgsKind::Empty { order_within_group }
+            | FuncArgsKind::Wildcard { order_within_group } => (vec![], order_within_group.as_deref(), true),
+            FuncArgsKind::All { args, order } => {
+                let args = args.iter().map(Located::source).collect();
+                match order.as_ref().map(Located::source) {
+                    Some(FuncArgsOrder::OrderBy(order)) => (args, Some(&order[..]), false),
+                    Some(FuncArgsOrder::WithinGroup(order)) => (args, Some(&order[..]), true),
+                    None => (args, None, false),
+                }
+            },
+            FuncArgsKind::Variadic { args, order }
+            | FuncArgsKind::Distinct { args, order } => (args.iter().collect(), order.as_deref(), false),
+        };
+
+        self.node("FuncCall", |fp| {
+            fp.bool("agg_distinct", matches!(func.args(), FuncArgsKind::Distinct { .. }));
+            fp.opt_expr("agg_filter", agg_filter)?;
+            fp.list("agg_order", order.unwrap_or_default(), |fp, sort_by| sort_by.accept(fp))?;
+            fp.bool("agg_star", matches!(func.args(), FuncArgsKind::Wildcard { .. }));
+            fp.bool("agg_within_group", within_group && order.is_some());
+            fp.list("args", &args, |fp, arg| arg.accept(fp))?;
+            fp.bool("func_variadic", matches!(func.args(), FuncArgsKind::Variadic { .. }));
+            fp.enum_value("funcformat", "COERCE_EXPLICIT_CALL");
+            fp.string_nodes("funcname", func.name())?;
+            match over {
+                Some(OverClause::WindowName(name)) => {
+                    fp.field("over", |fp| fp.fields(|fp| {
+                        fp.int("frameOptions", FRAMEOPTION_DEFAULTS);
+                        fp.string("name", Some(name));
+                        Continue(())
+                    }))
+                },
+                Some(OverClause::WindowDefinition(definition)) => {
+                    fp.field("over", |fp| fp.fields(|fp| fp.window_def(definition, None)))
+                },
+                None => Continue(()),
+            }
+        })
+    }
+
+    /// A function with special SQL syntax, e.g.: `TRIM(LEADING FROM a)` is `pg_catalog.ltrim(a)`.
+    fn sql_syntax_call(&mut self, name: &str, args: &[&ExprNode]) -> Flow {
+        self.node("FuncCall", |fp| {
+            fp.list("args", args, |fp, arg| arg.accept(fp))?;
+            fp.enum_value("funcformat", "COERCE_SQL_SYNTAX");
+            fp.string_nodes("funcname", &["pg_catalog", name])
+        })
+    }
+
+    /// E.g.: `SUBSTRING(a, 1)`, which isn't schema qualified, unlike the SQL syntax.
+    fn explicit_call(&mut self, name: &str, args: Option<&[NamedValue]>) -> Flow {
+        self.node("FuncCall", |fp| {
+            fp.list("args", args.unwrap_or_default(), |fp, arg| arg.accept(fp))?;
+            fp.enum_value("funcformat", "COERCE_EXPLICIT_CALL");
+            fp.string_nodes("funcname", &[name])
+        })
+    }
+
+    fn sql_value_function(&mut self, op: &'static str, precision: Option<i32>) -> Flow {
+        self.node("SQLValueFunction", |fp| {
+            fp.enum_value("op", op);
+            fp.int("typmod", precision.unwrap_or(-1).into());
+            Continue(())
+        })
+    }
+
+    fn min_max(&mut self, op: &'static str, args: &[ExprNode]) -> Flow {
+        self.node("MinMaxExpr", |fp| {
+            fp.exprs("args", args)?;
+            fp.enum_value("op", op);
+            Continue(())
+        })
+    }
+
+    /// Alias: `window_specification`
+    ///
+    /// The fields of a `WindowDef`. The existing window name is its `refname`.
+    fn window_def(&mut self, definition: &WindowDefinition, name: Option<&str>) -> Flow {
+
+        let (frame_options, start_offset, end_offset) = frame_options(definition);
+
+        self.opt_expr("endOffset", end_offset)?;
+        self.int("frameOptions", frame_options);
+        self.string("name", name);
+        self.list("orderClause", definition.order_clause().unwrap_or_default(), |fp, sort_by| sort_by.accept(fp))?;
+        self.exprs("partitionClause", definition.partition_clause().unwrap_or_default())?;
+        self.string("refname", definition.name());
+        self.opt_expr("startOffset", start_offset)
+    }
+}
+
+impl Visit for Fingerprinter<'_> {
+    type Break = Error;
+
+    fn visit_raw_stmt(&mut self, node: &RawStmt) -> Flow {
+        self.node("RawStmt", |fp| fp.field("stmt", |fp| fp.stmt(node)))
+    }
+
+    fn visit_prepare_stmt(&mut self, node: &PrepareStmt) -> Flow {
+        self.node("PrepareStmt", |fp| {
+            fp.list("argtypes", node.arg_types(), |fp, arg_type| fp.type_name_node(arg_type))?;
+            fp.field("query", |fp| fp.stmt(node.query()))
+        })
+    }
+
+    fn visit_drop_stmt(&mut self, node: &DropStmt) -> Flow {
+
+        let DropTarget::PropertyGraph(names) = node.target();
+
+        self.node("DropStmt", |fp| {
+            fp.enum_value("behavior", drop_behavior(node.behavior()));
+            fp.bool("missing_ok", node.existence() == Presence::Ignore);
+            fp.list("objects", names, |fp, name| fp.items("objects", name, |fp, name| fp.string_node(name)))?;
+            fp.enum_value("removeType", "OBJECT_PROPGRAPH");
+            Continue(())
+        })
+    }
+
+    fn visit_create_extension_stmt(&mut self, node: &CreateExtensionStmt) -> Flow {
+        self.node("CreateExtensionStmt", |fp| {
+            fp.string("extname", Some(node.name()));
+            fp.bool("if_not_exists", node.existence() == Presence::Ignore);
+            fp.list("options", node.options().unwrap_or_default(), |fp, option| option.accept(fp))
+        })
+    }
+
+    /// Alias: `create_extension_opt_item`
+    fn visit_create_extension_option(&mut self, node: &CreateExtensionOption) -> Flow {
+
+        let (name, value) = match node {
+            CreateExtensionOption::Schema(schema) => ("schema", Some(&**schema)),
+            CreateExtensionOption::Version(version) => ("new_version", Some(&**version)),
+            CreateExtensionOption::Cascade => ("cascade", None),
+        };
+
+        self.node("DefElem", |fp| {
+            fp.field("arg", |fp| match value {
+                Some(value) => fp.string_node(value),
+                None => fp.node("Boolean", |fp| {
+                    fp.bool("boolval", true);
+                    Continue(())
+                }),
+            })?;
+            fp.enum_value("defaction", "DEFELEM_UNSPEC");
+            fp.string("defname", Some(name));
+            Continue(())
+        })
+    }
+
+    fn visit_transaction_stmt(&mut self, node: &TransactionStmt) -> Flow {
+        self.node("TransactionStmt", |fp| {
+            if let TransactionStmt::Commit { chain } | TransactionStmt::Rollback { chain } = node {
+                fp.bool("chain", *chain);
+            }
+            fp.enum_value("kind", transaction_kind(node));
+            Continue(())
+        })
+    }
+
+    fn visit_transaction_mode(&mut self, node: &TransactionMode) -> Flow {
+        self.node("DefElem", |fp| {
+            fp.enum_value("defaction", "DEFELEM_UNSPEC");
+            fp.string("defname", Some(transaction_mode_name(node)));
+            Continue(())
+        })
+    }
+
+    fn visit_variable_set_stmt(&mut self, node: &VariableSetStmt) -> Flow {
+
+        let (kind, name) = match set_rest_kind(node.stmt()) {
+            Ok(kind) => kind,
+            Err(err) => return Break(err),
+        };
+
+        self.node("VariableSetStmt", |fp| {
+            match node.stmt() {
+                SetRest::SessionTransactionCharacteristics(modes)
+                | SetRest::LocalTransactionCharacteristics(modes) => {
+                    fp.list("args", modes, |fp, mode| mode.accept(fp))?;
+                },
+                SetRest::TimeZone(ZoneValue::Interval { range, .. }) => {
+                    let type_name = Type::from(TypeName::Interval(*range));
+                    fp.field("args", |fp| fp.node("TypeCast", |fp| {
+                        fp.field("typeName", |fp| type_name.accept(fp))
+                    }))?;
+                },
+                // The other arguments are constants.
+                _ => {},
+            }
+            fp.bool("is_local", node.is_local());
+            fp.enum_value("kind", kind);
+            fp.string("name", Some(&name));
+            Continue(())
+        })
+    }
+
+    fn visit_notify_stmt(&mut self, node: &NotifyStmt) -> Flow {
+        self.node("NotifyStmt", |fp| {
+            fp.string("conditionname", Some(node.condition_name()));
+            fp.string("payload", node.payload());
+            Continue(())
+        })
+    }
+
+    fn visit_discard_stmt(&mut self, node: &DiscardStmt) -> Flow {
+        self.node("DiscardStmt", |fp| {
+            fp.enum_value("target", discard_target(*node));
+            Continue(())
+        })
+    }
+
+    fn visit_utility_option(&mut self, node: &UtilityOption) -> Flow {
+        self.node("DefElem", |fp| {
+            // Unlike `SET`'s, the values are plain `String`, `Integer` and `Float` nodes.
+            fp.field("arg", |fp| match node.value() {
+                Some(VarValue::Boolean(true)) => fp.string_node("true"),
+                Some(VarValue::Boolean(false)) => fp.string_node("false"),
+                Some(VarValue::Number(SignedNumber::IntegerConst(value))) => fp.integer_node((*value).into()),
+                Some(VarValue::Number(SignedNumber::NumericConst { value, radix, negative })) => {
+                    let value = numeric_text(value, *radix);
+                    let value = if *negative { format!("-{value}") } else { value };
+                    fp.node("Float", |fp| {
+                        fp.string("str", Some(&value));
+                        Continue(())
+                    })
+                },
+                Some(VarValue::String(value)) => fp.string_node(value),
+                None => Continue(()),
+            })?;
+            fp.enum_value("defaction", "DEFELEM_UNSPEC");
+            fp.string("defname", Some(utility_option_name(node.name())));
+            Continue(())
+        })
+    }
+
+    fn visit_select_stmt(&mut self, node: &SelectStmt) -> Flow {
+        self.node("SelectStmt", |fp| fp.select_stmt(node))
+    }
+
+    fn visit_res_target(&mut self, node: &ResTarget) -> Flow {
+        // The names of a `SELECT`'s columns are aliases, so only the value is written.
+        self.node("ResTarget", |fp| fp.field("val", |fp| match node {
+            ResTarget::Star => fp.node("ColumnRef", |fp| fp.field("fields", Self::a_star)),
+            ResTarget::Expr { value, .. } => value.accept(fp),
+        }))
+    }
+
+    fn visit_named_window(&mut self, node: &NamedWindow) -> Flow {
+        self.node("WindowDef", |fp| fp.window_def(node.definition(), Some(node.name())))
+    }
+
+    fn visit_sort_by(&mut self, node: &SortBy) -> Flow {
+        self.node("SortBy", |fp| {
+            fp.field("node", |fp| node.expr().accept(fp))?;
+            fp.enum_value("sortby_dir", sort_by_dir(node));
+            fp.enum_value("sortby_nulls", sort_by_nulls(node));
+            if let Some(SortDirection::Using(QualifiedOperator(schema, op))) = node.direction() {
+                fp.field("useOp", |fp| {
+                    for name in schema {
+                        fp.string_node(name)?;
+                    }
+                    fp.string_node(operator_symbol(op))
+                })?;
+            }
+            Continue(())
+        })
+    }
+
+    fn visit_table_ref(&mut self, node: &TableRef) -> Flow {
+        match node {
+            TableRef::Relation(table_ref) => table_ref.relation().accept(self),
+            TableRef::Join(join) => join.accept(self),
+            TableRef::XmlTable(_) => Break(Unsupported("RangeTableFunc")),
+            TableRef::JsonTable(_) => Break(Unsupported("JsonTable")),
+            TableRef::Rows(rows) => {
+                let functions: Vec<_> = rows.functions()
+                    .iter()
+                    .map(|func| (func.func_call(), func.column_definitions()))
+                    .collect();
+                self.node("RangeFunction", |fp| {
+                    fp.range_function(&functions, rows.alias(), rows.ordinality(), rows.lateral(), true)
+                })
+            },
+            TableRef::Function(func) => {
+                let functions = [(func.function(), None)];
+                self.node("RangeFunction", |fp| {
+                    fp.range_function(&functions, func.alias(), func.ordinality(), func.lateral(), false)
+                })
+            },
+            TableRef::TableSample(sample) => {
+                let Some(table_sample) = sample.table_sample() else {
+                    return sample.relation().accept(self)
+                };
+                self.node("RangeTableSample", |fp| {
+                    fp.exprs("args", table_sample.args())?;
+                    fp.string_nodes("method", table_sample.function_name())?;
+                    fp.field("relation", |fp| sample.relation().accept(fp))?;
+                    fp.opt_expr("repeatable", table_sample.repeatable())
+                })
+            },
+            TableRef::Subselect(subselect) => {
+                self.node("RangeSubselect", |fp| {
+                    fp.bool("lateral", subselect.lateral());
+                    fp.field("subquery", |fp| subselect.select().accept(fp))
+                })
+            },
+            TableRef::GraphTable(_) => Break(Unsupported("RangeGraphTable")),
+        }
+    }
+
+    /// Alias: `relation_expr`
+    fn visit_relation_expr(&mut self, node: &RelationExpr) -> Flow {
+
+        let name = node.name();
+        let schema = name.schema();
+
+        self.node("RangeVar", |fp| {
+            fp.string("catalogname", schema.and_then(SchemaName::catalog));
+            fp.bool("inh", node.inherited());
+            fp.string("relname", Some(&without_numbers(name.name())));
+            fp.enum_value("relpersistence", "p");
+            fp.string("schemaname", schema.map(SchemaName::name));
+            Continue(())
+        })
+    }
+
+    fn visit_join_table_ref(&mut self, node: &JoinTableRef) -> Flow {
+        self.node("JoinExpr", |fp| {
+            fp.bool("isNatural", *node.condition() == JoinCondition::Natural);
+            fp.enum_value("jointype", join_type(node.join_type()));
+            fp.field("larg", |fp| node.left().accept(fp))?;
+            if let JoinCondition::On(quals) = node.condition() {
+                fp.field("quals", |fp| quals.accept(fp))?;
+            }
+            fp.field("rarg", |fp| node.right().accept(fp))?;
+            if let JoinCondition::Using { columns, .. } = node.condition() {
+                fp.string_nodes("usingClause", columns)?;
+            }
+            Continue(())
+        })
+    }
+
+    fn visit_expr_node(&mut self, node: &ExprNode) -> Flow {
+        match node.kind() {
+            NullConst
+            | StringConst(_)
+            | BinaryStringConst(_)
+            | HexStringConst(_)
+            | IntegerConst(_)
+            | NumericConst { .. }
+            | BooleanConst(_)
+            | DefaultExpr
+            | ParamRef { .. } => Continue(()),
+            ExprKind::CaseExpr(case) => case.accept(self),
+            Row(args) => {
+                let row_format = row_format(node.span(), self.source);
+                self.row_expr(args.as_deref().unwrap_or_default(), row_format)
+            },
+            Array(elements) => self.node("A_ArrayExpr", |fp| fp.exprs("elements", elements)),
+            RowCompare(row_compare) => row_compare.accept(self),
+            StringTypecast(typecast) => typecast.accept(self),
+            ExprKind::BinaryExpr(expr) => expr.accept(self),
+            ExprKind::UnaryExpr(expr) => expr.accept(self),
+            ExprKind::BoolExpr(expr) => expr.accept(self),
+            ExprKind::FuncCallExpr(func) => func.accept(self),
+            JsonArrayAggExpr(_) => Break(Unsupported("JsonArrayAgg")),
+            JsonObjectAggExpr(_) => Break(Unsupported("JsonObjectAgg")),
+            Distinct(operands) => self.distinct_expr("AEXPR_DISTINCT", operands),
+            NotDistinct(operands) => self.distinct_expr("AEXPR_NOT_DISTINCT", operands),
+            IsNull(operand) => self.null_test("IS_NULL", operand),
+            IsNotNull(operand) => self.null_test("IS_NOT_NULL", operand),
+            ExprKind::BooleanTest(test) => test.accept(self),
+            Between(expr) => expr.accept(self),
+            SimilarTo(expr) => expr.accept(self),
+            ArrayCompare(expr) => expr.accept(self),
+            // `timezone(zone, expr)`
+            AtTimeZone(operands) => {
+                let (left, zone) = &**operands;
+                self.sql_syntax_call("timezone", &[zone, left])
+            },
+            AtLocal(operand) => self.sql_syntax_call("timezone", &[operand]),
+            Collate(expr) => expr.accept(self),
+            Indirection(expr) => expr.accept(self),
+            Typecast(typecast) => typecast.accept(self),
+            ExprKind::InExpr(expr) => expr.accept(self),
+            ExprKind::SubLink(sub_link) => sub_link.accept(self),
+            ExprKind::ColumnRef(column_ref) => column_ref.accept(self),
+            GroupingFunc(args) => self.node("GroupingFunc", |fp| fp.exprs("args", args)),
+            ExprKind::FuncCall(func) => func.accept(self),
+            ExprKind::SqlFunction(func) => func.accept(self),
+        }
+    }
+
+    fn visit_case_expr(&mut self, node: &CaseExpr) -> Flow {
+        self.node("CaseExpr", |fp| {
+            fp.opt_expr("arg", node.target())?;
+            fp.list("args", node.when_clauses(), |fp, when| when.accept(fp))?;
+            fp.opt_expr("defresult", node.default())
+        })
+    }
+
+    fn visit_case_when(&mut self, node: &CaseWhen) -> Flow {
+        self.node("CaseWhen", |fp| {
+            fp.field("expr", |fp| node.condition().accept(fp))?;
+            fp.field("result", |fp| node.body().accept(fp))
+        })
+    }
+
+    fn visit_row_compare_expr(&mut self, node: &RowCompareExpr) -> Flow {
+        self.a_expr(
+            "AEXPR_OP",
+            (&[], row_compare_operator(node.kind())),
+            |fp| fp.row_expr(node.left(), "COERCE_IMPLICIT_CAST"),
+            |fp| fp.row_expr(node.right(), "COERCE_IMPLICIT_CAST")
+        )
+    }
+
+    fn visit_string_typecast_expr(&mut self, node: &StringTypecastExpr) -> Flow {
+        // The argument is a constant.
+        self.node("TypeCast", |fp| fp.field("typeName", |fp| node.type_name().accept(fp)))
+    }
+
+    fn visit_typecast_expr(&mut self, node: &TypecastExpr) -> Flow {
+        self.node("TypeCast", |fp| {
+            fp.field("arg", |fp| node.arg().accept(fp))?;
+            fp.field("typeName", |fp| node.type_name().accept(fp))
+        })
+    }
+
+    fn visit_binary_expr(&mut self, node: &BinaryExpr) -> Flow {
+        let QualifiedOperator(schema, op) = node.operator();
+        self.a_expr(
+            binary_expr_kind(node.operator()),
+            (schema, operator_symbol(op)),
+            |fp| node.left_operand().accept(fp),
+            |fp| node.right_operand().accept(fp)
+        )
+    }
+
+    fn visit_unary_expr(&mut self, node: &UnaryExpr) -> Flow {
+        let QualifiedOperator(schema, op) = node.operator();
+        self.a_expr(
+            "AEXPR_OP",
+            (schema, operator_symbol(op)),
+            |_| Continue(()),
+            |fp| node.operand().accept(fp)
+        )
+    }
+
+    fn visit_bool_expr(&mut self, node: &BoolExpr) -> Flow {
+        match node {
+            BoolExpr::Not(operand) => {
+                self.node("BoolExpr", |fp| {
+                    fp.list("args", slice::from_ref(operand), |fp, arg| arg.accept(fp))?;
+                    fp.enum_value("boolop", "NOT_EXPR");
+                    Continue(())
+                })
+            },
+            BoolExpr::And(operands) => self.bool_expr("AND_EXPR", operands),
+            BoolExpr::Or(operands) => self.bool_expr("OR_EXPR", operands),
+        }
+    }
+
+    fn visit_in_expr(&mut self, node: &InExpr) -> Flow {
+        let symbol = if node.not() { "<>" } else { "=" };
+        // `IN` has the same shape as `= ANY`, so its kind is `AEXPR_OP`.
+        self.a_expr(
+            "AEXPR_OP",
+            (&[], symbol),
+            |fp| node.expr().accept(fp),
+            |fp| fp.items("rexpr", node.list(), |fp, expr| expr.accept(fp))
+        )
+    }
+
+    fn visit_boolean_test(&mut self, node: &BooleanTest) -> Flow {
+        self.node("BooleanTest", |fp| {
+            fp.field("arg", |fp| node.expr().accept(fp))?;
+            fp.enum_value("booltesttype", bool_test_type(node.kind()));
+            Continue(())
+        })
+    }
+
+    fn visit_between_expr(&mut self, node: &BetweenExpr) -> Flow {
+        let (kind, name) = between_kind(node);
+        self.a_expr(
+            kind,
+            (&[], name),
+            |fp| node.expr().accept(fp),
+            |fp| fp.items("rexpr", &[node.low(), node.high()], |fp, expr| expr.accept(fp))
+        )
+    }
+
+    fn visit_similar_to_expr(&mut self, node: &SimilarToExpr) -> Flow {
+        let symbol = if node.not() { "!~" } else { "~" };
+        self.a_expr(
+            "AEXPR_SIMILAR",
+            (&[], symbol),
+            |fp| node.expr().accept(fp),
+            // PG wraps the pattern in `similar_to_escape()`.
+            |fp| fp.node("FuncCall", |fp| {
+                let args: Vec<_> = iter::once(node.pattern()).chain(node.escape()).collect();
+                fp.list("args", &args, |fp, arg| arg.accept(fp))?;
+                fp.enum_value("funcformat", "COERCE_EXPLICIT_CALL");
+                fp.string_nodes("funcname", &["pg_catalog", "similar_to_escape"])
+            })
+        )
+    }
+
+    fn visit_array_compare_expr(&mut self, node: &ArrayCompareExpr) -> Flow {
+        let QualifiedOperator(schema, op) = node.operator();
+        // Like `IN`, `= ANY` has the kind `AEXPR_OP`.
+        let kind = match node.kind() {
+            ArrayCompareKind::Any => "AEXPR_OP",
+            ArrayCompareKind::All => "AEXPR_OP_ALL",
+        };
+        self.a_expr(
+            kind,
+            (schema, operator_symbol(op)),
+            |fp| node.left().accept(fp),
+            |fp| node.right().accept(fp)
+        )
+    }
+
+    fn visit_collate_expr(&mut self, node: &CollateExpr) -> Flow {
+        self.node("CollateClause", |fp| {
+            fp.field("arg", |fp| node.expr().accept(fp))?;
+            fp.string_nodes("collname", node.collation())
+        })
+    }
+
+    fn visit_sub_link(&mut self, node: &SubLink) -> Flow {
+        self.node("SubLink", |fp| {
+            if let Some(QualifiedOperator(schema, op)) = node.operator() {
+                fp.field("operName", |fp| {
+                    for name in schema {
+                        fp.string_node(name)?;
+                    }
+                    fp.string_node(operator_symbol(op))
+                })?;
+            }
+            fp.enum_value("subLinkType", sub_link_type(node.kind()));
+            fp.field("subselect", |fp| node.subselect().accept(fp))?;
+            fp.opt_expr("testexpr", node.test_expr())
+        })
+    }
+
+    fn visit_column_ref(&mut self, node: &ColumnRef) -> Flow {
+
+        let (name, fields, subscripts) = column_ref_parts(node);
+
+        let column_ref = |fp: &mut Self| fp.node("ColumnRef", |fp| fp.field("fields", |fp| {
+            for name in name {
+                fp.string_node(name)?;
+            }
+            fp.items("fields", fields, |fp, el| el.accept(fp))
+        }));
+
+        if subscripts.is_empty() {
+            return column_ref(self)
+        }
+
+        self.node("A_Indirection", |fp| {
+            fp.field("arg", column_ref)?;
+            fp.list("indirection", subscripts, |fp, el| el.accept(fp))
+        })
+    }
+
+    fn visit_indirection(&mut self, node: &Indirection) -> Flow {
+        match node {
+            Indirection::Wildcard => self.a_star(),
+            Indirection::Property(name) => self.string_node(name),
+            Indirection::Index(index) => self.node("A_Indices", |fp| fp.field("uidx", |fp| index.accept(fp))),
+            Indirection::Slice(lower, upper) => {
+                self.node("A_Indices", |fp| {
+                    fp.bool("is_slice", true);
+                    fp.opt_expr("lidx", lower.as_ref())?;
+                    fp.opt_expr("uidx", upper.as_ref())
+                })
+            },
+        }
+    }
+
+    fn visit_indirection_expr(&mut self, node: &IndirectionExpr) -> Flow {
+        self.node("A_Indirection", |fp| {
+            fp.field("arg", |fp| node.expr().accept(fp))?;
+            fp.list("indirection", node.indirection(), |fp, el| el.accept(fp))
+        })
+    }
+
+    fn visit_func_call(&mut self, node: &FuncCall) -> Flow {
+        self.func_call(node, None, None)
+    }
+
+    fn visit_func_call_expr(&mut self, node: &FuncCallExpr) -> Flow {
+
+        if node.null_treatment().is_some() {
+            return Break(Unsupported("FuncCall.null_treatment"))
+        }
+
+        self.func_call(node.func(), node.agg_filter(), node.over())
+    }
+
+    /// Alias: `func_arg_expr`
+    fn visit_named_value(&mut self, node: &NamedValue) -> Flow {
+
+        let Some(name) = node.name() else {
+            return node.value().accept(self)
+        };
+
+        self.node("NamedArgExpr", |fp| {
+            fp.field("arg", |fp| node.value().accept(fp))?;
+            fp.int("argnumber", -1);
+            fp.string("name", Some(name));
+            Continue(())
+        })
+    }
+
+    /// Alias: `func_expr_common_subexpr`
+    fn visit_sql_function(&mut self, node: &SqlFunction) -> Flow {
+        match node {
+            SqlFunction::Coalesce(args) => self.node("CoalesceExpr", |fp| fp.exprs("args", args)),
+            SqlFunction::CollationFor(arg) => self.sql_syntax_call("pg_collation_for", &[arg]),
+            SqlFunction::CurrentCatalog => self.sql_value_function("SVFOP_CURRENT_CATALOG", None),
+            SqlFunction::CurrentSchema => self.sql_value_function("SVFOP_CURRENT_SCHEMA", None),
+            SqlFunction::Greatest(args) => self.min_max("IS_GREATEST", args),
+            SqlFunction::Least(args) => self.min_max("IS_LEAST", args),
+            SqlFunction::MergeAction => {
+                self.node("MergeSupportFunc", |fp| {
+                    fp.int("msftype", TEXTOID);
+                    Continue(())
+                })
+            },
+            SqlFunction::NullIf(left, right) => {
+                self.a_expr("AEXPR_NULLIF", (&[], "="), |fp| left.accept(fp), |fp| right.accept(fp))
+            },
+            SqlFunction::Treat(typecast) => {
+                // PG converts it into a call to the type's function, e.g.: `pg_catalog.int4(arg)`.
+                let names = qualified_type_name(typecast.type_name().name());
+                let name = names.last().copied().unwrap_or_default();
+                self.node("FuncCall", |fp| {
+                    fp.list("args", slice::from_ref(typecast.arg()), |fp, arg| arg.accept(fp))?;
+                    fp.enum_value("funcformat", "COERCE_EXPLICIT_CALL");
+                    fp.string_nodes("funcname", &["pg_catalog", name])
+                })
+            },
+            SqlFunction::Typecast(typecast) => typecast.accept(self),
+            // The normal form is a constant.
+            SqlFunction::Normalize(func) => self.sql_syntax_call("normalize", &[func.expr()]),
+            SqlFunction::Overlay(OverlayFunc::ExplicitCall(args)) => self.explicit_call("overlay", args.as_deref()),
+            SqlFunction::Overlay(OverlayFunc::SqlSyntax(args)) => {
+                let mut exprs = vec![args.arg(), args.placing(), args.from()];
+                exprs.extend(args.for_expr());
+                self.sql_syntax_call("overlay", &exprs)
+            },
+            SqlFunction::Position(func) => self.sql_syntax_call("position", &[func.haystack(), func.needle()]),
+            SqlFunction::Substring(SubstringFunc::ExplicitCall(args)) => {
+                self.explicit_call("substring", args.as_deref())
+            },
+            SqlFunction::Substring(SubstringFunc::SqlSyntax(arg, from, for_expr)) => {
+                let mut args = vec![arg, from];
+                args.extend(for_expr);
+                self.sql_syntax_call("substring", &args)
+            },
+            SqlFunction::Trim(func) => {
+                let name = match func.trim_side() {
+                    TrimSide::Both => "btrim",
+                    TrimSide::Leading => "ltrim",
+                    TrimSide::Trailing => "rtrim",
+                };
+                let args: Vec<_> = func.args().iter().collect();
+                self.sql_syntax_call(name, &args)
+            },
+            SqlFunction::CurrentDate => self.sql_value_function("SVFOP_CURRENT_DATE", None),
+            SqlFunction::CurrentTime { precision: None } => self.sql_value_function("SVFOP_CURRENT_TIME", None),
+            SqlFunction::CurrentTime { precision } => self.sql_value_function("SVFOP_CURRENT_TIME_N", *precision),
+            SqlFunction::CurrentTimestamp { precision: None } => {
+                self.sql_value_function("SVFOP_CURRENT_TIMESTAMP", None)
+            },
+            SqlFunction::CurrentTimestamp { precision } => {
+                self.sql_value_function("SVFOP_CURRENT_TIMESTAMP_N", *precision)
+            },
+            SqlFunction::LocalTime { precision: None } => self.sql_value_function("SVFOP_LOCALTIME", None),
+            SqlFunction::LocalTime { precision } => self.sql_value_function("SVFOP_LOCALTIME_N", *precision),
+            SqlFunction::LocalTimestamp { precision: None } => {
+                self.sql_value_function("SVFOP_LOCALTIMESTAMP", None)
+            },
+            SqlFunction::LocalTimestamp { precision } => {
+                self.sql_value_function("SVFOP_LOCALTIMESTAMP_N", *precision)
+            },
+            // The field is a constant.
+            SqlFunction::Extract(func) => self.sql_syntax_call("extract", &[func.target()]),
+            SqlFunction::CurrentRole => self.sql_value_function("SVFOP_CURRENT_ROLE", None),
+            SqlFunction::CurrentUser => self.sql_value_function("SVFOP_CURRENT_USER", None),
+            SqlFunction::SessionUser => self.sql_value_function("SVFOP_SESSION_USER", None),
+            SqlFunction::SystemUser => self.sql_syntax_call("system_user", &[]),
+            SqlFunction::User => self.sql_value_function("SVFOP_USER", None),
+            SqlFunction::Json(_) => Break(Unsupported("JsonParseExpr")),
+            SqlFunction::JsonArrayAgg(_) => Break(Unsupported("JsonArrayAgg")),
+            SqlFunction::JsonExists(_)
+            | SqlFunction::JsonQuery(_)
+            | SqlFunction::JsonValue(_) => Break(Unsupported("JsonFuncExpr")),
+            SqlFunction::JsonObject(_) => Break(Unsupported("JsonObjectConstructor")),
+            SqlFunction::JsonObjectAgg(_) => Break(Unsupported("JsonObjectAgg")),
+            SqlFunction::JsonScalar(_) => Break(Unsupported("JsonScalarExpr")),
+            SqlFunction::JsonSerialize(_) => Break(Unsupported("JsonSerializeExpr")),
+            SqlFunction::XmlSerialize(_) => Break(Unsupported("XmlSerialize")),
+            SqlFunction::XmlConcat(_)
+            | SqlFunction::XmlElement(_)
+            | SqlFunction::XmlExists(_)
+            | SqlFunction::XmlForest(_)
+            | SqlFunction::XmlParse(_)
+            | SqlFunction::XmlProcessingInstruction(_)
+            | SqlFunction::XmlRoot(_) => Break(Unsupported("XmlExpr")),
+        }
+    }
+
+    /// Alias: `Typename`
+    ///
+    /// The fields of a `TypeName`, which is never a list item, so it doesn't have the node name.
+    fn visit_type(&mut self, node: &Type) -> Flow {
+
+        let type_oid = match node.name() {
+            TypeName::Oid(oid) => *oid,
+            _ => 0,
+        };
+
+        // The modifiers of the other types are constants.
+        let typmods = match node.name() {
+            TypeName::Numeric(typmods)
+            | TypeName::Varbit(typmods)
+            | TypeName::Generic { type_modifiers: typmods, .. } => typmods.as_deref().unwrap_or_default(),
+            TypeName::Bit(Some(typmods)) => typmods,
+            _ => &[],
+        };
+
+        self.fields(|fp| {
+            fp.list("arrayBounds", node.array_bounds().unwrap_or_default(), |fp, bound| {
+                fp.integer_node(bound.unwrap_or(-1).into())
+            })?;
+            fp.string_nodes("names", &qualified_type_name(node.name()))?;
+            fp.bool("setof", node.mult() == SetOf::Table);
+            fp.int("typeOid", type_oid.into());
+            fp.int("typemod", -1);
+            fp.exprs("typmods", typmods)
+        })
+    }
+}
+
+fn drop_behavior(behavior: DropBehavior) -> &'static str {
+    match behavior {
+        DropBehavior::Restrict => "DROP_RESTRICT",
+        DropBehavior::Cascade => "DROP_CASCADE",
+    }
+}
+
+/// Removes numbers from table names, e.g., of partitions like `events_20240101`, but keeps single digits.
+fn without_numbers(relname: &str) -> Cow<'_, str> {
+
+    let digits: Vec<bool> = relname.chars().map(|c| c.is_ascii_digit()).collect();
+    let in_number = |index: usize| {
+        digits[index]
+            && (digits.get(index + 1) == Some(&true) || (index > 0 && digits[index - 1]))
+    };
+
+    if !(0..digits.len()).any(in_number) {
+        return relname.into()
+    }
+
+    relname.chars()
+        .enumerate()
+        .filter(|(index, _)| !in_number(*index))
+        .map(|(_, c)| c)
+        .collect::<String>()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    // Fingerprints from libpg_query.
+    #[test_case("SELECT 1", "50fde20626009aba")]
+    #[test_case("SELECT 2", "50fde20626009aba")]
+    #[test_case("SELECT $1", "50fde20626009aba")]
+    #[test_case("SELECT 1; SELECT a FROM b", "3efa3b10d558d06d")]
+    #[test_case("SELECT b AS x, a AS y FROM z", "1a8bf5d7614de3a5")]
+    #[test_case("SELECT * FROM x WHERE y = $1", "4ff39426bd074231")]
+    #[test_case("SELECT * FROM x WHERE y IN ($1)", "4ff39426bd074231")]
+    #[test_case("SELECT * FROM x WHERE y IN ($1, $2, $3)", "4ff39426bd074231")]
+    #[test_case("SELECT * FROM x AS a, y AS b", "4e9acae841dae228")]
+    #[test_case("SELECT * FROM y AS a, x AS b", "4e9acae841dae228")]
+    #[test_case("SELECT x AS a, y AS b FROM x", "65dff5f5e9a643ad")]
+    #[test_case("SELECT y AS a, x AS b FROM x", "65dff5f5e9a643ad")]
+    #[test_case("SELECT x, y FROM z", "330267237da5535f")]
+    #[test_case("SELECT y, x FROM z", "330267237da5535f")]
+    #[test_case("SELECT * FROM a", "fcf44da7b597ef43")]
+    #[test_case("SELECT * FROM a AS b", "fcf44da7b597ef43")]
+    #[test_case("DEALLOCATE a123", "d8a65a814fbc5f95")]
+    #[test_case("DEALLOCATE ALL", "2debfb8745df64a7")]
+    #[test_case("SAVEPOINT some_id", "8ebd566ea1bf947b")]
+    #[test_case("RELEASE some_id", "60d618658252d2af")]
+    #[test_case("PREPARE TRANSACTION 'some_id'", "d993959a33d627d4")]
+    #[test_case("START TRANSACTION READ WRITE", "4ca25828c835d55a")]
+    #[test_case("CLOSE cursor_123", "2c7963684fc2bad9")]
+    #[test_case("-- nothing", "d8d13f8b2da6c9ad")]
+    fn test_fingerprint(source: &str, expected: &str) {
+        let actual = fingerprint(source, ParserConfig::default()).unwrap();
+        assert_eq!(expected, actual.to_string());
+    }
+
+    /// `PREPARE` isn't parsed yet, so its statement is built around the parsed query.
+    #[test]
+    fn test_prepare_stmt() {
+        // Fingerprint from libpg_query, of `PREPARE a123 AS SELECT a`.
+        let source = "SELECT a";
+        let query = Parser::new(source, ParserConfig::default()).parse().result.remove(0).unwrap();
+        let stmt = RawStmt::from(PrepareStmt::new("a123".into(), Vec::new(), query.into_source()));
+
+        let mut fingerprinter = Fingerprinter::new(source);
+        assert_eq!(Continue(()), stmt.accept(&mut fingerprinter));
+        assert_eq!("9b5e6ead8be993e8", Fingerprint(hash(&fingerprinter.tokens)).to_string());
+    }
+
+    // libpg_query doesn't have fingerprints for the DDL that's parsed, so these are the tokens of its parse tree.
+    #[test_case(
+        "drop property graph if exists g1, s.g2 cascade",
+        &["DropStmt", "behavior", "DROP_CASCADE", "missing_ok", "true",
+          "objects", "String", "str", "g1", "String", "str", "s", "String", "str", "g2",
+          "removeType", "OBJECT_PROPGRAPH"]
+    )]
+    #[test_case(
+        "create extension if not exists e schema s cascade",
+        &["CreateExtensionStmt", "extname", "e", "if_not_exists", "true", "options",
+          "DefElem", "arg", "String", "str", "s", "defaction", "DEFELEM_UNSPEC", "defname", "schema",
+          "DefElem", "arg", "Boolean", "boolval", "true", "defaction", "DEFELEM_UNSPEC", "defname", "cascade"]
+    )]
+    fn test_ddl_tokens(source: &str, expected: &[&str]) {
+        let stmt = Parser::new(source, ParserConfig::default()).parse().result.remove(0).unwrap();
+
+        let mut fingerprinter = Fingerprinter::new(source);
+        assert_eq!(Continue(()), stmt.source().accept(&mut fingerprinter));
+
+        let expected: Vec<_> = ["RawStmt", "stmt"].iter().chain(expected).collect();
+        assert_eq!(expected, fingerprinter.tokens.iter().collect::<Vec<_>>());
+    }
+
+    #[test_case("select 1", "select 2")]
+    #[test_case("select 1", "select 'a'")]
+    #[test_case("select $1", "select $2")]
+    #[test_case("select a, b from t, u", "select b, a from u, t")]
+    #[test_case("select a as x from t as y", "select a from t")]
+    #[test_case("select a from t where b in (1, 2, 3)", "select a from t where b in (4)")]
+    #[test_case("values (1, 2), (3, 4)", "values (5, 6)")]
+    #[test_case("select a from events_20240101", "select a from events_20240102")]
+    #[test_case("deallocate p", "deallocate q")]
+    #[test_case("savepoint s; rollback to s", "savepoint t; rollback to t")]
+    #[test_case("select a between 1 and 2", "select a between 3 and 4")]
+    #[test_case("select a like 'x' escape '!'", "select a like 'y' escape '#'")]
+    #[test_case("drop property graph g", "drop property graph g restrict")]
+    fn test_same(left: &str, right: &str) {
+        assert_eq!(
+            fingerprint(left, ParserConfig::default()),
+            fingerprint(right, ParserConfig::default())
+        );
+    }
+
+    #[test_case("select a from t", "select a from u")]
+    #[test_case("select a from t", "select b from t")]
+    #[test_case("select 1", "select 1; select 1")]
+    #[test_case("select a from t1", "select a from t2")]
+    #[test_case("select distinct a from t", "select a from t")]
+    #[test_case("select a from t where b = 1", "select a from t where b < 1")]
+    #[test_case("select a is null", "select a is not null")]
+    #[test_case("select a between 1 and 2", "select a not between 1 and 2")]
+    #[test_case("select a = any(b)", "select a = all(b)")]
+    #[test_case("drop property graph g", "drop property graph if exists g")]
+    #[test_case("drop property graph g", "drop property graph g cascade")]
+    #[test_case("create extension e", "create extension f")]
+    #[test_case("create extension e", "create extension e cascade")]
+    // Unlike constants, the version is a `String` node.
+    #[test_case("create extension e version '1.0'", "create extension e version '1.1'")]
+    fn test_different(left: &str, right: &str) {
+        assert_ne!(
+            fingerprint(left, ParserConfig::default()).unwrap(),
+            fingerprint(right, ParserConfig::default()).unwrap()
+        );
+    }
+
+    #[test_case("events_20240101", "events_")]
+    #[test_case("t1", "t1")]
+    #[test_case("a1b22c333", "a1bc")]
+    fn test_without_numbers(relname: &str, expected: &str) {
+        assert_eq!(expected, without_numbers(relname));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("00000000000000ff", Fingerprint(0xff).to_string());
+    }
+}
+
+use crate::between_kind;
+use crate::binary_expr_kind;
+use crate::bool_test_type;
+use crate::column_ref_parts;
+use crate::func::frame_options;
+use crate::func::sort_by_dir;
+use crate::func::sort_by_nulls;
+use crate::func::FRAMEOPTION_DEFAULTS;
+use crate::func::TEXTOID;
+use crate::numeric_text;
+use crate::operator_symbol;
+use crate::qualified_type_name;
+use crate::row_compare_operator;
+use crate::row_format;
+use crate::select::limit_option;
+use crate::select::set_operation;
+use crate::stmt::discard_target;
+use crate::stmt::node_name;
+use crate::stmt::one;
+use crate::stmt::set_rest_kind;
+use crate::stmt::transaction_kind;
+use crate::stmt::transaction_mode_name;
+use crate::stmt::utility_option_name;
+use crate::stmt::variable_name;
+use crate::sub_link_type;
+use crate::table_ref::join_type;
+use crate::Error;
+use crate::Error::Unsupported;
+use crate::Result;
+use alloc::borrow::Cow;
+use core::iter;
+use core::mem;
+use core::ops::ControlFlow;
+use core::ops::ControlFlow::Break;
+use core::ops::ControlFlow::Continue;
+use core::slice;
+use derive_more::Display;
+use pg_ast::Accept;
+use pg_ast::ArrayCompareExpr;
+use pg_ast::ArrayCompareKind;
+use pg_ast::BetweenExpr;
+use pg_ast::BinaryExpr;
+use pg_ast::BinaryOperands;
+use pg_ast::BoolExpr;
+use pg_ast::BooleanTest;
+use pg_ast::CaseExpr;
+use pg_ast::CaseWhen;
+use pg_ast::CollateExpr;
+use pg_ast::ColumnRef;
+use pg_ast::CreateExtensionOption;
+use pg_ast::CreateExtensionStmt;
+use pg_ast::DiscardStmt;
+use pg_ast::DistinctClause;
+use pg_ast::DropBehavior;
+use pg_ast::DropStmt;
+use pg_ast::DropTarget;
+use pg_ast::ExprKind;
+use pg_ast::ExprKind::*;
+use pg_ast::ExprNode;
+use pg_ast::FuncAlias;
+use pg_ast::FuncArgsKind;
+use pg_ast::FuncArgsOrder;
+use pg_ast::FuncCall;
+use pg_ast::FuncCallExpr;
+use pg_ast::FuncExprWindowless;
+use pg_ast::InExpr;
+use pg_ast::Indirection;
+use pg_ast::IndirectionExpr;
+use pg_ast::JoinCondition;
+use pg_ast::JoinTableRef;
+use pg_ast::NamedValue;
+use pg_ast::NamedWindow;
+use pg_ast::NotifyStmt;
+use pg_ast::OneOrAll;
+use pg_ast::OneOrBoth;
+use pg_ast::OverClause;
+use pg_ast::OverlayFunc;
+use pg_ast::PrepareStmt;
+use pg_ast::Presence;
+use pg_ast::QualifiedOperator;
+use pg_ast::RawStmt;
+use pg_ast::RelationExpr;
+use pg_ast::ResTarget;
+use pg_ast::RowCompareExpr;
+use pg_ast::SchemaName;
+use pg_ast::SelectBody;
+use pg_ast::SelectStmt;
+use pg_ast::SetOf;
+use pg_ast::SetRest;
+use pg_ast::SignedNumber;
+use pg_ast::SimilarToExpr;
+use pg_ast::SimpleColumnDefinition;
+use pg_ast::SortBy;
+use pg_ast::SortDirection;
+use pg_ast::SqlFunction;
+use pg_ast::StringTypecastExpr;
+use pg_ast::SubLink;
+use pg_ast::SubstringFunc;
+use pg_ast::TableRef;
+use pg_ast::TransactionMode;
+use pg_ast::TransactionStmt;
+use pg_ast::TrimSide;
+use pg_ast::Type;
+use pg_ast::TypeName;
+use pg_ast::TypecastExpr;
+use pg_ast::UnaryExpr;
+use pg_ast::UtilityOption;
+use pg_ast::VarValue;
+use pg_ast::VariableSetStmt;
+use pg_ast::VariableTarget;
+use pg_ast::Visit;
+use pg_ast::WindowDefinition;
+use pg_ast::ZoneValue;
+use pg_basics::Located;
+use pg_basics::Str;
+use pg_elog::LocatedResult;
+use pg_parser::Parser;
+use pg_parser_core::ParserConfig;
+use xxhash_rust::xxh3::Xxh3;
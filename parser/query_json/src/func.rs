@@ -0,0 +1,569 @@
+impl Converter<'_> {
+
+    /// Alias: `func_expr`
+    pub fn func_call_expr(&self, func: &FuncCallExpr, location: Option<i64>) -> Result {
+
+        if func.null_treatment().is_some() {
+            return Err(Unsupported("FuncCall.null_treatment"))
+        }
+
+        let over = match func.over() {
+            Some(OverClause::WindowName(name)) => {
+                let node = Fields::new()
+                    .string("name", Some(name))
+                    .int("frameOptions", FRAMEOPTION_DEFAULTS)
+                    .into_value();
+                Some(node)
+            },
+            Some(OverClause::WindowDefinition(definition)) => Some(self.window_def(definition)?.into_value()),
+            None => None,
+        };
+
+        let node = self.func_call(func.func())?
+            .node("agg_filter", self.opt_expr(func.agg_filter())?)
+            .node("over", over)
+            .location(location)
+            .into_node("FuncCall");
+        Ok(node)
+    }
+
+    /// Alias: `func_application`
+    ///
+    /// The fields of a `FuncCall`, without its location.
+    pub fn func_call(&self, func: &FuncCall) -> Result<Fields> {
+
+        let mut star = false;
+        let mut distinct = false;
+        let mut variadic = false;
+
+        let (args, order, within_group) = match func.args() {
+            FuncArgsKind::Empty { order_within_group } => (vec![], order_within_group.as_deref(), true),
+            FuncArgsKind::Wildcard { order_within_group } => {
+                star = true;
+                (vec![], order_within_group.as_deref(), true)
+            },
+            FuncArgsKind::All { args, order } => {
+                let args = args.iter()
+                    .map(|Located(arg, loc)| self.func_arg(arg, Some(loc.range().start.into())))
+                    .collect::<Result<_>>()?;
+                match order.as_ref().map(Located::source) {
+                    Some(FuncArgsOrder::OrderBy(order)) => (args, Some(&order[..]), false),
+                    Some(FuncArgsOrder::WithinGroup(order)) => (args, Some(&order[..]), true),
+                    None => (args, None, false),
+                }
+            },
+            FuncArgsKind::Variadic { args, order } => {
+                variadic = true;
+                (self.func_args(args)?, order.as_deref(), false)
+            },
+            FuncArgsKind::Distinct { args, order } => {
+                distinct = true;
+                (self.func_args(args)?, order.as_deref(), false)
+            },
+        };
+
+        let fields = Fields::new()
+            .list("funcname", string_nodes(func.name()))
+            .list("args", args)
+            .list("agg_order", self.sort_clause(order.unwrap_or_default())?)
+            .bool("agg_within_group", within_group && order.is_some())
+            .bool("agg_star", star)
+            .bool("agg_distinct", distinct)
+            .bool("func_variadic", variadic)
+            .enum_value("funcformat", "COERCE_EXPLICIT_CALL");
+        Ok(fields)
+    }
+
+    /// Alias: `func_expr_common_subexpr`
+    pub fn sql_function(&self, func: &SqlFunction, location: Option<i64>) -> Result {
+
+        let node = match func {
+            SqlFunction::Coalesce(args) => {
+                Fields::new()
+                    .list("args", self.exprs(args)?)
+                    .location(location)
+                    .into_node("CoalesceExpr")
+            },
+            SqlFunction::CollationFor(arg) => {
+                sql_syntax_call("pg_collation_for", vec![self.expr(arg)?], location)
+            },
+            SqlFunction::CurrentCatalog => sql_value_function("SVFOP_CURRENT_CATALOG", None, location),
+            SqlFunction::CurrentSchema => sql_value_function("SVFOP_CURRENT_SCHEMA", None, location),
+            SqlFunction::Greatest(args) => self.min_max("IS_GREATEST", args, location)?,
+            SqlFunction::Least(args) => self.min_max("IS_LEAST", args, location)?,
+            SqlFunction::MergeAction => {
+                Fields::new()
+                    .int("msftype", TEXTOID)
+                    .location(location)
+                    .into_node("MergeSupportFunc")
+            },
+            SqlFunction::NullIf(left, right) => {
+                a_expr(
+                    "AEXPR_NULLIF",
+                    vec![string_node("=")],
+                    Some(self.expr(left)?),
+                    Some(self.expr(right)?),
+                    location
+                )
+            },
+            SqlFunction::Treat(typecast) => {
+                // PG converts it into a call to the type's function, e.g.: `pg_catalog.int4(arg)`.
+                let names = type_names(typecast.type_name().name());
+                let name = names.last().cloned().unwrap_or_default();
+                Fields::new()
+                    .list("funcname", vec![string_node("pg_catalog"), name])
+                    .list("args", vec![self.expr(typecast.arg())?])
+                    .enum_value("funcformat", "COERCE_EXPLICIT_CALL")
+                    .location(location)
+                    .into_node("FuncCall")
+            },
+            SqlFunction::Typecast(typecast) => self.typecast(typecast, location)?,
+            SqlFunction::Normalize(func) => {
+                let mut args = vec![self.expr(func.expr())?];
+                if let Some(normal_form) = func.normal_form() {
+                    let normal_form = match normal_form {
+                        UnicodeNormalForm::CanonicalComposition => "NFC",
+                        UnicodeNormalForm::CanonicalDecomposition => "NFD",
+                        UnicodeNormalForm::CompatibilityComposition => "NFKC",
+                        UnicodeNormalForm::CompatibilityDecomposition => "NFKD",
+                    };
+                    args.push(string_const(normal_form, None));
+                }
+                sql_syntax_call("normalize", args, location)
+            },
+            SqlFunction::Overlay(OverlayFunc::ExplicitCall(args)) => {
+                self.explicit_call("overlay", args.as_deref(), location)?
+            },
+            SqlFunction::Overlay(OverlayFunc::SqlSyntax(args)) => {
+                let mut exprs = vec![
+                    self.expr(args.arg())?,
+                    self.expr(args.placing())?,
+                    self.expr(args.from())?,
+                ];
+                if let Some(for_expr) = args.for_expr() {
+                    exprs.push(self.expr(for_expr)?);
+                }
+                sql_syntax_call("overlay", exprs, location)
+            },
+            SqlFunction::Position(func) => {
+                // The arguments are swapped: `POSITION(needle IN haystack)` is `position(haystack, needle)`.
+                let args = vec![self.expr(func.haystack())?, self.expr(func.needle())?];
+                sql_syntax_call("position", args, location)
+            },
+            SqlFunction::Substring(SubstringFunc::ExplicitCall(args)) => {
+                self.explicit_call("substring", args.as_deref(), location)?
+            },
+            SqlFunction::Substring(SubstringFunc::SqlSyntax(arg, from, for_expr)) => {
+                let mut args = vec![self.expr(arg)?, self.expr(from)?];
+                if let Some(for_expr) = for_expr {
+                    args.push(self.expr(for_expr)?);
+                }
+                sql_syntax_call("substring", args, location)
+            },
+            SqlFunction::Trim(func) => {
+                let name = match func.trim_side() {
+                    TrimSide::Both => "btrim",
+                    TrimSide::Leading => "ltrim",
+                    TrimSide::Trailing => "rtrim",
+                };
+                sql_syntax_call(name, self.exprs(func.args())?, location)
+            },
+            SqlFunction::CurrentDate => sql_value_function("SVFOP_CURRENT_DATE", None, location),
+            SqlFunction::CurrentTime { precision: None } => {
+                sql_value_function("SVFOP_CURRENT_TIME", None, location)
+            },
+            SqlFunction::CurrentTime { precision } => {
+                sql_value_function("SVFOP_CURRENT_TIME_N", *precision, location)
+            },
+            SqlFunction::CurrentTimestamp { precision: None } => {
+                sql_value_function("SVFOP_CURRENT_TIMESTAMP", None, location)
+            },
+            SqlFunction::CurrentTimestamp { precision } => {
+                sql_value_function("SVFOP_CURRENT_TIMESTAMP_N", *precision, location)
+            },
+            SqlFunction::LocalTime { precision: None } => {
+                sql_value_function("SVFOP_LOCALTIME", None, location)
+            },
+            SqlFunction::LocalTime { precision } => {
+                sql_value_function("SVFOP_LOCALTIME_N", *precision, location)
+            },
+            SqlFunction::LocalTimestamp { precision: None } => {
+                sql_value_function("SVFOP_LOCALTIMESTAMP", None, location)
+            },
+            SqlFunction::LocalTimestamp { precision } => {
+                sql_value_function("SVFOP_LOCALTIMESTAMP_N", *precision, location)
+            },
+            SqlFunction::Extract(func) => {
+                let field = match func.field() {
+                    ExtractArg::Year => "year",
+                    ExtractArg::Month => "month",
+                    ExtractArg::Day => "day",
+                    ExtractArg::Hour => "hour",
+                    ExtractArg::Minute => "minute",
+                    ExtractArg::Second => "second",
+                    ExtractArg::Named(field) => field,
+                };
+                let args = vec![string_const(field, None), self.expr(func.target())?];
+                sql_syntax_call("extract", args, location)
+            },
+            SqlFunction::CurrentRole => sql_value_function("SVFOP_CURRENT_ROLE", None, location),
+            SqlFunction::CurrentUser => sql_value_function("SVFOP_CURRENT_USER", None, location),
+            SqlFunction::SessionUser => sql_value_function("SVFOP_SESSION_USER", None, location),
+            SqlFunction::SystemUser => sql_syntax_call("system_user", vec![], location),
+            SqlFunction::User => sql_value_function("SVFOP_USER", None, location),
+            SqlFunction::Json(_) => return Err(Unsupported("JsonParseExpr")),
+            SqlFunction::JsonArrayAgg(_) => return Err(Unsupported("JsonArrayAgg")),
+            SqlFunction::JsonExists(_)
+            | SqlFunction::JsonQuery(_)
+            | SqlFunction::JsonValue(_) => return Err(Unsupported("JsonFuncExpr")),
+            SqlFunction::JsonObject(_) => return Err(Unsupported("JsonObjectConstructor")),
+            SqlFunction::JsonObjectAgg(_) => return Err(Unsupported("JsonObjectAgg")),
+            SqlFunction::JsonScalar(_) => return Err(Unsupported("JsonScalarExpr")),
+            SqlFunction::JsonSerialize(_) => return Err(Unsupported("JsonSerializeExpr")),
+            SqlFunction::XmlSerialize(_) => return Err(Unsupported("XmlSerialize")),
+            SqlFunction::XmlConcat(_)
+            | SqlFunction::XmlElement(_)
+            | SqlFunction::XmlExists(_)
+            | SqlFunction::XmlForest(_)
+            | SqlFunction::XmlParse(_)
+            | SqlFunction::XmlProcessingInstruction(_)
+            | SqlFunction::XmlRoot(_) => return Err(Unsupported("XmlExpr")),
+        };
+
+        Ok(node)
+    }
+
+    /// Alias: `sortby_list`
+    pub fn sort_clause(&self, sort_clause: &[SortBy]) -> Result<Vec<Value>> {
+        sort_clause.iter()
+            .map(|sort_by| {
+                let use_op = match sort_by.direction() {
+                    Some(SortDirection::Using(op)) => operator_name(op),
+                    _ => vec![],
+                };
+                // PG's location is the `USING` operator's, which isn't kept.
+                let node = Fields::new()
+                    .node("node", Some(self.expr(sort_by.expr())?))
+                    .enum_value("sortby_dir", sort_by_dir(sort_by))
+                    .enum_value("sortby_nulls", sort_by_nulls(sort_by))
+                    .list("useOp", use_op)
+                    .location(Some(-1))
+                    .into_node("SortBy");
+                Ok(node)
+            })
+            .collect()
+    }
+
+    /// Alias: `window_specification`
+    ///
+    /// The fields of a `WindowDef`. The existing window name is its `refname`.
+    pub fn window_def(&self, definition: &WindowDefinition) -> Result<Fields> {
+
+        let (frame_options, start_offset, end_offset) = frame_options(definition);
+
+        let fields = Fields::new()
+            .string("refname", definition.name())
+            .list("partitionClause", self.exprs(definition.partition_clause().unwrap_or_default())?)
+            .list("orderClause", self.sort_clause(definition.order_clause().unwrap_or_default())?)
+            .int("frameOptions", frame_options)
+            .node("startOffset", self.opt_expr(start_offset)?)
+            .node("endOffset", self.opt_expr(end_offset)?);
+        Ok(fields)
+    }
+
+    fn func_args(&self, args: &[NamedValue]) -> Result<Vec<Value>> {
+        args.iter()
+            .map(|arg| self.func_arg(arg, None))
+            .collect()
+    }
+
+    /// Alias: `func_arg_expr`
+    fn func_arg(&self, arg: &NamedValue, location: Option<i64>) -> Result {
+
+        let value = self.expr(arg.value())?;
+
+        let Some(name) = arg.name() else { return Ok(value) };
+
+        let node = Fields::new()
+            .node("arg", Some(value))
+            .string("name", Some(name))
+            .int("argnumber", -1)
+            .location(location)
+            .into_node("NamedArgExpr");
+        Ok(node)
+    }
+
+    /// E.g.: `SUBSTRING(a, 1)`, which isn't schema qualified, unlike the SQL syntax.
+    fn explicit_call(&self, name: &str, args: Option<&[NamedValue]>, location: Option<i64>) -> Result {
+        let node = Fields::new()
+            .list("funcname", vec![string_node(name)])
+            .list("args", self.func_args(args.unwrap_or_default())?)
+            .enum_value("funcformat", "COERCE_EXPLICIT_CALL")
+            .location(location)
+            .into_node("FuncCall");
+        Ok(node)
+    }
+
+    fn min_max(&self, op: &str, args: &[ExprNode], location: Option<i64>) -> Result {
+        let node = Fields::new()
+            .enum_value("op", op)
+            .list("args", self.exprs(args)?)
+            .location(location)
+            .into_node("MinMaxExpr");
+        Ok(node)
+    }
+}
+
+/// The `frameOptions` flags of a window, and its start and end offsets.
+pub(crate) fn frame_options(definition: &WindowDefinition) -> (i64, Option<&ExprNode>, Option<&ExprNode>) {
+
+    let mut start_offset = None;
+    let mut end_offset = None;
+
+    let frame_options = match definition.frame_clause() {
+        None => FRAMEOPTION_DEFAULTS,
+        Some(frame) => {
+
+            let kind = match frame.kind() {
+                WindowFrameKind::Range => FRAMEOPTION_RANGE,
+                WindowFrameKind::Rows => FRAMEOPTION_ROWS,
+                WindowFrameKind::Groups => FRAMEOPTION_GROUPS,
+            };
+
+            let exclusion = match frame.window_exclusion() {
+                WindowExclusion::NoOthers => 0,
+                WindowExclusion::CurrentRow => FRAMEOPTION_EXCLUDE_CURRENT_ROW,
+                WindowExclusion::Group => FRAMEOPTION_EXCLUDE_GROUP,
+                WindowExclusion::Ties => FRAMEOPTION_EXCLUDE_TIES,
+            };
+
+            // A single bound is the start, and the end is the current row.
+            let (start, end) = match frame.frame_extent() {
+                FrameExtent::Unbounded { end } => {
+                    (FRAMEOPTION_START_UNBOUNDED_PRECEDING, end.as_ref().map(preceding_end))
+                },
+                FrameExtent::CurrentRow { end } => {
+                    let end = end.as_ref().map(|end| match end {
+                        CurrentRowEnd::Unbounded => (FRAMEOPTION_END_UNBOUNDED_FOLLOWING, None),
+                        CurrentRowEnd::CurrentRow => (FRAMEOPTION_END_CURRENT_ROW, None),
+                        CurrentRowEnd::Following(end) => (FRAMEOPTION_END_OFFSET_FOLLOWING, Some(end)),
+                    });
+                    (FRAMEOPTION_START_CURRENT_ROW, end)
+                },
+                FrameExtent::Preceding { start, end } => {
+                    start_offset = Some(start);
+                    (FRAMEOPTION_START_OFFSET_PRECEDING, end.as_ref().map(preceding_end))
+                },
+                FrameExtent::Following { start, end } => {
+                    start_offset = Some(start);
+                    let end = match end {
+                        FollowingEnd::Unbounded => (FRAMEOPTION_END_UNBOUNDED_FOLLOWING, None),
+                        FollowingEnd::Following(end) => (FRAMEOPTION_END_OFFSET_FOLLOWING, Some(end)),
+                    };
+                    (FRAMEOPTION_START_OFFSET_FOLLOWING, Some(end))
+                },
+            };
+
+            let end = match end {
+                Some((end, offset)) => {
+                    end_offset = offset;
+                    FRAMEOPTION_BETWEEN | end
+                },
+                None => FRAMEOPTION_END_CURRENT_ROW,
+            };
+
+            FRAMEOPTION_NONDEFAULT | kind | start | end | exclusion
+        },
+    };
+
+    (frame_options, start_offset, end_offset)
+}
+
+pub(crate) fn sort_by_dir(sort_by: &SortBy) -> &'static str {
+    match sort_by.direction() {
+        Some(SortDirection::Ascending) => "SORTBY_ASC",
+        Some(SortDirection::Descending) => "SORTBY_DESC",
+        Some(SortDirection::Using(_)) => "SORTBY_USING",
+        None => "SORTBY_DEFAULT",
+    }
+}
+
+pub(crate) fn sort_by_nulls(sort_by: &SortBy) -> &'static str {
+    match sort_by.nulls() {
+        Some(SortNulls::NullsFirst) => "SORTBY_NULLS_FIRST",
+        Some(SortNulls::NullsLast) => "SORTBY_NULLS_LAST",
+        None => "SORTBY_NULLS_DEFAULT",
+    }
+}
+
+fn preceding_end(end: &PrecedingEnd) -> (i64, Option<&ExprNode>) {
+    match end {
+        PrecedingEnd::Unbounded => (FRAMEOPTION_END_UNBOUNDED_FOLLOWING, None),
+        PrecedingEnd::CurrentRow => (FRAMEOPTION_END_CURRENT_ROW, None),
+        PrecedingEnd::Preceding(end) => (FRAMEOPTION_END_OFFSET_PRECEDING, Some(end)),
+        PrecedingEnd::Following(end) => (FRAMEOPTION_END_OFFSET_FOLLOWING, Some(end)),
+    }
+}
+
+/// A function with special SQL syntax, e.g.: `TRIM(LEADING FROM a)` is `pg_catalog.ltrim(a)`.
+pub(crate) fn sql_syntax_call(name: &str, args: Vec<Value>, location: Option<i64>) -> Value {
+    Fields::new()
+        .list("funcname", vec![string_node("pg_catalog"), string_node(name)])
+        .list("args", args)
+        .enum_value("funcformat", "COERCE_SQL_SYNTAX")
+        .location(location)
+        .into_node("FuncCall")
+}
+
+fn sql_value_function(op: &str, precision: Option<i32>, location: Option<i64>) -> Value {
+    Fields::new()
+        .enum_value("op", op)
+        .int("typmod", precision.unwrap_or(-1).into())
+        .location(location)
+        .into_node("SQLValueFunction")
+}
+
+/// The type of `MERGE_ACTION()`.
+pub(crate) const TEXTOID: i64 = 25;
+
+// `frameOptions` flags, from `parsenodes.h`.
+const FRAMEOPTION_NONDEFAULT: i64 = 0x00001;
+const FRAMEOPTION_RANGE: i64 = 0x00002;
+const FRAMEOPTION_ROWS: i64 = 0x00004;
+const FRAMEOPTION_GROUPS: i64 = 0x00008;
+const FRAMEOPTION_BETWEEN: i64 = 0x00010;
+const FRAMEOPTION_START_UNBOUNDED_PRECEDING: i64 = 0x00020;
+const FRAMEOPTION_END_UNBOUNDED_FOLLOWING: i64 = 0x00100;
+const FRAMEOPTION_START_CURRENT_ROW: i64 = 0x00200;
+const FRAMEOPTION_END_CURRENT_ROW: i64 = 0x00400;
+const FRAMEOPTION_START_OFFSET_PRECEDING: i64 = 0x00800;
+const FRAMEOPTION_END_OFFSET_PRECEDING: i64 = 0x01000;
+const FRAMEOPTION_START_OFFSET_FOLLOWING: i64 = 0x02000;
+const FRAMEOPTION_END_OFFSET_FOLLOWING: i64 = 0x04000;
+const FRAMEOPTION_EXCLUDE_CURRENT_ROW: i64 = 0x08000;
+const FRAMEOPTION_EXCLUDE_GROUP: i64 = 0x10000;
+const FRAMEOPTION_EXCLUDE_TIES: i64 = 0x20000;
+pub(crate) const FRAMEOPTION_DEFAULTS: i64 = FRAMEOPTION_RANGE
+    | FRAMEOPTION_START_UNBOUNDED_PRECEDING
+    | FRAMEOPTION_END_CURRENT_ROW;
+
+#[cfg(test)]
+mod tests {
+    use crate::parse;
+    use pg_parser_core::ParserConfig;
+    use serde_json::json;
+    use serde_json::Value;
+    use test_case::test_case;
+
+    #[test_case("count(*)", json!({"FuncCall": {
+        "funcname": [{"String": {"sval": "count"}}],
+        "agg_star": true,
+        "funcformat": "COERCE_EXPLICIT_CALL",
+        "location": 7
+    }}))]
+    #[test_case("f(distinct a order by a desc)", json!({"FuncCall": {
+        "funcname": [{"String": {"sval": "f"}}],
+        "args": [{"ColumnRef": {"fields": [{"String": {"sval": "a"}}], "location": 18}}],
+        "agg_order": [{"SortBy": {
+            "node": {"ColumnRef": {"fields": [{"String": {"sval": "a"}}], "location": 29}},
+            "sortby_dir": "SORTBY_DESC",
+            "sortby_nulls": "SORTBY_NULLS_DEFAULT",
+            "location": -1
+        }}],
+        "agg_distinct": true,
+        "funcformat": "COERCE_EXPLICIT_CALL",
+        "location": 7
+    }}))]
+    #[test_case("f(x => 1)", json!({"FuncCall": {
+        "funcname": [{"String": {"sval": "f"}}],
+        "args": [{"NamedArgExpr": {
+            "arg": {"A_Const": {"ival": {"ival": 1}, "location": 14}},
+            "name": "x",
+            "argnumber": -1,
+            "location": 9
+        }}],
+        "funcformat": "COERCE_EXPLICIT_CALL",
+        "location": 7
+    }}))]
+    #[test_case("rank() over w from t window w as ()", json!({"FuncCall": {
+        "funcname": [{"String": {"sval": "rank"}}],
+        "over": {"name": "w", "frameOptions": 1058},
+        "funcformat": "COERCE_EXPLICIT_CALL",
+        "location": 7
+    }}))]
+    #[test_case("sum(a) over (rows between 1 preceding and unbounded following)", json!({"FuncCall": {
+        "funcname": [{"String": {"sval": "sum"}}],
+        "args": [{"ColumnRef": {"fields": [{"String": {"sval": "a"}}], "location": 11}}],
+        "over": {
+            "frameOptions": 0x1 | 0x4 | 0x10 | 0x800 | 0x100,
+            "startOffset": {"A_Const": {"ival": {"ival": 1}, "location": 33}}
+        },
+        "funcformat": "COERCE_EXPLICIT_CALL",
+        "location": 7
+    }}))]
+    #[test_case("trim(leading 'x' from a)", json!({"FuncCall": {
+        "funcname": [{"String": {"sval": "pg_catalog"}}, {"String": {"sval": "ltrim"}}],
+        "args": [
+            {"ColumnRef": {"fields": [{"String": {"sval": "a"}}], "location": 29}},
+            {"A_Const": {"sval": {"sval": "x"}, "location": 20}}
+        ],
+        "funcformat": "COERCE_SQL_SYNTAX",
+        "location": 7
+    }}))]
+    #[test_case("current_timestamp(3)", json!({"SQLValueFunction": {
+        "op": "SVFOP_CURRENT_TIMESTAMP_N",
+        "typmod": 3,
+        "location": 7
+    }}))]
+    #[test_case("coalesce(a, 1)", json!({"CoalesceExpr": {
+        "args": [
+            {"ColumnRef": {"fields": [{"String": {"sval": "a"}}], "location": 16}},
+            {"A_Const": {"ival": {"ival": 1}, "location": 19}}
+        ],
+        "location": 7
+    }}))]
+    fn test_func(source: &str, expected: Value) {
+
+        let source = format!("select {source}");
+        let tree = parse(&source, ParserConfig::default()).unwrap();
+
+        let actual = &tree["stmts"][0]["stmt"]["SelectStmt"]["targetList"][0]["ResTarget"]["val"];
+        assert_eq!(&expected, actual);
+    }
+}
+
+use crate::a_expr;
+use crate::operator_name;
+use crate::string_const;
+use crate::string_node;
+use crate::string_nodes;
+use crate::type_names;
+use crate::Converter;
+use crate::Error::Unsupported;
+use crate::Fields;
+use crate::Result;
+use pg_ast::CurrentRowEnd;
+use pg_ast::ExprNode;
+use pg_ast::ExtractArg;
+use pg_ast::FollowingEnd;
+use pg_ast::FrameExtent;
+use pg_ast::FuncArgsKind;
+use pg_ast::FuncArgsOrder;
+use pg_ast::FuncCall;
+use pg_ast::FuncCallExpr;
+use pg_ast::NamedValue;
+use pg_ast::OverClause;
+use pg_ast::OverlayFunc;
+use pg_ast::PrecedingEnd;
+use pg_ast::SortBy;
+use pg_ast::SortDirection;
+use pg_ast::SortNulls;
+use pg_ast::SqlFunction;
+use pg_ast::SubstringFunc;
+use pg_ast::TrimSide;
+use pg_ast::UnicodeNormalForm;
+use pg_ast::WindowDefinition;
+use pg_ast::WindowExclusion;
+use pg_ast::WindowFrameKind;
+use pg_basics::Located;
+use serde_json::Value;
@@ -0,0 +1,37 @@
+//! Converts the AST into the JSON parse tree of libpg_query (`pg_query.parse()`),
+//! with its node names, field names and enum values,
+//! so tools built on `pg_query` can consume this parser's output.
+//!
+//! Only part of the grammar is converted: `SELECT` (with its expressions and `FROM` items),
+//! transaction statements, `SET`/`RESET`/`SHOW`, `LISTEN`/`UNLISTEN`/`NOTIFY`, `LOAD`, `DISCARD`,
+//! `DEALLOCATE`, `CLOSE`, `CHECKPOINT` and `ALTER DATABASE ... REFRESH COLLATION VERSION`.
+//! Every other statement, including all DML and DDL, fails with [`Error::Unsupported`],
+//! so this isn't a drop-in replacement for `pg_query.parse()`.
+//!
+//! Like libpg_query, fields with default values (`false`, `0`, null and empty lists) are left out.
+//!
+//! Locations are byte offsets, but the AST only keeps where expressions, functions, types,
+//! relations and statements are, so other nodes' locations are approximated or left out.
+//!
+//! The same node names, field names and enum values are what pg_query's query fingerprints are defined over,
+//! so [`fingerprint()`] writes them while walking the AST.
+
+extern crate alloc;
+
+pg_basics::reexport! { pub
+    error,
+    fingerprint,
+    parse,
+}
+
+pg_basics::reexport! { pub(crate)
+    converter,
+    expr,
+    fields,
+    type_name,
+}
+
+mod func;
+mod select;
+mod stmt;
+mod table_ref;
@@ -0,0 +1,136 @@
+/// The `version` of the parse tree, which is the `PG_VERSION_NUM` of the grammar.
+pub const PG_VERSION_NUM: i32 = 190000;
+
+/// Parses all the statements in `source` into libpg_query's JSON parse tree.
+///
+/// Like PG's, a statement's `stmt_location` is right after the previous `;`,
+/// and its `stmt_len` goes up to its own `;`, or it's 0 when there isn't one.
+///
+/// Fails with the first statement that has a syntax error, or that can't be converted,
+/// which is any statement besides `SELECT` and a few utility statements (see the crate's docs).
+pub fn parse(source: &str, config: ParserConfig) -> Result {
+
+    let stmts = Parser::new(source, config)
+        .parse()
+        .result
+        .into_iter()
+        .collect::<LocatedResult<Vec<_>>>()?;
+
+    let standard_conforming_strings = config.standard_conforming_strings();
+    let converter = Converter::new(source, standard_conforming_strings);
+    let semicolons = semicolons(source, standard_conforming_strings);
+
+    let stmts = stmts.iter()
+        .map(|Located(stmt, loc)| {
+            let range = loc.range();
+
+            let previous = semicolons.partition_point(|&semicolon| semicolon < range.start);
+            let stmt_location = previous.checked_sub(1)
+                .map_or(0, |index| semicolons[index] + 1);
+
+            let next = semicolons.partition_point(|&semicolon| semicolon < range.end);
+            let stmt_len = semicolons.get(next)
+                .map_or(0, |semicolon| semicolon - stmt_location);
+
+            let raw_stmt = Fields::new()
+                .node("stmt", Some(converter.stmt(stmt)?))
+                .int("stmt_location", stmt_location.into())
+                .int("stmt_len", stmt_len.into())
+                .into_value();
+            Ok(raw_stmt)
+        })
+        .collect::<Result<_>>()?;
+
+    let tree = Fields::new()
+        .int("version", PG_VERSION_NUM.into())
+        .list("stmts", stmts)
+        .into_value();
+    Ok(tree)
+}
+
+/// Where the `;` that separate statements are, i.e., the ones outside parenthesis.
+fn semicolons(source: &str, standard_conforming_strings: bool) -> Vec<u32> {
+
+    let mut semicolons = Vec::new();
+    let mut depth = 0usize;
+
+    // The parser already failed on errors, so there shouldn't be any.
+    let lexer = Lexer::new(source)
+        .with_standard_conforming_strings(standard_conforming_strings)
+        .map_while(core::result::Result::ok);
+
+    for Located(kind, loc) in lexer {
+        match kind {
+            Operator(OpenParenthesis) => depth += 1,
+            Operator(CloseParenthesis) => depth = depth.saturating_sub(1),
+            Operator(Semicolon) if depth == 0 => semicolons.push(loc.range().start),
+            _ => {},
+        }
+    }
+
+    semicolons
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse() {
+        let expected = json!({
+            "version": PG_VERSION_NUM,
+            "stmts": [{"stmt": {"SelectStmt": {
+                "targetList": [{"ResTarget": {
+                    "val": {"A_Const": {"ival": {"ival": 1}, "location": 7}},
+                    "location": 7
+                }}],
+                "limitOption": "LIMIT_OPTION_DEFAULT",
+                "op": "SETOP_NONE"
+            }}}]
+        });
+
+        assert_eq!(Ok(expected), parse("select 1", ParserConfig::default()));
+    }
+
+    #[test]
+    fn test_stmt_ranges() {
+        let tree = parse("select 1;; select (2);\nselect 3", ParserConfig::default()).unwrap();
+        let ranges: Vec<_> = tree["stmts"].as_array()
+            .unwrap()
+            .iter()
+            .map(|stmt| (stmt["stmt_location"].as_i64(), stmt["stmt_len"].as_i64()))
+            .collect();
+
+        assert_eq!(
+            vec![
+                (None, Some(8)),
+                (Some(10), Some(11)),
+                (Some(22), None),
+            ],
+            ranges
+        );
+    }
+
+    #[test]
+    fn test_unsupported() {
+        assert_eq!(
+            Err(Error::Unsupported("CreateSchemaStmt")),
+            parse("create schema s", ParserConfig::default())
+        );
+    }
+}
+
+use crate::Converter;
+use crate::Fields;
+use crate::Result;
+use pg_basics::Located;
+use pg_elog::LocatedResult;
+use pg_lexer::Lexer;
+use pg_lexer::OperatorKind::CloseParenthesis;
+use pg_lexer::OperatorKind::OpenParenthesis;
+use pg_lexer::OperatorKind::Semicolon;
+use pg_lexer::RawTokenKind::Operator;
+use pg_parser::Parser;
+use pg_parser_core::ParserConfig;
@@ -0,0 +1,190 @@
+impl Converter<'_> {
+
+    /// Alias: `SelectStmt`
+    ///
+    /// The fields of a `SelectStmt`, which only has the node name when it's a generic node.
+    pub fn select_stmt(&self, stmt: &SelectStmt) -> Result<Fields> {
+
+        let fields = match stmt.body() {
+            SelectBody::Simple(select) => self.simple_select(select)?,
+            SelectBody::Values(rows) => {
+                let rows = rows.iter()
+                    .map(|row| Ok(list_node(self.exprs(row)?)))
+                    .collect::<Result<_>>()?;
+                Fields::new().list("valuesLists", rows)
+            },
+            SelectBody::SetOperation { .. } => Fields::new(),
+        };
+
+        let fields = match stmt.body() {
+            SelectBody::SetOperation { op, all, left, right } => {
+                fields.enum_value("op", set_operation(*op))
+                    .bool("all", *all)
+                    .node("larg", Some(self.select_stmt(left)?.into_value()))
+                    .node("rarg", Some(self.select_stmt(right)?.into_value()))
+            },
+            _ => fields.enum_value("op", "SETOP_NONE"),
+        };
+
+        let fields = fields
+            .list("sortClause", self.sort_clause(stmt.sort_clause().unwrap_or_default())?)
+            .node("limitOffset", self.opt_expr(stmt.limit_offset())?)
+            .node("limitCount", self.opt_expr(stmt.limit_count())?)
+            .enum_value("limitOption", limit_option(stmt));
+        Ok(fields)
+    }
+
+    /// Alias: `simple_select`
+    fn simple_select(&self, select: &SimpleSelect) -> Result<Fields> {
+
+        let distinct_clause = match select.distinct_clause() {
+            // A list with a single empty item, like PG's `list_make1(NIL)`.
+            Some(DistinctClause::Distinct) => vec![Fields::new().into_value()],
+            Some(DistinctClause::DistinctOn(exprs)) => self.exprs(exprs)?,
+            None => vec![],
+        };
+
+        let target_list = select.target_list()
+            .iter()
+            .map(|target| self.res_target(target))
+            .collect::<Result<_>>()?;
+
+        let window_clause = select.window_clause()
+            .unwrap_or_default()
+            .iter()
+            .map(|window| {
+                let node = self.window_def(window.definition())?
+                    .string("name", Some(window.name()))
+                    .into_node("WindowDef");
+                Ok(node)
+            })
+            .collect::<Result<_>>()?;
+
+        let fields = Fields::new()
+            .list("distinctClause", distinct_clause)
+            .list("targetList", target_list)
+            .list("fromClause", self.table_refs(select.from_clause().unwrap_or_default())?)
+            .node("whereClause", self.opt_expr(select.where_clause())?)
+            .list("groupClause", self.exprs(select.group_clause().unwrap_or_default())?)
+            .bool("groupDistinct", select.group_distinct())
+            .node("havingClause", self.opt_expr(select.having_clause())?)
+            .list("windowClause", window_clause);
+        Ok(fields)
+    }
+
+    /// Alias: `target_el`
+    fn res_target(&self, target: &ResTarget) -> Result {
+
+        let fields = match target {
+            ResTarget::Star => {
+                let star = Fields::new()
+                    .list("fields", vec![a_star()])
+                    .into_node("ColumnRef");
+                Fields::new().node("val", Some(star))
+            },
+            ResTarget::Expr { value, name } => {
+                Fields::new()
+                    .string("name", name.as_deref())
+                    .node("val", Some(self.expr(value)?))
+                    .location(start(value.span()))
+            },
+        };
+
+        Ok(fields.into_node("ResTarget"))
+    }
+}
+
+pub(crate) fn set_operation(op: SetOperation) -> &'static str {
+    match op {
+        SetOperation::Union => "SETOP_UNION",
+        SetOperation::Intersect => "SETOP_INTERSECT",
+        SetOperation::Except => "SETOP_EXCEPT",
+    }
+}
+
+pub(crate) fn limit_option(stmt: &SelectStmt) -> &'static str {
+    if stmt.limit_count().is_some() || stmt.limit_offset().is_some() {
+        "LIMIT_OPTION_COUNT"
+    }
+    else {
+        "LIMIT_OPTION_DEFAULT"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse;
+    use pg_parser_core::ParserConfig;
+    use serde_json::json;
+    use serde_json::Value;
+    use test_case::test_case;
+
+    #[test_case("select distinct a as b from t where a group by a order by a limit 1", json!({
+        "distinctClause": [{}],
+        "targetList": [{"ResTarget": {
+            "name": "b",
+            "val": {"ColumnRef": {"fields": [{"String": {"sval": "a"}}], "location": 16}},
+            "location": 16
+        }}],
+        "fromClause": [{"RangeVar": {"relname": "t", "inh": true, "relpersistence": "p", "location": 28}}],
+        "whereClause": {"ColumnRef": {"fields": [{"String": {"sval": "a"}}], "location": 36}},
+        "groupClause": [{"ColumnRef": {"fields": [{"String": {"sval": "a"}}], "location": 47}}],
+        "sortClause": [{"SortBy": {
+            "node": {"ColumnRef": {"fields": [{"String": {"sval": "a"}}], "location": 58}},
+            "sortby_dir": "SORTBY_DEFAULT",
+            "sortby_nulls": "SORTBY_NULLS_DEFAULT",
+            "location": -1
+        }}],
+        "limitCount": {"A_Const": {"ival": {"ival": 1}, "location": 66}},
+        "limitOption": "LIMIT_OPTION_COUNT",
+        "op": "SETOP_NONE"
+    }))]
+    #[test_case("values (1)", json!({
+        "valuesLists": [{"List": {"items": [{"A_Const": {"ival": {"ival": 1}, "location": 8}}]}}],
+        "limitOption": "LIMIT_OPTION_DEFAULT",
+        "op": "SETOP_NONE"
+    }))]
+    #[test_case("select 1 union all select 2", json!({
+        "op": "SETOP_UNION",
+        "all": true,
+        "larg": {
+            "targetList": [{"ResTarget": {"val": {"A_Const": {"ival": {"ival": 1}, "location": 7}}, "location": 7}}],
+            "limitOption": "LIMIT_OPTION_DEFAULT",
+            "op": "SETOP_NONE"
+        },
+        "rarg": {
+            "targetList": [{"ResTarget": {"val": {"A_Const": {"ival": {"ival": 2}, "location": 26}}, "location": 26}}],
+            "limitOption": "LIMIT_OPTION_DEFAULT",
+            "op": "SETOP_NONE"
+        },
+        "limitOption": "LIMIT_OPTION_DEFAULT"
+    }))]
+    #[test_case("select * from t window w as (partition by a)", json!({
+        "targetList": [{"ResTarget": {"val": {"ColumnRef": {"fields": [{"A_Star": {}}]}}}}],
+        "fromClause": [{"RangeVar": {"relname": "t", "inh": true, "relpersistence": "p", "location": 14}}],
+        "windowClause": [{"WindowDef": {
+            "name": "w",
+            "partitionClause": [{"ColumnRef": {"fields": [{"String": {"sval": "a"}}], "location": 42}}],
+            "frameOptions": 1058
+        }}],
+        "limitOption": "LIMIT_OPTION_DEFAULT",
+        "op": "SETOP_NONE"
+    }))]
+    fn test_select_stmt(source: &str, expected: Value) {
+        let tree = parse(source, ParserConfig::default()).unwrap();
+        assert_eq!(expected, tree["stmts"][0]["stmt"]["SelectStmt"]);
+    }
+}
+
+use crate::a_star;
+use crate::converter::start;
+use crate::list_node;
+use crate::Converter;
+use crate::Fields;
+use crate::Result;
+use pg_ast::DistinctClause;
+use pg_ast::ResTarget;
+use pg_ast::SelectBody;
+use pg_ast::SelectStmt;
+use pg_ast::SetOperation;
+use pg_ast::SimpleSelect;
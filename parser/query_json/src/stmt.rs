@@ -0,0 +1,499 @@
+impl Converter<'_> {
+
+    /// Alias: `stmt`
+    ///
+    /// Only `SELECT` and the utility statements below are converted. The rest are [`Unsupported`].
+    pub fn stmt(&self, stmt: &RawStmt) -> Result {
+
+        let node = match stmt {
+            RawStmt::SelectStmt(stmt) => self.select_stmt(stmt)?.into_node("SelectStmt"),
+            RawStmt::TransactionStmt(stmt) => transaction_stmt(stmt),
+            RawStmt::PrepareTransactionStmt(gid) => {
+                Fields::new()
+                    .enum_value("kind", "TRANS_STMT_PREPARE")
+                    .string("gid", Some(gid))
+                    .into_node("TransactionStmt")
+            },
+            RawStmt::VariableSetStmt(stmt) => {
+                self.set_rest(stmt.stmt())?
+                    .bool("is_local", stmt.is_local())
+                    .into_node("VariableSetStmt")
+            },
+            RawStmt::VariableResetStmt(VariableTarget::All) => {
+                Fields::new()
+                    .enum_value("kind", "VAR_RESET_ALL")
+                    .into_node("VariableSetStmt")
+            },
+            RawStmt::VariableResetStmt(target) => {
+                Fields::new()
+                    .enum_value("kind", "VAR_RESET")
+                    .string("name", Some(&variable_name(target)))
+                    .into_node("VariableSetStmt")
+            },
+            RawStmt::VariableShowStmt(target) => {
+                Fields::new()
+                    .string("name", Some(&variable_name(target)))
+                    .into_node("VariableShowStmt")
+            },
+            RawStmt::ListenStmt(channel) => {
+                Fields::new()
+                    .string("conditionname", Some(channel))
+                    .into_node("ListenStmt")
+            },
+            RawStmt::UnlistenStmt(channel) => {
+                Fields::new()
+                    .string("conditionname", one(channel))
+                    .into_node("UnlistenStmt")
+            },
+            RawStmt::NotifyStmt(stmt) => {
+                Fields::new()
+                    .string("conditionname", Some(stmt.condition_name()))
+                    .string("payload", stmt.payload())
+                    .into_node("NotifyStmt")
+            },
+            RawStmt::LoadStmt(filename) => {
+                Fields::new()
+                    .string("filename", Some(filename))
+                    .into_node("LoadStmt")
+            },
+            RawStmt::DiscardStmt(stmt) => {
+                Fields::new()
+                    .enum_value("target", discard_target(*stmt))
+                    .into_node("DiscardStmt")
+            },
+            RawStmt::DeallocateStmt(name) => {
+                Fields::new()
+                    .string("name", one(name))
+                    .bool("isall", matches!(name, OneOrAll::All))
+                    .into_node("DeallocateStmt")
+            },
+            RawStmt::ClosePortalStmt(name) => {
+                Fields::new()
+                    .string("portalname", one(name))
+                    .into_node("ClosePortalStmt")
+            },
+            RawStmt::CheckPointStmt(options) => {
+                let options = options.as_deref()
+                    .unwrap_or_default()
+                    .iter()
+                    .map(utility_option)
+                    .collect();
+                Fields::new()
+                    .list("options", options)
+                    .into_node("CheckPointStmt")
+            },
+            RawStmt::AlterDatabaseRefreshCollStmt(name) => {
+                Fields::new()
+                    .string("dbname", Some(name))
+                    .into_node("AlterDatabaseRefreshCollStmt")
+            },
+            _ => return Err(Unsupported(node_name(stmt))),
+        };
+
+        Ok(node)
+    }
+
+    /// Alias: `set_rest`
+    ///
+    /// The fields of a `VariableSetStmt`.
+    fn set_rest(&self, stmt: &SetRest) -> Result<Fields> {
+
+        let (kind, name) = set_rest_kind(stmt)?;
+
+        let args = match stmt {
+            SetRest::SessionTransactionCharacteristics(modes)
+            | SetRest::LocalTransactionCharacteristics(modes) => transaction_modes(modes),
+            SetRest::SessionAuthorization { user: ValueOrDefault::Value(user) } => vec![string_const(user, None)],
+            SetRest::TransactionSnapshot(snapshot) => vec![string_const(snapshot, None)],
+            SetRest::TimeZone(ZoneValue::String(zone)) => vec![string_const(zone, None)],
+            SetRest::TimeZone(ZoneValue::Numeric(zone)) => vec![signed_number(zone)],
+            SetRest::TimeZone(ZoneValue::Interval { value: zone, range }) => {
+                let zone = Fields::new()
+                    .node("arg", Some(string_const(zone, None)))
+                    .node("typeName", Some(self.type_name(&TypeName::Interval(*range).into())?))
+                    .location(Some(-1))
+                    .into_node("TypeCast");
+                vec![zone]
+            },
+            SetRest::Schema(schema) => vec![string_const(schema, None)],
+            SetRest::ClientEncoding(ValueOrDefault::Value(encoding)) => vec![string_const(encoding, None)],
+            SetRest::Role(role) => vec![string_const(role, None)],
+            SetRest::XmlOption(option) => {
+                let option = match option {
+                    XmlNodeKind::Document => "DOCUMENT",
+                    XmlNodeKind::Content => "CONTENT",
+                };
+                vec![string_const(option, None)]
+            },
+            SetRest::ConfigurationParameter { value: DefaultableValue::Null, .. } => {
+                let null = Fields::new()
+                    .bool("isnull", true)
+                    .into_node("A_Const");
+                vec![null]
+            },
+            SetRest::ConfigurationParameter { value: DefaultableValue::Value(values), .. } => {
+                values.iter().map(var_value).collect()
+            },
+            SetRest::SessionAuthorization { user: ValueOrDefault::Default }
+            | SetRest::TimeZone(ZoneValue::Local)
+            | SetRest::Catalog(_)
+            | SetRest::ClientEncoding(ValueOrDefault::Default)
+            | SetRest::FromCurrent { .. }
+            | SetRest::ConfigurationParameter { value: DefaultableValue::Default, .. } => vec![],
+        };
+
+        let fields = Fields::new()
+            .enum_value("kind", kind)
+            .string("name", Some(&name))
+            .list("args", args);
+        Ok(fields)
+    }
+}
+
+/// The `kind` and `name` of a `VariableSetStmt`.
+pub(crate) fn set_rest_kind(stmt: &SetRest) -> Result<(&'static str, String)> {
+
+    let (kind, name) = match stmt {
+        SetRest::SessionTransactionCharacteristics(_) => ("VAR_SET_MULTI", "SESSION CHARACTERISTICS".into()),
+        SetRest::LocalTransactionCharacteristics(_) => ("VAR_SET_MULTI", "TRANSACTION".into()),
+        SetRest::SessionAuthorization { user: ValueOrDefault::Value(_) } => {
+            ("VAR_SET_VALUE", "session_authorization".into())
+        },
+        SetRest::SessionAuthorization { user: ValueOrDefault::Default } => {
+            ("VAR_SET_DEFAULT", "session_authorization".into())
+        },
+        SetRest::TransactionSnapshot(_) => ("VAR_SET_MULTI", "TRANSACTION SNAPSHOT".into()),
+        SetRest::TimeZone(ZoneValue::Local) => ("VAR_SET_DEFAULT", "timezone".into()),
+        SetRest::TimeZone(_) => ("VAR_SET_VALUE", "timezone".into()),
+        // PG's grammar accepts `SET CATALOG`, but always fails.
+        SetRest::Catalog(_) => return Err(Unsupported("SET CATALOG")),
+        SetRest::Schema(_) => ("VAR_SET_VALUE", "search_path".into()),
+        SetRest::ClientEncoding(ValueOrDefault::Value(_)) => ("VAR_SET_VALUE", "client_encoding".into()),
+        SetRest::ClientEncoding(ValueOrDefault::Default) => ("VAR_SET_DEFAULT", "client_encoding".into()),
+        SetRest::Role(_) => ("VAR_SET_VALUE", "role".into()),
+        SetRest::XmlOption(_) => ("VAR_SET_VALUE", "xmloption".into()),
+        SetRest::FromCurrent { name } => ("VAR_SET_CURRENT", qualified_name(name)),
+        SetRest::ConfigurationParameter { name, value: DefaultableValue::Default } => {
+            ("VAR_SET_DEFAULT", qualified_name(name))
+        },
+        SetRest::ConfigurationParameter { name, .. } => ("VAR_SET_VALUE", qualified_name(name)),
+    };
+
+    Ok((kind, name))
+}
+
+fn transaction_stmt(stmt: &TransactionStmt) -> Value {
+
+    let fields = match stmt {
+        TransactionStmt::Begin(modes)
+        | TransactionStmt::Start(modes) => Fields::new().list("options", transaction_modes(modes)),
+        TransactionStmt::Commit { chain }
+        | TransactionStmt::Rollback { chain } => Fields::new().bool("chain", *chain),
+        TransactionStmt::CommitPrepared(gid)
+        | TransactionStmt::Prepare(gid)
+        | TransactionStmt::RollbackPrepared(gid) => Fields::new().string("gid", Some(gid)),
+        TransactionStmt::Savepoint(name)
+        | TransactionStmt::Release(name)
+        | TransactionStmt::RollbackTo(name) => Fields::new().string("savepoint_name", Some(name)),
+    };
+
+    fields.enum_value("kind", transaction_kind(stmt))
+        .into_node("TransactionStmt")
+}
+
+pub(crate) fn transaction_kind(stmt: &TransactionStmt) -> &'static str {
+    match stmt {
+        TransactionStmt::Begin(_) => "TRANS_STMT_BEGIN",
+        TransactionStmt::Start(_) => "TRANS_STMT_START",
+        TransactionStmt::Commit { .. } => "TRANS_STMT_COMMIT",
+        TransactionStmt::CommitPrepared(_) => "TRANS_STMT_COMMIT_PREPARED",
+        TransactionStmt::Savepoint(_) => "TRANS_STMT_SAVEPOINT",
+        TransactionStmt::Release(_) => "TRANS_STMT_RELEASE",
+        TransactionStmt::Prepare(_) => "TRANS_STMT_PREPARE",
+        TransactionStmt::Rollback { .. } => "TRANS_STMT_ROLLBACK",
+        TransactionStmt::RollbackTo(_) => "TRANS_STMT_ROLLBACK_TO",
+        TransactionStmt::RollbackPrepared(_) => "TRANS_STMT_ROLLBACK_PREPARED",
+    }
+}
+
+/// Alias: `transaction_mode_list`
+fn transaction_modes(modes: &[TransactionMode]) -> Vec<Value> {
+    modes.iter()
+        .map(|mode| {
+            let arg = match mode {
+                TransactionMode::IsolationLevel(level) => {
+                    let level = match level {
+                        IsolationLevel::ReadUncommitted => "read uncommitted",
+                        IsolationLevel::ReadCommitted => "read committed",
+                        IsolationLevel::RepeatableRead => "repeatable read",
+                        IsolationLevel::Serializable => "serializable",
+                    };
+                    string_const(level, None)
+                },
+                TransactionMode::ReadOnly | TransactionMode::Deferrable => int_const(1, None),
+                TransactionMode::ReadWrite | TransactionMode::NotDeferrable => int_const(0, None),
+            };
+            def_elem(transaction_mode_name(mode), Some(arg))
+        })
+        .collect()
+}
+
+/// The `defname` of a transaction mode's `DefElem`.
+pub(crate) fn transaction_mode_name(mode: &TransactionMode) -> &'static str {
+    match mode {
+        TransactionMode::IsolationLevel(_) => "transaction_isolation",
+        TransactionMode::ReadOnly | TransactionMode::ReadWrite => "transaction_read_only",
+        TransactionMode::Deferrable | TransactionMode::NotDeferrable => "transaction_deferrable",
+    }
+}
+
+/// Alias: `utility_option_elem`
+fn utility_option(option: &UtilityOption) -> Value {
+
+    // Unlike `SET`'s, the values are plain `String`, `Integer` and `Float` nodes.
+    let arg = option.value().map(|value| match value {
+        VarValue::Boolean(true) => string_node("true"),
+        VarValue::Boolean(false) => string_node("false"),
+        VarValue::Number(SignedNumber::IntegerConst(value)) => integer_node(*value),
+        VarValue::Number(SignedNumber::NumericConst { value, radix, negative }) => {
+            Fields::new()
+                .string("fval", Some(&signed_numeric_text(value, *radix, *negative)))
+                .into_node("Float")
+        },
+        VarValue::String(value) => string_node(value),
+    });
+
+    def_elem(utility_option_name(option.name()), arg)
+}
+
+pub(crate) fn utility_option_name(name: &UtilityOptionName) -> &str {
+    match name {
+        UtilityOptionName::Analyze => "analyze",
+        UtilityOptionName::Format => "format",
+        UtilityOptionName::Generic(name) => name,
+    }
+}
+
+fn def_elem(name: &str, arg: Option<Value>) -> Value {
+    Fields::new()
+        .string("defname", Some(name))
+        .node("arg", arg)
+        .enum_value("defaction", "DEFELEM_UNSPEC")
+        .into_node("DefElem")
+}
+
+/// Alias: `var_value`
+fn var_value(value: &VarValue) -> Value {
+    match value {
+        VarValue::Boolean(true) => string_const("true", None),
+        VarValue::Boolean(false) => string_const("false", None),
+        VarValue::Number(value) => signed_number(value),
+        VarValue::String(value) => string_const(value, None),
+    }
+}
+
+/// Alias: `NumericOnly`
+fn signed_number(value: &SignedNumber) -> Value {
+    match value {
+        SignedNumber::IntegerConst(value) => int_const(*value, None),
+        SignedNumber::NumericConst { value, radix, negative } => {
+            float_const(&signed_numeric_text(value, *radix, *negative), None)
+        },
+    }
+}
+
+fn signed_numeric_text(value: &str, radix: NumberRadix, negative: bool) -> String {
+    let value = numeric_text(value, radix);
+    if negative { format!("-{value}") } else { value }
+}
+
+pub(crate) fn discard_target(stmt: DiscardStmt) -> &'static str {
+    match stmt {
+        DiscardStmt::All => "DISCARD_ALL",
+        DiscardStmt::Plans => "DISCARD_PLANS",
+        DiscardStmt::Sequences => "DISCARD_SEQUENCES",
+        DiscardStmt::Temporary => "DISCARD_TEMP",
+    }
+}
+
+pub(crate) fn variable_name(target: &VariableTarget) -> String {
+    match target {
+        VariableTarget::All => "all".into(),
+        VariableTarget::SessionAuthorization => "session_authorization".into(),
+        VariableTarget::TransactionIsolation => "transaction_isolation".into(),
+        VariableTarget::TimeZone => "timezone".into(),
+        VariableTarget::Variable { name } => qualified_name(name),
+    }
+}
+
+/// Alias: `var_name`
+fn qualified_name(name: &QualifiedName) -> String {
+    name.iter()
+        .map(Str::as_ref)
+        .collect::<Vec<&str>>()
+        .join(".")
+}
+
+pub(crate) fn one(value: &OneOrAll<Str>) -> Option<&str> {
+    match value {
+        OneOrAll::One(value) => Some(value),
+        OneOrAll::All => None,
+    }
+}
+
+/// The name of PG's node for the statement.
+pub(crate) fn node_name(stmt: &RawStmt) -> &'static str {
+    match stmt {
+        RawStmt::AlterCompositeTypeStmt(_) => "AlterCompositeTypeStmt",
+        RawStmt::AlterDatabaseRefreshCollStmt(_) => "AlterDatabaseRefreshCollStmt",
+        RawStmt::AlterDatabaseSetStmt(_) => "AlterDatabaseSetStmt",
+        RawStmt::AlterDatabaseStmt(_) => "AlterDatabaseStmt",
+        RawStmt::AlterDefaultPrivilegesStmt(_) => "AlterDefaultPrivilegesStmt",
+        RawStmt::AlterDomainStmt(_) => "AlterDomainStmt",
+        RawStmt::AlterEnumStmt(_) => "AlterEnumStmt",
+        RawStmt::AlterEventTrigStmt(_) => "AlterEventTrigStmt",
+        RawStmt::AlterExtensionContentsStmt(_) => "AlterExtensionContentsStmt",
+        RawStmt::AlterExtensionStmt(_) => "AlterExtensionStmt",
+        RawStmt::AlterFunctionStmt(_) => "AlterFunctionStmt",
+        RawStmt::AlterObjectDependsStmt(_) => "AlterObjectDependsStmt",
+        RawStmt::AlterObjectSchemaStmt(_) => "AlterObjectSchemaStmt",
+        RawStmt::AlterOpFamilyStmt(_) => "AlterOpFamilyStmt",
+        RawStmt::AlterOperatorStmt(_) => "AlterOperatorStmt",
+        RawStmt::AlterOwnerStmt(_) => "AlterOwnerStmt",
+        RawStmt::AlterPropGraphStmt(_) => "AlterPropGraphStmt",
+        RawStmt::AlterRoleSetStmt(_) => "AlterRoleSetStmt",
+        RawStmt::AlterRoleStmt(_) => "AlterRoleStmt",
+        RawStmt::AlterSeqStmt(_) => "AlterSeqStmt",
+        RawStmt::AlterSystemStmt(_) => "AlterSystemStmt",
+        RawStmt::AlterTextSearchConfigurationStmt(_) => "AlterTextSearchConfigurationStmt",
+        RawStmt::AlterTextSearchDictionaryStmt(_) => "AlterTextSearchDictionaryStmt",
+        RawStmt::AlterUserMappingStmt(_) => "AlterUserMappingStmt",
+        RawStmt::CheckPointStmt(_) => "CheckPointStmt",
+        RawStmt::ClosePortalStmt(_) => "ClosePortalStmt",
+        RawStmt::CommentStmt(_) => "CommentStmt",
+        RawStmt::CompositeTypeStmt(_) => "CompositeTypeStmt",
+        RawStmt::ConstraintsSetStmt(_) => "ConstraintsSetStmt",
+        RawStmt::CreateAccessMethodStmt(_) => "CreateAccessMethodStmt",
+        RawStmt::CreateCastStmt(_) => "CreateCastStmt",
+        RawStmt::CreateConversionStmt(_) => "CreateConversionStmt",
+        RawStmt::CreateDatabaseStmt(_) => "CreateDatabaseStmt",
+        RawStmt::CreateDomainStmt(_) => "CreateDomainStmt",
+        RawStmt::CreateEnumStmt(_) => "CreateEnumStmt",
+        RawStmt::CreateExtensionStmt(_) => "CreateExtensionStmt",
+        RawStmt::CreateOpClassStmt(_) => "CreateOpClassStmt",
+        RawStmt::CreateOpFamilyStmt(_) => "CreateOpFamilyStmt",
+        RawStmt::CreatePLangStmt(_) => "CreatePLangStmt",
+        RawStmt::CreatePropGraphStmt(_) => "CreatePropGraphStmt",
+        RawStmt::CreateRangeStmt(_) => "CreateRangeStmt",
+        RawStmt::CreateRoleStmt(_) => "CreateRoleStmt",
+        RawStmt::CreateSchemaStmt(_) => "CreateSchemaStmt",
+        RawStmt::CreateSeqStmt(_) => "CreateSeqStmt",
+        RawStmt::CreateStatsStmt(_) => "CreateStatsStmt",
+        RawStmt::CreateTableSpaceStmt(_) => "CreateTableSpaceStmt",
+        RawStmt::CreateTransformStmt(_) => "CreateTransformStmt",
+        RawStmt::CreateUserMappingStmt(_) => "CreateUserMappingStmt",
+        RawStmt::DeallocateStmt(_) => "DeallocateStmt",
+        RawStmt::DefineStmt(_) => "DefineStmt",
+        RawStmt::DiscardStmt(_) => "DiscardStmt",
+        RawStmt::DropStmt(_) => "DropStmt",
+        RawStmt::ListenStmt(_) => "ListenStmt",
+        RawStmt::LoadStmt(_) => "LoadStmt",
+        RawStmt::NotifyStmt(_) => "NotifyStmt",
+        RawStmt::PrepareStmt(_) => "PrepareStmt",
+        RawStmt::PrepareTransactionStmt(_) => "TransactionStmt",
+        RawStmt::ReassignOwnedStmt(_) => "ReassignOwnedStmt",
+        RawStmt::RefreshCollationVersionStmt(_) => "AlterCollationStmt",
+        RawStmt::RenameStmt(_) => "RenameStmt",
+        RawStmt::RuleStmt(_) => "RuleStmt",
+        RawStmt::SecurityLabelStmt(_) => "SecurityLabelStmt",
+        RawStmt::SelectStmt(_) => "SelectStmt",
+        RawStmt::TransactionStmt(_) => "TransactionStmt",
+        RawStmt::UnlistenStmt(_) => "UnlistenStmt",
+        RawStmt::VariableResetStmt(_) => "VariableSetStmt",
+        RawStmt::VariableSetStmt(_) => "VariableSetStmt",
+        RawStmt::VariableShowStmt(_) => "VariableShowStmt",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse;
+    use pg_parser_core::ParserConfig;
+    use serde_json::json;
+    use serde_json::Value;
+    use test_case::test_case;
+
+    #[test_case("begin isolation level read committed, read only", json!({"TransactionStmt": {
+        "kind": "TRANS_STMT_BEGIN",
+        "options": [
+            {"DefElem": {
+                "defname": "transaction_isolation",
+                "arg": {"A_Const": {"sval": {"sval": "read committed"}}},
+                "defaction": "DEFELEM_UNSPEC"
+            }},
+            {"DefElem": {
+                "defname": "transaction_read_only",
+                "arg": {"A_Const": {"ival": {"ival": 1}}},
+                "defaction": "DEFELEM_UNSPEC"
+            }}
+        ]
+    }}))]
+    #[test_case("rollback to savepoint s", json!({"TransactionStmt": {
+        "kind": "TRANS_STMT_ROLLBACK_TO",
+        "savepoint_name": "s"
+    }}))]
+    #[test_case("set local a.b to 'x', 1.5", json!({"VariableSetStmt": {
+        "kind": "VAR_SET_VALUE",
+        "name": "a.b",
+        "args": [
+            {"A_Const": {"sval": {"sval": "x"}}},
+            {"A_Const": {"fval": {"fval": "1.5"}}}
+        ],
+        "is_local": true
+    }}))]
+    #[test_case("set time zone local", json!({"VariableSetStmt": {
+        "kind": "VAR_SET_DEFAULT",
+        "name": "timezone"
+    }}))]
+    #[test_case("reset all", json!({"VariableSetStmt": {"kind": "VAR_RESET_ALL"}}))]
+    #[test_case("show transaction isolation level", json!({"VariableShowStmt": {"name": "transaction_isolation"}}))]
+    #[test_case("notify c, 'p'", json!({"NotifyStmt": {"conditionname": "c", "payload": "p"}}))]
+    #[test_case("deallocate all", json!({"DeallocateStmt": {"isall": true}}))]
+    #[test_case("discard temp", json!({"DiscardStmt": {"target": "DISCARD_TEMP"}}))]
+    fn test_stmt(source: &str, expected: Value) {
+        let tree = parse(source, ParserConfig::default()).unwrap();
+        assert_eq!(expected, tree["stmts"][0]["stmt"]);
+    }
+}
+
+use crate::float_const;
+use crate::int_const;
+use crate::integer_node;
+use crate::numeric_text;
+use crate::string_const;
+use crate::string_node;
+use crate::Converter;
+use crate::Error::Unsupported;
+use crate::Fields;
+use crate::Result;
+use pg_ast::DefaultableValue;
+use pg_ast::DiscardStmt;
+use pg_ast::IsolationLevel;
+use pg_ast::OneOrAll;
+use pg_ast::RawStmt;
+use pg_ast::SetRest;
+use pg_ast::SignedNumber;
+use pg_ast::TransactionMode;
+use pg_ast::TransactionStmt;
+use pg_ast::TypeName;
+use pg_ast::UtilityOption;
+use pg_ast::UtilityOptionName;
+use pg_ast::ValueOrDefault;
+use pg_ast::VarValue;
+use pg_ast::VariableTarget;
+use pg_ast::XmlNodeKind;
+use pg_ast::ZoneValue;
+use pg_basics::NumberRadix;
+use pg_basics::QualifiedName;
+use pg_basics::Str;
+use serde_json::Value;
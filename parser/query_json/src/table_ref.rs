@@ -0,0 +1,300 @@
+impl Converter<'_> {
+
+    /// Alias: `from_list`
+    pub fn table_refs(&self, table_refs: &[TableRef]) -> Result<Vec<Value>> {
+        table_refs.iter()
+            .map(|table_ref| self.table_ref(table_ref))
+            .collect()
+    }
+
+    /// Alias: `table_ref`
+    fn table_ref(&self, table_ref: &TableRef) -> Result {
+
+        let node = match table_ref {
+            TableRef::Relation(table_ref) => {
+                range_var(table_ref.relation(), table_ref.alias()).into_node("RangeVar")
+            },
+            TableRef::Join(join) => self.join(join)?,
+            TableRef::XmlTable(_) => return Err(Unsupported("RangeTableFunc")),
+            TableRef::JsonTable(_) => return Err(Unsupported("JsonTable")),
+            TableRef::Rows(rows) => {
+                let functions = rows.functions()
+                    .iter()
+                    .map(|func| {
+                        let column_definitions = func.column_definitions()
+                            .map(|columns| self.column_defs(columns).map(list_node))
+                            .transpose()?;
+                        self.range_function_item(func.func_call(), column_definitions)
+                    })
+                    .collect::<Result<_>>()?;
+
+                self.range_function(functions, rows.alias(), rows.ordinality(), rows.lateral())?
+                    .bool("is_rowsfrom", true)
+                    .into_node("RangeFunction")
+            },
+            TableRef::Function(func) => {
+                let functions = vec![self.range_function_item(func.function(), None)?];
+                self.range_function(functions, func.alias(), func.ordinality(), func.lateral())?
+                    .into_node("RangeFunction")
+            },
+            TableRef::TableSample(sample) => {
+                let relation = range_var(sample.relation(), sample.alias());
+                let Some(table_sample) = sample.table_sample() else {
+                    return Ok(relation.into_node("RangeVar"))
+                };
+                Fields::new()
+                    .node("relation", Some(relation.into_node("RangeVar")))
+                    .list("method", string_nodes(table_sample.function_name()))
+                    .list("args", self.exprs(table_sample.args())?)
+                    .node("repeatable", self.opt_expr(table_sample.repeatable())?)
+                    .into_node("RangeTableSample")
+            },
+            TableRef::Subselect(subselect) => {
+                Fields::new()
+                    .bool("lateral", subselect.lateral())
+                    .node("subquery", Some(self.select_stmt(subselect.select())?.into_node("SelectStmt")))
+                    .node("alias", subselect.alias().map(alias))
+                    .into_node("RangeSubselect")
+            },
+            TableRef::GraphTable(_) => return Err(Unsupported("RangeGraphTable")),
+        };
+
+        Ok(node)
+    }
+
+    /// Alias: `joined_table`
+    fn join(&self, join: &JoinTableRef) -> Result {
+
+        let fields = Fields::new()
+            .enum_value("jointype", join_type(join.join_type()))
+            .bool("isNatural", *join.condition() == JoinCondition::Natural)
+            .node("larg", Some(self.table_ref(join.left())?))
+            .node("rarg", Some(self.table_ref(join.right())?));
+
+        let fields = match join.condition() {
+            JoinCondition::Cross | JoinCondition::Natural => fields,
+            JoinCondition::Using { columns, alias } => {
+                let alias = alias.as_ref().map(|alias|
+                    Fields::new()
+                        .string("aliasname", Some(alias))
+                        .into_value()
+                );
+                fields.list("usingClause", string_nodes(columns))
+                    .node("join_using_alias", alias)
+            },
+            JoinCondition::On(quals) => fields.node("quals", Some(self.expr(quals)?)),
+        };
+
+        let node = fields
+            .node("alias", join.alias().map(alias))
+            .into_node("JoinExpr");
+        Ok(node)
+    }
+
+    /// The fields of a `RangeFunction`, where column definitions in the alias are its `coldeflist`.
+    fn range_function(
+        &self,
+        functions: Vec<Value>,
+        func_alias: Option<&FuncAlias>,
+        ordinality: bool,
+        lateral: bool
+    )
+        -> Result<Fields>
+    {
+        let (name, columns) = match func_alias {
+            Some(OneOrBoth::Left(name)) => (Some(name), &[][..]),
+            Some(OneOrBoth::Right(columns)) => (None, &columns[..]),
+            Some(OneOrBoth::Both(name, columns)) => (Some(name), &columns[..]),
+            None => (None, &[][..]),
+        };
+
+        // Without types, the columns are only aliases.
+        let (colnames, coldeflist) = if columns.iter().all(|column| column.type_name().is_none()) {
+            let colnames = columns.iter()
+                .map(|column| string_node(column.name()))
+                .collect();
+            (colnames, vec![])
+        }
+        else {
+            let coldeflist = columns.iter()
+                .map(|column| self.column_def(column.name(), column.type_name(), column.collation()))
+                .collect::<Result<_>>()?;
+            (vec![], coldeflist)
+        };
+
+        let alias = name.map(|name|
+            Fields::new()
+                .string("aliasname", Some(name))
+                .list("colnames", colnames)
+                .into_value()
+        );
+
+        let fields = Fields::new()
+            .bool("lateral", lateral)
+            .bool("ordinality", ordinality)
+            .list("functions", functions)
+            .node("alias", alias)
+            .list("coldeflist", coldeflist);
+        Ok(fields)
+    }
+
+    /// A function and its column definitions, where the definitions are `{}` when there are none.
+    fn range_function_item(&self, func: &FuncExprWindowless, column_definitions: Option<Value>) -> Result {
+
+        let func = match func {
+            FuncExprWindowless::FuncCall(func) => {
+                self.func_call(func)?
+                    .location(start(func.span()))
+                    .into_node("FuncCall")
+            },
+            FuncExprWindowless::SqlFunction(func) => self.sql_function(func, None)?,
+        };
+
+        let column_definitions = column_definitions.unwrap_or_else(|| Fields::new().into_value());
+        Ok(list_node(vec![func, column_definitions]))
+    }
+
+    fn column_defs(&self, columns: &[SimpleColumnDefinition]) -> Result<Vec<Value>> {
+        columns.iter()
+            .map(|column| self.column_def(column.name(), Some(column.type_name()), column.collation()))
+            .collect()
+    }
+
+    /// Alias: `TableFuncElement`
+    fn column_def(&self, name: &str, type_name: Option<&Type>, collation: Option<&[Str]>) -> Result {
+
+        let collation = collation.map(|collation|
+            Fields::new()
+                .list("collname", string_nodes(collation))
+                .into_value()
+        );
+
+        let node = Fields::new()
+            .string("colname", Some(name))
+            .node("typeName", type_name.map(|typ| self.type_name(typ)).transpose()?)
+            .bool("is_local", true)
+            .node("collClause", collation)
+            .into_node("ColumnDef");
+        Ok(node)
+    }
+}
+
+/// Alias: `relation_expr opt_alias_clause`
+///
+/// The fields of a `RangeVar`.
+fn range_var(relation: &RelationExpr, alias_clause: Option<&Alias>) -> Fields {
+
+    let name = relation.name();
+    let schema = name.schema();
+
+    Fields::new()
+        .string("catalogname", schema.and_then(SchemaName::catalog))
+        .string("schemaname", schema.map(SchemaName::name))
+        .string("relname", Some(name.name()))
+        .bool("inh", relation.inherited())
+        .string("relpersistence", Some("p"))
+        .node("alias", alias_clause.map(alias))
+        .location(start(name.span()))
+}
+
+pub(crate) fn join_type(join_type: JoinType) -> &'static str {
+    match join_type {
+        JoinType::Inner => "JOIN_INNER",
+        JoinType::Left => "JOIN_LEFT",
+        JoinType::Right => "JOIN_RIGHT",
+        JoinType::Full => "JOIN_FULL",
+    }
+}
+
+/// Alias: `alias_clause`
+fn alias(alias: &Alias) -> Value {
+    Fields::new()
+        .string("aliasname", Some(alias.alias()))
+        .list("colnames", string_nodes(alias.columns().unwrap_or_default()))
+        .into_value()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse;
+    use pg_parser_core::ParserConfig;
+    use serde_json::json;
+    use serde_json::Value;
+    use test_case::test_case;
+
+    #[test_case("only s.t as x(a)", json!({"RangeVar": {
+        "schemaname": "s",
+        "relname": "t",
+        "relpersistence": "p",
+        "alias": {"aliasname": "x", "colnames": [{"String": {"sval": "a"}}]},
+        "location": 17
+    }}))]
+    #[test_case("a natural left join b", json!({"JoinExpr": {
+        "jointype": "JOIN_LEFT",
+        "isNatural": true,
+        "larg": {"RangeVar": {"relname": "a", "inh": true, "relpersistence": "p", "location": 12}},
+        "rarg": {"RangeVar": {"relname": "b", "inh": true, "relpersistence": "p", "location": 32}}
+    }}))]
+    #[test_case("a join b using (c)", json!({"JoinExpr": {
+        "jointype": "JOIN_INNER",
+        "larg": {"RangeVar": {"relname": "a", "inh": true, "relpersistence": "p", "location": 12}},
+        "rarg": {"RangeVar": {"relname": "b", "inh": true, "relpersistence": "p", "location": 19}},
+        "usingClause": [{"String": {"sval": "c"}}]
+    }}))]
+    #[test_case("lateral (select) as s", json!({"RangeSubselect": {
+        "lateral": true,
+        "subquery": {"SelectStmt": {"limitOption": "LIMIT_OPTION_DEFAULT", "op": "SETOP_NONE"}},
+        "alias": {"aliasname": "s"}
+    }}))]
+    #[test_case("f() with ordinality as x(a int)", json!({"RangeFunction": {
+        "ordinality": true,
+        "functions": [{"List": {"items": [
+            {"FuncCall": {
+                "funcname": [{"String": {"sval": "f"}}],
+                "funcformat": "COERCE_EXPLICIT_CALL",
+                "location": 12
+            }},
+            {}
+        ]}}],
+        "alias": {"aliasname": "x"},
+        "coldeflist": [{"ColumnDef": {
+            "colname": "a",
+            "typeName": {
+                "names": [{"String": {"sval": "pg_catalog"}}, {"String": {"sval": "int4"}}],
+                "typemod": -1,
+                "location": 39
+            },
+            "is_local": true
+        }}]
+    }}))]
+    fn test_table_ref(source: &str, expected: Value) {
+
+        let source = format!("select from {source}");
+        let tree = parse(&source, ParserConfig::default()).unwrap();
+
+        assert_eq!(expected, tree["stmts"][0]["stmt"]["SelectStmt"]["fromClause"][0]);
+    }
+}
+
+use crate::converter::start;
+use crate::list_node;
+use crate::string_node;
+use crate::string_nodes;
+use crate::Converter;
+use crate::Error::Unsupported;
+use crate::Fields;
+use crate::Result;
+use pg_ast::Alias;
+use pg_ast::FuncAlias;
+use pg_ast::FuncExprWindowless;
+use pg_ast::JoinCondition;
+use pg_ast::JoinTableRef;
+use pg_ast::JoinType;
+use pg_ast::OneOrBoth;
+use pg_ast::RelationExpr;
+use pg_ast::SchemaName;
+use pg_ast::SimpleColumnDefinition;
+use pg_ast::TableRef;
+use pg_ast::Type;
+use pg_basics::Str;
+use serde_json::Value;
@@ -0,0 +1,181 @@
+impl Converter<'_> {
+
+    /// Alias: `Typename`
+    ///
+    /// The fields of a `TypeName`, which is never a list item, so it doesn't have the node name.
+    pub fn type_name(&self, typ: &Type) -> Result {
+
+        let type_oid = match typ.name() {
+            TypeName::Oid(oid) => *oid,
+            _ => 0,
+        };
+
+        let array_bounds = typ.array_bounds()
+            .unwrap_or_default()
+            .iter()
+            .map(|bound| integer_node(bound.unwrap_or(-1)))
+            .collect();
+
+        let node = Fields::new()
+            .list("names", type_names(typ.name()))
+            .int("typeOid", type_oid.into())
+            .bool("setof", typ.mult() == SetOf::Table)
+            .list("typmods", self.type_modifiers(typ.name())?)
+            .int("typemod", -1)
+            .list("arrayBounds", array_bounds)
+            .location(start(typ.span()))
+            .into_value();
+        Ok(node)
+    }
+
+    fn type_modifiers(&self, type_name: &TypeName) -> Result<Vec<Value>> {
+
+        let typmod = |typmod: Option<i32>| {
+            typmod.map(|typmod| int_const(typmod, None))
+                .into_iter()
+                .collect::<Vec<_>>()
+        };
+
+        // Only the mask of the interval fields and the seconds' precision.
+        let interval = |mask: i32, precision: Option<i32>| {
+            let mut typmods = vec![int_const(mask, None)];
+            typmods.extend(precision.map(|precision| int_const(precision, None)));
+            typmods
+        };
+
+        let typmods = match type_name {
+            TypeName::Json
+            | TypeName::Bool
+            | TypeName::Int2
+            | TypeName::Int4
+            | TypeName::Int8
+            | TypeName::Float4
+            | TypeName::Float8
+            | TypeName::Oid(_) => vec![],
+            TypeName::Numeric(typmods)
+            | TypeName::Varbit(typmods)
+            | TypeName::Generic { type_modifiers: typmods, .. } => {
+                self.exprs(typmods.as_deref().unwrap_or_default())?
+            },
+            // `CHAR` and `BIT` default to a length of 1.
+            TypeName::Bit(None) => vec![int_const(1, None)],
+            TypeName::Bit(Some(typmods)) => self.exprs(typmods)?,
+            TypeName::Bpchar { length } => vec![int_const(length.unwrap_or(1), None)],
+            TypeName::Varchar { max_length: precision }
+            | TypeName::Time { precision }
+            | TypeName::TimeTz { precision }
+            | TypeName::Timestamp { precision }
+            | TypeName::TimestampTz { precision } => typmod(*precision),
+            TypeName::Interval(range) => match *range {
+                IntervalRange::Full { precision: None } => vec![],
+                IntervalRange::Full { precision } => interval(INTERVAL_FULL_RANGE, precision),
+                IntervalRange::Year => interval(YEAR, None),
+                IntervalRange::YearToMonth => interval(YEAR | MONTH, None),
+                IntervalRange::Month => interval(MONTH, None),
+                IntervalRange::Day => interval(DAY, None),
+                IntervalRange::DayToHour => interval(DAY | HOUR, None),
+                IntervalRange::DayToMinute => interval(DAY | HOUR | MINUTE, None),
+                IntervalRange::DayToSecond { precision } => interval(DAY | HOUR | MINUTE | SECOND, precision),
+                IntervalRange::Hour => interval(HOUR, None),
+                IntervalRange::HourToMinute => interval(HOUR | MINUTE, None),
+                IntervalRange::HourToSecond { precision } => interval(HOUR | MINUTE | SECOND, precision),
+                IntervalRange::Minute => interval(MINUTE, None),
+                IntervalRange::MinuteToSecond { precision } => interval(MINUTE | SECOND, precision),
+                IntervalRange::Second { precision } => interval(SECOND, precision),
+            },
+        };
+
+        Ok(typmods)
+    }
+}
+
+pub(crate) fn type_names(type_name: &TypeName) -> Vec<Value> {
+    string_nodes(&qualified_type_name(type_name))
+}
+
+/// Builtin types are qualified with `pg_catalog`, and have their internal name (e.g.: `int4`).
+pub(crate) fn qualified_type_name(type_name: &TypeName) -> Vec<&str> {
+
+    let name = match type_name {
+        TypeName::Json => "json",
+        TypeName::Bool => "bool",
+        TypeName::Int2 => "int2",
+        TypeName::Int4 => "int4",
+        TypeName::Int8 => "int8",
+        TypeName::Float4 => "float4",
+        TypeName::Float8 => "float8",
+        TypeName::Numeric(_) => "numeric",
+        TypeName::Bpchar { .. } => "bpchar",
+        TypeName::Varchar { .. } => "varchar",
+        TypeName::Bit(_) => "bit",
+        TypeName::Varbit(_) => "varbit",
+        TypeName::Time { .. } => "time",
+        TypeName::TimeTz { .. } => "timetz",
+        TypeName::Timestamp { .. } => "timestamp",
+        TypeName::TimestampTz { .. } => "timestamptz",
+        TypeName::Interval(_) => "interval",
+        TypeName::Oid(_) => return vec![],
+        TypeName::Generic { name, .. } => return name.iter().map(Str::as_ref).collect(),
+    };
+
+    vec!["pg_catalog", name]
+}
+
+// `INTERVAL_MASK()` of the interval fields, from `datetime.h`.
+const MONTH: i32 = 1 << 1;
+const YEAR: i32 = 1 << 2;
+const DAY: i32 = 1 << 3;
+const HOUR: i32 = 1 << 10;
+const MINUTE: i32 = 1 << 11;
+const SECOND: i32 = 1 << 12;
+const INTERVAL_FULL_RANGE: i32 = 0x7FFF;
+
+#[cfg(test)]
+mod tests {
+    use crate::parse;
+    use pg_parser_core::ParserConfig;
+    use serde_json::json;
+    use serde_json::Value;
+    use test_case::test_case;
+
+    #[test_case("varchar(10)[]", json!({
+        "names": [{"String": {"sval": "pg_catalog"}}, {"String": {"sval": "varchar"}}],
+        "typmods": [{"A_Const": {"ival": {"ival": 10}}}],
+        "typemod": -1,
+        "arrayBounds": [{"Integer": {"ival": -1}}],
+        "location": 10
+    }))]
+    #[test_case("interval day to second(3)", json!({
+        "names": [{"String": {"sval": "pg_catalog"}}, {"String": {"sval": "interval"}}],
+        "typmods": [{"A_Const": {"ival": {"ival": 7176}}}, {"A_Const": {"ival": {"ival": 3}}}],
+        "typemod": -1,
+        "location": 10
+    }))]
+    #[test_case("public.foo", json!({
+        "names": [{"String": {"sval": "public"}}, {"String": {"sval": "foo"}}],
+        "typemod": -1,
+        "location": 10
+    }))]
+    fn test_type_name(source: &str, expected: Value) {
+
+        let source = format!("select a::{source}");
+        let tree = parse(&source, ParserConfig::default()).unwrap();
+
+        let actual = &tree["stmts"][0]["stmt"]["SelectStmt"]["targetList"][0]["ResTarget"]["val"]["TypeCast"]["typeName"];
+        assert_eq!(&expected, actual);
+    }
+}
+
+use crate::converter::start;
+use crate::int_const;
+use crate::integer_node;
+use crate::string_nodes;
+use crate::Converter;
+use crate::Fields;
+use crate::Result;
+use pg_ast::IntervalRange;
+use pg_ast::SetOf;
+use pg_ast::Type;
+use pg_ast::TypeName;
+use pg_basics::Str;
+use serde_json::Value;